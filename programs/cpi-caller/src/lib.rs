@@ -0,0 +1,81 @@
+//! Toy program that contributes to a fundraise through CPI, forwarding its accounts in the order
+//! Contribute expects. It depends on the fundraiser with `default-features = false`, so
+//! `cargo build -p cpi-caller` checks that the fundraiser builds without its entrypoint.
+
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    cpi::invoke,
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use pinocchio_fundraiser::cpi::contribute_ix_data;
+
+// Only the on-chain build needs an entrypoint, which keeps host builds of the workspace from
+// linking two `entrypoint` symbols when features are unified with the fundraiser's own.
+#[cfg(target_os = "solana")]
+pinocchio::entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let [
+        fundraiser_program,
+        authority,
+        mint_to_raise,
+        fundraise,
+        contributor,
+        authority_token_account,
+        vault,
+        system_program,
+        token_program,
+        config,
+    ] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if fundraiser_program.key() != &pinocchio_fundraiser::ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let amount = instruction_data
+        .try_into()
+        .map(u64::from_le_bytes)
+        .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+    let data = contribute_ix_data(amount);
+    let metas = [
+        AccountMeta::writable_signer(authority.key()),
+        AccountMeta::readonly(mint_to_raise.key()),
+        AccountMeta::writable(fundraise.key()),
+        AccountMeta::writable(contributor.key()),
+        AccountMeta::writable(authority_token_account.key()),
+        AccountMeta::writable(vault.key()),
+        AccountMeta::readonly(system_program.key()),
+        AccountMeta::readonly(token_program.key()),
+        AccountMeta::readonly(config.key()),
+    ];
+
+    invoke(
+        &Instruction {
+            program_id: &pinocchio_fundraiser::ID,
+            accounts: &metas,
+            data: &data,
+        },
+        &[
+            authority,
+            mint_to_raise,
+            fundraise,
+            contributor,
+            authority_token_account,
+            vault,
+            system_program,
+            token_program,
+            config,
+        ],
+    )
+}
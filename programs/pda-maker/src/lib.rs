@@ -0,0 +1,68 @@
+//! Toy program whose PDA at `[b"maker"]` is the maker of a fundraise, used by the fundraiser's
+//! LiteSVM tests. It forwards its instruction data to the fundraiser unchanged, with the
+//! accounts after the fundraiser program in the same order and flags, and signs for the PDA
+//! wherever it appears. The PDA holds no lamports, so a rent payer has to fund anything the
+//! forwarded instruction creates.
+
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    cpi::invoke_signed_with_bounds,
+    instruction::{AccountMeta, Instruction, Seed, Signer},
+    program_error::ProgramError,
+    pubkey::{Pubkey, find_program_address},
+};
+
+pub const MAKER_SEED: &[u8] = b"maker";
+
+/// Most accounts a forwarded instruction may take.
+const MAX_ACCOUNTS: usize = 24;
+
+// Only the on-chain build needs an entrypoint, which keeps host builds of the workspace from
+// linking two `entrypoint` symbols when features are unified with the fundraiser's own.
+#[cfg(target_os = "solana")]
+pinocchio::entrypoint!(process_instruction);
+
+pub fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let [fundraiser_program, forwarded @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if fundraiser_program.key() != &pinocchio_fundraiser::ID {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if forwarded.is_empty() || forwarded.len() > MAX_ACCOUNTS {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let (maker, bump) = find_program_address(&[MAKER_SEED], program_id);
+
+    // unused slots repeat the first account and are sliced off
+    let infos: [&AccountInfo; MAX_ACCOUNTS] =
+        core::array::from_fn(|i| forwarded.get(i).unwrap_or(&forwarded[0]));
+    let metas: [AccountMeta; MAX_ACCOUNTS] = core::array::from_fn(|i| {
+        AccountMeta::new(
+            infos[i].key(),
+            infos[i].is_writable(),
+            infos[i].is_signer() || infos[i].key() == &maker,
+        )
+    });
+
+    let bump = [bump];
+    let seeds = [Seed::from(MAKER_SEED), Seed::from(&bump)];
+
+    invoke_signed_with_bounds::<MAX_ACCOUNTS>(
+        &Instruction {
+            program_id: &pinocchio_fundraiser::ID,
+            accounts: &metas[..forwarded.len()],
+            data: instruction_data,
+        },
+        &infos[..forwarded.len()],
+        &[Signer::from(&seeds)],
+    )
+}
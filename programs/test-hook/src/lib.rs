@@ -0,0 +1,38 @@
+//! Toy post-contribute hook used by the fundraiser's LiteSVM tests. It tallies every delivered
+//! contribution into a program-owned leaderboard account laid out as `[total: u64, calls: u64]`.
+
+use pinocchio::{
+    ProgramResult, account_info::AccountInfo, entrypoint, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+entrypoint!(process_instruction);
+
+fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let [hook_authority, _fundraise, _contributor, leaderboard, ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if !hook_authority.is_signer() {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if instruction_data.len() != 48 || leaderboard.owner().ne(program_id) {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let amount = u64::from_le_bytes(instruction_data[32..40].try_into().unwrap());
+
+    let mut data = leaderboard.try_borrow_mut_data()?;
+    let total = u64::from_le_bytes(data[0..8].try_into().unwrap());
+    let calls = u64::from_le_bytes(data[8..16].try_into().unwrap());
+
+    data[0..8].copy_from_slice(&(total + amount).to_le_bytes());
+    data[8..16].copy_from_slice(&(calls + 1).to_le_bytes());
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+//! Toy Token-2022 transfer hook used by the fundraiser's LiteSVM tests. Its validation account
+//! lists a program-owned counter, laid out as `[total: u64, calls: u64]`, that it tallies every
+//! transfer of the mint into, followed by any accounts it reads during the transfer.
+
+use pinocchio::{
+    ProgramResult, account_info::AccountInfo, entrypoint, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// `Execute` instruction of the transfer hook interface.
+const EXECUTE_DISCRIMINATOR: [u8; 8] = [105, 37, 101, 197, 75, 251, 102, 26];
+
+entrypoint!(process_instruction);
+
+fn process_instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let [
+        _source,
+        _mint,
+        _destination,
+        _authority,
+        _validation,
+        counter,
+        read @ ..,
+    ] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let Some((&EXECUTE_DISCRIMINATOR, amount)) = instruction_data.split_first_chunk::<8>() else {
+        return Err(ProgramError::InvalidInstructionData);
+    };
+
+    if amount.len() != 8 || counter.owner().ne(program_id) {
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let amount = u64::from_le_bytes(amount.try_into().unwrap());
+
+    for account in read {
+        if account.try_borrow_data()?.is_empty() {
+            return Err(ProgramError::UninitializedAccount);
+        }
+    }
+
+    let mut data = counter.try_borrow_mut_data()?;
+    let total = u64::from_le_bytes(data[0..8].try_into().unwrap());
+    let calls = u64::from_le_bytes(data[8..16].try_into().unwrap());
+
+    data[0..8].copy_from_slice(&(total + amount).to_le_bytes());
+    data[8..16].copy_from_slice(&(calls + 1).to_le_bytes());
+
+    Ok(())
+}
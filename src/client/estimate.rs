@@ -0,0 +1,274 @@
+use std::collections::HashMap;
+
+use solana_instruction::Instruction;
+use solana_pubkey::Pubkey;
+
+pub const MAX_TRANSACTION_SIZE: usize = 1232;
+pub const MAX_TRANSACTION_ACCOUNTS: usize = 64;
+
+const SIGNATURE_LEN: usize = 64;
+const PUBKEY_LEN: usize = 32;
+const BLOCKHASH_LEN: usize = 32;
+const MESSAGE_HEADER_LEN: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TxBudget {
+    pub size: usize,
+    pub accounts: usize,
+    pub signers: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TooLarge {
+    Size(usize),
+    Accounts(usize),
+}
+
+#[inline(always)]
+fn compact_len(len: usize) -> usize {
+    match len {
+        0..=0x7f => 1,
+        0x80..=0x3fff => 2,
+        _ => 3,
+    }
+}
+
+/// Computes the serialized size of a legacy transaction carrying `ixs`, assuming the fee payer is
+/// one of the signers already referenced by the instructions.
+pub fn estimate(ixs: &[Instruction]) -> TxBudget {
+    let mut keys: HashMap<Pubkey, bool> = HashMap::new();
+
+    for ix in ixs {
+        keys.entry(ix.program_id).or_insert(false);
+
+        for meta in &ix.accounts {
+            *keys.entry(meta.pubkey).or_insert(false) |= meta.is_signer;
+        }
+    }
+
+    let signers = keys.values().filter(|is_signer| **is_signer).count().max(1);
+    let accounts = keys.len() + usize::from(keys.values().all(|is_signer| !is_signer));
+
+    let instructions_len: usize = ixs
+        .iter()
+        .map(|ix| {
+            1 + compact_len(ix.accounts.len())
+                + ix.accounts.len()
+                + compact_len(ix.data.len())
+                + ix.data.len()
+        })
+        .sum();
+
+    let size = compact_len(signers)
+        + signers * SIGNATURE_LEN
+        + MESSAGE_HEADER_LEN
+        + compact_len(accounts)
+        + accounts * PUBKEY_LEN
+        + BLOCKHASH_LEN
+        + compact_len(ixs.len())
+        + instructions_len;
+
+    TxBudget {
+        size,
+        accounts,
+        signers,
+    }
+}
+
+pub fn fits_in_transaction(ixs: &[Instruction]) -> Result<TxBudget, TooLarge> {
+    let budget = estimate(ixs);
+
+    if budget.accounts > MAX_TRANSACTION_ACCOUNTS {
+        return Err(TooLarge::Accounts(budget.accounts));
+    }
+
+    if budget.size > MAX_TRANSACTION_SIZE {
+        return Err(TooLarge::Size(budget.size));
+    }
+
+    Ok(budget)
+}
+
+/// Greedily packs `items` into the fewest transactions, where `build` turns a contiguous run of
+/// items into the instructions of a single transaction. Fails if a single item cannot fit on its
+/// own.
+pub fn chunk<T>(
+    items: &[T],
+    build: impl Fn(&[T]) -> Vec<Instruction>,
+) -> Result<Vec<Vec<Instruction>>, TooLarge> {
+    let mut transactions = Vec::new();
+    let mut start = 0;
+
+    while start < items.len() {
+        let mut ixs = build(&items[start..start + 1]);
+        fits_in_transaction(&ixs)?;
+
+        let mut end = start + 1;
+
+        while end < items.len() {
+            let next = build(&items[start..end + 1]);
+
+            if fits_in_transaction(&next).is_err() {
+                break;
+            }
+
+            ixs = next;
+            end += 1;
+        }
+
+        transactions.push(ixs);
+        start = end;
+    }
+
+    Ok(transactions)
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_keypair::Keypair;
+    use solana_message::Message;
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use solana_transaction::Transaction;
+
+    use crate::client::estimate::{
+        MAX_TRANSACTION_ACCOUNTS, MAX_TRANSACTION_SIZE, TooLarge, chunk, estimate,
+        fits_in_transaction,
+    };
+
+    fn serialized_size(payer: &Keypair, ixs: &[Instruction]) -> usize {
+        let message = Message::new(ixs, Some(&payer.pubkey()));
+        let signers = message.header.num_required_signatures as usize;
+        let tx = Transaction {
+            signatures: vec![Default::default(); signers],
+            message,
+        };
+
+        bincode::serialize(&tx).unwrap().len()
+    }
+
+    fn ix(payer: &Pubkey, accounts: usize, data_len: usize) -> Instruction {
+        let mut metas = vec![AccountMeta::new(*payer, true)];
+        metas.extend((0..accounts).map(|_| AccountMeta::new(Pubkey::new_unique(), false)));
+
+        Instruction {
+            program_id: Pubkey::new_unique(),
+            accounts: metas,
+            data: vec![0; data_len],
+        }
+    }
+
+    #[test]
+    fn estimate_matches_serialized_size() {
+        let payer = Keypair::new();
+        let program_id = Pubkey::new_unique();
+        let shared = Pubkey::new_unique();
+
+        let batches = [
+            vec![ix(&payer.pubkey(), 0, 0)],
+            vec![ix(&payer.pubkey(), 7, 17)],
+            vec![ix(&payer.pubkey(), 8, 9), ix(&payer.pubkey(), 3, 1)],
+            (0..10)
+                .map(|_| Instruction {
+                    program_id,
+                    accounts: vec![
+                        AccountMeta::new(payer.pubkey(), true),
+                        AccountMeta::new_readonly(shared, false),
+                        AccountMeta::new(Pubkey::new_unique(), false),
+                    ],
+                    data: vec![2],
+                })
+                .collect(),
+            vec![ix(&payer.pubkey(), 2, 200)],
+        ];
+
+        for ixs in batches.iter() {
+            assert_eq!(estimate(ixs).size, serialized_size(&payer, ixs));
+        }
+    }
+
+    #[test]
+    fn estimate_counts_unique_accounts() {
+        let payer = Keypair::new();
+        let program_id = Pubkey::new_unique();
+        let shared = Pubkey::new_unique();
+
+        let ixs: Vec<Instruction> = (0..3)
+            .map(|_| Instruction {
+                program_id,
+                accounts: vec![
+                    AccountMeta::new(payer.pubkey(), true),
+                    AccountMeta::new(shared, false),
+                ],
+                data: vec![],
+            })
+            .collect();
+
+        let budget = estimate(&ixs);
+
+        assert_eq!(budget.accounts, 3);
+        assert_eq!(budget.signers, 1);
+    }
+
+    #[test]
+    fn reject_oversized_transactions() {
+        let payer = Keypair::new();
+
+        assert!(matches!(
+            fits_in_transaction(&[ix(&payer.pubkey(), 0, MAX_TRANSACTION_SIZE)]),
+            Err(TooLarge::Size(_))
+        ));
+        assert!(matches!(
+            fits_in_transaction(&[ix(&payer.pubkey(), MAX_TRANSACTION_ACCOUNTS, 0)]),
+            Err(TooLarge::Accounts(_))
+        ));
+    }
+
+    #[test]
+    fn chunk_into_maximal_transactions() {
+        let payer = Keypair::new();
+        let program_id = Pubkey::new_unique();
+        let items: Vec<(Pubkey, Pubkey)> = (0..60)
+            .map(|_| (Pubkey::new_unique(), Pubkey::new_unique()))
+            .collect();
+
+        let build = |pairs: &[(Pubkey, Pubkey)]| {
+            let mut accounts = vec![AccountMeta::new(payer.pubkey(), true)];
+            accounts.extend(
+                pairs
+                    .iter()
+                    .flat_map(|(a, b)| [AccountMeta::new(*a, false), AccountMeta::new(*b, false)]),
+            );
+
+            vec![Instruction {
+                program_id,
+                accounts,
+                data: vec![0],
+            }]
+        };
+
+        let transactions = chunk(&items, build).unwrap();
+        let total: usize = transactions
+            .iter()
+            .map(|ixs| (ixs[0].accounts.len() - 1) / 2)
+            .sum();
+
+        assert_eq!(total, items.len());
+        assert!(transactions.len() > 1);
+
+        for (i, ixs) in transactions.iter().enumerate() {
+            assert!(serialized_size(&payer, ixs) <= MAX_TRANSACTION_SIZE);
+
+            if i + 1 < transactions.len() {
+                let taken = (ixs[0].accounts.len() - 1) / 2;
+                let offset: usize = transactions[..i]
+                    .iter()
+                    .map(|ixs| (ixs[0].accounts.len() - 1) / 2)
+                    .sum();
+
+                assert!(fits_in_transaction(&build(&items[offset..offset + taken + 1])).is_err());
+            }
+        }
+    }
+}
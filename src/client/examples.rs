@@ -0,0 +1,1380 @@
+//! End-to-end usage of the client API, run against LiteSVM by `cargo test`.
+//!
+//! Every example is listed in [`EXAMPLES`], and every public client function must be called as
+//! `client::<name>(..)` from at least one of them, which the tests below enforce.
+
+use litesvm::{LiteSVM, types::FailedTransactionMetadata};
+use solana_instruction::{AccountMeta, error::InstructionError};
+use solana_keypair::Keypair;
+use solana_pubkey::Pubkey;
+use solana_signer::Signer;
+use solana_transaction_error::TransactionError;
+use spl_associated_token_account::solana_program::{
+    clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL,
+};
+use spl_token_2022::state::Account;
+
+use crate::{
+    AccountLoad, CRANK_REFUND_MAX_ENTRIES, Contributor, Fundraise, FundraiserError, Referral,
+    Stats, StatsView, Updates, client,
+    tests::{
+        constants::{
+            FEE_COLLECTOR, MINT_DECIMALS, TEST_TRANSFER_HOOK_PROGRAM_ID, TOKEN_2022_PROGRAM_ID,
+            TOKEN_PROGRAM_ID,
+        },
+        utils::{
+            build_and_send_transaction, fetch_account, forward_time, init_ata, init_config,
+            init_mint, init_multisig, init_token_account, init_transfer_hook_ata,
+            init_transfer_hook_mint, init_wallet, set_config_bond, set_freeze_authority,
+        },
+    },
+};
+
+pub struct Example {
+    pub name: &'static str,
+    pub run: fn(&mut LiteSVM),
+}
+
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        name: "create_campaign",
+        run: |litesvm| {
+            create_campaign(litesvm);
+        },
+    },
+    Example {
+        name: "contribute_to_campaign",
+        run: contribute_to_campaign,
+    },
+    Example {
+        name: "handle_rejections",
+        run: handle_rejections,
+    },
+    Example {
+        name: "claim_raised_funds",
+        run: claim_raised_funds,
+    },
+    Example {
+        name: "refund_contribution",
+        run: refund_contribution,
+    },
+    Example {
+        name: "batch_contributions",
+        run: batch_contributions,
+    },
+    Example {
+        name: "track_protocol_stats",
+        run: track_protocol_stats,
+    },
+    Example {
+        name: "contribute_transfer_hook_mint",
+        run: contribute_transfer_hook_mint,
+    },
+    Example {
+        name: "refund_sponsored_contribution",
+        run: refund_sponsored_contribution,
+    },
+    Example {
+        name: "contribute_from_multisig",
+        run: contribute_from_multisig,
+    },
+    Example {
+        name: "crank_failed_campaign",
+        run: crank_failed_campaign,
+    },
+    Example {
+        name: "name_campaign",
+        run: name_campaign,
+    },
+    Example {
+        name: "pause_campaign",
+        run: pause_campaign,
+    },
+    Example {
+        name: "reward_tiers",
+        run: reward_tiers,
+    },
+    Example {
+        name: "refer_backers",
+        run: refer_backers,
+    },
+    Example {
+        name: "cancel_bonded_campaign",
+        run: cancel_bonded_campaign,
+    },
+    Example {
+        name: "share_project_tokens",
+        run: share_project_tokens,
+    },
+    Example {
+        name: "issue_receipts",
+        run: issue_receipts,
+    },
+    Example {
+        name: "match_contributions",
+        run: match_contributions,
+    },
+    Example {
+        name: "reclaim_unused_match",
+        run: reclaim_unused_match,
+    },
+    Example {
+        name: "gate_on_collection",
+        run: gate_on_collection,
+    },
+    Example {
+        name: "post_updates",
+        run: post_updates,
+    },
+    Example {
+        name: "snapshot_governance_weight",
+        run: snapshot_governance_weight,
+    },
+    Example {
+        name: "clamp_to_target",
+        run: clamp_to_target,
+    },
+    Example {
+        name: "raise_freezable_mint",
+        run: raise_freezable_mint,
+    },
+    Example {
+        name: "delegate_claim",
+        run: delegate_claim,
+    },
+    Example {
+        name: "recover_stray_tokens",
+        run: recover_stray_tokens,
+    },
+    Example {
+        name: "close_dusted_campaign",
+        run: close_dusted_campaign,
+    },
+];
+
+const AMOUNT_TO_RAISE: u64 = 5_000_000;
+const DURATION: u64 = SECONDS_PER_DAY;
+
+pub struct Campaign {
+    pub maker: Keypair,
+    pub mint_to_raise: Pubkey,
+}
+
+/// Returns the fundraiser error a failed transaction was rejected with, if any.
+fn rejection(failed: FailedTransactionMetadata) -> Option<FundraiserError> {
+    match failed.err {
+        TransactionError::InstructionError(_, InstructionError::Custom(code)) => {
+            FundraiserError::try_from(code).ok()
+        }
+        _ => None,
+    }
+}
+
+fn backer(litesvm: &mut LiteSVM, campaign: &Campaign, amount: u64) -> Keypair {
+    let backer = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    init_ata(litesvm, campaign.mint_to_raise, backer.pubkey(), amount);
+
+    backer
+}
+
+pub fn create_campaign(litesvm: &mut LiteSVM) -> Campaign {
+    let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+    let ix = client::initialize(
+        &maker.pubkey(),
+        &mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+        AMOUNT_TO_RAISE,
+        DURATION,
+    );
+    build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+    let fundraise = client::fundraise_address(&maker.pubkey());
+    let account = litesvm.get_account(&fundraise).unwrap();
+
+    assert_eq!(account.owner, client::program_id());
+    assert_eq!(
+        Fundraise::load(&account.data)
+            .unwrap()
+            .get_amount_to_raise(),
+        AMOUNT_TO_RAISE
+    );
+
+    Campaign {
+        maker,
+        mint_to_raise,
+    }
+}
+
+pub fn contribute_to_campaign(litesvm: &mut LiteSVM) {
+    let campaign = create_campaign(litesvm);
+    let backer = backer(litesvm, &campaign, 1_000_000);
+
+    let ix = client::contribute(
+        &backer.pubkey(),
+        &campaign.maker.pubkey(),
+        &campaign.mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+        500_000,
+    );
+    build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]).unwrap();
+
+    let fundraise = client::fundraise_address(&campaign.maker.pubkey());
+    let contributor = client::contributor_address(&fundraise, &backer.pubkey());
+    let account = litesvm.get_account(&contributor).unwrap();
+
+    assert_eq!(
+        Contributor::load(&account.data).unwrap().get_amount(),
+        500_000
+    );
+}
+
+pub fn handle_rejections(litesvm: &mut LiteSVM) {
+    let campaign = create_campaign(litesvm);
+    let backer = backer(litesvm, &campaign, 1_000_000);
+
+    let contribute = |amount| {
+        client::contribute(
+            &backer.pubkey(),
+            &campaign.maker.pubkey(),
+            &campaign.mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            amount,
+        )
+    };
+
+    let failed =
+        build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[contribute(0)])
+            .unwrap_err();
+
+    assert_eq!(
+        rejection(failed),
+        Some(FundraiserError::ContributionTooSmall)
+    );
+
+    forward_time(litesvm, DURATION as i64 + 1);
+
+    let failed =
+        build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[contribute(1_000)])
+            .unwrap_err();
+
+    assert_eq!(rejection(failed), Some(FundraiserError::FundraiserEnded));
+}
+
+pub fn claim_raised_funds(litesvm: &mut LiteSVM) {
+    let campaign = create_campaign(litesvm);
+    let backer = backer(litesvm, &campaign, AMOUNT_TO_RAISE);
+    let maker = campaign.maker.pubkey();
+
+    let ix = client::contribute(
+        &backer.pubkey(),
+        &maker,
+        &campaign.mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+        AMOUNT_TO_RAISE,
+    );
+    build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]).unwrap();
+
+    // a 1% protocol fee is paid to the collector named by the config
+    let config = init_config(litesvm, maker, 100, FEE_COLLECTOR, false);
+
+    assert_eq!(config, client::config_address());
+
+    let ix = client::claim(
+        &maker,
+        &campaign.mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+        &FEE_COLLECTOR,
+    );
+    build_and_send_transaction(litesvm, &[&campaign.maker], &maker, &[ix]).unwrap();
+
+    let maker_ata =
+        client::associated_token_address(&maker, &campaign.mint_to_raise, &TOKEN_PROGRAM_ID);
+
+    let fee_collector_ata = client::associated_token_address(
+        &FEE_COLLECTOR,
+        &campaign.mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+    );
+
+    assert!(litesvm.get_account(&maker_ata).is_some());
+    assert!(litesvm.get_account(&fee_collector_ata).is_some());
+}
+
+pub fn refund_contribution(litesvm: &mut LiteSVM) {
+    let campaign = create_campaign(litesvm);
+    let backer = backer(litesvm, &campaign, 1_000_000);
+    let maker = campaign.maker.pubkey();
+
+    let ix = client::contribute(
+        &backer.pubkey(),
+        &maker,
+        &campaign.mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+        500_000,
+    );
+    build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]).unwrap();
+
+    let ix = client::refund(
+        &backer.pubkey(),
+        &maker,
+        &campaign.mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+    );
+    build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]).unwrap();
+
+    let fundraise = client::fundraise_address(&maker);
+    let account = litesvm.get_account(&fundraise).unwrap();
+
+    assert_eq!(
+        Fundraise::load(&account.data).unwrap().get_current_amount(),
+        0
+    );
+    // the emptied vault is closed back to the maker
+    assert!(
+        litesvm
+            .get_account(&client::vault_address(&fundraise))
+            .is_none()
+    );
+}
+
+pub fn batch_contributions(litesvm: &mut LiteSVM) {
+    let campaign = create_campaign(litesvm);
+    let backers: Vec<Keypair> = (0..24)
+        .map(|_| backer(litesvm, &campaign, 100_000))
+        .collect();
+
+    // every backer signs, so the greedy packer decides how many fit per transaction
+    let batches = client::chunk(&backers, |batch| {
+        batch
+            .iter()
+            .map(|backer| {
+                client::contribute(
+                    &backer.pubkey(),
+                    &campaign.maker.pubkey(),
+                    &campaign.mint_to_raise,
+                    &TOKEN_PROGRAM_ID,
+                    100_000,
+                )
+            })
+            .collect()
+    })
+    .unwrap();
+
+    let mut sent = 0;
+
+    for ixs in &batches {
+        let budget = client::fits_in_transaction(ixs).unwrap();
+
+        assert_eq!(budget, client::estimate(ixs));
+
+        let signers: Vec<&Keypair> = backers[sent..sent + ixs.len()].iter().collect();
+        build_and_send_transaction(litesvm, &signers, &signers[0].pubkey(), ixs).unwrap();
+        sent += ixs.len();
+    }
+
+    assert_eq!(sent, backers.len());
+
+    let fundraise = client::fundraise_address(&campaign.maker.pubkey());
+    let account = litesvm.get_account(&fundraise).unwrap();
+
+    assert_eq!(
+        Fundraise::load(&account.data).unwrap().get_current_amount(),
+        100_000 * backers.len() as u64
+    );
+}
+
+pub fn track_protocol_stats(litesvm: &mut LiteSVM) {
+    let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+    // the first instruction passing the stats account creates it
+    let ix = client::with_stats(client::initialize(
+        &maker.pubkey(),
+        &mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+        AMOUNT_TO_RAISE,
+        DURATION,
+    ));
+    build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+    let campaign = Campaign {
+        maker,
+        mint_to_raise,
+    };
+    let early = backer(litesvm, &campaign, 1_000_000);
+    let late = backer(litesvm, &campaign, AMOUNT_TO_RAISE);
+    let maker = campaign.maker.pubkey();
+
+    let contribute = |litesvm: &mut LiteSVM, backer: &Keypair, amount: u64| {
+        let ix = client::with_stats(client::contribute(
+            &backer.pubkey(),
+            &maker,
+            &campaign.mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            amount,
+        ));
+        build_and_send_transaction(litesvm, &[backer], &backer.pubkey(), &[ix]).unwrap();
+    };
+
+    contribute(litesvm, &early, 1_000_000);
+    contribute(litesvm, &late, 3_000_000);
+
+    let ix = client::with_stats(client::refund(
+        &early.pubkey(),
+        &maker,
+        &campaign.mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+    ));
+    build_and_send_transaction(litesvm, &[&early], &early.pubkey(), &[ix]).unwrap();
+
+    // the refunded backer's share is made up by the late one
+    contribute(litesvm, &late, 2_000_000);
+
+    init_config(litesvm, maker, 0, FEE_COLLECTOR, false);
+
+    let ix = client::with_stats(client::claim(
+        &maker,
+        &campaign.mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+        &FEE_COLLECTOR,
+    ));
+    build_and_send_transaction(litesvm, &[&campaign.maker], &maker, &[ix]).unwrap();
+
+    let account = litesvm.get_account(&client::stats_address()).unwrap();
+
+    assert_eq!(
+        Stats::unpack(&account.data).unwrap(),
+        StatsView {
+            fundraisers_created: 1,
+            total_raised: 6_000_000,
+            total_refunded: 1_000_000,
+            fundraisers_claimed: 1,
+            total_claimed: AMOUNT_TO_RAISE,
+            bump: crate::STATS_BUMP,
+        }
+    );
+}
+
+pub fn contribute_transfer_hook_mint(litesvm: &mut LiteSVM) {
+    let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let backer = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let (mint_to_raise, counter) =
+        init_transfer_hook_mint(litesvm, MINT_DECIMALS, 1_000_000_000, &[]);
+    init_transfer_hook_ata(litesvm, mint_to_raise, backer.pubkey(), 1_000_000);
+
+    let ix = client::initialize(
+        &maker.pubkey(),
+        &mint_to_raise,
+        &TOKEN_2022_PROGRAM_ID,
+        AMOUNT_TO_RAISE,
+        DURATION,
+    );
+    build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+    // the hook's program, validation account and the counter it lists go along with the transfer
+    let ix = client::with_transfer_hook(
+        client::contribute(
+            &backer.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_2022_PROGRAM_ID,
+            1_000_000,
+        ),
+        &mint_to_raise,
+        &TEST_TRANSFER_HOOK_PROGRAM_ID,
+        &[AccountMeta::new(counter, false)],
+    );
+    build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]).unwrap();
+
+    let account = litesvm.get_account(&counter).unwrap();
+
+    assert_eq!(
+        u64::from_le_bytes(account.data[8..16].try_into().unwrap()),
+        1
+    );
+}
+
+pub fn refund_sponsored_contribution(litesvm: &mut LiteSVM) {
+    let campaign = create_campaign(litesvm);
+    let backer = backer(litesvm, &campaign, 1_000_000);
+    let sponsor = init_wallet(litesvm, LAMPORTS_PER_SOL);
+
+    // a signer trailing the contribution pays the contributor account's rent
+    let ix = client::with_rent_payer(
+        client::contribute(
+            &backer.pubkey(),
+            &campaign.maker.pubkey(),
+            &campaign.mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            1_000_000,
+        ),
+        &sponsor.pubkey(),
+    );
+    build_and_send_transaction(litesvm, &[&backer, &sponsor], &backer.pubkey(), &[ix]).unwrap();
+
+    let balance = litesvm.get_balance(&sponsor.pubkey()).unwrap();
+
+    // and gets it back once the refund closes the account
+    let ix = client::with_rent_destination(
+        client::refund(
+            &backer.pubkey(),
+            &campaign.maker.pubkey(),
+            &campaign.mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        ),
+        &sponsor.pubkey(),
+    );
+    build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]).unwrap();
+
+    assert!(litesvm.get_balance(&sponsor.pubkey()).unwrap() > balance);
+}
+
+pub fn crank_failed_campaign(litesvm: &mut LiteSVM) {
+    let campaign = create_campaign(litesvm);
+    let backers: Vec<Keypair> = (0..12)
+        .map(|_| backer(litesvm, &campaign, 100_000))
+        .collect();
+
+    for backer in &backers {
+        let ix = client::contribute(
+            &backer.pubkey(),
+            &campaign.maker.pubkey(),
+            &campaign.mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            100_000,
+        );
+        build_and_send_transaction(litesvm, &[backer], &backer.pubkey(), &[ix]).unwrap();
+    }
+
+    // past the deadline and below target, anyone may refund every backer in batches
+    forward_time(litesvm, DURATION as i64 + 1);
+
+    let cranker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let positions: Vec<(Pubkey, Pubkey)> = backers
+        .iter()
+        .map(|backer| (backer.pubkey(), backer.pubkey()))
+        .collect();
+
+    for batch in positions.chunks(CRANK_REFUND_MAX_ENTRIES) {
+        let ix = client::crank_refund(
+            &campaign.maker.pubkey(),
+            &campaign.mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            batch,
+        );
+        build_and_send_transaction(litesvm, &[&cranker], &cranker.pubkey(), &[ix]).unwrap();
+    }
+
+    let fundraise = client::fundraise_address(&campaign.maker.pubkey());
+
+    for backer in &backers {
+        assert!(
+            litesvm
+                .get_account(&client::contributor_address(&fundraise, &backer.pubkey()))
+                .is_none()
+        );
+    }
+    assert!(
+        litesvm
+            .get_account(&client::vault_address(&fundraise))
+            .is_none()
+    );
+
+    // with nothing left owed, the fundraise itself is closed back to the maker
+    let ix = client::close_fundraise(&campaign.maker.pubkey(), &TOKEN_PROGRAM_ID);
+    build_and_send_transaction(litesvm, &[&cranker], &cranker.pubkey(), &[ix]).unwrap();
+
+    assert!(litesvm.get_account(&fundraise).is_none());
+}
+
+pub fn contribute_from_multisig(litesvm: &mut LiteSVM) {
+    let campaign = create_campaign(litesvm);
+    let signers = [
+        init_wallet(litesvm, LAMPORTS_PER_SOL),
+        init_wallet(litesvm, LAMPORTS_PER_SOL),
+    ];
+    let multisig = init_multisig(
+        litesvm,
+        TOKEN_PROGRAM_ID,
+        2,
+        &signers.each_ref().map(|signer| signer.pubkey()),
+    );
+    init_ata(litesvm, campaign.mint_to_raise, multisig, 1_000_000);
+
+    // both signers sign for the multisig, and the first pays the contributor account's rent
+    let mut ix = client::with_multisig_signers(
+        client::contribute(
+            &multisig,
+            &campaign.maker.pubkey(),
+            &campaign.mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            1_000_000,
+        ),
+        &signers.each_ref().map(|signer| signer.pubkey()),
+    );
+    ix.accounts
+        .push(AccountMeta::new(signers[0].pubkey(), true));
+    build_and_send_transaction(
+        litesvm,
+        &[&signers[0], &signers[1]],
+        &signers[0].pubkey(),
+        &[ix],
+    )
+    .unwrap();
+
+    let fundraise = client::fundraise_address(&campaign.maker.pubkey());
+
+    assert!(
+        litesvm
+            .get_account(&client::contributor_address(&fundraise, &multisig))
+            .is_some()
+    );
+}
+
+pub fn name_campaign(litesvm: &mut LiteSVM) {
+    let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+    let ix = client::with_metadata(
+        client::initialize(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            AMOUNT_TO_RAISE,
+            DURATION,
+        ),
+        "Community garden",
+        "https://example.com/garden.json",
+    );
+    build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+    // the maker can rename the campaign or move its metadata at any time
+    let ix = client::update_metadata(
+        &maker.pubkey(),
+        "Community garden, phase two",
+        "https://example.com/garden-2.json",
+    );
+    build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+    let fundraise = client::fundraise_address(&maker.pubkey());
+    let account = litesvm.get_account(&fundraise).unwrap();
+    let fundraise = Fundraise::load(&account.data).unwrap();
+
+    assert_eq!(fundraise.name(), b"Community garden, phase two");
+    assert_eq!(fundraise.uri(), b"https://example.com/garden-2.json");
+}
+
+pub fn pause_campaign(litesvm: &mut LiteSVM) {
+    let campaign = create_campaign(litesvm);
+    let backer = backer(litesvm, &campaign, 1_000_000);
+    let contribute = client::contribute(
+        &backer.pubkey(),
+        &campaign.maker.pubkey(),
+        &campaign.mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+        500_000,
+    );
+
+    let ix = client::set_paused(&campaign.maker.pubkey(), true);
+    build_and_send_transaction(litesvm, &[&campaign.maker], &campaign.maker.pubkey(), &[ix])
+        .unwrap();
+
+    // contributions are held off while paused, though the deadline keeps approaching
+    let failed = build_and_send_transaction(
+        litesvm,
+        &[&backer],
+        &backer.pubkey(),
+        std::slice::from_ref(&contribute),
+    )
+    .unwrap_err();
+
+    assert_eq!(rejection(failed), Some(FundraiserError::FundraisePaused));
+
+    let ix = client::set_paused(&campaign.maker.pubkey(), false);
+    build_and_send_transaction(litesvm, &[&campaign.maker], &campaign.maker.pubkey(), &[ix])
+        .unwrap();
+    litesvm.expire_blockhash();
+
+    build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[contribute]).unwrap();
+}
+
+pub fn reward_tiers(litesvm: &mut LiteSVM) {
+    let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+    // backers of 100_000 reach the first tier, and of 1_000_000 the second
+    let ix = client::with_tier_thresholds(
+        client::initialize(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            AMOUNT_TO_RAISE,
+            DURATION,
+        ),
+        [100_000, 1_000_000, 0, 0],
+    );
+    build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+    let campaign = Campaign {
+        maker,
+        mint_to_raise,
+    };
+    let backer = backer(litesvm, &campaign, 250_000);
+
+    let ix = client::contribute(
+        &backer.pubkey(),
+        &campaign.maker.pubkey(),
+        &campaign.mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+        250_000,
+    );
+    build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]).unwrap();
+
+    let contributor = client::contributor_address(
+        &client::fundraise_address(&campaign.maker.pubkey()),
+        &backer.pubkey(),
+    );
+    let account = litesvm.get_account(&contributor).unwrap();
+
+    assert_eq!(Contributor::load(&account.data).unwrap().get_tier(), 1);
+}
+
+pub fn refer_backers(litesvm: &mut LiteSVM) {
+    let campaign = create_campaign(litesvm);
+    let referrer = Pubkey::new_unique();
+
+    for amount in [250_000, 150_000] {
+        let backer = backer(litesvm, &campaign, amount);
+
+        let ix = client::with_referrer(
+            client::contribute(
+                &backer.pubkey(),
+                &campaign.maker.pubkey(),
+                &campaign.mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+                amount,
+            ),
+            &campaign.maker.pubkey(),
+            &referrer,
+        );
+        build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]).unwrap();
+    }
+
+    let referral = client::referral_address(
+        &client::fundraise_address(&campaign.maker.pubkey()),
+        &referrer,
+    );
+    let account = litesvm.get_account(&referral).unwrap();
+    let referral = Referral::unpack(&account.data).unwrap();
+
+    assert_eq!(referral.referred_amount, 400_000);
+    assert_eq!(referral.referred_count, 2);
+}
+
+pub fn cancel_bonded_campaign(litesvm: &mut LiteSVM) {
+    // makers stake 0.1 SOL, a quarter of which they forfeit by cancelling once backed
+    set_config_bond(litesvm, LAMPORTS_PER_SOL / 10, 2_500);
+
+    let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+    let ix = client::initialize(
+        &maker.pubkey(),
+        &mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+        AMOUNT_TO_RAISE,
+        DURATION,
+    );
+    build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+    let campaign = Campaign {
+        maker,
+        mint_to_raise,
+    };
+    let backer = backer(litesvm, &campaign, 250_000);
+
+    let ix = client::contribute(
+        &backer.pubkey(),
+        &campaign.maker.pubkey(),
+        &campaign.mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+        250_000,
+    );
+    build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]).unwrap();
+
+    let collected = litesvm.get_balance(&FEE_COLLECTOR).unwrap_or_default();
+
+    let ix = client::cancel(&campaign.maker.pubkey(), &FEE_COLLECTOR);
+    build_and_send_transaction(litesvm, &[&campaign.maker], &campaign.maker.pubkey(), &[ix])
+        .unwrap();
+
+    assert_eq!(
+        litesvm.get_balance(&FEE_COLLECTOR).unwrap_or_default(),
+        collected + LAMPORTS_PER_SOL / 40
+    );
+
+    // the backer takes the contribution back out
+    let ix = client::refund(
+        &backer.pubkey(),
+        &campaign.maker.pubkey(),
+        &campaign.mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+    );
+    build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]).unwrap();
+}
+
+pub fn share_project_tokens(litesvm: &mut LiteSVM) {
+    let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+    let reward_mint = init_mint(litesvm, TOKEN_PROGRAM_ID, 9, 1_000_000_000);
+    init_ata(litesvm, reward_mint, maker.pubkey(), 1_000_000_000);
+
+    // backers split 1_000_000_000 project tokens in proportion to what they put in
+    let ix = client::with_rewards(
+        client::initialize(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            AMOUNT_TO_RAISE,
+            DURATION,
+        ),
+        &reward_mint,
+        1_000_000_000,
+    );
+    build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+    let ix = client::deposit_rewards(
+        &maker.pubkey(),
+        &reward_mint,
+        &TOKEN_PROGRAM_ID,
+        1_000_000_000,
+    );
+    build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+    let campaign = Campaign {
+        maker,
+        mint_to_raise,
+    };
+    let backer = backer(litesvm, &campaign, AMOUNT_TO_RAISE);
+
+    let ix = client::contribute(
+        &backer.pubkey(),
+        &campaign.maker.pubkey(),
+        &campaign.mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+        AMOUNT_TO_RAISE,
+    );
+    build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]).unwrap();
+
+    // shares open up once the maker claims the raise
+    let ix = client::claim(
+        &campaign.maker.pubkey(),
+        &campaign.mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+        &FEE_COLLECTOR,
+    );
+    build_and_send_transaction(litesvm, &[&campaign.maker], &campaign.maker.pubkey(), &[ix])
+        .unwrap();
+
+    let ix = client::claim_reward(
+        &backer.pubkey(),
+        &campaign.maker.pubkey(),
+        &reward_mint,
+        &TOKEN_PROGRAM_ID,
+    );
+    build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]).unwrap();
+
+    let contributor = client::contributor_address(
+        &client::fundraise_address(&campaign.maker.pubkey()),
+        &backer.pubkey(),
+    );
+    let account = litesvm.get_account(&contributor).unwrap();
+
+    assert!(
+        Contributor::load(&account.data)
+            .unwrap()
+            .is_reward_claimed()
+    );
+
+    // with every share paid out, the maker takes back whatever rounding left behind
+    let ix = client::sweep_rewards(&campaign.maker.pubkey(), &reward_mint, &TOKEN_PROGRAM_ID);
+    build_and_send_transaction(litesvm, &[&campaign.maker], &campaign.maker.pubkey(), &[ix])
+        .unwrap();
+}
+
+pub fn issue_receipts(litesvm: &mut LiteSVM) {
+    let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let receipt_mint = Keypair::new();
+    let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+    // the receipt mint is created at a fresh address, which signs for it
+    let ix = client::with_receipt_mint(
+        client::initialize(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            AMOUNT_TO_RAISE,
+            DURATION,
+        ),
+        &receipt_mint.pubkey(),
+    );
+    build_and_send_transaction(litesvm, &[&maker, &receipt_mint], &maker.pubkey(), &[ix]).unwrap();
+
+    let campaign = Campaign {
+        maker,
+        mint_to_raise,
+    };
+    let backer = backer(litesvm, &campaign, 1_000_000);
+    let receipts = init_ata(litesvm, receipt_mint.pubkey(), backer.pubkey(), 0);
+
+    let ix = client::with_receipt(
+        client::contribute(
+            &backer.pubkey(),
+            &campaign.maker.pubkey(),
+            &campaign.mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            1_000_000,
+        ),
+        &receipt_mint.pubkey(),
+        &backer.pubkey(),
+    );
+    build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]).unwrap();
+
+    assert_eq!(
+        fetch_account::<Account>(litesvm, &receipts).amount,
+        1_000_000
+    );
+
+    // refunding burns the receipts along with the position
+    let ix = client::with_receipt(
+        client::refund(
+            &backer.pubkey(),
+            &campaign.maker.pubkey(),
+            &campaign.mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        ),
+        &receipt_mint.pubkey(),
+        &backer.pubkey(),
+    );
+    build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]).unwrap();
+
+    assert_eq!(fetch_account::<Account>(litesvm, &receipts).amount, 0);
+}
+
+pub fn match_contributions(litesvm: &mut LiteSVM) {
+    let campaign = create_campaign(litesvm);
+    let sponsor = backer(litesvm, &campaign, 8_000_000);
+
+    // the sponsor doubles every token contributed, up to 8_000_000
+    let ix = client::deposit_match(
+        &sponsor.pubkey(),
+        &campaign.maker.pubkey(),
+        &campaign.mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+        8_000_000,
+    );
+    build_and_send_transaction(litesvm, &[&sponsor], &sponsor.pubkey(), &[ix]).unwrap();
+
+    let backer = backer(litesvm, &campaign, AMOUNT_TO_RAISE);
+
+    let ix = client::contribute(
+        &backer.pubkey(),
+        &campaign.maker.pubkey(),
+        &campaign.mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+        AMOUNT_TO_RAISE,
+    );
+    build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]).unwrap();
+
+    // the claim pays the match on top and hands the unmatched 3_000_000 back to the sponsor
+    let ix = client::with_match(
+        client::claim(
+            &campaign.maker.pubkey(),
+            &campaign.mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            &FEE_COLLECTOR,
+        ),
+        &sponsor.pubkey(),
+    );
+    build_and_send_transaction(litesvm, &[&campaign.maker], &campaign.maker.pubkey(), &[ix])
+        .unwrap();
+
+    let maker_account = client::associated_token_address(
+        &campaign.maker.pubkey(),
+        &campaign.mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+    );
+    let sponsor_account = client::associated_token_address(
+        &sponsor.pubkey(),
+        &campaign.mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+    );
+
+    assert_eq!(
+        fetch_account::<Account>(litesvm, &maker_account).amount,
+        AMOUNT_TO_RAISE * 2
+    );
+    assert_eq!(
+        fetch_account::<Account>(litesvm, &sponsor_account).amount,
+        3_000_000
+    );
+}
+
+pub fn reclaim_unused_match(litesvm: &mut LiteSVM) {
+    let campaign = create_campaign(litesvm);
+    let sponsor = backer(litesvm, &campaign, 1_000_000);
+
+    let ix = client::deposit_match(
+        &sponsor.pubkey(),
+        &campaign.maker.pubkey(),
+        &campaign.mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+        1_000_000,
+    );
+    build_and_send_transaction(litesvm, &[&sponsor], &sponsor.pubkey(), &[ix]).unwrap();
+
+    forward_time(litesvm, DURATION as i64 + 1);
+
+    let ix = client::reclaim_match(
+        &sponsor.pubkey(),
+        &campaign.maker.pubkey(),
+        &campaign.mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+    );
+    build_and_send_transaction(litesvm, &[&sponsor], &sponsor.pubkey(), &[ix]).unwrap();
+}
+
+pub fn gate_on_collection(litesvm: &mut LiteSVM) {
+    let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let collection = init_mint(litesvm, TOKEN_PROGRAM_ID, 0, 1);
+    let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+    let ix = client::with_gate_mint(
+        client::initialize(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            AMOUNT_TO_RAISE,
+            DURATION,
+        ),
+        &collection,
+    );
+    build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+    let campaign = Campaign {
+        maker,
+        mint_to_raise,
+    };
+    let backer = backer(litesvm, &campaign, 1_000_000);
+    let ix = client::contribute(
+        &backer.pubkey(),
+        &campaign.maker.pubkey(),
+        &campaign.mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+        1_000_000,
+    );
+
+    // only holders of the collection may contribute, showing the token at each contribution
+    let gate_token = init_ata(litesvm, collection, backer.pubkey(), 1);
+    let ix = client::with_gate(ix, &gate_token);
+    build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]).unwrap();
+
+    let fundraise = client::fundraise_address(&campaign.maker.pubkey());
+    let account = litesvm.get_account(&fundraise).unwrap();
+
+    assert_eq!(
+        Fundraise::load(&account.data).unwrap().get_current_amount(),
+        1_000_000
+    );
+}
+
+pub fn post_updates(litesvm: &mut LiteSVM) {
+    let campaign = create_campaign(litesvm);
+    let maker = &campaign.maker;
+
+    // only a hash of each update is kept on-chain, against which backers check what they read
+    for content_hash in [[1; 32], [2; 32]] {
+        let ix = client::post_update(&maker.pubkey(), content_hash);
+        build_and_send_transaction(litesvm, &[maker], &maker.pubkey(), &[ix]).unwrap();
+    }
+
+    let updates = client::updates_address(&client::fundraise_address(&maker.pubkey()));
+    let updates = Updates::unpack(&litesvm.get_account(&updates).unwrap().data).unwrap();
+
+    assert_eq!(updates.entries.len(), 2);
+    assert_eq!(updates.entries[1].content_hash, [2; 32]);
+}
+
+pub fn snapshot_governance_weight(litesvm: &mut LiteSVM) {
+    let campaign = create_campaign(litesvm);
+    let mut backers = vec![];
+
+    for amount in [AMOUNT_TO_RAISE / 5 * 2, AMOUNT_TO_RAISE / 5 * 3] {
+        let backer = backer(litesvm, &campaign, amount);
+
+        let ix = client::contribute(
+            &backer.pubkey(),
+            &campaign.maker.pubkey(),
+            &campaign.mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            amount,
+        );
+        build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]).unwrap();
+
+        backers.push(backer);
+    }
+
+    // with the target met, anyone can freeze each backer's weight for governance to read
+    let cranker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+
+    for backer in &backers {
+        let ix = client::snapshot(&campaign.maker.pubkey(), &backer.pubkey());
+        build_and_send_transaction(litesvm, &[&cranker], &cranker.pubkey(), &[ix]).unwrap();
+    }
+
+    let fundraise = client::fundraise_address(&campaign.maker.pubkey());
+    let contributor = client::contributor_address(&fundraise, &backers[0].pubkey());
+    let fundraise = Fundraise::unpack(&litesvm.get_account(&fundraise).unwrap().data).unwrap();
+    let contributor =
+        Contributor::unpack(&litesvm.get_account(&contributor).unwrap().data).unwrap();
+
+    // 40% of the governance power goes to the first backer
+    assert_eq!(
+        u128::from(contributor.snapshot_amount) * 100 / u128::from(fundraise.snapshot_total),
+        40
+    );
+}
+
+pub fn clamp_to_target(litesvm: &mut LiteSVM) {
+    let campaign = create_campaign(litesvm);
+    let early = backer(litesvm, &campaign, AMOUNT_TO_RAISE / 5 * 4);
+
+    let ix = client::contribute(
+        &early.pubkey(),
+        &campaign.maker.pubkey(),
+        &campaign.mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+        AMOUNT_TO_RAISE / 5 * 4,
+    );
+    build_and_send_transaction(litesvm, &[&early], &early.pubkey(), &[ix]).unwrap();
+
+    // a late backer offering the whole target only gives the fifth still missing
+    let late = backer(litesvm, &campaign, AMOUNT_TO_RAISE);
+
+    let ix = client::with_clamp(client::contribute(
+        &late.pubkey(),
+        &campaign.maker.pubkey(),
+        &campaign.mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+        AMOUNT_TO_RAISE,
+    ));
+    build_and_send_transaction(litesvm, &[&late], &late.pubkey(), &[ix]).unwrap();
+
+    let late_ata = client::associated_token_address(
+        &late.pubkey(),
+        &campaign.mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+    );
+
+    assert_eq!(
+        fetch_account::<Account>(litesvm, &late_ata).amount,
+        AMOUNT_TO_RAISE / 5 * 4
+    );
+}
+
+pub fn raise_freezable_mint(litesvm: &mut LiteSVM) {
+    let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+    set_freeze_authority(litesvm, mint_to_raise, Pubkey::new_unique());
+
+    // the maker has to acknowledge that the vault could be frozen
+    let ix = client::with_accept_freezable(client::initialize(
+        &maker.pubkey(),
+        &mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+        AMOUNT_TO_RAISE,
+        DURATION,
+    ));
+    build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+    let fundraise = client::fundraise_address(&maker.pubkey());
+    let fundraise = Fundraise::unpack(&litesvm.get_account(&fundraise).unwrap().data).unwrap();
+
+    assert_eq!(fundraise.accept_freezable, 1);
+}
+
+pub fn delegate_claim(litesvm: &mut LiteSVM) {
+    let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let cold_wallet = Pubkey::new_unique();
+    let treasury = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+    let ix = client::with_claim_authority(
+        client::initialize(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            AMOUNT_TO_RAISE,
+            DURATION,
+        ),
+        &cold_wallet,
+    );
+    build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+    // the maker can move the claim on until it is made
+    let ix = client::set_claim_authority(&maker.pubkey(), &treasury.pubkey());
+    build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+    let campaign = Campaign {
+        maker,
+        mint_to_raise,
+    };
+    let backer = backer(litesvm, &campaign, AMOUNT_TO_RAISE);
+
+    let ix = client::contribute(
+        &backer.pubkey(),
+        &campaign.maker.pubkey(),
+        &mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+        AMOUNT_TO_RAISE,
+    );
+    build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]).unwrap();
+
+    init_config(litesvm, campaign.maker.pubkey(), 0, FEE_COLLECTOR, false);
+
+    let ix = client::with_claimant(
+        client::claim(
+            &campaign.maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            &FEE_COLLECTOR,
+        ),
+        &treasury.pubkey(),
+    );
+    build_and_send_transaction(litesvm, &[&treasury], &treasury.pubkey(), &[ix]).unwrap();
+
+    let treasury_ata =
+        client::associated_token_address(&treasury.pubkey(), &mint_to_raise, &TOKEN_PROGRAM_ID);
+
+    assert_eq!(
+        fetch_account::<Account>(litesvm, &treasury_ata).amount,
+        AMOUNT_TO_RAISE
+    );
+}
+
+pub fn recover_stray_tokens(litesvm: &mut LiteSVM) {
+    let campaign = create_campaign(litesvm);
+    let maker = &campaign.maker;
+    let fundraise = client::fundraise_address(&maker.pubkey());
+
+    // a backer sends some other token to the fundraise by mistake
+    let stray_mint = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000);
+    let stray_account = init_ata(litesvm, stray_mint, fundraise, 1_000_000);
+    let maker_ata = init_ata(litesvm, stray_mint, maker.pubkey(), 0);
+
+    let ix = client::sweep_foreign(
+        &maker.pubkey(),
+        &stray_mint,
+        &TOKEN_PROGRAM_ID,
+        &maker_ata,
+        true,
+    );
+    build_and_send_transaction(litesvm, &[maker], &maker.pubkey(), &[ix]).unwrap();
+
+    assert_eq!(
+        fetch_account::<Account>(litesvm, &maker_ata).amount,
+        1_000_000
+    );
+    assert!(litesvm.get_account(&stray_account).is_none());
+}
+
+pub fn close_dusted_campaign(litesvm: &mut LiteSVM) {
+    let campaign = create_campaign(litesvm);
+    let maker = &campaign.maker;
+    let fundraise = client::fundraise_address(&maker.pubkey());
+    let vault = client::vault_address(&fundraise);
+
+    // someone sends the vault a token it never recorded, hoping to keep it open
+    init_token_account(litesvm, vault, campaign.mint_to_raise, fundraise, 1);
+    let maker_ata = init_ata(litesvm, campaign.mint_to_raise, maker.pubkey(), 0);
+
+    forward_time(litesvm, DURATION as i64 + 1);
+
+    // the dust goes to the maker and the campaign still closes
+    let ix = client::with_vault_sweep(
+        client::close_fundraise(&maker.pubkey(), &TOKEN_PROGRAM_ID),
+        &campaign.mint_to_raise,
+    );
+    build_and_send_transaction(litesvm, &[maker], &maker.pubkey(), &[ix]).unwrap();
+
+    assert_eq!(fetch_account::<Account>(litesvm, &maker_ata).amount, 1);
+    assert!(litesvm.get_account(&fundraise).is_none());
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use crate::{client::examples::EXAMPLES, tests::utils::setup};
+
+    const CLIENT_SOURCES: &[&str] = &[include_str!("estimate.rs"), include_str!("instructions.rs")];
+    const EXAMPLES_SOURCE: &str = include_str!("examples.rs");
+
+    /// Names of the `pub fn` items of `source`, ignoring its test module.
+    fn public_functions(source: &str) -> impl Iterator<Item = &str> {
+        let items = source.split("#[cfg(test)]").next().unwrap();
+
+        items.lines().filter_map(|line| {
+            let name = line.strip_prefix("pub fn ")?;
+            name.split(['(', '<']).next()
+        })
+    }
+
+    /// The `client::` functions called from the body of the example named `name`.
+    fn client_calls(name: &str) -> BTreeSet<&'static str> {
+        let start = EXAMPLES_SOURCE
+            .find(&format!("\npub fn {name}("))
+            .unwrap_or_else(|| panic!("{name} is registered but not defined"));
+        let body = &EXAMPLES_SOURCE[start..];
+        let body = &body[..body.find("\n}\n").unwrap()];
+
+        body.split("client::")
+            .skip(1)
+            .filter_map(|call| call.split('(').next())
+            .filter(|call| call.chars().all(|c| c.is_ascii_lowercase() || c == '_'))
+            .collect()
+    }
+
+    #[test]
+    fn every_client_function_has_an_example() {
+        let covered: BTreeSet<&str> = EXAMPLES
+            .iter()
+            .flat_map(|example| client_calls(example.name))
+            .collect();
+
+        let functions: Vec<&str> = CLIENT_SOURCES
+            .iter()
+            .flat_map(|source| public_functions(source))
+            .collect();
+
+        assert!(functions.contains(&"chunk"));
+
+        for function in functions {
+            assert!(
+                covered.contains(function),
+                "client::{function} is not used by any registered example"
+            );
+        }
+    }
+
+    #[test]
+    fn run_every_example() {
+        for example in EXAMPLES {
+            let (mut litesvm, _default_payer) = setup();
+
+            (example.run)(&mut litesvm);
+        }
+    }
+}
@@ -0,0 +1,882 @@
+//! Instruction builders for off-chain clients. Each mirrors the account order its handler
+//! expects and encodes its data through [`crate::cpi`].
+
+use solana_instruction::{AccountMeta, Instruction};
+use solana_pubkey::Pubkey;
+
+use crate::{
+    ClaimDestination, Config, ContributeInstructionData, Contributor, Encoding, Fundraise,
+    FundraiserInstruction, InitializeInstructionData, MAX_TIERS, Prefix, Referral, STATS_ADDRESS,
+    Updates, VAULT_SEED, Versioned, cpi,
+};
+
+const SYSTEM_PROGRAM_ID: Pubkey = Pubkey::new_from_array(pinocchio_system::ID);
+const ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey =
+    Pubkey::new_from_array(pinocchio_associated_token_account::ID);
+const TOKEN_2022_PROGRAM_ID: Pubkey = Pubkey::new_from_array(pinocchio_token_2022::ID);
+
+#[inline(always)]
+pub fn program_id() -> Pubkey {
+    Pubkey::new_from_array(crate::ID)
+}
+
+pub fn fundraise_address(maker: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[Fundraise::PREFIX, maker.as_ref()], &program_id()).0
+}
+
+pub fn contributor_address(fundraise: &Pubkey, authority: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[Contributor::PREFIX, fundraise.as_ref(), authority.as_ref()],
+        &program_id(),
+    )
+    .0
+}
+
+pub fn referral_address(fundraise: &Pubkey, referrer: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[Referral::PREFIX, fundraise.as_ref(), referrer.as_ref()],
+        &program_id(),
+    )
+    .0
+}
+
+pub fn updates_address(fundraise: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[Updates::PREFIX, fundraise.as_ref()], &program_id()).0
+}
+
+pub fn vault_address(fundraise: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[VAULT_SEED, fundraise.as_ref()], &program_id()).0
+}
+
+pub fn config_address() -> Pubkey {
+    Pubkey::find_program_address(&[Config::PREFIX], &program_id()).0
+}
+
+pub fn stats_address() -> Pubkey {
+    Pubkey::new_from_array(STATS_ADDRESS)
+}
+
+pub fn associated_token_address(owner: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[owner.as_ref(), token_program.as_ref(), mint.as_ref()],
+        &ASSOCIATED_TOKEN_PROGRAM_ID,
+    )
+    .0
+}
+
+/// Creates a fundraise and its vault, without a completion window, caps, allowlist or trigger.
+/// The maker signs and funds both, and may be a PDA signing through CPI, which can leave the
+/// funding to [`with_rent_payer`] though it still stakes any bond. The config is a required
+/// eighth account, after the associated token program, which clients built against the
+/// seven-account layout must now pass. The data is sent in the version 1 layout.
+pub fn initialize(
+    maker: &Pubkey,
+    mint_to_raise: &Pubkey,
+    token_program: &Pubkey,
+    amount_to_raise: u64,
+    duration: u64,
+) -> Instruction {
+    let fundraise = fundraise_address(maker);
+
+    Instruction {
+        program_id: program_id(),
+        accounts: vec![
+            AccountMeta::new(*maker, true),
+            AccountMeta::new_readonly(*mint_to_raise, false),
+            AccountMeta::new(fundraise, false),
+            AccountMeta::new(vault_address(&fundraise), false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(*token_program, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(config_address(), false),
+        ],
+        data: cpi::initialize_v1_ix_data(&InitializeInstructionData::new(
+            amount_to_raise,
+            duration,
+        )),
+    }
+}
+
+/// Contributes `amount` from `authority`'s associated token account, sending the data in the
+/// version 1 layout.
+pub fn contribute(
+    authority: &Pubkey,
+    maker: &Pubkey,
+    mint_to_raise: &Pubkey,
+    token_program: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let fundraise = fundraise_address(maker);
+
+    Instruction {
+        program_id: program_id(),
+        accounts: vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new_readonly(*mint_to_raise, false),
+            AccountMeta::new(fundraise, false),
+            AccountMeta::new(contributor_address(&fundraise, authority), false),
+            AccountMeta::new(
+                associated_token_address(authority, mint_to_raise, token_program),
+                false,
+            ),
+            AccountMeta::new(vault_address(&fundraise), false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(*token_program, false),
+            AccountMeta::new_readonly(config_address(), false),
+        ],
+        data: cpi::contribute_v1_ix_data(&ContributeInstructionData::new(amount)),
+    }
+}
+
+/// Returns `authority`'s contributions to its associated token account, recreating it if it was
+/// closed since contributing.
+pub fn refund(
+    authority: &Pubkey,
+    maker: &Pubkey,
+    mint_to_raise: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    let fundraise = fundraise_address(maker);
+
+    Instruction {
+        program_id: program_id(),
+        accounts: vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new(*maker, false),
+            AccountMeta::new_readonly(*mint_to_raise, false),
+            AccountMeta::new(fundraise, false),
+            AccountMeta::new(contributor_address(&fundraise, authority), false),
+            AccountMeta::new(
+                associated_token_address(authority, mint_to_raise, token_program),
+                false,
+            ),
+            AccountMeta::new(vault_address(&fundraise), false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(*token_program, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+        ],
+        data: cpi::refund_ix_data(0).to_vec(),
+    }
+}
+
+/// Claims the raised funds into the maker's associated token account, paying the protocol fee
+/// to `fee_collector`. A fundraise whose maker handed the claim on needs [`with_claimant`]. The
+/// claim authority signs and funds any token account the claim creates, which a PDA signing
+/// through CPI can leave to [`with_rent_payer`]. Anything sent straight to the vault is swept to
+/// the maker on top of the funds raised, free of the fee. The maker is always passed writable,
+/// after the fee collector's token account, to be paid back any rent bond.
+pub fn claim(
+    maker: &Pubkey,
+    mint_to_raise: &Pubkey,
+    token_program: &Pubkey,
+    fee_collector: &Pubkey,
+) -> Instruction {
+    let fundraise = fundraise_address(maker);
+
+    Instruction {
+        program_id: program_id(),
+        accounts: vec![
+            AccountMeta::new(*maker, true),
+            AccountMeta::new_readonly(*mint_to_raise, false),
+            AccountMeta::new(fundraise, false),
+            AccountMeta::new(vault_address(&fundraise), false),
+            AccountMeta::new(
+                associated_token_address(maker, mint_to_raise, token_program),
+                false,
+            ),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(*token_program, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(config_address(), false),
+            AccountMeta::new_readonly(*fee_collector, false),
+            AccountMeta::new(
+                associated_token_address(fee_collector, mint_to_raise, token_program),
+                false,
+            ),
+            AccountMeta::new(*maker, false),
+        ],
+        data: cpi::claim_ix_data(ClaimDestination::MakerAta).to_vec(),
+    }
+}
+
+/// Refunds `positions` of a fundraise that ended below its target to their authorities'
+/// associated token accounts, each given as `(authority, rent_payer)`, where the rent payer is
+/// the authority unless a sponsor paid the contributor account's rent. Anyone may send it, with
+/// at most [`crate::CRANK_REFUND_MAX_ENTRIES`] positions at a time.
+pub fn crank_refund(
+    maker: &Pubkey,
+    mint_to_raise: &Pubkey,
+    token_program: &Pubkey,
+    positions: &[(Pubkey, Pubkey)],
+) -> Instruction {
+    let fundraise = fundraise_address(maker);
+    let entries = positions.iter().flat_map(|(authority, rent_payer)| {
+        [
+            AccountMeta::new(contributor_address(&fundraise, authority), false),
+            AccountMeta::new(
+                associated_token_address(authority, mint_to_raise, token_program),
+                false,
+            ),
+            AccountMeta::new(*rent_payer, false),
+        ]
+    });
+
+    Instruction {
+        program_id: program_id(),
+        accounts: [
+            AccountMeta::new(*maker, false),
+            AccountMeta::new_readonly(*mint_to_raise, false),
+            AccountMeta::new(fundraise, false),
+            AccountMeta::new(vault_address(&fundraise), false),
+            AccountMeta::new_readonly(*token_program, false),
+        ]
+        .into_iter()
+        .chain(entries)
+        .collect(),
+        data: cpi::crank_refund_ix_data().to_vec(),
+    }
+}
+
+/// Closes the fundraise of a failed campaign once every contribution has been refunded, along
+/// with its vault if still open, returning their rent to the maker. Anyone may send it. A
+/// fundraise that joined its mint's registry also needs the registry appended.
+pub fn close_fundraise(maker: &Pubkey, token_program: &Pubkey) -> Instruction {
+    let fundraise = fundraise_address(maker);
+
+    Instruction {
+        program_id: program_id(),
+        accounts: vec![
+            AccountMeta::new(*maker, false),
+            AccountMeta::new(fundraise, false),
+            AccountMeta::new(vault_address(&fundraise), false),
+            AccountMeta::new_readonly(*token_program, false),
+        ],
+        data: cpi::close_fundraise_ix_data().to_vec(),
+    }
+}
+
+/// Sweeps whatever was sent straight to the vault to the maker's associated token account of
+/// `mint_to_raise` when `ix`, a CloseFundraise, closes it. Apply this after appending any
+/// registry and before [`with_transfer_hook`].
+pub fn with_vault_sweep(mut ix: Instruction, mint_to_raise: &Pubkey) -> Instruction {
+    let maker = ix.accounts[0].pubkey;
+    let token_program = ix.accounts[3].pubkey;
+
+    ix.accounts
+        .push(AccountMeta::new_readonly(*mint_to_raise, false));
+    ix.accounts.push(AccountMeta::new(
+        associated_token_address(&maker, mint_to_raise, &token_program),
+        false,
+    ));
+    ix
+}
+
+/// Calls off the running fundraise of `maker`, refunding its bond less any slash paid to
+/// `fee_collector`.
+pub fn cancel(maker: &Pubkey, fee_collector: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: program_id(),
+        accounts: vec![
+            AccountMeta::new(*maker, true),
+            AccountMeta::new(fundraise_address(maker), false),
+            AccountMeta::new_readonly(config_address(), false),
+            AccountMeta::new(*fee_collector, false),
+        ],
+        data: cpi::cancel_ix_data().to_vec(),
+    }
+}
+
+/// Moves `amount` of `reward_mint` from the maker's associated token account into the reward
+/// vault of their fundraise, creating the vault on the first deposit.
+pub fn deposit_rewards(
+    maker: &Pubkey,
+    reward_mint: &Pubkey,
+    token_program: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let fundraise = fundraise_address(maker);
+
+    Instruction {
+        program_id: program_id(),
+        accounts: vec![
+            AccountMeta::new(*maker, true),
+            AccountMeta::new_readonly(fundraise, false),
+            AccountMeta::new_readonly(*reward_mint, false),
+            AccountMeta::new(
+                associated_token_address(maker, reward_mint, token_program),
+                false,
+            ),
+            AccountMeta::new(
+                associated_token_address(&fundraise, reward_mint, token_program),
+                false,
+            ),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(*token_program, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+        ],
+        data: cpi::deposit_rewards_ix_data(amount).to_vec(),
+    }
+}
+
+/// Pays `authority` their share of the rewards of the claimed fundraise of `maker`, into their
+/// associated token account of `reward_mint`.
+pub fn claim_reward(
+    authority: &Pubkey,
+    maker: &Pubkey,
+    reward_mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    let fundraise = fundraise_address(maker);
+
+    Instruction {
+        program_id: program_id(),
+        accounts: vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new(fundraise, false),
+            AccountMeta::new(contributor_address(&fundraise, authority), false),
+            AccountMeta::new_readonly(*reward_mint, false),
+            AccountMeta::new(
+                associated_token_address(&fundraise, reward_mint, token_program),
+                false,
+            ),
+            AccountMeta::new(
+                associated_token_address(authority, reward_mint, token_program),
+                false,
+            ),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(*token_program, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+        ],
+        data: cpi::claim_reward_ix_data().to_vec(),
+    }
+}
+
+/// Returns what is left in the reward vault of the fundraise of `maker` to their associated
+/// token account of `reward_mint`.
+pub fn sweep_rewards(maker: &Pubkey, reward_mint: &Pubkey, token_program: &Pubkey) -> Instruction {
+    let fundraise = fundraise_address(maker);
+
+    Instruction {
+        program_id: program_id(),
+        accounts: vec![
+            AccountMeta::new(*maker, true),
+            AccountMeta::new_readonly(fundraise, false),
+            AccountMeta::new_readonly(vault_address(&fundraise), false),
+            AccountMeta::new_readonly(*reward_mint, false),
+            AccountMeta::new(
+                associated_token_address(&fundraise, reward_mint, token_program),
+                false,
+            ),
+            AccountMeta::new(
+                associated_token_address(maker, reward_mint, token_program),
+                false,
+            ),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(*token_program, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+        ],
+        data: cpi::sweep_rewards_ix_data().to_vec(),
+    }
+}
+
+/// Sends everything the fundraise of `maker` holds in its associated token account of `mint`,
+/// a mint other than the one it raises, to `destination`, closing the account after if `close`
+/// is set. A token account of the fundraise's elsewhere can be swept by replacing account 2.
+pub fn sweep_foreign(
+    maker: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+    destination: &Pubkey,
+    close: bool,
+) -> Instruction {
+    let fundraise = fundraise_address(maker);
+
+    Instruction {
+        program_id: program_id(),
+        accounts: vec![
+            AccountMeta::new(*maker, true),
+            AccountMeta::new_readonly(fundraise, false),
+            AccountMeta::new(
+                associated_token_address(&fundraise, mint, token_program),
+                false,
+            ),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(*destination, false),
+            AccountMeta::new_readonly(*token_program, false),
+        ],
+        data: cpi::sweep_foreign_ix_data(close).to_vec(),
+    }
+}
+
+/// Adds `amount` of `mint_to_raise` from `sponsor`'s associated token account to the matching
+/// pool of the fundraise of `maker`.
+pub fn deposit_match(
+    sponsor: &Pubkey,
+    maker: &Pubkey,
+    mint_to_raise: &Pubkey,
+    token_program: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let fundraise = fundraise_address(maker);
+
+    Instruction {
+        program_id: program_id(),
+        accounts: vec![
+            AccountMeta::new(*sponsor, true),
+            AccountMeta::new_readonly(*mint_to_raise, false),
+            AccountMeta::new(fundraise, false),
+            AccountMeta::new(
+                associated_token_address(sponsor, mint_to_raise, token_program),
+                false,
+            ),
+            AccountMeta::new(
+                associated_token_address(&fundraise, mint_to_raise, token_program),
+                false,
+            ),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(*token_program, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+        ],
+        data: cpi::deposit_match_ix_data(amount).to_vec(),
+    }
+}
+
+/// Returns the matching pool of the failed fundraise of `maker` to `sponsor`'s associated token
+/// account of `mint_to_raise`.
+pub fn reclaim_match(
+    sponsor: &Pubkey,
+    maker: &Pubkey,
+    mint_to_raise: &Pubkey,
+    token_program: &Pubkey,
+) -> Instruction {
+    let fundraise = fundraise_address(maker);
+
+    Instruction {
+        program_id: program_id(),
+        accounts: vec![
+            AccountMeta::new(*sponsor, true),
+            AccountMeta::new_readonly(*mint_to_raise, false),
+            AccountMeta::new(fundraise, false),
+            AccountMeta::new_readonly(vault_address(&fundraise), false),
+            AccountMeta::new(
+                associated_token_address(&fundraise, mint_to_raise, token_program),
+                false,
+            ),
+            AccountMeta::new(
+                associated_token_address(sponsor, mint_to_raise, token_program),
+                false,
+            ),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(*token_program, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+        ],
+        data: cpi::reclaim_match_ix_data().to_vec(),
+    }
+}
+
+/// Appends `content_hash`, the hash of an update's off-chain content, to the log of the
+/// fundraise of `maker`, who pays for the log to grow.
+pub fn post_update(maker: &Pubkey, content_hash: [u8; 32]) -> Instruction {
+    let fundraise = fundraise_address(maker);
+
+    Instruction {
+        program_id: program_id(),
+        accounts: vec![
+            AccountMeta::new(*maker, true),
+            AccountMeta::new_readonly(fundraise, false),
+            AccountMeta::new(updates_address(&fundraise), false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: cpi::post_update_ix_data(content_hash).to_vec(),
+    }
+}
+
+/// Snapshots `authority`'s contribution to the successful fundraise of `maker`. Anyone may send
+/// it.
+pub fn snapshot(maker: &Pubkey, authority: &Pubkey) -> Instruction {
+    let fundraise = fundraise_address(maker);
+
+    Instruction {
+        program_id: program_id(),
+        accounts: vec![
+            AccountMeta::new(fundraise, false),
+            AccountMeta::new_readonly(vault_address(&fundraise), false),
+            AccountMeta::new(contributor_address(&fundraise, authority), false),
+        ],
+        data: cpi::snapshot_ix_data().to_vec(),
+    }
+}
+
+/// Renames the fundraise of `maker` and points it at the off-chain metadata at `uri`.
+pub fn update_metadata(maker: &Pubkey, name: &str, uri: &str) -> Instruction {
+    let (data, len) = cpi::update_metadata_ix_data(name.as_bytes(), uri.as_bytes());
+
+    Instruction {
+        program_id: program_id(),
+        accounts: vec![
+            AccountMeta::new_readonly(*maker, true),
+            AccountMeta::new(fundraise_address(maker), false),
+        ],
+        data: data[..len].to_vec(),
+    }
+}
+
+/// Pauses or resumes contributions to the fundraise of `maker`.
+pub fn set_paused(maker: &Pubkey, paused: bool) -> Instruction {
+    Instruction {
+        program_id: program_id(),
+        accounts: vec![
+            AccountMeta::new_readonly(*maker, true),
+            AccountMeta::new(fundraise_address(maker), false),
+        ],
+        data: cpi::set_paused_ix_data(paused).to_vec(),
+    }
+}
+
+/// Hands the claim of the fundraise of `maker` to `claim_authority`, or back to the maker if
+/// it is `maker` itself.
+pub fn set_claim_authority(maker: &Pubkey, claim_authority: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: program_id(),
+        accounts: vec![
+            AccountMeta::new_readonly(*maker, true),
+            AccountMeta::new(fundraise_address(maker), false),
+        ],
+        data: cpi::set_claim_authority_ix_data(claim_authority.to_bytes()).to_vec(),
+    }
+}
+
+/// Defines the reward tiers of the fundraise `ix`, an Initialize, creates: `thresholds` are
+/// the ascending contribution totals at which each tier starts, with unused tiers 0.
+pub fn with_tier_thresholds(ix: Instruction, thresholds: [u64; MAX_TIERS]) -> Instruction {
+    edit_initialize(ix, |data| data.tier_thresholds = thresholds)
+}
+
+/// Shares `amount` of `reward_mint` between the contributors to the fundraise `ix`, an
+/// Initialize, creates, in proportion to what each gives.
+pub fn with_rewards(ix: Instruction, reward_mint: &Pubkey, amount: u64) -> Instruction {
+    edit_initialize(ix, |data| {
+        data.reward_mint = reward_mint.to_bytes();
+        data.reward_amount = amount;
+    })
+}
+
+/// Has the fundraise `ix`, an Initialize, creates issue contributors non-transferable receipts
+/// of `receipt_mint`, a fresh keypair that signs alongside the maker. Apply this before
+/// [`with_stats`].
+pub fn with_receipt_mint(ix: Instruction, receipt_mint: &Pubkey) -> Instruction {
+    let mut ix = edit_initialize(ix, |data| data.receipt = true);
+    ix.accounts.push(AccountMeta::new(*receipt_mint, true));
+    ix.accounts
+        .push(AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false));
+    ix
+}
+
+/// Admits only holders of at least one token of `gate_mint` to the fundraise `ix`, an
+/// Initialize, creates.
+pub fn with_gate_mint(ix: Instruction, gate_mint: &Pubkey) -> Instruction {
+    edit_initialize(ix, |data| data.gate_mint = gate_mint.to_bytes())
+}
+
+/// Lets the fundraise `ix`, an Initialize, creates raise a mint with a freeze authority, which
+/// it records for UIs to warn backers of.
+pub fn with_accept_freezable(ix: Instruction) -> Instruction {
+    edit_initialize(ix, |data| data.accept_freezable = true)
+}
+
+/// Hands the claim of the fundraise `ix`, an Initialize, creates to `claim_authority`, which
+/// then signs it in the maker's place and receives the payout.
+pub fn with_claim_authority(ix: Instruction, claim_authority: &Pubkey) -> Instruction {
+    edit_initialize(ix, |data| data.claim_authority = claim_authority.to_bytes())
+}
+
+/// Names the fundraise `ix`, an Initialize, creates and points it at the off-chain metadata at
+/// `uri`.
+pub fn with_metadata(mut ix: Instruction, name: &str, uri: &str) -> Instruction {
+    let data = ix.data.clone();
+    let mut fields = initialize_fields(&data);
+    fields.name = name.as_bytes();
+    fields.uri = uri.as_bytes();
+
+    ix.data = cpi::initialize_v1_ix_data(&fields);
+    ix
+}
+
+/// Credits `referrer` with the contribution `ix`, a Contribute to the fundraise of `maker`,
+/// passing its referral account. Apply this after [`with_multisig_signers`] and before any
+/// other of these helpers.
+pub fn with_referrer(mut ix: Instruction, maker: &Pubkey, referrer: &Pubkey) -> Instruction {
+    if is_v1(&ix) {
+        ix = edit_contribute(ix, |data| data.referrer = Some(referrer.to_bytes()));
+    } else {
+        // the referrer follows the optional flags, and leads any allowlist proof
+        if ix.data.len() < 12 {
+            ix.data.resize(12, 0);
+        }
+        ix.data
+            .splice(12..12, [1].into_iter().chain(referrer.to_bytes()));
+    }
+    ix.accounts.push(AccountMeta::new(
+        referral_address(&fundraise_address(maker), referrer),
+        false,
+    ));
+    ix
+}
+
+/// Lets the contribution `ix`, a Contribute, ContributeFor or ContributeDelegated, take only as
+/// much of its amount as the contributor's cap and the target still allow, instead of failing.
+pub fn with_clamp(mut ix: Instruction) -> Instruction {
+    if is_v1(&ix) {
+        return edit_contribute(ix, |data| data.clamp = true);
+    }
+
+    ix.data[11] = 1;
+    ix
+}
+
+/// Passes the protocol stats account to `ix`, an Initialize, Contribute, Refund or Claim, so
+/// that it also updates the protocol-wide totals.
+pub fn with_stats(mut ix: Instruction) -> Instruction {
+    ix.accounts.push(AccountMeta::new(stats_address(), false));
+    ix
+}
+
+/// Passes the receipt accounts to `ix`, a Contribute, ContributeFor, ContributeDelegated or
+/// Refund to a fundraise issuing receipts of `receipt_mint`: `holder`, who owns the position,
+/// has receipts minted to or burned from their associated token account, which must already
+/// exist. Apply this after [`with_transfer_hook`] and before [`with_stats`].
+pub fn with_receipt(mut ix: Instruction, receipt_mint: &Pubkey, holder: &Pubkey) -> Instruction {
+    ix.accounts.push(AccountMeta::new(*receipt_mint, false));
+    ix.accounts.push(AccountMeta::new(
+        associated_token_address(holder, receipt_mint, &TOKEN_2022_PROGRAM_ID),
+        false,
+    ));
+    ix.accounts
+        .push(AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false));
+    ix
+}
+
+/// Passes `gate_token_account`, a token account of the gate mint held by whoever owns the
+/// position, to `ix`, a Contribute, ContributeFor or ContributeDelegated to a gated fundraise.
+/// Apply this after [`with_receipt`] and before [`with_stats`].
+pub fn with_gate(mut ix: Instruction, gate_token_account: &Pubkey) -> Instruction {
+    ix.accounts
+        .push(AccountMeta::new_readonly(*gate_token_account, false));
+    ix
+}
+
+/// Has `rent_payer`, which signs alongside the authority, fund the contributor account `ix`, a
+/// Contribute, ContributeFor or ContributeDelegated, creates, so a relayer can cover the rent
+/// of a wallet that only signs for its tokens. Refunding the position returns the rent to it
+/// through [`with_rent_destination`]. Given an Initialize, it funds the fundraise and what is
+/// created alongside, and given a Claim, the token accounts the claim creates, so that a PDA
+/// maker or claim authority need hold no lamports. Apply this after [`with_gate`],
+/// [`with_receipt_mint`] or [`with_transfer_hook`], and before [`with_stats`].
+pub fn with_rent_payer(mut ix: Instruction, rent_payer: &Pubkey) -> Instruction {
+    ix.accounts.push(AccountMeta::new(*rent_payer, true));
+    ix
+}
+
+/// Has `claim_authority`, to whom the maker handed the claim, sign `ix`, a Claim, and receive
+/// its payout in their associated token account. The rent bond still goes back to the maker.
+/// Apply this before any other of these helpers.
+pub fn with_claimant(mut ix: Instruction, claim_authority: &Pubkey) -> Instruction {
+    let mint_to_raise = ix.accounts[1].pubkey;
+    let token_program = ix.accounts[6].pubkey;
+
+    ix.accounts[0].pubkey = *claim_authority;
+    ix.accounts[4].pubkey =
+        associated_token_address(claim_authority, &mint_to_raise, &token_program);
+    ix
+}
+
+/// Passes the match accounts to `ix`, a Claim of a fundraise matched by `sponsor`, which is
+/// paid the unmatched rest of the pool. The fundraise, mint and token program are read from the
+/// Claim itself. Apply this before [`with_transfer_hook`] and [`with_stats`].
+pub fn with_match(mut ix: Instruction, sponsor: &Pubkey) -> Instruction {
+    let fundraise = ix.accounts[2].pubkey;
+    let mint_to_raise = ix.accounts[1].pubkey;
+    let token_program = ix.accounts[6].pubkey;
+
+    ix.accounts.push(AccountMeta::new(
+        associated_token_address(&fundraise, &mint_to_raise, &token_program),
+        false,
+    ));
+    ix.accounts.push(AccountMeta::new_readonly(*sponsor, false));
+    ix.accounts.push(AccountMeta::new(
+        associated_token_address(sponsor, &mint_to_raise, &token_program),
+        false,
+    ));
+    ix
+}
+
+/// Signs `ix`, a Contribute, ContributeFor or Refund whose authority is an SPL token multisig,
+/// with `signers` of that multisig. The first also pays for anything a Refund creates, and a
+/// Contribute still needs a rent payer. Apply this before any other of these helpers.
+pub fn with_multisig_signers(mut ix: Instruction, signers: &[Pubkey]) -> Instruction {
+    ix.accounts[0].is_signer = false;
+    ix.accounts
+        .extend(signers.iter().enumerate().map(|(i, signer)| AccountMeta {
+            pubkey: *signer,
+            is_signer: true,
+            is_writable: i == 0,
+        }));
+    ix
+}
+
+/// Returns the contributor account's rent to `rent_destination` when `ix`, a Refund, closes it.
+/// Needed when a sponsor paid the rent instead of the authority. Apply this before
+/// [`with_transfer_hook`] and [`with_stats`].
+pub fn with_rent_destination(mut ix: Instruction, rent_destination: &Pubkey) -> Instruction {
+    ix.accounts.push(AccountMeta::new(*rent_destination, false));
+    ix
+}
+
+/// Passes the accounts of `mint_to_raise`'s transfer hook to `ix`, a Contribute, ContributeFor,
+/// Refund, ForceRefund or Claim: the hook program, its validation account and the
+/// `extra_accounts` it lists. Apply this before [`with_stats`].
+pub fn with_transfer_hook(
+    mut ix: Instruction,
+    mint_to_raise: &Pubkey,
+    hook_program: &Pubkey,
+    extra_accounts: &[AccountMeta],
+) -> Instruction {
+    let (validation, _) = Pubkey::find_program_address(
+        &[b"extra-account-metas", mint_to_raise.as_ref()],
+        hook_program,
+    );
+
+    ix.accounts
+        .push(AccountMeta::new_readonly(*hook_program, false));
+    ix.accounts
+        .push(AccountMeta::new_readonly(validation, false));
+    ix.accounts.extend_from_slice(extra_accounts);
+    ix
+}
+
+/// Whether `ix` carries its data in a version 1 layout, as [`initialize`] and [`contribute`]
+/// build it.
+fn is_v1(ix: &Instruction) -> bool {
+    matches!(
+        FundraiserInstruction::parse(&ix.data),
+        Some((_, Encoding::Versioned, [Versioned::V1, ..]))
+    )
+}
+
+/// The fields of `data`, a version 1 Initialize, as they were set, unchecked.
+fn initialize_fields(data: &[u8]) -> InitializeInstructionData<'_> {
+    match FundraiserInstruction::parse(data) {
+        Some((
+            FundraiserInstruction::Initialize,
+            Encoding::Versioned,
+            [Versioned::V1, fields @ ..],
+        )) => InitializeInstructionData::from_v1(fields).expect("a version 1 Initialize"),
+        _ => panic!("not a version 1 Initialize"),
+    }
+}
+
+/// Applies `edit` to the fields of `ix`, a version 1 Initialize, and encodes them again.
+fn edit_initialize(
+    mut ix: Instruction,
+    edit: impl FnOnce(&mut InitializeInstructionData<'_>),
+) -> Instruction {
+    let data = ix.data.clone();
+    let mut fields = initialize_fields(&data);
+    edit(&mut fields);
+
+    ix.data = cpi::initialize_v1_ix_data(&fields);
+    ix
+}
+
+/// Applies `edit` to the fields of `ix`, a version 1 Contribute, and encodes them again.
+fn edit_contribute(
+    mut ix: Instruction,
+    edit: impl FnOnce(&mut ContributeInstructionData<'_>),
+) -> Instruction {
+    let data = ix.data.clone();
+    let mut fields = match FundraiserInstruction::parse(&data) {
+        Some((FundraiserInstruction::Contribute, Encoding::Versioned, payload)) => {
+            ContributeInstructionData::try_from(Versioned(payload)).expect("a version 1 Contribute")
+        }
+        _ => panic!("not a version 1 Contribute"),
+    };
+    edit(&mut fields);
+
+    ix.data = cpi::contribute_v1_ix_data(&fields);
+    ix
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_instruction::Instruction;
+    use solana_pubkey::Pubkey;
+
+    use crate::{
+        ContributeInstructionData, Encoding, FundraiserInstruction, InitializeInstructionData,
+        Versioned, client, cpi,
+    };
+
+    #[test]
+    fn builders_emit_version_1() {
+        let maker = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let token_program = Pubkey::new_unique();
+
+        let ix = client::initialize(&maker, &mint, &token_program, 5_000_000, 86_400);
+        let ix = client::with_gate_mint(ix, &Pubkey::new_from_array([7; 32]));
+        let ix = client::with_tier_thresholds(ix, [1, 2, 0, 0]);
+        let ix = client::with_metadata(ix, "Seed round", "https://example.com");
+        let ix = client::with_claim_authority(ix, &Pubkey::new_from_array([9; 32]));
+
+        let Some((FundraiserInstruction::Initialize, Encoding::Versioned, payload)) =
+            FundraiserInstruction::parse(&ix.data)
+        else {
+            panic!("not a versioned Initialize");
+        };
+        let data = InitializeInstructionData::try_from(Versioned(payload)).unwrap();
+
+        assert_eq!(payload[0], Versioned::V1);
+        assert_eq!(data.amount_to_raise, 5_000_000);
+        assert_eq!(data.gate_mint, [7; 32]);
+        assert_eq!(data.tier_thresholds, [1, 2, 0, 0]);
+        assert_eq!(data.claim_authority, [9; 32]);
+        assert_eq!(
+            (data.name, data.uri),
+            (&b"Seed round"[..], &b"https://example.com"[..])
+        );
+
+        let referrer = Pubkey::new_unique();
+        let ix = client::contribute(&maker, &maker, &mint, &token_program, 500_000);
+        let ix = client::with_referrer(ix, &maker, &referrer);
+        let ix = client::with_clamp(ix);
+
+        let Some((FundraiserInstruction::Contribute, Encoding::Versioned, payload)) =
+            FundraiserInstruction::parse(&ix.data)
+        else {
+            panic!("not a versioned Contribute");
+        };
+        let data = ContributeInstructionData::try_from(Versioned(payload)).unwrap();
+
+        assert_eq!(data.amount, 500_000);
+        assert_eq!(data.referrer, Some(referrer.to_bytes()));
+        assert!(data.clamp && !data.require_existing);
+    }
+
+    #[test]
+    fn helpers_keep_version_0_for_the_other_contributions() {
+        let ix = Instruction {
+            program_id: client::program_id(),
+            accounts: vec![],
+            data: cpi::contribute_for_ix_data(500_000).to_vec(),
+        };
+        let ix = client::with_clamp(ix);
+        let (instruction, encoding, payload) = FundraiserInstruction::parse(&ix.data).unwrap();
+        let data = ContributeInstructionData::try_from(payload).unwrap();
+
+        assert_eq!(
+            (instruction, encoding),
+            (FundraiserInstruction::ContributeFor, Encoding::Code)
+        );
+        assert!(data.clamp);
+    }
+}
@@ -0,0 +1,8 @@
+pub mod estimate;
+pub mod instructions;
+
+#[cfg(any(test, all(feature = "client", feature = "test-utils")))]
+pub mod examples;
+
+pub use estimate::*;
+pub use instructions::*;
@@ -1,3 +1,50 @@
+use pinocchio::pubkey::Pubkey;
+
 pub const MIN_AMOUNT_TO_RAISE: u8 = 3;
 pub const MAX_CONTRIBUTION_PERCENTAGE_BPS: u16 = 1_000;
 pub const MAX_BPS: u16 = 10_000;
+pub const MIN_CONTRIBUTION: u64 = 1;
+/// Claims paying out less than this, in base units of the raised mint, are not charged the
+/// protocol fee.
+pub const FEE_EXEMPT_THRESHOLD: u64 = 1_000_000;
+/// Shortest and longest fundraise, in seconds.
+pub const MIN_DURATION: u64 = 60 * 60;
+pub const MAX_DURATION: u64 = 365 * 24 * 60 * 60;
+/// How many times the maker may push back the end of a fundraise with Extend.
+pub const MAX_EXTENSIONS: u8 = 3;
+/// How far from now, in seconds, a fundraise's scheduled start time may lie.
+pub const MAX_START_LEAD_TIME: u64 = 90 * 24 * 60 * 60;
+pub const HOOK_MAX_ACCOUNTS: usize = 4;
+/// Longest fundraise name and metadata URI, in bytes of UTF-8.
+pub const MAX_NAME_LEN: usize = 32;
+pub const MAX_URI_LEN: usize = 128;
+/// Most reward tiers a fundraise can define.
+pub const MAX_TIERS: usize = 4;
+/// Most accounts forwarded to Token-2022 for a mint's transfer hook: its program, validation
+/// account and the extra accounts it lists.
+pub const TRANSFER_HOOK_MAX_ACCOUNTS: usize = 8;
+/// Most signers of an SPL token multisig, under either token program.
+pub const MULTISIG_MAX_SIGNERS: usize = 11;
+/// Most positions a single CrankRefund refunds, keeping a full crank within the transaction
+/// size limit and well under the compute limit.
+pub const CRANK_REFUND_MAX_ENTRIES: usize = 8;
+/// Most updates the maker of a fundraise can post.
+pub const MAX_UPDATES: u16 = 64;
+pub const HOOK_AUTHORITY_SEED: &[u8] = b"hook_authority";
+pub const VAULT_SEED: &[u8] = b"vault";
+/// Address and bump of the singleton [`crate::Stats`] account, the program address of
+/// `[b"stats"]`, fixed so instructions can recognise it without deriving it.
+pub const STATS_ADDRESS: Pubkey =
+    pinocchio_pubkey::pubkey!("HsxSgiuHf2o4YmWrLZmFcYsqXxyNzYKeBLwEmtJVDBjr");
+pub const STATS_BUMP: u8 = 255;
+/// Address of the singleton [`crate::Config`] account, the program address of `[b"config"]`,
+/// fixed so instructions taking it optionally can recognise it without deriving it.
+pub const CONFIG_ADDRESS: Pubkey =
+    pinocchio_pubkey::pubkey!("RY3zMThFbk3qJbX7LAaivJCjezGdAHz1DoC2Mw77ZE2");
+/// Wrapped SOL under the legacy token program.
+pub const NATIVE_MINT: Pubkey =
+    pinocchio_pubkey::pubkey!("So11111111111111111111111111111111111111112");
+pub const MEMO_PROGRAM_ID: Pubkey =
+    pinocchio_pubkey::pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+pub const BPF_LOADER_UPGRADEABLE_ID: Pubkey =
+    pinocchio_pubkey::pubkey!("BPFLoaderUpgradeab1e11111111111111111111111");
@@ -0,0 +1,616 @@
+//! Instruction data builders for programs invoking the fundraiser through CPI.
+//!
+//! Each payload starts with the instruction's one-byte code, followed by its `*InstructionData`
+//! encoding, except that the `_v1` builders start with the versioned code and a version 1
+//! layout. Depend on this crate with `default-features = false` to leave out the
+//! fundraiser's entrypoint. Contribute and Claim set return data, laid out in
+//! [`crate::return_data`].
+
+use pinocchio::pubkey::Pubkey;
+
+use crate::{
+    ClaimAllowlistSlotInstructionData, ClaimDestination, ClaimInstructionData,
+    ContributeInstructionData, DepositMatchInstructionData, DepositRewardsInstructionData,
+    DonateInstructionData, ExtendInstructionData, FundraiserInstruction,
+    InitializeAllowlistPageInstructionData, InitializeConfigInstructionData,
+    InitializeInstructionData, PostUpdateInstructionData, RefundInstructionData,
+    SetClaimAuthorityInstructionData, SetPausedInstructionData, SweepForeignInstructionData,
+    UpdateConfigInstructionData, UpdateMetadataInstructionData,
+};
+
+/// Prepends the code of `instruction` to `payload`. `M` must be one more than `N`.
+#[inline(always)]
+fn with_code<const N: usize, const M: usize>(
+    instruction: FundraiserInstruction,
+    payload: [u8; N],
+) -> [u8; M] {
+    let mut bytes = [0u8; M];
+
+    bytes[0] = instruction.code();
+    bytes[1..].copy_from_slice(&payload);
+
+    bytes
+}
+
+/// Prepends the versioned code of `instruction`, which must have one, to `payload`, a
+/// [`crate::Versioned`] layout led by its version byte.
+#[inline(always)]
+fn with_versioned_code(instruction: FundraiserInstruction, payload: Vec<u8>) -> Vec<u8> {
+    let mut bytes = payload;
+
+    bytes.insert(0, instruction.versioned_code().unwrap());
+
+    bytes
+}
+
+/// Initialize without a completion window, contribution caps, an allowlist or a keeper trigger.
+pub fn initialize_ix_data(
+    amount_to_raise: u64,
+    duration: u64,
+) -> [u8; 1 + InitializeInstructionData::LEN] {
+    with_code(
+        FundraiserInstruction::Initialize,
+        InitializeInstructionData::new(amount_to_raise, duration).to_bytes(),
+    )
+}
+
+/// Initialize in the version 1 layout, carrying only the optional fields `data` sets.
+pub fn initialize_v1_ix_data(data: &InitializeInstructionData) -> Vec<u8> {
+    with_versioned_code(FundraiserInstruction::Initialize, data.to_v1_bytes())
+}
+
+pub fn contribute_ix_data(amount: u64) -> [u8; 1 + ContributeInstructionData::LEN] {
+    with_code(
+        FundraiserInstruction::Contribute,
+        ContributeInstructionData::new(amount).to_bytes(),
+    )
+}
+
+/// Contribute in the version 1 layout, carrying only the optional fields `data` sets.
+pub fn contribute_v1_ix_data(data: &ContributeInstructionData) -> Vec<u8> {
+    with_versioned_code(FundraiserInstruction::Contribute, data.to_v1_bytes())
+}
+
+pub fn contribute_for_ix_data(amount: u64) -> [u8; 1 + ContributeInstructionData::LEN] {
+    with_code(
+        FundraiserInstruction::ContributeFor,
+        ContributeInstructionData::new(amount).to_bytes(),
+    )
+}
+
+pub fn contribute_delegated_ix_data(amount: u64) -> [u8; 1 + ContributeInstructionData::LEN] {
+    with_code(
+        FundraiserInstruction::ContributeDelegated,
+        ContributeInstructionData::new(amount).to_bytes(),
+    )
+}
+
+pub fn refund_ix_data(amount: u64) -> [u8; 1 + RefundInstructionData::LEN] {
+    with_code(
+        FundraiserInstruction::Refund,
+        RefundInstructionData {
+            amount,
+            unwrap: false,
+        }
+        .to_bytes(),
+    )
+}
+
+pub fn claim_ix_data(destination: ClaimDestination) -> [u8; 1 + ClaimInstructionData::LEN] {
+    with_code(
+        FundraiserInstruction::Claim,
+        ClaimInstructionData {
+            destination,
+            unwrap: false,
+        }
+        .to_bytes(),
+    )
+}
+
+pub fn run_hook_ix_data() -> [u8; 1] {
+    [FundraiserInstruction::RunHook.code()]
+}
+
+pub fn complete_ix_data() -> [u8; 1] {
+    [FundraiserInstruction::Complete.code()]
+}
+
+pub fn finalize_ix_data() -> [u8; 1] {
+    [FundraiserInstruction::Finalize.code()]
+}
+
+pub fn create_contributor_ix_data() -> [u8; 1] {
+    [FundraiserInstruction::CreateContributor.code()]
+}
+
+pub fn transfer_contribution_ix_data() -> [u8; 1] {
+    [FundraiserInstruction::TransferContribution.code()]
+}
+
+pub fn force_refund_ix_data() -> [u8; 1] {
+    [FundraiserInstruction::ForceRefund.code()]
+}
+
+pub fn crank_refund_ix_data() -> [u8; 1] {
+    [FundraiserInstruction::CrankRefund.code()]
+}
+
+pub fn close_fundraise_ix_data() -> [u8; 1] {
+    [FundraiserInstruction::CloseFundraise.code()]
+}
+
+pub fn cancel_ix_data() -> [u8; 1] {
+    [FundraiserInstruction::Cancel.code()]
+}
+
+pub fn deposit_rewards_ix_data(amount: u64) -> [u8; 1 + DepositRewardsInstructionData::LEN] {
+    with_code(
+        FundraiserInstruction::DepositRewards,
+        DepositRewardsInstructionData { amount }.to_bytes(),
+    )
+}
+
+pub fn claim_reward_ix_data() -> [u8; 1] {
+    [FundraiserInstruction::ClaimReward.code()]
+}
+
+pub fn sweep_rewards_ix_data() -> [u8; 1] {
+    [FundraiserInstruction::SweepRewards.code()]
+}
+
+pub fn deposit_match_ix_data(amount: u64) -> [u8; 1 + DepositMatchInstructionData::LEN] {
+    with_code(
+        FundraiserInstruction::DepositMatch,
+        DepositMatchInstructionData { amount }.to_bytes(),
+    )
+}
+
+pub fn reclaim_match_ix_data() -> [u8; 1] {
+    [FundraiserInstruction::ReclaimMatch.code()]
+}
+
+pub fn post_update_ix_data(content_hash: [u8; 32]) -> [u8; 1 + PostUpdateInstructionData::LEN] {
+    with_code(
+        FundraiserInstruction::PostUpdate,
+        PostUpdateInstructionData { content_hash }.to_bytes(),
+    )
+}
+
+pub fn snapshot_ix_data() -> [u8; 1] {
+    [FundraiserInstruction::Snapshot.code()]
+}
+
+/// Returns the data with the length of it actually used, since the name and URI vary in length.
+pub fn update_metadata_ix_data(
+    name: &[u8],
+    uri: &[u8],
+) -> ([u8; 1 + UpdateMetadataInstructionData::MAX_LEN], usize) {
+    let (payload, len) = UpdateMetadataInstructionData { name, uri }.to_bytes();
+
+    (
+        with_code(FundraiserInstruction::UpdateMetadata, payload),
+        1 + len,
+    )
+}
+
+pub fn set_paused_ix_data(paused: bool) -> [u8; 1 + SetPausedInstructionData::LEN] {
+    with_code(
+        FundraiserInstruction::SetPaused,
+        SetPausedInstructionData {
+            paused: u8::from(paused),
+        }
+        .to_bytes(),
+    )
+}
+
+pub fn set_claim_authority_ix_data(
+    claim_authority: [u8; 32],
+) -> [u8; 1 + SetClaimAuthorityInstructionData::LEN] {
+    with_code(
+        FundraiserInstruction::SetClaimAuthority,
+        SetClaimAuthorityInstructionData { claim_authority }.to_bytes(),
+    )
+}
+
+pub fn sweep_foreign_ix_data(close: bool) -> [u8; 1 + SweepForeignInstructionData::LEN] {
+    with_code(
+        FundraiserInstruction::SweepForeign,
+        SweepForeignInstructionData { close }.to_bytes(),
+    )
+}
+
+pub fn donate_ix_data(amount: u64) -> [u8; 1 + DonateInstructionData::LEN] {
+    with_code(
+        FundraiserInstruction::Donate,
+        DonateInstructionData { amount }.to_bytes(),
+    )
+}
+
+pub fn extend_ix_data(duration: u64) -> [u8; 1 + ExtendInstructionData::LEN] {
+    with_code(
+        FundraiserInstruction::Extend,
+        ExtendInstructionData { duration }.to_bytes(),
+    )
+}
+
+pub fn initialize_config_ix_data(
+    fee_bps: u16,
+    fee_collector: Pubkey,
+) -> [u8; 1 + InitializeConfigInstructionData::LEN] {
+    with_code(
+        FundraiserInstruction::InitializeConfig,
+        InitializeConfigInstructionData {
+            fee_bps,
+            fee_collector,
+        }
+        .to_bytes(),
+    )
+}
+
+pub fn update_config_ix_data(
+    admin: Pubkey,
+    fee_bps: u16,
+    fee_collector: Pubkey,
+    paused: bool,
+    bond_lamports: u64,
+    bond_slash_bps: u16,
+) -> [u8; 1 + UpdateConfigInstructionData::LEN] {
+    with_code(
+        FundraiserInstruction::UpdateConfig,
+        UpdateConfigInstructionData {
+            admin,
+            fee_bps,
+            fee_collector,
+            paused: u8::from(paused),
+            bond_lamports,
+            bond_slash_bps,
+        }
+        .to_bytes(),
+    )
+}
+
+pub fn initialize_allowlist_page_ix_data(
+    page_index: u16,
+) -> [u8; 1 + InitializeAllowlistPageInstructionData::LEN] {
+    with_code(
+        FundraiserInstruction::InitializeAllowlistPage,
+        InitializeAllowlistPageInstructionData { page_index }.to_bytes(),
+    )
+}
+
+pub fn claim_allowlist_slot_ix_data(
+    page_index: u16,
+    bit_index: u16,
+) -> [u8; 1 + ClaimAllowlistSlotInstructionData::LEN] {
+    with_code(
+        FundraiserInstruction::ClaimAllowlistSlot,
+        ClaimAllowlistSlotInstructionData {
+            page_index,
+            bit_index,
+        }
+        .to_bytes(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        ClaimAllowlistSlotInstructionData, ClaimDestination, ClaimInstructionData,
+        ContributeInstructionData, DepositMatchInstructionData, DepositRewardsInstructionData,
+        DonateInstructionData, Encoding, ExtendInstructionData, FundraiserInstruction,
+        InitializeAllowlistPageInstructionData, InitializeConfigInstructionData,
+        InitializeInstructionData, PostUpdateInstructionData, RefundInstructionData,
+        SetClaimAuthorityInstructionData, SetPausedInstructionData, SweepForeignInstructionData,
+        UpdateConfigInstructionData, UpdateMetadataInstructionData, Versioned, cpi,
+    };
+
+    fn payload(data: &[u8], instruction: FundraiserInstruction) -> &[u8] {
+        let (parsed, encoding, payload) = FundraiserInstruction::parse(data).unwrap();
+
+        assert_eq!(parsed, instruction);
+        assert_eq!(encoding, Encoding::Code);
+
+        payload
+    }
+
+    #[test]
+    fn initialize_round_trip() {
+        let bytes = cpi::initialize_ix_data(5_000_000, 86_400);
+        let data =
+            InitializeInstructionData::try_from(payload(&bytes, FundraiserInstruction::Initialize))
+                .unwrap();
+
+        assert_eq!(data.amount_to_raise, 5_000_000);
+        assert_eq!(data.duration, 86_400);
+        assert_eq!(data.completion_window, 0);
+        assert_eq!(data.max_contribution_bps, 0);
+        assert!(!data.allowlist);
+    }
+
+    #[test]
+    fn update_metadata_round_trip() {
+        let (bytes, len) = cpi::update_metadata_ix_data(b"Seed round", b"https://example.com");
+        let data = UpdateMetadataInstructionData::try_from(payload(
+            &bytes[..len],
+            FundraiserInstruction::UpdateMetadata,
+        ))
+        .unwrap();
+
+        assert_eq!(data.name, b"Seed round");
+        assert_eq!(data.uri, b"https://example.com");
+    }
+
+    #[test]
+    fn set_paused_round_trip() {
+        let bytes = cpi::set_paused_ix_data(true);
+        let data =
+            SetPausedInstructionData::try_from(payload(&bytes, FundraiserInstruction::SetPaused))
+                .unwrap();
+
+        assert_eq!(data.paused, 1);
+        assert!(SetPausedInstructionData::try_from(&[2][..]).is_err());
+    }
+
+    #[test]
+    fn set_claim_authority_round_trip() {
+        let bytes = cpi::set_claim_authority_ix_data([9; 32]);
+        let data = SetClaimAuthorityInstructionData::try_from(payload(
+            &bytes,
+            FundraiserInstruction::SetClaimAuthority,
+        ))
+        .unwrap();
+
+        assert_eq!(data.claim_authority, [9; 32]);
+        assert!(SetClaimAuthorityInstructionData::try_from(&[9; 31][..]).is_err());
+    }
+
+    #[test]
+    fn sweep_foreign_round_trip() {
+        for close in [false, true] {
+            let bytes = cpi::sweep_foreign_ix_data(close);
+            let data = SweepForeignInstructionData::try_from(payload(
+                &bytes,
+                FundraiserInstruction::SweepForeign,
+            ))
+            .unwrap();
+
+            assert_eq!(data.close, close);
+        }
+
+        assert!(SweepForeignInstructionData::try_from(&[2][..]).is_err());
+    }
+
+    #[test]
+    fn contribute_round_trip() {
+        let bytes = cpi::contribute_ix_data(500_000);
+        let data =
+            ContributeInstructionData::try_from(payload(&bytes, FundraiserInstruction::Contribute))
+                .unwrap();
+
+        assert_eq!(data.amount, 500_000);
+        assert!(!data.reject_duplicates);
+
+        let bytes = cpi::contribute_for_ix_data(500_000);
+        let data = ContributeInstructionData::try_from(payload(
+            &bytes,
+            FundraiserInstruction::ContributeFor,
+        ))
+        .unwrap();
+
+        assert_eq!(data.amount, 500_000);
+        assert!(!data.require_existing);
+
+        let bytes = cpi::contribute_delegated_ix_data(500_000);
+        let data = ContributeInstructionData::try_from(payload(
+            &bytes,
+            FundraiserInstruction::ContributeDelegated,
+        ))
+        .unwrap();
+
+        assert_eq!(data.amount, 500_000);
+    }
+
+    #[test]
+    fn versioned_round_trip() {
+        let bytes = cpi::initialize_v1_ix_data(&InitializeInstructionData {
+            claim_authority: [9; 32],
+            ..InitializeInstructionData::new(5_000_000, 86_400)
+        });
+        let (instruction, encoding, payload) = FundraiserInstruction::parse(&bytes).unwrap();
+        let data = InitializeInstructionData::try_from(Versioned(payload)).unwrap();
+
+        assert_eq!(
+            (instruction, encoding),
+            (FundraiserInstruction::Initialize, Encoding::Versioned)
+        );
+        assert_eq!(data.amount_to_raise, 5_000_000);
+        assert_eq!(data.claim_authority, [9; 32]);
+        assert_eq!(data.gate_mint, [0; 32]);
+
+        let bytes = cpi::contribute_v1_ix_data(&ContributeInstructionData {
+            clamp: true,
+            ..ContributeInstructionData::new(500_000)
+        });
+        let (instruction, encoding, payload) = FundraiserInstruction::parse(&bytes).unwrap();
+        let data = ContributeInstructionData::try_from(Versioned(payload)).unwrap();
+
+        assert_eq!(
+            (instruction, encoding),
+            (FundraiserInstruction::Contribute, Encoding::Versioned)
+        );
+        assert_eq!(data.amount, 500_000);
+        assert!(data.clamp && !data.reject_duplicates);
+    }
+
+    #[test]
+    fn donate_round_trip() {
+        let bytes = cpi::donate_ix_data(250_000);
+        let data = DonateInstructionData::try_from(payload(&bytes, FundraiserInstruction::Donate))
+            .unwrap();
+
+        assert_eq!(data.amount, 250_000);
+    }
+
+    #[test]
+    fn deposit_rewards_round_trip() {
+        let bytes = cpi::deposit_rewards_ix_data(1_000_000);
+        let data = DepositRewardsInstructionData::try_from(payload(
+            &bytes,
+            FundraiserInstruction::DepositRewards,
+        ))
+        .unwrap();
+
+        assert_eq!(data.amount, 1_000_000);
+    }
+
+    #[test]
+    fn deposit_match_round_trip() {
+        let bytes = cpi::deposit_match_ix_data(2_500_000);
+        let data = DepositMatchInstructionData::try_from(payload(
+            &bytes,
+            FundraiserInstruction::DepositMatch,
+        ))
+        .unwrap();
+
+        assert_eq!(data.amount, 2_500_000);
+    }
+
+    #[test]
+    fn post_update_round_trip() {
+        let bytes = cpi::post_update_ix_data([7; 32]);
+        let data =
+            PostUpdateInstructionData::try_from(payload(&bytes, FundraiserInstruction::PostUpdate))
+                .unwrap();
+
+        assert_eq!(data.content_hash, [7; 32]);
+    }
+
+    #[test]
+    fn refund_round_trip() {
+        for amount in [0, 250_000] {
+            let bytes = cpi::refund_ix_data(amount);
+            let data =
+                RefundInstructionData::try_from(payload(&bytes, FundraiserInstruction::Refund))
+                    .unwrap();
+
+            assert_eq!(data.amount, amount);
+        }
+
+        assert_eq!(RefundInstructionData::try_from(&[][..]).unwrap().amount, 0);
+    }
+
+    #[test]
+    fn extend_round_trip() {
+        let bytes = cpi::extend_ix_data(86_400);
+        let data = ExtendInstructionData::try_from(payload(&bytes, FundraiserInstruction::Extend))
+            .unwrap();
+
+        assert_eq!(data.duration, 86_400);
+    }
+
+    #[test]
+    fn claim_round_trip() {
+        for destination in [ClaimDestination::MakerAta, ClaimDestination::Beneficiary] {
+            let bytes = cpi::claim_ix_data(destination);
+            let data =
+                ClaimInstructionData::try_from(payload(&bytes, FundraiserInstruction::Claim))
+                    .unwrap();
+
+            assert_eq!(data.destination, destination);
+        }
+    }
+
+    #[test]
+    fn config_round_trip() {
+        let bytes = cpi::initialize_config_ix_data(250, [7u8; 32]);
+        let data = InitializeConfigInstructionData::try_from(payload(
+            &bytes,
+            FundraiserInstruction::InitializeConfig,
+        ))
+        .unwrap();
+
+        assert_eq!(data.fee_bps, 250);
+        assert_eq!(data.fee_collector, [7u8; 32]);
+
+        let bytes = cpi::update_config_ix_data([1u8; 32], 100, [2u8; 32], true, 50_000_000, 2_500);
+        let data = UpdateConfigInstructionData::try_from(payload(
+            &bytes,
+            FundraiserInstruction::UpdateConfig,
+        ))
+        .unwrap();
+
+        assert_eq!(data.admin, [1u8; 32]);
+        assert_eq!(data.fee_bps, 100);
+        assert_eq!(data.fee_collector, [2u8; 32]);
+        assert_eq!(data.paused, 1);
+        assert_eq!(data.bond_lamports, 50_000_000);
+        assert_eq!(data.bond_slash_bps, 2_500);
+    }
+
+    #[test]
+    fn allowlist_round_trip() {
+        let bytes = cpi::initialize_allowlist_page_ix_data(3);
+        let data = InitializeAllowlistPageInstructionData::try_from(payload(
+            &bytes,
+            FundraiserInstruction::InitializeAllowlistPage,
+        ))
+        .unwrap();
+
+        assert_eq!(data.page_index, 3);
+
+        let bytes = cpi::claim_allowlist_slot_ix_data(3, 4_095);
+        let data = ClaimAllowlistSlotInstructionData::try_from(payload(
+            &bytes,
+            FundraiserInstruction::ClaimAllowlistSlot,
+        ))
+        .unwrap();
+
+        assert_eq!(data.page_index, 3);
+        assert_eq!(data.bit_index, 4_095);
+    }
+
+    #[test]
+    fn data_less_instructions() {
+        for (bytes, instruction) in [
+            (cpi::run_hook_ix_data(), FundraiserInstruction::RunHook),
+            (cpi::complete_ix_data(), FundraiserInstruction::Complete),
+            (cpi::finalize_ix_data(), FundraiserInstruction::Finalize),
+            (
+                cpi::create_contributor_ix_data(),
+                FundraiserInstruction::CreateContributor,
+            ),
+            (
+                cpi::transfer_contribution_ix_data(),
+                FundraiserInstruction::TransferContribution,
+            ),
+            (
+                cpi::force_refund_ix_data(),
+                FundraiserInstruction::ForceRefund,
+            ),
+            (
+                cpi::crank_refund_ix_data(),
+                FundraiserInstruction::CrankRefund,
+            ),
+            (
+                cpi::close_fundraise_ix_data(),
+                FundraiserInstruction::CloseFundraise,
+            ),
+            (cpi::cancel_ix_data(), FundraiserInstruction::Cancel),
+            (
+                cpi::claim_reward_ix_data(),
+                FundraiserInstruction::ClaimReward,
+            ),
+            (
+                cpi::sweep_rewards_ix_data(),
+                FundraiserInstruction::SweepRewards,
+            ),
+            (
+                cpi::reclaim_match_ix_data(),
+                FundraiserInstruction::ReclaimMatch,
+            ),
+            (cpi::snapshot_ix_data(), FundraiserInstruction::Snapshot),
+        ] {
+            assert!(payload(&bytes, instruction).is_empty());
+        }
+    }
+}
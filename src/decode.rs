@@ -0,0 +1,498 @@
+//! Owned, copy-out views of program accounts for off-chain readers.
+//!
+//! Unlike `AccountLoad::load`, these accept data longer than the account layout, which RPC
+//! responses and resized accounts can carry, and ignore the trailing bytes.
+
+use pinocchio::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    AccountLoad, Contributor, Fundraise, FundraiserError, HOOK_MAX_ACCOUNTS, MAX_TIERS, Referral,
+    Registry, Space, Stats, UpdateEntry, Updates,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct FundraiseView {
+    pub maker: Pubkey,
+    pub mint_to_raise: Pubkey,
+    pub amount_to_raise: u64,
+    pub current_amount: u64,
+    pub time_started: i64,
+    pub duration: u64,
+    pub bump: u8,
+    pub hook_program: Pubkey,
+    pub hook_accounts: [Pubkey; HOOK_MAX_ACCOUNTS],
+    pub hook_accounts_len: u8,
+    pub hook_writable: u8,
+    pub pending_hooks: u64,
+    pub completion_window: u64,
+    pub completion_threshold_bps: u16,
+    pub maker_completion: u64,
+    pub mint_decimals: u8,
+    pub max_contribution_bps: u16,
+    pub min_contribution: u64,
+    pub allowlist: u8,
+    pub token_program: Pubkey,
+    pub vault: Pubkey,
+    pub donated_amount: u64,
+    pub claimed: u8,
+    pub extensions_used: u8,
+    pub refund_policy: u8,
+    pub campaign_type: u8,
+    pub merkle_root: Pubkey,
+    pub registered: u8,
+    pub name: String,
+    pub uri: String,
+    pub paused: u8,
+    pub tier_thresholds: [u64; MAX_TIERS],
+    pub bond: u64,
+    pub cancelled: u8,
+    pub reward_mint: Pubkey,
+    pub reward_amount: u64,
+    pub reward_basis: u64,
+    pub reward_claimed: u64,
+    pub receipt_mint: Pubkey,
+    pub match_sponsor: Pubkey,
+    pub match_pool: u64,
+    pub gate_mint: Pubkey,
+    pub snapshot_total: u64,
+    pub accept_freezable: u8,
+    pub claim_authority: Pubkey,
+    pub end_time: i64,
+    pub reward_paid: u64,
+}
+
+impl From<&Fundraise> for FundraiseView {
+    fn from(fundraise: &Fundraise) -> Self {
+        Self {
+            maker: fundraise.maker,
+            mint_to_raise: fundraise.mint_to_raise,
+            amount_to_raise: fundraise.get_amount_to_raise(),
+            current_amount: fundraise.get_current_amount(),
+            time_started: fundraise.get_time_started(),
+            duration: fundraise.get_duration(),
+            bump: fundraise.bump,
+            hook_program: fundraise.hook_program,
+            hook_accounts: fundraise.hook_accounts,
+            hook_accounts_len: fundraise.hook_accounts_len,
+            hook_writable: fundraise.hook_writable,
+            pending_hooks: fundraise.get_pending_hooks(),
+            completion_window: fundraise.get_completion_window(),
+            completion_threshold_bps: fundraise.get_completion_threshold_bps(),
+            maker_completion: fundraise.get_maker_completion(),
+            mint_decimals: fundraise.mint_decimals,
+            max_contribution_bps: fundraise.get_max_contribution_bps(),
+            min_contribution: fundraise.get_min_contribution(),
+            allowlist: fundraise.allowlist,
+            token_program: fundraise.token_program,
+            vault: fundraise.vault,
+            donated_amount: fundraise.get_donated_amount(),
+            claimed: fundraise.claimed,
+            extensions_used: fundraise.extensions_used,
+            refund_policy: fundraise.refund_policy,
+            campaign_type: fundraise.campaign_type,
+            merkle_root: fundraise.merkle_root,
+            registered: fundraise.registered,
+            name: String::from_utf8_lossy(fundraise.name()).into_owned(),
+            uri: String::from_utf8_lossy(fundraise.uri()).into_owned(),
+            paused: fundraise.paused,
+            tier_thresholds: fundraise.get_tier_thresholds(),
+            bond: fundraise.get_bond(),
+            cancelled: fundraise.cancelled,
+            reward_mint: fundraise.reward_mint,
+            reward_amount: fundraise.get_reward_amount(),
+            reward_basis: fundraise.get_reward_basis(),
+            reward_claimed: fundraise.get_reward_claimed(),
+            receipt_mint: fundraise.receipt_mint,
+            match_sponsor: fundraise.match_sponsor,
+            match_pool: fundraise.get_match_pool(),
+            gate_mint: fundraise.gate_mint,
+            snapshot_total: fundraise.get_snapshot_total(),
+            accept_freezable: fundraise.accept_freezable,
+            claim_authority: fundraise.claim_authority,
+            end_time: fundraise.get_end_time(),
+            reward_paid: fundraise.get_reward_paid(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ContributorView {
+    pub fundraise: Pubkey,
+    pub authority: Pubkey,
+    pub amount: u64,
+    pub bump: u8,
+    pub pending_hook_amount: u64,
+    pub contribution_count: u32,
+    pub last_contribution_ts: i64,
+    pub rent_payer: Pubkey,
+    pub tier: u8,
+    pub reward_claimed: u8,
+    pub snapshot_amount: u64,
+    pub snapshotted: u8,
+    pub mint: Pubkey,
+}
+
+impl From<&Contributor> for ContributorView {
+    fn from(contributor: &Contributor) -> Self {
+        Self {
+            fundraise: contributor.fundraise,
+            authority: contributor.authority,
+            amount: contributor.get_amount(),
+            bump: contributor.bump,
+            pending_hook_amount: contributor.get_pending_hook_amount(),
+            contribution_count: contributor.get_contribution_count(),
+            last_contribution_ts: contributor.get_last_contribution_ts(),
+            rent_payer: contributor.rent_payer,
+            tier: contributor.get_tier(),
+            reward_claimed: contributor.reward_claimed,
+            snapshot_amount: contributor.get_snapshot_amount(),
+            snapshotted: contributor.snapshotted,
+            mint: contributor.mint,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct StatsView {
+    pub fundraisers_created: u64,
+    pub total_raised: u64,
+    pub total_refunded: u64,
+    pub fundraisers_claimed: u64,
+    pub total_claimed: u64,
+    pub bump: u8,
+}
+
+impl From<&Stats> for StatsView {
+    fn from(stats: &Stats) -> Self {
+        Self {
+            fundraisers_created: stats.get_fundraisers_created(),
+            total_raised: stats.get_total_raised(),
+            total_refunded: stats.get_total_refunded(),
+            fundraisers_claimed: stats.get_fundraisers_claimed(),
+            total_claimed: stats.get_total_claimed(),
+            bump: stats.bump,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct RegistryView {
+    pub mint: Pubkey,
+    pub active_fundraisers: u64,
+    pub last_fundraise: Pubkey,
+    pub bump: u8,
+}
+
+impl From<&Registry> for RegistryView {
+    fn from(registry: &Registry) -> Self {
+        Self {
+            mint: registry.mint,
+            active_fundraisers: registry.get_active_fundraisers(),
+            last_fundraise: registry.last_fundraise,
+            bump: registry.bump,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct ReferralView {
+    pub fundraise: Pubkey,
+    pub referrer: Pubkey,
+    pub referred_amount: u64,
+    pub referred_count: u32,
+    pub bump: u8,
+}
+
+impl From<&Referral> for ReferralView {
+    fn from(referral: &Referral) -> Self {
+        Self {
+            fundraise: referral.fundraise,
+            referrer: referral.referrer,
+            referred_amount: referral.get_referred_amount(),
+            referred_count: referral.get_referred_count(),
+            bump: referral.bump,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct UpdateEntryView {
+    pub content_hash: [u8; 32],
+    pub timestamp: i64,
+}
+
+impl From<&UpdateEntry> for UpdateEntryView {
+    fn from(entry: &UpdateEntry) -> Self {
+        Self {
+            content_hash: entry.content_hash,
+            timestamp: entry.get_timestamp(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct UpdatesView {
+    pub fundraise: Pubkey,
+    pub bump: u8,
+    /// The posted updates, oldest first.
+    pub entries: Vec<UpdateEntryView>,
+}
+
+/// Loads the leading `T::LEN` bytes of `data`, rejecting data that is too short.
+#[inline(always)]
+fn load_prefix<T: AccountLoad>(data: &[u8]) -> Result<&T, ProgramError> {
+    T::load(
+        data.get(..T::LEN)
+            .ok_or(FundraiserError::InvalidAccountDataLength)?,
+    )
+}
+
+impl Fundraise {
+    pub fn unpack(data: &[u8]) -> Result<FundraiseView, ProgramError> {
+        load_prefix::<Self>(data).map(FundraiseView::from)
+    }
+}
+
+impl Contributor {
+    pub fn unpack(data: &[u8]) -> Result<ContributorView, ProgramError> {
+        load_prefix::<Self>(data).map(ContributorView::from)
+    }
+}
+
+impl Registry {
+    pub fn unpack(data: &[u8]) -> Result<RegistryView, ProgramError> {
+        load_prefix::<Self>(data).map(RegistryView::from)
+    }
+}
+
+impl Referral {
+    pub fn unpack(data: &[u8]) -> Result<ReferralView, ProgramError> {
+        load_prefix::<Self>(data).map(ReferralView::from)
+    }
+}
+
+impl Updates {
+    /// Reads the header and the entries it counts, rejecting data too short to hold them all.
+    pub fn unpack(data: &[u8]) -> Result<UpdatesView, ProgramError> {
+        let updates = load_prefix::<Self>(data)?;
+        let entries = data
+            .get(Self::LEN..Self::space(usize::from(updates.get_count())))
+            .ok_or(FundraiserError::InvalidAccountDataLength)?
+            .chunks_exact(UpdateEntry::LEN)
+            .map(|entry| UpdateEntry::load(entry).map(UpdateEntryView::from))
+            .collect::<Result<_, _>>()?;
+
+        Ok(UpdatesView {
+            fundraise: updates.fundraise,
+            bump: updates.bump,
+            entries,
+        })
+    }
+}
+
+impl Stats {
+    pub fn unpack(data: &[u8]) -> Result<StatsView, ProgramError> {
+        load_prefix::<Self>(data).map(StatsView::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_signer::Signer;
+    use spl_associated_token_account::solana_program::{
+        clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL,
+    };
+
+    use crate::{
+        AccountLoad, Contributor, ContributorParams, ContributorView, Fundraise, FundraiseView,
+        FundraiserError, SetInner, Space, Updates,
+        tests::{
+            constants::{
+                ASSOCIATED_TOKEN_PROGRAM_ID, MINT_DECIMALS, PROGRAM_ID, SYSTEM_PROGRAM_ID,
+                TOKEN_PROGRAM_ID,
+            },
+            pda::{get_config_pda, get_contributor_pda, get_fundraise_pda, get_vault_pda},
+            utils::{build_and_send_transaction, init_ata, init_mint, init_wallet, setup},
+        },
+    };
+
+    fn contributor() -> Vec<u8> {
+        let mut data = vec![0u8; Contributor::LEN];
+        let contributor = Contributor::load_mut(&mut data).unwrap();
+
+        contributor.set_inner(ContributorParams::new(
+            [1u8; 32], [2u8; 32], 254, [3u8; 32], [4u8; 32],
+        ));
+        contributor.set_amount(500_000);
+        contributor.set_pending_hook_amount(250_000);
+        contributor.set_contribution_count(3);
+        contributor.set_last_contribution_ts(1_700_000_000);
+        contributor.set_tier(2);
+        contributor.reward_claimed = 1;
+
+        data
+    }
+
+    #[test]
+    fn unpack_exact_length() {
+        assert_eq!(
+            Contributor::unpack(&contributor()).unwrap(),
+            ContributorView {
+                fundraise: [1u8; 32],
+                authority: [2u8; 32],
+                amount: 500_000,
+                bump: 254,
+                pending_hook_amount: 250_000,
+                contribution_count: 3,
+                last_contribution_ts: 1_700_000_000,
+                rent_payer: [3u8; 32],
+                tier: 2,
+                reward_claimed: 1,
+                snapshot_amount: 0,
+                snapshotted: 0,
+                mint: [4u8; 32],
+            }
+        );
+    }
+
+    #[test]
+    fn unpack_ignores_trailing_bytes() {
+        let data = contributor();
+        let mut padded = data.clone();
+        padded.extend_from_slice(&[0xff; 64]);
+
+        assert_eq!(
+            Contributor::unpack(&padded).unwrap(),
+            Contributor::unpack(&data).unwrap()
+        );
+        assert!(Contributor::load(&padded).is_err());
+    }
+
+    #[test]
+    fn reject_short_data() {
+        let data = contributor();
+
+        assert_eq!(
+            Contributor::unpack(&data[..Contributor::LEN - 1]),
+            Err(FundraiserError::InvalidAccountDataLength.into())
+        );
+        assert_eq!(
+            Fundraise::unpack(&vec![0u8; Fundraise::LEN - 1]),
+            Err(FundraiserError::InvalidAccountDataLength.into())
+        );
+        assert_eq!(
+            Fundraise::unpack(&[]),
+            Err(FundraiserError::InvalidAccountDataLength.into())
+        );
+
+        // an updates header counting more entries than follow it
+        let mut updates = vec![0u8; Updates::space(1)];
+        Updates::load_mut(&mut updates[..Updates::LEN])
+            .unwrap()
+            .set_count(2);
+
+        assert_eq!(
+            Updates::unpack(&updates),
+            Err(FundraiserError::InvalidAccountDataLength.into())
+        );
+    }
+
+    #[test]
+    fn unpack_accounts_from_litesvm() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let contribute_amount: u64 = 500_000;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        let fundraise_acc = litesvm.get_account(&fundraise_pda).unwrap();
+        let contributor_acc = litesvm.get_account(&contributor_pda).unwrap();
+
+        let mut padded_fundraise = fundraise_acc.data.clone();
+        padded_fundraise.extend_from_slice(&[0u8; 32]);
+        let mut padded_contributor = contributor_acc.data.clone();
+        padded_contributor.extend_from_slice(&[0u8; 32]);
+
+        let fundraise = Fundraise::unpack(&padded_fundraise).unwrap();
+        let contributor = Contributor::unpack(&padded_contributor).unwrap();
+
+        assert_eq!(
+            fundraise,
+            FundraiseView::from(Fundraise::load(&fundraise_acc.data).unwrap())
+        );
+        assert_eq!(
+            contributor,
+            ContributorView::from(Contributor::load(&contributor_acc.data).unwrap())
+        );
+        assert_eq!(fundraise.maker, maker.pubkey().to_bytes());
+        assert_eq!(fundraise.mint_to_raise, mint_to_raise.to_bytes());
+        assert_eq!(fundraise.amount_to_raise, amount_to_raise);
+        assert_eq!(fundraise.current_amount, contribute_amount);
+        assert_eq!(fundraise.duration, duration);
+        assert_eq!(contributor.fundraise, fundraise_pda.to_bytes());
+        assert_eq!(contributor.authority, authority.pubkey().to_bytes());
+        assert_eq!(contributor.amount, contribute_amount);
+    }
+}
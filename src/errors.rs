@@ -1,24 +1,300 @@
+use core::fmt;
 use pinocchio::program_error::{ProgramError, ToStr};
 
 impl From<FundraiserError> for ProgramError {
     fn from(e: FundraiserError) -> Self {
-        ProgramError::Custom(e as u32)
+        ProgramError::Custom(e.code())
     }
 }
 
+/// Decodes the code of a `ProgramError::Custom` back into its variant, handing unknown codes
+/// back unchanged.
+impl TryFrom<u32> for FundraiserError {
+    type Error = ProgramError;
+
+    fn try_from(code: u32) -> Result<Self, Self::Error> {
+        Self::ALL
+            .into_iter()
+            .find(|error| error.code() == code)
+            .ok_or(ProgramError::Custom(code))
+    }
+}
+
+impl fmt::Display for FundraiserError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.to_str::<Self>())
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum FundraiserError {
-    NotSigner,
-    InvalidAddress,
-    TargetNotMet,
-    TargetMet,
-    ContributionTooBig,
-    ContributionTooSmall,
-    MaximumContributionsReached,
-    FundraiserNotEnded,
-    FundraiserEnded,
-    InvalidAmount,
-    InvalidMintToRaise,
-    BelowMinRaiseAmount,
+    NotSigner = 0,
+    InvalidAddress = 1,
+    TargetNotMet = 2,
+    TargetMet = 3,
+    ContributionTooBig = 4,
+    ContributionTooSmall = 5,
+    MaximumContributionsReached = 6,
+    FundraiserNotEnded = 7,
+    FundraiserEnded = 8,
+    InvalidAmount = 9,
+    InvalidMintToRaise = 10,
+    BelowMinRaiseAmount = 11,
+    InvalidHookProgram = 12,
+    InvalidHookAccounts = 13,
+    NoPendingHook = 14,
+    MakerCompletionNotEligible = 15,
+    AccountNotWritable = 16,
+    ProtocolPaused = 17,
+    Unauthorized = 18,
+    MathOverflow = 19,
+    DuplicateInstruction = 20,
+    NotAllowlisted = 21,
+    AllowlistSlotTaken = 22,
+    InvalidAllowlistSlot = 23,
+    TriggerAlreadyFired = 24,
+    TransferExceedsLiability = 25,
+    InvalidTokenProgram = 26,
+    ContributorNotInitialized = 27,
+    AlreadyClaimed = 28,
+    FundraiserNotStarted = 29,
+    DurationTooShort = 30,
+    DurationTooLong = 31,
+    TooManyExtensions = 32,
+    MissingMemoProgram = 33,
+    NotNativeMint = 34,
+    MissingTransferHookAccounts = 35,
+    TooManyTransferHookAccounts = 36,
+    AccountAlreadyInitialized = 37,
+    InvalidRentDestination = 38,
+    MissingAccounts = 39,
+    AccountNotOwnedByProgram = 40,
+    MintNotOwnedByTokenProgram = 41,
+    InvalidMintData = 42,
+    TokenAccountNotOwnedByTokenProgram = 43,
+    InvalidTokenAccountData = 44,
+    AccountNotOwnedBySystemProgram = 45,
+    InvalidProgramData = 46,
+    ProgramIdMismatch = 47,
+    InvalidAccountDataLength = 48,
+    InvalidTokenExtensions = 49,
+    NotEnoughMultisigSigners = 50,
+    MissingRentPayer = 51,
+    MultisigUnwrapUnsupported = 52,
+    InvalidDelegate = 53,
+    InsufficientDelegation = 54,
+    TooManyCrankEntries = 55,
+    InvalidCrankEntries = 56,
+    FundraiseUnclaimed = 57,
+    VaultNotEmpty = 58,
+    OutstandingContributions = 59,
+    FundraisePaused = 60,
+    SelfReferral = 61,
+    FundraiseCancelled = 62,
+    NoRewards = 63,
+    RewardsLocked = 64,
+    RewardAlreadyClaimed = 65,
+    RewardsOutstanding = 66,
+    ReceiptNotTransferable = 67,
+    ReceiptHolderMustRefund = 68,
+    NoMatch = 69,
+    MatchLocked = 70,
+    MatchOutstanding = 71,
+    GateTokenRequired = 72,
+    TooManyUpdates = 73,
+    SnapshotNotReady = 74,
+    SnapshotTaken = 75,
+    MintHasFreezeAuthority = 76,
+    SourceAccountFrozen = 77,
+    VaultFrozen = 78,
+    DestinationFrozen = 79,
+    RewardMintNotForeign = 80,
+    FundraiseAlreadyExists = 81,
+}
+
+impl FundraiserError {
+    /// Every variant, in code order. `FundraiserError::name` is an exhaustive match, so a new
+    /// variant cannot be added without also being given a name and landing in the codes fixture.
+    pub const ALL: [Self; 82] = [
+        FundraiserError::NotSigner,
+        FundraiserError::InvalidAddress,
+        FundraiserError::TargetNotMet,
+        FundraiserError::TargetMet,
+        FundraiserError::ContributionTooBig,
+        FundraiserError::ContributionTooSmall,
+        FundraiserError::MaximumContributionsReached,
+        FundraiserError::FundraiserNotEnded,
+        FundraiserError::FundraiserEnded,
+        FundraiserError::InvalidAmount,
+        FundraiserError::InvalidMintToRaise,
+        FundraiserError::BelowMinRaiseAmount,
+        FundraiserError::InvalidHookProgram,
+        FundraiserError::InvalidHookAccounts,
+        FundraiserError::NoPendingHook,
+        FundraiserError::MakerCompletionNotEligible,
+        FundraiserError::AccountNotWritable,
+        FundraiserError::ProtocolPaused,
+        FundraiserError::Unauthorized,
+        FundraiserError::MathOverflow,
+        FundraiserError::DuplicateInstruction,
+        FundraiserError::NotAllowlisted,
+        FundraiserError::AllowlistSlotTaken,
+        FundraiserError::InvalidAllowlistSlot,
+        FundraiserError::TriggerAlreadyFired,
+        FundraiserError::TransferExceedsLiability,
+        FundraiserError::InvalidTokenProgram,
+        FundraiserError::ContributorNotInitialized,
+        FundraiserError::AlreadyClaimed,
+        FundraiserError::FundraiserNotStarted,
+        FundraiserError::DurationTooShort,
+        FundraiserError::DurationTooLong,
+        FundraiserError::TooManyExtensions,
+        FundraiserError::MissingMemoProgram,
+        FundraiserError::NotNativeMint,
+        FundraiserError::MissingTransferHookAccounts,
+        FundraiserError::TooManyTransferHookAccounts,
+        FundraiserError::AccountAlreadyInitialized,
+        FundraiserError::InvalidRentDestination,
+        FundraiserError::MissingAccounts,
+        FundraiserError::AccountNotOwnedByProgram,
+        FundraiserError::MintNotOwnedByTokenProgram,
+        FundraiserError::InvalidMintData,
+        FundraiserError::TokenAccountNotOwnedByTokenProgram,
+        FundraiserError::InvalidTokenAccountData,
+        FundraiserError::AccountNotOwnedBySystemProgram,
+        FundraiserError::InvalidProgramData,
+        FundraiserError::ProgramIdMismatch,
+        FundraiserError::InvalidAccountDataLength,
+        FundraiserError::InvalidTokenExtensions,
+        FundraiserError::NotEnoughMultisigSigners,
+        FundraiserError::MissingRentPayer,
+        FundraiserError::MultisigUnwrapUnsupported,
+        FundraiserError::InvalidDelegate,
+        FundraiserError::InsufficientDelegation,
+        FundraiserError::TooManyCrankEntries,
+        FundraiserError::InvalidCrankEntries,
+        FundraiserError::FundraiseUnclaimed,
+        FundraiserError::VaultNotEmpty,
+        FundraiserError::OutstandingContributions,
+        FundraiserError::FundraisePaused,
+        FundraiserError::SelfReferral,
+        FundraiserError::FundraiseCancelled,
+        FundraiserError::NoRewards,
+        FundraiserError::RewardsLocked,
+        FundraiserError::RewardAlreadyClaimed,
+        FundraiserError::RewardsOutstanding,
+        FundraiserError::ReceiptNotTransferable,
+        FundraiserError::ReceiptHolderMustRefund,
+        FundraiserError::NoMatch,
+        FundraiserError::MatchLocked,
+        FundraiserError::MatchOutstanding,
+        FundraiserError::GateTokenRequired,
+        FundraiserError::TooManyUpdates,
+        FundraiserError::SnapshotNotReady,
+        FundraiserError::SnapshotTaken,
+        FundraiserError::MintHasFreezeAuthority,
+        FundraiserError::SourceAccountFrozen,
+        FundraiserError::VaultFrozen,
+        FundraiserError::DestinationFrozen,
+        FundraiserError::RewardMintNotForeign,
+        FundraiserError::FundraiseAlreadyExists,
+    ];
+
+    /// The code carried by `ProgramError::Custom`. Codes are append-only and pinned by the
+    /// `error_codes.json` fixture, so clients can match on them.
+    #[inline(always)]
+    pub const fn code(&self) -> u32 {
+        *self as u32
+    }
+
+    pub const fn name(&self) -> &'static str {
+        match self {
+            FundraiserError::NotSigner => "NotSigner",
+            FundraiserError::InvalidAddress => "InvalidAddress",
+            FundraiserError::TargetNotMet => "TargetNotMet",
+            FundraiserError::TargetMet => "TargetMet",
+            FundraiserError::ContributionTooBig => "ContributionTooBig",
+            FundraiserError::ContributionTooSmall => "ContributionTooSmall",
+            FundraiserError::MaximumContributionsReached => "MaximumContributionsReached",
+            FundraiserError::FundraiserNotEnded => "FundraiserNotEnded",
+            FundraiserError::FundraiserEnded => "FundraiserEnded",
+            FundraiserError::InvalidAmount => "InvalidAmount",
+            FundraiserError::InvalidMintToRaise => "InvalidMintToRaise",
+            FundraiserError::BelowMinRaiseAmount => "BelowMinRaiseAmount",
+            FundraiserError::InvalidHookProgram => "InvalidHookProgram",
+            FundraiserError::InvalidHookAccounts => "InvalidHookAccounts",
+            FundraiserError::NoPendingHook => "NoPendingHook",
+            FundraiserError::MakerCompletionNotEligible => "MakerCompletionNotEligible",
+            FundraiserError::AccountNotWritable => "AccountNotWritable",
+            FundraiserError::ProtocolPaused => "ProtocolPaused",
+            FundraiserError::Unauthorized => "Unauthorized",
+            FundraiserError::MathOverflow => "MathOverflow",
+            FundraiserError::DuplicateInstruction => "DuplicateInstruction",
+            FundraiserError::NotAllowlisted => "NotAllowlisted",
+            FundraiserError::AllowlistSlotTaken => "AllowlistSlotTaken",
+            FundraiserError::InvalidAllowlistSlot => "InvalidAllowlistSlot",
+            FundraiserError::TriggerAlreadyFired => "TriggerAlreadyFired",
+            FundraiserError::TransferExceedsLiability => "TransferExceedsLiability",
+            FundraiserError::InvalidTokenProgram => "InvalidTokenProgram",
+            FundraiserError::ContributorNotInitialized => "ContributorNotInitialized",
+            FundraiserError::AlreadyClaimed => "AlreadyClaimed",
+            FundraiserError::FundraiserNotStarted => "FundraiserNotStarted",
+            FundraiserError::DurationTooShort => "DurationTooShort",
+            FundraiserError::DurationTooLong => "DurationTooLong",
+            FundraiserError::TooManyExtensions => "TooManyExtensions",
+            FundraiserError::MissingMemoProgram => "MissingMemoProgram",
+            FundraiserError::NotNativeMint => "NotNativeMint",
+            FundraiserError::MissingTransferHookAccounts => "MissingTransferHookAccounts",
+            FundraiserError::TooManyTransferHookAccounts => "TooManyTransferHookAccounts",
+            FundraiserError::AccountAlreadyInitialized => "AccountAlreadyInitialized",
+            FundraiserError::InvalidRentDestination => "InvalidRentDestination",
+            FundraiserError::MissingAccounts => "MissingAccounts",
+            FundraiserError::AccountNotOwnedByProgram => "AccountNotOwnedByProgram",
+            FundraiserError::MintNotOwnedByTokenProgram => "MintNotOwnedByTokenProgram",
+            FundraiserError::InvalidMintData => "InvalidMintData",
+            FundraiserError::TokenAccountNotOwnedByTokenProgram => {
+                "TokenAccountNotOwnedByTokenProgram"
+            }
+            FundraiserError::InvalidTokenAccountData => "InvalidTokenAccountData",
+            FundraiserError::AccountNotOwnedBySystemProgram => "AccountNotOwnedBySystemProgram",
+            FundraiserError::InvalidProgramData => "InvalidProgramData",
+            FundraiserError::ProgramIdMismatch => "ProgramIdMismatch",
+            FundraiserError::InvalidAccountDataLength => "InvalidAccountDataLength",
+            FundraiserError::InvalidTokenExtensions => "InvalidTokenExtensions",
+            FundraiserError::NotEnoughMultisigSigners => "NotEnoughMultisigSigners",
+            FundraiserError::MissingRentPayer => "MissingRentPayer",
+            FundraiserError::MultisigUnwrapUnsupported => "MultisigUnwrapUnsupported",
+            FundraiserError::InvalidDelegate => "InvalidDelegate",
+            FundraiserError::InsufficientDelegation => "InsufficientDelegation",
+            FundraiserError::TooManyCrankEntries => "TooManyCrankEntries",
+            FundraiserError::InvalidCrankEntries => "InvalidCrankEntries",
+            FundraiserError::FundraiseUnclaimed => "FundraiseUnclaimed",
+            FundraiserError::VaultNotEmpty => "VaultNotEmpty",
+            FundraiserError::OutstandingContributions => "OutstandingContributions",
+            FundraiserError::FundraisePaused => "FundraisePaused",
+            FundraiserError::SelfReferral => "SelfReferral",
+            FundraiserError::FundraiseCancelled => "FundraiseCancelled",
+            FundraiserError::NoRewards => "NoRewards",
+            FundraiserError::RewardsLocked => "RewardsLocked",
+            FundraiserError::RewardAlreadyClaimed => "RewardAlreadyClaimed",
+            FundraiserError::RewardsOutstanding => "RewardsOutstanding",
+            FundraiserError::ReceiptNotTransferable => "ReceiptNotTransferable",
+            FundraiserError::ReceiptHolderMustRefund => "ReceiptHolderMustRefund",
+            FundraiserError::NoMatch => "NoMatch",
+            FundraiserError::MatchLocked => "MatchLocked",
+            FundraiserError::MatchOutstanding => "MatchOutstanding",
+            FundraiserError::GateTokenRequired => "GateTokenRequired",
+            FundraiserError::TooManyUpdates => "TooManyUpdates",
+            FundraiserError::SnapshotNotReady => "SnapshotNotReady",
+            FundraiserError::SnapshotTaken => "SnapshotTaken",
+            FundraiserError::MintHasFreezeAuthority => "MintHasFreezeAuthority",
+            FundraiserError::SourceAccountFrozen => "SourceAccountFrozen",
+            FundraiserError::VaultFrozen => "VaultFrozen",
+            FundraiserError::DestinationFrozen => "DestinationFrozen",
+            FundraiserError::RewardMintNotForeign => "RewardMintNotForeign",
+            FundraiserError::FundraiseAlreadyExists => "FundraiseAlreadyExists",
+        }
+    }
 }
 
 impl ToStr for FundraiserError {
@@ -40,6 +316,194 @@ impl ToStr for FundraiserError {
             FundraiserError::BelowMinRaiseAmount => {
                 "The amount to raise is below the minimum required"
             }
+            FundraiserError::InvalidHookProgram => "Hook program is not allowed",
+            FundraiserError::InvalidHookAccounts => {
+                "Hook accounts do not match the registered template"
+            }
+            FundraiserError::NoPendingHook => "Contributor has no pending hook",
+            FundraiserError::MakerCompletionNotEligible => {
+                "Fundraise is not eligible for maker completion"
+            }
+            FundraiserError::AccountNotWritable => "Account is not writable",
+            FundraiserError::ProtocolPaused => "Protocol is paused",
+            FundraiserError::Unauthorized => "Signer is not the protocol admin",
+            FundraiserError::MathOverflow => "Arithmetic overflow",
+            FundraiserError::DuplicateInstruction => {
+                "Identical instruction already executed in this transaction"
+            }
+            FundraiserError::NotAllowlisted => "Contributor is not on the allowlist",
+            FundraiserError::AllowlistSlotTaken => "Allowlist slot is already taken",
+            FundraiserError::InvalidAllowlistSlot => "Allowlist slot is out of range",
+            FundraiserError::TriggerAlreadyFired => "Expiry trigger has already fired",
+            FundraiserError::TransferExceedsLiability => {
+                "Transfer exceeds the amount owed out of the vault"
+            }
+            FundraiserError::InvalidTokenProgram => "Token program does not match the fundraise",
+            FundraiserError::ContributorNotInitialized => {
+                "The contributor account has not been created"
+            }
+            FundraiserError::AlreadyClaimed => "Raised funds have already been claimed",
+            FundraiserError::FundraiserNotStarted => "Fundraiser has not started yet",
+            FundraiserError::DurationTooShort => "Fundraiser duration is too short",
+            FundraiserError::DurationTooLong => "Fundraiser duration is too long",
+            FundraiserError::TooManyExtensions => "Fundraise has used all of its extensions",
+            FundraiserError::MissingMemoProgram => {
+                "Destination requires a transfer memo but no memo program was passed"
+            }
+            FundraiserError::NotNativeMint => "Only a wrapped SOL fundraise can unwrap",
+            FundraiserError::MissingTransferHookAccounts => {
+                "Transfer hook program of the mint was not passed"
+            }
+            FundraiserError::TooManyTransferHookAccounts => {
+                "Too many accounts passed for the mint's transfer hook"
+            }
+            FundraiserError::AccountAlreadyInitialized => "Account is already initialized",
+            FundraiserError::InvalidRentDestination => {
+                "Rent destination is not the contributor's rent payer"
+            }
+            FundraiserError::MissingAccounts => "Not enough accounts were passed",
+            FundraiserError::AccountNotOwnedByProgram => {
+                "Account is not owned by the fundraiser program"
+            }
+            FundraiserError::MintNotOwnedByTokenProgram => "Mint is not owned by a token program",
+            FundraiserError::InvalidMintData => "Mint account data is malformed",
+            FundraiserError::TokenAccountNotOwnedByTokenProgram => {
+                "Token account is not owned by a token program"
+            }
+            FundraiserError::InvalidTokenAccountData => "Token account data is malformed",
+            FundraiserError::AccountNotOwnedBySystemProgram => {
+                "Account is not owned by the system program"
+            }
+            FundraiserError::InvalidProgramData => "Account is not this program's ProgramData",
+            FundraiserError::ProgramIdMismatch => {
+                "Program account does not match the expected program"
+            }
+            FundraiserError::InvalidAccountDataLength => {
+                "Account data has the wrong length for its type"
+            }
+            FundraiserError::InvalidTokenExtensions => "Token extension data is malformed",
+            FundraiserError::NotEnoughMultisigSigners => {
+                "Not enough of the multisig's signers signed"
+            }
+            FundraiserError::MissingRentPayer => "A multisig authority needs a rent payer",
+            FundraiserError::MultisigUnwrapUnsupported => {
+                "Wrapped SOL cannot be unwrapped to a multisig"
+            }
+            FundraiserError::InvalidDelegate => "Signer is not the token account's delegate",
+            FundraiserError::InsufficientDelegation => {
+                "Delegated amount does not cover the contribution"
+            }
+            FundraiserError::TooManyCrankEntries => {
+                "Too many contributors for a single refund crank"
+            }
+            FundraiserError::InvalidCrankEntries => {
+                "Refund crank accounts are not whole contributor entries"
+            }
+            FundraiserError::FundraiseUnclaimed => "Fundraise met its target and must be claimed",
+            FundraiserError::VaultNotEmpty => "Vault still holds tokens",
+            FundraiserError::OutstandingContributions => "Fundraise still owes contributions",
+            FundraiserError::FundraisePaused => "Fundraise is paused",
+            FundraiserError::SelfReferral => "Contributors cannot refer themselves",
+            FundraiserError::FundraiseCancelled => "Fundraise was cancelled by its maker",
+            FundraiserError::NoRewards => "Fundraise has no rewards",
+            FundraiserError::RewardsLocked => "Rewards are not claimable yet",
+            FundraiserError::RewardAlreadyClaimed => "Reward already claimed",
+            FundraiserError::RewardsOutstanding => "Contributors have unclaimed rewards",
+            FundraiserError::ReceiptNotTransferable => {
+                "Positions holding receipts cannot be transferred"
+            }
+            FundraiserError::ReceiptHolderMustRefund => {
+                "Positions holding receipts are refunded by their holder"
+            }
+            FundraiserError::NoMatch => "The fundraise has no matching pool",
+            FundraiserError::MatchLocked => "The matching pool is held until the fundraise settles",
+            FundraiserError::MatchOutstanding => "The matching pool has not been reclaimed",
+            FundraiserError::GateTokenRequired => {
+                "The contributor must hold a token of the gate mint"
+            }
+            FundraiserError::TooManyUpdates => "The fundraise has posted the most updates allowed",
+            FundraiserError::SnapshotNotReady => "The fundraise has not succeeded yet",
+            FundraiserError::SnapshotTaken => "The contribution has been snapshotted",
+            FundraiserError::MintHasFreezeAuthority => "The mint has a freeze authority",
+            FundraiserError::SourceAccountFrozen => "The source token account is frozen",
+            FundraiserError::VaultFrozen => "The vault is frozen",
+            FundraiserError::DestinationFrozen => "The destination token account is frozen",
+            FundraiserError::RewardMintNotForeign => "Reward tokens are swept by SweepRewards",
+            FundraiserError::FundraiseAlreadyExists => "Maker already has a fundraise",
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use pinocchio::program_error::ProgramError;
+
+    use crate::FundraiserError;
+
+    const ERROR_CODES_FIXTURE: &str = "src/tests/fixtures/error_codes.json";
+
+    fn render_error_codes() -> String {
+        let entries: Vec<String> = FundraiserError::ALL
+            .iter()
+            .map(|error| format!("  \"{}\": {}", error.name(), error.code()))
+            .collect();
+
+        format!("{{\n{}\n}}\n", entries.join(",\n"))
+    }
+
+    #[test]
+    fn code_round_trip() {
+        for error in FundraiserError::ALL {
+            assert_eq!(FundraiserError::try_from(error.code()), Ok(error));
+
+            let ProgramError::Custom(code) = ProgramError::from(error) else {
+                panic!("{} is not a custom error", error.name());
+            };
+
+            assert_eq!(FundraiserError::try_from(code), Ok(error));
+        }
+
+        let unknown = FundraiserError::ALL.len() as u32;
+
+        assert_eq!(
+            FundraiserError::try_from(unknown),
+            Err(ProgramError::Custom(unknown))
+        );
+    }
+
+    #[test]
+    fn display_is_the_error_message() {
+        assert_eq!(
+            FundraiserError::ContributionTooSmall.to_string(),
+            "The contribution is too small"
+        );
+        assert_eq!(
+            FundraiserError::TransferExceedsLiability.to_string(),
+            "Transfer exceeds the amount owed out of the vault"
+        );
+    }
+
+    #[test]
+    fn error_codes_are_sequential() {
+        for (i, error) in FundraiserError::ALL.iter().enumerate() {
+            assert_eq!(error.code(), i as u32, "{} is out of order", error.name());
+        }
+    }
+
+    /// Regenerate with `UPDATE_ERROR_CODES=1 cargo test error_codes_match_fixture` after a
+    /// deliberate change to the published codes.
+    #[test]
+    fn error_codes_match_fixture() {
+        let rendered = render_error_codes();
+
+        if std::env::var_os("UPDATE_ERROR_CODES").is_some() {
+            std::fs::write(ERROR_CODES_FIXTURE, &rendered).unwrap();
+        }
+
+        assert_eq!(
+            std::fs::read_to_string(ERROR_CODES_FIXTURE).unwrap(),
+            rendered,
+            "error codes changed; regenerate {ERROR_CODES_FIXTURE} if this is intentional"
+        );
+    }
+}
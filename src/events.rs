@@ -0,0 +1,168 @@
+use core::mem::size_of;
+use pinocchio::{log::sol_log_data, pubkey::Pubkey};
+
+/// Emitted when the maker deposits the remaining shortfall inside the completion window, so
+/// backers can tell the final amount apart from organic contributions.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MakerCompletionEvent {
+    pub fundraise: Pubkey,
+    pub maker: Pubkey,
+    pub amount: u64,
+    pub organic_amount: u64,
+}
+
+impl MakerCompletionEvent {
+    pub const DISCRIMINATOR: u8 = 0;
+    pub const LEN: usize = size_of::<u8>()
+        + size_of::<Pubkey>()
+        + size_of::<Pubkey>()
+        + size_of::<u64>()
+        + size_of::<u64>();
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut bytes = [0u8; Self::LEN];
+
+        bytes[0] = Self::DISCRIMINATOR;
+        bytes[1..33].copy_from_slice(&self.fundraise);
+        bytes[33..65].copy_from_slice(&self.maker);
+        bytes[65..73].copy_from_slice(&self.amount.to_le_bytes());
+        bytes[73..81].copy_from_slice(&self.organic_amount.to_le_bytes());
+
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8; Self::LEN]) -> Self {
+        Self {
+            fundraise: bytes[1..33].try_into().unwrap(),
+            maker: bytes[33..65].try_into().unwrap(),
+            amount: u64::from_le_bytes(bytes[65..73].try_into().unwrap()),
+            organic_amount: u64::from_le_bytes(bytes[73..81].try_into().unwrap()),
+        }
+    }
+
+    pub fn emit(&self) {
+        sol_log_data(&[&self.to_bytes()]);
+    }
+}
+
+#[cfg(any(test, feature = "client"))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FundraiserEvent {
+    MakerCompletion(MakerCompletionEvent),
+}
+
+#[cfg(any(test, feature = "client"))]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    Empty,
+    UnknownDiscriminator(u8),
+    InvalidLength { expected: usize, actual: usize },
+}
+
+/// Decodes a payload logged through `sol_log_data` by one of the events above.
+#[cfg(any(test, feature = "client"))]
+pub fn decode(bytes: &[u8]) -> Result<FundraiserEvent, DecodeError> {
+    match bytes.first() {
+        Some(&MakerCompletionEvent::DISCRIMINATOR) => {
+            let bytes = bytes.try_into().map_err(|_| DecodeError::InvalidLength {
+                expected: MakerCompletionEvent::LEN,
+                actual: bytes.len(),
+            })?;
+
+            Ok(FundraiserEvent::MakerCompletion(
+                MakerCompletionEvent::from_bytes(bytes),
+            ))
+        }
+        Some(discriminator) => Err(DecodeError::UnknownDiscriminator(*discriminator)),
+        None => Err(DecodeError::Empty),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{DecodeError, FundraiserEvent, MakerCompletionEvent, decode};
+
+    fn events() -> Vec<(FundraiserEvent, Vec<u8>)> {
+        let maker_completion = MakerCompletionEvent {
+            fundraise: [1; 32],
+            maker: [2; 32],
+            amount: 1_000_000,
+            organic_amount: u64::MAX,
+        };
+
+        vec![(
+            FundraiserEvent::MakerCompletion(maker_completion.clone()),
+            maker_completion.to_bytes().to_vec(),
+        )]
+    }
+
+    #[test]
+    fn decode_round_trip() {
+        for (event, bytes) in events() {
+            assert_eq!(decode(&bytes), Ok(event));
+        }
+    }
+
+    #[test]
+    fn throw_if_empty() {
+        assert_eq!(decode(&[]), Err(DecodeError::Empty));
+    }
+
+    #[test]
+    fn throw_if_truncated_or_extended() {
+        for (_, bytes) in events() {
+            for len in 1..bytes.len() {
+                assert_eq!(
+                    decode(&bytes[..len]),
+                    Err(DecodeError::InvalidLength {
+                        expected: bytes.len(),
+                        actual: len,
+                    })
+                );
+            }
+
+            let mut extended = bytes.clone();
+            extended.push(0);
+
+            assert_eq!(
+                decode(&extended),
+                Err(DecodeError::InvalidLength {
+                    expected: bytes.len(),
+                    actual: extended.len(),
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn throw_if_unknown_discriminator() {
+        for (_, mut bytes) in events() {
+            bytes[0] = u8::MAX;
+
+            assert_eq!(
+                decode(&bytes),
+                Err(DecodeError::UnknownDiscriminator(u8::MAX))
+            );
+        }
+    }
+
+    #[test]
+    fn decode_corrupted_payloads_without_panicking() {
+        let mut seed: u64 = 0x5eed;
+
+        for (_, bytes) in events() {
+            for _ in 0..1_000 {
+                let mut corrupted = bytes.clone();
+
+                seed = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
+                let index = (seed >> 33) as usize % corrupted.len();
+                corrupted[index] ^= (seed >> 13) as u8 | 1;
+
+                seed = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
+                corrupted.truncate((seed >> 33) as usize % (corrupted.len() + 1));
+
+                let _ = decode(&corrupted);
+            }
+        }
+    }
+}
@@ -0,0 +1,50 @@
+//! Byte offsets of account fields, for `memcmp` filters over `getProgramAccounts`.
+//!
+//! Accounts carry no discriminator, so pair these with a `dataSize` filter on the account's
+//! `DATA_SIZE` to match a single account type. A registry per mint, at `[b"registry", mint]`,
+//! finds a mint's fundraisers without a scan at all. Each offset is checked against the layout
+//! at build time, so these only change along with the account layout itself.
+
+/// Offsets into [`crate::Fundraise`].
+pub mod fundraise {
+    pub const DATA_SIZE: usize = 825;
+
+    pub const MAKER: usize = 0;
+    pub const MINT_TO_RAISE: usize = 32;
+    pub const AMOUNT_TO_RAISE: usize = 64;
+    pub const CURRENT_AMOUNT: usize = 72;
+    pub const TIME_STARTED: usize = 80;
+    pub const DURATION: usize = 88;
+    pub const HOOK_PROGRAM: usize = 97;
+    pub const ALLOWLIST: usize = 296;
+    pub const TOKEN_PROGRAM: usize = 297;
+    pub const VAULT: usize = 329;
+    pub const CLAIMED: usize = 369;
+    pub const REFUND_POLICY: usize = 371;
+    pub const CAMPAIGN_TYPE: usize = 372;
+    pub const MERKLE_ROOT: usize = 373;
+    pub const REGISTERED: usize = 405;
+    pub const NAME: usize = 406;
+    pub const URI: usize = 438;
+    pub const PAUSED: usize = 566;
+    pub const TIER_THRESHOLDS: usize = 567;
+    pub const BOND: usize = 599;
+    pub const CANCELLED: usize = 607;
+    pub const REWARD_MINT: usize = 608;
+    pub const RECEIPT_MINT: usize = 664;
+    pub const MATCH_SPONSOR: usize = 696;
+    pub const GATE_MINT: usize = 736;
+    pub const CLAIM_AUTHORITY: usize = 777;
+    pub const END_TIME: usize = 809;
+    pub const REWARD_PAID: usize = 817;
+}
+
+/// Offsets into [`crate::Contributor`].
+pub mod contributor {
+    pub const DATA_SIZE: usize = 168;
+
+    pub const FUNDRAISE: usize = 0;
+    pub const AUTHORITY: usize = 32;
+    pub const RENT_PAYER: usize = 93;
+    pub const MINT: usize = 136;
+}
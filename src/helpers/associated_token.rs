@@ -2,7 +2,7 @@ use pinocchio::{
     ProgramResult, account_info::AccountInfo, program_error::ProgramError,
     pubkey::find_program_address,
 };
-use pinocchio_associated_token_account::instructions::Create;
+use pinocchio_associated_token_account::instructions::{Create, CreateIdempotent};
 
 use crate::{AccountCheck, FundraiserError, TokenAccount};
 
@@ -17,6 +17,15 @@ impl AssociatedTokenAccount {
     ) -> Result<(), ProgramError> {
         TokenAccount::check(account)?;
 
+        Self::check_address(account, authority, mint, token_program)
+    }
+
+    pub fn check_address(
+        account: &AccountInfo,
+        authority: &AccountInfo,
+        mint: &AccountInfo,
+        token_program: &AccountInfo,
+    ) -> Result<(), ProgramError> {
         if find_program_address(
             &[authority.key(), token_program.key(), mint.key()],
             &pinocchio_associated_token_account::ID,
@@ -30,6 +39,7 @@ impl AssociatedTokenAccount {
         Ok(())
     }
 
+    /// Creates the account, failing if it already exists.
     pub fn init(
         account: &AccountInfo,
         mint: &AccountInfo,
@@ -49,6 +59,9 @@ impl AssociatedTokenAccount {
         .invoke()
     }
 
+    /// Creates the account unless it already exists, through the ATA program's
+    /// `CreateIdempotent` so that an account created earlier in the same transaction is left as
+    /// is. The ATA program still rejects an existing account of another mint or owner.
     pub fn init_if_needed(
         account: &AccountInfo,
         mint: &AccountInfo,
@@ -57,9 +70,14 @@ impl AssociatedTokenAccount {
         system_program: &AccountInfo,
         token_program: &AccountInfo,
     ) -> ProgramResult {
-        match Self::check(account, payer, mint, token_program) {
-            Ok(_) => Ok(()),
-            Err(_) => Self::init(account, mint, payer, owner, system_program, token_program),
+        CreateIdempotent {
+            funding_account: payer,
+            account,
+            wallet: owner,
+            mint,
+            system_program,
+            token_program,
         }
+        .invoke()
     }
 }
@@ -0,0 +1,19 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
+
+use crate::{AccountCheck, FundraiserError};
+
+pub struct HookProgram;
+
+impl AccountCheck for HookProgram {
+    fn check(account: &AccountInfo) -> Result<(), ProgramError> {
+        if !account.executable()
+            || account.key().eq(&crate::ID)
+            || account.key().eq(&pinocchio_token::ID)
+            || account.key().eq(&pinocchio_token_2022::ID)
+        {
+            return Err(FundraiserError::InvalidHookProgram.into());
+        }
+
+        Ok(())
+    }
+}
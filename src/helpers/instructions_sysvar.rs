@@ -0,0 +1,26 @@
+use pinocchio::{
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::instructions::{Instructions, IntrospectedInstruction},
+};
+
+pub struct InstructionsSysvar;
+
+impl InstructionsSysvar {
+    /// Returns whether any instruction executed before the current one in this transaction
+    /// matches `predicate`. Fails if `account` is not the instructions sysvar.
+    pub fn any_earlier(
+        account: &AccountInfo,
+        predicate: impl Fn(&IntrospectedInstruction) -> bool,
+    ) -> Result<bool, ProgramError> {
+        let instructions = Instructions::try_from(account)?;
+
+        for index in 0..instructions.load_current_index() {
+            if predicate(&instructions.load_instruction_at(index as usize)?) {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+}
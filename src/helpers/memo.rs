@@ -0,0 +1,45 @@
+use pinocchio::{ProgramResult, account_info::AccountInfo, cpi::invoke, instruction::Instruction};
+
+use crate::{FundraiserError, MEMO_PROGRAM_ID, TokenAccount2022Account};
+
+pub struct MemoProgram;
+
+impl MemoProgram {
+    /// Splits the memo program off the end of `accounts`, where it is optionally passed ahead of
+    /// any stats account.
+    #[inline(always)]
+    pub fn split_trailing(accounts: &[AccountInfo]) -> (Option<&AccountInfo>, &[AccountInfo]) {
+        match accounts.split_last() {
+            Some((memo_program, accounts)) if memo_program.key() == &MEMO_PROGRAM_ID => {
+                (Some(memo_program), accounts)
+            }
+            _ => (None, accounts),
+        }
+    }
+
+    /// Logs `memo` through the memo program if `destination` requires a memo on incoming
+    /// transfers. Token-2022 looks for it in the instruction processed just before the
+    /// transfer, so this must be called immediately ahead of it.
+    pub fn memo_if_required(
+        destination: &AccountInfo,
+        memo_program: Option<&AccountInfo>,
+        memo: &[u8],
+    ) -> ProgramResult {
+        if !TokenAccount2022Account::requires_incoming_memo(destination)? {
+            return Ok(());
+        }
+
+        if memo_program.is_none() {
+            return Err(FundraiserError::MissingMemoProgram.into());
+        }
+
+        invoke::<0>(
+            &Instruction {
+                program_id: &MEMO_PROGRAM_ID,
+                data: memo,
+                accounts: &[],
+            },
+            &[],
+        )
+    }
+}
@@ -0,0 +1,136 @@
+use pinocchio::pubkey::Pubkey;
+
+/// Domain separators keeping a leaf from being passed off as an inner node, and vice versa.
+const LEAF_PREFIX: &[u8] = &[0];
+const NODE_PREFIX: &[u8] = &[1];
+
+/// Proofs of membership in a Merkle tree of wallets. Leaves hash the wallet and inner nodes hash
+/// their children in sorted order, so a proof is just the siblings from the leaf up, without
+/// left or right markers.
+pub struct MerkleProof;
+
+impl MerkleProof {
+    pub const NODE_LEN: usize = 32;
+
+    #[inline(always)]
+    pub fn leaf(wallet: &Pubkey) -> [u8; 32] {
+        sha256(&[LEAF_PREFIX, wallet])
+    }
+
+    #[inline(always)]
+    pub fn node(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+        let (left, right) = if a <= b { (a, b) } else { (b, a) };
+
+        sha256(&[NODE_PREFIX, left, right])
+    }
+
+    /// Whether `proof`, the concatenated siblings of the path from the leaf of `wallet`, leads
+    /// up to `root`. A proof that is not a whole number of nodes never does.
+    pub fn verify(proof: &[u8], wallet: &Pubkey, root: &[u8; 32]) -> bool {
+        if !proof.len().is_multiple_of(Self::NODE_LEN) {
+            return false;
+        }
+
+        let hash = proof
+            .chunks_exact(Self::NODE_LEN)
+            .fold(Self::leaf(wallet), |hash, sibling| {
+                Self::node(&hash, sibling.try_into().unwrap())
+            });
+
+        hash == *root
+    }
+}
+
+/// SHA-256 of the concatenation of `vals`.
+pub fn sha256(vals: &[&[u8]]) -> [u8; 32] {
+    #[cfg(target_os = "solana")]
+    {
+        let mut hash = core::mem::MaybeUninit::<[u8; 32]>::uninit();
+
+        // SAFETY: the syscall reads `vals` as an array of slices and writes all 32 bytes.
+        unsafe {
+            pinocchio::syscalls::sol_sha256(
+                vals as *const _ as *const u8,
+                vals.len() as u64,
+                hash.as_mut_ptr() as *mut u8,
+            );
+
+            hash.assume_init()
+        }
+    }
+
+    #[cfg(all(not(target_os = "solana"), test))]
+    {
+        use sha2::{Digest, Sha256};
+
+        vals.iter()
+            .fold(Sha256::new(), |hasher, val| hasher.chain_update(val))
+            .finalize()
+            .into()
+    }
+
+    #[cfg(all(not(target_os = "solana"), not(test)))]
+    {
+        core::hint::black_box(vals);
+        panic!("sha256 is only available on-chain")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_pubkey::Pubkey;
+
+    use crate::{
+        MerkleProof,
+        tests::utils::{merkle_proof, merkle_root},
+    };
+
+    fn wallets(count: u8) -> Vec<Pubkey> {
+        (1..=count)
+            .map(|i| Pubkey::new_from_array([i; 32]))
+            .collect()
+    }
+
+    #[test]
+    fn verify_proofs_for_every_wallet() {
+        let wallets = wallets(5);
+        let root = merkle_root(&wallets);
+
+        for wallet in &wallets {
+            let proof = merkle_proof(&wallets, wallet);
+
+            assert!(MerkleProof::verify(&proof, &wallet.to_bytes(), &root));
+        }
+
+        // a lone wallet's leaf is the root, with an empty proof
+        assert!(MerkleProof::verify(
+            &[],
+            &wallets[0].to_bytes(),
+            &merkle_root(&wallets[..1])
+        ));
+    }
+
+    #[test]
+    fn reject_wrong_or_malformed_proofs() {
+        let wallets = wallets(4);
+        let root = merkle_root(&wallets);
+        let proof = merkle_proof(&wallets, &wallets[0]);
+
+        assert!(!MerkleProof::verify(&proof, &[9; 32], &root));
+        assert!(!MerkleProof::verify(&proof, &wallets[1].to_bytes(), &root));
+        assert!(!MerkleProof::verify(
+            &proof[..proof.len() - 1],
+            &wallets[0].to_bytes(),
+            &root
+        ));
+        assert!(!MerkleProof::verify(&[], &wallets[0].to_bytes(), &root));
+
+        // the parent of the first two leaves cannot be passed off as a wallet
+        let parent = MerkleProof::node(
+            &MerkleProof::leaf(&wallets[0].to_bytes()),
+            proof[..32].try_into().unwrap(),
+        );
+
+        assert!(!MerkleProof::verify(&proof[32..], &parent, &root));
+    }
+}
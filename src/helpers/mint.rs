@@ -7,18 +7,18 @@ use pinocchio::{
 use pinocchio_system::instructions::CreateAccount;
 use pinocchio_token::{instructions::InitializeMint2, state::Mint};
 
-use crate::{AccountCheck, MintInit};
+use crate::{AccountCheck, FundraiserError, MintInit};
 
 pub struct MintAccount;
 
 impl AccountCheck for MintAccount {
     fn check(account: &AccountInfo) -> Result<(), ProgramError> {
         if account.owner().ne(&pinocchio_token::ID) {
-            return Err(ProgramError::InvalidAccountOwner);
+            return Err(FundraiserError::MintNotOwnedByTokenProgram.into());
         }
 
         if account.data_len() != Mint::LEN {
-            return Err(ProgramError::InvalidAccountData);
+            return Err(FundraiserError::InvalidMintData.into());
         }
 
         Ok(())
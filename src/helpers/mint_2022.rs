@@ -1,31 +1,111 @@
 use pinocchio::{
     ProgramResult,
     account_info::AccountInfo,
+    cpi::invoke,
+    instruction::{AccountMeta, Instruction},
     program_error::ProgramError,
+    pubkey::Pubkey,
     sysvars::{Sysvar, rent::Rent},
 };
 use pinocchio_system::instructions::CreateAccount;
 use pinocchio_token_2022::{instructions::InitializeMint2, state::Mint};
 
 use crate::{
-    AccountCheck, MintInit,
-    helpers::{TOKEN_2022_ACCOUNT_DISCRIMINATOR_OFFSET, TOKEN_2022_MINT_DISCRIMINATOR},
+    AccountCheck, FundraiserError, MintInit,
+    helpers::{
+        TOKEN_2022_ACCOUNT_DISCRIMINATOR_OFFSET, TOKEN_2022_MINT_DISCRIMINATOR,
+        token_2022_extension,
+    },
 };
 
+/// Extension type of `TransferHook` in Token-2022's TLV data, holding the hook's update
+/// authority followed by its program id.
+const TRANSFER_HOOK_EXTENSION: u16 = 14;
+
+/// Token-2022's instruction enabling the `NonTransferable` extension on a mint that is not
+/// initialized yet.
+const INITIALIZE_NON_TRANSFERABLE_MINT_DISCRIMINATOR: u8 = 32;
+
+/// Length of a mint carrying only the `NonTransferable` extension: the base mint padded to the
+/// account type byte, that byte and the extension's empty TLV entry.
+const NON_TRANSFERABLE_MINT_LEN: usize = TOKEN_2022_ACCOUNT_DISCRIMINATOR_OFFSET + 1 + 4;
+
 pub struct Mint2022Account;
 
+impl Mint2022Account {
+    /// Program that Token-2022 invokes on every transfer of `mint`, if it has the
+    /// `TransferHook` extension with a program set. Legacy mints carry no extensions.
+    pub fn transfer_hook_program(mint: &AccountInfo) -> Result<Option<Pubkey>, ProgramError> {
+        if mint.owner().ne(&pinocchio_token_2022::ID) {
+            return Ok(None);
+        }
+
+        let data = mint.try_borrow_data()?;
+        let program_id = token_2022_extension(&data, TRANSFER_HOOK_EXTENSION)?
+            .and_then(|value| value.get(32..64))
+            .map(|program_id| Pubkey::try_from(program_id).unwrap());
+
+        Ok(program_id.filter(|program_id| program_id != &Pubkey::default()))
+    }
+
+    /// Creates a mint at `account` with the `NonTransferable` extension, so that its tokens can
+    /// only ever be minted and burned. As with [`MintInit::init`], `account` signs for its own
+    /// creation.
+    pub fn init_non_transferable(
+        account: &AccountInfo,
+        payer: &AccountInfo,
+        decimals: u8,
+        mint_authority: &[u8; 32],
+    ) -> ProgramResult {
+        Self::create(account, payer, NON_TRANSFERABLE_MINT_LEN)?;
+
+        invoke::<1>(
+            &Instruction {
+                program_id: &pinocchio_token_2022::ID,
+                data: &[INITIALIZE_NON_TRANSFERABLE_MINT_DISCRIMINATOR],
+                accounts: &[AccountMeta::writable(account.key())],
+            },
+            &[account],
+        )?;
+
+        InitializeMint2 {
+            mint: account,
+            decimals,
+            mint_authority,
+            freeze_authority: None,
+            token_program: &pinocchio_token_2022::ID,
+        }
+        .invoke()
+    }
+
+    fn create(account: &AccountInfo, payer: &AccountInfo, space: usize) -> ProgramResult {
+        let lamports = Rent::get()?.minimum_balance(space);
+
+        CreateAccount {
+            from: payer,
+            to: account,
+            lamports,
+            space: space as u64,
+            owner: &pinocchio_token_2022::ID,
+        }
+        .invoke()
+    }
+}
+
 impl AccountCheck for Mint2022Account {
     fn check(account: &AccountInfo) -> Result<(), ProgramError> {
         if account.owner().ne(&pinocchio_token_2022::ID) {
-            return Err(ProgramError::InvalidAccountOwner);
+            return Err(FundraiserError::MintNotOwnedByTokenProgram.into());
         }
 
         let data = account.try_borrow_data()?;
 
         if data.len().ne(&Mint::BASE_LEN)
-            && data[TOKEN_2022_ACCOUNT_DISCRIMINATOR_OFFSET].ne(&TOKEN_2022_MINT_DISCRIMINATOR)
+            && data
+                .get(TOKEN_2022_ACCOUNT_DISCRIMINATOR_OFFSET)
+                .ne(&Some(&TOKEN_2022_MINT_DISCRIMINATOR))
         {
-            return Err(ProgramError::InvalidAccountData);
+            return Err(FundraiserError::InvalidMintData.into());
         }
 
         Ok(())
@@ -40,16 +120,7 @@ impl MintInit for Mint2022Account {
         mint_authority: &[u8; 32],
         freeze_authority: Option<&[u8; 32]>,
     ) -> ProgramResult {
-        let lamports = Rent::get()?.minimum_balance(Mint::BASE_LEN);
-
-        CreateAccount {
-            from: payer,
-            to: account,
-            lamports,
-            space: Mint::BASE_LEN as u64,
-            owner: &pinocchio_token_2022::ID,
-        }
-        .invoke()?;
+        Self::create(account, payer, Mint::BASE_LEN)?;
 
         InitializeMint2 {
             mint: account,
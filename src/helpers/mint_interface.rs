@@ -1,31 +1,214 @@
-use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
-use pinocchio_token_2022::state::Mint;
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
 
-use crate::{
-    AccountCheck,
-    helpers::{TOKEN_2022_ACCOUNT_DISCRIMINATOR_OFFSET, TOKEN_2022_MINT_DISCRIMINATOR},
-};
+use crate::{AccountCheck, FundraiserError, Mint2022Account, MintAccount};
+
+/// Offset of the freeze authority in the mint state, which Token-2022 extends without moving.
+const FREEZE_AUTHORITY_OFFSET: usize = 46;
+
+/// Length of a `COption<Pubkey>`: a 4-byte little-endian tag, then the key.
+const COPTION_PUBKEY_LEN: usize = 4 + size_of::<Pubkey>();
 
 pub struct MintInterface;
 
 impl AccountCheck for MintInterface {
     fn check(account: &AccountInfo) -> Result<(), ProgramError> {
-        if account.owner().ne(&pinocchio_token_2022::ID) {
-            if account.owner().ne(&pinocchio_token::ID) {
-                return Err(ProgramError::InvalidAccountOwner);
-            } else if account.data_len().ne(&Mint::BASE_LEN) {
-                return Err(ProgramError::InvalidAccountData);
-            }
-        } else {
-            let data = account.try_borrow_data()?;
+        match *account.owner() {
+            pinocchio_token::ID => MintAccount::check(account),
+            pinocchio_token_2022::ID => Mint2022Account::check(account),
+            _ => Err(FundraiserError::MintNotOwnedByTokenProgram.into()),
+        }
+    }
+}
 
-            if data.len().ne(&Mint::BASE_LEN)
-                && data[TOKEN_2022_ACCOUNT_DISCRIMINATOR_OFFSET].ne(&TOKEN_2022_MINT_DISCRIMINATOR)
-            {
-                return Err(ProgramError::InvalidAccountData);
+impl MintInterface {
+    /// Decimals of a mint of either token program, which [`Self::check`] has already accepted.
+    pub fn decimals(account: &AccountInfo) -> Result<u8, ProgramError> {
+        match *account.owner() {
+            pinocchio_token::ID => {
+                let mint =
+                    unsafe { pinocchio_token::state::Mint::from_account_info_unchecked(account)? };
+                Ok(mint.decimals())
             }
+            pinocchio_token_2022::ID => {
+                let mint = unsafe {
+                    pinocchio_token_2022::state::Mint::from_account_info_unchecked(account)?
+                };
+                Ok(mint.decimals())
+            }
+            _ => Err(FundraiserError::MintNotOwnedByTokenProgram.into()),
+        }
+    }
+
+    /// Freeze authority of a mint of either token program, which could freeze any of its
+    /// token accounts.
+    pub fn freeze_authority(account: &AccountInfo) -> Result<Option<Pubkey>, ProgramError> {
+        if !matches!(
+            *account.owner(),
+            pinocchio_token::ID | pinocchio_token_2022::ID
+        ) {
+            return Err(FundraiserError::MintNotOwnedByTokenProgram.into());
+        }
+
+        let data = account.try_borrow_data()?;
+        let coption = data
+            .get(FREEZE_AUTHORITY_OFFSET..FREEZE_AUTHORITY_OFFSET + COPTION_PUBKEY_LEN)
+            .ok_or(FundraiserError::InvalidMintData)?;
+
+        parse_coption_pubkey(coption.try_into().unwrap())
+    }
+}
+
+/// Reads a `COption<Pubkey>` as the token programs lay it out, rejecting any tag but 0 for none
+/// and 1 for some.
+pub fn parse_coption_pubkey(
+    bytes: &[u8; COPTION_PUBKEY_LEN],
+) -> Result<Option<Pubkey>, ProgramError> {
+    let (tag, key) = bytes.split_at(4);
+
+    match u32::from_le_bytes(tag.try_into().unwrap()) {
+        0 => Ok(None),
+        1 => Ok(Some(key.try_into().unwrap())),
+        _ => Err(FundraiserError::InvalidMintData.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        AccountCheck, FundraiserError, MintInterface,
+        helpers::{
+            TOKEN_2022_ACCOUNT_DISCRIMINATOR_OFFSET, TOKEN_2022_MINT_DISCRIMINATOR,
+            mint_interface::{COPTION_PUBKEY_LEN, FREEZE_AUTHORITY_OFFSET, parse_coption_pubkey},
+        },
+        tests::utils::RawAccount,
+    };
+
+    fn coption(tag: u32, key: [u8; 32]) -> [u8; COPTION_PUBKEY_LEN] {
+        let mut bytes = [0u8; COPTION_PUBKEY_LEN];
+        bytes[..4].copy_from_slice(&tag.to_le_bytes());
+        bytes[4..].copy_from_slice(&key);
+        bytes
+    }
+
+    fn mint_2022_with_extensions() -> Vec<u8> {
+        let mut data = vec![0u8; TOKEN_2022_ACCOUNT_DISCRIMINATOR_OFFSET + 1 + 4 + 32];
+        data[TOKEN_2022_ACCOUNT_DISCRIMINATOR_OFFSET] = TOKEN_2022_MINT_DISCRIMINATOR;
+        data
+    }
+
+    #[test]
+    fn accept_legacy_mint() {
+        let data = vec![0u8; pinocchio_token::state::Mint::LEN];
+
+        assert!(MintInterface::check(&RawAccount::new(&pinocchio_token::ID, &data).info()).is_ok());
+    }
+
+    #[test]
+    fn accept_token_2022_mint() {
+        let base = vec![0u8; pinocchio_token_2022::state::Mint::BASE_LEN];
+        let extended = mint_2022_with_extensions();
+
+        assert!(
+            MintInterface::check(&RawAccount::new(&pinocchio_token_2022::ID, &base).info()).is_ok()
+        );
+        assert!(
+            MintInterface::check(&RawAccount::new(&pinocchio_token_2022::ID, &extended).info())
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn reject_token_2022_mint_under_legacy_owner() {
+        let extended = mint_2022_with_extensions();
+
+        assert_eq!(
+            MintInterface::check(&RawAccount::new(&pinocchio_token::ID, &extended).info()),
+            Err(FundraiserError::InvalidMintData.into())
+        );
+    }
+
+    #[test]
+    fn reject_mint_under_foreign_owner() {
+        let data = vec![0u8; pinocchio_token::state::Mint::LEN];
+        let extended = mint_2022_with_extensions();
+
+        assert_eq!(
+            MintInterface::check(&RawAccount::new(&pinocchio_system::ID, &data).info()),
+            Err(FundraiserError::MintNotOwnedByTokenProgram.into())
+        );
+        assert_eq!(
+            MintInterface::check(&RawAccount::new(&pinocchio_system::ID, &extended).info()),
+            Err(FundraiserError::MintNotOwnedByTokenProgram.into())
+        );
+    }
+
+    #[test]
+    fn reject_token_account_as_mint() {
+        let mut data = vec![0u8; TOKEN_2022_ACCOUNT_DISCRIMINATOR_OFFSET + 1 + 4];
+        data[TOKEN_2022_ACCOUNT_DISCRIMINATOR_OFFSET] = TOKEN_2022_MINT_DISCRIMINATOR + 1;
+        let short = vec![0u8; pinocchio_token::state::Mint::LEN + 1];
+
+        assert_eq!(
+            MintInterface::check(&RawAccount::new(&pinocchio_token_2022::ID, &data).info()),
+            Err(FundraiserError::InvalidMintData.into())
+        );
+        assert_eq!(
+            MintInterface::check(&RawAccount::new(&pinocchio_token_2022::ID, &short).info()),
+            Err(FundraiserError::InvalidMintData.into())
+        );
+    }
+
+    #[test]
+    fn parse_coption_pubkey_tags() {
+        assert_eq!(parse_coption_pubkey(&coption(0, [0; 32])), Ok(None));
+        assert_eq!(
+            parse_coption_pubkey(&coption(1, [7; 32])),
+            Ok(Some([7; 32]))
+        );
+        // a set key is only read behind a set tag
+        assert_eq!(parse_coption_pubkey(&coption(0, [7; 32])), Ok(None));
+    }
+
+    #[test]
+    fn reject_malformed_coption_tag() {
+        for tag in [2, 0x0100, u32::MAX] {
+            assert_eq!(
+                parse_coption_pubkey(&coption(tag, [7; 32])),
+                Err(FundraiserError::InvalidMintData.into())
+            );
+        }
+    }
+
+    #[test]
+    fn read_freeze_authority_of_either_program() {
+        let mut legacy = vec![0u8; pinocchio_token::state::Mint::LEN];
+        let mut extended = mint_2022_with_extensions();
+
+        assert_eq!(
+            MintInterface::freeze_authority(&RawAccount::new(&pinocchio_token::ID, &legacy).info()),
+            Ok(None)
+        );
+
+        for data in [&mut legacy, &mut extended] {
+            data[FREEZE_AUTHORITY_OFFSET..FREEZE_AUTHORITY_OFFSET + COPTION_PUBKEY_LEN]
+                .copy_from_slice(&coption(1, [9; 32]));
         }
 
-        Ok(())
+        assert_eq!(
+            MintInterface::freeze_authority(&RawAccount::new(&pinocchio_token::ID, &legacy).info()),
+            Ok(Some([9; 32]))
+        );
+        assert_eq!(
+            MintInterface::freeze_authority(
+                &RawAccount::new(&pinocchio_token_2022::ID, &extended).info()
+            ),
+            Ok(Some([9; 32]))
+        );
+        assert_eq!(
+            MintInterface::freeze_authority(
+                &RawAccount::new(&pinocchio_system::ID, &legacy).info()
+            ),
+            Err(FundraiserError::MintNotOwnedByTokenProgram.into())
+        );
     }
 }
@@ -1,31 +1,143 @@
-use pinocchio::{ProgramResult, account_info::AccountInfo, program_error::ProgramError};
+use pinocchio::{
+    ProgramResult, account_info::AccountInfo, instruction::Seed, program_error::ProgramError,
+};
+
+use crate::FundraiserError;
 
 pub mod associated_token;
+pub mod hook;
+pub mod instructions_sysvar;
+pub mod memo;
+pub mod merkle;
 pub mod mint;
 pub mod mint_2022;
 pub mod mint_interface;
+pub mod multisig;
 pub mod program;
 pub mod signer;
 pub mod system;
 pub mod token;
 pub mod token_2022;
 pub mod token_interface;
+pub mod transfer_hook;
+pub mod upgrade_authority;
+pub mod vault;
+pub mod writable;
 
 pub use associated_token::*;
+pub use hook::*;
+pub use instructions_sysvar::*;
+pub use memo::*;
+pub use merkle::*;
 pub use mint::*;
 pub use mint_2022::*;
 pub use mint_interface::*;
+pub use multisig::*;
 pub use program::*;
 pub use signer::*;
 pub use system::*;
 pub use token::*;
 pub use token_2022::*;
 pub use token_interface::*;
+pub use transfer_hook::*;
+pub use upgrade_authority::*;
+pub use vault::*;
+pub use writable::*;
 
 const TOKEN_2022_ACCOUNT_DISCRIMINATOR_OFFSET: usize = 165;
 const TOKEN_2022_MINT_DISCRIMINATOR: u8 = 0x01;
 const TOKEN_2022_TOKEN_ACCOUNT_DISCRIMINATOR: u8 = 0x02;
 
+/// Value of the `extension_type` entry in the TLV data of a Token-2022 mint or token account,
+/// found by walking the entries that follow the account type byte. Accounts without
+/// extensions have no entries.
+fn token_2022_extension(data: &[u8], extension_type: u16) -> Result<Option<&[u8]>, ProgramError> {
+    let mut tlv = data
+        .get(TOKEN_2022_ACCOUNT_DISCRIMINATOR_OFFSET + 1..)
+        .unwrap_or_default();
+
+    while let [t0, t1, l0, l1, rest @ ..] = tlv {
+        let len = u16::from_le_bytes([*l0, *l1]) as usize;
+        let value = rest
+            .get(..len)
+            .ok_or(FundraiserError::InvalidTokenExtensions)?;
+
+        match u16::from_le_bytes([*t0, *t1]) {
+            // uninitialized padding ends the entries
+            0 => break,
+            t if t == extension_type => return Ok(Some(value)),
+            _ => tlv = &rest[len..],
+        }
+    }
+
+    Ok(None)
+}
+
+/// Runs an account check from an accounts parser, given the checked account's `index` in the
+/// instruction. With the `logging` feature a failure is logged as
+/// `account #<index> (<account>) failed <kind> check`, so the caller can tell which account was
+/// wrong.
+macro_rules! check_account {
+    ($index:expr, $kind:literal, $($check:ident)::+($account:ident $(, $arg:expr)* $(,)?)) => {
+        check_account!(@log $index, $account, $kind, $($check)::+($account $(, $arg)*))
+    };
+    ($index:expr, $kind:literal, $state:ident.$check:ident($account:ident.key())) => {
+        check_account!(@log $index, $account, $kind, $state.$check($account.key()))
+    };
+    (@log $index:expr, $account:ident, $kind:literal, $result:expr) => {{
+        let result = $result;
+
+        #[cfg(feature = "logging")]
+        if result.is_err() {
+            $crate::helpers::log_failed_check($index, stringify!($account), $kind);
+        }
+        #[cfg(not(feature = "logging"))]
+        let _ = $index;
+
+        result
+    }};
+}
+
+pub(crate) use check_account;
+
+/// Logs `account #<index> (<name>) failed <kind> check`, assembled on the stack since the
+/// program does not allocate.
+#[cfg(feature = "logging")]
+#[cold]
+pub fn log_failed_check(index: u8, name: &str, kind: &str) {
+    let digits = [
+        b'0' + index / 100,
+        b'0' + index / 10 % 10,
+        b'0' + index % 10,
+    ];
+    let skip = match index {
+        0..=9 => 2,
+        10..=99 => 1,
+        _ => 0,
+    };
+    let mut line = [0u8; 96];
+    let mut len = 0;
+
+    for part in [
+        b"account #".as_slice(),
+        &digits[skip..],
+        b" (",
+        name.as_bytes(),
+        b") failed ",
+        kind.as_bytes(),
+        b" check",
+    ] {
+        let end = (len + part.len()).min(line.len());
+
+        line[len..end].copy_from_slice(&part[..end - len]);
+        len = end;
+    }
+
+    if let Ok(line) = core::str::from_utf8(&line[..len]) {
+        pinocchio::log::sol_log(line);
+    }
+}
+
 pub trait AccountCheck {
     fn check(account: &AccountInfo) -> Result<(), ProgramError>;
 }
@@ -48,12 +160,15 @@ pub trait MintInit {
     ) -> ProgramResult;
 }
 
+/// Creates a token account at `account`, a program address signed for by `seeds`, held by
+/// `owner`.
 pub trait TokenInit {
     fn init(
         account: &AccountInfo,
         mint: &AccountInfo,
         payer: &AccountInfo,
         owner: &[u8; 32],
+        seeds: &[Seed],
     ) -> ProgramResult;
 
     fn init_if_needed(
@@ -61,5 +176,6 @@ pub trait TokenInit {
         mint: &AccountInfo,
         payer: &AccountInfo,
         owner: &[u8; 32],
+        seeds: &[Seed],
     ) -> ProgramResult;
 }
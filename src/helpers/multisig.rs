@@ -0,0 +1,81 @@
+use pinocchio::{ProgramResult, account_info::AccountInfo, program_error::ProgramError};
+
+use crate::{FundraiserError, MULTISIG_MAX_SIGNERS};
+
+/// Size of an SPL token multisig account, the same under both token programs.
+const MULTISIG_LEN: usize = 355;
+const MULTISIG_M_OFFSET: usize = 0;
+const MULTISIG_N_OFFSET: usize = 1;
+const MULTISIG_SIGNERS_OFFSET: usize = 3;
+
+pub struct MultisigAccount;
+
+impl MultisigAccount {
+    /// Whether `authority` is an SPL token multisig of `token_program`, which signs through
+    /// its signer accounts instead of its own signature.
+    #[inline(always)]
+    pub fn is_multisig(authority: &AccountInfo, token_program: &AccountInfo) -> bool {
+        authority.owner() == token_program.key() && authority.data_len() == MULTISIG_LEN
+    }
+
+    /// Splits the signers of a multisig `authority` off the front of `accounts`: the leading
+    /// run of signer accounts listed by the multisig, ending at the first repeat so that a
+    /// signer may be passed again as a later optional account. Any other authority takes none.
+    pub fn split_signers<'a>(
+        authority: &AccountInfo,
+        token_program: &AccountInfo,
+        accounts: &'a [AccountInfo],
+    ) -> Result<(&'a [AccountInfo], &'a [AccountInfo]), ProgramError> {
+        if !Self::is_multisig(authority, token_program) {
+            return Ok((&[], accounts));
+        }
+
+        let data = authority.try_borrow_data()?;
+        let keys = Self::signer_keys(&data);
+
+        let count = accounts
+            .iter()
+            .enumerate()
+            .take_while(|(i, account)| {
+                account.is_signer()
+                    && keys.clone().any(|key| account.key().as_ref() == key)
+                    && accounts[..*i]
+                        .iter()
+                        .all(|other| other.key() != account.key())
+            })
+            .count();
+
+        Ok(accounts.split_at(count))
+    }
+
+    /// Checks that at least M of the multisig's N signers are among `signers`, each counted
+    /// once however often it is passed.
+    pub fn check_signers(multisig: &AccountInfo, signers: &[AccountInfo]) -> ProgramResult {
+        let data = multisig.try_borrow_data()?;
+        let m = data[MULTISIG_M_OFFSET] as usize;
+
+        let signed = Self::signer_keys(&data)
+            .filter(|key| {
+                signers
+                    .iter()
+                    .any(|signer| signer.is_signer() && signer.key().as_ref() == *key)
+            })
+            .count();
+
+        if m == 0 || signed < m {
+            return Err(FundraiserError::NotEnoughMultisigSigners.into());
+        }
+
+        Ok(())
+    }
+
+    /// The N signer keys listed in a multisig's data.
+    #[inline(always)]
+    fn signer_keys(data: &[u8]) -> impl Iterator<Item = &[u8]> + Clone {
+        let n = data[MULTISIG_N_OFFSET] as usize;
+
+        data[MULTISIG_SIGNERS_OFFSET..]
+            .chunks_exact(32)
+            .take(n.min(MULTISIG_MAX_SIGNERS))
+    }
+}
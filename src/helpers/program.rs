@@ -6,7 +6,7 @@ use pinocchio::{
     pubkey::{Pubkey, create_program_address},
     sysvars::{Sysvar, rent::Rent},
 };
-use pinocchio_system::instructions::CreateAccount;
+use pinocchio_system::instructions::{Allocate, Assign, CreateAccount, Transfer};
 
 use crate::{AccountCheck, AccountLoad, FundraiserError, Space};
 
@@ -21,7 +21,7 @@ pub trait SetInner: Sized {
 impl AccountCheck for ProgramAccount {
     fn check(account: &AccountInfo) -> Result<(), ProgramError> {
         if account.owner().ne(&crate::ID) {
-            return Err(ProgramError::InvalidAccountOwner);
+            return Err(FundraiserError::AccountNotOwnedByProgram.into());
         }
 
         Ok(())
@@ -29,28 +29,90 @@ impl AccountCheck for ProgramAccount {
 }
 
 impl ProgramAccount {
-    pub fn init<'a, T: Sized>(
+    /// The byte [`ProgramAccount::close`] leaves at the start of a closed account.
+    pub const TOMBSTONE: u8 = 0xff;
+
+    /// Creates `account` as a `T` at a program address signed for by `seeds`. An address that
+    /// was sent lamports beforehand, which would make `CreateAccount` fail, is topped up to rent
+    /// exemption, allocated and assigned instead.
+    #[inline(always)]
+    pub fn init<T: Space>(
+        payer: &AccountInfo,
+        account: &AccountInfo,
+        seeds: &[Seed],
+    ) -> ProgramResult {
+        Self::init_with_payer::<T>(None, payer, account, seeds)
+    }
+
+    /// [`ProgramAccount::init`] with the rent funded by `rent_payer` when one is passed, so a
+    /// sponsor can cover it in place of `payer`.
+    pub fn init_with_payer<T: Space>(
+        rent_payer: Option<&AccountInfo>,
+        payer: &AccountInfo,
+        account: &AccountInfo,
+        seeds: &[Seed],
+    ) -> ProgramResult {
+        if account.owner().eq(&crate::ID) && !account.data_is_empty() {
+            return Err(FundraiserError::AccountAlreadyInitialized.into());
+        }
+
+        Self::create(
+            rent_payer.unwrap_or(payer),
+            account,
+            T::LEN,
+            &crate::ID,
+            seeds,
+        )
+    }
+
+    /// Creates `account` with `space` bytes owned by `owner` at a program address signed for by
+    /// `seeds`, topping up, allocating and assigning an address that was sent lamports
+    /// beforehand instead of letting `CreateAccount` fail on it.
+    pub fn create(
         payer: &AccountInfo,
         account: &AccountInfo,
-        seeds: &[Seed<'a>],
         space: usize,
+        owner: &Pubkey,
+        seeds: &[Seed],
     ) -> ProgramResult {
         let lamports = Rent::get()?.minimum_balance(space);
 
         let signer = [Signer::from(seeds)];
 
-        CreateAccount {
-            from: payer,
-            to: account,
-            lamports,
+        if account.lamports() == 0 {
+            return CreateAccount {
+                from: payer,
+                to: account,
+                lamports,
+                space: space as u64,
+                owner,
+            }
+            .invoke_signed(&signer);
+        }
+
+        if account.lamports() < lamports {
+            Transfer {
+                from: payer,
+                to: account,
+                lamports: lamports - account.lamports(),
+            }
+            .invoke()?;
+        }
+
+        Allocate {
+            account,
             space: space as u64,
-            owner: &crate::ID,
         }
         .invoke_signed(&signer)?;
 
-        Ok(())
+        Assign { account, owner }.invoke_signed(&signer)
     }
 
+    /// Creates `account` as a `T` unless it already holds one. An account closed earlier in the
+    /// same transaction already reads as unowned and is simply created again. One this program
+    /// owns but that is shorter than a `T`, from before the type grew, is topped up to rent
+    /// exemption and initialized afresh. Accounts longer than `T::LEN` are left alone, since
+    /// some types (such as `Updates`) grow.
     pub fn init_if_needed<T: AccountLoad + Space + SetInner + Sized>(
         seeds: &[Seed<'_>],
         account: &AccountInfo,
@@ -58,21 +120,43 @@ impl ProgramAccount {
         params: T::Params,
     ) -> ProgramResult {
         if Self::check(account).is_err() {
-            Self::init::<T>(payer, account, seeds, T::LEN)?;
+            Self::init::<T>(payer, account, seeds)?;
+        } else if account.data_len() < T::LEN {
+            Self::realloc(account, payer, T::LEN)?;
+            account.try_borrow_mut_data()?.fill(0);
+        } else {
+            return Ok(());
+        }
 
-            let mut data = account.try_borrow_mut_data()?;
-            let account = T::load_mut(data.as_mut())?;
+        let mut data = account.try_borrow_mut_data()?;
+        let account = T::load_mut(data.as_mut())?;
 
-            account.set_inner(params);
-        }
+        account.set_inner(params);
 
         Ok(())
     }
 
+    /// Grows `account`, which this program owns, to `space` bytes, with `payer` topping it up
+    /// to rent exemption at the new size.
+    pub fn realloc(account: &AccountInfo, payer: &AccountInfo, space: usize) -> ProgramResult {
+        let lamports = Rent::get()?.minimum_balance(space);
+
+        if account.lamports() < lamports {
+            Transfer {
+                from: payer,
+                to: account,
+                lamports: lamports - account.lamports(),
+            }
+            .invoke()?;
+        }
+
+        account.resize(space)
+    }
+
     pub fn close(account: &AccountInfo, destination: &AccountInfo) -> ProgramResult {
         {
             let mut data = account.try_borrow_mut_data()?;
-            data[0] = 0xff;
+            data[0] = Self::TOMBSTONE;
         }
 
         *destination.try_borrow_mut_lamports()? += *account.try_borrow_lamports()?;
@@ -80,6 +164,22 @@ impl ProgramAccount {
         account.close()
     }
 
+    /// Moves `lamports` out of `account`, which this program owns, into `destination`.
+    pub fn transfer_lamports(
+        account: &AccountInfo,
+        destination: &AccountInfo,
+        lamports: u64,
+    ) -> ProgramResult {
+        let mut balance = account.try_borrow_mut_lamports()?;
+
+        *balance = balance
+            .checked_sub(lamports)
+            .ok_or(ProgramError::InsufficientFunds)?;
+        *destination.try_borrow_mut_lamports()? += lamports;
+
+        Ok(())
+    }
+
     pub fn validate(seeds: &[&[u8]], address: Pubkey) -> ProgramResult {
         let pda = create_program_address(seeds, &crate::ID)?;
 
@@ -90,3 +190,15 @@ impl ProgramAccount {
         Ok(())
     }
 }
+
+pub struct ProgramIdCheck;
+
+impl ProgramIdCheck {
+    pub fn check(account: &AccountInfo, program_id: &Pubkey) -> Result<(), ProgramError> {
+        if account.key().ne(program_id) {
+            return Err(FundraiserError::ProgramIdMismatch.into());
+        }
+
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
 use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
 
-use crate::{AccountCheck, FundraiserError};
+use crate::{AccountCheck, FundraiserError, WritableAccount};
 
 pub struct SignerAccount;
 
@@ -12,3 +12,22 @@ impl AccountCheck for SignerAccount {
         Ok(())
     }
 }
+
+impl SignerAccount {
+    /// Splits an optional rent payer off the end of `accounts`, recognised as a signer and
+    /// passed ahead of any stats account. It funds the accounts an instruction creates in place
+    /// of the maker or authority, who still signs for everything else.
+    #[inline(always)]
+    pub fn split_rent_payer(
+        accounts: &[AccountInfo],
+    ) -> Result<(Option<&AccountInfo>, &[AccountInfo]), ProgramError> {
+        match accounts.split_last() {
+            Some((rent_payer, accounts)) if rent_payer.is_signer() => {
+                WritableAccount::check(rent_payer)?;
+
+                Ok((Some(rent_payer), accounts))
+            }
+            _ => Ok((None, accounts)),
+        }
+    }
+}
@@ -1,24 +1,46 @@
 use pinocchio::{
-    ProgramResult,
-    account_info::AccountInfo,
-    program_error::ProgramError,
-    sysvars::{Sysvar, rent::Rent},
+    ProgramResult, account_info::AccountInfo, instruction::Seed, program_error::ProgramError,
+};
+use pinocchio_token::{
+    instructions::{InitializeAccount3, SyncNative},
+    state::TokenAccount as TokenAccountState,
 };
-use pinocchio_system::instructions::CreateAccount;
-use pinocchio_token::{instructions::InitializeAccount3, state::TokenAccount as TokenAccountState};
 
-use crate::{AccountCheck, TokenInit};
+use crate::{AccountCheck, FundraiserError, ProgramAccount, TokenInit};
 
 pub struct TokenAccount;
 
 impl AccountCheck for TokenAccount {
     fn check(account: &AccountInfo) -> Result<(), ProgramError> {
         if account.owner().ne(&pinocchio_token::ID) {
-            return Err(ProgramError::InvalidAccountOwner);
+            return Err(FundraiserError::TokenAccountNotOwnedByTokenProgram.into());
         }
 
         if account.data_len().ne(&TokenAccountState::LEN) {
-            return Err(ProgramError::InvalidAccountData);
+            return Err(FundraiserError::InvalidTokenAccountData.into());
+        }
+
+        Ok(())
+    }
+}
+
+impl TokenAccount {
+    /// Brings the token amount of `account`, a wrapped SOL account, up to date with lamports
+    /// sent straight to it, which the token program only counts after a `SyncNative`.
+    pub fn sync_native_if_stale(account: &AccountInfo) -> ProgramResult {
+        let token_account = TokenAccountState::from_account_info(account)?;
+        let wrapped = account
+            .lamports()
+            .saturating_sub(token_account.native_amount().unwrap_or(account.lamports()));
+        let stale = wrapped > token_account.amount();
+
+        drop(token_account);
+
+        if stale {
+            SyncNative {
+                native_token: account,
+            }
+            .invoke()?;
         }
 
         Ok(())
@@ -31,17 +53,15 @@ impl TokenInit for TokenAccount {
         mint: &AccountInfo,
         payer: &AccountInfo,
         owner: &[u8; 32],
+        seeds: &[Seed],
     ) -> ProgramResult {
-        let lamports = Rent::get()?.minimum_balance(TokenAccountState::LEN);
-
-        CreateAccount {
-            from: payer,
-            to: account,
-            lamports,
-            space: TokenAccountState::LEN as u64,
-            owner: &pinocchio_token::ID,
-        }
-        .invoke()?;
+        ProgramAccount::create(
+            payer,
+            account,
+            TokenAccountState::LEN,
+            &pinocchio_token::ID,
+            seeds,
+        )?;
 
         InitializeAccount3 {
             account,
@@ -56,10 +76,11 @@ impl TokenInit for TokenAccount {
         mint: &AccountInfo,
         payer: &AccountInfo,
         owner: &[u8; 32],
+        seeds: &[Seed],
     ) -> ProgramResult {
         match Self::check(account) {
             Ok(_) => Ok(()),
-            Err(_) => Self::init(account, mint, payer, owner),
+            Err(_) => Self::init(account, mint, payer, owner, seeds),
         }
     }
 }
@@ -1,34 +1,64 @@
 use pinocchio::{
     ProgramResult,
     account_info::AccountInfo,
+    cpi::invoke_signed_with_bounds,
+    instruction::{AccountMeta, Instruction, Seed, Signer},
     program_error::ProgramError,
-    sysvars::{Sysvar, rent::Rent},
+    pubkey::Pubkey,
 };
-use pinocchio_system::instructions::CreateAccount;
 use pinocchio_token_2022::{
-    instructions::InitializeAccount3, state::TokenAccount as TokenAccountState,
+    instructions::{InitializeAccount3, MintTo},
+    state::TokenAccount as TokenAccountState,
 };
 
 use crate::{
-    AccountCheck, TokenInit,
-    helpers::{TOKEN_2022_ACCOUNT_DISCRIMINATOR_OFFSET, TOKEN_2022_TOKEN_ACCOUNT_DISCRIMINATOR},
+    AccountCheck, FundraiserError, MULTISIG_MAX_SIGNERS, ProgramAccount, ProgramIdCheck,
+    TokenAccountInterface, TokenInit, WritableAccount,
+    helpers::{
+        TOKEN_2022_ACCOUNT_DISCRIMINATOR_OFFSET, TOKEN_2022_TOKEN_ACCOUNT_DISCRIMINATOR,
+        token_2022_extension,
+    },
 };
 
+/// Extension type of `MemoTransfer` in Token-2022's TLV data.
+const MEMO_TRANSFER_EXTENSION: u16 = 8;
+
+/// Token account, mint and authority, ahead of any multisig signers.
+const BURN_FIXED_ACCOUNTS: usize = 3;
+const BURN_MAX_ACCOUNTS: usize = BURN_FIXED_ACCOUNTS + MULTISIG_MAX_SIGNERS;
+const BURN_DISCRIMINATOR: u8 = 8;
+
 pub struct TokenAccount2022Account;
 
+impl TokenAccount2022Account {
+    /// Whether `account` has the `MemoTransfer` extension with incoming memos required. Legacy
+    /// token accounts carry no extensions.
+    pub fn requires_incoming_memo(account: &AccountInfo) -> Result<bool, ProgramError> {
+        if account.owner().ne(&pinocchio_token_2022::ID) {
+            return Ok(false);
+        }
+
+        let data = account.try_borrow_data()?;
+        let extension = token_2022_extension(&data, MEMO_TRANSFER_EXTENSION)?;
+
+        Ok(extension.and_then(|value| value.first()) == Some(&1))
+    }
+}
+
 impl AccountCheck for TokenAccount2022Account {
     fn check(account: &AccountInfo) -> Result<(), ProgramError> {
         if account.owner().ne(&pinocchio_token_2022::ID) {
-            return Err(ProgramError::InvalidAccountOwner);
+            return Err(FundraiserError::TokenAccountNotOwnedByTokenProgram.into());
         }
 
         let data = account.try_borrow_data()?;
 
         if data.len().ne(&TokenAccountState::BASE_LEN)
-            && data[TOKEN_2022_ACCOUNT_DISCRIMINATOR_OFFSET]
-                .ne(&TOKEN_2022_TOKEN_ACCOUNT_DISCRIMINATOR)
+            && data
+                .get(TOKEN_2022_ACCOUNT_DISCRIMINATOR_OFFSET)
+                .ne(&Some(&TOKEN_2022_TOKEN_ACCOUNT_DISCRIMINATOR))
         {
-            return Err(ProgramError::InvalidAccountData);
+            return Err(FundraiserError::InvalidTokenAccountData.into());
         }
 
         Ok(())
@@ -41,17 +71,15 @@ impl TokenInit for TokenAccount2022Account {
         mint: &AccountInfo,
         payer: &AccountInfo,
         owner: &[u8; 32],
+        seeds: &[Seed],
     ) -> ProgramResult {
-        let lamports = Rent::get()?.minimum_balance(TokenAccountState::BASE_LEN);
-
-        CreateAccount {
-            from: payer,
-            to: account,
-            lamports,
-            space: TokenAccountState::BASE_LEN as u64,
-            owner: &pinocchio_token_2022::ID,
-        }
-        .invoke()?;
+        ProgramAccount::create(
+            payer,
+            account,
+            TokenAccountState::BASE_LEN,
+            &pinocchio_token_2022::ID,
+            seeds,
+        )?;
 
         InitializeAccount3 {
             account,
@@ -67,10 +95,118 @@ impl TokenInit for TokenAccount2022Account {
         mint: &AccountInfo,
         payer: &AccountInfo,
         owner: &[u8; 32],
+        seeds: &[Seed],
     ) -> ProgramResult {
         match Self::check(account) {
             Ok(_) => Ok(()),
-            Err(_) => Self::init(account, mint, payer, owner),
+            Err(_) => Self::init(account, mint, payer, owner, seeds),
+        }
+    }
+}
+
+/// A fundraise's receipt mint, the receipt token account of the position's holder and the
+/// Token-2022 program, passed to the instructions that mint or burn receipts.
+pub struct ReceiptAccounts<'a> {
+    pub mint: &'a AccountInfo,
+    pub token_account: &'a AccountInfo,
+    pub token_program: &'a AccountInfo,
+}
+
+impl<'a> ReceiptAccounts<'a> {
+    /// Splits the receipt accounts off the end of `accounts` when the fundraise issues receipts
+    /// of `receipt_mint`. They follow any transfer hook accounts, ahead of any rent payer, memo
+    /// program or stats account. Fundraises without receipts take none.
+    pub fn split_trailing(
+        receipt_mint: &Pubkey,
+        accounts: &'a [AccountInfo],
+    ) -> Result<(Option<Self>, &'a [AccountInfo]), ProgramError> {
+        if receipt_mint == &Pubkey::default() {
+            return Ok((None, accounts));
+        }
+
+        let Some((rest, [mint, token_account, token_program])) = accounts.split_last_chunk() else {
+            return Err(FundraiserError::MissingAccounts.into());
+        };
+
+        if mint.key() != receipt_mint {
+            return Err(FundraiserError::InvalidAddress.into());
+        }
+
+        WritableAccount::check(mint)?;
+        WritableAccount::check(token_account)?;
+        ProgramIdCheck::check(token_program, &pinocchio_token_2022::ID)?;
+
+        Ok((
+            Some(Self {
+                mint,
+                token_account,
+                token_program,
+            }),
+            rest,
+        ))
+    }
+
+    /// Checks that the receipt token account is held by `holder`.
+    #[inline(always)]
+    pub fn check_holder(&self, holder: &Pubkey) -> ProgramResult {
+        TokenAccountInterface::check_mint_and_owner(self.token_account, self.mint.key(), holder)
+    }
+
+    /// Mints `amount` receipts to the holder, signed for by the fundraise as the mint's
+    /// authority.
+    pub fn mint_to(
+        &self,
+        fundraise: &AccountInfo,
+        amount: u64,
+        fundraise_seeds: &[Seed],
+    ) -> ProgramResult {
+        MintTo {
+            mint: self.mint,
+            account: self.token_account,
+            mint_authority: fundraise,
+            amount,
+            token_program: self.token_program.key(),
         }
+        .invoke_signed(&[Signer::from(fundraise_seeds)])
+    }
+
+    /// Burns `amount` of the holder's receipts. A holder that is a multisig signs through
+    /// `multisig_signers` instead.
+    pub fn burn(
+        &self,
+        holder: &AccountInfo,
+        multisig_signers: &[AccountInfo],
+        amount: u64,
+    ) -> ProgramResult {
+        let mut data = [0u8; 9];
+        data[0] = BURN_DISCRIMINATOR;
+        data[1..].copy_from_slice(&amount.to_le_bytes());
+
+        let mut metas: [AccountMeta; BURN_MAX_ACCOUNTS] =
+            core::array::from_fn(|_| AccountMeta::readonly(holder.key()));
+        let mut infos = [holder; BURN_MAX_ACCOUNTS];
+
+        metas[0] = AccountMeta::writable(self.token_account.key());
+        metas[1] = AccountMeta::writable(self.mint.key());
+        metas[2] = AccountMeta::new(holder.key(), false, multisig_signers.is_empty());
+        infos[0] = self.token_account;
+        infos[1] = self.mint;
+
+        for (i, signer) in multisig_signers.iter().enumerate() {
+            metas[BURN_FIXED_ACCOUNTS + i] = AccountMeta::readonly_signer(signer.key());
+            infos[BURN_FIXED_ACCOUNTS + i] = signer;
+        }
+
+        let len = BURN_FIXED_ACCOUNTS + multisig_signers.len();
+
+        invoke_signed_with_bounds::<BURN_MAX_ACCOUNTS>(
+            &Instruction {
+                program_id: self.token_program.key(),
+                data: &data,
+                accounts: &metas[..len],
+            },
+            &infos[..len],
+            &[],
+        )
     }
 }
@@ -1,26 +1,335 @@
-use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
-use pinocchio_token_2022::state::TokenAccount as TokenAccountState;
+use pinocchio::{
+    ProgramResult, account_info::AccountInfo, instruction::Seed, program_error::ProgramError,
+    pubkey::Pubkey,
+};
 
-use crate::AccountCheck;
+use crate::{AccountCheck, FundraiserError, TokenAccount, TokenAccount2022Account, TokenInit};
+
+/// Offset of the state byte in the token account layout, which Token-2022 extends without
+/// moving.
+const TOKEN_ACCOUNT_STATE_OFFSET: usize = 108;
+
+/// State byte of a token account its mint's freeze authority has frozen.
+const TOKEN_ACCOUNT_STATE_FROZEN: u8 = 2;
 
 pub struct TokenAccountInterface;
 
 impl AccountCheck for TokenAccountInterface {
     fn check(account: &AccountInfo) -> Result<(), ProgramError> {
-        if account.owner().ne(&pinocchio_token_2022::ID) {
-            if account.owner().ne(&pinocchio_token::ID) {
-                return Err(ProgramError::InvalidAccountOwner);
-            } else if account.data_len().ne(&TokenAccountState::BASE_LEN) {
-                return Err(ProgramError::InvalidAccountData);
+        match *account.owner() {
+            pinocchio_token::ID => TokenAccount::check(account),
+            pinocchio_token_2022::ID => TokenAccount2022Account::check(account),
+            _ => Err(FundraiserError::TokenAccountNotOwnedByTokenProgram.into()),
+        }
+    }
+}
+
+/// Creates the token account under whichever token program owns `mint`.
+impl TokenInit for TokenAccountInterface {
+    fn init(
+        account: &AccountInfo,
+        mint: &AccountInfo,
+        payer: &AccountInfo,
+        owner: &[u8; 32],
+        seeds: &[Seed],
+    ) -> ProgramResult {
+        match *mint.owner() {
+            pinocchio_token::ID => TokenAccount::init(account, mint, payer, owner, seeds),
+            pinocchio_token_2022::ID => {
+                TokenAccount2022Account::init(account, mint, payer, owner, seeds)
             }
-        } else {
-            let data = account.try_borrow_data()?;
+            _ => Err(FundraiserError::InvalidTokenProgram.into()),
+        }
+    }
 
-            if data.len().ne(&TokenAccountState::BASE_LEN) {
-                return Err(ProgramError::InvalidAccountData);
+    fn init_if_needed(
+        account: &AccountInfo,
+        mint: &AccountInfo,
+        payer: &AccountInfo,
+        owner: &[u8; 32],
+        seeds: &[Seed],
+    ) -> ProgramResult {
+        match Self::check(account) {
+            Ok(_) => Ok(()),
+            Err(_) => Self::init(account, mint, payer, owner, seeds),
+        }
+    }
+}
+
+impl TokenAccountInterface {
+    /// Checks that `account` is a token account of `mint` held by `owner`, without requiring it
+    /// to sit at the owner's associated token address.
+    pub fn check_mint_and_owner(
+        account: &AccountInfo,
+        mint: &Pubkey,
+        owner: &Pubkey,
+    ) -> Result<(), ProgramError> {
+        Self::check(account)?;
+
+        let (account_mint, account_owner) = match *account.owner() {
+            pinocchio_token::ID => {
+                let token_account = unsafe {
+                    pinocchio_token::state::TokenAccount::from_account_info_unchecked(account)?
+                };
+                (*token_account.mint(), *token_account.owner())
             }
+            _ => {
+                let token_account = unsafe {
+                    pinocchio_token_2022::state::TokenAccount::from_account_info_unchecked(account)?
+                };
+                (*token_account.mint(), *token_account.owner())
+            }
+        };
+
+        if account_mint.ne(mint) {
+            return Err(FundraiserError::InvalidMintToRaise.into());
+        }
+
+        if account_owner.ne(owner) {
+            return Err(FundraiserError::InvalidAddress.into());
         }
 
         Ok(())
     }
+
+    /// Balance of a token account of either token program.
+    pub fn amount(account: &AccountInfo) -> Result<u64, ProgramError> {
+        match *account.owner() {
+            pinocchio_token::ID => {
+                let token_account = unsafe {
+                    pinocchio_token::state::TokenAccount::from_account_info_unchecked(account)?
+                };
+                Ok(token_account.amount())
+            }
+            pinocchio_token_2022::ID => {
+                let token_account = unsafe {
+                    pinocchio_token_2022::state::TokenAccount::from_account_info_unchecked(account)?
+                };
+                Ok(token_account.amount())
+            }
+            _ => Err(FundraiserError::TokenAccountNotOwnedByTokenProgram.into()),
+        }
+    }
+
+    /// Whether `account`, a token account of either token program, has been frozen, so any
+    /// transfer in or out of it would fail. Anything that is not a token account reads as not
+    /// frozen, for the transfer itself to reject.
+    pub fn is_frozen(account: &AccountInfo) -> bool {
+        if !matches!(
+            *account.owner(),
+            pinocchio_token::ID | pinocchio_token_2022::ID
+        ) {
+            return false;
+        }
+
+        account.try_borrow_data().is_ok_and(|data| {
+            data.get(TOKEN_ACCOUNT_STATE_OFFSET) == Some(&TOKEN_ACCOUNT_STATE_FROZEN)
+        })
+    }
+
+    /// Checks that `delegate` is approved to move at least `amount` out of `account`.
+    pub fn check_delegate(account: &AccountInfo, delegate: &Pubkey, amount: u64) -> ProgramResult {
+        let (account_delegate, delegated_amount) = match *account.owner() {
+            pinocchio_token::ID => {
+                let token_account = unsafe {
+                    pinocchio_token::state::TokenAccount::from_account_info_unchecked(account)?
+                };
+                (
+                    token_account.delegate().copied(),
+                    token_account.delegated_amount(),
+                )
+            }
+            _ => {
+                let token_account = unsafe {
+                    pinocchio_token_2022::state::TokenAccount::from_account_info_unchecked(account)?
+                };
+                (
+                    token_account.delegate().copied(),
+                    token_account.delegated_amount(),
+                )
+            }
+        };
+
+        if account_delegate.as_ref() != Some(delegate) {
+            return Err(FundraiserError::InvalidDelegate.into());
+        }
+
+        if delegated_amount < amount {
+            return Err(FundraiserError::InsufficientDelegation.into());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        AccountCheck, FundraiserError, TokenAccountInterface,
+        helpers::{
+            TOKEN_2022_ACCOUNT_DISCRIMINATOR_OFFSET, TOKEN_2022_TOKEN_ACCOUNT_DISCRIMINATOR,
+            token_interface::{TOKEN_ACCOUNT_STATE_FROZEN, TOKEN_ACCOUNT_STATE_OFFSET},
+        },
+        tests::utils::RawAccount,
+    };
+
+    fn token_account_2022_with_extensions() -> Vec<u8> {
+        // account type byte followed by an ImmutableOwner TLV entry, as created by the ATA program
+        let mut data = vec![0u8; TOKEN_2022_ACCOUNT_DISCRIMINATOR_OFFSET + 1 + 4];
+        data[TOKEN_2022_ACCOUNT_DISCRIMINATOR_OFFSET] = TOKEN_2022_TOKEN_ACCOUNT_DISCRIMINATOR;
+        data[TOKEN_2022_ACCOUNT_DISCRIMINATOR_OFFSET + 1] = 7;
+        data
+    }
+
+    #[test]
+    fn accept_legacy_token_account() {
+        let data = vec![0u8; pinocchio_token::state::TokenAccount::LEN];
+
+        assert!(
+            TokenAccountInterface::check(&RawAccount::new(&pinocchio_token::ID, &data).info())
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn accept_token_2022_token_account() {
+        let base = vec![0u8; pinocchio_token_2022::state::TokenAccount::BASE_LEN];
+        let extended = token_account_2022_with_extensions();
+
+        assert!(
+            TokenAccountInterface::check(&RawAccount::new(&pinocchio_token_2022::ID, &base).info())
+                .is_ok()
+        );
+        assert!(
+            TokenAccountInterface::check(
+                &RawAccount::new(&pinocchio_token_2022::ID, &extended).info()
+            )
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn reject_token_2022_token_account_under_legacy_owner() {
+        let extended = token_account_2022_with_extensions();
+
+        assert_eq!(
+            TokenAccountInterface::check(&RawAccount::new(&pinocchio_token::ID, &extended).info()),
+            Err(FundraiserError::InvalidTokenAccountData.into())
+        );
+    }
+
+    #[test]
+    fn reject_token_account_under_foreign_owner() {
+        let data = vec![0u8; pinocchio_token::state::TokenAccount::LEN];
+
+        assert_eq!(
+            TokenAccountInterface::check(&RawAccount::new(&pinocchio_system::ID, &data).info()),
+            Err(FundraiserError::TokenAccountNotOwnedByTokenProgram.into())
+        );
+    }
+
+    #[test]
+    fn reject_mint_as_token_account() {
+        let mint = vec![0u8; pinocchio_token::state::Mint::LEN];
+        let mut extended_mint = token_account_2022_with_extensions();
+        extended_mint[TOKEN_2022_ACCOUNT_DISCRIMINATOR_OFFSET] = 1;
+
+        assert_eq!(
+            TokenAccountInterface::check(&RawAccount::new(&pinocchio_token::ID, &mint).info()),
+            Err(FundraiserError::InvalidTokenAccountData.into())
+        );
+        assert_eq!(
+            TokenAccountInterface::check(
+                &RawAccount::new(&pinocchio_token_2022::ID, &extended_mint).info()
+            ),
+            Err(FundraiserError::InvalidTokenAccountData.into())
+        );
+    }
+
+    fn legacy_token_account(mint: &[u8; 32], owner: &[u8; 32]) -> Vec<u8> {
+        let mut data = vec![0u8; pinocchio_token::state::TokenAccount::LEN];
+        data[..32].copy_from_slice(mint);
+        data[32..64].copy_from_slice(owner);
+        data
+    }
+
+    #[test]
+    fn accept_token_account_with_matching_mint_and_owner() {
+        let (mint, owner) = ([1u8; 32], [2u8; 32]);
+        let data = legacy_token_account(&mint, &owner);
+
+        assert!(
+            TokenAccountInterface::check_mint_and_owner(
+                &RawAccount::new(&pinocchio_token::ID, &data).info(),
+                &mint,
+                &owner,
+            )
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn reject_token_account_with_mismatched_mint_or_owner() {
+        let (mint, owner) = ([1u8; 32], [2u8; 32]);
+        let data = legacy_token_account(&mint, &owner);
+
+        assert_eq!(
+            TokenAccountInterface::check_mint_and_owner(
+                &RawAccount::new(&pinocchio_token::ID, &data).info(),
+                &[3u8; 32],
+                &owner,
+            ),
+            Err(FundraiserError::InvalidMintToRaise.into())
+        );
+        assert_eq!(
+            TokenAccountInterface::check_mint_and_owner(
+                &RawAccount::new(&pinocchio_token::ID, &data).info(),
+                &mint,
+                &[3u8; 32],
+            ),
+            Err(FundraiserError::InvalidAddress.into())
+        );
+    }
+
+    #[test]
+    fn read_frozen_state_of_either_program() {
+        let mut legacy = legacy_token_account(&[1u8; 32], &[2u8; 32]);
+        let mut extended = token_account_2022_with_extensions();
+
+        for data in [&mut legacy, &mut extended] {
+            // initialized
+            data[TOKEN_ACCOUNT_STATE_OFFSET] = 1;
+        }
+
+        assert!(!TokenAccountInterface::is_frozen(
+            &RawAccount::new(&pinocchio_token::ID, &legacy).info()
+        ));
+        assert!(!TokenAccountInterface::is_frozen(
+            &RawAccount::new(&pinocchio_token_2022::ID, &extended).info()
+        ));
+
+        for data in [&mut legacy, &mut extended] {
+            data[TOKEN_ACCOUNT_STATE_OFFSET] = TOKEN_ACCOUNT_STATE_FROZEN;
+        }
+
+        assert!(TokenAccountInterface::is_frozen(
+            &RawAccount::new(&pinocchio_token::ID, &legacy).info()
+        ));
+        assert!(TokenAccountInterface::is_frozen(
+            &RawAccount::new(&pinocchio_token_2022::ID, &extended).info()
+        ));
+    }
+
+    #[test]
+    fn non_token_accounts_read_as_not_frozen() {
+        let mut data = legacy_token_account(&[1u8; 32], &[2u8; 32]);
+        data[TOKEN_ACCOUNT_STATE_OFFSET] = TOKEN_ACCOUNT_STATE_FROZEN;
+
+        assert!(!TokenAccountInterface::is_frozen(
+            &RawAccount::new(&pinocchio_system::ID, &data).info()
+        ));
+        assert!(!TokenAccountInterface::is_frozen(
+            &RawAccount::new(&pinocchio_token::ID, &[]).info()
+        ));
+    }
 }
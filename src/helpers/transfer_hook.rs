@@ -0,0 +1,123 @@
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    cpi::invoke_signed_with_bounds,
+    instruction::{AccountMeta, Instruction, Signer},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{FundraiserError, MULTISIG_MAX_SIGNERS, Mint2022Account, TRANSFER_HOOK_MAX_ACCOUNTS};
+
+/// Source, mint, destination and authority, ahead of any multisig signers and transfer hook
+/// accounts.
+const TRANSFER_CHECKED_FIXED_ACCOUNTS: usize = 4;
+const TRANSFER_CHECKED_MAX_ACCOUNTS: usize =
+    TRANSFER_CHECKED_FIXED_ACCOUNTS + MULTISIG_MAX_SIGNERS + TRANSFER_HOOK_MAX_ACCOUNTS;
+const TRANSFER_CHECKED_DISCRIMINATOR: u8 = 12;
+
+pub struct TransferHook;
+
+impl TransferHook {
+    /// Splits the accounts for `mint`'s transfer hook off the end of `accounts`: its program,
+    /// then whatever else the hook needs, such as its validation account. They follow every
+    /// other optional account of the instruction, ahead of any memo program or stats account.
+    /// Mints without a transfer hook take none.
+    pub fn split_trailing<'a>(
+        mint: &AccountInfo,
+        accounts: &'a [AccountInfo],
+    ) -> Result<(&'a [AccountInfo], &'a [AccountInfo]), ProgramError> {
+        let Some(program_id) = Mint2022Account::transfer_hook_program(mint)? else {
+            return Ok((&[], accounts));
+        };
+
+        // Token-2022 would otherwise fail the transfer deep inside its own CPI to the hook
+        let index = accounts
+            .iter()
+            .position(|account| account.key() == &program_id)
+            .ok_or(FundraiserError::MissingTransferHookAccounts)?;
+        let (accounts, hook_accounts) = accounts.split_at(index);
+
+        if hook_accounts.len() > TRANSFER_HOOK_MAX_ACCOUNTS {
+            return Err(FundraiserError::TooManyTransferHookAccounts.into());
+        }
+
+        Ok((hook_accounts, accounts))
+    }
+}
+
+/// Token `TransferChecked` that also forwards `hook_accounts`, for Token-2022 to pass on to the
+/// mint's transfer hook. Token-2022 rejects plain transfers of mints with a transfer hook. An
+/// `authority` that is a multisig signs through `multisig_signers` instead.
+pub struct TransferChecked<'a, 'b> {
+    pub from: &'a AccountInfo,
+    pub mint: &'a AccountInfo,
+    pub to: &'a AccountInfo,
+    pub authority: &'a AccountInfo,
+    pub multisig_signers: &'a [AccountInfo],
+    pub amount: u64,
+    pub decimals: u8,
+    pub hook_accounts: &'a [AccountInfo],
+    pub token_program: &'b Pubkey,
+}
+
+impl TransferChecked<'_, '_> {
+    #[inline(always)]
+    pub fn invoke(&self) -> ProgramResult {
+        self.invoke_signed(&[])
+    }
+
+    pub fn invoke_signed(&self, signers: &[Signer]) -> ProgramResult {
+        let mut data = [0u8; 10];
+        data[0] = TRANSFER_CHECKED_DISCRIMINATOR;
+        data[1..9].copy_from_slice(&self.amount.to_le_bytes());
+        data[9] = self.decimals;
+
+        let mut metas: [AccountMeta; TRANSFER_CHECKED_MAX_ACCOUNTS] =
+            core::array::from_fn(|_| AccountMeta::readonly(self.mint.key()));
+        let mut infos = [self.mint; TRANSFER_CHECKED_MAX_ACCOUNTS];
+
+        metas[0] = AccountMeta::writable(self.from.key());
+        metas[2] = AccountMeta::writable(self.to.key());
+        metas[3] = AccountMeta::new(
+            self.authority.key(),
+            false,
+            self.multisig_signers.is_empty(),
+        );
+        infos[0] = self.from;
+        infos[2] = self.to;
+        infos[3] = self.authority;
+
+        // the token program takes the multisig signers first, then Token-2022 finds the hook's
+        // accounts by key among everything that follows the authority
+        let trailing = self
+            .multisig_signers
+            .iter()
+            .map(|account| AccountMeta::readonly_signer(account.key()))
+            .chain(
+                self.hook_accounts
+                    .iter()
+                    .map(|account| AccountMeta::new(account.key(), account.is_writable(), false)),
+            )
+            .zip(self.multisig_signers.iter().chain(self.hook_accounts));
+
+        for (i, (meta, account)) in trailing.enumerate() {
+            metas[TRANSFER_CHECKED_FIXED_ACCOUNTS + i] = meta;
+            infos[TRANSFER_CHECKED_FIXED_ACCOUNTS + i] = account;
+        }
+
+        let len = TRANSFER_CHECKED_FIXED_ACCOUNTS
+            + self.multisig_signers.len()
+            + self.hook_accounts.len();
+
+        invoke_signed_with_bounds::<TRANSFER_CHECKED_MAX_ACCOUNTS>(
+            &Instruction {
+                program_id: self.token_program,
+                data: &data,
+                accounts: &metas[..len],
+            },
+            &infos[..len],
+            signers,
+        )
+    }
+}
@@ -0,0 +1,45 @@
+use pinocchio::{
+    account_info::AccountInfo, program_error::ProgramError, pubkey::find_program_address,
+};
+
+use crate::{BPF_LOADER_UPGRADEABLE_ID, FundraiserError};
+
+const PROGRAM_DATA_TAG: u32 = 3;
+const PROGRAM_DATA_AUTHORITY_OPTION_OFFSET: usize = 12;
+const PROGRAM_DATA_AUTHORITY_OFFSET: usize = 13;
+
+pub struct UpgradeAuthority;
+
+impl UpgradeAuthority {
+    /// Checks that `program_data` is this program's ProgramData account and that `authority`
+    /// is its current upgrade authority.
+    pub fn check(program_data: &AccountInfo, authority: &AccountInfo) -> Result<(), ProgramError> {
+        if program_data.owner().ne(&BPF_LOADER_UPGRADEABLE_ID) {
+            return Err(FundraiserError::InvalidProgramData.into());
+        }
+
+        if find_program_address(&[&crate::ID], &BPF_LOADER_UPGRADEABLE_ID)
+            .0
+            .ne(program_data.key())
+        {
+            return Err(FundraiserError::InvalidAddress.into());
+        }
+
+        let data = program_data.try_borrow_data()?;
+
+        if data.len() < PROGRAM_DATA_AUTHORITY_OFFSET + 32
+            || data[..4] != PROGRAM_DATA_TAG.to_le_bytes()
+        {
+            return Err(FundraiserError::InvalidProgramData.into());
+        }
+
+        if data[PROGRAM_DATA_AUTHORITY_OPTION_OFFSET] != 1
+            || data[PROGRAM_DATA_AUTHORITY_OFFSET..PROGRAM_DATA_AUTHORITY_OFFSET + 32]
+                .ne(authority.key())
+        {
+            return Err(FundraiserError::Unauthorized.into());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,411 @@
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+};
+
+use crate::{
+    AccountLoad, Fundraise, Liability, MintInterface, Prefix, TokenAccountInterface,
+    TransferChecked,
+};
+
+/// The only way tokens leave one of the fundraise's token accounts under its signature, as a
+/// `TransferChecked` forwarding the accounts of the mint's transfer hook, if any. The transfer
+/// is first debited from `liability`, so it fails with `TransferExceedsLiability` rather than
+/// ever paying out more than is recorded as owed from `from`. The fundraise signs with the
+/// seeds it was created at.
+pub fn vault_transfer(
+    liability: Liability,
+    from: &AccountInfo,
+    mint: &AccountInfo,
+    to: &AccountInfo,
+    amount: u64,
+    fundraise: &AccountInfo,
+    hook_accounts: &[AccountInfo],
+) -> ProgramResult {
+    let balance = TokenAccountInterface::amount(from)?;
+
+    let mut fundraise_data = fundraise.try_borrow_mut_data()?;
+    let fundraise_state = Fundraise::load_mut(fundraise_data.as_mut())?;
+
+    fundraise_state.debit(liability, from.key(), mint.key(), balance, amount)?;
+
+    let decimals = match mint.key() == &fundraise_state.mint_to_raise {
+        true => fundraise_state.mint_decimals,
+        false => MintInterface::decimals(mint)?,
+    };
+
+    let bump = [fundraise_state.bump];
+    let maker = fundraise_state.maker;
+    let signer_seeds = [
+        Seed::from(Fundraise::PREFIX),
+        Seed::from(maker.as_ref()),
+        Seed::from(&bump),
+    ];
+
+    drop(fundraise_data);
+
+    TransferChecked {
+        from,
+        mint,
+        to,
+        authority: fundraise,
+        multisig_signers: &[],
+        amount,
+        decimals,
+        hook_accounts,
+        token_program: mint.owner(),
+    }
+    .invoke_signed(&[Signer::from(&signer_seeds)])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AccountLoad, Fundraise, FundraiserError, Liability, Space};
+
+    const VAULT: [u8; 32] = [3; 32];
+    const MATCH_VAULT: [u8; 32] = [4; 32];
+    const REWARD_VAULT: [u8; 32] = [5; 32];
+    const MINT_TO_RAISE: [u8; 32] = [2; 32];
+    const REWARD_MINT: [u8; 32] = [6; 32];
+    const FOREIGN_MINT: [u8; 32] = [7; 32];
+
+    const INSTRUCTION_SOURCES: &[(&str, &str)] = &[
+        ("cancel", include_str!("../instructions/cancel.rs")),
+        ("claim", include_str!("../instructions/claim.rs")),
+        (
+            "claim_allowlist_slot",
+            include_str!("../instructions/claim_allowlist_slot.rs"),
+        ),
+        (
+            "claim_reward",
+            include_str!("../instructions/claim_reward.rs"),
+        ),
+        (
+            "close_fundraise",
+            include_str!("../instructions/close_fundraise.rs"),
+        ),
+        ("complete", include_str!("../instructions/complete.rs")),
+        ("contribute", include_str!("../instructions/contribute.rs")),
+        (
+            "contribute_delegated",
+            include_str!("../instructions/contribute_delegated.rs"),
+        ),
+        (
+            "contribute_for",
+            include_str!("../instructions/contribute_for.rs"),
+        ),
+        (
+            "crank_refund",
+            include_str!("../instructions/crank_refund.rs"),
+        ),
+        (
+            "create_contributor",
+            include_str!("../instructions/create_contributor.rs"),
+        ),
+        (
+            "deposit_match",
+            include_str!("../instructions/deposit_match.rs"),
+        ),
+        (
+            "deposit_rewards",
+            include_str!("../instructions/deposit_rewards.rs"),
+        ),
+        ("dispatch", include_str!("../instructions/dispatch.rs")),
+        ("donate", include_str!("../instructions/donate.rs")),
+        ("extend", include_str!("../instructions/extend.rs")),
+        ("finalize", include_str!("../instructions/finalize.rs")),
+        (
+            "force_refund",
+            include_str!("../instructions/force_refund.rs"),
+        ),
+        ("initialize", include_str!("../instructions/initialize.rs")),
+        (
+            "initialize_allowlist_page",
+            include_str!("../instructions/initialize_allowlist_page.rs"),
+        ),
+        (
+            "initialize_config",
+            include_str!("../instructions/initialize_config.rs"),
+        ),
+        (
+            "post_update",
+            include_str!("../instructions/post_update.rs"),
+        ),
+        (
+            "reclaim_match",
+            include_str!("../instructions/reclaim_match.rs"),
+        ),
+        ("refund", include_str!("../instructions/refund.rs")),
+        (
+            "set_claim_authority",
+            include_str!("../instructions/set_claim_authority.rs"),
+        ),
+        ("set_paused", include_str!("../instructions/set_paused.rs")),
+        ("snapshot", include_str!("../instructions/snapshot.rs")),
+        ("run_hook", include_str!("../instructions/run_hook.rs")),
+        (
+            "sweep_foreign",
+            include_str!("../instructions/sweep_foreign.rs"),
+        ),
+        (
+            "sweep_rewards",
+            include_str!("../instructions/sweep_rewards.rs"),
+        ),
+        (
+            "transfer_contribution",
+            include_str!("../instructions/transfer_contribution.rs"),
+        ),
+        (
+            "update_config",
+            include_str!("../instructions/update_config.rs"),
+        ),
+        (
+            "update_metadata",
+            include_str!("../instructions/update_metadata.rs"),
+        ),
+    ];
+
+    fn fundraise(current_amount: u64, maker_completion: u64) -> Vec<u8> {
+        let mut data = vec![0u8; Fundraise::LEN];
+        let fundraise = Fundraise::load_mut(&mut data).unwrap();
+
+        fundraise.set_inner([1u8; 32], MINT_TO_RAISE, 5_000, 0, 100, 255);
+        fundraise.set_end_time(100);
+        fundraise.vault = VAULT;
+        fundraise.set_current_amount(current_amount);
+        fundraise.set_maker_completion(maker_completion);
+
+        data
+    }
+
+    #[test]
+    fn liability_covers_contributions_and_completion() {
+        let data = fundraise(3_000, 2_000);
+        let fundraise = Fundraise::load(&data).unwrap();
+
+        assert_eq!(fundraise.outstanding_liability(), 5_000);
+    }
+
+    #[test]
+    fn debit_draws_contributions_first() {
+        let mut data = fundraise(3_000, 2_000);
+        let fundraise = Fundraise::load_mut(&mut data).unwrap();
+
+        fundraise.debit_liability(1_000).unwrap();
+
+        assert_eq!(fundraise.get_current_amount(), 2_000);
+        assert_eq!(fundraise.get_maker_completion(), 2_000);
+
+        fundraise.debit_liability(3_000).unwrap();
+
+        assert_eq!(fundraise.get_current_amount(), 0);
+        assert_eq!(fundraise.get_maker_completion(), 1_000);
+        assert_eq!(fundraise.outstanding_liability(), 1_000);
+    }
+
+    #[test]
+    fn debit_draws_donations_before_completion() {
+        let mut data = fundraise(3_000, 1_000);
+        let fundraise = Fundraise::load_mut(&mut data).unwrap();
+
+        fundraise.set_donated_amount(1_000);
+
+        assert_eq!(fundraise.outstanding_liability(), 5_000);
+
+        fundraise.debit_liability(3_500).unwrap();
+
+        assert_eq!(fundraise.get_current_amount(), 0);
+        assert_eq!(fundraise.get_donated_amount(), 500);
+        assert_eq!(fundraise.get_maker_completion(), 1_000);
+
+        fundraise.debit_liability(1_000).unwrap();
+
+        assert_eq!(fundraise.get_donated_amount(), 0);
+        assert_eq!(fundraise.get_maker_completion(), 500);
+    }
+
+    #[test]
+    fn throw_if_debit_exceeds_liability() {
+        let mut data = fundraise(3_000, 2_000);
+        let fundraise = Fundraise::load_mut(&mut data).unwrap();
+
+        assert_eq!(
+            fundraise.debit_liability(5_001),
+            Err(FundraiserError::TransferExceedsLiability.into())
+        );
+        assert_eq!(fundraise.outstanding_liability(), 5_000);
+
+        fundraise.debit_liability(5_000).unwrap();
+
+        assert_eq!(
+            fundraise.debit_liability(1),
+            Err(FundraiserError::TransferExceedsLiability.into())
+        );
+    }
+
+    #[test]
+    fn raised_is_debited_from_the_vault_only() {
+        let mut data = fundraise(3_000, 0);
+        let fundraise = Fundraise::load_mut(&mut data).unwrap();
+        let exceeds = Err(FundraiserError::TransferExceedsLiability.into());
+
+        assert_eq!(
+            fundraise.debit(Liability::Raised, &MATCH_VAULT, &MINT_TO_RAISE, 3_000, 1),
+            exceeds
+        );
+
+        fundraise
+            .debit(Liability::Raised, &VAULT, &MINT_TO_RAISE, 3_000, 1_000)
+            .unwrap();
+
+        assert_eq!(fundraise.get_current_amount(), 2_000);
+    }
+
+    #[test]
+    fn surplus_is_what_the_vault_holds_past_its_liability() {
+        let mut data = fundraise(3_000, 0);
+        let fundraise = Fundraise::load_mut(&mut data).unwrap();
+
+        assert_eq!(
+            fundraise.debit(Liability::Surplus, &VAULT, &MINT_TO_RAISE, 3_500, 501),
+            Err(FundraiserError::TransferExceedsLiability.into())
+        );
+
+        fundraise
+            .debit(Liability::Surplus, &VAULT, &MINT_TO_RAISE, 3_500, 500)
+            .unwrap();
+
+        // nothing recorded is drawn down by a surplus
+        assert_eq!(fundraise.outstanding_liability(), 3_000);
+    }
+
+    #[test]
+    fn rewards_are_drawn_from_those_declared() {
+        let mut data = fundraise(0, 0);
+        let fundraise = Fundraise::load_mut(&mut data).unwrap();
+        let exceeds = Err(FundraiserError::TransferExceedsLiability.into());
+
+        fundraise.set_rewards(REWARD_MINT, 1_000);
+        fundraise
+            .debit(Liability::Rewards, &REWARD_VAULT, &REWARD_MINT, 1_500, 600)
+            .unwrap();
+
+        assert_eq!(fundraise.get_reward_paid(), 600);
+        assert_eq!(fundraise.outstanding_rewards(), 400);
+        assert_eq!(
+            fundraise.debit(Liability::Rewards, &REWARD_VAULT, &REWARD_MINT, 900, 401),
+            exceeds
+        );
+        assert_eq!(
+            fundraise.debit(Liability::Rewards, &VAULT, &MINT_TO_RAISE, 900, 400),
+            exceeds
+        );
+
+        // past what is still owed, the reward vault's surplus
+        fundraise
+            .debit(Liability::Surplus, &REWARD_VAULT, &REWARD_MINT, 900, 500)
+            .unwrap();
+
+        assert_eq!(
+            fundraise.debit(Liability::Surplus, &REWARD_VAULT, &REWARD_MINT, 900, 501),
+            exceeds
+        );
+    }
+
+    #[test]
+    fn match_is_drawn_from_the_pool_outside_the_vault() {
+        let mut data = fundraise(3_000, 0);
+        let fundraise = Fundraise::load_mut(&mut data).unwrap();
+        let exceeds = Err(FundraiserError::TransferExceedsLiability.into());
+
+        fundraise.set_match_pool(2_000);
+
+        assert_eq!(
+            fundraise.debit(Liability::Match, &VAULT, &MINT_TO_RAISE, 3_000, 1_000),
+            exceeds
+        );
+
+        fundraise
+            .debit(Liability::Match, &MATCH_VAULT, &MINT_TO_RAISE, 2_000, 1_500)
+            .unwrap();
+
+        assert_eq!(fundraise.get_match_pool(), 500);
+        assert_eq!(fundraise.get_current_amount(), 3_000);
+        assert_eq!(
+            fundraise.debit(Liability::Match, &MATCH_VAULT, &MINT_TO_RAISE, 2_000, 501),
+            exceeds
+        );
+    }
+
+    #[test]
+    fn foreign_excludes_every_mint_the_fundraise_records() {
+        let mut data = fundraise(3_000, 0);
+        let fundraise = Fundraise::load_mut(&mut data).unwrap();
+        let exceeds = Err(FundraiserError::TransferExceedsLiability.into());
+
+        fundraise.set_rewards(REWARD_MINT, 1_000);
+
+        for mint in [MINT_TO_RAISE, REWARD_MINT] {
+            assert_eq!(
+                fundraise.debit(Liability::Foreign, &[8; 32], &mint, 1_000, 1),
+                exceeds
+            );
+        }
+
+        fundraise
+            .debit(Liability::Foreign, &[8; 32], &FOREIGN_MINT, 1_000, 1_000)
+            .unwrap();
+
+        assert_eq!(
+            fundraise.debit(Liability::Foreign, &[8; 32], &FOREIGN_MINT, 1_000, 1_001),
+            exceeds
+        );
+    }
+
+    #[test]
+    fn vault_transfer_is_the_only_signed_transfer() {
+        let helper = include_str!("vault.rs")
+            .split("#[cfg(test)]")
+            .next()
+            .unwrap();
+
+        assert_eq!(helper.matches("pub fn ").count(), 1);
+        assert_eq!(helper.matches(".invoke_signed(").count(), 1);
+    }
+
+    #[test]
+    fn every_instruction_module_is_scanned() {
+        let modules = include_str!("../instructions/mod.rs")
+            .lines()
+            .filter_map(|line| line.strip_prefix("pub mod ")?.strip_suffix(';'));
+
+        for module in modules {
+            assert!(
+                INSTRUCTION_SOURCES.iter().any(|(name, _)| *name == module),
+                "instructions::{module} is not checked for raw signed transfers"
+            );
+        }
+    }
+
+    #[test]
+    fn no_raw_signed_transfers_outside_vault_transfer() {
+        for (name, source) in INSTRUCTION_SOURCES {
+            let program = source.split("#[cfg(test)]").next().unwrap();
+
+            let transfers = program
+                .match_indices("Transfer {")
+                .chain(program.match_indices("TransferChecked {"));
+
+            for (offset, _) in transfers {
+                let statement = &program[offset..];
+                let statement = &statement[..statement.find(';').unwrap()];
+
+                assert!(
+                    !statement.contains("invoke_signed"),
+                    "{name} signs a token transfer without going through vault_transfer"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,14 @@
+use pinocchio::{account_info::AccountInfo, program_error::ProgramError};
+
+use crate::{AccountCheck, FundraiserError};
+
+pub struct WritableAccount;
+
+impl AccountCheck for WritableAccount {
+    fn check(account: &AccountInfo) -> Result<(), ProgramError> {
+        if !account.is_writable() {
+            return Err(FundraiserError::AccountNotWritable.into());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,986 @@
+//! Declarative description of the program's interface, for front-ends generating clients.
+//!
+//! The description is rendered to `idl/fundraiser.json`; regenerate it with
+//! `UPDATE_IDL=1 cargo test idl_matches_snapshot` after a deliberate interface change. State
+//! field offsets are taken from the structs themselves, so reordering a field changes the file.
+
+use crate::{
+    AllowlistPage, AllowlistReceipt, Config, Contributor, Fundraise, FundraiserInstruction, Prefix,
+    Referral, Registry, Space, Stats, Trigger, Updates,
+};
+
+pub struct IdlAccountMeta {
+    pub name: &'static str,
+    pub writable: bool,
+    pub signer: bool,
+    /// Trailing accounts a client may leave out.
+    pub optional: bool,
+}
+
+pub struct IdlField {
+    pub name: &'static str,
+    pub ty: &'static str,
+    /// Where the field starts. Fields following a variable-length one share its offset, each
+    /// starting where the previous one ends.
+    pub offset: usize,
+    /// Trailing instruction data a client may leave out.
+    pub optional: bool,
+}
+
+pub struct IdlInstruction {
+    pub instruction: FundraiserInstruction,
+    pub accounts: &'static [IdlAccountMeta],
+    pub args: &'static [IdlField],
+}
+
+pub struct IdlAccountType {
+    pub name: &'static str,
+    pub prefix: &'static [u8],
+    /// Seeds following the prefix.
+    pub seeds: &'static [&'static str],
+    pub size: usize,
+    pub fields: &'static [IdlField],
+}
+
+const fn account(name: &'static str, writable: bool, signer: bool) -> IdlAccountMeta {
+    IdlAccountMeta {
+        name,
+        writable,
+        signer,
+        optional: false,
+    }
+}
+
+const fn optional_account(name: &'static str, writable: bool) -> IdlAccountMeta {
+    IdlAccountMeta {
+        name,
+        writable,
+        signer: false,
+        optional: true,
+    }
+}
+
+const fn optional_signer(name: &'static str) -> IdlAccountMeta {
+    IdlAccountMeta {
+        name,
+        writable: true,
+        signer: true,
+        optional: true,
+    }
+}
+
+const fn arg(name: &'static str, ty: &'static str, offset: usize) -> IdlField {
+    IdlField {
+        name,
+        ty,
+        offset,
+        optional: false,
+    }
+}
+
+const fn optional_arg(name: &'static str, ty: &'static str, offset: usize) -> IdlField {
+    IdlField {
+        name,
+        ty,
+        offset,
+        optional: true,
+    }
+}
+
+/// Describes a field of a state struct, at its offset in the account data.
+macro_rules! field {
+    ($account:ty, $name:ident, $ty:literal) => {
+        $crate::idl::IdlField {
+            name: stringify!($name),
+            ty: $ty,
+            offset: core::mem::offset_of!($account, $name),
+            optional: false,
+        }
+    };
+}
+
+pub(crate) use field;
+
+pub const INSTRUCTIONS: &[IdlInstruction] = &[
+    IdlInstruction {
+        instruction: FundraiserInstruction::Initialize,
+        accounts: &[
+            account("maker", true, true),
+            account("mint_to_raise", false, false),
+            account("fundraise", true, false),
+            account("vault", true, false),
+            account("system_program", false, false),
+            account("token_program", false, false),
+            account("associated_token_program", false, false),
+            account("config", false, false),
+            optional_account("hook_program", false),
+            optional_account("hook_accounts", false),
+            optional_account("trigger", true),
+            optional_account("registry", true),
+            optional_signer("receipt_mint"),
+            optional_account("receipt_token_program", false),
+            optional_signer("rent_payer"),
+            optional_account("stats", true),
+        ],
+        args: &[
+            arg("amount_to_raise", "u64", 0),
+            arg("duration", "u64", 8),
+            arg("completion_window", "u64", 16),
+            arg("completion_threshold_bps", "u16", 24),
+            arg("max_contribution_bps", "u16", 26),
+            arg("min_contribution", "u64", 28),
+            optional_arg("allowlist", "bool", 36),
+            optional_arg("keeper_trigger", "bool", 37),
+            optional_arg("start_time", "i64", 38),
+            optional_arg("refund_policy", "u8", 46),
+            optional_arg("campaign_type", "u8", 47),
+            optional_arg("merkle_root", "[u8; 32]", 48),
+            optional_arg("register", "bool", 80),
+            optional_arg("tier_thresholds", "[u64; 4]", 81),
+            optional_arg("reward_mint", "pubkey", 113),
+            optional_arg("reward_amount", "u64", 145),
+            optional_arg("receipt", "bool", 153),
+            optional_arg("gate_mint", "pubkey", 154),
+            optional_arg("accept_freezable", "bool", 186),
+            optional_arg("claim_authority", "pubkey", 187),
+            optional_arg("name", "string", 219),
+            optional_arg("uri", "string", 219),
+        ],
+    },
+    IdlInstruction {
+        instruction: FundraiserInstruction::Contribute,
+        accounts: &[
+            account("authority", true, true),
+            account("mint_to_raise", false, false),
+            account("fundraise", true, false),
+            account("contributor", true, false),
+            account("authority_token_account", true, false),
+            account("vault", true, false),
+            account("system_program", false, false),
+            account("token_program", false, false),
+            account("config", false, false),
+            optional_signer("multisig_signers"),
+            optional_account("instructions_sysvar", false),
+            optional_account("allowlist_receipt", false),
+            optional_account("allowlist_page", false),
+            optional_account("referral", true),
+            optional_account("transfer_hook_accounts", false),
+            optional_account("receipt_mint", true),
+            optional_account("receipt_token_account", true),
+            optional_account("receipt_token_program", false),
+            optional_account("gate_token_account", false),
+            optional_signer("rent_payer"),
+            optional_account("stats", true),
+        ],
+        args: &[
+            arg("amount", "u64", 0),
+            optional_arg("reject_duplicates", "bool", 8),
+            optional_arg("require_existing", "bool", 9),
+            optional_arg("clamp", "bool", 10),
+            optional_arg("referrer", "option<pubkey>", 11),
+            optional_arg("merkle_proof", "vec<[u8; 32]>", 11),
+        ],
+    },
+    IdlInstruction {
+        instruction: FundraiserInstruction::Refund,
+        accounts: &[
+            account("authority", true, true),
+            account("maker", true, false),
+            account("mint_to_raise", false, false),
+            account("fundraise", true, false),
+            account("contributor", true, false),
+            account("authority_token_account", true, false),
+            account("vault", true, false),
+            account("system_program", false, false),
+            account("token_program", false, false),
+            account("associated_token_program", false, false),
+            optional_signer("multisig_signers"),
+            optional_account("rent_destination", true),
+            optional_account("transfer_hook_accounts", false),
+            optional_account("receipt_mint", true),
+            optional_account("receipt_token_account", true),
+            optional_account("receipt_token_program", false),
+            optional_account("memo_program", false),
+            optional_account("stats", true),
+        ],
+        args: &[
+            optional_arg("amount", "u64", 0),
+            optional_arg("unwrap", "bool", 8),
+        ],
+    },
+    IdlInstruction {
+        instruction: FundraiserInstruction::Claim,
+        accounts: &[
+            account("claim_authority", true, true),
+            account("mint_to_raise", false, false),
+            account("fundraise", true, false),
+            account("vault", true, false),
+            account("maker_token_account", true, false),
+            account("system_program", false, false),
+            account("token_program", false, false),
+            account("associated_token_program", false, false),
+            account("config", false, false),
+            account("fee_collector", false, false),
+            account("fee_collector_token_account", true, false),
+            account("maker", true, false),
+            optional_account("beneficiary", false),
+            optional_account("registry", true),
+            optional_account("match_vault", true),
+            optional_account("sponsor", false),
+            optional_account("sponsor_token_account", true),
+            optional_account("transfer_hook_accounts", false),
+            optional_signer("rent_payer"),
+            optional_account("memo_program", false),
+            optional_account("stats", true),
+        ],
+        args: &[
+            optional_arg("destination", "u8", 0),
+            optional_arg("unwrap", "bool", 1),
+        ],
+    },
+    IdlInstruction {
+        instruction: FundraiserInstruction::RunHook,
+        accounts: &[
+            account("fundraise", true, false),
+            account("contributor", true, false),
+            account("hook_authority", false, false),
+            account("hook_program", false, false),
+            optional_account("hook_accounts", false),
+        ],
+        args: &[],
+    },
+    IdlInstruction {
+        instruction: FundraiserInstruction::Complete,
+        accounts: &[
+            account("maker", false, true),
+            account("mint_to_raise", false, false),
+            account("fundraise", true, false),
+            account("maker_token_account", true, false),
+            account("vault", true, false),
+            account("token_program", false, false),
+        ],
+        args: &[],
+    },
+    IdlInstruction {
+        instruction: FundraiserInstruction::InitializeConfig,
+        accounts: &[
+            account("admin", true, true),
+            account("config", true, false),
+            account("program_data", false, false),
+            account("system_program", false, false),
+        ],
+        args: &[arg("fee_bps", "u16", 0), arg("fee_collector", "pubkey", 2)],
+    },
+    IdlInstruction {
+        instruction: FundraiserInstruction::UpdateConfig,
+        accounts: &[
+            account("admin", false, true),
+            account("config", true, false),
+        ],
+        args: &[
+            arg("admin", "pubkey", 0),
+            arg("fee_bps", "u16", 32),
+            arg("fee_collector", "pubkey", 34),
+            arg("paused", "bool", 66),
+            arg("bond_lamports", "u64", 67),
+            arg("bond_slash_bps", "u16", 75),
+        ],
+    },
+    IdlInstruction {
+        instruction: FundraiserInstruction::InitializeAllowlistPage,
+        accounts: &[
+            account("maker", true, true),
+            account("fundraise", false, false),
+            account("page", true, false),
+            account("system_program", false, false),
+        ],
+        args: &[arg("page_index", "u16", 0)],
+    },
+    IdlInstruction {
+        instruction: FundraiserInstruction::ClaimAllowlistSlot,
+        accounts: &[
+            account("wallet", true, true),
+            account("maker", false, true),
+            account("fundraise", false, false),
+            account("page", true, false),
+            account("receipt", true, false),
+            account("system_program", false, false),
+        ],
+        args: &[arg("page_index", "u16", 0), arg("bit_index", "u16", 2)],
+    },
+    IdlInstruction {
+        instruction: FundraiserInstruction::Finalize,
+        accounts: &[
+            account("fundraise", false, false),
+            account("trigger", true, false),
+        ],
+        args: &[],
+    },
+    IdlInstruction {
+        instruction: FundraiserInstruction::Donate,
+        accounts: &[
+            account("donor", false, true),
+            account("mint_to_raise", false, false),
+            account("fundraise", true, false),
+            account("donor_token_account", true, false),
+            account("vault", true, false),
+            account("token_program", false, false),
+        ],
+        args: &[arg("amount", "u64", 0)],
+    },
+    IdlInstruction {
+        instruction: FundraiserInstruction::CreateContributor,
+        accounts: &[
+            account("authority", true, true),
+            account("fundraise", false, false),
+            account("contributor", true, false),
+            account("system_program", false, false),
+        ],
+        args: &[],
+    },
+    IdlInstruction {
+        instruction: FundraiserInstruction::ContributeFor,
+        accounts: &[
+            account("payer", true, true),
+            account("beneficiary", false, false),
+            account("mint_to_raise", false, false),
+            account("fundraise", true, false),
+            account("contributor", true, false),
+            account("payer_token_account", true, false),
+            account("vault", true, false),
+            account("system_program", false, false),
+            account("token_program", false, false),
+            account("config", false, false),
+            optional_signer("multisig_signers"),
+            optional_account("instructions_sysvar", false),
+            optional_account("allowlist_receipt", false),
+            optional_account("allowlist_page", false),
+            optional_account("referral", true),
+            optional_account("transfer_hook_accounts", false),
+            optional_account("receipt_mint", true),
+            optional_account("receipt_token_account", true),
+            optional_account("receipt_token_program", false),
+            optional_account("gate_token_account", false),
+            optional_signer("rent_payer"),
+            optional_account("stats", true),
+        ],
+        args: &[
+            arg("amount", "u64", 0),
+            optional_arg("reject_duplicates", "bool", 8),
+            optional_arg("require_existing", "bool", 9),
+            optional_arg("clamp", "bool", 10),
+            optional_arg("referrer", "option<pubkey>", 11),
+            optional_arg("merkle_proof", "vec<[u8; 32]>", 11),
+        ],
+    },
+    IdlInstruction {
+        instruction: FundraiserInstruction::TransferContribution,
+        accounts: &[
+            account("authority", true, true),
+            account("new_authority", true, true),
+            account("fundraise", true, false),
+            account("contributor", true, false),
+            account("new_contributor", true, false),
+            account("system_program", false, false),
+        ],
+        args: &[],
+    },
+    IdlInstruction {
+        instruction: FundraiserInstruction::ForceRefund,
+        accounts: &[
+            account("maker", true, true),
+            account("authority", true, false),
+            account("mint_to_raise", false, false),
+            account("fundraise", true, false),
+            account("contributor", true, false),
+            account("authority_token_account", true, false),
+            account("vault", true, false),
+            account("system_program", false, false),
+            account("token_program", false, false),
+            account("associated_token_program", false, false),
+            optional_account("transfer_hook_accounts", false),
+        ],
+        args: &[],
+    },
+    IdlInstruction {
+        instruction: FundraiserInstruction::Extend,
+        accounts: &[
+            account("maker", false, true),
+            account("fundraise", true, false),
+            optional_account("trigger", true),
+        ],
+        args: &[arg("duration", "u64", 0)],
+    },
+    IdlInstruction {
+        instruction: FundraiserInstruction::ContributeDelegated,
+        accounts: &[
+            account("delegate", true, true),
+            account("owner", false, false),
+            account("mint_to_raise", false, false),
+            account("fundraise", true, false),
+            account("contributor", true, false),
+            account("owner_token_account", true, false),
+            account("vault", true, false),
+            account("system_program", false, false),
+            account("token_program", false, false),
+            account("config", false, false),
+            optional_account("instructions_sysvar", false),
+            optional_account("allowlist_receipt", false),
+            optional_account("allowlist_page", false),
+            optional_account("referral", true),
+            optional_account("transfer_hook_accounts", false),
+            optional_account("receipt_mint", true),
+            optional_account("receipt_token_account", true),
+            optional_account("receipt_token_program", false),
+            optional_account("gate_token_account", false),
+            optional_signer("rent_payer"),
+            optional_account("stats", true),
+        ],
+        args: &[
+            arg("amount", "u64", 0),
+            optional_arg("reject_duplicates", "bool", 8),
+            optional_arg("require_existing", "bool", 9),
+            optional_arg("clamp", "bool", 10),
+            optional_arg("referrer", "option<pubkey>", 11),
+            optional_arg("merkle_proof", "vec<[u8; 32]>", 11),
+        ],
+    },
+    IdlInstruction {
+        instruction: FundraiserInstruction::CrankRefund,
+        accounts: &[
+            account("maker", true, false),
+            account("mint_to_raise", false, false),
+            account("fundraise", true, false),
+            account("vault", true, false),
+            account("token_program", false, false),
+            optional_account("contributor", true),
+            optional_account("authority_token_account", true),
+            optional_account("rent_destination", true),
+            optional_account("transfer_hook_accounts", false),
+        ],
+        args: &[],
+    },
+    IdlInstruction {
+        instruction: FundraiserInstruction::CloseFundraise,
+        accounts: &[
+            account("maker", true, false),
+            account("fundraise", true, false),
+            account("vault", true, false),
+            account("token_program", false, false),
+            optional_account("registry", true),
+            optional_account("mint_to_raise", false),
+            optional_account("maker_token_account", true),
+            optional_account("transfer_hook_accounts", false),
+        ],
+        args: &[],
+    },
+    IdlInstruction {
+        instruction: FundraiserInstruction::UpdateMetadata,
+        accounts: &[
+            account("maker", false, true),
+            account("fundraise", true, false),
+        ],
+        args: &[arg("name", "string", 0), arg("uri", "string", 0)],
+    },
+    IdlInstruction {
+        instruction: FundraiserInstruction::SetPaused,
+        accounts: &[
+            account("maker", false, true),
+            account("fundraise", true, false),
+        ],
+        args: &[arg("paused", "bool", 0)],
+    },
+    IdlInstruction {
+        instruction: FundraiserInstruction::Cancel,
+        accounts: &[
+            account("maker", true, true),
+            account("fundraise", true, false),
+            account("config", false, false),
+            account("fee_collector", true, false),
+        ],
+        args: &[],
+    },
+    IdlInstruction {
+        instruction: FundraiserInstruction::DepositRewards,
+        accounts: &[
+            account("maker", true, true),
+            account("fundraise", false, false),
+            account("reward_mint", false, false),
+            account("maker_token_account", true, false),
+            account("reward_vault", true, false),
+            account("system_program", false, false),
+            account("token_program", false, false),
+            account("associated_token_program", false, false),
+        ],
+        args: &[arg("amount", "u64", 0)],
+    },
+    IdlInstruction {
+        instruction: FundraiserInstruction::ClaimReward,
+        accounts: &[
+            account("authority", true, true),
+            account("fundraise", true, false),
+            account("contributor", true, false),
+            account("reward_mint", false, false),
+            account("reward_vault", true, false),
+            account("authority_token_account", true, false),
+            account("system_program", false, false),
+            account("token_program", false, false),
+            account("associated_token_program", false, false),
+        ],
+        args: &[],
+    },
+    IdlInstruction {
+        instruction: FundraiserInstruction::SweepRewards,
+        accounts: &[
+            account("maker", true, true),
+            account("fundraise", false, false),
+            account("vault", false, false),
+            account("reward_mint", false, false),
+            account("reward_vault", true, false),
+            account("maker_token_account", true, false),
+            account("system_program", false, false),
+            account("token_program", false, false),
+            account("associated_token_program", false, false),
+        ],
+        args: &[],
+    },
+    IdlInstruction {
+        instruction: FundraiserInstruction::DepositMatch,
+        accounts: &[
+            account("sponsor", true, true),
+            account("mint_to_raise", false, false),
+            account("fundraise", true, false),
+            account("sponsor_token_account", true, false),
+            account("match_vault", true, false),
+            account("system_program", false, false),
+            account("token_program", false, false),
+            account("associated_token_program", false, false),
+            optional_account("transfer_hook_accounts", false),
+        ],
+        args: &[arg("amount", "u64", 0)],
+    },
+    IdlInstruction {
+        instruction: FundraiserInstruction::ReclaimMatch,
+        accounts: &[
+            account("sponsor", true, true),
+            account("mint_to_raise", false, false),
+            account("fundraise", true, false),
+            account("vault", false, false),
+            account("match_vault", true, false),
+            account("sponsor_token_account", true, false),
+            account("system_program", false, false),
+            account("token_program", false, false),
+            account("associated_token_program", false, false),
+            optional_account("transfer_hook_accounts", false),
+        ],
+        args: &[],
+    },
+    IdlInstruction {
+        instruction: FundraiserInstruction::PostUpdate,
+        accounts: &[
+            account("maker", true, true),
+            account("fundraise", false, false),
+            account("updates", true, false),
+            account("system_program", false, false),
+        ],
+        args: &[arg("content_hash", "[u8; 32]", 0)],
+    },
+    IdlInstruction {
+        instruction: FundraiserInstruction::Snapshot,
+        accounts: &[
+            account("fundraise", true, false),
+            account("vault", false, false),
+            account("contributor", true, false),
+        ],
+        args: &[],
+    },
+    IdlInstruction {
+        instruction: FundraiserInstruction::SetClaimAuthority,
+        accounts: &[
+            account("maker", false, true),
+            account("fundraise", true, false),
+        ],
+        args: &[arg("claim_authority", "pubkey", 0)],
+    },
+    IdlInstruction {
+        instruction: FundraiserInstruction::SweepForeign,
+        accounts: &[
+            account("maker", true, true),
+            account("fundraise", false, false),
+            account("token_account", true, false),
+            account("mint", false, false),
+            account("destination", true, false),
+            account("token_program", false, false),
+        ],
+        args: &[arg("close", "bool", 0)],
+    },
+];
+
+pub const ACCOUNT_TYPES: &[IdlAccountType] = &[
+    IdlAccountType {
+        name: "Fundraise",
+        prefix: Fundraise::PREFIX,
+        seeds: &["maker"],
+        size: Fundraise::LEN,
+        fields: Fundraise::IDL_FIELDS,
+    },
+    IdlAccountType {
+        name: "Contributor",
+        prefix: Contributor::PREFIX,
+        seeds: &["fundraise", "authority"],
+        size: Contributor::LEN,
+        fields: Contributor::IDL_FIELDS,
+    },
+    IdlAccountType {
+        name: "Config",
+        prefix: Config::PREFIX,
+        seeds: &[],
+        size: Config::LEN,
+        fields: Config::IDL_FIELDS,
+    },
+    IdlAccountType {
+        name: "AllowlistPage",
+        prefix: AllowlistPage::PREFIX,
+        seeds: &["fundraise", "page_index"],
+        size: AllowlistPage::LEN,
+        fields: AllowlistPage::IDL_FIELDS,
+    },
+    IdlAccountType {
+        name: "AllowlistReceipt",
+        prefix: AllowlistReceipt::PREFIX,
+        seeds: &["fundraise", "wallet"],
+        size: AllowlistReceipt::LEN,
+        fields: AllowlistReceipt::IDL_FIELDS,
+    },
+    IdlAccountType {
+        name: "Trigger",
+        prefix: Trigger::PREFIX,
+        seeds: &["fundraise"],
+        size: Trigger::LEN,
+        fields: Trigger::IDL_FIELDS,
+    },
+    IdlAccountType {
+        name: "Stats",
+        prefix: Stats::PREFIX,
+        seeds: &[],
+        size: Stats::LEN,
+        fields: Stats::IDL_FIELDS,
+    },
+    IdlAccountType {
+        name: "Registry",
+        prefix: Registry::PREFIX,
+        seeds: &["mint"],
+        size: Registry::LEN,
+        fields: Registry::IDL_FIELDS,
+    },
+    IdlAccountType {
+        name: "Referral",
+        prefix: Referral::PREFIX,
+        seeds: &["fundraise", "referrer"],
+        size: Referral::LEN,
+        fields: Referral::IDL_FIELDS,
+    },
+    IdlAccountType {
+        name: "Updates",
+        prefix: Updates::PREFIX,
+        seeds: &["fundraise"],
+        size: Updates::LEN,
+        fields: Updates::IDL_FIELDS,
+    },
+];
+
+#[cfg(test)]
+mod tests {
+    use pinocchio::program_error::ToStr;
+    use serde_json::{Value, json};
+    use solana_instruction::Instruction;
+    use solana_pubkey::Pubkey;
+
+    use crate::{
+        ClaimInstructionData, ContributeInstructionData, DepositMatchInstructionData,
+        DepositRewardsInstructionData, DonateInstructionData, ExtendInstructionData,
+        FundraiserError, FundraiserInstruction, InitializeAllowlistPageInstructionData,
+        InitializeConfigInstructionData, InitializeInstructionData, PostUpdateInstructionData,
+        RefundInstructionData, SetClaimAuthorityInstructionData, SetPausedInstructionData,
+        SweepForeignInstructionData, UpdateConfigInstructionData, client,
+        idl::{ACCOUNT_TYPES, INSTRUCTIONS, IdlField, IdlInstruction},
+    };
+
+    const IDL_SNAPSHOT: &str = "idl/fundraiser.json";
+
+    fn size_of_type(ty: &str) -> usize {
+        match ty {
+            "bool" | "u8" => 1,
+            "u16" => 2,
+            "u32" => 4,
+            "u64" | "i64" => 8,
+            "pubkey" => 32,
+            // variable-length, taking up the rest of the instruction data
+            ty if ty.starts_with("vec<") => 0,
+            // variable-length, nothing when absent and a 1 byte followed by the value otherwise
+            ty if ty.starts_with("option<") => 0,
+            // variable-length, a one-byte length followed by that many bytes of UTF-8
+            "string" => 0,
+            _ => {
+                let (inner, len) = ty
+                    .strip_prefix('[')
+                    .and_then(|ty| ty.strip_suffix(']'))
+                    .and_then(|ty| ty.split_once("; "))
+                    .unwrap_or_else(|| panic!("unknown type {ty}"));
+
+                size_of_type(inner) * len.parse::<usize>().unwrap()
+            }
+        }
+    }
+
+    fn fields(fields: &[IdlField]) -> Value {
+        fields
+            .iter()
+            .map(|field| {
+                json!({
+                    "name": field.name,
+                    "type": field.ty,
+                    "offset": field.offset,
+                    "optional": field.optional,
+                })
+            })
+            .collect()
+    }
+
+    fn render_idl() -> String {
+        let instructions: Vec<Value> = INSTRUCTIONS
+            .iter()
+            .map(|ix| {
+                let accounts: Vec<Value> = ix
+                    .accounts
+                    .iter()
+                    .map(|account| {
+                        json!({
+                            "name": account.name,
+                            "writable": account.writable,
+                            "signer": account.signer,
+                            "optional": account.optional,
+                        })
+                    })
+                    .collect();
+
+                json!({
+                    "name": ix.instruction.name(),
+                    "code": ix.instruction.code(),
+                    "legacyCode": ix.instruction.legacy_code(),
+                    "versionedCode": ix.instruction.versioned_code(),
+                    "sighash": ix.instruction.sighash(),
+                    "accounts": accounts,
+                    "args": fields(ix.args),
+                })
+            })
+            .collect();
+
+        let accounts: Vec<Value> = ACCOUNT_TYPES
+            .iter()
+            .map(|account| {
+                json!({
+                    "name": account.name,
+                    "prefix": String::from_utf8(account.prefix.to_vec()).unwrap(),
+                    "seeds": account.seeds,
+                    "size": account.size,
+                    "fields": fields(account.fields),
+                })
+            })
+            .collect();
+
+        let errors: Vec<Value> = FundraiserError::ALL
+            .iter()
+            .map(|error| {
+                json!({
+                    "code": error.code(),
+                    "name": error.name(),
+                    "msg": error.to_str::<FundraiserError>(),
+                })
+            })
+            .collect();
+
+        let idl = json!({
+            "name": "pinocchio_fundraiser",
+            "address": Pubkey::new_from_array(crate::ID).to_string(),
+            "instructions": instructions,
+            "accounts": accounts,
+            "errors": errors,
+        });
+
+        serde_json::to_string_pretty(&idl).unwrap() + "\n"
+    }
+
+    fn described(instruction: FundraiserInstruction) -> &'static IdlInstruction {
+        INSTRUCTIONS
+            .iter()
+            .find(|ix| ix.instruction == instruction)
+            .unwrap()
+    }
+
+    #[test]
+    fn every_instruction_is_described_once() {
+        for instruction in FundraiserInstruction::ALL {
+            let count = INSTRUCTIONS
+                .iter()
+                .filter(|ix| ix.instruction == instruction)
+                .count();
+
+            assert_eq!(count, 1, "{}", instruction.name());
+        }
+    }
+
+    #[test]
+    fn args_match_instruction_data_lengths() {
+        for (instruction, len) in [
+            (
+                FundraiserInstruction::Initialize,
+                InitializeInstructionData::LEN,
+            ),
+            (
+                FundraiserInstruction::Contribute,
+                ContributeInstructionData::LEN,
+            ),
+            (FundraiserInstruction::Refund, RefundInstructionData::LEN),
+            (FundraiserInstruction::Claim, ClaimInstructionData::LEN),
+            (
+                FundraiserInstruction::InitializeConfig,
+                InitializeConfigInstructionData::LEN,
+            ),
+            (
+                FundraiserInstruction::UpdateConfig,
+                UpdateConfigInstructionData::LEN,
+            ),
+            (
+                FundraiserInstruction::InitializeAllowlistPage,
+                InitializeAllowlistPageInstructionData::LEN,
+            ),
+            (FundraiserInstruction::Donate, DonateInstructionData::LEN),
+            (FundraiserInstruction::CreateContributor, 0),
+            (
+                FundraiserInstruction::ContributeFor,
+                ContributeInstructionData::LEN,
+            ),
+            (FundraiserInstruction::TransferContribution, 0),
+            (FundraiserInstruction::ForceRefund, 0),
+            (FundraiserInstruction::Extend, ExtendInstructionData::LEN),
+            (
+                FundraiserInstruction::ContributeDelegated,
+                ContributeInstructionData::LEN,
+            ),
+            (FundraiserInstruction::CrankRefund, 0),
+            (FundraiserInstruction::CloseFundraise, 0),
+            (FundraiserInstruction::UpdateMetadata, 0),
+            (
+                FundraiserInstruction::SetPaused,
+                SetPausedInstructionData::LEN,
+            ),
+            (FundraiserInstruction::Cancel, 0),
+            (
+                FundraiserInstruction::DepositRewards,
+                DepositRewardsInstructionData::LEN,
+            ),
+            (FundraiserInstruction::ClaimReward, 0),
+            (FundraiserInstruction::SweepRewards, 0),
+            (
+                FundraiserInstruction::DepositMatch,
+                DepositMatchInstructionData::LEN,
+            ),
+            (FundraiserInstruction::ReclaimMatch, 0),
+            (
+                FundraiserInstruction::PostUpdate,
+                PostUpdateInstructionData::LEN,
+            ),
+            (FundraiserInstruction::Snapshot, 0),
+            (
+                FundraiserInstruction::SetClaimAuthority,
+                SetClaimAuthorityInstructionData::LEN,
+            ),
+            (
+                FundraiserInstruction::SweepForeign,
+                SweepForeignInstructionData::LEN,
+            ),
+        ] {
+            let args = described(instruction).args;
+            let end = args
+                .last()
+                .map_or(0, |arg| arg.offset + size_of_type(arg.ty));
+
+            assert_eq!(end, len, "{}", instruction.name());
+        }
+    }
+
+    #[test]
+    fn fields_are_contiguous_and_fill_the_account() {
+        for account in ACCOUNT_TYPES {
+            let mut offset = 0;
+
+            for field in account.fields {
+                assert_eq!(field.offset, offset, "{}.{}", account.name, field.name);
+                offset += size_of_type(field.ty);
+            }
+
+            assert_eq!(offset, account.size, "{}", account.name);
+        }
+    }
+
+    #[test]
+    fn accounts_match_client_builders() {
+        let key = Pubkey::new_unique();
+
+        let built: [(FundraiserInstruction, Instruction); 4] = [
+            (
+                FundraiserInstruction::Initialize,
+                client::initialize(&key, &key, &key, 1, 1),
+            ),
+            (
+                FundraiserInstruction::Contribute,
+                client::contribute(&key, &key, &key, &key, 1),
+            ),
+            (
+                FundraiserInstruction::Refund,
+                client::refund(&key, &key, &key, &key),
+            ),
+            (
+                FundraiserInstruction::Claim,
+                client::claim(&key, &key, &key, &key),
+            ),
+        ];
+
+        for (instruction, ix) in built {
+            let required: Vec<_> = described(instruction)
+                .accounts
+                .iter()
+                .filter(|account| !account.optional)
+                .collect();
+
+            assert_eq!(ix.accounts.len(), required.len(), "{}", instruction.name());
+
+            for (meta, account) in ix.accounts.iter().zip(required) {
+                assert_eq!(
+                    (meta.is_writable, meta.is_signer),
+                    (account.writable, account.signer),
+                    "{}.{}",
+                    instruction.name(),
+                    account.name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn idl_matches_snapshot() {
+        let rendered = render_idl();
+
+        if std::env::var_os("UPDATE_IDL").is_some() {
+            std::fs::create_dir_all("idl").unwrap();
+            std::fs::write(IDL_SNAPSHOT, &rendered).unwrap();
+        }
+
+        assert_eq!(
+            std::fs::read_to_string(IDL_SNAPSHOT).unwrap(),
+            rendered,
+            "the program interface changed; regenerate {IDL_SNAPSHOT} if this is intentional"
+        );
+    }
+}
@@ -0,0 +1,325 @@
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{Sysvar, clock::Clock},
+};
+
+use crate::{
+    AccountCheck, AccountLoad, Config, Fundraise, FundraiserError, FundraiserInstruction, Handler,
+    Prefix, ProgramAccount, SignerAccount, WritableAccount, check_account,
+};
+
+pub struct CancelAccounts<'a> {
+    pub maker: &'a AccountInfo,
+    pub fundraise: &'a AccountInfo,
+    pub config: &'a AccountInfo,
+    /// Receives the slashed share of the bond.
+    pub fee_collector: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for CancelAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [maker, fundraise, config, fee_collector] = accounts else {
+            return Err(FundraiserError::MissingAccounts.into());
+        };
+
+        check_account!(0, "signer", SignerAccount::check(maker))?;
+        check_account!(0, "writable", WritableAccount::check(maker))?;
+        check_account!(1, "writable", WritableAccount::check(fundraise))?;
+        check_account!(3, "writable", WritableAccount::check(fee_collector))?;
+        check_account!(1, "owner", ProgramAccount::check(fundraise))?;
+        check_account!(2, "owner", ProgramAccount::check(config))?;
+
+        Ok(Self {
+            maker,
+            fundraise,
+            config,
+            fee_collector,
+        })
+    }
+}
+
+/// Lets the maker call off a running fundraise. It ends straight away and only refunds from
+/// then on, whatever its target, type or refund policy. The maker's bond comes back, less the
+/// protocol's slash if anyone had contributed.
+pub struct Cancel<'a> {
+    pub accounts: CancelAccounts<'a>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for Cancel<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        Ok(Self {
+            accounts: CancelAccounts::try_from(accounts)?,
+        })
+    }
+}
+
+impl Handler for Cancel<'_> {
+    const DISCRIMINATOR: u8 = FundraiserInstruction::Cancel as u8;
+
+    fn process(&mut self) -> ProgramResult {
+        let mut fundraise_data = self.accounts.fundraise.try_borrow_mut_data()?;
+        let fundraise = Fundraise::load_mut(fundraise_data.as_mut())?;
+
+        if fundraise.maker != *self.accounts.maker.key() {
+            return Err(FundraiserError::InvalidAddress.into());
+        }
+
+        ProgramAccount::validate(
+            &[Fundraise::PREFIX, &fundraise.maker, &[fundraise.bump]],
+            *self.accounts.fundraise.key(),
+        )?;
+
+        if fundraise.is_claimed() {
+            return Err(FundraiserError::AlreadyClaimed.into());
+        }
+
+        if fundraise.is_cancelled() {
+            return Err(FundraiserError::FundraiseCancelled.into());
+        }
+
+        let now = Clock::get()?.unix_timestamp;
+
+        if now > fundraise.get_end_time() {
+            return Err(FundraiserError::FundraiserEnded.into());
+        }
+
+        let config_data = self.accounts.config.try_borrow_data()?;
+        let config = Config::load(&config_data)?;
+
+        ProgramAccount::validate(
+            &[Config::PREFIX, &[config.bump]],
+            *self.accounts.config.key(),
+        )?;
+
+        if config.fee_collector != *self.accounts.fee_collector.key() {
+            return Err(FundraiserError::InvalidAddress.into());
+        }
+
+        let bond = fundraise.get_bond();
+        let slash = match fundraise.get_current_amount() {
+            0 => 0,
+            _ => config.bond_slash_on(bond),
+        };
+
+        drop(config_data);
+
+        // ending as of the previous second makes every deadline check see the fundraise over
+        let time_started = fundraise.get_time_started().min(now - 1);
+        fundraise.set_time_started(time_started);
+        fundraise.set_duration((now - 1 - time_started) as u64);
+        fundraise.set_end_time(now - 1);
+        fundraise.cancelled = 1;
+        fundraise.set_bond(0);
+
+        drop(fundraise_data);
+
+        ProgramAccount::transfer_lamports(
+            self.accounts.fundraise,
+            self.accounts.fee_collector,
+            slash,
+        )?;
+        ProgramAccount::transfer_lamports(
+            self.accounts.fundraise,
+            self.accounts.maker,
+            bond - slash,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use spl_associated_token_account::solana_program::{
+        clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL,
+    };
+
+    use crate::{
+        Fundraise, FundraiserError, Space, client,
+        tests::{
+            constants::{FEE_COLLECTOR, MINT_DECIMALS, TOKEN_PROGRAM_ID},
+            utils::{
+                assert_error, build_and_send_transaction, forward_time, init_ata, init_mint,
+                init_wallet, set_config_bond, setup,
+            },
+        },
+    };
+
+    const BOND: u64 = LAMPORTS_PER_SOL / 10;
+
+    fn initialize(litesvm: &mut LiteSVM) -> (Keypair, Pubkey) {
+        set_config_bond(litesvm, BOND, 2_500);
+
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        let ix = client::initialize(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            5_000_000,
+            SECONDS_PER_DAY,
+        );
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        (maker, mint_to_raise)
+    }
+
+    fn contribute(litesvm: &mut LiteSVM, maker: &Keypair, mint_to_raise: Pubkey) -> Keypair {
+        let backer = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        init_ata(litesvm, mint_to_raise, backer.pubkey(), 1_000_000);
+
+        let ix = client::contribute(
+            &backer.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            500_000,
+        );
+        build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]).unwrap();
+
+        backer
+    }
+
+    fn lamports(litesvm: &LiteSVM, address: &Pubkey) -> u64 {
+        litesvm
+            .get_account(address)
+            .map_or(0, |account| account.lamports)
+    }
+
+    #[test]
+    fn cancel_without_contributions_returns_bond() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, _) = initialize(litesvm);
+        let fundraise = client::fundraise_address(&maker.pubkey());
+        let rent = litesvm.minimum_balance_for_rent_exemption(Fundraise::LEN);
+
+        assert_eq!(lamports(litesvm, &fundraise), rent + BOND);
+
+        let maker_before = lamports(litesvm, &maker.pubkey());
+        let collector_before = lamports(litesvm, &FEE_COLLECTOR);
+
+        let ix = client::cancel(&maker.pubkey(), &FEE_COLLECTOR);
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        assert_eq!(lamports(litesvm, &fundraise), rent);
+        assert_eq!(lamports(litesvm, &FEE_COLLECTOR), collector_before);
+        // the maker also paid the transaction fee
+        assert!(lamports(litesvm, &maker.pubkey()) > maker_before + BOND - 10_000);
+
+        let fundraise = Fundraise::unpack(&litesvm.get_account(&fundraise).unwrap().data).unwrap();
+
+        assert_eq!(fundraise.cancelled, 1);
+        assert_eq!(fundraise.bond, 0);
+    }
+
+    #[test]
+    fn cancel_with_contributions_slashes_bond() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, mint_to_raise) = initialize(litesvm);
+        contribute(litesvm, &maker, mint_to_raise);
+
+        let fundraise = client::fundraise_address(&maker.pubkey());
+        let rent = litesvm.minimum_balance_for_rent_exemption(Fundraise::LEN);
+        let collector_before = lamports(litesvm, &FEE_COLLECTOR);
+
+        let ix = client::cancel(&maker.pubkey(), &FEE_COLLECTOR);
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        assert_eq!(lamports(litesvm, &fundraise), rent);
+        assert_eq!(
+            lamports(litesvm, &FEE_COLLECTOR),
+            collector_before + BOND / 4
+        );
+    }
+
+    #[test]
+    fn cancel_opens_refunds_and_blocks_contributions() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, mint_to_raise) = initialize(litesvm);
+        let backer = contribute(litesvm, &maker, mint_to_raise);
+
+        let ix = client::cancel(&maker.pubkey(), &FEE_COLLECTOR);
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let ix = client::contribute(
+            &backer.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            500_000,
+        );
+        let res = build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::FundraiserEnded);
+
+        let ix = client::refund(
+            &backer.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+        build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]).unwrap();
+
+        let contributor = client::contributor_address(
+            &client::fundraise_address(&maker.pubkey()),
+            &backer.pubkey(),
+        );
+
+        assert!(litesvm.get_account(&contributor).is_none());
+    }
+
+    #[test]
+    fn throw_if_cancelled_twice() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, _) = initialize(litesvm);
+
+        let ix = client::cancel(&maker.pubkey(), &FEE_COLLECTOR);
+        build_and_send_transaction(
+            litesvm,
+            &[&maker],
+            &maker.pubkey(),
+            std::slice::from_ref(&ix),
+        )
+        .unwrap();
+        litesvm.expire_blockhash();
+
+        let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::FundraiseCancelled);
+    }
+
+    #[test]
+    fn throw_if_fundraiser_ended() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, _) = initialize(litesvm);
+
+        forward_time(litesvm, SECONDS_PER_DAY as i64 + 1);
+
+        let ix = client::cancel(&maker.pubkey(), &FEE_COLLECTOR);
+        let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::FundraiserEnded);
+    }
+
+    #[test]
+    fn throw_if_signer_not_maker() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, _) = initialize(litesvm);
+        let other = init_wallet(litesvm, LAMPORTS_PER_SOL);
+
+        let mut ix = client::cancel(&other.pubkey(), &FEE_COLLECTOR);
+        ix.accounts[1].pubkey = client::fundraise_address(&maker.pubkey());
+        let res = build_and_send_transaction(litesvm, &[&other], &other.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::InvalidAddress);
+    }
+}
@@ -1,18 +1,24 @@
+use core::mem::size_of;
 use pinocchio::{
     ProgramResult,
     account_info::AccountInfo,
-    instruction::{Seed, Signer},
     program_error::ProgramError,
+    sysvars::{Sysvar, clock::Clock},
 };
-use pinocchio_token_2022::instructions::Transfer;
+
+use pinocchio_token_2022::instructions::CloseAccount;
 
 use crate::{
-    AccountCheck, AccountLoad, AssociatedTokenAccount, Fundraise, FundraiserError, Handler,
-    MintInterface, Prefix, ProgramAccount, SignerAccount,
+    AccountCheck, AccountLoad, AssociatedTokenAccount, CampaignType, ClaimReturnData, Config,
+    Fundraise, FundraiseStatus, FundraiserError, FundraiserInstruction, Handler, Liability,
+    MemoProgram, MintInterface, NATIVE_MINT, Prefix, ProgramAccount, ProgramIdCheck, Registry,
+    SignerAccount, Stats, TokenAccountInterface, TransferHook, WritableAccount, check_account,
+    vault_transfer,
 };
 
 pub struct ClaimAccounts<'a> {
-    pub maker: &'a AccountInfo,
+    /// Signs the claim and receives its payout and any unwrapped lamports.
+    pub claim_authority: &'a AccountInfo,
     pub mint_to_raise: &'a AccountInfo,
     pub fundraise: &'a AccountInfo,
     pub vault: &'a AccountInfo,
@@ -20,14 +26,51 @@ pub struct ClaimAccounts<'a> {
     pub system_program: &'a AccountInfo,
     pub token_program: &'a AccountInfo,
     pub associated_token_program: &'a AccountInfo,
+    pub config: &'a AccountInfo,
+    pub fee_collector: &'a AccountInfo,
+    pub fee_collector_token_account: &'a AccountInfo,
+    /// The fundraise's maker, who posted the rent bond and is paid it back, even when the claim
+    /// was handed to someone else.
+    pub maker: &'a AccountInfo,
+    pub beneficiary: Option<&'a AccountInfo>,
+    pub registry: Option<&'a AccountInfo>,
+    pub match_accounts: Option<ClaimMatchAccounts<'a>>,
+    pub transfer_hook_accounts: &'a [AccountInfo],
+    /// Funds the token accounts and stats account the claim creates in place of the claim
+    /// authority, which a PDA may not hold lamports for.
+    pub rent_payer: Option<&'a AccountInfo>,
+    pub memo_program: Option<&'a AccountInfo>,
+    pub stats: Option<&'a AccountInfo>,
+}
+
+impl ClaimAccounts<'_> {
+    /// Pays for the token accounts the claim creates, and the stats update.
+    #[inline(always)]
+    fn payer(&self) -> &AccountInfo {
+        self.rent_payer.unwrap_or(self.claim_authority)
+    }
+}
+
+/// What a matched fundraise's claim pays the match out of, and returns the rest of the pool to.
+pub struct ClaimMatchAccounts<'a> {
+    pub match_vault: &'a AccountInfo,
+    pub sponsor: &'a AccountInfo,
+    /// The sponsor's associated token account of the mint to raise, created if it does not exist
+    /// yet.
+    pub sponsor_token_account: &'a AccountInfo,
 }
 
 impl<'a> TryFrom<&'a [AccountInfo]> for ClaimAccounts<'a> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        // any rent payer follows the transfer hook accounts, ahead of the memo program and the
+        // stats account
+        let (stats, accounts) = Stats::split_trailing(accounts);
+        let (memo_program, accounts) = MemoProgram::split_trailing(accounts);
+        let (rent_payer, accounts) = SignerAccount::split_rent_payer(accounts)?;
         let [
-            maker,
+            claim_authority,
             mint_to_raise,
             fundraise,
             vault,
@@ -35,18 +78,131 @@ impl<'a> TryFrom<&'a [AccountInfo]> for ClaimAccounts<'a> {
             system_program,
             token_program,
             associated_token_program,
+            config,
+            fee_collector,
+            fee_collector_token_account,
+            maker,
+            rest @ ..,
         ] = accounts
         else {
-            return Err(ProgramError::NotEnoughAccountKeys);
+            return Err(FundraiserError::MissingAccounts.into());
+        };
+
+        check_account!(0, "signer", SignerAccount::check(claim_authority))?;
+        check_account!(0, "writable", WritableAccount::check(claim_authority))?;
+        check_account!(3, "writable", WritableAccount::check(vault))?;
+        check_account!(4, "writable", WritableAccount::check(maker_token_account))?;
+        check_account!(
+            10,
+            "writable",
+            WritableAccount::check(fee_collector_token_account)
+        )?;
+        check_account!(11, "writable", WritableAccount::check(maker))?;
+        check_account!(1, "mint", MintInterface::check(mint_to_raise))?;
+        check_account!(
+            5,
+            "program id",
+            ProgramIdCheck::check(system_program, &pinocchio_system::ID)
+        )?;
+        check_account!(
+            7,
+            "program id",
+            ProgramIdCheck::check(
+                associated_token_program,
+                &pinocchio_associated_token_account::ID,
+            )
+        )?;
+        check_account!(2, "owner", ProgramAccount::check(fundraise))?;
+
+        let (transfer_hook_accounts, rest) = TransferHook::split_trailing(mint_to_raise, rest)?;
+        let fundraise_data = fundraise.try_borrow_data()?;
+        let fundraise_state = Fundraise::load(&fundraise_data)?;
+
+        check_account!(
+            6,
+            "token program",
+            fundraise_state.check_token_program(token_program.key())
+        )?;
+        check_account!(3, "vault", fundraise_state.check_vault(vault.key()))?;
+
+        // a matched fundraise pays out of its match vault, passed after everything but the
+        // transfer hook accounts
+        let (match_accounts, rest) = match fundraise_state.has_match() {
+            true => match rest.split_last_chunk() {
+                Some((rest, [match_vault, sponsor, sponsor_token_account])) => {
+                    if fundraise_state.match_sponsor != *sponsor.key() {
+                        return Err(FundraiserError::InvalidAddress.into());
+                    }
+
+                    let match_accounts = ClaimMatchAccounts {
+                        match_vault,
+                        sponsor,
+                        sponsor_token_account,
+                    };
+
+                    (Some(match_accounts), rest)
+                }
+                None => return Err(FundraiserError::MissingAccounts.into()),
+            },
+            false => (None, rest),
+        };
+
+        // a registered fundraise leaves its registry, passed after any beneficiary and ahead of
+        // any match accounts
+        let (registry, rest) = match fundraise_state.is_registered() {
+            true => rest
+                .split_last()
+                .map(|(registry, rest)| (Some(registry), rest))
+                .ok_or(FundraiserError::MissingAccounts)?,
+            false => (None, rest),
         };
+        drop(fundraise_data);
 
-        SignerAccount::check(maker)?;
-        MintInterface::check(mint_to_raise)?;
-        ProgramAccount::check(fundraise)?;
-        AssociatedTokenAccount::check(vault, fundraise, mint_to_raise, token_program)?;
+        if let Some(registry) = registry {
+            WritableAccount::check(registry)?;
+            ProgramAccount::check(registry)?;
+        }
+
+        if let Some(match_accounts) = &match_accounts {
+            WritableAccount::check(match_accounts.match_vault)?;
+            WritableAccount::check(match_accounts.sponsor_token_account)?;
+            AssociatedTokenAccount::check_address(
+                match_accounts.match_vault,
+                fundraise,
+                mint_to_raise,
+                token_program,
+            )?;
+            AssociatedTokenAccount::check_address(
+                match_accounts.sponsor_token_account,
+                match_accounts.sponsor,
+                mint_to_raise,
+                token_program,
+            )?;
+        }
+
+        check_account!(
+            3,
+            "mint and owner",
+            TokenAccountInterface::check_mint_and_owner(
+                vault,
+                mint_to_raise.key(),
+                fundraise.key()
+            )
+        )?;
+        check_account!(8, "owner", ProgramAccount::check(config))?;
+        check_account!(
+            10,
+            "address",
+            AssociatedTokenAccount::check_address(
+                fee_collector_token_account,
+                fee_collector,
+                mint_to_raise,
+                token_program,
+            )
+        )?;
 
         Ok(Self {
-            maker,
+            claim_authority,
             mint_to_raise,
             fundraise,
             vault,
@@ -54,37 +210,132 @@ impl<'a> TryFrom<&'a [AccountInfo]> for ClaimAccounts<'a> {
             system_program,
             token_program,
             associated_token_program,
+            config,
+            fee_collector,
+            fee_collector_token_account,
+            maker,
+            beneficiary: rest.first(),
+            registry,
+            match_accounts,
+            transfer_hook_accounts,
+            rent_payer,
+            memo_program,
+            stats,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClaimDestination {
+    /// The claim authority's associated token account, created if it does not exist yet.
+    MakerAta = 0,
+    /// A pre-existing token account owned by the trailing `beneficiary` account, which may be
+    /// an off-curve PDA such as a multisig vault. The destination's mint and owner are checked
+    /// in place of ATA derivation, and no account is created.
+    Beneficiary = 1,
+}
+
+pub struct ClaimInstructionData {
+    pub destination: ClaimDestination,
+    /// Whether to close the claim authority's wrapped SOL account afterwards, paying out
+    /// lamports. Only their own account can be closed, so this requires the `MakerAta`
+    /// destination.
+    pub unwrap: bool,
+}
+
+impl ClaimInstructionData {
+    pub const LEN: usize = size_of::<u8>() * 2;
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        [self.destination as u8, u8::from(self.unwrap)]
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for ClaimInstructionData {
+    type Error = ProgramError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        let (destination, unwrap) = match data {
+            [] | [0] | [0, 0] => (ClaimDestination::MakerAta, false),
+            [0, 1] => (ClaimDestination::MakerAta, true),
+            [1] | [1, 0] => (ClaimDestination::Beneficiary, false),
+            _ => return Err(ProgramError::InvalidInstructionData),
+        };
+
+        Ok(Self {
+            destination,
+            unwrap,
         })
     }
 }
 
 pub struct Claim<'a> {
     pub accounts: ClaimAccounts<'a>,
+    pub data: ClaimInstructionData,
 }
 
-impl<'a> TryFrom<&'a [AccountInfo]> for Claim<'a> {
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for Claim<'a> {
     type Error = ProgramError;
 
-    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
         let accounts = ClaimAccounts::try_from(accounts)?;
+        let data = ClaimInstructionData::try_from(data)?;
 
-        AssociatedTokenAccount::init_if_needed(
-            accounts.maker_token_account,
-            accounts.mint_to_raise,
-            accounts.maker,
-            accounts.maker,
-            accounts.system_program,
-            accounts.token_program,
-        )?;
+        if data.unwrap && accounts.mint_to_raise.key() != &NATIVE_MINT {
+            return Err(FundraiserError::NotNativeMint.into());
+        }
+
+        match data.destination {
+            ClaimDestination::MakerAta => {
+                AssociatedTokenAccount::check_address(
+                    accounts.maker_token_account,
+                    accounts.claim_authority,
+                    accounts.mint_to_raise,
+                    accounts.token_program,
+                )?;
+
+                AssociatedTokenAccount::init_if_needed(
+                    accounts.maker_token_account,
+                    accounts.mint_to_raise,
+                    accounts.payer(),
+                    accounts.claim_authority,
+                    accounts.system_program,
+                    accounts.token_program,
+                )?;
+            }
+            ClaimDestination::Beneficiary => {
+                let beneficiary = accounts
+                    .beneficiary
+                    .ok_or(FundraiserError::MissingAccounts)?;
+
+                TokenAccountInterface::check_mint_and_owner(
+                    accounts.maker_token_account,
+                    accounts.mint_to_raise.key(),
+                    beneficiary.key(),
+                )?;
+            }
+        }
 
-        Ok(Self { accounts })
+        Ok(Self { accounts, data })
     }
 }
 
-impl<'a> Handler<'a> for Claim<'a> {
-    const DISCRIMINATOR: &'a u8 = &3;
+impl Handler for Claim<'_> {
+    const DISCRIMINATOR: u8 = FundraiserInstruction::Claim as u8;
 
     fn process(&mut self) -> ProgramResult {
+        // the token program would reject a transfer to or from a frozen account, opaquely
+        if TokenAccountInterface::is_frozen(self.accounts.vault) {
+            return Err(FundraiserError::VaultFrozen.into());
+        }
+
+        // a fee collector account yet to be created reads as not frozen
+        if TokenAccountInterface::is_frozen(self.accounts.maker_token_account)
+            || TokenAccountInterface::is_frozen(self.accounts.fee_collector_token_account)
+        {
+            return Err(FundraiserError::DestinationFrozen.into());
+        }
+
         let vault_amount = match *self.accounts.vault.owner() {
             pinocchio_token::ID => {
                 let vault = unsafe {
@@ -102,33 +353,220 @@ impl<'a> Handler<'a> for Claim<'a> {
                 };
                 vault.amount()
             }
-            _ => return Err(ProgramError::IncorrectProgramId),
+            _ => return Err(FundraiserError::InvalidTokenProgram.into()),
         };
 
         let fundraise_data = self.accounts.fundraise.try_borrow_data()?;
         let fundraise = Fundraise::load(&fundraise_data)?;
-        let amount_to_raise = fundraise.get_amount_to_raise();
 
-        if vault_amount < amount_to_raise {
-            return Err(FundraiserError::TargetNotMet.into());
+        if fundraise.claim_authority != *self.accounts.claim_authority.key()
+            || fundraise.maker != *self.accounts.maker.key()
+        {
+            return Err(FundraiserError::InvalidAddress.into());
         }
 
-        let fundraise_bump = [fundraise.bump];
-        let fundraise_seeds = [
-            Seed::from(Fundraise::PREFIX),
-            Seed::from(fundraise.maker.as_ref()),
-            Seed::from(&fundraise_bump),
-        ];
-        let fundraise_signer = Signer::from(&fundraise_seeds);
-
-        Transfer {
-            amount: vault_amount,
-            authority: self.accounts.fundraise,
-            from: self.accounts.vault,
-            to: self.accounts.maker_token_account,
-            token_program: self.accounts.token_program.key(),
+        if fundraise.is_claimed() {
+            return Err(FundraiserError::AlreadyClaimed.into());
+        }
+
+        if fundraise.is_cancelled() {
+            return Err(FundraiserError::FundraiseCancelled.into());
+        }
+
+        let status = fundraise.status(Clock::get()?.unix_timestamp);
+
+        match (fundraise.campaign_type(), status) {
+            (_, FundraiseStatus::Claimable)
+            | (CampaignType::AllOrNothing, FundraiseStatus::TargetMet) => {}
+            // a keep-it-all fundraise may still grow until its deadline
+            (CampaignType::KeepItAll, FundraiseStatus::Active | FundraiseStatus::TargetMet) => {
+                return Err(FundraiserError::FundraiserNotEnded.into());
+            }
+            _ => return Err(FundraiserError::TargetNotMet.into()),
+        }
+
+        let config_data = self.accounts.config.try_borrow_data()?;
+        let config = Config::load(&config_data)?;
+
+        ProgramAccount::validate(
+            &[Config::PREFIX, &[config.bump]],
+            *self.accounts.config.key(),
+        )?;
+
+        if config.fee_collector != *self.accounts.fee_collector.key() {
+            return Err(FundraiserError::InvalidAddress.into());
+        }
+
+        // the fee is taken on the liability, and tokens sent straight to the vault follow it free
+        let claimable = fundraise.outstanding_liability().min(vault_amount);
+        let surplus = vault_amount - claimable;
+        // contributions are drawn down by the payout, so reward shares count against them now
+        let reward_basis = fundraise.get_current_amount();
+        let fee = config.fee_on(claimable);
+        // the match is paid on top and free of the fee, with what it leaves over back to the
+        // sponsor
+        let match_payout = fundraise.match_payout();
+        let match_refund = fundraise.get_match_pool() - match_payout;
+
+        drop(config_data);
+
+        // nothing stays borrowed across the CPIs, which a transfer hook may read accounts during
+        drop(fundraise_data);
+
+        if fee > 0 {
+            AssociatedTokenAccount::init_if_needed(
+                self.accounts.fee_collector_token_account,
+                self.accounts.mint_to_raise,
+                self.accounts.payer(),
+                self.accounts.fee_collector,
+                self.accounts.system_program,
+                self.accounts.token_program,
+            )?;
+
+            MemoProgram::memo_if_required(
+                self.accounts.fee_collector_token_account,
+                self.accounts.memo_program,
+                b"fundraiser fee",
+            )?;
+            vault_transfer(
+                Liability::Raised,
+                self.accounts.vault,
+                self.accounts.mint_to_raise,
+                self.accounts.fee_collector_token_account,
+                fee,
+                self.accounts.fundraise,
+                self.accounts.transfer_hook_accounts,
+            )?;
+        }
+
+        MemoProgram::memo_if_required(
+            self.accounts.maker_token_account,
+            self.accounts.memo_program,
+            b"fundraiser claim",
+        )?;
+        vault_transfer(
+            Liability::Raised,
+            self.accounts.vault,
+            self.accounts.mint_to_raise,
+            self.accounts.maker_token_account,
+            claimable - fee,
+            self.accounts.fundraise,
+            self.accounts.transfer_hook_accounts,
+        )?;
+
+        if surplus > 0 {
+            MemoProgram::memo_if_required(
+                self.accounts.maker_token_account,
+                self.accounts.memo_program,
+                b"fundraiser surplus",
+            )?;
+            vault_transfer(
+                Liability::Surplus,
+                self.accounts.vault,
+                self.accounts.mint_to_raise,
+                self.accounts.maker_token_account,
+                surplus,
+                self.accounts.fundraise,
+                self.accounts.transfer_hook_accounts,
+            )?;
+        }
+
+        if let Some(match_accounts) = &self.accounts.match_accounts {
+            if match_payout > 0 {
+                MemoProgram::memo_if_required(
+                    self.accounts.maker_token_account,
+                    self.accounts.memo_program,
+                    b"fundraiser match",
+                )?;
+                vault_transfer(
+                    Liability::Match,
+                    match_accounts.match_vault,
+                    self.accounts.mint_to_raise,
+                    self.accounts.maker_token_account,
+                    match_payout,
+                    self.accounts.fundraise,
+                    self.accounts.transfer_hook_accounts,
+                )?;
+            }
+
+            if match_refund > 0 {
+                AssociatedTokenAccount::init_if_needed(
+                    match_accounts.sponsor_token_account,
+                    self.accounts.mint_to_raise,
+                    self.accounts.payer(),
+                    match_accounts.sponsor,
+                    self.accounts.system_program,
+                    self.accounts.token_program,
+                )?;
+
+                MemoProgram::memo_if_required(
+                    match_accounts.sponsor_token_account,
+                    self.accounts.memo_program,
+                    b"fundraiser match refund",
+                )?;
+                vault_transfer(
+                    Liability::Match,
+                    match_accounts.match_vault,
+                    self.accounts.mint_to_raise,
+                    match_accounts.sponsor_token_account,
+                    match_refund,
+                    self.accounts.fundraise,
+                    self.accounts.transfer_hook_accounts,
+                )?;
+            }
+        }
+
+        let bond = {
+            let mut fundraise_data = self.accounts.fundraise.try_borrow_mut_data()?;
+            let fundraise = Fundraise::load_mut(fundraise_data.as_mut())?;
+
+            fundraise.claimed = 1;
+            fundraise.set_reward_basis(reward_basis);
+            let bond = fundraise.get_bond();
+            fundraise.set_bond(0);
+
+            bond
+        };
+
+        // a successful campaign's bond goes back to the maker who posted it
+        ProgramAccount::transfer_lamports(self.accounts.fundraise, self.accounts.maker, bond)?;
+
+        if self.data.unwrap {
+            CloseAccount {
+                account: self.accounts.maker_token_account,
+                destination: self.accounts.claim_authority,
+                authority: self.accounts.claim_authority,
+                token_program: self.accounts.token_program.key(),
+            }
+            .invoke()?;
+        }
+
+        if let Some(registry) = self.accounts.registry {
+            let mut registry_data = registry.try_borrow_mut_data()?;
+            let registry_state = Registry::load_mut(registry_data.as_mut())?;
+
+            ProgramAccount::validate(
+                &[
+                    Registry::PREFIX,
+                    self.accounts.mint_to_raise.key(),
+                    &[registry_state.bump],
+                ],
+                *registry.key(),
+            )?;
+
+            registry_state.record_closed()?;
         }
-        .invoke_signed(&[fundraise_signer])?;
+
+        if let Some(stats) = self.accounts.stats {
+            Stats::update(stats, self.accounts.payer(), |stats| {
+                stats.record_claim(claimable)
+            })?;
+        }
+
+        ClaimReturnData {
+            claimed_amount: claimable - fee + surplus + match_payout,
+        }
+        .set();
 
         Ok(())
     }
@@ -136,25 +574,34 @@ impl<'a> Handler<'a> for Claim<'a> {
 
 #[cfg(test)]
 mod tests {
+    use litesvm::LiteSVM;
     use solana_instruction::{AccountMeta, Instruction};
+    use solana_keypair::Keypair;
+    use solana_pubkey::Pubkey;
     use solana_signer::Signer;
     use spl_associated_token_account::{
         get_associated_token_address_with_program_id,
         solana_program::{clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL},
     };
-    use spl_token_2022::state::Account;
+    use spl_token_2022::{extension::StateWithExtensions, state::Account};
 
     use crate::{
-        FundraiserError,
+        CampaignType, ClaimReturnData, FEE_EXEMPT_THRESHOLD, Fundraise, FundraiserError,
+        RefundPolicy, Registry, Space, client,
         tests::{
             constants::{
-                ASSOCIATED_TOKEN_PROGRAM_ID, MINT_DECIMALS, PROGRAM_ID, SYSTEM_PROGRAM_ID,
+                ASSOCIATED_TOKEN_PROGRAM_ID, FEE_COLLECTOR, MEMO_PROGRAM_ID, MINT_DECIMALS,
+                MOCK_MULTISIG_PROGRAM_ID, PROGRAM_ID, SYSTEM_PROGRAM_ID, TOKEN_2022_PROGRAM_ID,
                 TOKEN_PROGRAM_ID,
             },
-            pda::{get_contributor_pda, get_fundraise_pda},
+            pda::{
+                get_config_pda, get_contributor_pda, get_fundraise_pda, get_registry_pda,
+                get_vault_pda,
+            },
             utils::{
                 assert_error, build_and_send_transaction, fetch_account, forward_time, init_ata,
-                init_mint, init_wallet, setup,
+                init_config, init_memo_required_ata, init_mint, init_token_account, init_wallet,
+                set_config_bond, setup, simulate_return_data,
             },
         },
     };
@@ -170,16 +617,16 @@ mod tests {
         let amount_to_raise: u64 = 5_000_000;
         let duration: u64 = SECONDS_PER_DAY; // 1 day
         let fundraise_pda = get_fundraise_pda(&maker.pubkey());
-        let vault = get_associated_token_address_with_program_id(
-            &fundraise_pda,
-            &mint_to_raise,
-            &TOKEN_PROGRAM_ID,
-        );
+        let vault = get_vault_pda(&fundraise_pda);
 
         let data = [
             vec![0u8],
             amount_to_raise.to_le_bytes().to_vec(),
             duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
         ]
         .concat();
         let ix = Instruction {
@@ -192,6 +639,7 @@ mod tests {
                 AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
                 AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
                 AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
             ],
             data,
         };
@@ -213,6 +661,7 @@ mod tests {
                 AccountMeta::new(vault, false),
                 AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
                 AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
             ],
             data,
         };
@@ -237,6 +686,7 @@ mod tests {
                     AccountMeta::new(vault, false),
                     AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
                     AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(get_config_pda(), false),
                 ],
                 data,
             };
@@ -248,6 +698,12 @@ mod tests {
 
         let maker_ata = init_ata(litesvm, mint_to_raise, maker.pubkey(), 0);
 
+        let fee_collector_ata = get_associated_token_address_with_program_id(
+            &FEE_COLLECTOR,
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+
         let data = vec![3u8];
         let ix = Instruction {
             program_id: PROGRAM_ID,
@@ -260,6 +716,10 @@ mod tests {
                 AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
                 AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
                 AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+                AccountMeta::new_readonly(FEE_COLLECTOR, false),
+                AccountMeta::new(fee_collector_ata, false),
+                AccountMeta::new(maker.pubkey(), false),
             ],
             data,
         };
@@ -272,26 +732,178 @@ mod tests {
     }
 
     #[test]
-    fn throw_if_target_not_met() {
+    fn claim_keep_it_all_below_target() {
         let (litesvm, _default_payer) = &mut setup();
         let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
-        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
         let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 10_000_000_000);
-        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 5_000_000_000);
 
         let amount_to_raise: u64 = 5_000_000;
         let duration: u64 = SECONDS_PER_DAY; // 1 day
         let fundraise_pda = get_fundraise_pda(&maker.pubkey());
-        let vault = get_associated_token_address_with_program_id(
-            &fundraise_pda,
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            10_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            vec![0u8, 0u8],
+            0i64.to_le_bytes().to_vec(),
+            vec![RefundPolicy::Flexible as u8, CampaignType::KeepItAll as u8],
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        // 40% of the target, split across two contributors
+        let mut contributors = vec![];
+
+        for contribute_amount in [1_500_000u64, 500_000] {
+            let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+            let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+            let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+            let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+            let ix = Instruction {
+                program_id: PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new(authority.pubkey(), true),
+                    AccountMeta::new_readonly(mint_to_raise, false),
+                    AccountMeta::new(fundraise_pda, false),
+                    AccountMeta::new(contributor_pda, false),
+                    AccountMeta::new(authority_ata, false),
+                    AccountMeta::new(vault, false),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(get_config_pda(), false),
+                ],
+                data,
+            };
+
+            build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+
+            contributors.push((authority, authority_ata, contributor_pda));
+        }
+
+        let maker_ata = init_ata(litesvm, mint_to_raise, maker.pubkey(), 0);
+
+        let fee_collector_ata = get_associated_token_address_with_program_id(
+            &FEE_COLLECTOR,
             &mint_to_raise,
             &TOKEN_PROGRAM_ID,
         );
 
+        let claim_ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new(maker_ata, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+                AccountMeta::new_readonly(FEE_COLLECTOR, false),
+                AccountMeta::new(fee_collector_ata, false),
+                AccountMeta::new(maker.pubkey(), false),
+            ],
+            data: vec![3u8],
+        };
+
+        let res = build_and_send_transaction(
+            litesvm,
+            &[&maker],
+            &maker.pubkey(),
+            core::slice::from_ref(&claim_ix),
+        );
+
+        assert_error(res.unwrap_err(), FundraiserError::FundraiserNotEnded);
+
+        forward_time(litesvm, duration as i64 + 1);
+
+        let (authority, authority_ata, contributor_pda) = &contributors[1];
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new(maker.pubkey(), false),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(*contributor_pda, false),
+                AccountMeta::new(*authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            ],
+            data: vec![2u8],
+        };
+
+        let res = build_and_send_transaction(litesvm, &[authority], &authority.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::FundraiserEnded);
+
+        litesvm.expire_blockhash();
+
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[claim_ix]).unwrap();
+
+        assert_eq!(fetch_account::<Account>(litesvm, &vault).amount, 0);
+        assert_eq!(
+            fetch_account::<Account>(litesvm, &maker_ata).amount
+                + litesvm
+                    .get_account(&fee_collector_ata)
+                    .map_or(0, |_| fetch_account::<Account>(litesvm, &fee_collector_ata)
+                        .amount),
+            2_000_000
+        );
+    }
+
+    #[test]
+    fn claim_to_memo_required_account() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(
+            litesvm,
+            TOKEN_2022_PROGRAM_ID,
+            MINT_DECIMALS,
+            10_000_000_000,
+        );
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+        let maker_ata = init_memo_required_ata(litesvm, mint_to_raise, maker.pubkey(), 0);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
         let data = [
             vec![0u8],
             amount_to_raise.to_le_bytes().to_vec(),
             duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            10_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
         ]
         .concat();
         let ix = Instruction {
@@ -302,18 +914,16 @@ mod tests {
                 AccountMeta::new(fundraise_pda, false),
                 AccountMeta::new(vault, false),
                 AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
-                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false),
                 AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
             ],
             data,
         };
 
-        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
 
-        let contribute_amount: u64 = 500_000;
         let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
-
-        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
         let ix = Instruction {
             program_id: PROGRAM_ID,
             accounts: vec![
@@ -324,19 +934,21 @@ mod tests {
                 AccountMeta::new(authority_ata, false),
                 AccountMeta::new(vault, false),
                 AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
-                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
             ],
-            data,
+            data: [vec![1u8], amount_to_raise.to_le_bytes().to_vec()].concat(),
         };
 
-        let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
-
-        forward_time(litesvm, 3600); // jump forward 1 hour
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
 
-        let maker_ata = init_ata(litesvm, mint_to_raise, maker.pubkey(), 0);
+        let fee_collector_ata = get_associated_token_address_with_program_id(
+            &FEE_COLLECTOR,
+            &mint_to_raise,
+            &TOKEN_2022_PROGRAM_ID,
+        );
 
-        let data = vec![3u8];
-        let ix = Instruction {
+        let mut ix = Instruction {
             program_id: PROGRAM_ID,
             accounts: vec![
                 AccountMeta::new(maker.pubkey(), true),
@@ -345,14 +957,1791 @@ mod tests {
                 AccountMeta::new(vault, false),
                 AccountMeta::new(maker_ata, false),
                 AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
-                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false),
                 AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+                AccountMeta::new_readonly(FEE_COLLECTOR, false),
+                AccountMeta::new(fee_collector_ata, false),
+                AccountMeta::new(maker.pubkey(), false),
             ],
-            data,
+            data: vec![3u8],
         };
 
-        let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+        let res = build_and_send_transaction(
+            litesvm,
+            &[&maker],
+            &maker.pubkey(),
+            core::slice::from_ref(&ix),
+        );
 
-        assert_error(res.unwrap_err(), FundraiserError::TargetNotMet);
+        assert_error(res.unwrap_err(), FundraiserError::MissingMemoProgram);
+
+        ix.accounts
+            .push(AccountMeta::new_readonly(MEMO_PROGRAM_ID, false));
+
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let maker_ata_acc = litesvm.get_account(&maker_ata).unwrap();
+        let maker_ata_bal = StateWithExtensions::<Account>::unpack(&maker_ata_acc.data)
+            .unwrap()
+            .base
+            .amount;
+
+        assert_eq!(maker_ata_bal, amount_to_raise);
+    }
+
+    #[test]
+    fn claim_with_token_2022() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(
+            litesvm,
+            TOKEN_2022_PROGRAM_ID,
+            MINT_DECIMALS,
+            10_000_000_000,
+        );
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 5_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let contribute_amount: u64 = 500_000;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+
+        // init 9 more authorities to contribute and meet the fundraiser target
+        for _ in 0..9 {
+            let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+            let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+            let temp_contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+            let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+            let ix = Instruction {
+                program_id: PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new(authority.pubkey(), true),
+                    AccountMeta::new_readonly(mint_to_raise, false),
+                    AccountMeta::new(fundraise_pda, false),
+                    AccountMeta::new(temp_contributor_pda, false),
+                    AccountMeta::new(authority_ata, false),
+                    AccountMeta::new(vault, false),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(get_config_pda(), false),
+                ],
+                data,
+            };
+
+            build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+        }
+
+        forward_time(litesvm, 3600); // jump forward 1 hour
+
+        let maker_ata = init_ata(litesvm, mint_to_raise, maker.pubkey(), 0);
+
+        let fee_collector_ata = get_associated_token_address_with_program_id(
+            &FEE_COLLECTOR,
+            &mint_to_raise,
+            &TOKEN_2022_PROGRAM_ID,
+        );
+
+        let data = vec![3u8];
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new(maker_ata, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+                AccountMeta::new_readonly(FEE_COLLECTOR, false),
+                AccountMeta::new(fee_collector_ata, false),
+                AccountMeta::new(maker.pubkey(), false),
+            ],
+            data,
+        };
+
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let vault_bal = fetch_account::<Account>(litesvm, &vault).amount;
+
+        assert_eq!(vault_bal, 0);
+
+        let maker_ata_bal = fetch_account::<Account>(litesvm, &maker_ata).amount;
+
+        assert_eq!(maker_ata_bal, amount_to_raise);
+    }
+
+    #[test]
+    fn claim_sweeps_unrecorded_tokens_from_vault() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 10_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 5_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let contribute_amount: u64 = 500_000;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        // init 9 more authorities to contribute and meet the fundraiser target
+        for _ in 0..9 {
+            let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+            let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+            let temp_contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+            let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+            let ix = Instruction {
+                program_id: PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new(authority.pubkey(), true),
+                    AccountMeta::new_readonly(mint_to_raise, false),
+                    AccountMeta::new(fundraise_pda, false),
+                    AccountMeta::new(temp_contributor_pda, false),
+                    AccountMeta::new(authority_ata, false),
+                    AccountMeta::new(vault, false),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(get_config_pda(), false),
+                ],
+                data,
+            };
+
+            let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+        }
+
+        // tokens sent straight to the vault are not a contribution, but claim sweeps them with it
+        let donation: u64 = 1_000_000;
+        init_token_account(
+            litesvm,
+            vault,
+            mint_to_raise,
+            fundraise_pda,
+            amount_to_raise + donation,
+        );
+
+        forward_time(litesvm, 3600); // jump forward 1 hour
+
+        let maker_ata = init_ata(litesvm, mint_to_raise, maker.pubkey(), 0);
+
+        let fee_collector_ata = get_associated_token_address_with_program_id(
+            &FEE_COLLECTOR,
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        let data = vec![3u8];
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new(maker_ata, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+                AccountMeta::new_readonly(FEE_COLLECTOR, false),
+                AccountMeta::new(fee_collector_ata, false),
+                AccountMeta::new(maker.pubkey(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let maker_bal = fetch_account::<Account>(litesvm, &maker_ata).amount;
+        let vault_bal = fetch_account::<Account>(litesvm, &vault).amount;
+
+        assert_eq!(maker_bal, amount_to_raise + donation);
+        assert_eq!(vault_bal, 0);
+    }
+
+    #[test]
+    fn throw_if_maker_token_account_not_maker_ata() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 10_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 5_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let contribute_amount: u64 = 500_000;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        // init 9 more authorities to contribute and meet the fundraiser target
+        for _ in 0..9 {
+            let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+            let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+            let temp_contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+            let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+            let ix = Instruction {
+                program_id: PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new(authority.pubkey(), true),
+                    AccountMeta::new_readonly(mint_to_raise, false),
+                    AccountMeta::new(fundraise_pda, false),
+                    AccountMeta::new(temp_contributor_pda, false),
+                    AccountMeta::new(authority_ata, false),
+                    AccountMeta::new(vault, false),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(get_config_pda(), false),
+                ],
+                data,
+            };
+
+            let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+        }
+
+        forward_time(litesvm, 3600); // jump forward 1 hour
+
+        let third_party = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let third_party_ata = init_ata(litesvm, mint_to_raise, third_party.pubkey(), 0);
+
+        let fee_collector_ata = get_associated_token_address_with_program_id(
+            &FEE_COLLECTOR,
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        let data = vec![3u8];
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new(third_party_ata, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+                AccountMeta::new_readonly(FEE_COLLECTOR, false),
+                AccountMeta::new(fee_collector_ata, false),
+                AccountMeta::new(maker.pubkey(), false),
+            ],
+            data,
+        };
+
+        let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::InvalidAddress);
+    }
+
+    #[test]
+    fn claim_with_fee() {
+        let (litesvm, default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 10_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 5_000_000_000);
+        init_config(litesvm, default_payer.pubkey(), 100, FEE_COLLECTOR, false); // 1% fee
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let first_amount: u64 = 499_999;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], first_amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        let contribute_amount: u64 = 500_000;
+
+        // init 10 more authorities to contribute and exceed the fundraiser target
+        for _ in 0..10 {
+            let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+            let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+            let temp_contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+            let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+            let ix = Instruction {
+                program_id: PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new(authority.pubkey(), true),
+                    AccountMeta::new_readonly(mint_to_raise, false),
+                    AccountMeta::new(fundraise_pda, false),
+                    AccountMeta::new(temp_contributor_pda, false),
+                    AccountMeta::new(authority_ata, false),
+                    AccountMeta::new(vault, false),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(get_config_pda(), false),
+                ],
+                data,
+            };
+
+            let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+        }
+
+        forward_time(litesvm, 3600); // jump forward 1 hour
+
+        let maker_ata = init_ata(litesvm, mint_to_raise, maker.pubkey(), 0);
+
+        let fee_collector_ata = get_associated_token_address_with_program_id(
+            &FEE_COLLECTOR,
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        let data = vec![3u8];
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new(maker_ata, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+                AccountMeta::new_readonly(FEE_COLLECTOR, false),
+                AccountMeta::new(fee_collector_ata, false),
+                AccountMeta::new(maker.pubkey(), false),
+            ],
+            data,
+        };
+
+        let (program_id, data) = simulate_return_data(
+            litesvm,
+            &[&maker],
+            &maker.pubkey(),
+            core::slice::from_ref(&ix),
+        );
+
+        assert_eq!(program_id, PROGRAM_ID);
+        assert_eq!(
+            ClaimReturnData::from_bytes(&data.try_into().unwrap()),
+            ClaimReturnData {
+                claimed_amount: 5_499_999 - 54_999,
+            }
+        );
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let fee_collector_bal = fetch_account::<Account>(litesvm, &fee_collector_ata).amount;
+        let maker_bal = fetch_account::<Account>(litesvm, &maker_ata).amount;
+
+        // 1% of 5_499_999 rounds down to 54_999, the maker keeps the remainder
+        assert_eq!(fee_collector_bal, 54_999);
+        assert_eq!(maker_bal, 5_499_999 - 54_999);
+    }
+
+    #[test]
+    fn claim_below_fee_exempt_threshold() {
+        let (litesvm, default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 10_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 5_000_000_000);
+        init_config(litesvm, default_payer.pubkey(), 100, FEE_COLLECTOR, false); // 1% fee
+
+        let amount_to_raise: u64 = FEE_EXEMPT_THRESHOLD - 1;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            10_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], amount_to_raise.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+
+        let maker_ata = init_ata(litesvm, mint_to_raise, maker.pubkey(), 0);
+
+        let fee_collector_ata = get_associated_token_address_with_program_id(
+            &FEE_COLLECTOR,
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new(maker_ata, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+                AccountMeta::new_readonly(FEE_COLLECTOR, false),
+                AccountMeta::new(fee_collector_ata, false),
+                AccountMeta::new(maker.pubkey(), false),
+            ],
+            data: vec![3u8],
+        };
+
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        // no fee is charged, so the collector's token account is never created
+        assert!(litesvm.get_account(&fee_collector_ata).is_none());
+        assert_eq!(fetch_account::<Account>(litesvm, &vault).amount, 0);
+        assert_eq!(
+            fetch_account::<Account>(litesvm, &maker_ata).amount,
+            amount_to_raise
+        );
+    }
+
+    #[test]
+    fn throw_if_target_not_met() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 10_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 5_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let contribute_amount: u64 = 500_000;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        forward_time(litesvm, 3600); // jump forward 1 hour
+
+        let maker_ata = init_ata(litesvm, mint_to_raise, maker.pubkey(), 0);
+
+        let fee_collector_ata = get_associated_token_address_with_program_id(
+            &FEE_COLLECTOR,
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        let data = vec![3u8];
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new(maker_ata, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+                AccountMeta::new_readonly(FEE_COLLECTOR, false),
+                AccountMeta::new(fee_collector_ata, false),
+                AccountMeta::new(maker.pubkey(), false),
+            ],
+            data,
+        };
+
+        let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::TargetNotMet);
+    }
+
+    #[test]
+    fn throw_if_invalid_program_ids() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let maker_ata = init_ata(litesvm, mint_to_raise, maker.pubkey(), 0);
+
+        let fee_collector_ata = get_associated_token_address_with_program_id(
+            &FEE_COLLECTOR,
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        for index in [5, 7] {
+            litesvm.expire_blockhash();
+
+            let data = vec![3u8];
+            let mut ix = Instruction {
+                program_id: PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new(maker.pubkey(), true),
+                    AccountMeta::new_readonly(mint_to_raise, false),
+                    AccountMeta::new(fundraise_pda, false),
+                    AccountMeta::new(vault, false),
+                    AccountMeta::new(maker_ata, false),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(get_config_pda(), false),
+                    AccountMeta::new_readonly(FEE_COLLECTOR, false),
+                    AccountMeta::new(fee_collector_ata, false),
+                    AccountMeta::new(maker.pubkey(), false),
+                ],
+                data,
+            };
+
+            ix.accounts[index].pubkey = Pubkey::new_unique();
+
+            let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+            assert_error(res.unwrap_err(), FundraiserError::ProgramIdMismatch);
+        }
+    }
+
+    #[test]
+    fn throw_if_token_program_mismatch() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let maker_ata = init_ata(litesvm, mint_to_raise, maker.pubkey(), 0);
+
+        let fee_collector_ata = get_associated_token_address_with_program_id(
+            &FEE_COLLECTOR,
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        let data = vec![3u8];
+        let mut ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new(maker_ata, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+                AccountMeta::new_readonly(FEE_COLLECTOR, false),
+                AccountMeta::new(fee_collector_ata, false),
+                AccountMeta::new(maker.pubkey(), false),
+            ],
+            data,
+        };
+
+        ix.accounts[6].pubkey = TOKEN_2022_PROGRAM_ID;
+
+        let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::InvalidTokenProgram);
+    }
+
+    #[test]
+    fn throw_if_vault_mismatch() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let maker_ata = init_ata(litesvm, mint_to_raise, maker.pubkey(), 0);
+
+        let fee_collector_ata = get_associated_token_address_with_program_id(
+            &FEE_COLLECTOR,
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        let data = vec![3u8];
+        let mut ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new(maker_ata, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+                AccountMeta::new_readonly(FEE_COLLECTOR, false),
+                AccountMeta::new(fee_collector_ata, false),
+                AccountMeta::new(maker.pubkey(), false),
+            ],
+            data,
+        };
+
+        // a token account of the mint held by the fundraise, but not the recorded vault
+        let decoy_vault = Pubkey::new_unique();
+        init_token_account(litesvm, decoy_vault, mint_to_raise, fundraise_pda, 0);
+
+        ix.accounts[3].pubkey = decoy_vault;
+
+        let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::InvalidAddress);
+    }
+
+    #[test]
+    fn throw_if_accounts_not_writable() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let maker_ata = init_ata(litesvm, mint_to_raise, maker.pubkey(), 0);
+
+        let fee_collector_ata = get_associated_token_address_with_program_id(
+            &FEE_COLLECTOR,
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        for index in [3, 4] {
+            litesvm.expire_blockhash();
+
+            let data = vec![3u8];
+            let mut ix = Instruction {
+                program_id: PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new(maker.pubkey(), true),
+                    AccountMeta::new_readonly(mint_to_raise, false),
+                    AccountMeta::new(fundraise_pda, false),
+                    AccountMeta::new(vault, false),
+                    AccountMeta::new(maker_ata, false),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(get_config_pda(), false),
+                    AccountMeta::new_readonly(FEE_COLLECTOR, false),
+                    AccountMeta::new(fee_collector_ata, false),
+                    AccountMeta::new(maker.pubkey(), false),
+                ],
+                data,
+            };
+
+            ix.accounts[index].is_writable = false;
+
+            let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+            assert_error(res.unwrap_err(), FundraiserError::AccountNotWritable);
+        }
+    }
+
+    #[test]
+    fn claim_into_beneficiary_token_account() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 10_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 5_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let contribute_amount: u64 = 500_000;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        // init 9 more authorities to contribute and meet the fundraiser target
+        for _ in 0..9 {
+            let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+            let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+            let temp_contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+            let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+            let ix = Instruction {
+                program_id: PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new(authority.pubkey(), true),
+                    AccountMeta::new_readonly(mint_to_raise, false),
+                    AccountMeta::new(fundraise_pda, false),
+                    AccountMeta::new(temp_contributor_pda, false),
+                    AccountMeta::new(authority_ata, false),
+                    AccountMeta::new(vault, false),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(get_config_pda(), false),
+                ],
+                data,
+            };
+
+            let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+        }
+
+        forward_time(litesvm, 3600); // jump forward 1 hour
+
+        // the multisig program pre-creates a non-associated token account for its vault PDA
+        let (multisig_vault, _) = Pubkey::find_program_address(
+            &[b"vault", maker.pubkey().as_ref()],
+            &MOCK_MULTISIG_PROGRAM_ID,
+        );
+        let multisig_token_account = Pubkey::new_unique();
+        init_token_account(
+            litesvm,
+            multisig_token_account,
+            mint_to_raise,
+            multisig_vault,
+            0,
+        );
+
+        let fee_collector_ata = get_associated_token_address_with_program_id(
+            &FEE_COLLECTOR,
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        let data = vec![3u8, 1u8];
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new(multisig_token_account, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+                AccountMeta::new_readonly(FEE_COLLECTOR, false),
+                AccountMeta::new(fee_collector_ata, false),
+                AccountMeta::new(maker.pubkey(), false),
+                AccountMeta::new_readonly(multisig_vault, false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let vault_bal = fetch_account::<Account>(litesvm, &vault).amount;
+        let multisig_bal = fetch_account::<Account>(litesvm, &multisig_token_account).amount;
+
+        assert_eq!(vault_bal, 0);
+        assert_eq!(multisig_bal, 5_000_000);
+    }
+
+    #[test]
+    fn throw_if_beneficiary_not_token_account_owner() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 10_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 5_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let contribute_amount: u64 = 500_000;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        // init 9 more authorities to contribute and meet the fundraiser target
+        for _ in 0..9 {
+            let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+            let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+            let temp_contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+            let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+            let ix = Instruction {
+                program_id: PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new(authority.pubkey(), true),
+                    AccountMeta::new_readonly(mint_to_raise, false),
+                    AccountMeta::new(fundraise_pda, false),
+                    AccountMeta::new(temp_contributor_pda, false),
+                    AccountMeta::new(authority_ata, false),
+                    AccountMeta::new(vault, false),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(get_config_pda(), false),
+                ],
+                data,
+            };
+
+            let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+        }
+
+        forward_time(litesvm, 3600); // jump forward 1 hour
+
+        // the multisig program pre-creates a non-associated token account for its vault PDA
+        let (multisig_vault, _) = Pubkey::find_program_address(
+            &[b"vault", maker.pubkey().as_ref()],
+            &MOCK_MULTISIG_PROGRAM_ID,
+        );
+        let multisig_token_account = Pubkey::new_unique();
+        init_token_account(
+            litesvm,
+            multisig_token_account,
+            mint_to_raise,
+            multisig_vault,
+            0,
+        );
+
+        let fee_collector_ata = get_associated_token_address_with_program_id(
+            &FEE_COLLECTOR,
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        let data = vec![3u8, 1u8];
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new(multisig_token_account, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+                AccountMeta::new_readonly(FEE_COLLECTOR, false),
+                AccountMeta::new(fee_collector_ata, false),
+                AccountMeta::new(maker.pubkey(), false),
+                AccountMeta::new_readonly(maker.pubkey(), false),
+            ],
+            data,
+        };
+
+        let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::InvalidAddress);
+    }
+
+    #[test]
+    fn claim_leaves_registry() {
+        let (litesvm, _default_payer) = &mut setup();
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 10_000_000_000);
+        let registry_pda = get_registry_pda(&mint_to_raise);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            10_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            vec![0u8, 0u8],
+            0i64.to_le_bytes().to_vec(),
+            vec![
+                RefundPolicy::Flexible as u8,
+                CampaignType::AllOrNothing as u8,
+            ],
+            vec![0u8; 32],
+            vec![1u8],
+        ]
+        .concat();
+
+        // two campaigns raising the same mint share its registry
+        let mut campaigns = vec![];
+
+        for _ in 0..2 {
+            let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+            let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+            let vault = get_vault_pda(&fundraise_pda);
+
+            let ix = Instruction {
+                program_id: PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new(maker.pubkey(), true),
+                    AccountMeta::new_readonly(mint_to_raise, false),
+                    AccountMeta::new(fundraise_pda, false),
+                    AccountMeta::new(vault, false),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(get_config_pda(), false),
+                    AccountMeta::new(registry_pda, false),
+                ],
+                data: data.clone(),
+            };
+
+            build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+            campaigns.push((maker, fundraise_pda, vault));
+        }
+
+        let registry = Registry::unpack(&litesvm.get_account(&registry_pda).unwrap().data).unwrap();
+
+        assert_eq!(registry.mint, mint_to_raise.to_bytes());
+        assert_eq!(registry.active_fundraisers, 2);
+        assert_eq!(registry.last_fundraise, campaigns[1].1.to_bytes());
+
+        let (maker, fundraise_pda, vault) = &campaigns[0];
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), amount_to_raise);
+        let contributor_pda = get_contributor_pda(fundraise_pda, &authority.pubkey());
+
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(*fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data: [vec![1u8], amount_to_raise.to_le_bytes().to_vec()].concat(),
+        };
+
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+
+        let maker_ata = init_ata(litesvm, mint_to_raise, maker.pubkey(), 0);
+        let fee_collector_ata = get_associated_token_address_with_program_id(
+            &FEE_COLLECTOR,
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        let mut claim_ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(*fundraise_pda, false),
+                AccountMeta::new(*vault, false),
+                AccountMeta::new(maker_ata, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+                AccountMeta::new_readonly(FEE_COLLECTOR, false),
+                AccountMeta::new(fee_collector_ata, false),
+                AccountMeta::new(maker.pubkey(), false),
+            ],
+            data: vec![3u8],
+        };
+
+        // a registered fundraise cannot be claimed without leaving its registry
+        let res = build_and_send_transaction(
+            litesvm,
+            &[maker],
+            &maker.pubkey(),
+            core::slice::from_ref(&claim_ix),
+        );
+
+        assert_error(res.unwrap_err(), FundraiserError::MissingAccounts);
+
+        claim_ix
+            .accounts
+            .push(AccountMeta::new(registry_pda, false));
+
+        build_and_send_transaction(litesvm, &[maker], &maker.pubkey(), &[claim_ix]).unwrap();
+
+        let registry = Registry::unpack(&litesvm.get_account(&registry_pda).unwrap().data).unwrap();
+
+        assert_eq!(registry.active_fundraisers, 1);
+        assert_eq!(registry.last_fundraise, campaigns[1].1.to_bytes());
+    }
+
+    #[test]
+    fn claim_after_creating_atas_in_same_transaction() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        init_ata(litesvm, mint_to_raise, authority.pubkey(), 5_000_000);
+
+        let ix = client::initialize(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            5_000_000,
+            SECONDS_PER_DAY,
+        );
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let ix = client::contribute(
+            &authority.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            5_000_000,
+        );
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+
+        init_config(litesvm, maker.pubkey(), 100, FEE_COLLECTOR, false);
+
+        let maker_ata = get_associated_token_address_with_program_id(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+        let fee_collector_ata = get_associated_token_address_with_program_id(
+            &FEE_COLLECTOR,
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        // ATA program Create, ahead of the claim that would otherwise create the same accounts
+        let create_ata = |wallet: Pubkey, ata: Pubkey| Instruction {
+            program_id: ASSOCIATED_TOKEN_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new(ata, false),
+                AccountMeta::new_readonly(wallet, false),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            ],
+            data: vec![0],
+        };
+        let ixs = [
+            create_ata(maker.pubkey(), maker_ata),
+            create_ata(FEE_COLLECTOR, fee_collector_ata),
+            client::claim(
+                &maker.pubkey(),
+                &mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+                &FEE_COLLECTOR,
+            ),
+        ];
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &ixs).unwrap();
+
+        assert_eq!(
+            fetch_account::<Account>(litesvm, &maker_ata).amount,
+            4_950_000
+        );
+        assert_eq!(
+            fetch_account::<Account>(litesvm, &fee_collector_ata).amount,
+            50_000
+        );
+    }
+
+    #[test]
+    fn claim_returns_bond() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        init_ata(litesvm, mint_to_raise, authority.pubkey(), 5_000_000);
+
+        let bond = LAMPORTS_PER_SOL / 10;
+        set_config_bond(litesvm, bond, 2_500);
+
+        let ix = client::initialize(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            5_000_000,
+            SECONDS_PER_DAY,
+        );
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let rent = litesvm.minimum_balance_for_rent_exemption(Fundraise::LEN);
+
+        assert_eq!(litesvm.get_balance(&fundraise_pda), Some(rent + bond));
+
+        let ix = client::contribute(
+            &authority.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            5_000_000,
+        );
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+
+        let ix = client::claim(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            &FEE_COLLECTOR,
+        );
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let fundraise = litesvm.get_account(&fundraise_pda).unwrap();
+
+        // back to exactly rent exempt, with nothing of the bond left behind
+        assert_eq!(fundraise.lamports, rent);
+        assert_eq!(Fundraise::unpack(&fundraise.data).unwrap().bond, 0);
+    }
+
+    fn matched_campaign(
+        litesvm: &mut LiteSVM,
+        pool: u64,
+        contribution: u64,
+    ) -> (Keypair, Keypair, Pubkey) {
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let sponsor = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        init_ata(litesvm, mint_to_raise, sponsor.pubkey(), pool);
+        init_ata(litesvm, mint_to_raise, authority.pubkey(), contribution);
+
+        let ix = client::initialize(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            5_000_000,
+            SECONDS_PER_DAY,
+        );
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let ix = client::deposit_match(
+            &sponsor.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            pool,
+        );
+        build_and_send_transaction(litesvm, &[&sponsor], &sponsor.pubkey(), &[ix]).unwrap();
+
+        let ix = client::contribute(
+            &authority.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            contribution,
+        );
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+
+        (maker, sponsor, mint_to_raise)
+    }
+
+    #[test]
+    fn claim_pays_match_capped_by_pool() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, sponsor, mint_to_raise) = matched_campaign(litesvm, 3_000_000, 5_000_000);
+
+        let ix = client::with_match(
+            client::claim(
+                &maker.pubkey(),
+                &mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+                &FEE_COLLECTOR,
+            ),
+            &sponsor.pubkey(),
+        );
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let maker_ata = get_associated_token_address_with_program_id(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+        let match_vault = client::associated_token_address(
+            &get_fundraise_pda(&maker.pubkey()),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+        let sponsor_ata = get_associated_token_address_with_program_id(
+            &sponsor.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        // the whole pool matches, since contributions came to more than it
+        assert_eq!(
+            fetch_account::<Account>(litesvm, &maker_ata).amount,
+            8_000_000
+        );
+        assert_eq!(fetch_account::<Account>(litesvm, &match_vault).amount, 0);
+        assert_eq!(fetch_account::<Account>(litesvm, &sponsor_ata).amount, 0);
+
+        let fundraise_acc = litesvm
+            .get_account(&get_fundraise_pda(&maker.pubkey()))
+            .unwrap();
+
+        assert_eq!(
+            Fundraise::unpack(&fundraise_acc.data).unwrap().match_pool,
+            0
+        );
+    }
+
+    #[test]
+    fn claim_returns_unmatched_pool() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, sponsor, mint_to_raise) = matched_campaign(litesvm, 8_000_000, 5_000_000);
+
+        let ix = client::with_match(
+            client::claim(
+                &maker.pubkey(),
+                &mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+                &FEE_COLLECTOR,
+            ),
+            &sponsor.pubkey(),
+        );
+        let (_, data) = simulate_return_data(
+            litesvm,
+            &[&maker],
+            &maker.pubkey(),
+            core::slice::from_ref(&ix),
+        );
+
+        // the match is part of what the maker is reported to have claimed
+        assert_eq!(
+            ClaimReturnData::from_bytes(&data.try_into().unwrap()),
+            ClaimReturnData {
+                claimed_amount: 10_000_000,
+            }
+        );
+
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let maker_ata = get_associated_token_address_with_program_id(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+        let sponsor_ata = get_associated_token_address_with_program_id(
+            &sponsor.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        // contributions are matched one for one, and the 3_000_000 they left goes back
+        assert_eq!(
+            fetch_account::<Account>(litesvm, &maker_ata).amount,
+            10_000_000
+        );
+        assert_eq!(
+            fetch_account::<Account>(litesvm, &sponsor_ata).amount,
+            3_000_000
+        );
+    }
+
+    #[test]
+    fn throw_if_match_accounts_missing() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, _sponsor, mint_to_raise) = matched_campaign(litesvm, 3_000_000, 5_000_000);
+
+        let ix = client::claim(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            &FEE_COLLECTOR,
+        );
+        let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::MissingAccounts);
+    }
+
+    #[test]
+    fn throw_if_match_sponsor_mismatch() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, _sponsor, mint_to_raise) = matched_campaign(litesvm, 3_000_000, 5_000_000);
+        let other = init_wallet(litesvm, LAMPORTS_PER_SOL);
+
+        let ix = client::with_match(
+            client::claim(
+                &maker.pubkey(),
+                &mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+                &FEE_COLLECTOR,
+            ),
+            &other.pubkey(),
+        );
+        let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::InvalidAddress);
     }
 }
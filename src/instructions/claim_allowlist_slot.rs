@@ -0,0 +1,507 @@
+use core::mem::size_of;
+use pinocchio::{
+    ProgramResult, account_info::AccountInfo, instruction::Seed, program_error::ProgramError,
+    pubkey::find_program_address,
+};
+
+use crate::{
+    AccountCheck, AccountLoad, AllowlistPage, AllowlistReceipt, AllowlistReceiptParams, Fundraise,
+    FundraiserError, FundraiserInstruction, Handler, Prefix, ProgramAccount, ProgramIdCheck,
+    SetInner, SignerAccount, WritableAccount, check_account,
+};
+
+pub struct ClaimAllowlistSlotAccounts<'a> {
+    pub wallet: &'a AccountInfo,
+    pub maker: &'a AccountInfo,
+    pub fundraise: &'a AccountInfo,
+    pub page: &'a AccountInfo,
+    pub receipt: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for ClaimAllowlistSlotAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [wallet, maker, fundraise, page, receipt, system_program] = accounts else {
+            return Err(FundraiserError::MissingAccounts.into());
+        };
+
+        check_account!(0, "signer", SignerAccount::check(wallet))?;
+        check_account!(0, "writable", WritableAccount::check(wallet))?;
+        check_account!(1, "signer", SignerAccount::check(maker))?;
+        check_account!(3, "writable", WritableAccount::check(page))?;
+        check_account!(4, "writable", WritableAccount::check(receipt))?;
+        check_account!(
+            5,
+            "program id",
+            ProgramIdCheck::check(system_program, &pinocchio_system::ID)
+        )?;
+        check_account!(2, "owner", ProgramAccount::check(fundraise))?;
+        check_account!(3, "owner", ProgramAccount::check(page))?;
+
+        Ok(Self {
+            wallet,
+            maker,
+            fundraise,
+            page,
+            receipt,
+            system_program,
+        })
+    }
+}
+
+pub struct ClaimAllowlistSlotInstructionData {
+    pub page_index: u16,
+    pub bit_index: u16,
+}
+
+impl ClaimAllowlistSlotInstructionData {
+    pub const LEN: usize = size_of::<u16>() * 2;
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut bytes = [0u8; Self::LEN];
+
+        bytes[0..2].copy_from_slice(&self.page_index.to_le_bytes());
+        bytes[2..4].copy_from_slice(&self.bit_index.to_le_bytes());
+
+        bytes
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for ClaimAllowlistSlotInstructionData {
+    type Error = ProgramError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        if data.len() != size_of::<u16>() * 2 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let offset = size_of::<u16>();
+        let page_index = u16::from_le_bytes(data[0..offset].try_into().unwrap());
+        let bit_index =
+            u16::from_le_bytes(data[offset..offset + size_of::<u16>()].try_into().unwrap());
+
+        Ok(Self {
+            page_index,
+            bit_index,
+        })
+    }
+}
+
+/// Assigns an allowlist slot to a wallet. The maker co-signs to approve the wallet, the bit is
+/// set on the page, and the wallet pays for a receipt mapping it to the slot. Each wallet can
+/// claim a single slot per fundraise, and each slot is claimed once.
+pub struct ClaimAllowlistSlot<'a> {
+    pub accounts: ClaimAllowlistSlotAccounts<'a>,
+    pub data: ClaimAllowlistSlotInstructionData,
+    pub bump: u8,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for ClaimAllowlistSlot<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = ClaimAllowlistSlotAccounts::try_from(accounts)?;
+        let data = ClaimAllowlistSlotInstructionData::try_from(data)?;
+
+        let (pda, bump) = find_program_address(
+            &[
+                AllowlistReceipt::PREFIX,
+                accounts.fundraise.key(),
+                accounts.wallet.key(),
+            ],
+            &crate::ID,
+        );
+
+        if pda != *accounts.receipt.key() {
+            return Err(FundraiserError::InvalidAddress.into());
+        }
+
+        let receipt_bump = [bump];
+        let receipt_seeds = [
+            Seed::from(AllowlistReceipt::PREFIX),
+            Seed::from(accounts.fundraise.key().as_ref()),
+            Seed::from(accounts.wallet.key().as_ref()),
+            Seed::from(&receipt_bump),
+        ];
+
+        ProgramAccount::init::<AllowlistReceipt>(
+            accounts.wallet,
+            accounts.receipt,
+            &receipt_seeds,
+        )?;
+
+        Ok(Self {
+            accounts,
+            data,
+            bump,
+        })
+    }
+}
+
+impl Handler for ClaimAllowlistSlot<'_> {
+    const DISCRIMINATOR: u8 = FundraiserInstruction::ClaimAllowlistSlot as u8;
+
+    fn process(&mut self) -> ProgramResult {
+        let fundraise_data = self.accounts.fundraise.try_borrow_data()?;
+        let fundraise = Fundraise::load(&fundraise_data)?;
+
+        ProgramAccount::validate(
+            &[
+                Fundraise::PREFIX,
+                self.accounts.maker.key(),
+                &[fundraise.bump],
+            ],
+            *self.accounts.fundraise.key(),
+        )?;
+
+        drop(fundraise_data);
+
+        let mut page_data = self.accounts.page.try_borrow_mut_data()?;
+        let page = AllowlistPage::load_mut(page_data.as_mut())?;
+
+        if page.get_page_index() != self.data.page_index {
+            return Err(FundraiserError::InvalidAddress.into());
+        }
+
+        ProgramAccount::validate(
+            &[
+                AllowlistPage::PREFIX,
+                self.accounts.fundraise.key(),
+                &self.data.page_index.to_le_bytes(),
+                &[page.bump],
+            ],
+            *self.accounts.page.key(),
+        )?;
+
+        page.set(self.data.bit_index)?;
+
+        let mut receipt_data = self.accounts.receipt.try_borrow_mut_data()?;
+        let receipt = AllowlistReceipt::load_mut(receipt_data.as_mut())?;
+
+        receipt.set_inner(AllowlistReceiptParams::new(
+            *self.accounts.fundraise.key(),
+            *self.accounts.wallet.key(),
+            self.data.page_index,
+            self.data.bit_index,
+            self.bump,
+        ));
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_signer::Signer;
+    use spl_associated_token_account::solana_program::{
+        clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL,
+    };
+
+    use crate::{
+        ALLOWLIST_PAGE_BITS, AccountLoad, AllowlistPage, AllowlistReceipt, FundraiserError,
+        tests::{
+            constants::{
+                ASSOCIATED_TOKEN_PROGRAM_ID, MINT_DECIMALS, PROGRAM_ID, SYSTEM_PROGRAM_ID,
+                TOKEN_PROGRAM_ID,
+            },
+            pda::{
+                get_allowlist_page_pda, get_allowlist_receipt_pda, get_config_pda,
+                get_fundraise_pda, get_vault_pda,
+            },
+            utils::{assert_error, build_and_send_transaction, init_mint, init_wallet, setup},
+        },
+    };
+
+    #[test]
+    fn claim_allowlist_slot() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            1u8.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let page_index: u16 = 0;
+        let page_pda = get_allowlist_page_pda(&fundraise_pda, page_index);
+
+        let data = [vec![18u8], page_index.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(fundraise_pda, false),
+                AccountMeta::new(page_pda, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let bit_index: u16 = 42;
+        let receipt_pda = get_allowlist_receipt_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [
+            vec![19u8],
+            page_index.to_le_bytes().to_vec(),
+            bit_index.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(maker.pubkey(), true),
+                AccountMeta::new_readonly(fundraise_pda, false),
+                AccountMeta::new(page_pda, false),
+                AccountMeta::new(receipt_pda, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+            data,
+        };
+
+        let _ =
+            build_and_send_transaction(litesvm, &[&authority, &maker], &authority.pubkey(), &[ix]);
+
+        let receipt_acc = litesvm.get_account(&receipt_pda).unwrap();
+        let receipt = AllowlistReceipt::load(receipt_acc.data.as_ref()).unwrap();
+
+        assert_eq!(receipt.fundraise, fundraise_pda.to_bytes());
+        assert_eq!(receipt.wallet, authority.pubkey().to_bytes());
+        assert_eq!(receipt.get_page_index(), page_index);
+        assert_eq!(receipt.get_bit_index(), bit_index);
+
+        let page_acc = litesvm.get_account(&page_pda).unwrap();
+        let page = AllowlistPage::load(page_acc.data.as_ref()).unwrap();
+
+        assert!(page.is_set(bit_index).unwrap());
+    }
+
+    #[test]
+    fn throw_if_slot_taken() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let other = init_wallet(litesvm, LAMPORTS_PER_SOL);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            1u8.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let page_index: u16 = 0;
+        let page_pda = get_allowlist_page_pda(&fundraise_pda, page_index);
+
+        let data = [vec![18u8], page_index.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(fundraise_pda, false),
+                AccountMeta::new(page_pda, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let bit_index: u16 = 42;
+        let receipt_pda = get_allowlist_receipt_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [
+            vec![19u8],
+            page_index.to_le_bytes().to_vec(),
+            bit_index.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(maker.pubkey(), true),
+                AccountMeta::new_readonly(fundraise_pda, false),
+                AccountMeta::new(page_pda, false),
+                AccountMeta::new(receipt_pda, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+            data,
+        };
+
+        let _ =
+            build_and_send_transaction(litesvm, &[&authority, &maker], &authority.pubkey(), &[ix]);
+
+        let other_receipt_pda = get_allowlist_receipt_pda(&fundraise_pda, &other.pubkey());
+
+        let data = [
+            vec![19u8],
+            page_index.to_le_bytes().to_vec(),
+            bit_index.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(other.pubkey(), true),
+                AccountMeta::new_readonly(maker.pubkey(), true),
+                AccountMeta::new_readonly(fundraise_pda, false),
+                AccountMeta::new(page_pda, false),
+                AccountMeta::new(other_receipt_pda, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+            data,
+        };
+
+        let res = build_and_send_transaction(litesvm, &[&other, &maker], &other.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::AllowlistSlotTaken);
+    }
+
+    #[test]
+    fn throw_if_slot_out_of_range() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            1u8.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let page_index: u16 = 0;
+        let page_pda = get_allowlist_page_pda(&fundraise_pda, page_index);
+
+        let data = [vec![18u8], page_index.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(fundraise_pda, false),
+                AccountMeta::new(page_pda, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let bit_index: u16 = ALLOWLIST_PAGE_BITS;
+        let receipt_pda = get_allowlist_receipt_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [
+            vec![19u8],
+            page_index.to_le_bytes().to_vec(),
+            bit_index.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(maker.pubkey(), true),
+                AccountMeta::new_readonly(fundraise_pda, false),
+                AccountMeta::new(page_pda, false),
+                AccountMeta::new(receipt_pda, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+            data,
+        };
+
+        let res =
+            build_and_send_transaction(litesvm, &[&authority, &maker], &authority.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::InvalidAllowlistSlot);
+    }
+}
@@ -0,0 +1,498 @@
+use pinocchio::{ProgramResult, account_info::AccountInfo, program_error::ProgramError};
+
+use crate::{
+    AccountCheck, AccountLoad, AssociatedTokenAccount, Contributor, Fundraise, FundraiserError,
+    FundraiserInstruction, Handler, Liability, MintInterface, Prefix, ProgramAccount,
+    ProgramIdCheck, SignerAccount, WritableAccount, check_account, vault_transfer,
+};
+
+pub struct ClaimRewardAccounts<'a> {
+    pub authority: &'a AccountInfo,
+    pub fundraise: &'a AccountInfo,
+    pub contributor: &'a AccountInfo,
+    pub reward_mint: &'a AccountInfo,
+    pub reward_vault: &'a AccountInfo,
+    /// The authority's associated token account of the reward mint, created if it does not
+    /// exist yet.
+    pub authority_token_account: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+    pub token_program: &'a AccountInfo,
+    pub associated_token_program: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for ClaimRewardAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [
+            authority,
+            fundraise,
+            contributor,
+            reward_mint,
+            reward_vault,
+            authority_token_account,
+            system_program,
+            token_program,
+            associated_token_program,
+        ] = accounts
+        else {
+            return Err(FundraiserError::MissingAccounts.into());
+        };
+
+        check_account!(0, "signer", SignerAccount::check(authority))?;
+        check_account!(0, "writable", WritableAccount::check(authority))?;
+        check_account!(1, "writable", WritableAccount::check(fundraise))?;
+        check_account!(2, "writable", WritableAccount::check(contributor))?;
+        check_account!(4, "writable", WritableAccount::check(reward_vault))?;
+        check_account!(
+            5,
+            "writable",
+            WritableAccount::check(authority_token_account)
+        )?;
+        check_account!(3, "mint", MintInterface::check(reward_mint))?;
+        check_account!(
+            6,
+            "program id",
+            ProgramIdCheck::check(system_program, &pinocchio_system::ID)
+        )?;
+        check_account!(
+            7,
+            "program id",
+            ProgramIdCheck::check(token_program, reward_mint.owner())
+        )?;
+        check_account!(
+            8,
+            "program id",
+            ProgramIdCheck::check(
+                associated_token_program,
+                &pinocchio_associated_token_account::ID,
+            )
+        )?;
+        check_account!(1, "owner", ProgramAccount::check(fundraise))?;
+        check_account!(2, "owner", ProgramAccount::check(contributor))?;
+        check_account!(
+            4,
+            "address",
+            AssociatedTokenAccount::check_address(
+                reward_vault,
+                fundraise,
+                reward_mint,
+                token_program
+            )
+        )?;
+        check_account!(
+            5,
+            "address",
+            AssociatedTokenAccount::check_address(
+                authority_token_account,
+                authority,
+                reward_mint,
+                token_program,
+            )
+        )?;
+
+        Ok(Self {
+            authority,
+            fundraise,
+            contributor,
+            reward_mint,
+            reward_vault,
+            authority_token_account,
+            system_program,
+            token_program,
+            associated_token_program,
+        })
+    }
+}
+
+/// Pays a contributor their share of the fundraise's rewards once the maker has claimed,
+/// `amount * reward_amount / amount_to_raise` rounded down, or counted against everything
+/// contributed if that overshot the target. Each contributor claims once.
+pub struct ClaimReward<'a> {
+    pub accounts: ClaimRewardAccounts<'a>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for ClaimReward<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        Ok(Self {
+            accounts: ClaimRewardAccounts::try_from(accounts)?,
+        })
+    }
+}
+
+impl Handler for ClaimReward<'_> {
+    const DISCRIMINATOR: u8 = FundraiserInstruction::ClaimReward as u8;
+
+    fn process(&mut self) -> ProgramResult {
+        let mut fundraise_data = self.accounts.fundraise.try_borrow_mut_data()?;
+        let fundraise = Fundraise::load_mut(fundraise_data.as_mut())?;
+
+        ProgramAccount::validate(
+            &[Fundraise::PREFIX, &fundraise.maker, &[fundraise.bump]],
+            *self.accounts.fundraise.key(),
+        )?;
+
+        if !fundraise.has_rewards() {
+            return Err(FundraiserError::NoRewards.into());
+        }
+
+        if fundraise.reward_mint != *self.accounts.reward_mint.key() {
+            return Err(FundraiserError::InvalidAddress.into());
+        }
+
+        // shares are only fixed once the claim records what was raised
+        if !fundraise.is_claimed() {
+            return Err(FundraiserError::RewardsLocked.into());
+        }
+
+        let mut contributor_data = self.accounts.contributor.try_borrow_mut_data()?;
+        let contributor = Contributor::load_mut(contributor_data.as_mut())?;
+
+        if contributor.authority != *self.accounts.authority.key() {
+            return Err(FundraiserError::InvalidAddress.into());
+        }
+
+        ProgramAccount::validate(
+            &[
+                Contributor::PREFIX,
+                self.accounts.fundraise.key(),
+                self.accounts.authority.key(),
+                &[contributor.bump],
+            ],
+            *self.accounts.contributor.key(),
+        )?;
+
+        if contributor.is_reward_claimed() {
+            return Err(FundraiserError::RewardAlreadyClaimed.into());
+        }
+
+        // the claim settled the campaign, so this is as good a time as any to snapshot
+        contributor.snapshot(fundraise)?;
+
+        let amount = contributor.get_amount();
+        let reward = fundraise.reward_for(amount);
+
+        fundraise.record_reward_claimed(amount)?;
+        contributor.reward_claimed = 1;
+
+        drop(contributor_data);
+        drop(fundraise_data);
+
+        if reward == 0 {
+            return Ok(());
+        }
+
+        AssociatedTokenAccount::init_if_needed(
+            self.accounts.authority_token_account,
+            self.accounts.reward_mint,
+            self.accounts.authority,
+            self.accounts.authority,
+            self.accounts.system_program,
+            self.accounts.token_program,
+        )?;
+
+        vault_transfer(
+            Liability::Rewards,
+            self.accounts.reward_vault,
+            self.accounts.reward_mint,
+            self.accounts.authority_token_account,
+            reward,
+            self.accounts.fundraise,
+            &[],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use litesvm::LiteSVM;
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_keypair::Keypair;
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use spl_associated_token_account::solana_program::{
+        clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL,
+    };
+    use spl_token_2022::state::Account;
+
+    use crate::{
+        Contributor, Fundraise, FundraiserError, client, cpi, normalize,
+        tests::{
+            constants::{
+                FEE_COLLECTOR, MINT_DECIMALS, PROGRAM_ID, SYSTEM_PROGRAM_ID, TOKEN_PROGRAM_ID,
+            },
+            utils::{
+                assert_error, build_and_send_transaction, fetch_account, init_ata, init_mint,
+                init_wallet, setup,
+            },
+        },
+    };
+
+    const AMOUNT_TO_RAISE: u64 = 5_000_000;
+    const REWARDS: u64 = 1_000_000_007;
+
+    struct Campaign {
+        maker: Keypair,
+        mint_to_raise: Pubkey,
+        reward_mint: Pubkey,
+    }
+
+    fn initialize(litesvm: &mut LiteSVM) -> Campaign {
+        initialize_with_decimals(litesvm, MINT_DECIMALS, 9, AMOUNT_TO_RAISE, REWARDS)
+    }
+
+    fn initialize_with_decimals(
+        litesvm: &mut LiteSVM,
+        raise_decimals: u8,
+        reward_decimals: u8,
+        amount_to_raise: u64,
+        rewards: u64,
+    ) -> Campaign {
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(
+            litesvm,
+            TOKEN_PROGRAM_ID,
+            raise_decimals,
+            amount_to_raise * 2,
+        );
+        let reward_mint = init_mint(litesvm, TOKEN_PROGRAM_ID, reward_decimals, rewards);
+        init_ata(litesvm, reward_mint, maker.pubkey(), rewards);
+
+        let ix = client::with_rewards(
+            client::initialize(
+                &maker.pubkey(),
+                &mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+                amount_to_raise,
+                SECONDS_PER_DAY,
+            ),
+            &reward_mint,
+            rewards,
+        );
+        let deposit =
+            client::deposit_rewards(&maker.pubkey(), &reward_mint, &TOKEN_PROGRAM_ID, rewards);
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix, deposit]).unwrap();
+
+        Campaign {
+            maker,
+            mint_to_raise,
+            reward_mint,
+        }
+    }
+
+    fn contribute(litesvm: &mut LiteSVM, campaign: &Campaign, amount: u64) -> Keypair {
+        let backer = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        init_ata(litesvm, campaign.mint_to_raise, backer.pubkey(), amount);
+
+        let ix = client::contribute(
+            &backer.pubkey(),
+            &campaign.maker.pubkey(),
+            &campaign.mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            amount,
+        );
+        build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]).unwrap();
+
+        backer
+    }
+
+    fn claim(litesvm: &mut LiteSVM, campaign: &Campaign) {
+        let maker = &campaign.maker;
+        let ix = client::claim(
+            &maker.pubkey(),
+            &campaign.mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            &FEE_COLLECTOR,
+        );
+        build_and_send_transaction(litesvm, &[maker], &maker.pubkey(), &[ix]).unwrap();
+    }
+
+    fn claim_reward_ix(campaign: &Campaign, backer: &Keypair) -> Instruction {
+        client::claim_reward(
+            &backer.pubkey(),
+            &campaign.maker.pubkey(),
+            &campaign.reward_mint,
+            &TOKEN_PROGRAM_ID,
+        )
+    }
+
+    fn reward_balance(litesvm: &LiteSVM, campaign: &Campaign, owner: &Pubkey) -> u64 {
+        let address =
+            client::associated_token_address(owner, &campaign.reward_mint, &TOKEN_PROGRAM_ID);
+
+        fetch_account::<Account>(litesvm, &address).amount
+    }
+
+    #[test]
+    fn claim_reward_pays_pro_rata() {
+        let (litesvm, _default_payer) = &mut setup();
+        let campaign = initialize(litesvm);
+        let backers = [1_000_000, 1_500_000, 2_500_000]
+            .map(|amount| (contribute(litesvm, &campaign, amount), amount));
+
+        claim(litesvm, &campaign);
+
+        for (backer, _) in &backers {
+            let ix = claim_reward_ix(&campaign, backer);
+            build_and_send_transaction(litesvm, &[backer], &backer.pubkey(), &[ix]).unwrap();
+        }
+
+        // 1_000_000_007 shared 1:1.5:2.5, each share rounded down
+        for ((backer, _), expected) in backers.iter().zip([200_000_001, 300_000_002, 500_000_003]) {
+            assert_eq!(
+                reward_balance(litesvm, &campaign, &backer.pubkey()),
+                expected
+            );
+        }
+
+        let fundraise = client::fundraise_address(&campaign.maker.pubkey());
+
+        assert_eq!(reward_balance(litesvm, &campaign, &fundraise), 1);
+
+        let fundraise = Fundraise::unpack(&litesvm.get_account(&fundraise).unwrap().data).unwrap();
+
+        assert_eq!(fundraise.reward_basis, AMOUNT_TO_RAISE);
+        assert_eq!(fundraise.reward_claimed, AMOUNT_TO_RAISE);
+
+        for (backer, amount) in &backers {
+            let contributor = client::contributor_address(
+                &client::fundraise_address(&campaign.maker.pubkey()),
+                &backer.pubkey(),
+            );
+            let contributor =
+                Contributor::unpack(&litesvm.get_account(&contributor).unwrap().data).unwrap();
+
+            assert_eq!(contributor.amount, *amount);
+            assert_eq!(contributor.reward_claimed, 1);
+        }
+    }
+
+    #[test]
+    fn claim_reward_across_mismatched_decimals() {
+        let (litesvm, _default_payer) = &mut setup();
+        let amount_to_raise = 5_000_000_000;
+        // one 6-decimal reward token per whole 9-decimal token raised
+        let rewards = normalize(amount_to_raise, 9, 6).unwrap();
+        let campaign = initialize_with_decimals(litesvm, 9, 6, amount_to_raise, rewards);
+        let backers = [1_000_000_000, 1_500_000_000, 2_500_000_000]
+            .map(|amount| (contribute(litesvm, &campaign, amount), amount));
+
+        claim(litesvm, &campaign);
+
+        for (backer, amount) in &backers {
+            let ix = claim_reward_ix(&campaign, backer);
+            build_and_send_transaction(litesvm, &[backer], &backer.pubkey(), &[ix]).unwrap();
+
+            assert_eq!(
+                reward_balance(litesvm, &campaign, &backer.pubkey()),
+                normalize(*amount, 9, 6).unwrap()
+            );
+        }
+
+        let fundraise = client::fundraise_address(&campaign.maker.pubkey());
+
+        assert_eq!(reward_balance(litesvm, &campaign, &fundraise), 0);
+
+        let fundraise = Fundraise::unpack(&litesvm.get_account(&fundraise).unwrap().data).unwrap();
+
+        assert_eq!(fundraise.mint_decimals, 9);
+        assert_eq!(fundraise.reward_basis, amount_to_raise);
+    }
+
+    #[test]
+    fn throw_if_reward_claimed_twice() {
+        let (litesvm, _default_payer) = &mut setup();
+        let campaign = initialize(litesvm);
+        let backer = contribute(litesvm, &campaign, AMOUNT_TO_RAISE);
+
+        claim(litesvm, &campaign);
+
+        let ix = claim_reward_ix(&campaign, &backer);
+        build_and_send_transaction(
+            litesvm,
+            &[&backer],
+            &backer.pubkey(),
+            std::slice::from_ref(&ix),
+        )
+        .unwrap();
+        litesvm.expire_blockhash();
+
+        let res = build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::RewardAlreadyClaimed);
+        assert_eq!(
+            reward_balance(litesvm, &campaign, &backer.pubkey()),
+            REWARDS
+        );
+    }
+
+    #[test]
+    fn throw_if_claimed_reward_transferred() {
+        let (litesvm, _default_payer) = &mut setup();
+        let campaign = initialize(litesvm);
+        let backer = contribute(litesvm, &campaign, AMOUNT_TO_RAISE);
+        let buyer = init_wallet(litesvm, LAMPORTS_PER_SOL);
+
+        claim(litesvm, &campaign);
+
+        let ix = claim_reward_ix(&campaign, &backer);
+        build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]).unwrap();
+
+        // moving the position on would let the buyer claim its reward again
+        let fundraise = client::fundraise_address(&campaign.maker.pubkey());
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(backer.pubkey(), true),
+                AccountMeta::new(buyer.pubkey(), true),
+                AccountMeta::new(fundraise, false),
+                AccountMeta::new(
+                    client::contributor_address(&fundraise, &backer.pubkey()),
+                    false,
+                ),
+                AccountMeta::new(
+                    client::contributor_address(&fundraise, &buyer.pubkey()),
+                    false,
+                ),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+            data: cpi::transfer_contribution_ix_data().to_vec(),
+        };
+        let res = build_and_send_transaction(litesvm, &[&backer, &buyer], &backer.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::RewardAlreadyClaimed);
+    }
+
+    #[test]
+    fn throw_if_fundraise_unclaimed() {
+        let (litesvm, _default_payer) = &mut setup();
+        let campaign = initialize(litesvm);
+        let backer = contribute(litesvm, &campaign, AMOUNT_TO_RAISE);
+
+        let ix = claim_reward_ix(&campaign, &backer);
+        let res = build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::RewardsLocked);
+    }
+
+    #[test]
+    fn throw_if_signer_not_contributor() {
+        let (litesvm, _default_payer) = &mut setup();
+        let campaign = initialize(litesvm);
+        let backer = contribute(litesvm, &campaign, AMOUNT_TO_RAISE);
+        let other = init_wallet(litesvm, LAMPORTS_PER_SOL);
+
+        claim(litesvm, &campaign);
+
+        let mut ix = claim_reward_ix(&campaign, &other);
+        ix.accounts[2].pubkey = client::contributor_address(
+            &client::fundraise_address(&campaign.maker.pubkey()),
+            &backer.pubkey(),
+        );
+        let res = build_and_send_transaction(litesvm, &[&other], &other.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::InvalidAddress);
+    }
+}
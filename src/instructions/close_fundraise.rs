@@ -0,0 +1,474 @@
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    sysvars::{Sysvar, clock::Clock},
+};
+use pinocchio_token_2022::instructions::CloseAccount;
+
+use crate::{
+    AccountCheck, AccountLoad, Fundraise, FundraiseStatus, FundraiserError, FundraiserInstruction,
+    Handler, Liability, Prefix, ProgramAccount, Registry, TokenAccountInterface, TransferHook,
+    WritableAccount, check_account, vault_transfer,
+};
+
+pub struct CloseFundraiseAccounts<'a> {
+    pub maker: &'a AccountInfo,
+    pub fundraise: &'a AccountInfo,
+    /// Already closed if the last refund emptied it.
+    pub vault: &'a AccountInfo,
+    pub token_program: &'a AccountInfo,
+    pub registry: Option<&'a AccountInfo>,
+    pub sweep_accounts: Option<CloseFundraiseSweepAccounts<'a>>,
+}
+
+/// Passed after any registry when the vault holds tokens that were never recorded, which are
+/// swept to the maker before the vault is closed.
+pub struct CloseFundraiseSweepAccounts<'a> {
+    pub mint_to_raise: &'a AccountInfo,
+    /// Any token account of the mint to raise owned by the maker.
+    pub maker_token_account: &'a AccountInfo,
+    pub transfer_hook_accounts: &'a [AccountInfo],
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for CloseFundraiseAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [maker, fundraise, vault, token_program, rest @ ..] = accounts else {
+            return Err(FundraiserError::MissingAccounts.into());
+        };
+
+        check_account!(0, "writable", WritableAccount::check(maker))?;
+        check_account!(1, "writable", WritableAccount::check(fundraise))?;
+        check_account!(2, "writable", WritableAccount::check(vault))?;
+        check_account!(1, "owner", ProgramAccount::check(fundraise))?;
+
+        let fundraise_data = fundraise.try_borrow_data()?;
+        let fundraise_state = Fundraise::load(&fundraise_data)?;
+
+        check_account!(
+            3,
+            "token program",
+            fundraise_state.check_token_program(token_program.key())
+        )?;
+        check_account!(2, "vault", fundraise_state.check_vault(vault.key()))?;
+
+        // a registered fundraise leaves its registry, as it would have when claimed
+        let (registry, rest) = match fundraise_state.is_registered() {
+            true => rest
+                .split_first()
+                .map(|(registry, rest)| (Some(registry), rest))
+                .ok_or(FundraiserError::MissingAccounts)?,
+            false => (None, rest),
+        };
+
+        let sweep_accounts = match rest {
+            [mint_to_raise, maker_token_account, rest @ ..] => {
+                fundraise_state.check_mint_to_raise(mint_to_raise.key())?;
+                WritableAccount::check(maker_token_account)?;
+                TokenAccountInterface::check_mint_and_owner(
+                    maker_token_account,
+                    mint_to_raise.key(),
+                    maker.key(),
+                )?;
+
+                let (transfer_hook_accounts, _) =
+                    TransferHook::split_trailing(mint_to_raise, rest)?;
+
+                Some(CloseFundraiseSweepAccounts {
+                    mint_to_raise,
+                    maker_token_account,
+                    transfer_hook_accounts,
+                })
+            }
+            _ => None,
+        };
+        drop(fundraise_data);
+
+        if let Some(registry) = registry {
+            WritableAccount::check(registry)?;
+            ProgramAccount::check(registry)?;
+        }
+
+        Ok(Self {
+            maker,
+            fundraise,
+            vault,
+            token_program,
+            registry,
+            sweep_accounts,
+        })
+    }
+}
+
+/// Closes the fundraise and any vault left behind once a failed campaign is fully refunded,
+/// returning their rent to the maker. Anyone may send it. Tokens sent straight to the vault are
+/// swept to the maker first, so they cannot hold the close up. A claimed fundraise stays open, so
+/// the positions of its contributors never outlive it into a campaign reinitialized at the same
+/// address.
+pub struct CloseFundraise<'a> {
+    pub accounts: CloseFundraiseAccounts<'a>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for CloseFundraise<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        Ok(Self {
+            accounts: CloseFundraiseAccounts::try_from(accounts)?,
+        })
+    }
+}
+
+impl Handler for CloseFundraise<'_> {
+    const DISCRIMINATOR: u8 = FundraiserInstruction::CloseFundraise as u8;
+
+    fn process(&mut self) -> ProgramResult {
+        let fundraise_data = self.accounts.fundraise.try_borrow_data()?;
+        let fundraise = Fundraise::load(&fundraise_data)?;
+
+        let fundraise_maker = fundraise.maker;
+
+        if fundraise_maker != *self.accounts.maker.key() {
+            return Err(FundraiserError::InvalidAddress.into());
+        }
+
+        let fundraise_bump = [fundraise.bump];
+
+        ProgramAccount::validate(
+            &[Fundraise::PREFIX, &fundraise_maker, &fundraise_bump],
+            *self.accounts.fundraise.key(),
+        )?;
+
+        if fundraise.is_claimed() {
+            return Err(FundraiserError::AlreadyClaimed.into());
+        }
+
+        // the last refund closes the vault, which then reads as empty
+        let vault_open = self
+            .accounts
+            .vault
+            .is_owned_by(self.accounts.token_program.key());
+        let vault_amount = match (vault_open, *self.accounts.token_program.key()) {
+            (false, _) => 0,
+            (true, pinocchio_token::ID) => {
+                let vault = unsafe {
+                    pinocchio_token::state::TokenAccount::from_account_info_unchecked(
+                        self.accounts.vault,
+                    )?
+                };
+                vault.amount()
+            }
+            (true, pinocchio_token_2022::ID) => {
+                let vault = unsafe {
+                    pinocchio_token_2022::state::TokenAccount::from_account_info_unchecked(
+                        self.accounts.vault,
+                    )?
+                };
+                vault.amount()
+            }
+            _ => return Err(FundraiserError::InvalidTokenProgram.into()),
+        };
+
+        match fundraise.status(Clock::get()?.unix_timestamp) {
+            FundraiseStatus::Expired => {}
+            FundraiseStatus::Active | FundraiseStatus::TargetMet => {
+                return Err(FundraiserError::FundraiserNotEnded.into());
+            }
+            FundraiseStatus::Claimable => return Err(FundraiserError::FundraiseUnclaimed.into()),
+            FundraiseStatus::Refundable => return Err(FundraiserError::VaultNotEmpty.into()),
+        }
+
+        // tokens sent straight to the vault do not count towards the status, and can only be
+        // swept out to the maker
+        if vault_amount > 0 && self.accounts.sweep_accounts.is_none() {
+            return Err(FundraiserError::VaultNotEmpty.into());
+        }
+
+        if fundraise.get_current_amount() > 0 {
+            return Err(FundraiserError::OutstandingContributions.into());
+        }
+
+        // the sponsor reclaims the pool under the fundraise's signature, so it has to go first
+        if fundraise.get_match_pool() > 0 {
+            return Err(FundraiserError::MatchOutstanding.into());
+        }
+
+        let mint_to_raise = fundraise.mint_to_raise;
+
+        drop(fundraise_data);
+
+        if vault_open {
+            let fundraise_seeds = [
+                Seed::from(Fundraise::PREFIX),
+                Seed::from(fundraise_maker.as_ref()),
+                Seed::from(&fundraise_bump),
+            ];
+
+            if let Some(sweep_accounts) = &self.accounts.sweep_accounts
+                && vault_amount > 0
+            {
+                vault_transfer(
+                    Liability::Surplus,
+                    self.accounts.vault,
+                    sweep_accounts.mint_to_raise,
+                    sweep_accounts.maker_token_account,
+                    vault_amount,
+                    self.accounts.fundraise,
+                    sweep_accounts.transfer_hook_accounts,
+                )?;
+            }
+
+            CloseAccount {
+                account: self.accounts.vault,
+                destination: self.accounts.maker,
+                authority: self.accounts.fundraise,
+                token_program: self.accounts.token_program.key(),
+            }
+            .invoke_signed(&[Signer::from(&fundraise_seeds)])?;
+        }
+
+        if let Some(registry) = self.accounts.registry {
+            let mut registry_data = registry.try_borrow_mut_data()?;
+            let registry_state = Registry::load_mut(registry_data.as_mut())?;
+
+            ProgramAccount::validate(
+                &[Registry::PREFIX, &mint_to_raise, &[registry_state.bump]],
+                *registry.key(),
+            )?;
+
+            registry_state.record_closed()?;
+        }
+
+        ProgramAccount::close(self.accounts.fundraise, self.accounts.maker)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use spl_associated_token_account::solana_program::{
+        clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL,
+    };
+
+    use spl_token_2022::state::Account;
+
+    use crate::{
+        FundraiserError, client,
+        tests::{
+            constants::{MINT_DECIMALS, TOKEN_PROGRAM_ID},
+            utils::{
+                assert_error, build_and_send_transaction, fetch_account, forward_time, init_ata,
+                init_mint, init_token_account, init_wallet, setup,
+            },
+        },
+    };
+
+    const AMOUNT_TO_RAISE: u64 = 5_000_000;
+
+    fn initialize(litesvm: &mut LiteSVM) -> (Keypair, Pubkey) {
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        let ix = client::initialize(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            AMOUNT_TO_RAISE,
+            SECONDS_PER_DAY,
+        );
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        (maker, mint_to_raise)
+    }
+
+    fn contribute(litesvm: &mut LiteSVM, maker: &Keypair, mint_to_raise: Pubkey, amount: u64) {
+        let backer = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        init_ata(litesvm, mint_to_raise, backer.pubkey(), amount);
+
+        let ix = client::contribute(
+            &backer.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            amount,
+        );
+        build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]).unwrap();
+    }
+
+    #[test]
+    fn close_after_refunds() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, mint_to_raise) = initialize(litesvm);
+        let backer = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let cranker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        init_ata(litesvm, mint_to_raise, backer.pubkey(), 500_000);
+
+        let ix = client::contribute(
+            &backer.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            500_000,
+        );
+        build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]).unwrap();
+
+        // the refund empties and closes the vault
+        let ix = client::refund(
+            &backer.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+        build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]).unwrap();
+
+        let ix = client::close_fundraise(&maker.pubkey(), &TOKEN_PROGRAM_ID);
+        let res = build_and_send_transaction(litesvm, &[&cranker], &cranker.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::FundraiserNotEnded);
+
+        forward_time(litesvm, SECONDS_PER_DAY as i64 + 1);
+
+        let fundraise = client::fundraise_address(&maker.pubkey());
+        let fundraise_rent = litesvm.get_balance(&fundraise).unwrap();
+        let balance = litesvm.get_balance(&maker.pubkey()).unwrap();
+
+        litesvm.expire_blockhash();
+        let ix = client::close_fundraise(&maker.pubkey(), &TOKEN_PROGRAM_ID);
+        build_and_send_transaction(litesvm, &[&cranker], &cranker.pubkey(), &[ix]).unwrap();
+
+        assert!(litesvm.get_account(&fundraise).is_none());
+        assert_eq!(
+            litesvm.get_balance(&maker.pubkey()).unwrap(),
+            balance + fundraise_rent
+        );
+    }
+
+    #[test]
+    fn close_with_empty_vault_open() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, _mint_to_raise) = initialize(litesvm);
+        let fundraise = client::fundraise_address(&maker.pubkey());
+        let vault = client::vault_address(&fundraise);
+
+        forward_time(litesvm, SECONDS_PER_DAY as i64 + 1);
+
+        let rent = litesvm.get_balance(&fundraise).unwrap() + litesvm.get_balance(&vault).unwrap();
+        let balance = litesvm.get_balance(&maker.pubkey()).unwrap();
+
+        let ix = client::close_fundraise(&maker.pubkey(), &TOKEN_PROGRAM_ID);
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        assert!(litesvm.get_account(&fundraise).is_none());
+        assert!(litesvm.get_account(&vault).is_none());
+        assert_eq!(
+            litesvm.get_balance(&maker.pubkey()).unwrap(),
+            balance + rent - 5_000
+        );
+    }
+
+    #[test]
+    fn close_sweeps_dust_sent_to_vault() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, mint_to_raise) = initialize(litesvm);
+        let fundraise = client::fundraise_address(&maker.pubkey());
+        let vault = client::vault_address(&fundraise);
+        let maker_ata = init_ata(litesvm, mint_to_raise, maker.pubkey(), 0);
+
+        // dust sent straight to the vault, which nothing recorded
+        init_token_account(litesvm, vault, mint_to_raise, fundraise, 1);
+        forward_time(litesvm, SECONDS_PER_DAY as i64 + 1);
+
+        let ix = client::close_fundraise(&maker.pubkey(), &TOKEN_PROGRAM_ID);
+        let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::VaultNotEmpty);
+
+        let ix = client::with_vault_sweep(
+            client::close_fundraise(&maker.pubkey(), &TOKEN_PROGRAM_ID),
+            &mint_to_raise,
+        );
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        assert!(litesvm.get_account(&fundraise).is_none());
+        assert!(litesvm.get_account(&vault).is_none());
+        assert_eq!(fetch_account::<Account>(litesvm, &maker_ata).amount, 1);
+    }
+
+    #[test]
+    fn throw_if_contributions_not_refunded() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, mint_to_raise) = initialize(litesvm);
+
+        contribute(litesvm, &maker, mint_to_raise, 500_000);
+        forward_time(litesvm, SECONDS_PER_DAY as i64 + 1);
+
+        let ix = client::close_fundraise(&maker.pubkey(), &TOKEN_PROGRAM_ID);
+        let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::VaultNotEmpty);
+    }
+
+    #[test]
+    fn throw_if_target_met_but_unclaimed() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, mint_to_raise) = initialize(litesvm);
+
+        for _ in 0..10 {
+            contribute(litesvm, &maker, mint_to_raise, AMOUNT_TO_RAISE / 10);
+        }
+
+        forward_time(litesvm, SECONDS_PER_DAY as i64 + 1);
+
+        let ix = client::close_fundraise(&maker.pubkey(), &TOKEN_PROGRAM_ID);
+        let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::FundraiseUnclaimed);
+    }
+
+    #[test]
+    fn throw_if_match_not_reclaimed() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, mint_to_raise) = initialize(litesvm);
+        let sponsor = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        init_ata(litesvm, mint_to_raise, sponsor.pubkey(), 1_000_000);
+
+        let ix = client::deposit_match(
+            &sponsor.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            1_000_000,
+        );
+        build_and_send_transaction(litesvm, &[&sponsor], &sponsor.pubkey(), &[ix]).unwrap();
+
+        forward_time(litesvm, SECONDS_PER_DAY as i64 + 1);
+
+        let ix = client::close_fundraise(&maker.pubkey(), &TOKEN_PROGRAM_ID);
+        let res = build_and_send_transaction(
+            litesvm,
+            &[&maker],
+            &maker.pubkey(),
+            std::slice::from_ref(&ix),
+        );
+
+        assert_error(res.unwrap_err(), FundraiserError::MatchOutstanding);
+
+        let ix = client::reclaim_match(
+            &sponsor.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+        build_and_send_transaction(litesvm, &[&sponsor], &sponsor.pubkey(), &[ix]).unwrap();
+        litesvm.expire_blockhash();
+
+        let ix = client::close_fundraise(&maker.pubkey(), &TOKEN_PROGRAM_ID);
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+    }
+}
@@ -0,0 +1,861 @@
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{Sysvar, clock::Clock},
+};
+use pinocchio_token_2022::instructions::Transfer;
+
+use crate::{
+    AccountCheck, AccountLoad, AssociatedTokenAccount, Fundraise, FundraiserError,
+    FundraiserInstruction, Handler, MakerCompletionEvent, MintInterface, Prefix, ProgramAccount,
+    SignerAccount, TokenAccountInterface, WritableAccount, check_account,
+};
+
+pub struct CompleteAccounts<'a> {
+    pub maker: &'a AccountInfo,
+    pub mint_to_raise: &'a AccountInfo,
+    pub fundraise: &'a AccountInfo,
+    pub maker_token_account: &'a AccountInfo,
+    pub vault: &'a AccountInfo,
+    pub token_program: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for CompleteAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [
+            maker,
+            mint_to_raise,
+            fundraise,
+            maker_token_account,
+            vault,
+            token_program,
+        ] = accounts
+        else {
+            return Err(FundraiserError::MissingAccounts.into());
+        };
+
+        check_account!(0, "signer", SignerAccount::check(maker))?;
+        check_account!(2, "writable", WritableAccount::check(fundraise))?;
+        check_account!(3, "writable", WritableAccount::check(maker_token_account))?;
+        check_account!(4, "writable", WritableAccount::check(vault))?;
+        check_account!(1, "mint", MintInterface::check(mint_to_raise))?;
+        check_account!(2, "owner", ProgramAccount::check(fundraise))?;
+
+        let fundraise_data = fundraise.try_borrow_data()?;
+        let fundraise_state = Fundraise::load(&fundraise_data)?;
+
+        check_account!(
+            5,
+            "token program",
+            fundraise_state.check_token_program(token_program.key())
+        )?;
+        check_account!(4, "vault", fundraise_state.check_vault(vault.key()))?;
+        drop(fundraise_data);
+
+        check_account!(
+            3,
+            "token account",
+            AssociatedTokenAccount::check(maker_token_account, maker, mint_to_raise, token_program)
+        )?;
+        check_account!(
+            4,
+            "mint and owner",
+            TokenAccountInterface::check_mint_and_owner(
+                vault,
+                mint_to_raise.key(),
+                fundraise.key()
+            )
+        )?;
+
+        Ok(Self {
+            maker,
+            mint_to_raise,
+            fundraise,
+            maker_token_account,
+            vault,
+            token_program,
+        })
+    }
+}
+
+/// Lets the maker deposit the remaining shortfall of a nearly funded fundraise in its final
+/// moments. The deposit is tracked as `maker_completion` rather than as a contribution, so it
+/// is never refundable and the fundraise is successful as soon as it lands.
+pub struct Complete<'a> {
+    pub accounts: CompleteAccounts<'a>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for Complete<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let accounts = CompleteAccounts::try_from(accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl Handler for Complete<'_> {
+    const DISCRIMINATOR: u8 = FundraiserInstruction::Complete as u8;
+
+    fn process(&mut self) -> ProgramResult {
+        let mut fundraise_data = self.accounts.fundraise.try_borrow_mut_data()?;
+        let fundraise = Fundraise::load_mut(fundraise_data.as_mut())?;
+
+        if fundraise.maker != *self.accounts.maker.key() {
+            return Err(FundraiserError::InvalidAddress.into());
+        }
+
+        let fundraise_seeds = &[Fundraise::PREFIX, &fundraise.maker, &[fundraise.bump]];
+
+        ProgramAccount::validate(fundraise_seeds, *self.accounts.fundraise.key())?;
+        fundraise.check_mint_to_raise(self.accounts.mint_to_raise.key())?;
+
+        if fundraise.is_claimed() {
+            return Err(FundraiserError::AlreadyClaimed.into());
+        }
+
+        let now = Clock::get()?.unix_timestamp;
+        let shortfall = fundraise.completion_shortfall(now)?;
+
+        fundraise.set_maker_completion(shortfall);
+
+        let event = MakerCompletionEvent {
+            fundraise: *self.accounts.fundraise.key(),
+            maker: fundraise.maker,
+            amount: shortfall,
+            organic_amount: fundraise.get_current_amount(),
+        };
+
+        drop(fundraise_data);
+
+        Transfer {
+            amount: shortfall,
+            authority: self.accounts.maker,
+            from: self.accounts.maker_token_account,
+            to: self.accounts.vault,
+            token_program: self.accounts.token_program.key(),
+        }
+        .invoke()?;
+
+        event.emit();
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_signer::Signer;
+    use spl_associated_token_account::solana_program::{
+        clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL,
+    };
+    use spl_token_2022::state::Account;
+
+    use crate::{
+        AccountLoad, Fundraise, FundraiserError, FundraiserEvent, MakerCompletionEvent, decode,
+        tests::{
+            constants::{
+                ASSOCIATED_TOKEN_PROGRAM_ID, MINT_DECIMALS, PROGRAM_ID, SYSTEM_PROGRAM_ID,
+                TOKEN_PROGRAM_ID,
+            },
+            pda::{get_config_pda, get_contributor_pda, get_fundraise_pda, get_vault_pda},
+            utils::{
+                assert_error, build_and_send_transaction, emitted_events, fetch_account,
+                forward_time, init_ata, init_mint, init_wallet, setup,
+            },
+        },
+    };
+
+    #[test]
+    fn complete() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 10_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+        let completion_window: u64 = 3_600; // 1 hour
+        let completion_threshold_bps: u16 = 8_000; // 80%
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            completion_window.to_le_bytes().to_vec(),
+            completion_threshold_bps.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let contribute_amount: u64 = 500_000;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        // init 7 more authorities to contribute and reach 80% of the fundraiser target
+        for _ in 0..7 {
+            let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+            let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+            let temp_contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+            let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+            let ix = Instruction {
+                program_id: PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new(authority.pubkey(), true),
+                    AccountMeta::new_readonly(mint_to_raise, false),
+                    AccountMeta::new(fundraise_pda, false),
+                    AccountMeta::new(temp_contributor_pda, false),
+                    AccountMeta::new(authority_ata, false),
+                    AccountMeta::new(vault, false),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(get_config_pda(), false),
+                ],
+                data,
+            };
+
+            let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+        }
+
+        // jump to the start of the completion window
+        forward_time(litesvm, (duration - completion_window) as i64);
+
+        let maker_ata = init_ata(litesvm, mint_to_raise, maker.pubkey(), 1_000_000_000);
+
+        let data = vec![15u8];
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(maker_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            ],
+            data,
+        };
+
+        let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+        let events = emitted_events(&res.logs);
+
+        let event = MakerCompletionEvent {
+            fundraise: fundraise_pda.to_bytes(),
+            maker: maker.pubkey().to_bytes(),
+            amount: 1_000_000,
+            organic_amount: 4_000_000,
+        };
+
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            decode(&events[0]),
+            Ok(FundraiserEvent::MakerCompletion(event))
+        );
+
+        let fundraise_acc = litesvm.get_account(&fundraise_pda).unwrap();
+        let fundraise = Fundraise::load(fundraise_acc.data.as_ref()).unwrap();
+
+        assert_eq!(fundraise.get_maker_completion(), 1_000_000);
+        assert_eq!(fundraise.get_current_amount(), 4_000_000);
+
+        let vault_bal = fetch_account::<Account>(litesvm, &vault).amount;
+
+        assert_eq!(vault_bal, amount_to_raise);
+
+        let data = vec![2u8];
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new(maker.pubkey(), false),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            ],
+            data,
+        };
+
+        let res = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::TargetMet);
+    }
+
+    #[test]
+    fn throw_if_before_completion_window() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 10_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+        let completion_window: u64 = 3_600; // 1 hour
+        let completion_threshold_bps: u16 = 8_000; // 80%
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            completion_window.to_le_bytes().to_vec(),
+            completion_threshold_bps.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let contribute_amount: u64 = 500_000;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        // init 7 more authorities to contribute and reach 80% of the fundraiser target
+        for _ in 0..7 {
+            let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+            let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+            let temp_contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+            let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+            let ix = Instruction {
+                program_id: PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new(authority.pubkey(), true),
+                    AccountMeta::new_readonly(mint_to_raise, false),
+                    AccountMeta::new(fundraise_pda, false),
+                    AccountMeta::new(temp_contributor_pda, false),
+                    AccountMeta::new(authority_ata, false),
+                    AccountMeta::new(vault, false),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(get_config_pda(), false),
+                ],
+                data,
+            };
+
+            let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+        }
+
+        // one second before the completion window
+        forward_time(litesvm, (duration - completion_window) as i64 - 1);
+
+        let maker_ata = init_ata(litesvm, mint_to_raise, maker.pubkey(), 1_000_000_000);
+
+        let data = vec![15u8];
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(maker_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            ],
+            data,
+        };
+
+        let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        assert_error(
+            res.unwrap_err(),
+            FundraiserError::MakerCompletionNotEligible,
+        );
+    }
+
+    #[test]
+    fn throw_if_below_completion_threshold() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 10_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+        let completion_window: u64 = 3_600; // 1 hour
+        let completion_threshold_bps: u16 = 8_000; // 80%
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            completion_window.to_le_bytes().to_vec(),
+            completion_threshold_bps.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let contribute_amount: u64 = 499_999; // keeps the organic total just short of 80%
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        // init 7 more authorities to contribute and reach 80% of the fundraiser target
+        for _ in 0..7 {
+            let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+            let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+            let temp_contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+            let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+            let ix = Instruction {
+                program_id: PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new(authority.pubkey(), true),
+                    AccountMeta::new_readonly(mint_to_raise, false),
+                    AccountMeta::new(fundraise_pda, false),
+                    AccountMeta::new(temp_contributor_pda, false),
+                    AccountMeta::new(authority_ata, false),
+                    AccountMeta::new(vault, false),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(get_config_pda(), false),
+                ],
+                data,
+            };
+
+            let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+        }
+
+        // jump to the start of the completion window
+        forward_time(litesvm, (duration - completion_window) as i64);
+
+        let maker_ata = init_ata(litesvm, mint_to_raise, maker.pubkey(), 1_000_000_000);
+
+        let data = vec![15u8];
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(maker_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            ],
+            data,
+        };
+
+        let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        assert_error(
+            res.unwrap_err(),
+            FundraiserError::MakerCompletionNotEligible,
+        );
+    }
+
+    #[test]
+    fn throw_if_completion_disabled() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 10_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+        let completion_window: u64 = 0; // disabled
+        let completion_threshold_bps: u16 = 8_000; // 80%
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            completion_window.to_le_bytes().to_vec(),
+            completion_threshold_bps.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let contribute_amount: u64 = 500_000;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        // init 7 more authorities to contribute and reach 80% of the fundraiser target
+        for _ in 0..7 {
+            let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+            let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+            let temp_contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+            let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+            let ix = Instruction {
+                program_id: PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new(authority.pubkey(), true),
+                    AccountMeta::new_readonly(mint_to_raise, false),
+                    AccountMeta::new(fundraise_pda, false),
+                    AccountMeta::new(temp_contributor_pda, false),
+                    AccountMeta::new(authority_ata, false),
+                    AccountMeta::new(vault, false),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(get_config_pda(), false),
+                ],
+                data,
+            };
+
+            let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+        }
+
+        // jump to the end of the fundraiser
+        forward_time(litesvm, duration as i64);
+
+        let maker_ata = init_ata(litesvm, mint_to_raise, maker.pubkey(), 1_000_000_000);
+
+        let data = vec![15u8];
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(maker_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            ],
+            data,
+        };
+
+        let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        assert_error(
+            res.unwrap_err(),
+            FundraiserError::MakerCompletionNotEligible,
+        );
+    }
+
+    #[test]
+    fn throw_if_fundraiser_ended() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 10_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+        let completion_window: u64 = 3_600; // 1 hour
+        let completion_threshold_bps: u16 = 8_000; // 80%
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            completion_window.to_le_bytes().to_vec(),
+            completion_threshold_bps.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let contribute_amount: u64 = 500_000;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        // init 7 more authorities to contribute and reach 80% of the fundraiser target
+        for _ in 0..7 {
+            let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+            let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+            let temp_contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+            let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+            let ix = Instruction {
+                program_id: PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new(authority.pubkey(), true),
+                    AccountMeta::new_readonly(mint_to_raise, false),
+                    AccountMeta::new(fundraise_pda, false),
+                    AccountMeta::new(temp_contributor_pda, false),
+                    AccountMeta::new(authority_ata, false),
+                    AccountMeta::new(vault, false),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(get_config_pda(), false),
+                ],
+                data,
+            };
+
+            let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+        }
+
+        // jump past the end of the fundraiser
+        forward_time(litesvm, duration as i64 + 1);
+
+        let maker_ata = init_ata(litesvm, mint_to_raise, maker.pubkey(), 1_000_000_000);
+
+        let data = vec![15u8];
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(maker_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            ],
+            data,
+        };
+
+        let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::FundraiserEnded);
+    }
+
+    #[test]
+    fn throw_if_accounts_not_writable() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let maker_ata = init_ata(litesvm, mint_to_raise, maker.pubkey(), 1_000_000_000);
+
+        for index in [2, 3, 4] {
+            litesvm.expire_blockhash();
+
+            let data = vec![15u8];
+            let mut ix = Instruction {
+                program_id: PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new(maker.pubkey(), true),
+                    AccountMeta::new_readonly(mint_to_raise, false),
+                    AccountMeta::new(fundraise_pda, false),
+                    AccountMeta::new(maker_ata, false),
+                    AccountMeta::new(vault, false),
+                    AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                ],
+                data,
+            };
+
+            ix.accounts[index].is_writable = false;
+
+            let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+            assert_error(res.unwrap_err(), FundraiserError::AccountNotWritable);
+        }
+    }
+}
@@ -4,34 +4,114 @@ use pinocchio::{
     account_info::AccountInfo,
     instruction::Seed,
     program_error::ProgramError,
-    pubkey::find_program_address,
-    sysvars::{Sysvar, clock::Clock},
+    pubkey::{Pubkey, find_program_address},
+    sysvars::{Sysvar, clock::Clock, instructions::INSTRUCTIONS_ID},
 };
-use pinocchio_token_2022::instructions::Transfer;
 
 use crate::{
-    AccountCheck, AccountLoad, AssociatedTokenAccount, Contributor, ContributorParams, Fundraise,
-    FundraiserError, Handler, MAX_BPS, MAX_CONTRIBUTION_PERCENTAGE_BPS, MintInterface, Prefix,
-    ProgramAccount, SignerAccount,
+    AccountCheck, AccountLoad, AllowlistPage, AllowlistReceipt, AssociatedTokenAccount, Config,
+    ContributeReturnData, Contributor, ContributorParams, Fundraise, FundraiseStatus,
+    FundraiserError, FundraiserInstruction, Handler, InstructionsSysvar, MIN_CONTRIBUTION,
+    MerkleProof, MintInterface, MultisigAccount, NATIVE_MINT, Prefix, PresentFields,
+    ProgramAccount, ProgramIdCheck, ReceiptAccounts, Referral, ReferralParams, SignerAccount,
+    Space, Stats, TokenAccount, TokenAccountInterface, TransferChecked, TransferHook, Versioned,
+    WritableAccount, check_account, check_contribution_cap, clamp_contribution,
 };
 
+/// Position of the contributor account in the instructions recording a contribution, used to
+/// recognise duplicate contributions.
+const fn contributor_account_index(instruction: FundraiserInstruction) -> Option<usize> {
+    match instruction {
+        FundraiserInstruction::Contribute => Some(3),
+        FundraiserInstruction::ContributeFor | FundraiserInstruction::ContributeDelegated => {
+            Some(4)
+        }
+        _ => None,
+    }
+}
+
 pub struct ContributeAccounts<'a> {
+    /// Signs, funds the transfer and pays for the contributor account unless a rent payer is
+    /// passed. May instead be an SPL token multisig, signed for by its signers.
     pub authority: &'a AccountInfo,
+    /// Owns the contribution and the right to refund it. The authority itself, except in
+    /// ContributeFor and ContributeDelegated.
+    pub beneficiary: &'a AccountInfo,
     pub mint_to_raise: &'a AccountInfo,
     pub fundraise: &'a AccountInfo,
     pub contributor: &'a AccountInfo,
+    /// The authority's associated token account, except in ContributeDelegated, where it is
+    /// the beneficiary's and the authority is its delegate.
     pub authority_token_account: &'a AccountInfo,
     pub vault: &'a AccountInfo,
     pub system_program: &'a AccountInfo,
     pub token_program: &'a AccountInfo,
+    pub config: &'a AccountInfo,
+    /// Signers of a multisig authority. Empty for any other authority.
+    pub multisig_signers: &'a [AccountInfo],
+    /// Accounts between any multisig signers and the transfer hook accounts, holding the
+    /// instructions sysvar, allowlist accounts and referral account that were passed.
+    pub other_accounts: &'a [AccountInfo],
+    pub instructions_sysvar: Option<&'a AccountInfo>,
+    pub allowlist_receipt: Option<&'a AccountInfo>,
+    pub allowlist_page: Option<&'a AccountInfo>,
+    /// Credited with the contribution when the instruction data names a referrer.
+    pub referral: Option<&'a AccountInfo>,
+    pub transfer_hook_accounts: &'a [AccountInfo],
+    /// Passed when the fundraise issues receipts, which are minted to the beneficiary.
+    pub receipt: Option<ReceiptAccounts<'a>>,
+    /// Passed when the fundraise is gated: a token account of the gate mint holding at least
+    /// one token, owned by the beneficiary.
+    pub gate_token_account: Option<&'a AccountInfo>,
+    /// Funds the contributor account in place of the authority, which still funds the transfer.
+    pub rent_payer: Option<&'a AccountInfo>,
+    pub stats: Option<&'a AccountInfo>,
 }
 
 impl<'a> TryFrom<&'a [AccountInfo]> for ContributeAccounts<'a> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, accounts @ ..] = accounts else {
+            return Err(FundraiserError::MissingAccounts.into());
+        };
+
+        Self::parse(authority, authority, authority, accounts, 1)
+    }
+}
+
+impl<'a> ContributeAccounts<'a> {
+    /// Parses the accounts following ContributeFor's payer and the beneficiary.
+    #[inline(always)]
+    pub fn with_beneficiary(
+        authority: &'a AccountInfo,
+        beneficiary: &'a AccountInfo,
+        accounts: &'a [AccountInfo],
+    ) -> Result<Self, ProgramError> {
+        Self::parse(authority, beneficiary, authority, accounts, 2)
+    }
+
+    /// Parses the accounts following ContributeDelegated's delegate and the owner of the token
+    /// account it spends from.
+    #[inline(always)]
+    pub fn delegated(
+        delegate: &'a AccountInfo,
+        owner: &'a AccountInfo,
+        accounts: &'a [AccountInfo],
+    ) -> Result<Self, ProgramError> {
+        Self::parse(delegate, owner, owner, accounts, 2)
+    }
+
+    /// `first_index` is the position of the first of `accounts` in the instruction, by which a
+    /// failed check is logged.
+    fn parse(
+        authority: &'a AccountInfo,
+        beneficiary: &'a AccountInfo,
+        token_account_owner: &'a AccountInfo,
+        accounts: &'a [AccountInfo],
+        first_index: u8,
+    ) -> Result<Self, ProgramError> {
         let [
-            authority,
             mint_to_raise,
             fundraise,
             contributor,
@@ -39,24 +119,138 @@ impl<'a> TryFrom<&'a [AccountInfo]> for ContributeAccounts<'a> {
             vault,
             system_program,
             token_program,
+            config,
+            rest @ ..,
         ] = accounts
         else {
-            return Err(ProgramError::NotEnoughAccountKeys);
+            return Err(FundraiserError::MissingAccounts.into());
         };
 
-        SignerAccount::check(authority)?;
-        MintInterface::check(mint_to_raise)?;
-        ProgramAccount::check(fundraise)?;
-        AssociatedTokenAccount::check(
-            authority_token_account,
-            authority,
-            mint_to_raise,
-            token_program,
+        check_account!(0, "writable", WritableAccount::check(authority))?;
+        check_account!(
+            first_index + 1,
+            "writable",
+            WritableAccount::check(fundraise)
+        )?;
+        check_account!(
+            first_index + 2,
+            "writable",
+            WritableAccount::check(contributor)
+        )?;
+        check_account!(
+            first_index + 3,
+            "writable",
+            WritableAccount::check(authority_token_account)
+        )?;
+        check_account!(first_index + 4, "writable", WritableAccount::check(vault))?;
+        check_account!(first_index, "mint", MintInterface::check(mint_to_raise))?;
+        check_account!(
+            first_index + 5,
+            "program id",
+            ProgramIdCheck::check(system_program, &pinocchio_system::ID)
+        )?;
+        check_account!(first_index + 1, "owner", ProgramAccount::check(fundraise))?;
+
+        let fundraise_data = fundraise.try_borrow_data()?;
+        let fundraise_state = Fundraise::load(&fundraise_data)?;
+
+        check_account!(
+            first_index + 6,
+            "token program",
+            fundraise_state.check_token_program(token_program.key())
         )?;
-        AssociatedTokenAccount::check(vault, fundraise, mint_to_raise, token_program)?;
+        check_account!(
+            first_index + 4,
+            "vault",
+            fundraise_state.check_vault(vault.key())
+        )?;
+        let receipt_mint = fundraise_state.receipt_mint;
+        let gate_mint = fundraise_state
+            .has_gate()
+            .then_some(fundraise_state.gate_mint);
+        drop(fundraise_data);
+
+        check_account!(first_index + 7, "owner", ProgramAccount::check(config))?;
+        check_account!(
+            first_index + 3,
+            "token account",
+            AssociatedTokenAccount::check(
+                authority_token_account,
+                token_account_owner,
+                mint_to_raise,
+                token_program,
+            )
+        )?;
+        check_account!(
+            first_index + 4,
+            "mint and owner",
+            TokenAccountInterface::check_mint_and_owner(
+                vault,
+                mint_to_raise.key(),
+                fundraise.key()
+            )
+        )?;
+
+        // The signers of a multisig authority lead the remaining accounts. The instructions
+        // sysvar and any referral account are recognised by their addresses, and the allowlist
+        // receipt and page follow in that order, before any transfer hook accounts, receipt
+        // accounts, gate token account, rent payer and stats account.
+        let (stats, rest) = Stats::split_trailing(rest);
+        let (rent_payer, rest) = SignerAccount::split_rent_payer(rest)?;
+        let (gate_token_account, rest) = match gate_mint {
+            Some(gate_mint) => {
+                let Some((gate_token_account, rest)) = rest.split_last() else {
+                    return Err(FundraiserError::MissingAccounts.into());
+                };
+
+                TokenAccountInterface::check_mint_and_owner(
+                    gate_token_account,
+                    &gate_mint,
+                    beneficiary.key(),
+                )
+                .map_err(|_| FundraiserError::GateTokenRequired)?;
+
+                if TokenAccountInterface::amount(gate_token_account)? == 0 {
+                    return Err(FundraiserError::GateTokenRequired.into());
+                }
+
+                (Some(gate_token_account), rest)
+            }
+            None => (None, rest),
+        };
+        let (receipt, rest) = ReceiptAccounts::split_trailing(&receipt_mint, rest)?;
+        let (transfer_hook_accounts, rest) = TransferHook::split_trailing(mint_to_raise, rest)?;
+
+        if let Some(receipt) = &receipt {
+            receipt.check_holder(beneficiary.key())?;
+        }
+
+        let (multisig_signers, rest) =
+            MultisigAccount::split_signers(authority, token_program, rest)?;
+
+        match MultisigAccount::is_multisig(authority, token_program) {
+            // a token account cannot pay for the contributor account
+            true if rent_payer.is_none() => {
+                return Err(FundraiserError::MissingRentPayer.into());
+            }
+            true => check_account!(
+                0,
+                "multisig",
+                MultisigAccount::check_signers(authority, multisig_signers)
+            )?,
+            false => check_account!(0, "signer", SignerAccount::check(authority))?,
+        }
+
+        let instructions_sysvar = rest
+            .iter()
+            .find(|account| account.key() == &INSTRUCTIONS_ID);
+        let mut allowlist = rest
+            .iter()
+            .filter(|account| account.key() != &INSTRUCTIONS_ID);
 
         Ok(Self {
             authority,
+            beneficiary,
             mint_to_raise,
             fundraise,
             contributor,
@@ -64,32 +258,211 @@ impl<'a> TryFrom<&'a [AccountInfo]> for ContributeAccounts<'a> {
             vault,
             system_program,
             token_program,
+            config,
+            multisig_signers,
+            other_accounts: rest,
+            instructions_sysvar,
+            allowlist_receipt: allowlist.next(),
+            allowlist_page: allowlist.next(),
+            referral: None,
+            transfer_hook_accounts,
+            receipt,
+            gate_token_account,
+            rent_payer,
+            stats,
         })
     }
+
+    /// Picks the account at `address` out of the other accounts as the referral account, so
+    /// that it is not mistaken for an allowlist account.
+    fn take_referral(&mut self, address: &Pubkey) -> Result<&'a AccountInfo, ProgramError> {
+        let referral = self
+            .other_accounts
+            .iter()
+            .find(|account| account.key() == address)
+            .ok_or(FundraiserError::MissingAccounts)?;
+
+        WritableAccount::check(referral)?;
+
+        let mut allowlist = self
+            .other_accounts
+            .iter()
+            .filter(|account| account.key() != &INSTRUCTIONS_ID && account.key() != address);
+
+        self.allowlist_receipt = allowlist.next();
+        self.allowlist_page = allowlist.next();
+        self.referral = Some(referral);
+
+        Ok(referral)
+    }
 }
 
-pub struct ContributeInstructionData {
+pub struct ContributeInstructionData<'a> {
     pub amount: u64,
+    pub reject_duplicates: bool,
+    pub require_existing: bool,
+    /// Lowers `amount` to the most the contributor's cap and the target still allow, rather
+    /// than failing past either. The amount taken is in the return data.
+    pub clamp: bool,
+    /// Who referred the contribution, following the flags as a 1 byte and the referrer's
+    /// address. Not part of `to_bytes`.
+    pub referrer: Option<Pubkey>,
+    /// Merkle proof that the beneficiary is allowlisted, following the flags and any
+    /// referrer. Not part of `to_bytes`.
+    pub proof: &'a [u8],
+}
+
+impl<'a> ContributeInstructionData<'a> {
+    pub const LEN: usize = size_of::<u64>() + 3 * size_of::<u8>();
+
+    // The presence flags leading a version 1 payload. Each boolean field is its flag alone, and
+    // each other field follows the amount only when flagged, in the order listed.
+    pub const REJECT_DUPLICATES: u16 = 1 << 0;
+    pub const REQUIRE_EXISTING: u16 = 1 << 1;
+    pub const CLAMP: u16 = 1 << 2;
+    pub const REFERRER: u16 = 1 << 3;
+    /// The proof takes the rest of the payload, at least one node of it.
+    pub const PROOF: u16 = 1 << 4;
+
+    /// A contribution of `amount` with every flag unset.
+    pub const fn new(amount: u64) -> Self {
+        Self {
+            amount,
+            reject_duplicates: false,
+            require_existing: false,
+            clamp: false,
+            referrer: None,
+            proof: &[],
+        }
+    }
+
+    pub fn to_bytes(&self) -> [u8; ContributeInstructionData::LEN] {
+        let mut bytes = [0u8; ContributeInstructionData::LEN];
+
+        bytes[0..8].copy_from_slice(&self.amount.to_le_bytes());
+        bytes[8] = u8::from(self.reject_duplicates);
+        bytes[9] = u8::from(self.require_existing);
+        bytes[10] = u8::from(self.clamp);
+
+        bytes
+    }
+
+    /// The version 1 payload, led by its version byte, with the referrer and proof included.
+    pub fn to_v1_bytes(&self) -> Vec<u8> {
+        let flags = [
+            (self.reject_duplicates, Self::REJECT_DUPLICATES),
+            (self.require_existing, Self::REQUIRE_EXISTING),
+            (self.clamp, Self::CLAMP),
+            (self.referrer.is_some(), Self::REFERRER),
+            (!self.proof.is_empty(), Self::PROOF),
+        ]
+        .into_iter()
+        .filter(|(present, _)| *present)
+        .fold(0, |flags, (_, flag)| flags | flag);
+
+        let mut bytes = vec![Versioned::V1];
+        bytes.extend_from_slice(&flags.to_le_bytes());
+        bytes.extend_from_slice(&self.amount.to_le_bytes());
+
+        if let Some(referrer) = &self.referrer {
+            bytes.extend_from_slice(referrer);
+        }
+
+        bytes.extend_from_slice(self.proof);
+
+        bytes
+    }
+
+    /// Reads a version 1 payload, from the presence flags on.
+    fn from_v1(data: &'a [u8]) -> Result<Self, ProgramError> {
+        const KNOWN: u16 = ContributeInstructionData::REJECT_DUPLICATES
+            | ContributeInstructionData::REQUIRE_EXISTING
+            | ContributeInstructionData::CLAMP
+            | ContributeInstructionData::REFERRER
+            | ContributeInstructionData::PROOF;
+
+        let mut fields = PresentFields::new(data, KNOWN)?;
+
+        let amount = u64::from_le_bytes(*fields.required()?);
+        let referrer = fields.next(Self::REFERRER)?.copied();
+        let proof = match fields.flag(Self::PROOF) {
+            true if !fields.rest().is_empty()
+                && fields.rest().len().is_multiple_of(MerkleProof::NODE_LEN) =>
+            {
+                fields.rest()
+            }
+            // nothing may follow the last field flagged
+            false if fields.rest().is_empty() => &[],
+            _ => return Err(ProgramError::InvalidInstructionData),
+        };
+
+        Ok(Self {
+            amount,
+            reject_duplicates: fields.flag(Self::REJECT_DUPLICATES),
+            require_existing: fields.flag(Self::REQUIRE_EXISTING),
+            clamp: fields.flag(Self::CLAMP),
+            referrer,
+            proof,
+        })
+    }
 }
 
-impl<'a> TryFrom<&'a [u8]> for ContributeInstructionData {
+impl<'a> TryFrom<&'a [u8]> for ContributeInstructionData<'a> {
     type Error = ProgramError;
 
     fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
-        if data.len() != size_of::<u64>() {
+        let offset = size_of::<u64>();
+
+        if data.len() < offset {
             return Err(ProgramError::InvalidInstructionData);
         }
 
-        let offset = size_of::<u64>();
+        // The trailing flags are optional so existing clients keep sending just the amount, and
+        // anything after them is the Merkle proof, checked against the fundraise's root. A
+        // proof is a whole number of 32-byte nodes, so a referrer is told apart by the byte
+        // leading it.
+        let flag = |index: usize| match data.get(offset + index) {
+            None | Some(0) => Ok(false),
+            Some(1) => Ok(true),
+            Some(_) => Err(ProgramError::InvalidInstructionData),
+        };
+
         let amount = u64::from_le_bytes(data[0..offset].try_into().unwrap());
+        let trailing = data.get(Self::LEN..).unwrap_or_default();
+        let (referrer, proof) = match trailing.len() % size_of::<Pubkey>() {
+            1 => match trailing.split_first_chunk::<{ 1 + size_of::<Pubkey>() }>() {
+                Some(([1, referrer @ ..], proof)) => (Some(*referrer), proof),
+                _ => return Err(ProgramError::InvalidInstructionData),
+            },
+            _ => (None, trailing),
+        };
+
+        Ok(Self {
+            amount,
+            reject_duplicates: flag(0)?,
+            require_existing: flag(1)?,
+            clamp: flag(2)?,
+            referrer,
+            proof,
+        })
+    }
+}
+
+impl<'a> TryFrom<Versioned<'a>> for ContributeInstructionData<'a> {
+    type Error = ProgramError;
 
-        Ok(Self { amount })
+    fn try_from(data: Versioned<'a>) -> Result<Self, Self::Error> {
+        match data.split()? {
+            (Versioned::V0, payload) => Self::try_from(payload),
+            (Versioned::V1, payload) => Self::from_v1(payload),
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
     }
 }
 
 pub struct Contribute<'a> {
     pub accounts: ContributeAccounts<'a>,
-    pub data: ContributeInstructionData,
+    pub data: ContributeInstructionData<'a>,
     pub bump: u8,
 }
 
@@ -97,14 +470,50 @@ impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for Contribute<'a> {
     type Error = ProgramError;
 
     fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let data = ContributeInstructionData::try_from(data)?;
         let accounts = ContributeAccounts::try_from(accounts)?;
+
+        Self::new(data, accounts)
+    }
+}
+
+impl<'a> TryFrom<(Versioned<'a>, &'a [AccountInfo])> for Contribute<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (Versioned<'a>, &'a [AccountInfo])) -> Result<Self, Self::Error> {
         let data = ContributeInstructionData::try_from(data)?;
+        let accounts = ContributeAccounts::try_from(accounts)?;
+
+        Self::new(data, accounts)
+    }
+}
+
+impl<'a> Contribute<'a> {
+    /// Creates the beneficiary's contributor account if needed, unless `require_existing` is
+    /// set.
+    pub fn new(
+        data: ContributeInstructionData<'a>,
+        mut accounts: ContributeAccounts<'a>,
+    ) -> Result<Self, ProgramError> {
+        if let Some(referrer) = &data.referrer {
+            Self::init_referral(&mut accounts, referrer)?;
+        }
+
+        if data.require_existing {
+            let bump = Self::check_existing_contributor(&accounts)?;
+
+            return Ok(Self {
+                accounts,
+                data,
+                bump,
+            });
+        }
 
         let (pda, bump) = find_program_address(
             &[
                 Contributor::PREFIX,
                 accounts.fundraise.key(),
-                accounts.authority.key(),
+                accounts.beneficiary.key(),
             ],
             &crate::ID,
         );
@@ -117,16 +526,23 @@ impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for Contribute<'a> {
         let contributor_seeds = [
             Seed::from(Contributor::PREFIX),
             Seed::from(accounts.fundraise.key().as_ref()),
-            Seed::from(accounts.authority.key().as_ref()),
+            Seed::from(accounts.beneficiary.key().as_ref()),
             Seed::from(&bump_binding),
         ];
-        let params =
-            ContributorParams::new(*accounts.fundraise.key(), *accounts.authority.key(), bump);
+        let rent_payer = accounts.rent_payer.unwrap_or(accounts.authority);
+        let mint = Fundraise::load(&accounts.fundraise.try_borrow_data()?)?.mint_to_raise;
+        let params = ContributorParams::new(
+            *accounts.fundraise.key(),
+            *accounts.beneficiary.key(),
+            bump,
+            *rent_payer.key(),
+            mint,
+        );
 
         ProgramAccount::init_if_needed::<Contributor>(
             &contributor_seeds,
             accounts.contributor,
-            accounts.authority,
+            rent_payer,
             params,
         )?;
 
@@ -138,34 +554,57 @@ impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for Contribute<'a> {
     }
 }
 
-impl<'a> Handler<'a> for Contribute<'a> {
-    const DISCRIMINATOR: &'a u8 = &1;
+impl Handler for Contribute<'_> {
+    const DISCRIMINATOR: u8 = FundraiserInstruction::Contribute as u8;
 
     fn process(&mut self) -> ProgramResult {
-        let decimals = match *self.accounts.mint_to_raise.owner() {
-            pinocchio_token::ID => {
-                let mint = unsafe {
-                    pinocchio_token::state::Mint::from_account_info_unchecked(
-                        self.accounts.mint_to_raise,
-                    )?
-                };
-                mint.decimals()
-            }
-            pinocchio_token_2022::ID => {
-                let mint = unsafe {
-                    pinocchio_token_2022::state::Mint::from_account_info_unchecked(
-                        self.accounts.mint_to_raise,
-                    )?
-                };
-                mint.decimals()
+        // the token program would reject a transfer to or from a frozen account, opaquely
+        if TokenAccountInterface::is_frozen(self.accounts.authority_token_account) {
+            return Err(FundraiserError::SourceAccountFrozen.into());
+        }
+
+        if TokenAccountInterface::is_frozen(self.accounts.vault) {
+            return Err(FundraiserError::VaultFrozen.into());
+        }
+
+        if self.data.reject_duplicates {
+            let instructions_sysvar = self
+                .accounts
+                .instructions_sysvar
+                .ok_or(FundraiserError::MissingAccounts)?;
+            let amount = self.data.amount.to_le_bytes();
+            let contributor = self.accounts.contributor.key();
+
+            if InstructionsSysvar::any_earlier(instructions_sysvar, |ix| {
+                ix.get_program_id() == &crate::ID
+                    && FundraiserInstruction::parse(ix.get_instruction_data()).is_some_and(
+                        |(instruction, _, data)| {
+                            data.get(..amount.len()) == Some(&amount[..])
+                                && contributor_account_index(instruction).is_some_and(|index| {
+                                    ix.get_account_meta_at(index)
+                                        .is_ok_and(|meta| &meta.key == contributor)
+                                })
+                        },
+                    )
+            })? {
+                return Err(FundraiserError::DuplicateInstruction.into());
             }
-            _ => return Err(ProgramError::IncorrectProgramId),
-        };
+        }
 
-        if self.data.amount <= 1u64.pow(decimals as u32) {
-            return Err(FundraiserError::ContributionTooSmall.into());
+        let config_data = self.accounts.config.try_borrow_data()?;
+        let config = Config::load(&config_data)?;
+
+        ProgramAccount::validate(
+            &[Config::PREFIX, &[config.bump]],
+            *self.accounts.config.key(),
+        )?;
+
+        if config.is_paused() {
+            return Err(FundraiserError::ProtocolPaused.into());
         }
 
+        drop(config_data);
+
         let mut fundraise_data = self.accounts.fundraise.try_borrow_mut_data()?;
         let fundraise = Fundraise::load_mut(fundraise_data.as_mut())?;
 
@@ -174,21 +613,51 @@ impl<'a> Handler<'a> for Contribute<'a> {
         ProgramAccount::validate(fundraise_seeds, *self.accounts.fundraise.key())?;
         fundraise.check_mint_to_raise(self.accounts.mint_to_raise.key())?;
 
-        let amount_to_raise = fundraise.get_amount_to_raise();
+        // a claim zeroes the recorded liability, which would otherwise read as active again
+        if fundraise.is_claimed() {
+            return Err(FundraiserError::AlreadyClaimed.into());
+        }
+
+        if fundraise.is_paused() {
+            return Err(FundraiserError::FundraisePaused.into());
+        }
+
+        if fundraise.has_allowlist() {
+            self.check_allowlisted()?;
+        }
 
-        if self.data.amount
-            > amount_to_raise * u64::from(MAX_CONTRIBUTION_PERCENTAGE_BPS) / u64::from(MAX_BPS)
+        if fundraise.has_merkle_root()
+            && !MerkleProof::verify(
+                self.data.proof,
+                self.accounts.beneficiary.key(),
+                &fundraise.merkle_root,
+            )
         {
-            return Err(FundraiserError::ContributionTooBig.into());
+            return Err(FundraiserError::NotAllowlisted.into());
+        }
+
+        let min_contribution = fundraise.get_min_contribution().max(MIN_CONTRIBUTION);
+
+        if self.data.amount < min_contribution {
+            return Err(FundraiserError::ContributionTooSmall.into());
+        }
+
+        let max_contribution = fundraise.max_contribution();
+
+        if !self.data.clamp {
+            check_contribution_cap(max_contribution, 0, self.data.amount)?;
         }
 
         let now = Clock::get()?.unix_timestamp;
 
-        let duration = fundraise.get_duration();
-        let time_started = fundraise.get_time_started();
+        if now < fundraise.get_time_started() {
+            return Err(FundraiserError::FundraiserNotStarted.into());
+        }
 
-        if now > time_started + duration as i64 {
-            return Err(FundraiserError::FundraiserEnded.into());
+        match fundraise.status(now) {
+            FundraiseStatus::Active => {}
+            FundraiseStatus::TargetMet => return Err(FundraiserError::TargetMet.into()),
+            _ => return Err(FundraiserError::FundraiserEnded.into()),
         }
 
         let mut contributor_data = self.accounts.contributor.try_borrow_mut_data()?;
@@ -196,53 +665,255 @@ impl<'a> Handler<'a> for Contribute<'a> {
 
         let contributor_amount = contributor.get_amount();
 
-        if contributor_amount + self.data.amount
-            > amount_to_raise * u64::from(MAX_CONTRIBUTION_PERCENTAGE_BPS) / u64::from(MAX_BPS)
-        {
-            return Err(FundraiserError::MaximumContributionsReached.into());
+        // everything from here on moves the clamped amount
+        if self.data.clamp {
+            self.data.amount = clamp_contribution(
+                self.data.amount,
+                min_contribution,
+                max_contribution,
+                contributor_amount,
+                fundraise.remaining_amount(),
+            )?;
         }
 
-        let current_amount = fundraise.get_current_amount();
-        fundraise.set_current_amount(current_amount + self.data.amount);
+        check_contribution_cap(max_contribution, contributor_amount, self.data.amount)?;
 
-        let contributor_amount = contributor.get_amount();
-        contributor.set_amount(contributor_amount + self.data.amount);
+        let current_amount = fundraise
+            .get_current_amount()
+            .checked_add(self.data.amount)
+            .ok_or(FundraiserError::MathOverflow)?;
+        fundraise.set_current_amount(current_amount);
+
+        let contributor_amount = contributor_amount
+            .checked_add(self.data.amount)
+            .ok_or(FundraiserError::MathOverflow)?;
+        contributor.set_amount(contributor_amount);
+        contributor.set_tier(fundraise.tier(contributor.get_amount()));
+        contributor.record_contribution(now);
+
+        if let Some(referral) = self.accounts.referral {
+            let mut referral_data = referral.try_borrow_mut_data()?;
+
+            Referral::load_mut(referral_data.as_mut())?.record_referred(self.data.amount)?;
+        }
 
-        Transfer {
+        let return_data = ContributeReturnData {
+            contributor_total: contributor.get_amount(),
+            fundraise_total: fundraise.get_current_amount(),
+            remaining: fundraise.remaining_amount(),
             amount: self.data.amount,
-            authority: self.accounts.authority,
+        };
+
+        if fundraise.has_hook() {
+            let pending_hook_amount = contributor.get_pending_hook_amount();
+
+            if pending_hook_amount == 0 {
+                fundraise.set_pending_hooks(fundraise.get_pending_hooks() + 1);
+            }
+
+            contributor.set_pending_hook_amount(
+                pending_hook_amount
+                    .checked_add(self.data.amount)
+                    .ok_or(FundraiserError::MathOverflow)?,
+            );
+        }
+
+        let decimals = fundraise.mint_decimals;
+        let fundraise_maker = fundraise.maker;
+        let fundraise_bump = [fundraise.bump];
+
+        // nothing stays borrowed across the CPIs, which a transfer hook may read accounts during
+        drop(contributor_data);
+        drop(fundraise_data);
+
+        // lamports sent straight to a wrapped SOL account only count once synced
+        if self.accounts.mint_to_raise.key() == &NATIVE_MINT {
+            TokenAccount::sync_native_if_stale(self.accounts.authority_token_account)?;
+        }
+
+        TransferChecked {
             from: self.accounts.authority_token_account,
+            mint: self.accounts.mint_to_raise,
             to: self.accounts.vault,
+            authority: self.accounts.authority,
+            multisig_signers: self.accounts.multisig_signers,
+            amount: self.data.amount,
+            decimals,
+            hook_accounts: self.accounts.transfer_hook_accounts,
             token_program: self.accounts.token_program.key(),
         }
         .invoke()?;
 
+        if let Some(receipt) = &self.accounts.receipt {
+            receipt.mint_to(
+                self.accounts.fundraise,
+                self.data.amount,
+                &[
+                    Seed::from(Fundraise::PREFIX),
+                    Seed::from(fundraise_maker.as_ref()),
+                    Seed::from(&fundraise_bump),
+                ],
+            )?;
+        }
+
+        if let Some(stats) = self.accounts.stats {
+            let payer = self.accounts.rent_payer.unwrap_or(self.accounts.authority);
+
+            Stats::update(stats, payer, |stats| {
+                stats.record_contribution(self.data.amount)
+            })?;
+        }
+
+        return_data.set();
+
+        Ok(())
+    }
+}
+
+impl<'a> Contribute<'a> {
+    /// Finds the referral account of `referrer` and creates it if needed.
+    fn init_referral(accounts: &mut ContributeAccounts<'a>, referrer: &Pubkey) -> ProgramResult {
+        if referrer == accounts.authority.key() || referrer == accounts.beneficiary.key() {
+            return Err(FundraiserError::SelfReferral.into());
+        }
+
+        let (pda, bump) = find_program_address(
+            &[Referral::PREFIX, accounts.fundraise.key(), referrer],
+            &crate::ID,
+        );
+        let referral = accounts.take_referral(&pda)?;
+
+        let bump_binding = [bump];
+        let referral_seeds = [
+            Seed::from(Referral::PREFIX),
+            Seed::from(accounts.fundraise.key().as_ref()),
+            Seed::from(referrer.as_ref()),
+            Seed::from(&bump_binding),
+        ];
+        let rent_payer = accounts.rent_payer.unwrap_or(accounts.authority);
+
+        ProgramAccount::init_if_needed::<Referral>(
+            &referral_seeds,
+            referral,
+            rent_payer,
+            ReferralParams::new(*accounts.fundraise.key(), *referrer, bump),
+        )
+    }
+}
+
+impl Contribute<'_> {
+    /// Checks the contributor account was already created for this fundraise and authority, as
+    /// by CreateContributor, and returns its bump. Only the stored bump is re-hashed, so unlike
+    /// the creating path this neither searches for the bump nor may CPI the system program.
+    fn check_existing_contributor(accounts: &ContributeAccounts) -> Result<u8, ProgramError> {
+        if ProgramAccount::check(accounts.contributor).is_err()
+            || accounts.contributor.data_len() != Contributor::LEN
+        {
+            return Err(FundraiserError::ContributorNotInitialized.into());
+        }
+
+        let contributor_data = accounts.contributor.try_borrow_data()?;
+        let contributor = Contributor::load(&contributor_data)?;
+
+        if contributor.fundraise != *accounts.fundraise.key()
+            || contributor.authority != *accounts.beneficiary.key()
+        {
+            return Err(FundraiserError::ContributorNotInitialized.into());
+        }
+
+        ProgramAccount::validate(
+            &[
+                Contributor::PREFIX,
+                &contributor.fundraise,
+                &contributor.authority,
+                &[contributor.bump],
+            ],
+            *accounts.contributor.key(),
+        )?;
+
+        Ok(contributor.bump)
+    }
+
+    /// Checks the trailing receipt maps the contributor to a slot, and that the slot's bit is set
+    /// on the trailing page.
+    fn check_allowlisted(&self) -> ProgramResult {
+        let (Some(receipt_account), Some(page_account)) = (
+            self.accounts.allowlist_receipt,
+            self.accounts.allowlist_page,
+        ) else {
+            return Err(FundraiserError::NotAllowlisted.into());
+        };
+
+        ProgramAccount::check(receipt_account)?;
+        ProgramAccount::check(page_account)?;
+
+        let receipt_data = receipt_account.try_borrow_data()?;
+        let receipt = AllowlistReceipt::load(&receipt_data)?;
+
+        ProgramAccount::validate(
+            &[
+                AllowlistReceipt::PREFIX,
+                self.accounts.fundraise.key(),
+                self.accounts.beneficiary.key(),
+                &[receipt.bump],
+            ],
+            *receipt_account.key(),
+        )?;
+
+        let page_data = page_account.try_borrow_data()?;
+        let page = AllowlistPage::load(&page_data)?;
+
+        ProgramAccount::validate(
+            &[
+                AllowlistPage::PREFIX,
+                self.accounts.fundraise.key(),
+                &receipt.get_page_index().to_le_bytes(),
+                &[page.bump],
+            ],
+            *page_account.key(),
+        )?;
+
+        if !page.is_set(receipt.get_bit_index())? {
+            return Err(FundraiserError::NotAllowlisted.into());
+        }
+
         Ok(())
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use solana_clock::Clock;
     use solana_instruction::{AccountMeta, Instruction};
+    use solana_keypair::Keypair;
+    use solana_pubkey::Pubkey;
     use solana_signer::Signer;
-    use spl_associated_token_account::{
-        get_associated_token_address_with_program_id,
-        solana_program::{clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL},
+    use spl_associated_token_account::solana_program::{
+        clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL,
+    };
+    use spl_token_2022::{
+        extension::{
+            BaseStateWithExtensions, StateWithExtensions, non_transferable::NonTransferable,
+        },
+        state::{Account, Mint},
     };
-    use spl_token_2022::state::Account;
 
     use crate::{
-        AccountLoad, Contributor, Fundraise, FundraiserError, MAX_BPS,
-        MAX_CONTRIBUTION_PERCENTAGE_BPS,
+        AccountLoad, ContributeReturnData, Contributor, Fundraise, FundraiserError,
+        InitializeInstructionData, MAX_BPS, MAX_CONTRIBUTION_PERCENTAGE_BPS, Referral, client, cpi,
         tests::{
             constants::{
-                ASSOCIATED_TOKEN_PROGRAM_ID, MINT_DECIMALS, PROGRAM_ID, SYSTEM_PROGRAM_ID,
-                TOKEN_PROGRAM_ID,
+                ASSOCIATED_TOKEN_PROGRAM_ID, FEE_COLLECTOR, INSTRUCTIONS_SYSVAR_ID, MINT_DECIMALS,
+                PROGRAM_ID, SYSTEM_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID,
+            },
+            pda::{
+                get_allowlist_page_pda, get_allowlist_receipt_pda, get_config_pda,
+                get_contributor_pda, get_fundraise_pda, get_vault_pda,
             },
-            pda::{get_contributor_pda, get_fundraise_pda},
             utils::{
-                assert_error, build_and_send_transaction, fetch_account, forward_time, init_ata,
-                init_mint, init_wallet, setup,
+                assert_error, build_and_send_transaction, fetch_account, forward_time,
+                freeze_token_account, init_ata, init_config, init_mint, init_token_account,
+                init_wallet, merkle_proof, merkle_root, setup, simulate_return_data,
             },
         },
     };
@@ -258,16 +929,16 @@ mod tests {
         let amount_to_raise: u64 = 5_000_000;
         let duration: u64 = SECONDS_PER_DAY; // 1 day
         let fundraise_pda = get_fundraise_pda(&maker.pubkey());
-        let vault = get_associated_token_address_with_program_id(
-            &fundraise_pda,
-            &mint_to_raise,
-            &TOKEN_PROGRAM_ID,
-        );
+        let vault = get_vault_pda(&fundraise_pda);
 
         let data = [
             vec![0u8],
             amount_to_raise.to_le_bytes().to_vec(),
             duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
         ]
         .concat();
         let ix = Instruction {
@@ -280,6 +951,7 @@ mod tests {
                 AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
                 AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
                 AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
             ],
             data,
         };
@@ -287,7 +959,7 @@ mod tests {
         let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
 
         let fundraise_acc = litesvm.get_account(&fundraise_pda).unwrap();
-        let fundraise = Fundraise::load(&fundraise_acc.data.as_ref()).unwrap();
+        let fundraise = Fundraise::load(fundraise_acc.data.as_ref()).unwrap();
         let pre_fundraise_current_amount = fundraise.get_current_amount();
 
         let pre_authority_ata_bal = fetch_account::<Account>(litesvm, &authority_ata).amount;
@@ -308,6 +980,7 @@ mod tests {
                 AccountMeta::new(vault, false),
                 AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
                 AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
             ],
             data,
         };
@@ -315,14 +988,15 @@ mod tests {
         let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
 
         let contributor_acc = litesvm.get_account(&contributor_pda).unwrap();
-        let contributor = Contributor::load(&contributor_acc.data.as_ref()).unwrap();
+        let contributor = Contributor::load(contributor_acc.data.as_ref()).unwrap();
 
         assert_eq!(contributor.fundraise, fundraise_pda.to_bytes());
         assert_eq!(contributor.authority, authority.pubkey().to_bytes());
+        assert_eq!(contributor.mint, mint_to_raise.to_bytes());
         assert_eq!(contributor.get_amount(), contribute_amount);
 
         let fundraise_acc = litesvm.get_account(&fundraise_pda).unwrap();
-        let fundraise = Fundraise::load(&fundraise_acc.data.as_ref()).unwrap();
+        let fundraise = Fundraise::load(fundraise_acc.data.as_ref()).unwrap();
         let post_fundraise_current_amount = fundraise.get_current_amount();
 
         assert_eq!(
@@ -341,7 +1015,7 @@ mod tests {
     }
 
     #[test]
-    fn throw_if_contribution_too_small() {
+    fn contribute_twice_records_history() {
         let (litesvm, _default_payer) = &mut setup();
         let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
         let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
@@ -351,16 +1025,16 @@ mod tests {
         let amount_to_raise: u64 = 5_000_000;
         let duration: u64 = SECONDS_PER_DAY; // 1 day
         let fundraise_pda = get_fundraise_pda(&maker.pubkey());
-        let vault = get_associated_token_address_with_program_id(
-            &fundraise_pda,
-            &mint_to_raise,
-            &TOKEN_PROGRAM_ID,
-        );
+        let vault = get_vault_pda(&fundraise_pda);
 
         let data = [
             vec![0u8],
             amount_to_raise.to_le_bytes().to_vec(),
             duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
         ]
         .concat();
         let ix = Instruction {
@@ -373,13 +1047,14 @@ mod tests {
                 AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
                 AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
                 AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
             ],
             data,
         };
 
-        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
 
-        let contribute_amount: u64 = 1u64.pow(MINT_DECIMALS as u32) - 1;
+        let contribute_amount: u64 = 100_000;
         let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
 
         let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
@@ -394,36 +1069,60 @@ mod tests {
                 AccountMeta::new(vault, false),
                 AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
                 AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
             ],
             data,
         };
 
-        let res = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+        build_and_send_transaction(
+            litesvm,
+            &[&authority],
+            &authority.pubkey(),
+            core::slice::from_ref(&ix),
+        )
+        .unwrap();
 
-        assert_error(res.unwrap_err(), FundraiserError::ContributionTooSmall);
+        let first_ts = litesvm.get_sysvar::<Clock>().unix_timestamp;
+        let contributor_acc = litesvm.get_account(&contributor_pda).unwrap();
+        let contributor = Contributor::load(contributor_acc.data.as_ref()).unwrap();
+
+        assert_eq!(contributor.get_contribution_count(), 1);
+        assert_eq!(contributor.get_last_contribution_ts(), first_ts);
+
+        forward_time(litesvm, 3600); // jump forward 1 hour
+        litesvm.expire_blockhash();
+
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+
+        let contributor_acc = litesvm.get_account(&contributor_pda).unwrap();
+        let contributor = Contributor::load(contributor_acc.data.as_ref()).unwrap();
+
+        assert_eq!(contributor.get_amount(), contribute_amount * 2);
+        assert_eq!(contributor.get_contribution_count(), 2);
+        assert_eq!(contributor.get_last_contribution_ts(), first_ts + 3600);
     }
 
     #[test]
-    fn throw_if_contribution_too_big() {
+    fn contribute_with_token_2022() {
         let (litesvm, _default_payer) = &mut setup();
         let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
         let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
-        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let mint_to_raise = init_mint(litesvm, TOKEN_2022_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
         let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
 
         let amount_to_raise: u64 = 5_000_000;
         let duration: u64 = SECONDS_PER_DAY; // 1 day
         let fundraise_pda = get_fundraise_pda(&maker.pubkey());
-        let vault = get_associated_token_address_with_program_id(
-            &fundraise_pda,
-            &mint_to_raise,
-            &TOKEN_PROGRAM_ID,
-        );
+        let vault = get_vault_pda(&fundraise_pda);
 
         let data = [
             vec![0u8],
             amount_to_raise.to_le_bytes().to_vec(),
             duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
         ]
         .concat();
         let ix = Instruction {
@@ -434,16 +1133,23 @@ mod tests {
                 AccountMeta::new(fundraise_pda, false),
                 AccountMeta::new(vault, false),
                 AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
-                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false),
                 AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
             ],
             data,
         };
 
-        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
 
-        let contribute_amount: u64 =
-            amount_to_raise * u64::from(MAX_CONTRIBUTION_PERCENTAGE_BPS) / u64::from(MAX_BPS) + 1;
+        let fundraise_acc = litesvm.get_account(&fundraise_pda).unwrap();
+        let fundraise = Fundraise::load(fundraise_acc.data.as_ref()).unwrap();
+        let pre_fundraise_current_amount = fundraise.get_current_amount();
+
+        let pre_authority_ata_bal = fetch_account::<Account>(litesvm, &authority_ata).amount;
+        let pre_vault_bal = fetch_account::<Account>(litesvm, &vault).amount;
+
+        let contribute_amount: u64 = 500_000;
         let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
 
         let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
@@ -457,37 +1163,193 @@ mod tests {
                 AccountMeta::new(authority_ata, false),
                 AccountMeta::new(vault, false),
                 AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
-                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
             ],
             data,
         };
 
-        let res = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
 
-        assert_error(res.unwrap_err(), FundraiserError::ContributionTooBig);
-    }
+        let contributor_acc = litesvm.get_account(&contributor_pda).unwrap();
+        let contributor = Contributor::load(contributor_acc.data.as_ref()).unwrap();
 
-    #[test]
-    fn throw_if_fundraiser_ended() {
-        let (litesvm, _default_payer) = &mut setup();
-        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
-        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
-        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
-        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+        assert_eq!(contributor.fundraise, fundraise_pda.to_bytes());
+        assert_eq!(contributor.authority, authority.pubkey().to_bytes());
+        assert_eq!(contributor.get_amount(), contribute_amount);
 
-        let amount_to_raise: u64 = 5_000_000;
-        let duration: u64 = SECONDS_PER_DAY; // 1 day
-        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
-        let vault = get_associated_token_address_with_program_id(
-            &fundraise_pda,
-            &mint_to_raise,
-            &TOKEN_PROGRAM_ID,
+        let fundraise_acc = litesvm.get_account(&fundraise_pda).unwrap();
+        let fundraise = Fundraise::load(fundraise_acc.data.as_ref()).unwrap();
+        let post_fundraise_current_amount = fundraise.get_current_amount();
+
+        assert_eq!(
+            pre_fundraise_current_amount,
+            post_fundraise_current_amount - contribute_amount
+        );
+
+        let post_authority_ata_bal = fetch_account::<Account>(litesvm, &authority_ata).amount;
+        let post_vault_bal = fetch_account::<Account>(litesvm, &vault).amount;
+
+        assert_eq!(
+            pre_authority_ata_bal,
+            post_authority_ata_bal + contribute_amount
         );
+        assert_eq!(pre_vault_bal, post_vault_bal - contribute_amount);
+    }
+
+    #[test]
+    fn throw_if_contribution_too_small() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let min_contribution: u64 = 1u64.pow(MINT_DECIMALS as u32);
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            min_contribution.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let contribute_amount: u64 = min_contribution - 1;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let res = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::ContributionTooSmall);
+    }
+
+    #[test]
+    fn throw_if_contribution_too_big() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let contribute_amount: u64 =
+            amount_to_raise * u64::from(MAX_CONTRIBUTION_PERCENTAGE_BPS) / u64::from(MAX_BPS) + 1;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let res = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::ContributionTooBig);
+    }
+
+    #[test]
+    fn throw_if_fundraiser_ended() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
 
         let data = [
             vec![0u8],
             amount_to_raise.to_le_bytes().to_vec(),
             duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
         ]
         .concat();
         let ix = Instruction {
@@ -500,6 +1362,7 @@ mod tests {
                 AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
                 AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
                 AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
             ],
             data,
         };
@@ -523,6 +1386,7 @@ mod tests {
                 AccountMeta::new(vault, false),
                 AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
                 AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
             ],
             data,
         };
@@ -532,6 +1396,59 @@ mod tests {
         assert_error(res.unwrap_err(), FundraiserError::FundraiserEnded);
     }
 
+    #[test]
+    fn throw_if_contributing_after_early_claim() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        init_ata(litesvm, mint_to_raise, authority.pubkey(), 10_000_000);
+
+        let ix = client::initialize(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            5_000_000,
+            SECONDS_PER_DAY,
+        );
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let contribute_ix = |amount| {
+            client::contribute(
+                &authority.pubkey(),
+                &maker.pubkey(),
+                &mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+                amount,
+            )
+        };
+        build_and_send_transaction(
+            litesvm,
+            &[&authority],
+            &authority.pubkey(),
+            &[contribute_ix(5_000_000)],
+        )
+        .unwrap();
+
+        // claimed before the deadline, leaving nothing recorded as raised
+        let ix = client::claim(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            &FEE_COLLECTOR,
+        );
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let res = build_and_send_transaction(
+            litesvm,
+            &[&authority],
+            &authority.pubkey(),
+            &[contribute_ix(500_000)],
+        );
+
+        assert_error(res.unwrap_err(), FundraiserError::AlreadyClaimed);
+    }
+
     #[test]
     fn throw_if_max_contribution_reached() {
         let (litesvm, _default_payer) = &mut setup();
@@ -543,16 +1460,16 @@ mod tests {
         let amount_to_raise: u64 = 5_000_000;
         let duration: u64 = SECONDS_PER_DAY; // 1 day
         let fundraise_pda = get_fundraise_pda(&maker.pubkey());
-        let vault = get_associated_token_address_with_program_id(
-            &fundraise_pda,
-            &mint_to_raise,
-            &TOKEN_PROGRAM_ID,
-        );
+        let vault = get_vault_pda(&fundraise_pda);
 
         let data = [
             vec![0u8],
             amount_to_raise.to_le_bytes().to_vec(),
             duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
         ]
         .concat();
         let ix = Instruction {
@@ -565,6 +1482,7 @@ mod tests {
                 AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
                 AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
                 AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
             ],
             data,
         };
@@ -586,6 +1504,7 @@ mod tests {
                 AccountMeta::new(vault, false),
                 AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
                 AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
             ],
             data,
         };
@@ -609,6 +1528,7 @@ mod tests {
                 AccountMeta::new(vault, false),
                 AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
                 AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
             ],
             data,
         };
@@ -620,4 +1540,1768 @@ mod tests {
             FundraiserError::MaximumContributionsReached,
         );
     }
+
+    #[test]
+    fn throw_if_invalid_program_ids() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let contribute_amount: u64 = 500_000;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let mut ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        ix.accounts[6].pubkey = Pubkey::new_unique();
+
+        let res = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::ProgramIdMismatch);
+    }
+
+    #[test]
+    fn throw_if_token_program_mismatch() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let contribute_amount: u64 = 500_000;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let mut ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        ix.accounts[7].pubkey = TOKEN_2022_PROGRAM_ID;
+
+        let res = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::InvalidTokenProgram);
+    }
+
+    #[test]
+    fn throw_if_vault_mismatch() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let contribute_amount: u64 = 500_000;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let mut ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        // a token account of the mint held by the fundraise, but not the recorded vault
+        let decoy_vault = Pubkey::new_unique();
+        init_token_account(litesvm, decoy_vault, mint_to_raise, fundraise_pda, 0);
+
+        ix.accounts[5].pubkey = decoy_vault;
+
+        let res = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::InvalidAddress);
+    }
+
+    #[test]
+    fn throw_if_accounts_not_writable() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let contribute_amount: u64 = 500_000;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        for index in [2, 3, 4, 5] {
+            litesvm.expire_blockhash();
+
+            let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+            let mut ix = Instruction {
+                program_id: PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new(authority.pubkey(), true),
+                    AccountMeta::new_readonly(mint_to_raise, false),
+                    AccountMeta::new(fundraise_pda, false),
+                    AccountMeta::new(contributor_pda, false),
+                    AccountMeta::new(authority_ata, false),
+                    AccountMeta::new(vault, false),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(get_config_pda(), false),
+                ],
+                data,
+            };
+
+            ix.accounts[index].is_writable = false;
+
+            let res =
+                build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+            assert_error(res.unwrap_err(), FundraiserError::AccountNotWritable);
+        }
+    }
+
+    #[test]
+    fn throw_if_protocol_paused() {
+        let (litesvm, default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+        init_config(litesvm, default_payer.pubkey(), 0, FEE_COLLECTOR, true);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let contribute_amount: u64 = 500_000;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let res = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::ProtocolPaused);
+    }
+
+    #[test]
+    fn contribute_with_full_cap() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 10_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+        let max_contribution_bps: u16 = 10_000; // 100%
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            max_contribution_bps.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let contribute_amount: u64 = 5_000_000;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        let contributor_acc = litesvm.get_account(&contributor_pda).unwrap();
+        let contributor = Contributor::load(contributor_acc.data.as_ref()).unwrap();
+
+        assert_eq!(contributor.get_amount(), contribute_amount);
+    }
+
+    #[test]
+    fn contribute_without_cap() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 10_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+        let max_contribution_bps: u16 = 0; // no cap
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            max_contribution_bps.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let contribute_amount: u64 = 6_000_000;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        let contributor_acc = litesvm.get_account(&contributor_pda).unwrap();
+        let contributor = Contributor::load(contributor_acc.data.as_ref()).unwrap();
+
+        assert_eq!(contributor.get_amount(), contribute_amount);
+    }
+
+    #[test]
+    fn contribute_with_duplicate_check() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let contribute_amount: u64 = 200_000;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [
+            vec![1u8],
+            contribute_amount.to_le_bytes().to_vec(),
+            vec![1u8],
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+                AccountMeta::new_readonly(INSTRUCTIONS_SYSVAR_ID, false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        let contributor_acc = litesvm.get_account(&contributor_pda).unwrap();
+        let contributor = Contributor::load(contributor_acc.data.as_ref()).unwrap();
+
+        assert_eq!(contributor.get_amount(), contribute_amount);
+    }
+
+    #[test]
+    fn throw_if_duplicate_contribute() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let contribute_amount: u64 = 200_000;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [
+            vec![1u8],
+            contribute_amount.to_le_bytes().to_vec(),
+            vec![1u8],
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+                AccountMeta::new_readonly(INSTRUCTIONS_SYSVAR_ID, false),
+            ],
+            data,
+        };
+
+        let res = build_and_send_transaction(
+            litesvm,
+            &[&authority],
+            &authority.pubkey(),
+            &[ix.clone(), ix],
+        );
+
+        assert_error(res.unwrap_err(), FundraiserError::DuplicateInstruction);
+    }
+
+    #[test]
+    fn contribute_repeated_without_duplicate_check() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let contribute_amount: u64 = 200_000;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(
+            litesvm,
+            &[&authority],
+            &authority.pubkey(),
+            &[ix.clone(), ix],
+        );
+
+        let contributor_acc = litesvm.get_account(&contributor_pda).unwrap();
+        let contributor = Contributor::load(contributor_acc.data.as_ref()).unwrap();
+
+        assert_eq!(contributor.get_amount(), contribute_amount * 2);
+    }
+
+    #[test]
+    fn contribute_equal_to_min_contribution() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+        let min_contribution: u64 = 1u64.pow(MINT_DECIMALS as u32);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            min_contribution.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let contribute_amount: u64 = min_contribution;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        let contributor_acc = litesvm.get_account(&contributor_pda).unwrap();
+        let contributor = Contributor::load(contributor_acc.data.as_ref()).unwrap();
+
+        assert_eq!(contributor.get_amount(), contribute_amount);
+    }
+
+    #[test]
+    fn throw_if_contribution_zero() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let contribute_amount: u64 = 0;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let res = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::ContributionTooSmall);
+    }
+
+    #[test]
+    fn contribute_with_allowlist_slot() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            1u8.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let page_index: u16 = 0;
+        let page_pda = get_allowlist_page_pda(&fundraise_pda, page_index);
+
+        let data = [vec![18u8], page_index.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(fundraise_pda, false),
+                AccountMeta::new(page_pda, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let bit_index: u16 = 42;
+        let receipt_pda = get_allowlist_receipt_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [
+            vec![19u8],
+            page_index.to_le_bytes().to_vec(),
+            bit_index.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(maker.pubkey(), true),
+                AccountMeta::new_readonly(fundraise_pda, false),
+                AccountMeta::new(page_pda, false),
+                AccountMeta::new(receipt_pda, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+            data,
+        };
+
+        let _ =
+            build_and_send_transaction(litesvm, &[&authority, &maker], &authority.pubkey(), &[ix]);
+
+        let contribute_amount: u64 = 500_000;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+                AccountMeta::new_readonly(receipt_pda, false),
+                AccountMeta::new_readonly(page_pda, false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        let contributor_acc = litesvm.get_account(&contributor_pda).unwrap();
+        let contributor = Contributor::load(contributor_acc.data.as_ref()).unwrap();
+
+        assert_eq!(contributor.get_amount(), contribute_amount);
+    }
+
+    #[test]
+    fn contribute_with_merkle_proof() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        let wallets: Vec<_> = (0..3)
+            .map(|_| init_wallet(litesvm, LAMPORTS_PER_SOL))
+            .collect();
+        let excluded = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let allowed: Vec<_> = wallets.iter().map(|wallet| wallet.pubkey()).collect();
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            vec![0u8, 0u8],
+            0i64.to_le_bytes().to_vec(),
+            vec![0u8, 0u8],
+            merkle_root(&allowed).to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let contribute_amount: u64 = 500_000;
+
+        let mut contribute = |authority: &Keypair, proof: &[u8]| {
+            let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+            let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+            let data = [
+                vec![1u8],
+                contribute_amount.to_le_bytes().to_vec(),
+                vec![0u8, 0u8],
+                proof.to_vec(),
+            ]
+            .concat();
+            let ix = Instruction {
+                program_id: PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new(authority.pubkey(), true),
+                    AccountMeta::new_readonly(mint_to_raise, false),
+                    AccountMeta::new(fundraise_pda, false),
+                    AccountMeta::new(contributor_pda, false),
+                    AccountMeta::new(authority_ata, false),
+                    AccountMeta::new(vault, false),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(get_config_pda(), false),
+                ],
+                data,
+            };
+
+            build_and_send_transaction(litesvm, &[authority], &authority.pubkey(), &[ix]).err()
+        };
+
+        let proof = merkle_proof(&allowed, &wallets[0].pubkey());
+
+        assert!(contribute(&wallets[0], &proof).is_none());
+
+        // a wallet outside the tree, even with a proof valid for another wallet
+        assert_error(
+            contribute(&excluded, &proof).unwrap(),
+            FundraiserError::NotAllowlisted,
+        );
+
+        // a proof cut short of a whole sibling
+        let proof = merkle_proof(&allowed, &wallets[1].pubkey());
+
+        assert_error(
+            contribute(&wallets[1], &proof[..proof.len() - 1]).unwrap(),
+            FundraiserError::NotAllowlisted,
+        );
+
+        // a missing proof
+        assert_error(
+            contribute(&wallets[2], &[]).unwrap(),
+            FundraiserError::NotAllowlisted,
+        );
+
+        let fundraise_acc = litesvm.get_account(&fundraise_pda).unwrap();
+        let fundraise = Fundraise::load(fundraise_acc.data.as_ref()).unwrap();
+
+        assert_eq!(fundraise.get_current_amount(), contribute_amount);
+    }
+
+    #[test]
+    fn throw_if_not_allowlisted() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            1u8.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let contribute_amount: u64 = 500_000;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let res = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::NotAllowlisted);
+    }
+
+    #[test]
+    fn contribute_sets_return_data() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+        let contribute_ix = |amount: u64| Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data: [vec![1u8], amount.to_le_bytes().to_vec()].concat(),
+        };
+
+        build_and_send_transaction(
+            litesvm,
+            &[&authority],
+            &authority.pubkey(),
+            &[contribute_ix(300_000)],
+        )
+        .unwrap();
+
+        let (program_id, data) = simulate_return_data(
+            litesvm,
+            &[&authority],
+            &authority.pubkey(),
+            &[contribute_ix(200_000)],
+        );
+
+        assert_eq!(program_id, PROGRAM_ID);
+        assert_eq!(
+            ContributeReturnData::from_bytes(&data.try_into().unwrap()),
+            ContributeReturnData {
+                contributor_total: 500_000,
+                fundraise_total: 500_000,
+                remaining: amount_to_raise - 500_000,
+                amount: 200_000,
+            }
+        );
+    }
+
+    #[test]
+    fn rent_payer_funds_contributor() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let sponsor = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000);
+
+        let ix = client::initialize(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            5_000_000,
+            SECONDS_PER_DAY,
+        );
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+        let pre_authority_bal = litesvm.get_balance(&authority.pubkey()).unwrap();
+        let pre_sponsor_bal = litesvm.get_balance(&sponsor.pubkey()).unwrap();
+
+        // the authority signs for its tokens while the sponsor pays the fee and the rent
+        let ix = client::with_rent_payer(
+            client::contribute(
+                &authority.pubkey(),
+                &maker.pubkey(),
+                &mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+                500_000,
+            ),
+            &sponsor.pubkey(),
+        );
+        build_and_send_transaction(litesvm, &[&sponsor, &authority], &sponsor.pubkey(), &[ix])
+            .unwrap();
+
+        let contributor_acc = litesvm.get_account(&contributor_pda).unwrap();
+
+        assert_eq!(
+            Contributor::load(contributor_acc.data.as_ref())
+                .unwrap()
+                .get_amount(),
+            500_000
+        );
+        assert_eq!(
+            litesvm.get_balance(&authority.pubkey()).unwrap(),
+            pre_authority_bal
+        );
+        assert_eq!(
+            litesvm.get_balance(&sponsor.pubkey()).unwrap(),
+            pre_sponsor_bal - contributor_acc.lamports - 2 * 5_000
+        );
+        assert_eq!(
+            fetch_account::<Account>(litesvm, &authority_ata).amount,
+            500_000
+        );
+    }
+
+    #[test]
+    fn second_contribution_raises_tier() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000);
+
+        let ix = client::with_tier_thresholds(
+            client::initialize(
+                &maker.pubkey(),
+                &mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+                5_000_000,
+                SECONDS_PER_DAY,
+            ),
+            [300_000, 700_000, 0, 0],
+        );
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let contributor_pda =
+            get_contributor_pda(&get_fundraise_pda(&maker.pubkey()), &authority.pubkey());
+        let mut tiers = Vec::new();
+
+        for amount in [400_000, 350_000] {
+            let ix = client::contribute(
+                &authority.pubkey(),
+                &maker.pubkey(),
+                &mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+                amount,
+            );
+            build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+
+            let contributor_acc = litesvm.get_account(&contributor_pda).unwrap();
+            tiers.push(
+                Contributor::load(contributor_acc.data.as_ref())
+                    .unwrap()
+                    .get_tier(),
+            );
+        }
+
+        assert_eq!(tiers, [1, 2]);
+    }
+
+    #[test]
+    fn contribute_clamped_to_cap() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000);
+
+        // a 10% cap of 500_000
+        let mut ix = client::initialize(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            5_000_000,
+            SECONDS_PER_DAY,
+        );
+        ix.data = cpi::initialize_v1_ix_data(&InitializeInstructionData {
+            max_contribution_bps: MAX_CONTRIBUTION_PERCENTAGE_BPS,
+            ..InitializeInstructionData::new(5_000_000, SECONDS_PER_DAY)
+        });
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let contribute_ix = |amount: u64| {
+            client::with_clamp(client::contribute(
+                &authority.pubkey(),
+                &maker.pubkey(),
+                &mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+                amount,
+            ))
+        };
+
+        build_and_send_transaction(
+            litesvm,
+            &[&authority],
+            &authority.pubkey(),
+            &[contribute_ix(300_000)],
+        )
+        .unwrap();
+
+        // only the 200_000 left under the cap is taken
+        let (_, data) = simulate_return_data(
+            litesvm,
+            &[&authority],
+            &authority.pubkey(),
+            &[contribute_ix(400_000)],
+        );
+
+        assert_eq!(
+            ContributeReturnData::from_bytes(&data.try_into().unwrap()).amount,
+            200_000
+        );
+
+        build_and_send_transaction(
+            litesvm,
+            &[&authority],
+            &authority.pubkey(),
+            &[contribute_ix(400_000)],
+        )
+        .unwrap();
+
+        assert_eq!(
+            fetch_account::<Account>(litesvm, &authority_ata).amount,
+            500_000
+        );
+        assert_eq!(
+            fetch_account::<Account>(litesvm, &get_vault_pda(&get_fundraise_pda(&maker.pubkey())))
+                .amount,
+            500_000
+        );
+
+        // with the cap used up there is nothing left to clamp to
+        let res = build_and_send_transaction(
+            litesvm,
+            &[&authority],
+            &authority.pubkey(),
+            &[contribute_ix(100_000)],
+        );
+
+        assert_error(res.unwrap_err(), FundraiserError::ContributionTooSmall);
+    }
+
+    #[test]
+    fn throw_if_token_accounts_frozen() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000);
+
+        let ix = client::initialize(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            5_000_000,
+            SECONDS_PER_DAY,
+        );
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let contribute_ix = |amount: u64| {
+            client::contribute(
+                &authority.pubkey(),
+                &maker.pubkey(),
+                &mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+                amount,
+            )
+        };
+
+        freeze_token_account(litesvm, authority_ata);
+
+        let res = build_and_send_transaction(
+            litesvm,
+            &[&authority],
+            &authority.pubkey(),
+            &[contribute_ix(500_000)],
+        );
+
+        assert_error(res.unwrap_err(), FundraiserError::SourceAccountFrozen);
+
+        init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000);
+        freeze_token_account(litesvm, get_vault_pda(&get_fundraise_pda(&maker.pubkey())));
+
+        let res = build_and_send_transaction(
+            litesvm,
+            &[&authority],
+            &authority.pubkey(),
+            &[contribute_ix(400_000)],
+        );
+
+        assert_error(res.unwrap_err(), FundraiserError::VaultFrozen);
+    }
+
+    #[test]
+    fn contributions_credit_referrer() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let referrer = Pubkey::new_unique();
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        let ix = client::initialize(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            5_000_000,
+            SECONDS_PER_DAY,
+        );
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let referral_pda = client::referral_address(&get_fundraise_pda(&maker.pubkey()), &referrer);
+        let mut totals = Vec::new();
+
+        for amount in [400_000, 250_000] {
+            let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+            init_ata(litesvm, mint_to_raise, authority.pubkey(), amount);
+
+            let ix = client::with_referrer(
+                client::contribute(
+                    &authority.pubkey(),
+                    &maker.pubkey(),
+                    &mint_to_raise,
+                    &TOKEN_PROGRAM_ID,
+                    amount,
+                ),
+                &maker.pubkey(),
+                &referrer,
+            );
+            build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+
+            let referral_acc = litesvm.get_account(&referral_pda).unwrap();
+            let referral = Referral::load(referral_acc.data.as_ref()).unwrap();
+            totals.push((
+                referral.get_referred_amount(),
+                referral.get_referred_count(),
+            ));
+
+            assert_eq!(referral.referrer, referrer.to_bytes());
+        }
+
+        assert_eq!(totals, [(400_000, 1), (650_000, 2)]);
+    }
+
+    #[test]
+    fn throw_if_self_referral() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000);
+
+        let ix = client::initialize(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            5_000_000,
+            SECONDS_PER_DAY,
+        );
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let ix = client::with_referrer(
+            client::contribute(
+                &authority.pubkey(),
+                &maker.pubkey(),
+                &mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+                500_000,
+            ),
+            &maker.pubkey(),
+            &authority.pubkey(),
+        );
+        let res = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::SelfReferral);
+    }
+
+    #[test]
+    fn contribute_mints_receipt() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let receipt_mint = Keypair::new();
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000);
+
+        let ix = client::with_receipt_mint(
+            client::initialize(
+                &maker.pubkey(),
+                &mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+                5_000_000,
+                SECONDS_PER_DAY,
+            ),
+            &receipt_mint.pubkey(),
+        );
+        build_and_send_transaction(litesvm, &[&maker, &receipt_mint], &maker.pubkey(), &[ix])
+            .unwrap();
+
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let mint_acc = litesvm.get_account(&receipt_mint.pubkey()).unwrap();
+        let mint = StateWithExtensions::<Mint>::unpack(&mint_acc.data).unwrap();
+
+        assert_eq!(mint_acc.owner, TOKEN_2022_PROGRAM_ID);
+        assert_eq!(mint.base.mint_authority, Some(fundraise_pda).into());
+        assert_eq!(mint.base.decimals, MINT_DECIMALS);
+        assert!(mint.get_extension::<NonTransferable>().is_ok());
+
+        let receipt_ata = init_ata(litesvm, receipt_mint.pubkey(), authority.pubkey(), 0);
+
+        for amount in [300_000, 200_000] {
+            let ix = client::with_receipt(
+                client::contribute(
+                    &authority.pubkey(),
+                    &maker.pubkey(),
+                    &mint_to_raise,
+                    &TOKEN_PROGRAM_ID,
+                    amount,
+                ),
+                &receipt_mint.pubkey(),
+                &authority.pubkey(),
+            );
+            build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+        }
+
+        let fundraise_acc = litesvm.get_account(&fundraise_pda).unwrap();
+
+        assert_eq!(
+            Fundraise::load(&fundraise_acc.data).unwrap().receipt_mint,
+            receipt_mint.pubkey().to_bytes()
+        );
+        assert_eq!(
+            fetch_account::<Account>(litesvm, &receipt_ata).amount,
+            500_000
+        );
+    }
+
+    #[test]
+    fn throw_if_receipt_accounts_missing() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let receipt_mint = Keypair::new();
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000);
+
+        let ix = client::with_receipt_mint(
+            client::initialize(
+                &maker.pubkey(),
+                &mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+                5_000_000,
+                SECONDS_PER_DAY,
+            ),
+            &receipt_mint.pubkey(),
+        );
+        build_and_send_transaction(litesvm, &[&maker, &receipt_mint], &maker.pubkey(), &[ix])
+            .unwrap();
+
+        let ix = client::contribute(
+            &authority.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            500_000,
+        );
+        let res = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::MissingAccounts);
+    }
+
+    /// Creates a fundraise gated on `gate_mint`, returning the maker and the mint to raise.
+    fn gated_fundraise(litesvm: &mut litesvm::LiteSVM, gate_mint: &Pubkey) -> (Keypair, Pubkey) {
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        let ix = client::with_gate_mint(
+            client::initialize(
+                &maker.pubkey(),
+                &mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+                5_000_000,
+                SECONDS_PER_DAY,
+            ),
+            gate_mint,
+        );
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        (maker, mint_to_raise)
+    }
+
+    #[test]
+    fn contribute_if_gate_token_held() {
+        let (litesvm, _default_payer) = &mut setup();
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let gate_mint = init_mint(litesvm, TOKEN_PROGRAM_ID, 0, 1);
+        let (maker, mint_to_raise) = gated_fundraise(litesvm, &gate_mint);
+        init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000);
+        let gate_ata = init_ata(litesvm, gate_mint, authority.pubkey(), 1);
+
+        let ix = client::with_gate(
+            client::contribute(
+                &authority.pubkey(),
+                &maker.pubkey(),
+                &mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+                500_000,
+            ),
+            &gate_ata,
+        );
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+
+        let fundraise_acc = litesvm
+            .get_account(&get_fundraise_pda(&maker.pubkey()))
+            .unwrap();
+        let fundraise = Fundraise::load(&fundraise_acc.data).unwrap();
+
+        assert_eq!(fundraise.gate_mint, gate_mint.to_bytes());
+        assert_eq!(fundraise.get_current_amount(), 500_000);
+        // the gate token is only checked, never moved
+        assert_eq!(fetch_account::<Account>(litesvm, &gate_ata).amount, 1);
+    }
+
+    #[test]
+    fn throw_if_gate_token_not_held() {
+        let (litesvm, _default_payer) = &mut setup();
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let holder = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let gate_mint = init_mint(litesvm, TOKEN_PROGRAM_ID, 0, 1);
+        let (maker, mint_to_raise) = gated_fundraise(litesvm, &gate_mint);
+        init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000);
+        let holder_gate_ata = init_ata(litesvm, gate_mint, holder.pubkey(), 1);
+
+        let ix = client::with_gate(
+            client::contribute(
+                &authority.pubkey(),
+                &maker.pubkey(),
+                &mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+                500_000,
+            ),
+            &holder_gate_ata,
+        );
+        let res = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::GateTokenRequired);
+    }
+
+    #[test]
+    fn throw_if_gate_token_balance_zero() {
+        let (litesvm, _default_payer) = &mut setup();
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let gate_mint = init_mint(litesvm, TOKEN_PROGRAM_ID, 0, 1);
+        let (maker, mint_to_raise) = gated_fundraise(litesvm, &gate_mint);
+        init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000);
+        let gate_ata = init_ata(litesvm, gate_mint, authority.pubkey(), 0);
+
+        let ix = client::with_gate(
+            client::contribute(
+                &authority.pubkey(),
+                &maker.pubkey(),
+                &mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+                500_000,
+            ),
+            &gate_ata,
+        );
+        let res = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::GateTokenRequired);
+    }
+
+    #[test]
+    fn log_failed_account_check() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000);
+
+        let ix = client::initialize(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            5_000_000,
+            SECONDS_PER_DAY,
+        );
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        // the vault and the authority's token account swapped places
+
+        let mut ix = client::contribute(
+            &authority.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            500_000,
+        );
+        ix.accounts.swap(4, 5);
+        let res = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+        let failed = res.unwrap_err();
+
+        assert!(
+            failed
+                .meta
+                .logs
+                .contains(&"Program log: account #5 (vault) failed vault check".to_string())
+        );
+        assert_error(failed, FundraiserError::InvalidAddress);
+    }
 }
@@ -0,0 +1,197 @@
+use pinocchio::{ProgramResult, account_info::AccountInfo, program_error::ProgramError};
+
+use crate::{
+    Contribute, ContributeAccounts, ContributeInstructionData, FundraiserError,
+    FundraiserInstruction, Handler, TokenAccountInterface,
+};
+
+/// Contribute spending from `owner`'s token account through an approval, signed by its
+/// delegate, who precedes the owner in the account list. The delegate pays for the contributor
+/// account, which is the owner's, so only the owner can refund the contribution.
+pub struct ContributeDelegated<'a> {
+    pub inner: Contribute<'a>,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for ContributeDelegated<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let [delegate, owner, accounts @ ..] = accounts else {
+            return Err(FundraiserError::MissingAccounts.into());
+        };
+        let accounts = ContributeAccounts::delegated(delegate, owner, accounts)?;
+        let data = ContributeInstructionData::try_from(data)?;
+
+        TokenAccountInterface::check_delegate(
+            accounts.authority_token_account,
+            delegate.key(),
+            data.amount,
+        )?;
+
+        Ok(Self {
+            inner: Contribute::new(data, accounts)?,
+        })
+    }
+}
+
+impl Handler for ContributeDelegated<'_> {
+    const DISCRIMINATOR: u8 = FundraiserInstruction::ContributeDelegated as u8;
+
+    fn process(&mut self) -> ProgramResult {
+        self.inner.process()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use litesvm::LiteSVM;
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_keypair::Keypair;
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use spl_associated_token_account::solana_program::{
+        clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL,
+    };
+    use spl_token_2022::state::Account;
+
+    use crate::{
+        AccountLoad, Contributor, FundraiserError, client, cpi,
+        tests::{
+            constants::{MINT_DECIMALS, TOKEN_PROGRAM_ID},
+            utils::{
+                approve_delegate, assert_error, build_and_send_transaction, fetch_account,
+                init_ata, init_mint, init_wallet, setup,
+            },
+        },
+    };
+
+    /// Contribute of `amount` from `owner`'s associated token account, signed by `delegate`.
+    fn contribute_delegated(
+        delegate: &Pubkey,
+        owner: &Pubkey,
+        maker: &Pubkey,
+        mint_to_raise: &Pubkey,
+        amount: u64,
+    ) -> Instruction {
+        let fundraise = client::fundraise_address(maker);
+        let mut ix = client::contribute(owner, maker, mint_to_raise, &TOKEN_PROGRAM_ID, amount);
+
+        ix.accounts[0] = AccountMeta::new(*delegate, true);
+        ix.accounts
+            .insert(1, AccountMeta::new_readonly(*owner, false));
+        ix.accounts[4] = AccountMeta::new(client::contributor_address(&fundraise, owner), false);
+        ix.data = cpi::contribute_delegated_ix_data(amount).to_vec();
+        ix
+    }
+
+    fn initialize(litesvm: &mut LiteSVM, maker: &Keypair, mint_to_raise: &Pubkey) {
+        let ix = client::initialize(
+            &maker.pubkey(),
+            mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            5_000_000,
+            SECONDS_PER_DAY,
+        );
+        build_and_send_transaction(litesvm, &[maker], &maker.pubkey(), &[ix]).unwrap();
+    }
+
+    #[test]
+    fn contribute_through_approval() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let owner = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let delegate = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let owner_ata = init_ata(litesvm, mint_to_raise, owner.pubkey(), 1_000_000);
+        approve_delegate(litesvm, owner_ata, delegate.pubkey(), 800_000);
+        initialize(litesvm, &maker, &mint_to_raise);
+
+        let ix = contribute_delegated(
+            &delegate.pubkey(),
+            &owner.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            500_000,
+        );
+        build_and_send_transaction(litesvm, &[&delegate], &delegate.pubkey(), &[ix]).unwrap();
+
+        let fundraise = client::fundraise_address(&maker.pubkey());
+        let contributor_pda = client::contributor_address(&fundraise, &owner.pubkey());
+        let contributor_acc = litesvm.get_account(&contributor_pda).unwrap();
+        let contributor = Contributor::load(contributor_acc.data.as_ref()).unwrap();
+
+        assert_eq!(contributor.authority, owner.pubkey().to_bytes());
+        assert_eq!(contributor.rent_payer, delegate.pubkey().to_bytes());
+        assert_eq!(contributor.get_amount(), 500_000);
+
+        let owner_ata_acc = fetch_account::<Account>(litesvm, &owner_ata);
+
+        assert_eq!(owner_ata_acc.amount, 500_000);
+        assert_eq!(owner_ata_acc.delegated_amount, 300_000);
+
+        // only the owner refunds, returning the contributor's rent to the delegate
+        let ix = client::with_rent_destination(
+            client::refund(
+                &owner.pubkey(),
+                &maker.pubkey(),
+                &mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+            ),
+            &delegate.pubkey(),
+        );
+        build_and_send_transaction(litesvm, &[&owner], &owner.pubkey(), &[ix]).unwrap();
+
+        assert!(litesvm.get_account(&contributor_pda).is_none());
+        assert_eq!(
+            fetch_account::<Account>(litesvm, &owner_ata).amount,
+            1_000_000
+        );
+    }
+
+    #[test]
+    fn throw_if_delegation_too_small() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let owner = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let delegate = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let owner_ata = init_ata(litesvm, mint_to_raise, owner.pubkey(), 1_000_000);
+        approve_delegate(litesvm, owner_ata, delegate.pubkey(), 400_000);
+        initialize(litesvm, &maker, &mint_to_raise);
+
+        let ix = contribute_delegated(
+            &delegate.pubkey(),
+            &owner.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            500_000,
+        );
+        let res = build_and_send_transaction(litesvm, &[&delegate], &delegate.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::InsufficientDelegation);
+    }
+
+    #[test]
+    fn throw_if_signer_not_delegate() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let owner = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let delegate = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let impostor = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let owner_ata = init_ata(litesvm, mint_to_raise, owner.pubkey(), 1_000_000);
+        approve_delegate(litesvm, owner_ata, delegate.pubkey(), 1_000_000);
+        initialize(litesvm, &maker, &mint_to_raise);
+
+        let ix = contribute_delegated(
+            &impostor.pubkey(),
+            &owner.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            500_000,
+        );
+        let res = build_and_send_transaction(litesvm, &[&impostor], &impostor.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::InvalidDelegate);
+    }
+}
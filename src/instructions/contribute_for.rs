@@ -0,0 +1,171 @@
+use pinocchio::{ProgramResult, account_info::AccountInfo, program_error::ProgramError};
+
+use crate::{
+    Contribute, ContributeAccounts, ContributeInstructionData, FundraiserError,
+    FundraiserInstruction, Handler,
+};
+
+/// Contribute paid for by the signer on behalf of `beneficiary`, who follows the signer in the
+/// account list. The tokens come from the signer's token account, while the contributor
+/// account, the contribution cap and the allowlist are those of the beneficiary, so only the
+/// beneficiary can refund the contribution.
+pub struct ContributeFor<'a> {
+    pub inner: Contribute<'a>,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for ContributeFor<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let [payer, beneficiary, accounts @ ..] = accounts else {
+            return Err(FundraiserError::MissingAccounts.into());
+        };
+        let accounts = ContributeAccounts::with_beneficiary(payer, beneficiary, accounts)?;
+
+        Ok(Self {
+            inner: Contribute::new(ContributeInstructionData::try_from(data)?, accounts)?,
+        })
+    }
+}
+
+impl Handler for ContributeFor<'_> {
+    const DISCRIMINATOR: u8 = FundraiserInstruction::ContributeFor as u8;
+
+    fn process(&mut self) -> ProgramResult {
+        self.inner.process()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use litesvm::LiteSVM;
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_keypair::Keypair;
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use spl_associated_token_account::solana_program::{
+        clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL,
+    };
+    use spl_token_2022::state::Account;
+
+    use crate::{
+        AccountLoad, Contributor,
+        tests::{
+            constants::{
+                ASSOCIATED_TOKEN_PROGRAM_ID, MINT_DECIMALS, PROGRAM_ID, SYSTEM_PROGRAM_ID,
+                TOKEN_PROGRAM_ID,
+            },
+            pda::{get_config_pda, get_contributor_pda, get_fundraise_pda, get_vault_pda},
+            utils::{
+                build_and_send_transaction, fetch_account, forward_time, init_ata, init_mint,
+                init_wallet, setup,
+            },
+        },
+    };
+
+    fn initialize(litesvm: &mut LiteSVM, maker: &Keypair, mint_to_raise: Pubkey) -> Pubkey {
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+
+        let data = [
+            vec![0u8],
+            5_000_000u64.to_le_bytes().to_vec(),
+            SECONDS_PER_DAY.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(get_vault_pda(&fundraise_pda), false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        build_and_send_transaction(litesvm, &[maker], &maker.pubkey(), &[ix]).unwrap();
+
+        fundraise_pda
+    }
+
+    #[test]
+    fn contribute_for_beneficiary() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let payer = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let beneficiary = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let payer_ata = init_ata(litesvm, mint_to_raise, payer.pubkey(), 1_000_000_000);
+        let beneficiary_ata = init_ata(litesvm, mint_to_raise, beneficiary.pubkey(), 0);
+
+        let fundraise_pda = initialize(litesvm, &maker, mint_to_raise);
+        let vault = get_vault_pda(&fundraise_pda);
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &beneficiary.pubkey());
+        let contribute_amount: u64 = 500_000;
+
+        let data = [vec![23u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(payer.pubkey(), true),
+                AccountMeta::new_readonly(beneficiary.pubkey(), false),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(payer_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        build_and_send_transaction(litesvm, &[&payer], &payer.pubkey(), &[ix]).unwrap();
+
+        let contributor_acc = litesvm.get_account(&contributor_pda).unwrap();
+        let contributor = Contributor::load(contributor_acc.data.as_ref()).unwrap();
+
+        assert_eq!(contributor.authority, beneficiary.pubkey().to_bytes());
+        assert_eq!(contributor.get_amount(), contribute_amount);
+        assert_eq!(
+            fetch_account::<Account>(litesvm, &payer_ata).amount,
+            1_000_000_000 - contribute_amount
+        );
+
+        forward_time(litesvm, 3600); // jump forward 1 hour
+
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(beneficiary.pubkey(), true),
+                AccountMeta::new(maker.pubkey(), false),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(beneficiary_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            ],
+            data: vec![2u8],
+        };
+
+        build_and_send_transaction(litesvm, &[&beneficiary], &beneficiary.pubkey(), &[ix]).unwrap();
+
+        assert!(litesvm.get_account(&contributor_pda).is_none());
+        assert_eq!(
+            fetch_account::<Account>(litesvm, &beneficiary_ata).amount,
+            contribute_amount
+        );
+    }
+}
@@ -0,0 +1,528 @@
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+    sysvars::{Sysvar, clock::Clock},
+};
+use pinocchio_token_2022::instructions::CloseAccount;
+
+use crate::{
+    AccountCheck, AccountLoad, CRANK_REFUND_MAX_ENTRIES, Contributor, Fundraise, FundraiseStatus,
+    FundraiserError, FundraiserInstruction, Handler, Liability, MintInterface, Prefix,
+    ProgramAccount, TokenAccountInterface, TransferHook, WritableAccount, check_account,
+    vault_transfer,
+};
+
+/// Contributor, the token account it is refunded to and the destination of its rent.
+const CRANK_REFUND_ENTRY_LEN: usize = 3;
+
+pub struct CrankRefundAccounts<'a> {
+    pub maker: &'a AccountInfo,
+    pub mint_to_raise: &'a AccountInfo,
+    pub fundraise: &'a AccountInfo,
+    pub vault: &'a AccountInfo,
+    pub token_program: &'a AccountInfo,
+    /// `[contributor, authority_token_account, rent_destination]` for each position to refund.
+    pub entries: &'a [AccountInfo],
+    pub transfer_hook_accounts: &'a [AccountInfo],
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for CrankRefundAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [
+            maker,
+            mint_to_raise,
+            fundraise,
+            vault,
+            token_program,
+            rest @ ..,
+        ] = accounts
+        else {
+            return Err(FundraiserError::MissingAccounts.into());
+        };
+
+        check_account!(0, "writable", WritableAccount::check(maker))?;
+        check_account!(2, "writable", WritableAccount::check(fundraise))?;
+        check_account!(3, "writable", WritableAccount::check(vault))?;
+        check_account!(1, "mint", MintInterface::check(mint_to_raise))?;
+        check_account!(2, "owner", ProgramAccount::check(fundraise))?;
+
+        let fundraise_data = fundraise.try_borrow_data()?;
+        let fundraise_state = Fundraise::load(&fundraise_data)?;
+
+        check_account!(
+            4,
+            "token program",
+            fundraise_state.check_token_program(token_program.key())
+        )?;
+        check_account!(3, "vault", fundraise_state.check_vault(vault.key()))?;
+        drop(fundraise_data);
+
+        check_account!(
+            3,
+            "mint and owner",
+            TokenAccountInterface::check_mint_and_owner(
+                vault,
+                mint_to_raise.key(),
+                fundraise.key()
+            )
+        )?;
+
+        let (transfer_hook_accounts, entries) = TransferHook::split_trailing(mint_to_raise, rest)?;
+
+        if entries.len() % CRANK_REFUND_ENTRY_LEN != 0 {
+            return Err(FundraiserError::InvalidCrankEntries.into());
+        }
+
+        if entries.len() / CRANK_REFUND_ENTRY_LEN > CRANK_REFUND_MAX_ENTRIES {
+            return Err(FundraiserError::TooManyCrankEntries.into());
+        }
+
+        Ok(Self {
+            maker,
+            mint_to_raise,
+            fundraise,
+            vault,
+            token_program,
+            entries,
+            transfer_hook_accounts,
+        })
+    }
+}
+
+/// Refunds a batch of positions of a fundraise that ended below its target, sent by anyone, so
+/// a failed campaign is wound down without waiting on every contributor. Each position goes back
+/// to a token account of its authority and its rent to whoever paid it. Positions already
+/// refunded are skipped, so a crank built before a contributor refunded themselves still lands.
+/// A fundraise that issues receipts is left to Refund, as only holders can burn theirs.
+pub struct CrankRefund<'a> {
+    pub accounts: CrankRefundAccounts<'a>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for CrankRefund<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        Ok(Self {
+            accounts: CrankRefundAccounts::try_from(accounts)?,
+        })
+    }
+}
+
+impl Handler for CrankRefund<'_> {
+    const DISCRIMINATOR: u8 = FundraiserInstruction::CrankRefund as u8;
+
+    fn process(&mut self) -> ProgramResult {
+        let fundraise_data = self.accounts.fundraise.try_borrow_data()?;
+        let fundraise = Fundraise::load(&fundraise_data)?;
+
+        let fundraise_maker = fundraise.maker;
+
+        if fundraise_maker != *self.accounts.maker.key() {
+            return Err(FundraiserError::InvalidAddress.into());
+        }
+
+        let fundraise_bump = [fundraise.bump];
+
+        ProgramAccount::validate(
+            &[Fundraise::PREFIX, &fundraise_maker, &fundraise_bump],
+            *self.accounts.fundraise.key(),
+        )?;
+        fundraise.check_mint_to_raise(self.accounts.mint_to_raise.key())?;
+
+        if fundraise.is_claimed() {
+            return Err(FundraiserError::AlreadyClaimed.into());
+        }
+
+        if fundraise.has_receipt() {
+            return Err(FundraiserError::ReceiptHolderMustRefund.into());
+        }
+
+        let vault_amount = match *self.accounts.vault.owner() {
+            pinocchio_token::ID => {
+                let vault = unsafe {
+                    pinocchio_token::state::TokenAccount::from_account_info_unchecked(
+                        self.accounts.vault,
+                    )?
+                };
+                vault.amount()
+            }
+            pinocchio_token_2022::ID => {
+                let vault = unsafe {
+                    pinocchio_token_2022::state::TokenAccount::from_account_info_unchecked(
+                        self.accounts.vault,
+                    )?
+                };
+                vault.amount()
+            }
+            _ => return Err(FundraiserError::InvalidTokenProgram.into()),
+        };
+
+        match fundraise.status(Clock::get()?.unix_timestamp) {
+            FundraiseStatus::Refundable | FundraiseStatus::Expired => {}
+            FundraiseStatus::Active | FundraiseStatus::TargetMet => {
+                return Err(FundraiserError::FundraiserNotEnded.into());
+            }
+            FundraiseStatus::Claimable => return Err(FundraiserError::TargetMet.into()),
+        }
+
+        drop(fundraise_data);
+
+        let fundraise_seeds = [
+            Seed::from(Fundraise::PREFIX),
+            Seed::from(fundraise_maker.as_ref()),
+            Seed::from(&fundraise_bump),
+        ];
+        let mut refunded: u64 = 0;
+
+        for entry in self.accounts.entries.chunks_exact(CRANK_REFUND_ENTRY_LEN) {
+            let [contributor, authority_token_account, rent_destination] = entry else {
+                return Err(FundraiserError::InvalidCrankEntries.into());
+            };
+
+            refunded +=
+                self.refund_entry(contributor, authority_token_account, rent_destination)?;
+        }
+
+        if vault_amount - refunded == 0 {
+            CloseAccount {
+                account: self.accounts.vault,
+                destination: self.accounts.maker,
+                authority: self.accounts.fundraise,
+                token_program: self.accounts.token_program.key(),
+            }
+            .invoke_signed(&[Signer::from(&fundraise_seeds)])?;
+        }
+
+        Ok(())
+    }
+}
+
+impl CrankRefund<'_> {
+    /// Refunds and closes a single position, returning the amount paid out of the vault.
+    fn refund_entry(
+        &self,
+        contributor: &AccountInfo,
+        authority_token_account: &AccountInfo,
+        rent_destination: &AccountInfo,
+    ) -> Result<u64, ProgramError> {
+        // closed by its own refund or an earlier crank since the crank was built
+        if !contributor.is_owned_by(&crate::ID) || contributor.data_is_empty() {
+            return Ok(0);
+        }
+
+        WritableAccount::check(contributor)?;
+        WritableAccount::check(authority_token_account)?;
+        WritableAccount::check(rent_destination)?;
+
+        let contributor_data = contributor.try_borrow_data()?;
+        let contributor_state = Contributor::load(&contributor_data)?;
+
+        ProgramAccount::validate(
+            &[
+                Contributor::PREFIX,
+                self.accounts.fundraise.key(),
+                &contributor_state.authority,
+                &[contributor_state.bump],
+            ],
+            *contributor.key(),
+        )?;
+        TokenAccountInterface::check_mint_and_owner(
+            authority_token_account,
+            self.accounts.mint_to_raise.key(),
+            &contributor_state.authority,
+        )?;
+
+        if contributor_state.rent_payer != *rent_destination.key() {
+            return Err(FundraiserError::InvalidRentDestination.into());
+        }
+
+        let contributor_amount = contributor_state.get_amount();
+        let pending_hook = contributor_state.get_pending_hook_amount() > 0;
+
+        drop(contributor_data);
+
+        if pending_hook {
+            let mut fundraise_data = self.accounts.fundraise.try_borrow_mut_data()?;
+            let fundraise = Fundraise::load_mut(fundraise_data.as_mut())?;
+
+            fundraise.set_pending_hooks(fundraise.get_pending_hooks() - 1);
+        }
+
+        if contributor_amount > 0 {
+            vault_transfer(
+                Liability::Raised,
+                self.accounts.vault,
+                self.accounts.mint_to_raise,
+                authority_token_account,
+                contributor_amount,
+                self.accounts.fundraise,
+                self.accounts.transfer_hook_accounts,
+            )?;
+        }
+
+        ProgramAccount::close(contributor, rent_destination)?;
+
+        Ok(contributor_amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use spl_associated_token_account::solana_program::{
+        clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL,
+    };
+    use spl_token_2022::state::Account;
+
+    use crate::{
+        AccountLoad, CRANK_REFUND_MAX_ENTRIES, Fundraise, FundraiserError, client,
+        tests::{
+            constants::{MINT_DECIMALS, TOKEN_PROGRAM_ID},
+            utils::{
+                assert_error, build_and_send_transaction, fetch_account, forward_time, init_ata,
+                init_mint, init_wallet, setup,
+            },
+        },
+    };
+
+    const AMOUNT_TO_RAISE: u64 = 10_000_000;
+    const CONTRIBUTION: u64 = 100_000;
+
+    /// A fundraise of a fresh mint with `count` backers that each contributed `CONTRIBUTION`.
+    fn failing_campaign(litesvm: &mut LiteSVM, count: usize) -> (Keypair, Pubkey, Vec<Keypair>) {
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        let ix = client::initialize(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            AMOUNT_TO_RAISE,
+            SECONDS_PER_DAY,
+        );
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let backers: Vec<Keypair> = (0..count)
+            .map(|_| {
+                let backer = init_wallet(litesvm, LAMPORTS_PER_SOL);
+                init_ata(litesvm, mint_to_raise, backer.pubkey(), CONTRIBUTION);
+
+                let ix = client::contribute(
+                    &backer.pubkey(),
+                    &maker.pubkey(),
+                    &mint_to_raise,
+                    &TOKEN_PROGRAM_ID,
+                    CONTRIBUTION,
+                );
+                build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]).unwrap();
+
+                backer
+            })
+            .collect();
+
+        (maker, mint_to_raise, backers)
+    }
+
+    fn positions(backers: &[Keypair]) -> Vec<(Pubkey, Pubkey)> {
+        backers
+            .iter()
+            .map(|backer| (backer.pubkey(), backer.pubkey()))
+            .collect()
+    }
+
+    #[test]
+    fn crank_refund_in_two_calls() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, mint_to_raise, backers) = failing_campaign(litesvm, 5);
+        let cranker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let fundraise = client::fundraise_address(&maker.pubkey());
+        let contributor_rent = litesvm
+            .get_account(&client::contributor_address(
+                &fundraise,
+                &backers[0].pubkey(),
+            ))
+            .unwrap()
+            .lamports;
+        let balances: Vec<u64> = backers
+            .iter()
+            .map(|backer| litesvm.get_balance(&backer.pubkey()).unwrap())
+            .collect();
+
+        forward_time(litesvm, SECONDS_PER_DAY as i64 + 1);
+
+        let positions = positions(&backers);
+        let ix = client::crank_refund(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            &positions[..3],
+        );
+        build_and_send_transaction(litesvm, &[&cranker], &cranker.pubkey(), &[ix]).unwrap();
+
+        let account = litesvm.get_account(&fundraise).unwrap();
+
+        assert_eq!(
+            Fundraise::load(&account.data).unwrap().get_current_amount(),
+            2 * CONTRIBUTION
+        );
+
+        // the third backer was already refunded by the first call and is skipped
+        let ix = client::crank_refund(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            &positions[2..],
+        );
+        build_and_send_transaction(litesvm, &[&cranker], &cranker.pubkey(), &[ix]).unwrap();
+
+        for (backer, balance) in backers.iter().zip(balances) {
+            let authority_ata = client::associated_token_address(
+                &backer.pubkey(),
+                &mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+            );
+
+            assert!(
+                litesvm
+                    .get_account(&client::contributor_address(&fundraise, &backer.pubkey()))
+                    .is_none()
+            );
+            assert_eq!(
+                fetch_account::<Account>(litesvm, &authority_ata).amount,
+                CONTRIBUTION
+            );
+            assert_eq!(
+                litesvm.get_balance(&backer.pubkey()).unwrap(),
+                balance + contributor_rent
+            );
+        }
+
+        let account = litesvm.get_account(&fundraise).unwrap();
+
+        assert_eq!(
+            Fundraise::load(&account.data).unwrap().get_current_amount(),
+            0
+        );
+        // the emptied vault is closed back to the maker
+        assert!(
+            litesvm
+                .get_account(&client::vault_address(&fundraise))
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn crank_refund_sixty_positions_in_chunks() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, mint_to_raise, backers) = failing_campaign(litesvm, 60);
+        let cranker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let fundraise = client::fundraise_address(&maker.pubkey());
+
+        forward_time(litesvm, SECONDS_PER_DAY as i64 + 1);
+
+        // each transaction carries as many full crank calls as the packer fits
+        let positions = positions(&backers);
+        let transactions = client::chunk(&positions, |positions| {
+            positions
+                .chunks(CRANK_REFUND_MAX_ENTRIES)
+                .map(|entries| {
+                    client::crank_refund(
+                        &maker.pubkey(),
+                        &mint_to_raise,
+                        &TOKEN_PROGRAM_ID,
+                        entries,
+                    )
+                })
+                .collect()
+        })
+        .unwrap();
+
+        assert!(transactions.len() > 1);
+
+        for ixs in &transactions {
+            build_and_send_transaction(litesvm, &[&cranker], &cranker.pubkey(), ixs).unwrap();
+            litesvm.expire_blockhash();
+        }
+
+        for backer in &backers {
+            let authority_ata = client::associated_token_address(
+                &backer.pubkey(),
+                &mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+            );
+
+            assert_eq!(
+                fetch_account::<Account>(litesvm, &authority_ata).amount,
+                CONTRIBUTION
+            );
+        }
+
+        let account = litesvm.get_account(&fundraise).unwrap();
+
+        assert_eq!(
+            Fundraise::load(&account.data).unwrap().get_current_amount(),
+            0
+        );
+    }
+
+    #[test]
+    fn throw_if_fundraise_not_ended() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, mint_to_raise, backers) = failing_campaign(litesvm, 1);
+
+        let ix = client::crank_refund(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            &positions(&backers),
+        );
+        let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::FundraiserNotEnded);
+    }
+
+    #[test]
+    fn throw_if_rent_destination_not_rent_payer() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, mint_to_raise, backers) = failing_campaign(litesvm, 1);
+        let cranker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+
+        forward_time(litesvm, SECONDS_PER_DAY as i64 + 1);
+
+        let ix = client::crank_refund(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            &[(backers[0].pubkey(), cranker.pubkey())],
+        );
+        let res = build_and_send_transaction(litesvm, &[&cranker], &cranker.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::InvalidRentDestination);
+    }
+
+    #[test]
+    fn throw_if_too_many_entries() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, mint_to_raise, backers) = failing_campaign(litesvm, 1);
+
+        forward_time(litesvm, SECONDS_PER_DAY as i64 + 1);
+
+        let positions = positions(&backers).repeat(CRANK_REFUND_MAX_ENTRIES + 1);
+        let ix = client::crank_refund(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            &positions,
+        );
+        let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::TooManyCrankEntries);
+    }
+}
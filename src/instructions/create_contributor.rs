@@ -0,0 +1,273 @@
+use pinocchio::{
+    ProgramResult, account_info::AccountInfo, instruction::Seed, program_error::ProgramError,
+    pubkey::find_program_address,
+};
+
+use crate::{
+    AccountCheck, AccountLoad, Contributor, ContributorParams, Fundraise, FundraiserError,
+    FundraiserInstruction, Handler, Prefix, ProgramAccount, ProgramIdCheck, SignerAccount,
+    WritableAccount, check_account,
+};
+
+pub struct CreateContributorAccounts<'a> {
+    pub authority: &'a AccountInfo,
+    pub fundraise: &'a AccountInfo,
+    pub contributor: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for CreateContributorAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [authority, fundraise, contributor, system_program] = accounts else {
+            return Err(FundraiserError::MissingAccounts.into());
+        };
+
+        check_account!(0, "signer", SignerAccount::check(authority))?;
+        check_account!(0, "writable", WritableAccount::check(authority))?;
+        check_account!(2, "writable", WritableAccount::check(contributor))?;
+        check_account!(
+            3,
+            "program id",
+            ProgramIdCheck::check(system_program, &pinocchio_system::ID)
+        )?;
+        check_account!(1, "owner", ProgramAccount::check(fundraise))?;
+
+        Ok(Self {
+            authority,
+            fundraise,
+            contributor,
+            system_program,
+        })
+    }
+}
+
+/// Creates `authority`'s contributor account for a fundraise ahead of its first contribution,
+/// so that Contribute can be sent with `require_existing` and skip the bump search and the
+/// CreateAccount CPI. Leaves an existing contributor account untouched.
+pub struct CreateContributor<'a> {
+    pub accounts: CreateContributorAccounts<'a>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for CreateContributor<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let accounts = CreateContributorAccounts::try_from(accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl Handler for CreateContributor<'_> {
+    const DISCRIMINATOR: u8 = FundraiserInstruction::CreateContributor as u8;
+
+    fn process(&mut self) -> ProgramResult {
+        let fundraise_data = self.accounts.fundraise.try_borrow_data()?;
+        let fundraise = Fundraise::load(&fundraise_data)?;
+
+        ProgramAccount::validate(
+            &[Fundraise::PREFIX, &fundraise.maker, &[fundraise.bump]],
+            *self.accounts.fundraise.key(),
+        )?;
+
+        let mint = fundraise.mint_to_raise;
+
+        drop(fundraise_data);
+
+        let (pda, bump) = find_program_address(
+            &[
+                Contributor::PREFIX,
+                self.accounts.fundraise.key(),
+                self.accounts.authority.key(),
+            ],
+            &crate::ID,
+        );
+
+        if pda != *self.accounts.contributor.key() {
+            return Err(FundraiserError::InvalidAddress.into());
+        }
+
+        let bump_binding = [bump];
+        let contributor_seeds = [
+            Seed::from(Contributor::PREFIX),
+            Seed::from(self.accounts.fundraise.key().as_ref()),
+            Seed::from(self.accounts.authority.key().as_ref()),
+            Seed::from(&bump_binding),
+        ];
+        let params = ContributorParams::new(
+            *self.accounts.fundraise.key(),
+            *self.accounts.authority.key(),
+            bump,
+            *self.accounts.authority.key(),
+            mint,
+        );
+
+        ProgramAccount::init_if_needed::<Contributor>(
+            &contributor_seeds,
+            self.accounts.contributor,
+            self.accounts.authority,
+            params,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use litesvm::LiteSVM;
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_keypair::Keypair;
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use spl_associated_token_account::solana_program::{
+        clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL,
+    };
+
+    use crate::{
+        AccountLoad, Contributor,
+        tests::{
+            constants::{
+                ASSOCIATED_TOKEN_PROGRAM_ID, MINT_DECIMALS, PROGRAM_ID, SYSTEM_PROGRAM_ID,
+                TOKEN_PROGRAM_ID,
+            },
+            pda::{get_config_pda, get_contributor_pda, get_fundraise_pda, get_vault_pda},
+            utils::{build_and_send_transaction, init_ata, init_mint, init_wallet, setup},
+        },
+    };
+
+    fn initialize(litesvm: &mut LiteSVM, maker: &Keypair, mint_to_raise: Pubkey) -> Pubkey {
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+
+        let data = [
+            vec![0u8],
+            5_000_000u64.to_le_bytes().to_vec(),
+            SECONDS_PER_DAY.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(get_vault_pda(&fundraise_pda), false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        build_and_send_transaction(litesvm, &[maker], &maker.pubkey(), &[ix]).unwrap();
+
+        fundraise_pda
+    }
+
+    fn create_contributor_ix(authority: &Pubkey, fundraise_pda: Pubkey) -> Instruction {
+        Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(*authority, true),
+                AccountMeta::new_readonly(fundraise_pda, false),
+                AccountMeta::new(get_contributor_pda(&fundraise_pda, authority), false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+            data: vec![22u8],
+        }
+    }
+
+    fn contribute_existing_ix(
+        authority: &Pubkey,
+        authority_ata: Pubkey,
+        mint_to_raise: Pubkey,
+        fundraise_pda: Pubkey,
+        amount: u64,
+    ) -> Instruction {
+        // require_existing is the second trailing flag
+        let data = [vec![1u8], amount.to_le_bytes().to_vec(), vec![0u8, 1u8]].concat();
+
+        Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(*authority, true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(get_contributor_pda(&fundraise_pda, authority), false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(get_vault_pda(&fundraise_pda), false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        }
+    }
+
+    #[test]
+    fn create_contributor() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        let fundraise_pda = initialize(litesvm, &maker, mint_to_raise);
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let ix = create_contributor_ix(&authority.pubkey(), fundraise_pda);
+
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+
+        let contributor_acc = litesvm.get_account(&contributor_pda).unwrap();
+        let contributor = Contributor::load(contributor_acc.data.as_ref()).unwrap();
+
+        assert_eq!(contributor_acc.owner, PROGRAM_ID);
+        assert_eq!(contributor.fundraise, fundraise_pda.to_bytes());
+        assert_eq!(contributor.authority, authority.pubkey().to_bytes());
+        assert_eq!(contributor.get_amount(), 0);
+    }
+
+    #[test]
+    fn contribute_to_created_contributor() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let fundraise_pda = initialize(litesvm, &maker, mint_to_raise);
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+        let contribute_amount: u64 = 500_000;
+
+        let ix = create_contributor_ix(&authority.pubkey(), fundraise_pda);
+
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+
+        let ix = contribute_existing_ix(
+            &authority.pubkey(),
+            authority_ata,
+            mint_to_raise,
+            fundraise_pda,
+            contribute_amount,
+        );
+
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+
+        // creating again leaves the contributor's record alone
+        litesvm.expire_blockhash();
+
+        let ix = create_contributor_ix(&authority.pubkey(), fundraise_pda);
+
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+
+        let contributor_acc = litesvm.get_account(&contributor_pda).unwrap();
+        let contributor = Contributor::load(contributor_acc.data.as_ref()).unwrap();
+
+        assert_eq!(contributor.get_amount(), contribute_amount);
+    }
+}
@@ -0,0 +1,360 @@
+use core::mem::size_of;
+
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{Sysvar, clock::Clock},
+};
+
+use crate::{
+    AccountCheck, AccountLoad, AssociatedTokenAccount, Fundraise, FundraiserError,
+    FundraiserInstruction, Handler, MintInterface, Prefix, ProgramAccount, ProgramIdCheck,
+    SignerAccount, TransferChecked, TransferHook, WritableAccount, check_account,
+};
+
+pub struct DepositMatchAccounts<'a> {
+    pub sponsor: &'a AccountInfo,
+    pub mint_to_raise: &'a AccountInfo,
+    pub fundraise: &'a AccountInfo,
+    pub sponsor_token_account: &'a AccountInfo,
+    /// The fundraise's associated token account of the mint to raise, created if it does not
+    /// exist yet.
+    pub match_vault: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+    pub token_program: &'a AccountInfo,
+    pub associated_token_program: &'a AccountInfo,
+    pub transfer_hook_accounts: &'a [AccountInfo],
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for DepositMatchAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [
+            sponsor,
+            mint_to_raise,
+            fundraise,
+            sponsor_token_account,
+            match_vault,
+            system_program,
+            token_program,
+            associated_token_program,
+            rest @ ..,
+        ] = accounts
+        else {
+            return Err(FundraiserError::MissingAccounts.into());
+        };
+
+        check_account!(0, "signer", SignerAccount::check(sponsor))?;
+        check_account!(0, "writable", WritableAccount::check(sponsor))?;
+        check_account!(2, "writable", WritableAccount::check(fundraise))?;
+        check_account!(3, "writable", WritableAccount::check(sponsor_token_account))?;
+        check_account!(4, "writable", WritableAccount::check(match_vault))?;
+        check_account!(1, "mint", MintInterface::check(mint_to_raise))?;
+        check_account!(
+            5,
+            "program id",
+            ProgramIdCheck::check(system_program, &pinocchio_system::ID)
+        )?;
+        check_account!(
+            6,
+            "program id",
+            ProgramIdCheck::check(token_program, mint_to_raise.owner())
+        )?;
+        check_account!(
+            7,
+            "program id",
+            ProgramIdCheck::check(
+                associated_token_program,
+                &pinocchio_associated_token_account::ID,
+            )
+        )?;
+        check_account!(2, "owner", ProgramAccount::check(fundraise))?;
+        check_account!(
+            4,
+            "address",
+            AssociatedTokenAccount::check_address(
+                match_vault,
+                fundraise,
+                mint_to_raise,
+                token_program,
+            )
+        )?;
+
+        let (transfer_hook_accounts, _) = TransferHook::split_trailing(mint_to_raise, rest)?;
+
+        Ok(Self {
+            sponsor,
+            mint_to_raise,
+            fundraise,
+            sponsor_token_account,
+            match_vault,
+            system_program,
+            token_program,
+            associated_token_program,
+            transfer_hook_accounts,
+        })
+    }
+}
+
+pub struct DepositMatchInstructionData {
+    pub amount: u64,
+}
+
+impl DepositMatchInstructionData {
+    pub const LEN: usize = size_of::<u64>();
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        self.amount.to_le_bytes()
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for DepositMatchInstructionData {
+    type Error = ProgramError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        let amount = u64::from_le_bytes(
+            data.try_into()
+                .map_err(|_| ProgramError::InvalidInstructionData)?,
+        );
+
+        Ok(Self { amount })
+    }
+}
+
+/// Adds `amount` of the mint to raise to the matching pool of a running fundraise. The first
+/// deposit makes its signer the fundraise's sponsor, and only they may top the pool up. At the
+/// maker's claim the pool matches contributions one for one, with whatever it does not match
+/// back to the sponsor, and ReclaimMatch returns all of it should the campaign fail.
+pub struct DepositMatch<'a> {
+    pub accounts: DepositMatchAccounts<'a>,
+    pub data: DepositMatchInstructionData,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for DepositMatch<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = DepositMatchAccounts::try_from(accounts)?;
+        let data = DepositMatchInstructionData::try_from(data)?;
+
+        Ok(Self { accounts, data })
+    }
+}
+
+impl Handler for DepositMatch<'_> {
+    const DISCRIMINATOR: u8 = FundraiserInstruction::DepositMatch as u8;
+
+    fn process(&mut self) -> ProgramResult {
+        if self.data.amount == 0 {
+            return Err(FundraiserError::InvalidAmount.into());
+        }
+
+        let mut fundraise_data = self.accounts.fundraise.try_borrow_mut_data()?;
+        let fundraise = Fundraise::load_mut(fundraise_data.as_mut())?;
+
+        ProgramAccount::validate(
+            &[Fundraise::PREFIX, &fundraise.maker, &[fundraise.bump]],
+            *self.accounts.fundraise.key(),
+        )?;
+        fundraise.check_mint_to_raise(self.accounts.mint_to_raise.key())?;
+
+        if fundraise.is_claimed() {
+            return Err(FundraiserError::AlreadyClaimed.into());
+        }
+
+        if fundraise.is_cancelled() {
+            return Err(FundraiserError::FundraiseCancelled.into());
+        }
+
+        if Clock::get()?.unix_timestamp > fundraise.get_end_time() {
+            return Err(FundraiserError::FundraiserEnded.into());
+        }
+
+        if fundraise.has_match() && fundraise.match_sponsor != *self.accounts.sponsor.key() {
+            return Err(FundraiserError::InvalidAddress.into());
+        }
+
+        let match_pool = fundraise
+            .get_match_pool()
+            .checked_add(self.data.amount)
+            .ok_or(FundraiserError::MathOverflow)?;
+
+        fundraise.match_sponsor = *self.accounts.sponsor.key();
+        fundraise.set_match_pool(match_pool);
+
+        let decimals = fundraise.mint_decimals;
+
+        drop(fundraise_data);
+
+        AssociatedTokenAccount::init_if_needed(
+            self.accounts.match_vault,
+            self.accounts.mint_to_raise,
+            self.accounts.sponsor,
+            self.accounts.fundraise,
+            self.accounts.system_program,
+            self.accounts.token_program,
+        )?;
+
+        TransferChecked {
+            from: self.accounts.sponsor_token_account,
+            mint: self.accounts.mint_to_raise,
+            to: self.accounts.match_vault,
+            authority: self.accounts.sponsor,
+            multisig_signers: &[],
+            amount: self.data.amount,
+            decimals,
+            hook_accounts: self.accounts.transfer_hook_accounts,
+            token_program: self.accounts.token_program.key(),
+        }
+        .invoke()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use spl_associated_token_account::solana_program::{
+        clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL,
+    };
+    use spl_token_2022::state::Account;
+
+    use crate::{
+        Fundraise, FundraiserError, client,
+        tests::{
+            constants::{MINT_DECIMALS, TOKEN_PROGRAM_ID},
+            utils::{
+                assert_error, build_and_send_transaction, fetch_account, forward_time, init_ata,
+                init_mint, init_wallet, setup,
+            },
+        },
+    };
+
+    const POOL: u64 = 3_000_000;
+
+    fn initialize(litesvm: &mut LiteSVM) -> (Keypair, Pubkey) {
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        let ix = client::initialize(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            5_000_000,
+            SECONDS_PER_DAY,
+        );
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        (maker, mint_to_raise)
+    }
+
+    fn sponsor(litesvm: &mut LiteSVM, mint_to_raise: Pubkey) -> Keypair {
+        let sponsor = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        init_ata(litesvm, mint_to_raise, sponsor.pubkey(), POOL);
+
+        sponsor
+    }
+
+    #[test]
+    fn deposit_funds_match_vault() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, mint_to_raise) = initialize(litesvm);
+        let sponsor = sponsor(litesvm, mint_to_raise);
+
+        for amount in [POOL / 3, POOL / 3 * 2] {
+            let ix = client::deposit_match(
+                &sponsor.pubkey(),
+                &maker.pubkey(),
+                &mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+                amount,
+            );
+            build_and_send_transaction(litesvm, &[&sponsor], &sponsor.pubkey(), &[ix]).unwrap();
+        }
+
+        let fundraise = client::fundraise_address(&maker.pubkey());
+        let match_vault =
+            client::associated_token_address(&fundraise, &mint_to_raise, &TOKEN_PROGRAM_ID);
+
+        assert_eq!(fetch_account::<Account>(litesvm, &match_vault).amount, POOL);
+
+        let fundraise = Fundraise::unpack(&litesvm.get_account(&fundraise).unwrap().data).unwrap();
+
+        assert_eq!(fundraise.match_sponsor, sponsor.pubkey().to_bytes());
+        assert_eq!(fundraise.match_pool, POOL);
+        // the pool is held apart from the contributions the target counts
+        assert_eq!(fundraise.current_amount, 0);
+    }
+
+    #[test]
+    fn throw_if_sponsor_mismatch() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, mint_to_raise) = initialize(litesvm);
+        let sponsor = sponsor(litesvm, mint_to_raise);
+        let other = self::sponsor(litesvm, mint_to_raise);
+
+        let ix = client::deposit_match(
+            &sponsor.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            POOL,
+        );
+        build_and_send_transaction(litesvm, &[&sponsor], &sponsor.pubkey(), &[ix]).unwrap();
+
+        let ix = client::deposit_match(
+            &other.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            POOL,
+        );
+        let res = build_and_send_transaction(litesvm, &[&other], &other.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::InvalidAddress);
+    }
+
+    #[test]
+    fn throw_if_fundraiser_ended() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, mint_to_raise) = initialize(litesvm);
+        let sponsor = sponsor(litesvm, mint_to_raise);
+
+        forward_time(litesvm, SECONDS_PER_DAY as i64 + 1);
+
+        let ix = client::deposit_match(
+            &sponsor.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            POOL,
+        );
+        let res = build_and_send_transaction(litesvm, &[&sponsor], &sponsor.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::FundraiserEnded);
+    }
+
+    #[test]
+    fn throw_if_amount_zero() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, mint_to_raise) = initialize(litesvm);
+        let sponsor = sponsor(litesvm, mint_to_raise);
+
+        let ix = client::deposit_match(
+            &sponsor.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            0,
+        );
+        let res = build_and_send_transaction(litesvm, &[&sponsor], &sponsor.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::InvalidAmount);
+    }
+}
@@ -0,0 +1,305 @@
+use core::mem::size_of;
+
+use pinocchio::{ProgramResult, account_info::AccountInfo, program_error::ProgramError};
+
+use crate::{
+    AccountCheck, AccountLoad, AssociatedTokenAccount, Fundraise, FundraiserError,
+    FundraiserInstruction, Handler, MintInterface, Prefix, ProgramAccount, ProgramIdCheck,
+    SignerAccount, TransferChecked, WritableAccount, check_account,
+};
+
+pub struct DepositRewardsAccounts<'a> {
+    pub maker: &'a AccountInfo,
+    pub fundraise: &'a AccountInfo,
+    pub reward_mint: &'a AccountInfo,
+    pub maker_token_account: &'a AccountInfo,
+    /// The fundraise's associated token account of the reward mint, created if it does not
+    /// exist yet.
+    pub reward_vault: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+    pub token_program: &'a AccountInfo,
+    pub associated_token_program: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for DepositRewardsAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [
+            maker,
+            fundraise,
+            reward_mint,
+            maker_token_account,
+            reward_vault,
+            system_program,
+            token_program,
+            associated_token_program,
+        ] = accounts
+        else {
+            return Err(FundraiserError::MissingAccounts.into());
+        };
+
+        check_account!(0, "signer", SignerAccount::check(maker))?;
+        check_account!(0, "writable", WritableAccount::check(maker))?;
+        check_account!(3, "writable", WritableAccount::check(maker_token_account))?;
+        check_account!(4, "writable", WritableAccount::check(reward_vault))?;
+        check_account!(2, "mint", MintInterface::check(reward_mint))?;
+        check_account!(
+            5,
+            "program id",
+            ProgramIdCheck::check(system_program, &pinocchio_system::ID)
+        )?;
+        check_account!(
+            6,
+            "program id",
+            ProgramIdCheck::check(token_program, reward_mint.owner())
+        )?;
+        check_account!(
+            7,
+            "program id",
+            ProgramIdCheck::check(
+                associated_token_program,
+                &pinocchio_associated_token_account::ID,
+            )
+        )?;
+        check_account!(1, "owner", ProgramAccount::check(fundraise))?;
+        check_account!(
+            4,
+            "address",
+            AssociatedTokenAccount::check_address(
+                reward_vault,
+                fundraise,
+                reward_mint,
+                token_program
+            )
+        )?;
+
+        Ok(Self {
+            maker,
+            fundraise,
+            reward_mint,
+            maker_token_account,
+            reward_vault,
+            system_program,
+            token_program,
+            associated_token_program,
+        })
+    }
+}
+
+pub struct DepositRewardsInstructionData {
+    pub amount: u64,
+}
+
+impl DepositRewardsInstructionData {
+    pub const LEN: usize = size_of::<u64>();
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        self.amount.to_le_bytes()
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for DepositRewardsInstructionData {
+    type Error = ProgramError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        let amount = u64::from_le_bytes(
+            data.try_into()
+                .map_err(|_| ProgramError::InvalidInstructionData)?,
+        );
+
+        Ok(Self { amount })
+    }
+}
+
+/// Moves `amount` of the reward mint from the maker into the fundraise's reward vault, which
+/// contributors claim their shares from once the maker claims. The maker may deposit in as many
+/// parts as they like, and sweeps back whatever is left once every share is claimed.
+pub struct DepositRewards<'a> {
+    pub accounts: DepositRewardsAccounts<'a>,
+    pub data: DepositRewardsInstructionData,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for DepositRewards<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = DepositRewardsAccounts::try_from(accounts)?;
+        let data = DepositRewardsInstructionData::try_from(data)?;
+
+        Ok(Self { accounts, data })
+    }
+}
+
+impl Handler for DepositRewards<'_> {
+    const DISCRIMINATOR: u8 = FundraiserInstruction::DepositRewards as u8;
+
+    fn process(&mut self) -> ProgramResult {
+        if self.data.amount == 0 {
+            return Err(FundraiserError::InvalidAmount.into());
+        }
+
+        let fundraise_data = self.accounts.fundraise.try_borrow_data()?;
+        let fundraise = Fundraise::load(&fundraise_data)?;
+
+        if fundraise.maker != *self.accounts.maker.key() {
+            return Err(FundraiserError::InvalidAddress.into());
+        }
+
+        ProgramAccount::validate(
+            &[Fundraise::PREFIX, &fundraise.maker, &[fundraise.bump]],
+            *self.accounts.fundraise.key(),
+        )?;
+
+        if !fundraise.has_rewards() {
+            return Err(FundraiserError::NoRewards.into());
+        }
+
+        if fundraise.reward_mint != *self.accounts.reward_mint.key() {
+            return Err(FundraiserError::InvalidAddress.into());
+        }
+
+        if fundraise.is_cancelled() {
+            return Err(FundraiserError::FundraiseCancelled.into());
+        }
+
+        drop(fundraise_data);
+
+        AssociatedTokenAccount::init_if_needed(
+            self.accounts.reward_vault,
+            self.accounts.reward_mint,
+            self.accounts.maker,
+            self.accounts.fundraise,
+            self.accounts.system_program,
+            self.accounts.token_program,
+        )?;
+
+        TransferChecked {
+            from: self.accounts.maker_token_account,
+            mint: self.accounts.reward_mint,
+            to: self.accounts.reward_vault,
+            authority: self.accounts.maker,
+            multisig_signers: &[],
+            amount: self.data.amount,
+            decimals: MintInterface::decimals(self.accounts.reward_mint)?,
+            hook_accounts: &[],
+            token_program: self.accounts.token_program.key(),
+        }
+        .invoke()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use spl_associated_token_account::solana_program::{
+        clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL,
+    };
+    use spl_token_2022::state::Account;
+
+    use crate::{
+        FundraiserError, client,
+        tests::{
+            constants::{FEE_COLLECTOR, MINT_DECIMALS, TOKEN_PROGRAM_ID},
+            utils::{
+                assert_error, build_and_send_transaction, fetch_account, init_ata, init_mint,
+                init_wallet, setup,
+            },
+        },
+    };
+
+    const REWARDS: u64 = 1_000_000_000;
+
+    fn initialize(litesvm: &mut LiteSVM, rewards: u64) -> (Keypair, Pubkey) {
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let reward_mint = init_mint(litesvm, TOKEN_PROGRAM_ID, 9, REWARDS);
+        init_ata(litesvm, reward_mint, maker.pubkey(), REWARDS);
+
+        let ix = client::initialize(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            5_000_000,
+            SECONDS_PER_DAY,
+        );
+        let ix = match rewards {
+            0 => ix,
+            rewards => client::with_rewards(ix, &reward_mint, rewards),
+        };
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        (maker, reward_mint)
+    }
+
+    #[test]
+    fn deposit_funds_reward_vault() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, reward_mint) = initialize(litesvm, REWARDS);
+
+        for amount in [REWARDS / 4, REWARDS / 4 * 3] {
+            let ix =
+                client::deposit_rewards(&maker.pubkey(), &reward_mint, &TOKEN_PROGRAM_ID, amount);
+            build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+        }
+
+        let reward_vault = client::associated_token_address(
+            &client::fundraise_address(&maker.pubkey()),
+            &reward_mint,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        assert_eq!(
+            fetch_account::<Account>(litesvm, &reward_vault).amount,
+            REWARDS
+        );
+    }
+
+    #[test]
+    fn throw_if_no_rewards() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, reward_mint) = initialize(litesvm, 0);
+
+        let ix = client::deposit_rewards(&maker.pubkey(), &reward_mint, &TOKEN_PROGRAM_ID, REWARDS);
+        let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::NoRewards);
+    }
+
+    #[test]
+    fn throw_if_fundraise_cancelled() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, reward_mint) = initialize(litesvm, REWARDS);
+
+        let ix = client::cancel(&maker.pubkey(), &FEE_COLLECTOR);
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let ix = client::deposit_rewards(&maker.pubkey(), &reward_mint, &TOKEN_PROGRAM_ID, REWARDS);
+        let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::FundraiseCancelled);
+    }
+
+    #[test]
+    fn throw_if_signer_not_maker() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, reward_mint) = initialize(litesvm, REWARDS);
+        let other = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        init_ata(litesvm, reward_mint, other.pubkey(), REWARDS);
+
+        let mut ix =
+            client::deposit_rewards(&other.pubkey(), &reward_mint, &TOKEN_PROGRAM_ID, REWARDS);
+        let fundraise = client::fundraise_address(&maker.pubkey());
+        ix.accounts[1].pubkey = fundraise;
+        ix.accounts[4].pubkey =
+            client::associated_token_address(&fundraise, &reward_mint, &TOKEN_PROGRAM_ID);
+        let res = build_and_send_transaction(litesvm, &[&other], &other.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::InvalidAddress);
+    }
+}
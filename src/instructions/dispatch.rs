@@ -0,0 +1,1064 @@
+use pinocchio::program_error::ProgramError;
+
+/// Every instruction the program accepts. The discriminant is the instruction's one-byte code.
+///
+/// An instruction can be addressed three ways, all reaching the same handler with the same
+/// payload:
+///
+/// - its code, from 10 upward;
+/// - for the original instructions, the legacy code 0–3, which deployed clients keep using and
+///   which will not be removed;
+/// - the Anchor-style sighash, the first 8 bytes of `sha256("global:<snake_case_name>")`.
+///
+/// Initialize and Contribute also have a versioned code, 128 above their code, whose payload
+/// leads with a layout version byte and is read through [`Versioned`].
+///
+/// Sighashes are matched before one-byte codes, since the `complete` and `refund` sighashes
+/// start with the legacy codes for Initialize and Refund. A legacy Initialize whose payload
+/// happens to continue the `complete` sighash is therefore read as Complete and rejected, as is
+/// a legacy partial Refund whose amount continues the `refund` sighash.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FundraiserInstruction {
+    Initialize = 10,
+    Contribute = 11,
+    Refund = 12,
+    Claim = 13,
+    RunHook = 14,
+    Complete = 15,
+    InitializeConfig = 16,
+    UpdateConfig = 17,
+    InitializeAllowlistPage = 18,
+    ClaimAllowlistSlot = 19,
+    Finalize = 20,
+    Donate = 21,
+    CreateContributor = 22,
+    ContributeFor = 23,
+    TransferContribution = 24,
+    ForceRefund = 25,
+    Extend = 26,
+    ContributeDelegated = 27,
+    CrankRefund = 28,
+    CloseFundraise = 29,
+    UpdateMetadata = 30,
+    SetPaused = 31,
+    Cancel = 32,
+    DepositRewards = 33,
+    ClaimReward = 34,
+    SweepRewards = 35,
+    DepositMatch = 36,
+    ReclaimMatch = 37,
+    PostUpdate = 38,
+    Snapshot = 39,
+    SetClaimAuthority = 40,
+    SweepForeign = 41,
+}
+
+/// How an instruction was addressed, as reported by [`FundraiserInstruction::parse`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Encoding {
+    Legacy,
+    Code,
+    Sighash,
+    Versioned,
+}
+
+pub const SIGHASH_LEN: usize = 8;
+
+/// Added to an instruction's code to address its versioned layouts.
+pub const VERSIONED_CODE_OFFSET: u8 = 128;
+
+/// The fields of a version 1 payload past its presence flags, each optional one read only when
+/// flagged.
+pub(crate) struct PresentFields<'a> {
+    data: &'a [u8],
+    flags: u16,
+}
+
+impl<'a> PresentFields<'a> {
+    /// Splits off the presence flags, rejecting any outside `known`: a field this build does not
+    /// know of would leave the rest of the payload misread.
+    #[inline(always)]
+    pub fn new(data: &'a [u8], known: u16) -> Result<Self, ProgramError> {
+        let (flags, data) = data
+            .split_first_chunk()
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        let flags = u16::from_le_bytes(*flags);
+
+        if flags & !known != 0 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        Ok(Self { data, flags })
+    }
+
+    #[inline(always)]
+    pub fn flag(&self, flag: u16) -> bool {
+        self.flags & flag != 0
+    }
+
+    /// The next `N`-byte field, which is always present. A field cut short is rejected.
+    #[inline(always)]
+    pub fn required<const N: usize>(&mut self) -> Result<&'a [u8; N], ProgramError> {
+        let (field, rest) = self
+            .data
+            .split_first_chunk::<N>()
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        self.data = rest;
+
+        Ok(field)
+    }
+
+    /// The next `N`-byte field if `flag` is set. A field cut short is rejected.
+    #[inline(always)]
+    pub fn next<const N: usize>(&mut self, flag: u16) -> Result<Option<&'a [u8; N]>, ProgramError> {
+        match self.flag(flag) {
+            true => self.required().map(Some),
+            false => Ok(None),
+        }
+    }
+
+    /// What follows the fields read so far.
+    #[inline(always)]
+    pub fn rest(&self) -> &'a [u8] {
+        self.data
+    }
+}
+
+/// Instruction data sent under a versioned code: a layout version byte, then the payload in
+/// that layout.
+///
+/// Version 0 is the unversioned payload, its optional fields told apart by total length.
+/// Version 1 leads with flags for which optional fields are present, so a field can be left out
+/// without leaving out every field after it, and one added later without changing the length
+/// of any payload sent today.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Versioned<'a>(pub &'a [u8]);
+
+impl<'a> Versioned<'a> {
+    pub const V0: u8 = 0;
+    pub const V1: u8 = 1;
+
+    /// Splits off the version byte, rejecting a payload without one.
+    #[inline(always)]
+    pub fn split(self) -> Result<(u8, &'a [u8]), ProgramError> {
+        self.0
+            .split_first()
+            .map(|(version, payload)| (*version, payload))
+            .ok_or(ProgramError::InvalidInstructionData)
+    }
+}
+
+impl FundraiserInstruction {
+    pub const ALL: [Self; 32] = [
+        Self::Initialize,
+        Self::Contribute,
+        Self::Refund,
+        Self::Claim,
+        Self::RunHook,
+        Self::Complete,
+        Self::InitializeConfig,
+        Self::UpdateConfig,
+        Self::InitializeAllowlistPage,
+        Self::ClaimAllowlistSlot,
+        Self::Finalize,
+        Self::Donate,
+        Self::CreateContributor,
+        Self::ContributeFor,
+        Self::TransferContribution,
+        Self::ForceRefund,
+        Self::Extend,
+        Self::ContributeDelegated,
+        Self::CrankRefund,
+        Self::CloseFundraise,
+        Self::UpdateMetadata,
+        Self::SetPaused,
+        Self::Cancel,
+        Self::DepositRewards,
+        Self::ClaimReward,
+        Self::SweepRewards,
+        Self::DepositMatch,
+        Self::ReclaimMatch,
+        Self::PostUpdate,
+        Self::Snapshot,
+        Self::SetClaimAuthority,
+        Self::SweepForeign,
+    ];
+
+    #[inline(always)]
+    pub const fn code(self) -> u8 {
+        self as u8
+    }
+
+    /// The variant's name, as logged at the entrypoint.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Initialize => "Initialize",
+            Self::Contribute => "Contribute",
+            Self::Refund => "Refund",
+            Self::Claim => "Claim",
+            Self::RunHook => "RunHook",
+            Self::Complete => "Complete",
+            Self::InitializeConfig => "InitializeConfig",
+            Self::UpdateConfig => "UpdateConfig",
+            Self::InitializeAllowlistPage => "InitializeAllowlistPage",
+            Self::ClaimAllowlistSlot => "ClaimAllowlistSlot",
+            Self::Finalize => "Finalize",
+            Self::Donate => "Donate",
+            Self::CreateContributor => "CreateContributor",
+            Self::ContributeFor => "ContributeFor",
+            Self::TransferContribution => "TransferContribution",
+            Self::ForceRefund => "ForceRefund",
+            Self::Extend => "Extend",
+            Self::ContributeDelegated => "ContributeDelegated",
+            Self::CrankRefund => "CrankRefund",
+            Self::CloseFundraise => "CloseFundraise",
+            Self::UpdateMetadata => "UpdateMetadata",
+            Self::SetPaused => "SetPaused",
+            Self::Cancel => "Cancel",
+            Self::DepositRewards => "DepositRewards",
+            Self::ClaimReward => "ClaimReward",
+            Self::SweepRewards => "SweepRewards",
+            Self::DepositMatch => "DepositMatch",
+            Self::ReclaimMatch => "ReclaimMatch",
+            Self::PostUpdate => "PostUpdate",
+            Self::Snapshot => "Snapshot",
+            Self::SetClaimAuthority => "SetClaimAuthority",
+            Self::SweepForeign => "SweepForeign",
+        }
+    }
+
+    #[inline(always)]
+    pub const fn legacy_code(self) -> Option<u8> {
+        match self {
+            Self::Initialize => Some(0),
+            Self::Contribute => Some(1),
+            Self::Refund => Some(2),
+            Self::Claim => Some(3),
+            _ => None,
+        }
+    }
+
+    /// The code addressing the instruction's [`Versioned`] layouts, for the instructions that
+    /// have them.
+    #[inline(always)]
+    pub const fn versioned_code(self) -> Option<u8> {
+        match self {
+            Self::Initialize | Self::Contribute => Some(self.code() + VERSIONED_CODE_OFFSET),
+            _ => None,
+        }
+    }
+
+    #[inline(always)]
+    pub const fn sighash(self) -> [u8; SIGHASH_LEN] {
+        match self {
+            Self::Initialize => [175, 175, 109, 31, 13, 152, 155, 237],
+            Self::Contribute => [82, 33, 68, 131, 32, 0, 205, 95],
+            Self::Refund => [2, 96, 183, 251, 63, 208, 46, 46],
+            Self::Claim => [62, 198, 214, 193, 213, 159, 108, 210],
+            Self::RunHook => [127, 119, 71, 35, 86, 218, 193, 43],
+            Self::Complete => [0, 77, 224, 147, 136, 25, 88, 76],
+            Self::InitializeConfig => [208, 127, 21, 1, 194, 190, 196, 70],
+            Self::UpdateConfig => [29, 158, 252, 191, 10, 83, 219, 99],
+            Self::InitializeAllowlistPage => [164, 14, 27, 183, 54, 17, 77, 215],
+            Self::ClaimAllowlistSlot => [34, 243, 89, 48, 248, 21, 133, 187],
+            Self::Finalize => [171, 61, 218, 56, 127, 115, 12, 217],
+            Self::Donate => [121, 186, 218, 211, 73, 70, 196, 180],
+            Self::CreateContributor => [110, 184, 236, 66, 220, 3, 68, 216],
+            Self::ContributeFor => [176, 243, 142, 195, 157, 108, 99, 171],
+            Self::TransferContribution => [220, 201, 212, 108, 14, 226, 230, 170],
+            Self::ForceRefund => [127, 173, 30, 92, 164, 123, 109, 177],
+            Self::Extend => [228, 127, 0, 1, 227, 154, 54, 168],
+            Self::ContributeDelegated => [162, 245, 86, 101, 200, 11, 253, 104],
+            Self::CrankRefund => [251, 56, 53, 18, 109, 203, 63, 180],
+            Self::CloseFundraise => [106, 9, 86, 178, 167, 100, 195, 234],
+            Self::UpdateMetadata => [170, 182, 43, 239, 97, 78, 225, 186],
+            Self::SetPaused => [91, 60, 125, 192, 176, 225, 166, 218],
+            Self::Cancel => [232, 219, 223, 41, 219, 236, 220, 190],
+            Self::DepositRewards => [52, 249, 112, 72, 206, 161, 196, 1],
+            Self::ClaimReward => [149, 95, 181, 242, 94, 90, 158, 162],
+            Self::SweepRewards => [51, 178, 232, 16, 65, 222, 150, 39],
+            Self::DepositMatch => [120, 187, 75, 129, 210, 120, 85, 233],
+            Self::ReclaimMatch => [169, 37, 188, 163, 246, 228, 5, 64],
+            Self::PostUpdate => [133, 95, 207, 175, 11, 79, 118, 44],
+            Self::Snapshot => [144, 236, 6, 133, 233, 160, 21, 94],
+            Self::SetClaimAuthority => [70, 101, 219, 159, 202, 190, 195, 20],
+            Self::SweepForeign => [38, 141, 229, 189, 70, 196, 64, 23],
+        }
+    }
+
+    /// Splits instruction data into the addressed instruction, how it was addressed, and the
+    /// payload that follows the discriminator.
+    pub fn parse(data: &[u8]) -> Option<(Self, Encoding, &[u8])> {
+        if let Some((sighash, payload)) = data.split_at_checked(SIGHASH_LEN)
+            && let Some(instruction) = Self::ALL.into_iter().find(|ix| ix.sighash() == sighash)
+        {
+            return Some((instruction, Encoding::Sighash, payload));
+        }
+
+        let (code, payload) = data.split_first()?;
+
+        Self::ALL.into_iter().find_map(|ix| {
+            if ix.code() == *code {
+                Some((ix, Encoding::Code, payload))
+            } else if ix.legacy_code() == Some(*code) {
+                Some((ix, Encoding::Legacy, payload))
+            } else if ix.versioned_code() == Some(*code) {
+                Some((ix, Encoding::Versioned, payload))
+            } else {
+                None
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use litesvm::LiteSVM;
+    use pinocchio::program_error::ProgramError;
+    use sha2::{Digest, Sha256};
+    use solana_account::Account;
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_keypair::Keypair;
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use solana_transaction_error::TransactionError;
+    use spl_associated_token_account::{
+        get_associated_token_address_with_program_id,
+        solana_program::{clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL},
+    };
+
+    use std::collections::BTreeSet;
+
+    use crate::{
+        Cancel, Claim, ClaimAllowlistSlot, ClaimReward, CloseFundraise, Complete, Contribute,
+        ContributeDelegated, ContributeFor, CrankRefund, CreateContributor, DepositMatch,
+        DepositRewards, Donate, Encoding, Extend, Finalize, ForceRefund, FundraiserInstruction,
+        Handler, Initialize, InitializeAllowlistPage, InitializeConfig, PostUpdate, PresentFields,
+        ReclaimMatch, Refund, RunHook, SIGHASH_LEN, SetClaimAuthority, SetPaused, Snapshot,
+        SweepForeign, SweepRewards, TransferContribution, UpdateConfig, UpdateMetadata, Versioned,
+        tests::{
+            constants::{
+                ASSOCIATED_TOKEN_PROGRAM_ID, FEE_COLLECTOR, MINT_DECIMALS, PROGRAM_ID,
+                SYSTEM_PROGRAM_ID, TEST_HOOK_PROGRAM_ID, TOKEN_PROGRAM_ID,
+            },
+            pda::{
+                get_config_pda, get_contributor_pda, get_fundraise_pda, get_hook_authority_pda,
+                get_vault_pda,
+            },
+            utils::{
+                build_and_send_transaction, forward_time, init_ata, init_mint, init_program_data,
+                init_wallet, setup,
+            },
+        },
+    };
+
+    const NAMES: [&str; 32] = [
+        "initialize",
+        "contribute",
+        "refund",
+        "claim",
+        "run_hook",
+        "complete",
+        "initialize_config",
+        "update_config",
+        "initialize_allowlist_page",
+        "claim_allowlist_slot",
+        "finalize",
+        "donate",
+        "create_contributor",
+        "contribute_for",
+        "transfer_contribution",
+        "force_refund",
+        "extend",
+        "contribute_delegated",
+        "crank_refund",
+        "close_fundraise",
+        "update_metadata",
+        "set_paused",
+        "cancel",
+        "deposit_rewards",
+        "claim_reward",
+        "sweep_rewards",
+        "deposit_match",
+        "reclaim_match",
+        "post_update",
+        "snapshot",
+        "set_claim_authority",
+        "sweep_foreign",
+    ];
+
+    #[test]
+    fn sighashes_match_anchor_derivation() {
+        for (ix, name) in FundraiserInstruction::ALL.into_iter().zip(NAMES) {
+            let hash = Sha256::digest(format!("global:{name}"));
+
+            assert_eq!(ix.sighash(), hash[..SIGHASH_LEN], "{name}");
+        }
+    }
+
+    #[test]
+    fn names_match_sighash_names() {
+        for (ix, name) in FundraiserInstruction::ALL.into_iter().zip(NAMES) {
+            let snake_case: String = ix
+                .name()
+                .chars()
+                .enumerate()
+                .flat_map(|(i, c)| {
+                    let separator = (i > 0 && c.is_ascii_uppercase()).then_some('_');
+                    separator.into_iter().chain([c.to_ascii_lowercase()])
+                })
+                .collect();
+
+            assert_eq!(snake_case, name);
+        }
+    }
+
+    #[test]
+    fn every_encoding_parses_to_the_same_instruction_and_payload() {
+        let payload = [9u8, 8, 7, 6, 5, 4, 3, 2, 1];
+
+        for ix in FundraiserInstruction::ALL {
+            let mut encodings = vec![
+                ([vec![ix.code()], payload.to_vec()].concat(), Encoding::Code),
+                (
+                    [ix.sighash().to_vec(), payload.to_vec()].concat(),
+                    Encoding::Sighash,
+                ),
+            ];
+
+            if let Some(legacy) = ix.legacy_code() {
+                encodings.push(([vec![legacy], payload.to_vec()].concat(), Encoding::Legacy));
+            }
+
+            for (data, encoding) in encodings {
+                assert_eq!(
+                    FundraiserInstruction::parse(&data),
+                    Some((ix, encoding, &payload[..])),
+                    "{ix:?} via {encoding:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn legacy_codes_are_the_original_instructions() {
+        let legacy: Vec<_> = FundraiserInstruction::ALL
+            .into_iter()
+            .filter_map(|ix| ix.legacy_code().map(|code| (code, ix)))
+            .collect();
+
+        assert_eq!(
+            legacy,
+            [
+                (0, FundraiserInstruction::Initialize),
+                (1, FundraiserInstruction::Contribute),
+                (2, FundraiserInstruction::Refund),
+                (3, FundraiserInstruction::Claim),
+            ]
+        );
+    }
+
+    #[test]
+    fn sighash_wins_over_colliding_legacy_code() {
+        let complete = FundraiserInstruction::Complete.sighash();
+
+        assert_eq!(
+            complete[0],
+            FundraiserInstruction::Initialize.legacy_code().unwrap()
+        );
+        assert_eq!(
+            FundraiserInstruction::parse(&complete),
+            Some((FundraiserInstruction::Complete, Encoding::Sighash, &[][..]))
+        );
+
+        // a bare legacy Refund is shorter than any sighash
+        assert_eq!(
+            FundraiserInstruction::parse(&[2]),
+            Some((FundraiserInstruction::Refund, Encoding::Legacy, &[][..]))
+        );
+    }
+
+    #[test]
+    fn discriminators_are_unique() {
+        let codes: BTreeSet<_> = FundraiserInstruction::ALL
+            .into_iter()
+            .map(FundraiserInstruction::code)
+            .collect();
+        let versioned: BTreeSet<_> = FundraiserInstruction::ALL
+            .into_iter()
+            .filter_map(FundraiserInstruction::versioned_code)
+            .collect();
+
+        assert!(codes.is_disjoint(&versioned));
+        let sighashes: BTreeSet<_> = FundraiserInstruction::ALL
+            .into_iter()
+            .map(FundraiserInstruction::sighash)
+            .collect();
+
+        assert_eq!(codes.len(), FundraiserInstruction::ALL.len());
+        assert_eq!(sighashes.len(), FundraiserInstruction::ALL.len());
+
+        // legacy codes sit below every current code
+        for ix in FundraiserInstruction::ALL {
+            if let Some(legacy) = ix.legacy_code() {
+                assert!(!codes.contains(&legacy), "{ix:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn handlers_use_their_instruction_code() {
+        for (discriminator, ix) in [
+            (Initialize::DISCRIMINATOR, FundraiserInstruction::Initialize),
+            (Contribute::DISCRIMINATOR, FundraiserInstruction::Contribute),
+            (Refund::DISCRIMINATOR, FundraiserInstruction::Refund),
+            (Claim::DISCRIMINATOR, FundraiserInstruction::Claim),
+            (RunHook::DISCRIMINATOR, FundraiserInstruction::RunHook),
+            (Complete::DISCRIMINATOR, FundraiserInstruction::Complete),
+            (
+                InitializeConfig::DISCRIMINATOR,
+                FundraiserInstruction::InitializeConfig,
+            ),
+            (
+                UpdateConfig::DISCRIMINATOR,
+                FundraiserInstruction::UpdateConfig,
+            ),
+            (
+                InitializeAllowlistPage::DISCRIMINATOR,
+                FundraiserInstruction::InitializeAllowlistPage,
+            ),
+            (
+                ClaimAllowlistSlot::DISCRIMINATOR,
+                FundraiserInstruction::ClaimAllowlistSlot,
+            ),
+            (Finalize::DISCRIMINATOR, FundraiserInstruction::Finalize),
+            (Donate::DISCRIMINATOR, FundraiserInstruction::Donate),
+            (
+                CreateContributor::DISCRIMINATOR,
+                FundraiserInstruction::CreateContributor,
+            ),
+            (
+                ContributeFor::DISCRIMINATOR,
+                FundraiserInstruction::ContributeFor,
+            ),
+            (
+                TransferContribution::DISCRIMINATOR,
+                FundraiserInstruction::TransferContribution,
+            ),
+            (
+                ForceRefund::DISCRIMINATOR,
+                FundraiserInstruction::ForceRefund,
+            ),
+            (Extend::DISCRIMINATOR, FundraiserInstruction::Extend),
+            (
+                ContributeDelegated::DISCRIMINATOR,
+                FundraiserInstruction::ContributeDelegated,
+            ),
+            (
+                CrankRefund::DISCRIMINATOR,
+                FundraiserInstruction::CrankRefund,
+            ),
+            (
+                CloseFundraise::DISCRIMINATOR,
+                FundraiserInstruction::CloseFundraise,
+            ),
+            (
+                UpdateMetadata::DISCRIMINATOR,
+                FundraiserInstruction::UpdateMetadata,
+            ),
+            (SetPaused::DISCRIMINATOR, FundraiserInstruction::SetPaused),
+            (Cancel::DISCRIMINATOR, FundraiserInstruction::Cancel),
+            (
+                DepositRewards::DISCRIMINATOR,
+                FundraiserInstruction::DepositRewards,
+            ),
+            (
+                ClaimReward::DISCRIMINATOR,
+                FundraiserInstruction::ClaimReward,
+            ),
+            (
+                SweepRewards::DISCRIMINATOR,
+                FundraiserInstruction::SweepRewards,
+            ),
+            (
+                DepositMatch::DISCRIMINATOR,
+                FundraiserInstruction::DepositMatch,
+            ),
+            (
+                ReclaimMatch::DISCRIMINATOR,
+                FundraiserInstruction::ReclaimMatch,
+            ),
+            (PostUpdate::DISCRIMINATOR, FundraiserInstruction::PostUpdate),
+            (Snapshot::DISCRIMINATOR, FundraiserInstruction::Snapshot),
+            (
+                SetClaimAuthority::DISCRIMINATOR,
+                FundraiserInstruction::SetClaimAuthority,
+            ),
+            (
+                SweepForeign::DISCRIMINATOR,
+                FundraiserInstruction::SweepForeign,
+            ),
+        ] {
+            assert_eq!(discriminator, ix.code(), "{ix:?}");
+        }
+    }
+
+    #[test]
+    fn reject_unknown_discriminators() {
+        assert_eq!(FundraiserInstruction::parse(&[]), None);
+
+        let next = FundraiserInstruction::ALL
+            .into_iter()
+            .map(FundraiserInstruction::code)
+            .max()
+            .unwrap()
+            + 1;
+
+        let versioned: BTreeSet<_> = FundraiserInstruction::ALL
+            .into_iter()
+            .filter_map(FundraiserInstruction::versioned_code)
+            .collect();
+
+        for code in (4..10).chain(next..=u8::MAX) {
+            if !versioned.contains(&code) {
+                assert_eq!(FundraiserInstruction::parse(&[code]), None, "{code}");
+            }
+        }
+    }
+
+    #[test]
+    fn versioned_codes_are_initialize_and_contribute() {
+        let versioned: Vec<_> = FundraiserInstruction::ALL
+            .into_iter()
+            .filter_map(|ix| ix.versioned_code().map(|code| (code, ix)))
+            .collect();
+
+        assert_eq!(
+            versioned,
+            [
+                (138, FundraiserInstruction::Initialize),
+                (139, FundraiserInstruction::Contribute),
+            ]
+        );
+
+        for (code, ix) in versioned {
+            assert_eq!(
+                FundraiserInstruction::parse(&[code, Versioned::V1, 0, 0]),
+                Some((ix, Encoding::Versioned, &[Versioned::V1, 0, 0][..]))
+            );
+
+            // no sighash starts with a versioned code, so none is read in its place
+            for other in FundraiserInstruction::ALL {
+                assert_ne!(other.sighash()[0], code, "{other:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn versioned_data_needs_a_version_byte() {
+        assert_eq!(
+            Versioned(&[]).split(),
+            Err(ProgramError::InvalidInstructionData)
+        );
+        assert_eq!(Versioned(&[1, 2, 3]).split(), Ok((1, &[2, 3][..])));
+    }
+
+    #[test]
+    fn present_fields_reads_only_what_is_flagged() {
+        assert!(PresentFields::new(&[], 0b11).is_err());
+        assert!(PresentFields::new(&[0], 0b11).is_err());
+        // a flag outside those known
+        assert!(PresentFields::new(&[0b100, 0], 0b11).is_err());
+
+        let mut fields = PresentFields::new(&[0b10, 0, 7, 8, 9], 0b11).unwrap();
+
+        assert_eq!(fields.required::<1>(), Ok(&[7]));
+        assert_eq!(fields.next::<1>(0b01), Ok(None));
+        assert_eq!(fields.next::<1>(0b10), Ok(Some(&[8])));
+        assert_eq!(fields.rest(), &[9]);
+        // a flagged field cut short
+        assert!(fields.next::<2>(0b10).is_err());
+    }
+
+    fn encodings(instruction: FundraiserInstruction) -> Vec<Vec<u8>> {
+        let mut encodings = vec![vec![instruction.code()], instruction.sighash().to_vec()];
+
+        if let Some(legacy) = instruction.legacy_code() {
+            encodings.push(vec![legacy]);
+        }
+
+        encodings
+    }
+
+    /// Sends `payload` under every encoding of `instruction`, each on its own copy of
+    /// `litesvm`, and asserts that the outcome and every referenced account end up identical.
+    fn assert_encodings_agree(
+        litesvm: &LiteSVM,
+        signer: &Keypair,
+        instruction: FundraiserInstruction,
+        accounts: Vec<AccountMeta>,
+        payload: &[u8],
+    ) -> Option<TransactionError> {
+        let outcomes: Vec<_> = encodings(instruction)
+            .into_iter()
+            .map(|discriminator| {
+                let mut litesvm = litesvm.clone();
+                let ix = Instruction {
+                    program_id: PROGRAM_ID,
+                    accounts: accounts.clone(),
+                    data: [discriminator, payload.to_vec()].concat(),
+                };
+
+                let err =
+                    build_and_send_transaction(&mut litesvm, &[signer], &signer.pubkey(), &[ix])
+                        .err()
+                        .map(|failed| failed.err);
+                let state: Vec<Option<Account>> = accounts
+                    .iter()
+                    .map(|meta| litesvm.get_account(&meta.pubkey))
+                    .collect();
+
+                (err, state)
+            })
+            .collect();
+
+        for outcome in &outcomes[1..] {
+            assert_eq!(outcome, &outcomes[0], "{instruction:?}");
+        }
+
+        outcomes[0].0.clone()
+    }
+
+    struct Scenario {
+        maker: Keypair,
+        mint_to_raise: Pubkey,
+        fundraise_pda: Pubkey,
+        vault: Pubkey,
+    }
+
+    fn initialize_payload() -> Vec<u8> {
+        [
+            5_000_000u64.to_le_bytes().to_vec(),
+            SECONDS_PER_DAY.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat()
+    }
+
+    fn initialize_metas(fundraise: &Scenario) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(fundraise.maker.pubkey(), true),
+            AccountMeta::new_readonly(fundraise.mint_to_raise, false),
+            AccountMeta::new(fundraise.fundraise_pda, false),
+            AccountMeta::new(fundraise.vault, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(get_config_pda(), false),
+        ]
+    }
+
+    fn contribute_metas(fundraise: &Scenario, authority: &Pubkey) -> Vec<AccountMeta> {
+        let authority_ata = get_associated_token_address_with_program_id(
+            authority,
+            &fundraise.mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        vec![
+            AccountMeta::new(*authority, true),
+            AccountMeta::new_readonly(fundraise.mint_to_raise, false),
+            AccountMeta::new(fundraise.fundraise_pda, false),
+            AccountMeta::new(
+                get_contributor_pda(&fundraise.fundraise_pda, authority),
+                false,
+            ),
+            AccountMeta::new(authority_ata, false),
+            AccountMeta::new(fundraise.vault, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(get_config_pda(), false),
+        ]
+    }
+
+    fn new_fundraise(litesvm: &mut LiteSVM) -> Scenario {
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 10_000_000_000);
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        Scenario {
+            maker,
+            mint_to_raise,
+            fundraise_pda,
+            vault,
+        }
+    }
+
+    fn initialize(litesvm: &mut LiteSVM) -> Scenario {
+        let fundraise = new_fundraise(litesvm);
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: initialize_metas(&fundraise),
+            data: [vec![0u8], initialize_payload()].concat(),
+        };
+
+        build_and_send_transaction(
+            litesvm,
+            &[&fundraise.maker],
+            &fundraise.maker.pubkey(),
+            &[ix],
+        )
+        .unwrap();
+
+        fundraise
+    }
+
+    fn contribute(litesvm: &mut LiteSVM, fundraise: &Scenario, amount: u64) -> Keypair {
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        init_ata(
+            litesvm,
+            fundraise.mint_to_raise,
+            authority.pubkey(),
+            1_000_000_000,
+        );
+
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: contribute_metas(fundraise, &authority.pubkey()),
+            data: [vec![1u8], amount.to_le_bytes().to_vec()].concat(),
+        };
+
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+
+        authority
+    }
+
+    #[test]
+    fn dispatch_initialize() {
+        let (litesvm, _default_payer) = &mut setup();
+        let fundraise = new_fundraise(litesvm);
+
+        let err = assert_encodings_agree(
+            litesvm,
+            &fundraise.maker,
+            FundraiserInstruction::Initialize,
+            initialize_metas(&fundraise),
+            &initialize_payload(),
+        );
+
+        assert_eq!(err, None);
+    }
+
+    #[test]
+    fn dispatch_contribute() {
+        let (litesvm, _default_payer) = &mut setup();
+        let fundraise = initialize(litesvm);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        init_ata(
+            litesvm,
+            fundraise.mint_to_raise,
+            authority.pubkey(),
+            1_000_000_000,
+        );
+
+        let err = assert_encodings_agree(
+            litesvm,
+            &authority,
+            FundraiserInstruction::Contribute,
+            contribute_metas(&fundraise, &authority.pubkey()),
+            &500_000u64.to_le_bytes(),
+        );
+
+        assert_eq!(err, None);
+    }
+
+    #[test]
+    fn dispatch_refund() {
+        let (litesvm, _default_payer) = &mut setup();
+        let fundraise = initialize(litesvm);
+        let authority = contribute(litesvm, &fundraise, 500_000);
+
+        forward_time(litesvm, 3600); // jump forward 1 hour
+
+        let authority_ata = get_associated_token_address_with_program_id(
+            &authority.pubkey(),
+            &fundraise.mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+        let err = assert_encodings_agree(
+            litesvm,
+            &authority,
+            FundraiserInstruction::Refund,
+            vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new(fundraise.maker.pubkey(), false),
+                AccountMeta::new_readonly(fundraise.mint_to_raise, false),
+                AccountMeta::new(fundraise.fundraise_pda, false),
+                AccountMeta::new(
+                    get_contributor_pda(&fundraise.fundraise_pda, &authority.pubkey()),
+                    false,
+                ),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(fundraise.vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            ],
+            &[],
+        );
+
+        assert_eq!(err, None);
+    }
+
+    #[test]
+    fn dispatch_claim() {
+        let (litesvm, _default_payer) = &mut setup();
+        let fundraise = initialize(litesvm);
+
+        for _ in 0..10 {
+            contribute(litesvm, &fundraise, 500_000);
+        }
+
+        let maker_ata = get_associated_token_address_with_program_id(
+            &fundraise.maker.pubkey(),
+            &fundraise.mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+        let fee_collector_ata = get_associated_token_address_with_program_id(
+            &FEE_COLLECTOR,
+            &fundraise.mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+        let err = assert_encodings_agree(
+            litesvm,
+            &fundraise.maker,
+            FundraiserInstruction::Claim,
+            vec![
+                AccountMeta::new(fundraise.maker.pubkey(), true),
+                AccountMeta::new_readonly(fundraise.mint_to_raise, false),
+                AccountMeta::new(fundraise.fundraise_pda, false),
+                AccountMeta::new(fundraise.vault, false),
+                AccountMeta::new(maker_ata, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+                AccountMeta::new_readonly(FEE_COLLECTOR, false),
+                AccountMeta::new(fee_collector_ata, false),
+                AccountMeta::new(fundraise.maker.pubkey(), false),
+            ],
+            &[],
+        );
+
+        assert_eq!(err, None);
+    }
+
+    #[test]
+    fn dispatch_run_hook() {
+        let (litesvm, _default_payer) = &mut setup();
+        let fundraise = initialize(litesvm);
+        let authority = contribute(litesvm, &fundraise, 500_000);
+
+        // without a registered hook every encoding is rejected the same way
+        let err = assert_encodings_agree(
+            litesvm,
+            &authority,
+            FundraiserInstruction::RunHook,
+            vec![
+                AccountMeta::new(fundraise.fundraise_pda, false),
+                AccountMeta::new(
+                    get_contributor_pda(&fundraise.fundraise_pda, &authority.pubkey()),
+                    false,
+                ),
+                AccountMeta::new_readonly(get_hook_authority_pda(&fundraise.fundraise_pda), false),
+                AccountMeta::new_readonly(TEST_HOOK_PROGRAM_ID, false),
+            ],
+            &[],
+        );
+
+        assert!(err.is_some());
+    }
+
+    #[test]
+    fn dispatch_complete() {
+        let (litesvm, _default_payer) = &mut setup();
+        let fundraise = initialize(litesvm);
+        let maker_ata = init_ata(
+            litesvm,
+            fundraise.mint_to_raise,
+            fundraise.maker.pubkey(),
+            5_000_000,
+        );
+
+        // the fundraise has no completion window, so every encoding is rejected the same way
+        let err = assert_encodings_agree(
+            litesvm,
+            &fundraise.maker,
+            FundraiserInstruction::Complete,
+            vec![
+                AccountMeta::new(fundraise.maker.pubkey(), true),
+                AccountMeta::new_readonly(fundraise.mint_to_raise, false),
+                AccountMeta::new(fundraise.fundraise_pda, false),
+                AccountMeta::new(maker_ata, false),
+                AccountMeta::new(fundraise.vault, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            ],
+            &[],
+        );
+
+        assert!(err.is_some());
+    }
+
+    #[test]
+    fn dispatch_initialize_config() {
+        let (litesvm, default_payer) = &mut setup();
+        let program_data = init_program_data(litesvm, default_payer.pubkey());
+        let config_pda = get_config_pda();
+
+        // drop the config preloaded by setup
+        litesvm.set_account(config_pda, Account::default()).unwrap();
+
+        let err = assert_encodings_agree(
+            litesvm,
+            default_payer,
+            FundraiserInstruction::InitializeConfig,
+            vec![
+                AccountMeta::new(default_payer.pubkey(), true),
+                AccountMeta::new(config_pda, false),
+                AccountMeta::new_readonly(program_data, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+            &[250u16.to_le_bytes().as_ref(), FEE_COLLECTOR.as_ref()].concat(),
+        );
+
+        assert_eq!(err, None);
+    }
+
+    #[test]
+    fn dispatch_update_config() {
+        let (litesvm, default_payer) = &mut setup();
+
+        let err = assert_encodings_agree(
+            litesvm,
+            default_payer,
+            FundraiserInstruction::UpdateConfig,
+            vec![
+                AccountMeta::new_readonly(default_payer.pubkey(), true),
+                AccountMeta::new(get_config_pda(), false),
+            ],
+            &[
+                default_payer.pubkey().to_bytes().to_vec(),
+                100u16.to_le_bytes().to_vec(),
+                FEE_COLLECTOR.to_bytes().to_vec(),
+                vec![0u8],
+            ]
+            .concat(),
+        );
+
+        assert_eq!(err, None);
+    }
+}
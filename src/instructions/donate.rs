@@ -0,0 +1,466 @@
+use core::mem::size_of;
+
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{Sysvar, clock::Clock},
+};
+use pinocchio_token_2022::instructions::Transfer;
+
+use crate::{
+    AccountCheck, AccountLoad, AssociatedTokenAccount, Fundraise, FundraiserError,
+    FundraiserInstruction, Handler, MintInterface, Prefix, ProgramAccount, SignerAccount,
+    TokenAccountInterface, WritableAccount, check_account,
+};
+
+pub struct DonateAccounts<'a> {
+    pub donor: &'a AccountInfo,
+    pub mint_to_raise: &'a AccountInfo,
+    pub fundraise: &'a AccountInfo,
+    pub donor_token_account: &'a AccountInfo,
+    pub vault: &'a AccountInfo,
+    pub token_program: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for DonateAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [
+            donor,
+            mint_to_raise,
+            fundraise,
+            donor_token_account,
+            vault,
+            token_program,
+        ] = accounts
+        else {
+            return Err(FundraiserError::MissingAccounts.into());
+        };
+
+        check_account!(0, "signer", SignerAccount::check(donor))?;
+        check_account!(2, "writable", WritableAccount::check(fundraise))?;
+        check_account!(3, "writable", WritableAccount::check(donor_token_account))?;
+        check_account!(4, "writable", WritableAccount::check(vault))?;
+        check_account!(1, "mint", MintInterface::check(mint_to_raise))?;
+        check_account!(2, "owner", ProgramAccount::check(fundraise))?;
+
+        let fundraise_data = fundraise.try_borrow_data()?;
+        let fundraise_state = Fundraise::load(&fundraise_data)?;
+
+        check_account!(
+            5,
+            "token program",
+            fundraise_state.check_token_program(token_program.key())
+        )?;
+        check_account!(4, "vault", fundraise_state.check_vault(vault.key()))?;
+        drop(fundraise_data);
+
+        check_account!(
+            3,
+            "token account",
+            AssociatedTokenAccount::check(donor_token_account, donor, mint_to_raise, token_program)
+        )?;
+        check_account!(
+            4,
+            "mint and owner",
+            TokenAccountInterface::check_mint_and_owner(
+                vault,
+                mint_to_raise.key(),
+                fundraise.key()
+            )
+        )?;
+
+        Ok(Self {
+            donor,
+            mint_to_raise,
+            fundraise,
+            donor_token_account,
+            vault,
+            token_program,
+        })
+    }
+}
+
+pub struct DonateInstructionData {
+    pub amount: u64,
+}
+
+impl DonateInstructionData {
+    pub const LEN: usize = size_of::<u64>();
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        self.amount.to_le_bytes()
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for DonateInstructionData {
+    type Error = ProgramError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        let amount = u64::from_le_bytes(
+            data.try_into()
+                .map_err(|_| ProgramError::InvalidInstructionData)?,
+        );
+
+        Ok(Self { amount })
+    }
+}
+
+/// Tops up the vault of a running fundraise, e.g. a sponsor matching contributions. Donations
+/// are tracked as `donated_amount` rather than against a contributor, so they count towards the
+/// target and are paid out by Claim, but are never refundable.
+pub struct Donate<'a> {
+    pub accounts: DonateAccounts<'a>,
+    pub data: DonateInstructionData,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for Donate<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = DonateAccounts::try_from(accounts)?;
+        let data = DonateInstructionData::try_from(data)?;
+
+        Ok(Self { accounts, data })
+    }
+}
+
+impl Handler for Donate<'_> {
+    const DISCRIMINATOR: u8 = FundraiserInstruction::Donate as u8;
+
+    fn process(&mut self) -> ProgramResult {
+        if self.data.amount == 0 {
+            return Err(FundraiserError::InvalidAmount.into());
+        }
+
+        let mut fundraise_data = self.accounts.fundraise.try_borrow_mut_data()?;
+        let fundraise = Fundraise::load_mut(fundraise_data.as_mut())?;
+
+        let fundraise_seeds = &[Fundraise::PREFIX, &fundraise.maker, &[fundraise.bump]];
+
+        ProgramAccount::validate(fundraise_seeds, *self.accounts.fundraise.key())?;
+        fundraise.check_mint_to_raise(self.accounts.mint_to_raise.key())?;
+
+        if fundraise.is_claimed() {
+            return Err(FundraiserError::AlreadyClaimed.into());
+        }
+
+        if Clock::get()?.unix_timestamp > fundraise.get_end_time() {
+            return Err(FundraiserError::FundraiserEnded.into());
+        }
+
+        let donated_amount = fundraise
+            .get_donated_amount()
+            .checked_add(self.data.amount)
+            .ok_or(FundraiserError::MathOverflow)?;
+
+        fundraise.set_donated_amount(donated_amount);
+
+        drop(fundraise_data);
+
+        Transfer {
+            amount: self.data.amount,
+            authority: self.accounts.donor,
+            from: self.accounts.donor_token_account,
+            to: self.accounts.vault,
+            token_program: self.accounts.token_program.key(),
+        }
+        .invoke()?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use litesvm::LiteSVM;
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_keypair::Keypair;
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use spl_associated_token_account::{
+        get_associated_token_address_with_program_id,
+        solana_program::{clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL},
+    };
+    use spl_token_2022::state::Account;
+
+    use crate::{
+        AccountLoad, Fundraise,
+        tests::{
+            constants::{
+                ASSOCIATED_TOKEN_PROGRAM_ID, FEE_COLLECTOR, MINT_DECIMALS, PROGRAM_ID,
+                SYSTEM_PROGRAM_ID, TOKEN_PROGRAM_ID,
+            },
+            pda::{get_config_pda, get_contributor_pda, get_fundraise_pda, get_vault_pda},
+            utils::{
+                build_and_send_transaction, fetch_account, forward_time, init_ata, init_mint,
+                init_wallet, setup,
+            },
+        },
+    };
+
+    const AMOUNT_TO_RAISE: u64 = 5_000_000;
+
+    fn initialize(litesvm: &mut LiteSVM, maker: &Keypair, mint_to_raise: Pubkey) -> Pubkey {
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+
+        let data = [
+            vec![0u8],
+            AMOUNT_TO_RAISE.to_le_bytes().to_vec(),
+            SECONDS_PER_DAY.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(get_vault_pda(&fundraise_pda), false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        build_and_send_transaction(litesvm, &[maker], &maker.pubkey(), &[ix]).unwrap();
+
+        fundraise_pda
+    }
+
+    fn contribute(
+        litesvm: &mut LiteSVM,
+        authority: &Keypair,
+        mint_to_raise: Pubkey,
+        fundraise_pda: Pubkey,
+        amount: u64,
+    ) {
+        let data = [vec![1u8], amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(
+                    get_contributor_pda(&fundraise_pda, &authority.pubkey()),
+                    false,
+                ),
+                AccountMeta::new(
+                    get_associated_token_address_with_program_id(
+                        &authority.pubkey(),
+                        &mint_to_raise,
+                        &TOKEN_PROGRAM_ID,
+                    ),
+                    false,
+                ),
+                AccountMeta::new(get_vault_pda(&fundraise_pda), false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        build_and_send_transaction(litesvm, &[authority], &authority.pubkey(), &[ix]).unwrap();
+    }
+
+    fn donate_ix(
+        donor: &Pubkey,
+        mint_to_raise: Pubkey,
+        fundraise_pda: Pubkey,
+        amount: u64,
+    ) -> Instruction {
+        let data = [vec![21u8], amount.to_le_bytes().to_vec()].concat();
+
+        Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(*donor, true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(
+                    get_associated_token_address_with_program_id(
+                        donor,
+                        &mint_to_raise,
+                        &TOKEN_PROGRAM_ID,
+                    ),
+                    false,
+                ),
+                AccountMeta::new(get_vault_pda(&fundraise_pda), false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            ],
+            data,
+        }
+    }
+
+    #[test]
+    fn donate() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let donor = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 10_000_000_000);
+        let donor_ata = init_ata(litesvm, mint_to_raise, donor.pubkey(), 1_000_000_000);
+
+        let fundraise_pda = initialize(litesvm, &maker, mint_to_raise);
+        let donate_amount: u64 = 1_000_000;
+
+        let ix = donate_ix(&donor.pubkey(), mint_to_raise, fundraise_pda, donate_amount);
+
+        build_and_send_transaction(litesvm, &[&donor], &donor.pubkey(), &[ix]).unwrap();
+
+        let fundraise_acc = litesvm.get_account(&fundraise_pda).unwrap();
+        let fundraise = Fundraise::load(fundraise_acc.data.as_ref()).unwrap();
+
+        assert_eq!(fundraise.get_donated_amount(), donate_amount);
+        assert_eq!(fundraise.get_current_amount(), 0);
+
+        let vault_bal = fetch_account::<Account>(litesvm, &get_vault_pda(&fundraise_pda)).amount;
+        let donor_bal = fetch_account::<Account>(litesvm, &donor_ata).amount;
+
+        assert_eq!(vault_bal, donate_amount);
+        assert_eq!(donor_bal, 1_000_000_000 - donate_amount);
+    }
+
+    #[test]
+    fn donation_matches_contributions_to_target() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let sponsor = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 10_000_000_000);
+        init_ata(litesvm, mint_to_raise, sponsor.pubkey(), 1_000_000_000);
+
+        let fundraise_pda = initialize(litesvm, &maker, mint_to_raise);
+        let vault = get_vault_pda(&fundraise_pda);
+
+        // 5 authorities raise half of the target, which the sponsor matches
+        for _ in 0..5 {
+            let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+            init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+            contribute(litesvm, &authority, mint_to_raise, fundraise_pda, 500_000);
+        }
+
+        let ix = donate_ix(
+            &sponsor.pubkey(),
+            mint_to_raise,
+            fundraise_pda,
+            AMOUNT_TO_RAISE / 2,
+        );
+
+        build_and_send_transaction(litesvm, &[&sponsor], &sponsor.pubkey(), &[ix]).unwrap();
+
+        let fundraise_acc = litesvm.get_account(&fundraise_pda).unwrap();
+        let fundraise = Fundraise::load(fundraise_acc.data.as_ref()).unwrap();
+
+        assert_eq!(fundraise.remaining_amount(), 0);
+
+        forward_time(litesvm, 3600); // jump forward 1 hour
+
+        let maker_ata = init_ata(litesvm, mint_to_raise, maker.pubkey(), 0);
+        let fee_collector_ata = get_associated_token_address_with_program_id(
+            &FEE_COLLECTOR,
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        let data = vec![3u8];
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new(maker_ata, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+                AccountMeta::new_readonly(FEE_COLLECTOR, false),
+                AccountMeta::new(fee_collector_ata, false),
+                AccountMeta::new(maker.pubkey(), false),
+            ],
+            data,
+        };
+
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let vault_bal = fetch_account::<Account>(litesvm, &vault).amount;
+        let maker_bal = fetch_account::<Account>(litesvm, &maker_ata).amount;
+
+        assert_eq!(vault_bal, 0);
+        assert_eq!(maker_bal, AMOUNT_TO_RAISE);
+    }
+
+    #[test]
+    fn refund_leaves_donations_in_vault() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let donor = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 10_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+        init_ata(litesvm, mint_to_raise, donor.pubkey(), 1_000_000_000);
+
+        let fundraise_pda = initialize(litesvm, &maker, mint_to_raise);
+        let vault = get_vault_pda(&fundraise_pda);
+        let contribute_amount: u64 = 500_000;
+        let donate_amount: u64 = 1_000_000;
+
+        contribute(
+            litesvm,
+            &authority,
+            mint_to_raise,
+            fundraise_pda,
+            contribute_amount,
+        );
+
+        let ix = donate_ix(&donor.pubkey(), mint_to_raise, fundraise_pda, donate_amount);
+
+        build_and_send_transaction(litesvm, &[&donor], &donor.pubkey(), &[ix]).unwrap();
+
+        let data = vec![2u8];
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new(maker.pubkey(), false),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(
+                    get_contributor_pda(&fundraise_pda, &authority.pubkey()),
+                    false,
+                ),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            ],
+            data,
+        };
+
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+
+        let fundraise_acc = litesvm.get_account(&fundraise_pda).unwrap();
+        let fundraise = Fundraise::load(fundraise_acc.data.as_ref()).unwrap();
+
+        assert_eq!(fundraise.get_current_amount(), 0);
+        assert_eq!(fundraise.get_donated_amount(), donate_amount);
+
+        let vault_bal = fetch_account::<Account>(litesvm, &vault).amount;
+        let authority_bal = fetch_account::<Account>(litesvm, &authority_ata).amount;
+
+        assert_eq!(vault_bal, donate_amount);
+        assert_eq!(authority_bal, 1_000_000_000);
+    }
+}
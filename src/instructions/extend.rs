@@ -0,0 +1,257 @@
+use core::mem::size_of;
+
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::find_program_address,
+    sysvars::{Sysvar, clock::Clock},
+};
+
+use crate::{
+    AccountCheck, AccountLoad, Fundraise, FundraiserError, FundraiserInstruction, Handler,
+    MAX_DURATION, MAX_EXTENSIONS, Prefix, ProgramAccount, SignerAccount, Trigger, WritableAccount,
+};
+
+pub struct ExtendAccounts<'a> {
+    pub maker: &'a AccountInfo,
+    pub fundraise: &'a AccountInfo,
+    pub trigger: Option<&'a AccountInfo>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for ExtendAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let (maker, fundraise, trigger) = match accounts {
+            [maker, fundraise] => (maker, fundraise, None),
+            [maker, fundraise, trigger] => (maker, fundraise, Some(trigger)),
+            _ => return Err(FundraiserError::MissingAccounts.into()),
+        };
+
+        SignerAccount::check(maker)?;
+        WritableAccount::check(fundraise)?;
+        ProgramAccount::check(fundraise)?;
+
+        if let Some(trigger) = trigger {
+            WritableAccount::check(trigger)?;
+            ProgramAccount::check(trigger)?;
+        }
+
+        Ok(Self {
+            maker,
+            fundraise,
+            trigger,
+        })
+    }
+}
+
+pub struct ExtendInstructionData {
+    pub duration: u64,
+}
+
+impl ExtendInstructionData {
+    pub const LEN: usize = size_of::<u64>();
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        self.duration.to_le_bytes()
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for ExtendInstructionData {
+    type Error = ProgramError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        let duration = u64::from_le_bytes(
+            data.try_into()
+                .map_err(|_| ProgramError::InvalidInstructionData)?,
+        );
+
+        Ok(Self { duration })
+    }
+}
+
+/// Lets the maker push back the end of a running fundraise by `duration` seconds. Only a
+/// fundraise still short of its target can be extended, at most `MAX_EXTENSIONS` times and
+/// never beyond `MAX_DURATION` in total, so a failing campaign cannot hold contributions
+/// hostage indefinitely. A keeper trigger, passed last, is moved to the new end time.
+pub struct Extend<'a> {
+    pub accounts: ExtendAccounts<'a>,
+    pub data: ExtendInstructionData,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for Extend<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = ExtendAccounts::try_from(accounts)?;
+        let data = ExtendInstructionData::try_from(data)?;
+
+        Ok(Self { accounts, data })
+    }
+}
+
+impl Handler for Extend<'_> {
+    const DISCRIMINATOR: u8 = FundraiserInstruction::Extend as u8;
+
+    fn process(&mut self) -> ProgramResult {
+        if self.data.duration == 0 {
+            return Err(FundraiserError::InvalidAmount.into());
+        }
+
+        let mut fundraise_data = self.accounts.fundraise.try_borrow_mut_data()?;
+        let fundraise = Fundraise::load_mut(fundraise_data.as_mut())?;
+
+        if fundraise.maker != *self.accounts.maker.key() {
+            return Err(FundraiserError::InvalidAddress.into());
+        }
+
+        ProgramAccount::validate(
+            &[Fundraise::PREFIX, &fundraise.maker, &[fundraise.bump]],
+            *self.accounts.fundraise.key(),
+        )?;
+
+        if fundraise.is_claimed() {
+            return Err(FundraiserError::AlreadyClaimed.into());
+        }
+
+        if Clock::get()?.unix_timestamp > fundraise.get_end_time() {
+            return Err(FundraiserError::FundraiserEnded.into());
+        }
+
+        if fundraise.remaining_amount() == 0 {
+            return Err(FundraiserError::TargetMet.into());
+        }
+
+        if fundraise.extensions_used >= MAX_EXTENSIONS {
+            return Err(FundraiserError::TooManyExtensions.into());
+        }
+
+        let duration = fundraise.get_duration().saturating_add(self.data.duration);
+
+        if duration > MAX_DURATION {
+            return Err(FundraiserError::DurationTooLong.into());
+        }
+
+        fundraise.set_duration(duration);
+        fundraise.set_end_time(
+            fundraise
+                .get_end_time()
+                .checked_add_unsigned(self.data.duration)
+                .ok_or(FundraiserError::MathOverflow)?,
+        );
+        fundraise.extensions_used += 1;
+
+        if let Some(trigger) = self.accounts.trigger {
+            let (pda, _) = find_program_address(
+                &[Trigger::PREFIX, self.accounts.fundraise.key()],
+                &crate::ID,
+            );
+
+            if pda != *trigger.key() {
+                return Err(FundraiserError::InvalidAddress.into());
+            }
+
+            let mut trigger_data = trigger.try_borrow_mut_data()?;
+
+            Trigger::load_mut(trigger_data.as_mut())?.set_end_time(fundraise.get_end_time());
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_signer::Signer;
+    use spl_associated_token_account::solana_program::{
+        clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL,
+    };
+
+    use crate::{
+        AccountLoad, Fundraise, Trigger,
+        tests::{
+            constants::{
+                ASSOCIATED_TOKEN_PROGRAM_ID, MINT_DECIMALS, PROGRAM_ID, SYSTEM_PROGRAM_ID,
+                TOKEN_PROGRAM_ID,
+            },
+            pda::{get_config_pda, get_fundraise_pda, get_trigger_pda, get_vault_pda},
+            utils::{build_and_send_transaction, init_mint, init_wallet, setup},
+        },
+    };
+
+    #[test]
+    fn extend_moves_end_time_and_trigger() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+        let trigger_pda = get_trigger_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u8.to_le_bytes().to_vec(),
+            1u8.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+                AccountMeta::new(trigger_pda, false),
+            ],
+            data,
+        };
+
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let fundraise_acc = litesvm.get_account(&fundraise_pda).unwrap();
+        let end_time = Fundraise::load(fundraise_acc.data.as_ref())
+            .unwrap()
+            .get_end_time();
+
+        let extension: u64 = SECONDS_PER_DAY;
+        let data = [vec![26u8], extension.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new_readonly(maker.pubkey(), true),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(trigger_pda, false),
+            ],
+            data,
+        };
+
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let fundraise_acc = litesvm.get_account(&fundraise_pda).unwrap();
+        let fundraise = Fundraise::load(fundraise_acc.data.as_ref()).unwrap();
+
+        assert_eq!(fundraise.get_duration(), duration + extension);
+        assert_eq!(fundraise.get_end_time(), end_time + extension as i64);
+        assert_eq!(fundraise.extensions_used, 1);
+
+        let trigger_acc = litesvm.get_account(&trigger_pda).unwrap();
+        let trigger = Trigger::load(trigger_acc.data.as_ref()).unwrap();
+
+        assert_eq!(trigger.get_end_time(), fundraise.get_end_time());
+    }
+}
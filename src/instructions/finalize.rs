@@ -0,0 +1,262 @@
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    pubkey::find_program_address,
+    sysvars::{Sysvar, clock::Clock},
+};
+
+use crate::{
+    AccountCheck, AccountLoad, Fundraise, FundraiserError, FundraiserInstruction, Handler, Prefix,
+    ProgramAccount, Trigger, WritableAccount, check_account,
+};
+
+pub struct FinalizeAccounts<'a> {
+    pub fundraise: &'a AccountInfo,
+    pub trigger: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for FinalizeAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [fundraise, trigger] = accounts else {
+            return Err(FundraiserError::MissingAccounts.into());
+        };
+
+        check_account!(1, "writable", WritableAccount::check(trigger))?;
+        check_account!(0, "owner", ProgramAccount::check(fundraise))?;
+        check_account!(1, "owner", ProgramAccount::check(trigger))?;
+
+        Ok(Self { fundraise, trigger })
+    }
+}
+
+/// Permissionless instruction a keeper fires once the fundraise has expired. It marks the
+/// fundraise's trigger as fired, so each trigger succeeds exactly once.
+pub struct Finalize<'a> {
+    pub accounts: FinalizeAccounts<'a>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for Finalize<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let accounts = FinalizeAccounts::try_from(accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl Handler for Finalize<'_> {
+    const DISCRIMINATOR: u8 = FundraiserInstruction::Finalize as u8;
+
+    fn process(&mut self) -> ProgramResult {
+        let fundraise_data = self.accounts.fundraise.try_borrow_data()?;
+        let fundraise = Fundraise::load(&fundraise_data)?;
+
+        ProgramAccount::validate(
+            &[Fundraise::PREFIX, &fundraise.maker, &[fundraise.bump]],
+            *self.accounts.fundraise.key(),
+        )?;
+
+        // The trigger only holds the end time and fired flag, so its address is re-derived.
+        let (pda, _) = find_program_address(
+            &[Trigger::PREFIX, self.accounts.fundraise.key()],
+            &crate::ID,
+        );
+
+        if pda != *self.accounts.trigger.key() {
+            return Err(FundraiserError::InvalidAddress.into());
+        }
+
+        if Clock::get()?.unix_timestamp <= fundraise.get_end_time() {
+            return Err(FundraiserError::FundraiserNotEnded.into());
+        }
+
+        let mut trigger_data = self.accounts.trigger.try_borrow_mut_data()?;
+        let trigger = Trigger::load_mut(trigger_data.as_mut())?;
+
+        if trigger.is_fired() {
+            return Err(FundraiserError::TriggerAlreadyFired.into());
+        }
+
+        trigger.fired = 1;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_signer::Signer;
+    use spl_associated_token_account::solana_program::{
+        clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL,
+    };
+
+    use crate::{
+        AccountLoad, Fundraise, FundraiserError, Trigger,
+        tests::{
+            constants::{
+                ASSOCIATED_TOKEN_PROGRAM_ID, MINT_DECIMALS, PROGRAM_ID, SYSTEM_PROGRAM_ID,
+                TOKEN_PROGRAM_ID,
+            },
+            pda::{get_config_pda, get_fundraise_pda, get_trigger_pda, get_vault_pda},
+            utils::{
+                assert_error, build_and_send_transaction, forward_time, init_mint, init_wallet,
+                setup,
+            },
+        },
+    };
+
+    #[test]
+    fn finalize_fires_trigger_once() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let keeper = init_wallet(litesvm, LAMPORTS_PER_SOL);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+        let trigger_pda = get_trigger_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u8.to_le_bytes().to_vec(),
+            1u8.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+                AccountMeta::new(trigger_pda, false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let fundraise_acc = litesvm.get_account(&fundraise_pda).unwrap();
+        let fundraise = Fundraise::load(fundraise_acc.data.as_ref()).unwrap();
+        let trigger_acc = litesvm.get_account(&trigger_pda).unwrap();
+        let trigger = Trigger::load(trigger_acc.data.as_ref()).unwrap();
+
+        assert_eq!(trigger.get_end_time(), fundraise.get_end_time());
+        assert!(!trigger.is_fired());
+
+        forward_time(litesvm, duration as i64 + 1);
+
+        let data = vec![20u8];
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new_readonly(fundraise_pda, false),
+                AccountMeta::new(trigger_pda, false),
+            ],
+            data,
+        };
+
+        let res = build_and_send_transaction(litesvm, &[&keeper], &keeper.pubkey(), &[ix]);
+
+        assert!(res.is_ok());
+
+        let trigger_acc = litesvm.get_account(&trigger_pda).unwrap();
+        let trigger = Trigger::load(trigger_acc.data.as_ref()).unwrap();
+
+        assert!(trigger.is_fired());
+
+        // a keeper retrying in a later block must not fire the trigger again
+        litesvm.expire_blockhash();
+
+        let data = vec![20u8];
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new_readonly(fundraise_pda, false),
+                AccountMeta::new(trigger_pda, false),
+            ],
+            data,
+        };
+
+        let res = build_and_send_transaction(litesvm, &[&keeper], &keeper.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::TriggerAlreadyFired);
+    }
+
+    #[test]
+    fn throw_if_fundraiser_not_ended() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let keeper = init_wallet(litesvm, LAMPORTS_PER_SOL);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+        let trigger_pda = get_trigger_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u8.to_le_bytes().to_vec(),
+            1u8.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+                AccountMeta::new(trigger_pda, false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        forward_time(litesvm, duration as i64);
+
+        let data = vec![20u8];
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new_readonly(fundraise_pda, false),
+                AccountMeta::new(trigger_pda, false),
+            ],
+            data,
+        };
+
+        let res = build_and_send_transaction(litesvm, &[&keeper], &keeper.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::FundraiserNotEnded);
+    }
+}
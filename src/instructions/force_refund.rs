@@ -0,0 +1,500 @@
+use pinocchio::{ProgramResult, account_info::AccountInfo, program_error::ProgramError};
+
+use crate::{
+    AccountCheck, AccountLoad, AssociatedTokenAccount, Contributor, Fundraise, FundraiserError,
+    FundraiserInstruction, Handler, Liability, MintInterface, Prefix, ProgramAccount,
+    ProgramIdCheck, SignerAccount, TokenAccountInterface, TransferHook, WritableAccount,
+    check_account, vault_transfer,
+};
+
+pub struct ForceRefundAccounts<'a> {
+    pub maker: &'a AccountInfo,
+    pub authority: &'a AccountInfo,
+    pub mint_to_raise: &'a AccountInfo,
+    pub fundraise: &'a AccountInfo,
+    pub contributor: &'a AccountInfo,
+    pub authority_token_account: &'a AccountInfo,
+    pub vault: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+    pub token_program: &'a AccountInfo,
+    pub associated_token_program: &'a AccountInfo,
+    pub transfer_hook_accounts: &'a [AccountInfo],
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for ForceRefundAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [
+            maker,
+            authority,
+            mint_to_raise,
+            fundraise,
+            contributor,
+            authority_token_account,
+            vault,
+            system_program,
+            token_program,
+            associated_token_program,
+            rest @ ..,
+        ] = accounts
+        else {
+            return Err(FundraiserError::MissingAccounts.into());
+        };
+
+        check_account!(0, "signer", SignerAccount::check(maker))?;
+        check_account!(0, "writable", WritableAccount::check(maker))?;
+        check_account!(1, "writable", WritableAccount::check(authority))?;
+        check_account!(3, "writable", WritableAccount::check(fundraise))?;
+        check_account!(4, "writable", WritableAccount::check(contributor))?;
+        check_account!(
+            5,
+            "writable",
+            WritableAccount::check(authority_token_account)
+        )?;
+        check_account!(6, "writable", WritableAccount::check(vault))?;
+        check_account!(2, "mint", MintInterface::check(mint_to_raise))?;
+        check_account!(
+            7,
+            "program id",
+            ProgramIdCheck::check(system_program, &pinocchio_system::ID)
+        )?;
+        check_account!(
+            9,
+            "program id",
+            ProgramIdCheck::check(
+                associated_token_program,
+                &pinocchio_associated_token_account::ID,
+            )
+        )?;
+        check_account!(3, "owner", ProgramAccount::check(fundraise))?;
+
+        let fundraise_data = fundraise.try_borrow_data()?;
+        let fundraise_state = Fundraise::load(&fundraise_data)?;
+
+        check_account!(
+            8,
+            "token program",
+            fundraise_state.check_token_program(token_program.key())
+        )?;
+        check_account!(6, "vault", fundraise_state.check_vault(vault.key()))?;
+        drop(fundraise_data);
+
+        check_account!(4, "owner", ProgramAccount::check(contributor))?;
+        check_account!(
+            5,
+            "address",
+            AssociatedTokenAccount::check_address(
+                authority_token_account,
+                authority,
+                mint_to_raise,
+                token_program,
+            )
+        )?;
+        check_account!(
+            6,
+            "mint and owner",
+            TokenAccountInterface::check_mint_and_owner(
+                vault,
+                mint_to_raise.key(),
+                fundraise.key()
+            )
+        )?;
+
+        let (transfer_hook_accounts, _) = TransferHook::split_trailing(mint_to_raise, rest)?;
+
+        Ok(Self {
+            maker,
+            authority,
+            mint_to_raise,
+            fundraise,
+            contributor,
+            authority_token_account,
+            vault,
+            system_program,
+            token_program,
+            associated_token_program,
+            transfer_hook_accounts,
+        })
+    }
+}
+
+/// Refund of `authority`'s contribution sent by the maker, for ejecting a contribution the
+/// campaign cannot keep. Unlike Refund it ignores the deadline and the target, and only stops
+/// working once the maker has claimed. The maker pays for the contributor's token account if it
+/// has to be created, while the contributor account's rent goes back to `authority`. Receipts
+/// can only be burned by their holder, so positions in a fundraise that issues them are left to
+/// Refund.
+pub struct ForceRefund<'a> {
+    pub accounts: ForceRefundAccounts<'a>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for ForceRefund<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let accounts = ForceRefundAccounts::try_from(accounts)?;
+
+        AssociatedTokenAccount::init_if_needed(
+            accounts.authority_token_account,
+            accounts.mint_to_raise,
+            accounts.maker,
+            accounts.authority,
+            accounts.system_program,
+            accounts.token_program,
+        )?;
+        AssociatedTokenAccount::check(
+            accounts.authority_token_account,
+            accounts.authority,
+            accounts.mint_to_raise,
+            accounts.token_program,
+        )?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl Handler for ForceRefund<'_> {
+    const DISCRIMINATOR: u8 = FundraiserInstruction::ForceRefund as u8;
+
+    fn process(&mut self) -> ProgramResult {
+        let mut fundraise_data = self.accounts.fundraise.try_borrow_mut_data()?;
+        let fundraise = Fundraise::load_mut(fundraise_data.as_mut())?;
+
+        let fundraise_maker = fundraise.maker;
+
+        if fundraise_maker != *self.accounts.maker.key() {
+            return Err(FundraiserError::InvalidAddress.into());
+        }
+
+        let fundraise_bump = [fundraise.bump];
+
+        ProgramAccount::validate(
+            &[Fundraise::PREFIX, &fundraise_maker, &fundraise_bump],
+            *self.accounts.fundraise.key(),
+        )?;
+        fundraise.check_mint_to_raise(self.accounts.mint_to_raise.key())?;
+
+        if fundraise.is_claimed() {
+            return Err(FundraiserError::AlreadyClaimed.into());
+        }
+
+        if fundraise.has_receipt() {
+            return Err(FundraiserError::ReceiptHolderMustRefund.into());
+        }
+
+        let contributor_data = self.accounts.contributor.try_borrow_data()?;
+        let contributor = Contributor::load(&contributor_data)?;
+
+        if contributor.authority != *self.accounts.authority.key() {
+            return Err(FundraiserError::InvalidAddress.into());
+        }
+
+        ProgramAccount::validate(
+            &[
+                Contributor::PREFIX,
+                self.accounts.fundraise.key(),
+                self.accounts.authority.key(),
+                &[contributor.bump],
+            ],
+            *self.accounts.contributor.key(),
+        )?;
+
+        let contributor_amount = contributor.get_amount();
+
+        // the position leaves any snapshot it was counted in
+        fundraise
+            .set_snapshot_total(fundraise.get_snapshot_total() - contributor.get_snapshot_amount());
+
+        if contributor.get_pending_hook_amount() > 0 {
+            fundraise.set_pending_hooks(fundraise.get_pending_hooks() - 1);
+        }
+
+        drop(contributor_data);
+        drop(fundraise_data);
+
+        // the vault stays open even if this empties it, since the campaign may carry on
+        vault_transfer(
+            Liability::Raised,
+            self.accounts.vault,
+            self.accounts.mint_to_raise,
+            self.accounts.authority_token_account,
+            contributor_amount,
+            self.accounts.fundraise,
+            self.accounts.transfer_hook_accounts,
+        )?;
+
+        ProgramAccount::close(self.accounts.contributor, self.accounts.authority)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use litesvm::LiteSVM;
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_keypair::Keypair;
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use spl_associated_token_account::{
+        get_associated_token_address_with_program_id,
+        solana_program::{clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL},
+    };
+    use spl_token_2022::state::Account;
+
+    use crate::{
+        AccountLoad, Fundraise, FundraiserError, client,
+        tests::{
+            constants::{
+                ASSOCIATED_TOKEN_PROGRAM_ID, MINT_DECIMALS, PROGRAM_ID, SYSTEM_PROGRAM_ID,
+                TOKEN_PROGRAM_ID,
+            },
+            pda::{get_config_pda, get_contributor_pda, get_fundraise_pda, get_vault_pda},
+            utils::{
+                assert_error, build_and_send_transaction, fetch_account, init_ata, init_mint,
+                init_wallet, setup,
+            },
+        },
+    };
+
+    const AMOUNT_TO_RAISE: u64 = 5_000_000;
+
+    fn initialize(litesvm: &mut LiteSVM, maker: &Keypair, mint_to_raise: Pubkey) -> Pubkey {
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+
+        let data = [
+            vec![0u8],
+            AMOUNT_TO_RAISE.to_le_bytes().to_vec(),
+            SECONDS_PER_DAY.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            10_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(get_vault_pda(&fundraise_pda), false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        build_and_send_transaction(litesvm, &[maker], &maker.pubkey(), &[ix]).unwrap();
+
+        fundraise_pda
+    }
+
+    fn contribute(
+        litesvm: &mut LiteSVM,
+        authority: &Keypair,
+        mint_to_raise: Pubkey,
+        fundraise_pda: Pubkey,
+        amount: u64,
+    ) {
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), amount);
+
+        let data = [vec![1u8], amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(
+                    get_contributor_pda(&fundraise_pda, &authority.pubkey()),
+                    false,
+                ),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(get_vault_pda(&fundraise_pda), false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        build_and_send_transaction(litesvm, &[authority], &authority.pubkey(), &[ix]).unwrap();
+    }
+
+    fn force_refund_ix(
+        maker: &Pubkey,
+        authority: &Pubkey,
+        mint_to_raise: Pubkey,
+        fundraise_pda: Pubkey,
+    ) -> Instruction {
+        Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(*maker, true),
+                AccountMeta::new(*authority, false),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(get_contributor_pda(&fundraise_pda, authority), false),
+                AccountMeta::new(
+                    get_associated_token_address_with_program_id(
+                        authority,
+                        &mint_to_raise,
+                        &TOKEN_PROGRAM_ID,
+                    ),
+                    false,
+                ),
+                AccountMeta::new(get_vault_pda(&fundraise_pda), false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            ],
+            data: vec![25u8],
+        }
+    }
+
+    #[test]
+    fn force_refund() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let other = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        let fundraise_pda = initialize(litesvm, &maker, mint_to_raise);
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        contribute(litesvm, &authority, mint_to_raise, fundraise_pda, 500_000);
+        contribute(litesvm, &other, mint_to_raise, fundraise_pda, 250_000);
+
+        let contributor_rent = litesvm.get_account(&contributor_pda).unwrap().lamports;
+        let pre_authority_lamports = litesvm.get_account(&authority.pubkey()).unwrap().lamports;
+        let pre_maker_lamports = litesvm.get_account(&maker.pubkey()).unwrap().lamports;
+
+        let ix = force_refund_ix(
+            &maker.pubkey(),
+            &authority.pubkey(),
+            mint_to_raise,
+            fundraise_pda,
+        );
+
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        assert!(litesvm.get_account(&contributor_pda).is_none());
+
+        // the contributor account's rent goes to its authority, not to the maker paying fees
+        let post_authority_lamports = litesvm.get_account(&authority.pubkey()).unwrap().lamports;
+        let post_maker_lamports = litesvm.get_account(&maker.pubkey()).unwrap().lamports;
+
+        assert_eq!(
+            post_authority_lamports,
+            pre_authority_lamports + contributor_rent
+        );
+        assert!(post_maker_lamports < pre_maker_lamports);
+
+        let authority_ata = get_associated_token_address_with_program_id(
+            &authority.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        assert_eq!(
+            fetch_account::<Account>(litesvm, &authority_ata).amount,
+            500_000
+        );
+
+        let fundraise_acc = litesvm.get_account(&fundraise_pda).unwrap();
+        let fundraise = Fundraise::load(fundraise_acc.data.as_ref()).unwrap();
+
+        assert_eq!(fundraise.get_current_amount(), 250_000);
+        assert_eq!(
+            fetch_account::<Account>(litesvm, &get_vault_pda(&fundraise_pda)).amount,
+            250_000
+        );
+    }
+
+    #[test]
+    fn force_refund_after_target_met() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        let fundraise_pda = initialize(litesvm, &maker, mint_to_raise);
+
+        contribute(
+            litesvm,
+            &authority,
+            mint_to_raise,
+            fundraise_pda,
+            AMOUNT_TO_RAISE,
+        );
+
+        let ix = force_refund_ix(
+            &maker.pubkey(),
+            &authority.pubkey(),
+            mint_to_raise,
+            fundraise_pda,
+        );
+
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let fundraise_acc = litesvm.get_account(&fundraise_pda).unwrap();
+        let fundraise = Fundraise::load(fundraise_acc.data.as_ref()).unwrap();
+
+        assert_eq!(fundraise.get_current_amount(), 0);
+        assert_eq!(
+            fetch_account::<Account>(litesvm, &get_vault_pda(&fundraise_pda)).amount,
+            0
+        );
+    }
+
+    #[test]
+    fn throw_if_fundraise_has_receipts() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let receipt_mint = Keypair::new();
+        init_ata(litesvm, mint_to_raise, authority.pubkey(), 500_000);
+
+        let ix = client::with_receipt_mint(
+            client::initialize(
+                &maker.pubkey(),
+                &mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+                5_000_000,
+                SECONDS_PER_DAY,
+            ),
+            &receipt_mint.pubkey(),
+        );
+        build_and_send_transaction(litesvm, &[&maker, &receipt_mint], &maker.pubkey(), &[ix])
+            .unwrap();
+        init_ata(litesvm, receipt_mint.pubkey(), authority.pubkey(), 0);
+
+        let ix = client::with_receipt(
+            client::contribute(
+                &authority.pubkey(),
+                &maker.pubkey(),
+                &mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+                500_000,
+            ),
+            &receipt_mint.pubkey(),
+            &authority.pubkey(),
+        );
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let ix = force_refund_ix(
+            &maker.pubkey(),
+            &authority.pubkey(),
+            mint_to_raise,
+            fundraise_pda,
+        );
+        let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::ReceiptHolderMustRefund);
+    }
+}
@@ -4,13 +4,18 @@ use pinocchio::{
     account_info::AccountInfo,
     instruction::Seed,
     program_error::ProgramError,
-    pubkey::find_program_address,
+    pubkey::{Pubkey, find_program_address},
     sysvars::{Sysvar, clock::Clock},
 };
+use pinocchio_system::instructions::Transfer;
 
 use crate::{
-    AccountCheck, AccountLoad, AssociatedTokenAccount, Fundraise, FundraiserError, Handler,
-    MIN_AMOUNT_TO_RAISE, MintInterface, Prefix, ProgramAccount, SignerAccount, Space,
+    AccountCheck, AccountLoad, CampaignType, Config, Fundraise, FundraiserError,
+    FundraiserInstruction, HOOK_MAX_ACCOUNTS, Handler, HookProgram, MAX_BPS, MAX_DURATION,
+    MAX_START_LEAD_TIME, MAX_TIERS, MIN_AMOUNT_TO_RAISE, MIN_DURATION, Mint2022Account,
+    MintInterface, Prefix, PresentFields, ProgramAccount, ProgramIdCheck, RefundPolicy, Registry,
+    RegistryParams, SignerAccount, Space, Stats, TokenAccountInterface, TokenInit, Trigger,
+    UpdateMetadataInstructionData, VAULT_SEED, Versioned, WritableAccount, check_account,
 };
 
 pub struct InitializeAccounts<'a> {
@@ -21,6 +26,17 @@ pub struct InitializeAccounts<'a> {
     pub system_program: &'a AccountInfo,
     pub token_program: &'a AccountInfo,
     pub associated_token_program: &'a AccountInfo,
+    /// Sets the bond the maker stakes.
+    pub config: &'a AccountInfo,
+    pub hook_program: Option<&'a AccountInfo>,
+    pub hook_accounts: &'a [AccountInfo],
+    pub trigger: Option<&'a AccountInfo>,
+    pub registry: Option<&'a AccountInfo>,
+    /// Signs for the creation of the fundraise's receipt mint at its address.
+    pub receipt_mint: Option<&'a AccountInfo>,
+    /// Funds the fundraise, vault and any trigger or registry in place of the maker.
+    pub rent_payer: Option<&'a AccountInfo>,
+    pub stats: Option<&'a AccountInfo>,
 }
 
 impl<'a> TryFrom<&'a [AccountInfo]> for InitializeAccounts<'a> {
@@ -35,13 +51,52 @@ impl<'a> TryFrom<&'a [AccountInfo]> for InitializeAccounts<'a> {
             system_program,
             token_program,
             associated_token_program,
+            config,
+            hook @ ..,
         ] = accounts
         else {
-            return Err(ProgramError::NotEnoughAccountKeys);
+            return Err(FundraiserError::MissingAccounts.into());
         };
 
-        SignerAccount::check(maker)?;
-        MintInterface::check(mint_to_raise)?;
+        check_account!(0, "signer", SignerAccount::check(maker))?;
+        check_account!(0, "writable", WritableAccount::check(maker))?;
+        check_account!(2, "writable", WritableAccount::check(fundraise))?;
+        check_account!(3, "writable", WritableAccount::check(vault))?;
+        check_account!(1, "mint", MintInterface::check(mint_to_raise))?;
+        check_account!(
+            4,
+            "program id",
+            ProgramIdCheck::check(system_program, &pinocchio_system::ID)
+        )?;
+        check_account!(
+            5,
+            "program id",
+            ProgramIdCheck::check(token_program, mint_to_raise.owner())
+        )?;
+        // The vault is no longer an associated token account, but the program stays in the
+        // account list so existing clients keep their layout.
+        check_account!(
+            6,
+            "program id",
+            ProgramIdCheck::check(
+                associated_token_program,
+                &pinocchio_associated_token_account::ID,
+            )
+        )?;
+        check_account!(7, "owner", ProgramAccount::check(config))?;
+
+        let (hook_program, hook_accounts) = match hook.split_first() {
+            Some((hook_program, hook_accounts)) => {
+                HookProgram::check(hook_program)?;
+
+                if hook_accounts.len() > HOOK_MAX_ACCOUNTS {
+                    return Err(FundraiserError::InvalidHookAccounts.into());
+                }
+
+                (Some(hook_program), hook_accounts)
+            }
+            None => (None, hook),
+        };
 
         Ok(Self {
             maker,
@@ -51,38 +106,514 @@ impl<'a> TryFrom<&'a [AccountInfo]> for InitializeAccounts<'a> {
             system_program,
             token_program,
             associated_token_program,
+            config,
+            hook_program,
+            hook_accounts,
+            trigger: None,
+            registry: None,
+            receipt_mint: None,
+            rent_payer: None,
+            stats: None,
         })
     }
 }
 
-pub struct InitializeInstructionData {
+pub struct InitializeInstructionData<'a> {
     pub amount_to_raise: u64,
     pub duration: u64,
+    pub completion_window: u64,
+    pub completion_threshold_bps: u16,
+    pub max_contribution_bps: u16,
+    pub min_contribution: u64,
+    pub allowlist: bool,
+    pub keeper_trigger: bool,
+    /// When contributions open, or 0 to open them straight away.
+    pub start_time: i64,
+    pub refund_policy: RefundPolicy,
+    pub campaign_type: CampaignType,
+    /// Root of the Merkle tree of allowed contributors, or all zeroes to allow anyone.
+    pub merkle_root: [u8; 32],
+    /// Whether to count the fundraise in the registry of its mint.
+    pub register: bool,
+    /// Ascending contribution totals at which each reward tier starts, with unused tiers 0
+    /// after the defined ones.
+    pub tier_thresholds: [u64; MAX_TIERS],
+    /// Mint contributors are paid rewards in, or all zeroes for none.
+    pub reward_mint: Pubkey,
+    /// Rewards shared between contributors pro rata once the maker claims, following the reward
+    /// mint.
+    pub reward_amount: u64,
+    /// Whether to mint contributors a non-transferable receipt token 1:1 with what they give.
+    pub receipt: bool,
+    /// Mint a contributor must hold at least one token of, or all zeroes to admit anyone.
+    pub gate_mint: Pubkey,
+    /// Whether the maker accepts a mint with a freeze authority, which Initialize otherwise
+    /// rejects.
+    pub accept_freezable: bool,
+    /// Signs the claim in the maker's place and receives its payout, or all zeroes for the
+    /// maker.
+    pub claim_authority: Pubkey,
+    /// Name of the fundraise, encoded after the claim authority as in UpdateMetadata. Not part
+    /// of [`Self::to_bytes`].
+    pub name: &'a [u8],
+    /// URI of the fundraise's off-chain metadata, following the name. Not part of
+    /// [`Self::to_bytes`].
+    pub uri: &'a [u8],
+}
+
+impl<'a> InitializeInstructionData<'a> {
+    pub const LEN: usize = size_of::<u64>() * 4
+        + size_of::<u16>() * 2
+        + size_of::<u8>() * 5
+        + size_of::<i64>()
+        + size_of::<[u8; 32]>()
+        + size_of::<[u64; MAX_TIERS]>()
+        + size_of::<Pubkey>()
+        + size_of::<u64>()
+        + size_of::<u8>()
+        + size_of::<Pubkey>()
+        + size_of::<u8>()
+        + size_of::<Pubkey>();
+
+    /// The fields every layout starts with, up to the minimum contribution.
+    pub const REQUIRED_LEN: usize = size_of::<u64>() * 4 + size_of::<u16>() * 2;
+
+    // The presence flags leading a version 1 payload. Each boolean field is its flag alone, and
+    // each other field follows the required ones only when flagged, in the order listed.
+    pub const ALLOWLIST: u16 = 1 << 0;
+    pub const KEEPER_TRIGGER: u16 = 1 << 1;
+    pub const START_TIME: u16 = 1 << 2;
+    pub const REFUND_POLICY: u16 = 1 << 3;
+    pub const CAMPAIGN_TYPE: u16 = 1 << 4;
+    pub const MERKLE_ROOT: u16 = 1 << 5;
+    pub const REGISTER: u16 = 1 << 6;
+    pub const TIER_THRESHOLDS: u16 = 1 << 7;
+    /// The reward mint and amount, which come together.
+    pub const REWARDS: u16 = 1 << 8;
+    pub const RECEIPT: u16 = 1 << 9;
+    pub const GATE_MINT: u16 = 1 << 10;
+    pub const ACCEPT_FREEZABLE: u16 = 1 << 11;
+    pub const CLAIM_AUTHORITY: u16 = 1 << 12;
+    /// The name and URI, encoded as in UpdateMetadata, which take the rest of the payload.
+    pub const METADATA: u16 = 1 << 13;
+
+    const KNOWN: u16 = (Self::METADATA << 1) - 1;
+
+    /// A fundraise raising `amount_to_raise` over `duration`, with every optional field left
+    /// at its default.
+    pub const fn new(amount_to_raise: u64, duration: u64) -> Self {
+        Self {
+            amount_to_raise,
+            duration,
+            completion_window: 0,
+            completion_threshold_bps: 0,
+            max_contribution_bps: 0,
+            min_contribution: 0,
+            allowlist: false,
+            keeper_trigger: false,
+            start_time: 0,
+            refund_policy: RefundPolicy::Flexible,
+            campaign_type: CampaignType::AllOrNothing,
+            merkle_root: [0; 32],
+            register: false,
+            tier_thresholds: [0; MAX_TIERS],
+            reward_mint: [0; 32],
+            reward_amount: 0,
+            receipt: false,
+            gate_mint: [0; 32],
+            accept_freezable: false,
+            claim_authority: [0; 32],
+            name: &[],
+            uri: &[],
+        }
+    }
+
+    pub fn to_bytes(&self) -> [u8; InitializeInstructionData::LEN] {
+        let mut bytes = [0u8; InitializeInstructionData::LEN];
+
+        bytes[0..8].copy_from_slice(&self.amount_to_raise.to_le_bytes());
+        bytes[8..16].copy_from_slice(&self.duration.to_le_bytes());
+        bytes[16..24].copy_from_slice(&self.completion_window.to_le_bytes());
+        bytes[24..26].copy_from_slice(&self.completion_threshold_bps.to_le_bytes());
+        bytes[26..28].copy_from_slice(&self.max_contribution_bps.to_le_bytes());
+        bytes[28..36].copy_from_slice(&self.min_contribution.to_le_bytes());
+        bytes[36] = u8::from(self.allowlist);
+        bytes[37] = u8::from(self.keeper_trigger);
+        bytes[38..46].copy_from_slice(&self.start_time.to_le_bytes());
+        bytes[46] = self.refund_policy as u8;
+        bytes[47] = self.campaign_type as u8;
+        bytes[48..80].copy_from_slice(&self.merkle_root);
+        bytes[80] = u8::from(self.register);
+
+        for (i, threshold) in self.tier_thresholds.iter().enumerate() {
+            bytes[81 + i * 8..89 + i * 8].copy_from_slice(&threshold.to_le_bytes());
+        }
+
+        bytes[113..145].copy_from_slice(&self.reward_mint);
+        bytes[145..153].copy_from_slice(&self.reward_amount.to_le_bytes());
+        bytes[153] = u8::from(self.receipt);
+        bytes[154..186].copy_from_slice(&self.gate_mint);
+        bytes[186] = u8::from(self.accept_freezable);
+        bytes[187..219].copy_from_slice(&self.claim_authority);
+
+        bytes
+    }
+
+    /// The version 1 payload, led by its version byte, with the name and URI included. Fields
+    /// left at their default are left out.
+    pub fn to_v1_bytes(&self) -> Vec<u8> {
+        let v0 = self.to_bytes();
+        let mut flags = 0;
+        let mut fields = Vec::new();
+        let mut field = |flag: u16, present: bool, bytes: &[u8]| {
+            if present {
+                flags |= flag;
+                fields.extend_from_slice(bytes);
+            }
+        };
+
+        field(Self::ALLOWLIST, self.allowlist, &[]);
+        field(Self::KEEPER_TRIGGER, self.keeper_trigger, &[]);
+        field(
+            Self::START_TIME,
+            self.start_time != 0,
+            &self.start_time.to_le_bytes(),
+        );
+        field(
+            Self::REFUND_POLICY,
+            self.refund_policy != RefundPolicy::Flexible,
+            &[self.refund_policy as u8],
+        );
+        field(
+            Self::CAMPAIGN_TYPE,
+            self.campaign_type != CampaignType::AllOrNothing,
+            &[self.campaign_type as u8],
+        );
+        field(
+            Self::MERKLE_ROOT,
+            self.merkle_root != [0; 32],
+            &self.merkle_root,
+        );
+        field(Self::REGISTER, self.register, &[]);
+        field(
+            Self::TIER_THRESHOLDS,
+            self.tier_thresholds != [0; MAX_TIERS],
+            &v0[81..113],
+        );
+        field(
+            Self::REWARDS,
+            self.reward_mint != Pubkey::default() || self.reward_amount != 0,
+            &v0[113..153],
+        );
+        field(Self::RECEIPT, self.receipt, &[]);
+        field(
+            Self::GATE_MINT,
+            self.gate_mint != Pubkey::default(),
+            &self.gate_mint,
+        );
+        field(Self::ACCEPT_FREEZABLE, self.accept_freezable, &[]);
+        field(
+            Self::CLAIM_AUTHORITY,
+            self.claim_authority != Pubkey::default(),
+            &self.claim_authority,
+        );
+
+        let (metadata, len) = UpdateMetadataInstructionData {
+            name: self.name,
+            uri: self.uri,
+        }
+        .to_bytes();
+        field(
+            Self::METADATA,
+            !self.name.is_empty() || !self.uri.is_empty(),
+            &metadata[..len],
+        );
+
+        let mut bytes = vec![Versioned::V1];
+        bytes.extend_from_slice(&flags.to_le_bytes());
+        bytes.extend_from_slice(&v0[..Self::REQUIRED_LEN]);
+        bytes.extend_from_slice(&fields);
+
+        bytes
+    }
+
+    /// Reads a version 1 payload, from the presence flags on, without checking the fields
+    /// against each other.
+    pub(crate) fn from_v1(data: &'a [u8]) -> Result<Self, ProgramError> {
+        let mut fields = PresentFields::new(data, Self::KNOWN)?;
+
+        let amount_to_raise = u64::from_le_bytes(*fields.required()?);
+        let duration = u64::from_le_bytes(*fields.required()?);
+        let completion_window = u64::from_le_bytes(*fields.required()?);
+        let completion_threshold_bps = u16::from_le_bytes(*fields.required()?);
+        let max_contribution_bps = u16::from_le_bytes(*fields.required()?);
+        let min_contribution = u64::from_le_bytes(*fields.required()?);
+
+        let start_time = fields
+            .next(Self::START_TIME)?
+            .map_or(0, |&bytes| i64::from_le_bytes(bytes));
+        let refund_policy = match fields.next::<1>(Self::REFUND_POLICY)? {
+            Some(&[policy]) => RefundPolicy::try_from(policy)?,
+            None => RefundPolicy::Flexible,
+        };
+        let campaign_type = match fields.next::<1>(Self::CAMPAIGN_TYPE)? {
+            Some(&[campaign_type]) => CampaignType::try_from(campaign_type)?,
+            None => CampaignType::AllOrNothing,
+        };
+        let merkle_root = fields.next(Self::MERKLE_ROOT)?.copied().unwrap_or_default();
+
+        let mut tier_thresholds = [0u64; MAX_TIERS];
+
+        if let Some(tiers) =
+            fields.next::<{ size_of::<[u64; MAX_TIERS]>() }>(Self::TIER_THRESHOLDS)?
+        {
+            for (threshold, bytes) in tier_thresholds.iter_mut().zip(tiers.chunks_exact(8)) {
+                *threshold = u64::from_le_bytes(bytes.try_into().unwrap());
+            }
+        }
+
+        let (reward_mint, reward_amount) = match fields.next::<40>(Self::REWARDS)? {
+            Some(rewards) => {
+                let (mint, amount) = rewards.split_at(size_of::<Pubkey>());
+
+                (
+                    mint.try_into().unwrap(),
+                    u64::from_le_bytes(amount.try_into().unwrap()),
+                )
+            }
+            None => (Pubkey::default(), 0),
+        };
+        let gate_mint = fields.next(Self::GATE_MINT)?.copied().unwrap_or_default();
+        let claim_authority = fields
+            .next(Self::CLAIM_AUTHORITY)?
+            .copied()
+            .unwrap_or_default();
+
+        // the metadata takes the rest of the payload, and nothing may follow the last field
+        // flagged otherwise
+        let (name, uri) = match (fields.flag(Self::METADATA), fields.rest()) {
+            (false, []) => (&[][..], &[][..]),
+            (true, metadata) => {
+                let metadata = UpdateMetadataInstructionData::try_from(metadata)?;
+
+                (metadata.name, metadata.uri)
+            }
+            (false, _) => return Err(ProgramError::InvalidInstructionData),
+        };
+
+        Ok(Self {
+            amount_to_raise,
+            duration,
+            completion_window,
+            completion_threshold_bps,
+            max_contribution_bps,
+            min_contribution,
+            allowlist: fields.flag(Self::ALLOWLIST),
+            keeper_trigger: fields.flag(Self::KEEPER_TRIGGER),
+            start_time,
+            refund_policy,
+            campaign_type,
+            merkle_root,
+            register: fields.flag(Self::REGISTER),
+            tier_thresholds,
+            reward_mint,
+            reward_amount,
+            receipt: fields.flag(Self::RECEIPT),
+            gate_mint,
+            accept_freezable: fields.flag(Self::ACCEPT_FREEZABLE),
+            claim_authority,
+            name,
+            uri,
+        })
+    }
+
+    /// Rejects fields that contradict each other, whichever layout they were read from.
+    fn checked(self) -> Result<Self, ProgramError> {
+        // defined tiers come first, each starting above the last
+        let tiers_ascending = self
+            .tier_thresholds
+            .windows(2)
+            .all(|pair| pair[1] == 0 || (pair[0] != 0 && pair[0] < pair[1]));
+
+        // a keep-it-all fundraise never fails, so locked contributions could never be refunded
+        if self.campaign_type == CampaignType::KeepItAll
+            && self.refund_policy == RefundPolicy::Locked
+        {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        if self.completion_threshold_bps > MAX_BPS
+            || self.completion_window > self.duration
+            || self.max_contribution_bps > MAX_BPS
+            || !tiers_ascending
+            || (self.reward_mint == Pubkey::default()) != (self.reward_amount == 0)
+        {
+            return Err(FundraiserError::InvalidAmount.into());
+        }
+
+        Ok(self)
+    }
 }
 
-impl<'a> TryFrom<&'a [u8]> for InitializeInstructionData {
+impl<'a> TryFrom<&'a [u8]> for InitializeInstructionData<'a> {
     type Error = ProgramError;
 
     fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
-        if data.len() != size_of::<u64>() + size_of::<u64>() {
+        let Some((data, trailing)) = data.split_at_checked(Self::REQUIRED_LEN) else {
             return Err(ProgramError::InvalidInstructionData);
+        };
+
+        // Every field after the required ones was appended later and is optional so existing
+        // clients keep their payload, but only ever in the order it was added: each is read in
+        // full or left out along with everything after it. A name and URI can only follow every
+        // other field.
+        let mut fields = OptionalFields(trailing);
+
+        let allowlist = fields.flag()?;
+        let keeper_trigger = fields.flag()?;
+        let start_time = fields.next()?.map_or(0, |&bytes| i64::from_le_bytes(bytes));
+        let refund_policy = match fields.next::<1>()? {
+            Some(&[policy]) => RefundPolicy::try_from(policy)?,
+            None => RefundPolicy::Flexible,
+        };
+        let campaign_type = match fields.next::<1>()? {
+            Some(&[campaign_type]) => CampaignType::try_from(campaign_type)?,
+            None => CampaignType::AllOrNothing,
+        };
+        let merkle_root = fields.next()?.copied().unwrap_or_default();
+        let register = fields.flag()?;
+
+        let mut tier_thresholds = [0u64; MAX_TIERS];
+
+        if let Some(tiers) = fields.next::<{ size_of::<[u64; MAX_TIERS]>() }>()? {
+            for (threshold, bytes) in tier_thresholds.iter_mut().zip(tiers.chunks_exact(8)) {
+                *threshold = u64::from_le_bytes(bytes.try_into().unwrap());
+            }
         }
 
+        // the reward mint and amount come together
+        let (reward_mint, reward_amount) =
+            match fields.next::<{ size_of::<Pubkey>() + size_of::<u64>() }>()? {
+                Some(rewards) => {
+                    let (mint, amount) = rewards.split_at(size_of::<Pubkey>());
+
+                    (
+                        mint.try_into().unwrap(),
+                        u64::from_le_bytes(amount.try_into().unwrap()),
+                    )
+                }
+                None => (Pubkey::default(), 0),
+            };
+        let receipt = fields.flag()?;
+        let gate_mint = fields.next()?.copied().unwrap_or_default();
+        let accept_freezable = fields.flag()?;
+        let claim_authority = fields.next()?.copied().unwrap_or_default();
+
+        let (name, uri) = match fields.0 {
+            [] => (&[][..], &[][..]),
+            metadata => {
+                let metadata = UpdateMetadataInstructionData::try_from(metadata)?;
+
+                (metadata.name, metadata.uri)
+            }
+        };
+
         let offset = size_of::<u64>();
         let amount_to_raise = u64::from_le_bytes(data[0..offset].try_into().unwrap());
         let duration =
             u64::from_le_bytes(data[offset..offset + size_of::<u64>()].try_into().unwrap());
 
-        Ok(Self {
+        let offset = offset + size_of::<u64>();
+        let completion_window =
+            u64::from_le_bytes(data[offset..offset + size_of::<u64>()].try_into().unwrap());
+
+        let offset = offset + size_of::<u64>();
+        let completion_threshold_bps =
+            u16::from_le_bytes(data[offset..offset + size_of::<u16>()].try_into().unwrap());
+
+        let offset = offset + size_of::<u16>();
+        let max_contribution_bps =
+            u16::from_le_bytes(data[offset..offset + size_of::<u16>()].try_into().unwrap());
+
+        let offset = offset + size_of::<u16>();
+        let min_contribution =
+            u64::from_le_bytes(data[offset..offset + size_of::<u64>()].try_into().unwrap());
+
+        Self {
             amount_to_raise,
             duration,
-        })
+            completion_window,
+            completion_threshold_bps,
+            max_contribution_bps,
+            min_contribution,
+            allowlist,
+            keeper_trigger,
+            start_time,
+            refund_policy,
+            campaign_type,
+            merkle_root,
+            register,
+            tier_thresholds,
+            reward_mint,
+            reward_amount,
+            receipt,
+            gate_mint,
+            accept_freezable,
+            claim_authority,
+            name,
+            uri,
+        }
+        .checked()
+    }
+}
+
+impl<'a> TryFrom<Versioned<'a>> for InitializeInstructionData<'a> {
+    type Error = ProgramError;
+
+    fn try_from(data: Versioned<'a>) -> Result<Self, Self::Error> {
+        match data.split()? {
+            (Versioned::V0, payload) => Self::try_from(payload),
+            (Versioned::V1, payload) => Self::from_v1(payload)?.checked(),
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+}
+
+/// The optional fields trailing Initialize's required ones, consumed front to back.
+struct OptionalFields<'a>(&'a [u8]);
+
+impl<'a> OptionalFields<'a> {
+    /// The next `N`-byte field, or `None` once the data runs out. A field cut short is
+    /// rejected.
+    #[inline(always)]
+    fn next<const N: usize>(&mut self) -> Result<Option<&'a [u8; N]>, ProgramError> {
+        if self.0.is_empty() {
+            return Ok(None);
+        }
+
+        let (field, rest) = self
+            .0
+            .split_first_chunk::<N>()
+            .ok_or(ProgramError::InvalidInstructionData)?;
+        self.0 = rest;
+
+        Ok(Some(field))
+    }
+
+    /// The next one-byte boolean field, unset once the data runs out.
+    #[inline(always)]
+    fn flag(&mut self) -> Result<bool, ProgramError> {
+        match self.next::<1>()? {
+            None | Some([0]) => Ok(false),
+            Some([1]) => Ok(true),
+            Some(_) => Err(ProgramError::InvalidInstructionData),
+        }
     }
 }
 
 pub struct Initialize<'a> {
     pub accounts: InitializeAccounts<'a>,
-    pub data: InitializeInstructionData,
+    pub data: InitializeInstructionData<'a>,
     pub bump: u8,
 }
 
@@ -90,8 +621,63 @@ impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for Initialize<'a> {
     type Error = ProgramError;
 
     fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
-        let accounts = InitializeAccounts::try_from(accounts)?;
-        let data = InitializeInstructionData::try_from(data)?;
+        Self::new(InitializeInstructionData::try_from(data)?, accounts)
+    }
+}
+
+impl<'a> TryFrom<(Versioned<'a>, &'a [AccountInfo])> for Initialize<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (Versioned<'a>, &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        Self::new(InitializeInstructionData::try_from(data)?, accounts)
+    }
+}
+
+impl<'a> Initialize<'a> {
+    /// Splits off the optional accounts `data` asks for and creates the fundraise and its vault.
+    pub fn new(
+        data: InitializeInstructionData<'a>,
+        accounts: &'a [AccountInfo],
+    ) -> Result<Self, ProgramError> {
+        // A requested keeper trigger is passed after any hook accounts, followed by a requested
+        // registry, a requested receipt mint and the Token-2022 program, any rent payer and then
+        // the stats account.
+        let (stats, accounts) = Stats::split_trailing(accounts);
+        let (rent_payer, accounts) = SignerAccount::split_rent_payer(accounts)?;
+        let (receipt_mint, accounts) = match data.receipt {
+            true => match accounts.split_last_chunk() {
+                Some((accounts, [receipt_mint, token_program])) => {
+                    ProgramIdCheck::check(token_program, &pinocchio_token_2022::ID)?;
+
+                    (Some(receipt_mint), accounts)
+                }
+                None => return Err(FundraiserError::MissingAccounts.into()),
+            },
+            false => (None, accounts),
+        };
+        let (registry, accounts) = match data.register {
+            true => accounts
+                .split_last()
+                .map(|(registry, accounts)| (Some(registry), accounts))
+                .ok_or(FundraiserError::MissingAccounts)?,
+            false => (None, accounts),
+        };
+        let (trigger, accounts) = match data.keeper_trigger {
+            true => accounts
+                .split_last()
+                .map(|(trigger, accounts)| (Some(trigger), accounts))
+                .ok_or(FundraiserError::MissingAccounts)?,
+            false => (None, accounts),
+        };
+
+        let mut accounts = InitializeAccounts::try_from(accounts)?;
+        accounts.trigger = trigger;
+        accounts.registry = registry;
+        accounts.receipt_mint = receipt_mint;
+        accounts.rent_payer = rent_payer;
+        accounts.stats = stats;
+
+        let payer = rent_payer.unwrap_or(accounts.maker);
 
         let (pda, bump) =
             find_program_address(&[Fundraise::PREFIX, accounts.maker.key()], &crate::ID);
@@ -100,6 +686,13 @@ impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for Initialize<'a> {
             return Err(FundraiserError::InvalidAddress.into());
         }
 
+        // a maker runs one fundraise at a time, and only once it is closed may they start another
+        if accounts.fundraise.owner() == &crate::ID
+            && accounts.fundraise.data_len() == Fundraise::LEN
+        {
+            return Err(FundraiserError::FundraiseAlreadyExists.into());
+        }
+
         let fundraise_bump = [bump];
         let fundraise_seeds = [
             Seed::from(Fundraise::PREFIX),
@@ -107,22 +700,101 @@ impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for Initialize<'a> {
             Seed::from(&fundraise_bump),
         ];
 
-        ProgramAccount::init::<Fundraise>(
+        ProgramAccount::init_with_payer::<Fundraise>(
+            accounts.rent_payer,
             accounts.maker,
             accounts.fundraise,
             &fundraise_seeds,
-            Fundraise::LEN,
         )?;
 
-        AssociatedTokenAccount::init(
+        let (pda, vault_bump) =
+            find_program_address(&[VAULT_SEED, accounts.fundraise.key()], &crate::ID);
+
+        if pda != *accounts.vault.key() {
+            return Err(FundraiserError::InvalidAddress.into());
+        }
+
+        let vault_bump = [vault_bump];
+        let vault_seeds = [
+            Seed::from(VAULT_SEED),
+            Seed::from(accounts.fundraise.key().as_ref()),
+            Seed::from(&vault_bump),
+        ];
+
+        TokenAccountInterface::init(
             accounts.vault,
             accounts.mint_to_raise,
-            accounts.maker,
-            accounts.fundraise,
-            accounts.system_program,
-            accounts.token_program,
+            payer,
+            accounts.fundraise.key(),
+            &vault_seeds,
         )?;
 
+        if let Some(trigger) = accounts.trigger {
+            WritableAccount::check(trigger)?;
+
+            let (pda, bump) =
+                find_program_address(&[Trigger::PREFIX, accounts.fundraise.key()], &crate::ID);
+
+            if pda != *trigger.key() {
+                return Err(FundraiserError::InvalidAddress.into());
+            }
+
+            let trigger_bump = [bump];
+            let trigger_seeds = [
+                Seed::from(Trigger::PREFIX),
+                Seed::from(accounts.fundraise.key().as_ref()),
+                Seed::from(&trigger_bump),
+            ];
+
+            ProgramAccount::init_with_payer::<Trigger>(
+                accounts.rent_payer,
+                accounts.maker,
+                trigger,
+                &trigger_seeds,
+            )?;
+        }
+
+        // the first registered fundraise of a mint creates its registry
+        if let Some(registry) = accounts.registry {
+            WritableAccount::check(registry)?;
+
+            let (pda, bump) = find_program_address(
+                &[Registry::PREFIX, accounts.mint_to_raise.key()],
+                &crate::ID,
+            );
+
+            if pda != *registry.key() {
+                return Err(FundraiserError::InvalidAddress.into());
+            }
+
+            let registry_bump = [bump];
+            let registry_seeds = [
+                Seed::from(Registry::PREFIX),
+                Seed::from(accounts.mint_to_raise.key().as_ref()),
+                Seed::from(&registry_bump),
+            ];
+
+            ProgramAccount::init_if_needed::<Registry>(
+                &registry_seeds,
+                registry,
+                payer,
+                RegistryParams::new(*accounts.mint_to_raise.key(), bump),
+            )?;
+        }
+
+        // receipts share the decimals of the mint they are issued 1:1 against
+        if let Some(receipt_mint) = accounts.receipt_mint {
+            SignerAccount::check(receipt_mint)?;
+            WritableAccount::check(receipt_mint)?;
+
+            Mint2022Account::init_non_transferable(
+                receipt_mint,
+                payer,
+                MintInterface::decimals(accounts.mint_to_raise)?,
+                accounts.fundraise.key(),
+            )?;
+        }
+
         Ok(Self {
             accounts,
             data,
@@ -132,10 +804,33 @@ impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for Initialize<'a> {
 }
 
 // impl<'a> Initialize<'a> {
-impl<'a> Handler<'a> for Initialize<'a> {
-    const DISCRIMINATOR: &'a u8 = &0;
+impl Handler for Initialize<'_> {
+    const DISCRIMINATOR: u8 = FundraiserInstruction::Initialize as u8;
 
     fn process(&mut self) -> ProgramResult {
+        // the bond sits on top of the fundraise's rent, so paying it back out never touches
+        // rent exemption
+        let bond = {
+            let config_data = self.accounts.config.try_borrow_data()?;
+            let config = Config::load(&config_data)?;
+
+            ProgramAccount::validate(
+                &[Config::PREFIX, &[config.bump]],
+                *self.accounts.config.key(),
+            )?;
+
+            config.get_bond_lamports()
+        };
+
+        if bond > 0 {
+            Transfer {
+                from: self.accounts.maker,
+                to: self.accounts.fundraise,
+                lamports: bond,
+            }
+            .invoke()?;
+        }
+
         let mut data = self.accounts.fundraise.try_borrow_mut_data()?;
         let fundraise = Fundraise::load_mut(data.as_mut())?;
 
@@ -156,23 +851,115 @@ impl<'a> Handler<'a> for Initialize<'a> {
                 };
                 mint.decimals()
             }
-            _ => return Err(ProgramError::IncorrectProgramId),
+            _ => return Err(FundraiserError::InvalidTokenProgram.into()),
         };
 
+        // a freeze authority could freeze the vault, and with it every refund and claim
+        if !self.data.accept_freezable
+            && MintInterface::freeze_authority(self.accounts.mint_to_raise)?.is_some()
+        {
+            return Err(FundraiserError::MintHasFreezeAuthority.into());
+        }
+
         if self.data.amount_to_raise <= u64::from(MIN_AMOUNT_TO_RAISE).pow(decimals as u32) {
             return Err(FundraiserError::BelowMinRaiseAmount.into());
         }
 
+        if self.data.duration < MIN_DURATION {
+            return Err(FundraiserError::DurationTooShort.into());
+        }
+
+        if self.data.duration > MAX_DURATION {
+            return Err(FundraiserError::DurationTooLong.into());
+        }
+
         let now = Clock::get()?.unix_timestamp;
 
+        // a start time already passed, as from a client with a lagging clock, starts now
+        let time_started = match self.data.start_time {
+            0 => now,
+            start_time if start_time.abs_diff(now) > MAX_START_LEAD_TIME => {
+                return Err(FundraiserError::InvalidAmount.into());
+            }
+            start_time => start_time.max(now),
+        };
+        let end_time = time_started
+            .checked_add_unsigned(self.data.duration)
+            .ok_or(FundraiserError::MathOverflow)?;
+
         fundraise.set_inner(
             *self.accounts.maker.key(),
             *self.accounts.mint_to_raise.key(),
             self.data.amount_to_raise,
-            now,
+            time_started,
             self.data.duration,
             self.bump,
         );
+        fundraise.set_end_time(end_time);
+        fundraise.set_completion(
+            self.data.completion_window,
+            self.data.completion_threshold_bps,
+        );
+        fundraise.mint_decimals = decimals;
+        fundraise.set_max_contribution_bps(self.data.max_contribution_bps);
+        fundraise.set_min_contribution(self.data.min_contribution);
+        fundraise.set_metadata(self.data.name, self.data.uri);
+        fundraise.set_tier_thresholds(self.data.tier_thresholds);
+        fundraise.set_bond(bond);
+        fundraise.set_rewards(self.data.reward_mint, self.data.reward_amount);
+
+        if let Some(receipt_mint) = self.accounts.receipt_mint {
+            fundraise.receipt_mint = *receipt_mint.key();
+        }
+
+        fundraise.gate_mint = self.data.gate_mint;
+
+        if self.data.claim_authority != Pubkey::default() {
+            fundraise.claim_authority = self.data.claim_authority;
+        }
+
+        fundraise.allowlist = u8::from(self.data.allowlist);
+        fundraise.accept_freezable = u8::from(self.data.accept_freezable);
+        fundraise.refund_policy = self.data.refund_policy as u8;
+        fundraise.campaign_type = self.data.campaign_type as u8;
+        fundraise.merkle_root = self.data.merkle_root;
+        fundraise.token_program = *self.accounts.mint_to_raise.owner();
+        fundraise.vault = *self.accounts.vault.key();
+
+        if let Some(trigger) = self.accounts.trigger {
+            let mut trigger_data = trigger.try_borrow_mut_data()?;
+            let trigger = Trigger::load_mut(trigger_data.as_mut())?;
+
+            trigger.set_end_time(fundraise.get_end_time());
+            trigger.fired = 0;
+        }
+
+        if let Some(registry) = self.accounts.registry {
+            let mut registry_data = registry.try_borrow_mut_data()?;
+
+            Registry::load_mut(registry_data.as_mut())?
+                .record_created(*self.accounts.fundraise.key())?;
+            fundraise.registered = 1;
+        }
+
+        if let Some(hook_program) = self.accounts.hook_program {
+            let mut template = [(Pubkey::default(), false); HOOK_MAX_ACCOUNTS];
+
+            for (entry, account) in template.iter_mut().zip(self.accounts.hook_accounts) {
+                *entry = (*account.key(), account.is_writable());
+            }
+
+            fundraise.set_hook(
+                *hook_program.key(),
+                &template[..self.accounts.hook_accounts.len()],
+            );
+        }
+
+        if let Some(stats) = self.accounts.stats {
+            let payer = self.accounts.rent_payer.unwrap_or(self.accounts.maker);
+
+            Stats::update(stats, payer, Stats::record_fundraiser_created)?;
+        }
 
         Ok(())
     }
@@ -182,21 +969,27 @@ impl<'a> Handler<'a> for Initialize<'a> {
 mod tests {
     use solana_clock::Clock;
     use solana_instruction::{AccountMeta, Instruction};
+    use solana_pubkey::Pubkey;
     use solana_signer::Signer;
     use spl_associated_token_account::{
         get_associated_token_address_with_program_id,
         solana_program::{clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL},
     };
+    use spl_token_2022::state::Account;
 
     use crate::{
-        AccountLoad, Fundraise, FundraiserError, MIN_AMOUNT_TO_RAISE,
+        AccountLoad, Contributor, Fundraise, FundraiserError, MAX_BPS, MAX_DURATION,
+        MAX_START_LEAD_TIME, MIN_AMOUNT_TO_RAISE, MIN_DURATION, Space, client, normalize,
         tests::{
             constants::{
-                ASSOCIATED_TOKEN_PROGRAM_ID, MINT_DECIMALS, PROGRAM_ID, SYSTEM_PROGRAM_ID,
-                TOKEN_PROGRAM_ID,
+                ASSOCIATED_TOKEN_PROGRAM_ID, FEE_COLLECTOR, MINT_DECIMALS, PROGRAM_ID,
+                SYSTEM_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID,
+            },
+            pda::{get_config_pda, get_contributor_pda, get_fundraise_pda, get_vault_pda},
+            utils::{
+                assert_error, build_and_send_transaction, fetch_account, forward_time, init_ata,
+                init_config, init_mint, init_wallet, set_freeze_authority, setup,
             },
-            pda::get_fundraise_pda,
-            utils::{assert_error, build_and_send_transaction, init_mint, init_wallet, setup},
         },
     };
 
@@ -209,17 +1002,17 @@ mod tests {
         let amount_to_raise: u64 = 5_000_000;
         let duration: u64 = SECONDS_PER_DAY; // 1 day
         let fundraise_pda = get_fundraise_pda(&maker.pubkey());
-        let vault = get_associated_token_address_with_program_id(
-            &fundraise_pda,
-            &mint_to_raise,
-            &TOKEN_PROGRAM_ID,
-        );
+        let vault = get_vault_pda(&fundraise_pda);
         let now = litesvm.get_sysvar::<Clock>().unix_timestamp;
 
         let data = [
             vec![0u8],
             amount_to_raise.to_le_bytes().to_vec(),
             duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
         ]
         .concat();
         let ix = Instruction {
@@ -232,6 +1025,7 @@ mod tests {
                 AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
                 AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
                 AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
             ],
             data,
         };
@@ -246,17 +1040,27 @@ mod tests {
         assert_eq!(fundraise.get_amount_to_raise(), amount_to_raise);
         assert_eq!(fundraise.get_time_started(), now);
         assert_eq!(fundraise.get_duration(), duration);
+        assert_eq!(fundraise.mint_decimals, MINT_DECIMALS);
+        assert_eq!(fundraise.get_min_contribution(), 0);
+        assert_eq!(fundraise.token_program, TOKEN_PROGRAM_ID.to_bytes());
+        assert_eq!(fundraise.vault, vault.to_bytes());
+
+        let vault_acc = fetch_account::<Account>(litesvm, &vault);
+
+        assert_eq!(vault_acc.mint, mint_to_raise);
+        assert_eq!(vault_acc.owner, fundraise_pda);
     }
 
     #[test]
-    fn throw_if_below_min_raise_amount() {
+    fn throw_if_vault_not_pda() {
         let (litesvm, _default_payer) = &mut setup();
         let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
         let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
 
-        let amount_to_raise: u64 = u64::from(MIN_AMOUNT_TO_RAISE) - 1;
+        let amount_to_raise: u64 = 5_000_000;
         let duration: u64 = SECONDS_PER_DAY; // 1 day
         let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        // the fundraise's associated token account, which earlier versions used as the vault
         let vault = get_associated_token_address_with_program_id(
             &fundraise_pda,
             &mint_to_raise,
@@ -267,6 +1071,10 @@ mod tests {
             vec![0u8],
             amount_to_raise.to_le_bytes().to_vec(),
             duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
         ]
         .concat();
         let ix = Instruction {
@@ -279,12 +1087,811 @@ mod tests {
                 AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
                 AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
                 AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
             ],
             data,
         };
 
         let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
 
-        assert_error(res.unwrap_err(), FundraiserError::BelowMinRaiseAmount);
+        assert_error(res.unwrap_err(), FundraiserError::InvalidAddress);
+    }
+
+    #[test]
+    fn initialize_with_token_2022() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_2022_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+        let now = litesvm.get_sysvar::<Clock>().unix_timestamp;
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let fundraise_acc = litesvm.get_account(&fundraise_pda).unwrap();
+        let fundraise = Fundraise::load(fundraise_acc.data.as_ref()).unwrap();
+
+        assert_eq!(fundraise.maker, maker.pubkey().to_bytes());
+        assert_eq!(fundraise.mint_to_raise, mint_to_raise.to_bytes());
+        assert_eq!(fundraise.get_amount_to_raise(), amount_to_raise);
+        assert_eq!(fundraise.get_time_started(), now);
+        assert_eq!(fundraise.get_duration(), duration);
+        assert_eq!(fundraise.mint_decimals, MINT_DECIMALS);
+        assert_eq!(fundraise.get_min_contribution(), 0);
+        assert_eq!(fundraise.token_program, TOKEN_2022_PROGRAM_ID.to_bytes());
+        assert_eq!(fundraise.vault, vault.to_bytes());
+    }
+
+    #[test]
+    fn initialize_with_mismatched_decimals() {
+        let (litesvm, _default_payer) = &mut setup();
+
+        for decimals in [0, 6, 9] {
+            let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+            let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, decimals, 1_000_000_000_000);
+
+            // 5 whole tokens in each mint's own base units
+            let amount_to_raise: u64 = 5 * 10u64.pow(u32::from(decimals));
+            let duration: u64 = SECONDS_PER_DAY; // 1 day
+            let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+            let vault = get_vault_pda(&fundraise_pda);
+
+            let data = [
+                vec![0u8],
+                amount_to_raise.to_le_bytes().to_vec(),
+                duration.to_le_bytes().to_vec(),
+                0u64.to_le_bytes().to_vec(),
+                0u16.to_le_bytes().to_vec(),
+                1_000u16.to_le_bytes().to_vec(),
+                0u64.to_le_bytes().to_vec(),
+            ]
+            .concat();
+            let ix = Instruction {
+                program_id: PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new(maker.pubkey(), true),
+                    AccountMeta::new_readonly(mint_to_raise, false),
+                    AccountMeta::new(fundraise_pda, false),
+                    AccountMeta::new(vault, false),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(get_config_pda(), false),
+                ],
+                data,
+            };
+
+            let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+            let fundraise_acc = litesvm.get_account(&fundraise_pda).unwrap();
+            let fundraise = Fundraise::load(fundraise_acc.data.as_ref()).unwrap();
+
+            assert_eq!(fundraise.mint_decimals, decimals);
+            assert_eq!(
+                normalize(
+                    fundraise.get_amount_to_raise(),
+                    fundraise.mint_decimals,
+                    MINT_DECIMALS
+                ),
+                Ok(5_000_000)
+            );
+        }
+    }
+
+    #[test]
+    fn throw_if_below_min_raise_amount() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        let amount_to_raise: u64 = u64::from(MIN_AMOUNT_TO_RAISE) - 1;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::BelowMinRaiseAmount);
+    }
+
+    #[test]
+    fn throw_if_invalid_completion_threshold() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            3_600u64.to_le_bytes().to_vec(),
+            (MAX_BPS + 1).to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::InvalidAmount);
+    }
+
+    #[test]
+    fn throw_if_invalid_max_contribution_bps() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+        let max_contribution_bps: u16 = MAX_BPS + 1;
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            max_contribution_bps.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::InvalidAmount);
+    }
+
+    #[test]
+    fn duration_bounds() {
+        let (litesvm, _default_payer) = &mut setup();
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        for (duration, error) in [
+            (0, Some(FundraiserError::DurationTooShort)),
+            (MIN_DURATION - 1, Some(FundraiserError::DurationTooShort)),
+            (MIN_DURATION, None),
+            (MAX_DURATION, None),
+            (MAX_DURATION + 1, Some(FundraiserError::DurationTooLong)),
+            (u64::MAX, Some(FundraiserError::DurationTooLong)),
+        ] {
+            let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+            let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+
+            let data = [
+                vec![0u8],
+                5_000_000u64.to_le_bytes().to_vec(),
+                duration.to_le_bytes().to_vec(),
+                0u64.to_le_bytes().to_vec(),
+                0u16.to_le_bytes().to_vec(),
+                1_000u16.to_le_bytes().to_vec(),
+                0u64.to_le_bytes().to_vec(),
+            ]
+            .concat();
+            let ix = Instruction {
+                program_id: PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new(maker.pubkey(), true),
+                    AccountMeta::new_readonly(mint_to_raise, false),
+                    AccountMeta::new(fundraise_pda, false),
+                    AccountMeta::new(get_vault_pda(&fundraise_pda), false),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(get_config_pda(), false),
+                ],
+                data,
+            };
+
+            let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+            match error {
+                Some(error) => assert_error(res.unwrap_err(), error),
+                None => {
+                    res.unwrap();
+
+                    let fundraise_acc = litesvm.get_account(&fundraise_pda).unwrap();
+                    let fundraise = Fundraise::load(fundraise_acc.data.as_ref()).unwrap();
+
+                    assert_eq!(fundraise.get_duration(), duration);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn initialize_with_start_time() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+        let start_time = litesvm.get_sysvar::<Clock>().unix_timestamp + 3600;
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            vec![0u8, 0u8],
+            start_time.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let fundraise_acc = litesvm.get_account(&fundraise_pda).unwrap();
+        let fundraise = Fundraise::load(fundraise_acc.data.as_ref()).unwrap();
+
+        assert_eq!(fundraise.get_time_started(), start_time);
+        assert_eq!(fundraise.get_end_time(), start_time + duration as i64);
+
+        // contributions open exactly at the start time
+        forward_time(litesvm, 3600);
+
+        let contribute_amount: u64 = 500_000;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+
+        let contributor_acc = litesvm.get_account(&contributor_pda).unwrap();
+        let contributor = Contributor::load(contributor_acc.data.as_ref()).unwrap();
+
+        assert_eq!(contributor.get_amount(), contribute_amount);
+    }
+
+    #[test]
+    fn throw_if_start_time_too_far() {
+        let (litesvm, _default_payer) = &mut setup();
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let now = litesvm.get_sysvar::<Clock>().unix_timestamp;
+        let lead_time = MAX_START_LEAD_TIME as i64 + 1;
+
+        for start_time in [now + lead_time, now - lead_time] {
+            let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+            let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+
+            let data = [
+                vec![0u8],
+                5_000_000u64.to_le_bytes().to_vec(),
+                SECONDS_PER_DAY.to_le_bytes().to_vec(),
+                0u64.to_le_bytes().to_vec(),
+                0u16.to_le_bytes().to_vec(),
+                1_000u16.to_le_bytes().to_vec(),
+                0u64.to_le_bytes().to_vec(),
+                vec![0u8, 0u8],
+                start_time.to_le_bytes().to_vec(),
+            ]
+            .concat();
+            let ix = Instruction {
+                program_id: PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new(maker.pubkey(), true),
+                    AccountMeta::new_readonly(mint_to_raise, false),
+                    AccountMeta::new(fundraise_pda, false),
+                    AccountMeta::new(get_vault_pda(&fundraise_pda), false),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(get_config_pda(), false),
+                ],
+                data,
+            };
+
+            let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+            assert_error(res.unwrap_err(), FundraiserError::InvalidAmount);
+        }
+    }
+
+    #[test]
+    fn throw_if_invalid_program_ids() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        for index in [4, 5, 6] {
+            litesvm.expire_blockhash();
+
+            let data = [
+                vec![0u8],
+                amount_to_raise.to_le_bytes().to_vec(),
+                duration.to_le_bytes().to_vec(),
+                0u64.to_le_bytes().to_vec(),
+                0u16.to_le_bytes().to_vec(),
+                1_000u16.to_le_bytes().to_vec(),
+                0u64.to_le_bytes().to_vec(),
+            ]
+            .concat();
+            let mut ix = Instruction {
+                program_id: PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new(maker.pubkey(), true),
+                    AccountMeta::new_readonly(mint_to_raise, false),
+                    AccountMeta::new(fundraise_pda, false),
+                    AccountMeta::new(vault, false),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(get_config_pda(), false),
+                ],
+                data,
+            };
+
+            ix.accounts[index].pubkey = Pubkey::new_unique();
+
+            let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+            assert_error(res.unwrap_err(), FundraiserError::ProgramIdMismatch);
+        }
+    }
+
+    #[test]
+    fn throw_if_accounts_not_writable() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        for index in [2, 3] {
+            litesvm.expire_blockhash();
+
+            let data = [
+                vec![0u8],
+                amount_to_raise.to_le_bytes().to_vec(),
+                duration.to_le_bytes().to_vec(),
+                0u64.to_le_bytes().to_vec(),
+                0u16.to_le_bytes().to_vec(),
+                1_000u16.to_le_bytes().to_vec(),
+                0u64.to_le_bytes().to_vec(),
+            ]
+            .concat();
+            let mut ix = Instruction {
+                program_id: PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new(maker.pubkey(), true),
+                    AccountMeta::new_readonly(mint_to_raise, false),
+                    AccountMeta::new(fundraise_pda, false),
+                    AccountMeta::new(vault, false),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(get_config_pda(), false),
+                ],
+                data,
+            };
+
+            ix.accounts[index].is_writable = false;
+
+            let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+            assert_error(res.unwrap_err(), FundraiserError::AccountNotWritable);
+        }
+    }
+
+    fn initialize_ix(maker: &Pubkey, mint_to_raise: &Pubkey) -> Instruction {
+        let fundraise_pda = get_fundraise_pda(maker);
+
+        let data = [
+            vec![0u8],
+            5_000_000u64.to_le_bytes().to_vec(),
+            SECONDS_PER_DAY.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+
+        Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(*maker, true),
+                AccountMeta::new_readonly(*mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(get_vault_pda(&fundraise_pda), false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        }
+    }
+
+    #[test]
+    fn initialize_prefunded_fundraise() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+
+        // lamports sent to the address ahead of time would make CreateAccount fail
+        litesvm.airdrop(&fundraise_pda, 1_000).unwrap();
+
+        let ix = initialize_ix(&maker.pubkey(), &mint_to_raise);
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let fundraise_acc = litesvm.get_account(&fundraise_pda).unwrap();
+
+        assert_eq!(fundraise_acc.owner, PROGRAM_ID);
+        assert_eq!(fundraise_acc.data.len(), Fundraise::LEN);
+        assert_eq!(
+            fundraise_acc.lamports,
+            litesvm.minimum_balance_for_rent_exemption(Fundraise::LEN)
+        );
+        assert_eq!(
+            Fundraise::load(fundraise_acc.data.as_ref()).unwrap().maker,
+            maker.pubkey().to_bytes()
+        );
+    }
+
+    #[test]
+    fn initialize_with_prefunded_vault() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let vault = get_vault_pda(&get_fundraise_pda(&maker.pubkey()));
+
+        // anyone can send lamports to the announced vault address to make CreateAccount fail
+        litesvm.airdrop(&vault, 1_000).unwrap();
+
+        let ix = client::initialize(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            5_000_000,
+            SECONDS_PER_DAY,
+        );
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let vault_acc = fetch_account::<Account>(litesvm, &vault);
+
+        assert_eq!(vault_acc.mint, mint_to_raise);
+        assert_eq!(vault_acc.owner, get_fundraise_pda(&maker.pubkey()));
+
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        init_ata(litesvm, mint_to_raise, authority.pubkey(), 5_000_000);
+
+        let ix = client::contribute(
+            &authority.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            5_000_000,
+        );
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+
+        init_config(litesvm, maker.pubkey(), 0, FEE_COLLECTOR, false);
+
+        let ix = client::claim(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            &FEE_COLLECTOR,
+        );
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let maker_ata =
+            client::associated_token_address(&maker.pubkey(), &mint_to_raise, &TOKEN_PROGRAM_ID);
+
+        assert_eq!(
+            fetch_account::<Account>(litesvm, &maker_ata).amount,
+            5_000_000
+        );
+    }
+
+    #[test]
+    fn throw_if_fundraise_already_exists() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        let ix = initialize_ix(&maker.pubkey(), &mint_to_raise);
+        build_and_send_transaction(
+            litesvm,
+            &[&maker],
+            &maker.pubkey(),
+            core::slice::from_ref(&ix),
+        )
+        .unwrap();
+
+        litesvm.expire_blockhash();
+        let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::FundraiseAlreadyExists);
+    }
+
+    #[test]
+    fn throw_if_config_not_pda() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        // a program-owned copy of the config, carrying its bump, at another address
+        let impostor = Pubkey::new_unique();
+        let config = litesvm.get_account(&get_config_pda()).unwrap();
+        litesvm.set_account(impostor, config).unwrap();
+
+        for (config, error) in [
+            (impostor, FundraiserError::InvalidAddress),
+            (maker.pubkey(), FundraiserError::AccountNotOwnedByProgram),
+        ] {
+            let mut ix = initialize_ix(&maker.pubkey(), &mint_to_raise);
+            ix.accounts[7].pubkey = config;
+
+            let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+            assert_error(res.unwrap_err(), error);
+        }
+    }
+
+    #[test]
+    fn throw_if_rewards_incomplete() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        for (reward_mint, reward_amount) in [(Pubkey::new_unique(), 0), (Pubkey::default(), 1)] {
+            let ix = client::with_rewards(
+                client::initialize(
+                    &maker.pubkey(),
+                    &mint_to_raise,
+                    &TOKEN_PROGRAM_ID,
+                    5_000_000,
+                    SECONDS_PER_DAY,
+                ),
+                &reward_mint,
+                reward_amount,
+            );
+            let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+            assert_error(res.unwrap_err(), FundraiserError::InvalidAmount);
+        }
+    }
+
+    #[test]
+    fn initialize_without_freeze_authority() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        let ix = client::initialize(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            5_000_000,
+            SECONDS_PER_DAY,
+        );
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let fundraise_acc = litesvm
+            .get_account(&get_fundraise_pda(&maker.pubkey()))
+            .unwrap();
+
+        assert_eq!(
+            Fundraise::unpack(&fundraise_acc.data)
+                .unwrap()
+                .accept_freezable,
+            0
+        );
+    }
+
+    #[test]
+    fn throw_if_mint_has_freeze_authority() {
+        let (litesvm, _default_payer) = &mut setup();
+
+        for token_program in [TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID] {
+            let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+            let mint_to_raise = init_mint(litesvm, token_program, MINT_DECIMALS, 1_000_000_000);
+            set_freeze_authority(litesvm, mint_to_raise, Pubkey::new_unique());
+
+            let ix = client::initialize(
+                &maker.pubkey(),
+                &mint_to_raise,
+                &token_program,
+                5_000_000,
+                SECONDS_PER_DAY,
+            );
+            let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+            assert_error(res.unwrap_err(), FundraiserError::MintHasFreezeAuthority);
+        }
+    }
+
+    #[test]
+    fn initialize_if_freeze_authority_accepted() {
+        let (litesvm, _default_payer) = &mut setup();
+
+        for token_program in [TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID] {
+            let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+            let mint_to_raise = init_mint(litesvm, token_program, MINT_DECIMALS, 1_000_000_000);
+            set_freeze_authority(litesvm, mint_to_raise, Pubkey::new_unique());
+
+            let ix = client::with_accept_freezable(client::initialize(
+                &maker.pubkey(),
+                &mint_to_raise,
+                &token_program,
+                5_000_000,
+                SECONDS_PER_DAY,
+            ));
+            build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+            let fundraise_acc = litesvm
+                .get_account(&get_fundraise_pda(&maker.pubkey()))
+                .unwrap();
+
+            // recorded so UIs can warn backers
+            assert_eq!(
+                Fundraise::unpack(&fundraise_acc.data)
+                    .unwrap()
+                    .accept_freezable,
+                1
+            );
+        }
+    }
+
+    #[test]
+    fn initialize_with_claim_authority() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let claim_authority = Pubkey::new_unique();
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        let ix = client::with_claim_authority(
+            client::initialize(
+                &maker.pubkey(),
+                &mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+                5_000_000,
+                SECONDS_PER_DAY,
+            ),
+            &claim_authority,
+        );
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let fundraise_acc = litesvm
+            .get_account(&get_fundraise_pda(&maker.pubkey()))
+            .unwrap();
+        let fundraise = Fundraise::unpack(&fundraise_acc.data).unwrap();
+
+        assert_eq!(fundraise.maker, maker.pubkey().to_bytes());
+        assert_eq!(fundraise.claim_authority, claim_authority.to_bytes());
     }
 }
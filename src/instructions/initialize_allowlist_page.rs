@@ -0,0 +1,290 @@
+use core::mem::size_of;
+use pinocchio::{
+    ProgramResult, account_info::AccountInfo, instruction::Seed, program_error::ProgramError,
+    pubkey::find_program_address,
+};
+
+use crate::{
+    AccountCheck, AccountLoad, AllowlistPage, AllowlistPageParams, Fundraise, FundraiserError,
+    FundraiserInstruction, Handler, Prefix, ProgramAccount, ProgramIdCheck, SetInner,
+    SignerAccount, WritableAccount, check_account,
+};
+
+pub struct InitializeAllowlistPageAccounts<'a> {
+    pub maker: &'a AccountInfo,
+    pub fundraise: &'a AccountInfo,
+    pub page: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for InitializeAllowlistPageAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [maker, fundraise, page, system_program] = accounts else {
+            return Err(FundraiserError::MissingAccounts.into());
+        };
+
+        check_account!(0, "signer", SignerAccount::check(maker))?;
+        check_account!(0, "writable", WritableAccount::check(maker))?;
+        check_account!(2, "writable", WritableAccount::check(page))?;
+        check_account!(
+            3,
+            "program id",
+            ProgramIdCheck::check(system_program, &pinocchio_system::ID)
+        )?;
+        check_account!(1, "owner", ProgramAccount::check(fundraise))?;
+
+        Ok(Self {
+            maker,
+            fundraise,
+            page,
+            system_program,
+        })
+    }
+}
+
+pub struct InitializeAllowlistPageInstructionData {
+    pub page_index: u16,
+}
+
+impl InitializeAllowlistPageInstructionData {
+    pub const LEN: usize = size_of::<u16>();
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        self.page_index.to_le_bytes()
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for InitializeAllowlistPageInstructionData {
+    type Error = ProgramError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        if data.len() != size_of::<u16>() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let page_index = u16::from_le_bytes(data.try_into().unwrap());
+
+        Ok(Self { page_index })
+    }
+}
+
+/// Creates an empty allowlist page of a fundraise, paid for by the maker.
+pub struct InitializeAllowlistPage<'a> {
+    pub accounts: InitializeAllowlistPageAccounts<'a>,
+    pub data: InitializeAllowlistPageInstructionData,
+    pub bump: u8,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for InitializeAllowlistPage<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = InitializeAllowlistPageAccounts::try_from(accounts)?;
+        let data = InitializeAllowlistPageInstructionData::try_from(data)?;
+
+        {
+            let fundraise_data = accounts.fundraise.try_borrow_data()?;
+            let fundraise = Fundraise::load(&fundraise_data)?;
+
+            ProgramAccount::validate(
+                &[Fundraise::PREFIX, accounts.maker.key(), &[fundraise.bump]],
+                *accounts.fundraise.key(),
+            )?;
+        }
+
+        let page_index = data.page_index.to_le_bytes();
+        let (pda, bump) = find_program_address(
+            &[AllowlistPage::PREFIX, accounts.fundraise.key(), &page_index],
+            &crate::ID,
+        );
+
+        if pda != *accounts.page.key() {
+            return Err(FundraiserError::InvalidAddress.into());
+        }
+
+        let page_bump = [bump];
+        let page_seeds = [
+            Seed::from(AllowlistPage::PREFIX),
+            Seed::from(accounts.fundraise.key().as_ref()),
+            Seed::from(&page_index),
+            Seed::from(&page_bump),
+        ];
+
+        ProgramAccount::init::<AllowlistPage>(accounts.maker, accounts.page, &page_seeds)?;
+
+        Ok(Self {
+            accounts,
+            data,
+            bump,
+        })
+    }
+}
+
+impl Handler for InitializeAllowlistPage<'_> {
+    const DISCRIMINATOR: u8 = FundraiserInstruction::InitializeAllowlistPage as u8;
+
+    fn process(&mut self) -> ProgramResult {
+        let mut data = self.accounts.page.try_borrow_mut_data()?;
+        let page = AllowlistPage::load_mut(data.as_mut())?;
+
+        page.set_inner(AllowlistPageParams::new(
+            *self.accounts.fundraise.key(),
+            self.data.page_index,
+            self.bump,
+        ));
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_signer::Signer;
+    use spl_associated_token_account::solana_program::{
+        clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL,
+    };
+
+    use crate::{
+        AccountLoad, AllowlistPage, Fundraise, FundraiserError,
+        tests::{
+            constants::{
+                ASSOCIATED_TOKEN_PROGRAM_ID, MINT_DECIMALS, PROGRAM_ID, SYSTEM_PROGRAM_ID,
+                TOKEN_PROGRAM_ID,
+            },
+            pda::{get_allowlist_page_pda, get_config_pda, get_fundraise_pda, get_vault_pda},
+            utils::{assert_error, build_and_send_transaction, init_mint, init_wallet, setup},
+        },
+    };
+
+    #[test]
+    fn initialize_allowlist_page() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            1u8.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let page_index: u16 = 0;
+        let page_pda = get_allowlist_page_pda(&fundraise_pda, page_index);
+
+        let data = [vec![18u8], page_index.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(fundraise_pda, false),
+                AccountMeta::new(page_pda, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let fundraise_acc = litesvm.get_account(&fundraise_pda).unwrap();
+        let fundraise = Fundraise::load(fundraise_acc.data.as_ref()).unwrap();
+
+        assert!(fundraise.has_allowlist());
+
+        let page_acc = litesvm.get_account(&page_pda).unwrap();
+        let page = AllowlistPage::load(page_acc.data.as_ref()).unwrap();
+
+        assert_eq!(page.fundraise, fundraise_pda.to_bytes());
+        assert_eq!(page.get_page_index(), page_index);
+        assert!(!page.is_set(0).unwrap());
+    }
+
+    #[test]
+    fn throw_if_not_maker() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let other = init_wallet(litesvm, LAMPORTS_PER_SOL);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            1u8.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let page_index: u16 = 0;
+        let page_pda = get_allowlist_page_pda(&fundraise_pda, page_index);
+
+        let data = [vec![18u8], page_index.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(other.pubkey(), true),
+                AccountMeta::new_readonly(fundraise_pda, false),
+                AccountMeta::new(page_pda, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+            data,
+        };
+
+        let res = build_and_send_transaction(litesvm, &[&other], &other.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::InvalidAddress);
+    }
+}
@@ -0,0 +1,242 @@
+use core::mem::size_of;
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::Seed,
+    program_error::ProgramError,
+    pubkey::{Pubkey, find_program_address},
+};
+
+use crate::{
+    AccountCheck, AccountLoad, Config, ConfigParams, FundraiserError, FundraiserInstruction,
+    Handler, MAX_BPS, Prefix, ProgramAccount, ProgramIdCheck, SetInner, SignerAccount,
+    UpgradeAuthority, WritableAccount, check_account,
+};
+
+pub struct InitializeConfigAccounts<'a> {
+    pub admin: &'a AccountInfo,
+    pub config: &'a AccountInfo,
+    pub program_data: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for InitializeConfigAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [admin, config, program_data, system_program] = accounts else {
+            return Err(FundraiserError::MissingAccounts.into());
+        };
+
+        check_account!(0, "signer", SignerAccount::check(admin))?;
+        check_account!(0, "writable", WritableAccount::check(admin))?;
+        check_account!(1, "writable", WritableAccount::check(config))?;
+        check_account!(
+            3,
+            "program id",
+            ProgramIdCheck::check(system_program, &pinocchio_system::ID)
+        )?;
+        check_account!(
+            2,
+            "upgrade authority",
+            UpgradeAuthority::check(program_data, admin)
+        )?;
+
+        Ok(Self {
+            admin,
+            config,
+            program_data,
+            system_program,
+        })
+    }
+}
+
+pub struct InitializeConfigInstructionData {
+    pub fee_bps: u16,
+    pub fee_collector: Pubkey,
+}
+
+impl InitializeConfigInstructionData {
+    pub const LEN: usize = size_of::<u16>() + size_of::<Pubkey>();
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut bytes = [0u8; Self::LEN];
+
+        bytes[0..2].copy_from_slice(&self.fee_bps.to_le_bytes());
+        bytes[2..34].copy_from_slice(&self.fee_collector);
+
+        bytes
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for InitializeConfigInstructionData {
+    type Error = ProgramError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        if data.len() != size_of::<u16>() + size_of::<Pubkey>() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let offset = size_of::<u16>();
+        let fee_bps = u16::from_le_bytes(data[0..offset].try_into().unwrap());
+        let fee_collector: Pubkey = data[offset..offset + size_of::<Pubkey>()]
+            .try_into()
+            .unwrap();
+
+        if fee_bps > MAX_BPS {
+            return Err(FundraiserError::InvalidAmount.into());
+        }
+
+        Ok(Self {
+            fee_bps,
+            fee_collector,
+        })
+    }
+}
+
+/// Creates the protocol-wide config. Only the program's upgrade authority may do so, and it
+/// becomes the initial admin.
+pub struct InitializeConfig<'a> {
+    pub accounts: InitializeConfigAccounts<'a>,
+    pub data: InitializeConfigInstructionData,
+    pub bump: u8,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for InitializeConfig<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = InitializeConfigAccounts::try_from(accounts)?;
+        let data = InitializeConfigInstructionData::try_from(data)?;
+
+        let (pda, bump) = find_program_address(&[Config::PREFIX], &crate::ID);
+
+        if pda != *accounts.config.key() {
+            return Err(FundraiserError::InvalidAddress.into());
+        }
+
+        let config_bump = [bump];
+        let config_seeds = [Seed::from(Config::PREFIX), Seed::from(&config_bump)];
+
+        ProgramAccount::init::<Config>(accounts.admin, accounts.config, &config_seeds)?;
+
+        Ok(Self {
+            accounts,
+            data,
+            bump,
+        })
+    }
+}
+
+impl Handler for InitializeConfig<'_> {
+    const DISCRIMINATOR: u8 = FundraiserInstruction::InitializeConfig as u8;
+
+    fn process(&mut self) -> ProgramResult {
+        let mut data = self.accounts.config.try_borrow_mut_data()?;
+        let config = Config::load_mut(data.as_mut())?;
+
+        config.set_inner(ConfigParams::new(
+            *self.accounts.admin.key(),
+            self.data.fee_bps,
+            self.data.fee_collector,
+            0,
+            self.bump,
+        ));
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_account::Account;
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_signer::Signer;
+    use spl_associated_token_account::solana_program::native_token::LAMPORTS_PER_SOL;
+
+    use crate::{
+        AccountLoad, Config, FundraiserError,
+        tests::{
+            constants::{FEE_COLLECTOR, PROGRAM_ID, SYSTEM_PROGRAM_ID},
+            pda::get_config_pda,
+            utils::{
+                assert_error, build_and_send_transaction, init_program_data, init_wallet, setup,
+            },
+        },
+    };
+
+    #[test]
+    fn initialize_config() {
+        let (litesvm, _default_payer) = &mut setup();
+        let admin = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let program_data = init_program_data(litesvm, admin.pubkey());
+        let config_pda = get_config_pda();
+
+        // drop the config preloaded by setup
+        litesvm.set_account(config_pda, Account::default()).unwrap();
+
+        let fee_bps: u16 = 250; // 2.5%
+
+        let data = [
+            &[16u8],
+            fee_bps.to_le_bytes().as_ref(),
+            FEE_COLLECTOR.as_ref(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(admin.pubkey(), true),
+                AccountMeta::new(config_pda, false),
+                AccountMeta::new_readonly(program_data, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&admin], &admin.pubkey(), &[ix]);
+
+        let config_acc = litesvm.get_account(&config_pda).unwrap();
+        let config = Config::load(config_acc.data.as_ref()).unwrap();
+
+        assert_eq!(config.admin, admin.pubkey().to_bytes());
+        assert_eq!(config.get_fee_bps(), fee_bps);
+        assert_eq!(config.fee_collector, FEE_COLLECTOR.to_bytes());
+        assert!(!config.is_paused());
+    }
+
+    #[test]
+    fn throw_if_not_upgrade_authority() {
+        let (litesvm, _default_payer) = &mut setup();
+        let admin = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let upgrade_authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let program_data = init_program_data(litesvm, upgrade_authority.pubkey());
+        let config_pda = get_config_pda();
+
+        // drop the config preloaded by setup
+        litesvm.set_account(config_pda, Account::default()).unwrap();
+
+        let fee_bps: u16 = 250; // 2.5%
+
+        let data = [
+            &[16u8],
+            fee_bps.to_le_bytes().as_ref(),
+            FEE_COLLECTOR.as_ref(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(admin.pubkey(), true),
+                AccountMeta::new(config_pda, false),
+                AccountMeta::new_readonly(program_data, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+            data,
+        };
+
+        let res = build_and_send_transaction(litesvm, &[&admin], &admin.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::Unauthorized);
+    }
+}
@@ -1,17 +1,75 @@
 use pinocchio::ProgramResult;
 
+pub mod cancel;
 pub mod claim;
+pub mod claim_allowlist_slot;
+pub mod claim_reward;
+pub mod close_fundraise;
+pub mod complete;
 pub mod contribute;
+pub mod contribute_delegated;
+pub mod contribute_for;
+pub mod crank_refund;
+pub mod create_contributor;
+pub mod deposit_match;
+pub mod deposit_rewards;
+pub mod dispatch;
+pub mod donate;
+pub mod extend;
+pub mod finalize;
+pub mod force_refund;
 pub mod initialize;
+pub mod initialize_allowlist_page;
+pub mod initialize_config;
+pub mod post_update;
+pub mod reclaim_match;
 pub mod refund;
+pub mod run_hook;
+pub mod set_claim_authority;
+pub mod set_paused;
+pub mod snapshot;
+pub mod sweep_foreign;
+pub mod sweep_rewards;
+pub mod transfer_contribution;
+pub mod update_config;
+pub mod update_metadata;
 
+pub use cancel::*;
 pub use claim::*;
+pub use claim_allowlist_slot::*;
+pub use claim_reward::*;
+pub use close_fundraise::*;
+pub use complete::*;
 pub use contribute::*;
+pub use contribute_delegated::*;
+pub use contribute_for::*;
+pub use crank_refund::*;
+pub use create_contributor::*;
+pub use deposit_match::*;
+pub use deposit_rewards::*;
+pub use dispatch::*;
+pub use donate::*;
+pub use extend::*;
+pub use finalize::*;
+pub use force_refund::*;
 pub use initialize::*;
+pub use initialize_allowlist_page::*;
+pub use initialize_config::*;
+pub use post_update::*;
+pub use reclaim_match::*;
 pub use refund::*;
+pub use run_hook::*;
+pub use set_claim_authority::*;
+pub use set_paused::*;
+pub use snapshot::*;
+pub use sweep_foreign::*;
+pub use sweep_rewards::*;
+pub use transfer_contribution::*;
+pub use update_config::*;
+pub use update_metadata::*;
 
-pub trait Handler<'a> {
-    const DISCRIMINATOR: &'a u8;
+pub trait Handler {
+    const DISCRIMINATOR: u8;
 
     fn process(&mut self) -> ProgramResult;
 }
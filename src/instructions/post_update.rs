@@ -0,0 +1,304 @@
+use core::mem::size_of;
+
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::Seed,
+    program_error::ProgramError,
+    pubkey::find_program_address,
+    sysvars::{Sysvar, clock::Clock},
+};
+
+use crate::{
+    AccountCheck, AccountLoad, Fundraise, FundraiserError, FundraiserInstruction, Handler,
+    MAX_UPDATES, Prefix, ProgramAccount, ProgramIdCheck, SignerAccount, Space, UpdateEntry,
+    Updates, UpdatesParams, WritableAccount, check_account,
+};
+
+pub struct PostUpdateAccounts<'a> {
+    /// Signs and pays for the updates account and its growth.
+    pub maker: &'a AccountInfo,
+    pub fundraise: &'a AccountInfo,
+    /// The fundraise's updates account, created by the first post.
+    pub updates: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for PostUpdateAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [maker, fundraise, updates, system_program] = accounts else {
+            return Err(FundraiserError::MissingAccounts.into());
+        };
+
+        check_account!(0, "signer", SignerAccount::check(maker))?;
+        check_account!(0, "writable", WritableAccount::check(maker))?;
+        check_account!(2, "writable", WritableAccount::check(updates))?;
+        check_account!(
+            3,
+            "program id",
+            ProgramIdCheck::check(system_program, &pinocchio_system::ID)
+        )?;
+        check_account!(1, "owner", ProgramAccount::check(fundraise))?;
+
+        Ok(Self {
+            maker,
+            fundraise,
+            updates,
+            system_program,
+        })
+    }
+}
+
+pub struct PostUpdateInstructionData {
+    pub content_hash: [u8; 32],
+}
+
+impl PostUpdateInstructionData {
+    pub const LEN: usize = size_of::<[u8; 32]>();
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        self.content_hash
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for PostUpdateInstructionData {
+    type Error = ProgramError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        let content_hash = data
+            .try_into()
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+        Ok(Self { content_hash })
+    }
+}
+
+/// Records that the maker posted an update, appending the hash of its off-chain content and
+/// the time to the fundraise's updates account, which grows by one entry per post. Backers can
+/// then check any update they are shown against the log. At most [`MAX_UPDATES`] are kept.
+pub struct PostUpdate<'a> {
+    pub accounts: PostUpdateAccounts<'a>,
+    pub data: PostUpdateInstructionData,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for PostUpdate<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = PostUpdateAccounts::try_from(accounts)?;
+        let data = PostUpdateInstructionData::try_from(data)?;
+
+        Ok(Self { accounts, data })
+    }
+}
+
+impl Handler for PostUpdate<'_> {
+    const DISCRIMINATOR: u8 = FundraiserInstruction::PostUpdate as u8;
+
+    fn process(&mut self) -> ProgramResult {
+        let fundraise_data = self.accounts.fundraise.try_borrow_data()?;
+        let fundraise = Fundraise::load(&fundraise_data)?;
+
+        if fundraise.maker != *self.accounts.maker.key() {
+            return Err(FundraiserError::InvalidAddress.into());
+        }
+
+        ProgramAccount::validate(
+            &[Fundraise::PREFIX, &fundraise.maker, &[fundraise.bump]],
+            *self.accounts.fundraise.key(),
+        )?;
+
+        drop(fundraise_data);
+
+        let (pda, bump) = find_program_address(
+            &[Updates::PREFIX, self.accounts.fundraise.key()],
+            &crate::ID,
+        );
+
+        if *self.accounts.updates.key() != pda {
+            return Err(FundraiserError::InvalidAddress.into());
+        }
+
+        let bump_binding = [bump];
+        let updates_seeds = [
+            Seed::from(Updates::PREFIX),
+            Seed::from(self.accounts.fundraise.key().as_ref()),
+            Seed::from(&bump_binding),
+        ];
+
+        ProgramAccount::init_if_needed::<Updates>(
+            &updates_seeds,
+            self.accounts.updates,
+            self.accounts.maker,
+            UpdatesParams::new(*self.accounts.fundraise.key(), bump),
+        )?;
+
+        let count = {
+            let updates_data = self.accounts.updates.try_borrow_data()?;
+            let header = updates_data
+                .get(..Updates::LEN)
+                .ok_or(FundraiserError::InvalidAccountDataLength)?;
+
+            Updates::load(header)?.get_count()
+        };
+
+        if count >= MAX_UPDATES {
+            return Err(FundraiserError::TooManyUpdates.into());
+        }
+
+        let offset = Updates::space(usize::from(count));
+
+        ProgramAccount::realloc(
+            self.accounts.updates,
+            self.accounts.maker,
+            offset + UpdateEntry::LEN,
+        )?;
+
+        let mut updates_data = self.accounts.updates.try_borrow_mut_data()?;
+        let (header, entries) = updates_data.split_at_mut(offset);
+
+        Updates::load_mut(&mut header[..Updates::LEN])?.set_count(count + 1);
+        UpdateEntry::load_mut(entries)?.set(self.data.content_hash, Clock::get()?.unix_timestamp);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_signer::Signer;
+    use spl_associated_token_account::solana_program::{
+        clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL,
+    };
+
+    use crate::{
+        FundraiserError, MAX_UPDATES, Space, UpdateEntry, Updates, client,
+        tests::{
+            constants::{MINT_DECIMALS, PROGRAM_ID, TOKEN_PROGRAM_ID},
+            utils::{
+                assert_error, build_and_send_transaction, forward_time, init_mint, init_wallet,
+                setup,
+            },
+        },
+    };
+
+    fn initialize(litesvm: &mut LiteSVM) -> Keypair {
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        let ix = client::initialize(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            5_000_000,
+            SECONDS_PER_DAY,
+        );
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        maker
+    }
+
+    fn post(litesvm: &mut LiteSVM, maker: &Keypair, content_hash: [u8; 32]) {
+        let ix = client::post_update(&maker.pubkey(), content_hash);
+        build_and_send_transaction(litesvm, &[maker], &maker.pubkey(), &[ix]).unwrap();
+    }
+
+    #[test]
+    fn first_post_creates_updates() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = initialize(litesvm);
+
+        post(litesvm, &maker, [7; 32]);
+
+        let fundraise = client::fundraise_address(&maker.pubkey());
+        let updates_acc = litesvm
+            .get_account(&client::updates_address(&fundraise))
+            .unwrap();
+        let updates = Updates::unpack(&updates_acc.data).unwrap();
+
+        assert_eq!(updates_acc.owner, PROGRAM_ID);
+        assert_eq!(updates_acc.data.len(), Updates::space(1));
+        assert_eq!(
+            updates_acc.lamports,
+            litesvm.minimum_balance_for_rent_exemption(Updates::space(1))
+        );
+        assert_eq!(updates.fundraise, fundraise.to_bytes());
+        assert_eq!(updates.entries.len(), 1);
+        assert_eq!(updates.entries[0].content_hash, [7; 32]);
+        assert_eq!(
+            updates.entries[0].timestamp,
+            litesvm.get_sysvar::<solana_clock::Clock>().unix_timestamp
+        );
+    }
+
+    #[test]
+    fn later_posts_grow_updates() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = initialize(litesvm);
+
+        post(litesvm, &maker, [1; 32]);
+        forward_time(litesvm, 60);
+        post(litesvm, &maker, [2; 32]);
+        forward_time(litesvm, 60);
+        post(litesvm, &maker, [3; 32]);
+
+        let updates_acc = litesvm
+            .get_account(&client::updates_address(&client::fundraise_address(
+                &maker.pubkey(),
+            )))
+            .unwrap();
+        let updates = Updates::unpack(&updates_acc.data).unwrap();
+
+        assert_eq!(updates_acc.data.len(), Updates::LEN + 3 * UpdateEntry::LEN);
+        assert_eq!(
+            updates_acc.lamports,
+            litesvm.minimum_balance_for_rent_exemption(Updates::space(3))
+        );
+        assert_eq!(
+            updates
+                .entries
+                .iter()
+                .map(|entry| entry.content_hash[0])
+                .collect::<Vec<_>>(),
+            [1, 2, 3]
+        );
+        assert_eq!(
+            updates.entries[2].timestamp - updates.entries[0].timestamp,
+            120
+        );
+    }
+
+    #[test]
+    fn throw_if_too_many_updates() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = initialize(litesvm);
+
+        for i in 0..MAX_UPDATES {
+            post(litesvm, &maker, [i as u8; 32]);
+        }
+
+        let ix = client::post_update(&maker.pubkey(), [0xff; 32]);
+        let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::TooManyUpdates);
+    }
+
+    #[test]
+    fn throw_if_signer_not_maker() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = initialize(litesvm);
+        let other = init_wallet(litesvm, LAMPORTS_PER_SOL);
+
+        let mut ix = client::post_update(&other.pubkey(), [7; 32]);
+        let fundraise = client::fundraise_address(&maker.pubkey());
+        ix.accounts[1].pubkey = fundraise;
+        ix.accounts[2].pubkey = client::updates_address(&fundraise);
+        let res = build_and_send_transaction(litesvm, &[&other], &other.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::InvalidAddress);
+    }
+}
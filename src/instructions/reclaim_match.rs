@@ -0,0 +1,432 @@
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{Sysvar, clock::Clock},
+};
+
+use crate::{
+    AccountCheck, AccountLoad, AssociatedTokenAccount, Fundraise, FundraiseStatus, FundraiserError,
+    FundraiserInstruction, Handler, Liability, MintInterface, Prefix, ProgramAccount,
+    ProgramIdCheck, SignerAccount, TransferHook, WritableAccount, check_account, vault_transfer,
+};
+
+pub struct ReclaimMatchAccounts<'a> {
+    pub sponsor: &'a AccountInfo,
+    pub mint_to_raise: &'a AccountInfo,
+    pub fundraise: &'a AccountInfo,
+    /// The fundraise's vault, checked against it. Whether the campaign failed is judged by the
+    /// amount it recorded, not by this balance.
+    pub vault: &'a AccountInfo,
+    pub match_vault: &'a AccountInfo,
+    /// The sponsor's associated token account of the mint to raise, created if it does not exist
+    /// yet.
+    pub sponsor_token_account: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+    pub token_program: &'a AccountInfo,
+    pub associated_token_program: &'a AccountInfo,
+    pub transfer_hook_accounts: &'a [AccountInfo],
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for ReclaimMatchAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [
+            sponsor,
+            mint_to_raise,
+            fundraise,
+            vault,
+            match_vault,
+            sponsor_token_account,
+            system_program,
+            token_program,
+            associated_token_program,
+            rest @ ..,
+        ] = accounts
+        else {
+            return Err(FundraiserError::MissingAccounts.into());
+        };
+
+        check_account!(0, "signer", SignerAccount::check(sponsor))?;
+        check_account!(0, "writable", WritableAccount::check(sponsor))?;
+        check_account!(2, "writable", WritableAccount::check(fundraise))?;
+        check_account!(4, "writable", WritableAccount::check(match_vault))?;
+        check_account!(5, "writable", WritableAccount::check(sponsor_token_account))?;
+        check_account!(1, "mint", MintInterface::check(mint_to_raise))?;
+        check_account!(
+            6,
+            "program id",
+            ProgramIdCheck::check(system_program, &pinocchio_system::ID)
+        )?;
+        check_account!(
+            7,
+            "program id",
+            ProgramIdCheck::check(token_program, mint_to_raise.owner())
+        )?;
+        check_account!(
+            8,
+            "program id",
+            ProgramIdCheck::check(
+                associated_token_program,
+                &pinocchio_associated_token_account::ID,
+            )
+        )?;
+        check_account!(2, "owner", ProgramAccount::check(fundraise))?;
+        check_account!(
+            4,
+            "address",
+            AssociatedTokenAccount::check_address(
+                match_vault,
+                fundraise,
+                mint_to_raise,
+                token_program,
+            )
+        )?;
+        check_account!(
+            5,
+            "address",
+            AssociatedTokenAccount::check_address(
+                sponsor_token_account,
+                sponsor,
+                mint_to_raise,
+                token_program,
+            )
+        )?;
+
+        let (transfer_hook_accounts, _) = TransferHook::split_trailing(mint_to_raise, rest)?;
+
+        Ok(Self {
+            sponsor,
+            mint_to_raise,
+            fundraise,
+            vault,
+            match_vault,
+            sponsor_token_account,
+            system_program,
+            token_program,
+            associated_token_program,
+            transfer_hook_accounts,
+        })
+    }
+}
+
+/// Returns the whole matching pool to its sponsor once the fundraise has failed, i.e. was
+/// cancelled or ended where the maker can never claim it. A fundraise the maker claims settles
+/// the pool there instead.
+pub struct ReclaimMatch<'a> {
+    pub accounts: ReclaimMatchAccounts<'a>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for ReclaimMatch<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        Ok(Self {
+            accounts: ReclaimMatchAccounts::try_from(accounts)?,
+        })
+    }
+}
+
+impl Handler for ReclaimMatch<'_> {
+    const DISCRIMINATOR: u8 = FundraiserInstruction::ReclaimMatch as u8;
+
+    fn process(&mut self) -> ProgramResult {
+        let fundraise_data = self.accounts.fundraise.try_borrow_data()?;
+        let fundraise = Fundraise::load(&fundraise_data)?;
+
+        ProgramAccount::validate(
+            &[Fundraise::PREFIX, &fundraise.maker, &[fundraise.bump]],
+            *self.accounts.fundraise.key(),
+        )?;
+        fundraise.check_mint_to_raise(self.accounts.mint_to_raise.key())?;
+        fundraise.check_vault(self.accounts.vault.key())?;
+
+        if !fundraise.has_match() {
+            return Err(FundraiserError::NoMatch.into());
+        }
+
+        if fundraise.match_sponsor != *self.accounts.sponsor.key() {
+            return Err(FundraiserError::InvalidAddress.into());
+        }
+
+        if fundraise.is_claimed() {
+            return Err(FundraiserError::AlreadyClaimed.into());
+        }
+
+        let status = fundraise.status(Clock::get()?.unix_timestamp);
+
+        // a fundraise left expired or refundable will never be claimed
+        if !matches!(
+            status,
+            FundraiseStatus::Expired | FundraiseStatus::Refundable
+        ) {
+            return Err(FundraiserError::MatchLocked.into());
+        }
+
+        let match_pool = fundraise.get_match_pool();
+
+        drop(fundraise_data);
+
+        if match_pool == 0 {
+            return Ok(());
+        }
+
+        AssociatedTokenAccount::init_if_needed(
+            self.accounts.sponsor_token_account,
+            self.accounts.mint_to_raise,
+            self.accounts.sponsor,
+            self.accounts.sponsor,
+            self.accounts.system_program,
+            self.accounts.token_program,
+        )?;
+
+        vault_transfer(
+            Liability::Match,
+            self.accounts.match_vault,
+            self.accounts.mint_to_raise,
+            self.accounts.sponsor_token_account,
+            match_pool,
+            self.accounts.fundraise,
+            self.accounts.transfer_hook_accounts,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use litesvm::LiteSVM;
+    use solana_instruction::Instruction;
+    use solana_keypair::Keypair;
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use spl_associated_token_account::solana_program::{
+        clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL,
+    };
+    use spl_token_2022::state::Account;
+
+    use crate::{
+        FundraiserError, client,
+        tests::{
+            constants::{FEE_COLLECTOR, MINT_DECIMALS, TOKEN_PROGRAM_ID},
+            utils::{
+                assert_error, build_and_send_transaction, fetch_account, forward_time, init_ata,
+                init_mint, init_wallet, setup,
+            },
+        },
+    };
+
+    const AMOUNT_TO_RAISE: u64 = 5_000_000;
+    const POOL: u64 = 3_000_000;
+
+    struct Matched {
+        maker: Keypair,
+        sponsor: Keypair,
+        mint_to_raise: Pubkey,
+    }
+
+    /// A fundraise matched with the whole pool and `contribution` contributed.
+    fn matched(litesvm: &mut LiteSVM, contribution: u64) -> Matched {
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let sponsor = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        init_ata(litesvm, mint_to_raise, sponsor.pubkey(), POOL);
+
+        let ix = client::initialize(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            AMOUNT_TO_RAISE,
+            SECONDS_PER_DAY,
+        );
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let ix = client::deposit_match(
+            &sponsor.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            POOL,
+        );
+        build_and_send_transaction(litesvm, &[&sponsor], &sponsor.pubkey(), &[ix]).unwrap();
+
+        if contribution > 0 {
+            let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+            init_ata(litesvm, mint_to_raise, authority.pubkey(), contribution);
+
+            let ix = client::contribute(
+                &authority.pubkey(),
+                &maker.pubkey(),
+                &mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+                contribution,
+            );
+            build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+        }
+
+        Matched {
+            maker,
+            sponsor,
+            mint_to_raise,
+        }
+    }
+
+    fn reclaim(matched: &Matched) -> Instruction {
+        client::reclaim_match(
+            &matched.sponsor.pubkey(),
+            &matched.maker.pubkey(),
+            &matched.mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        )
+    }
+
+    fn sponsor_balance(litesvm: &LiteSVM, matched: &Matched) -> u64 {
+        let sponsor_ata = client::associated_token_address(
+            &matched.sponsor.pubkey(),
+            &matched.mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        fetch_account::<Account>(litesvm, &sponsor_ata).amount
+    }
+
+    #[test]
+    fn reclaim_after_target_missed() {
+        let (litesvm, _default_payer) = &mut setup();
+        let matched = matched(litesvm, 1_000_000);
+
+        forward_time(litesvm, SECONDS_PER_DAY as i64 + 1);
+
+        let ix = reclaim(&matched);
+        build_and_send_transaction(
+            litesvm,
+            &[&matched.sponsor],
+            &matched.sponsor.pubkey(),
+            &[ix],
+        )
+        .unwrap();
+
+        assert_eq!(sponsor_balance(litesvm, &matched), POOL);
+
+        let match_vault = client::associated_token_address(
+            &client::fundraise_address(&matched.maker.pubkey()),
+            &matched.mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        assert_eq!(fetch_account::<Account>(litesvm, &match_vault).amount, 0);
+    }
+
+    #[test]
+    fn reclaim_after_cancel() {
+        let (litesvm, _default_payer) = &mut setup();
+        let matched = matched(litesvm, 1_000_000);
+
+        let ix = client::cancel(&matched.maker.pubkey(), &FEE_COLLECTOR);
+        build_and_send_transaction(litesvm, &[&matched.maker], &matched.maker.pubkey(), &[ix])
+            .unwrap();
+
+        // no need to wait out the deadline once the maker has called the fundraise off
+        let ix = reclaim(&matched);
+        build_and_send_transaction(
+            litesvm,
+            &[&matched.sponsor],
+            &matched.sponsor.pubkey(),
+            &[ix],
+        )
+        .unwrap();
+
+        assert_eq!(sponsor_balance(litesvm, &matched), POOL);
+    }
+
+    #[test]
+    fn throw_if_fundraise_running() {
+        let (litesvm, _default_payer) = &mut setup();
+        let matched = matched(litesvm, 1_000_000);
+
+        let ix = reclaim(&matched);
+        let res = build_and_send_transaction(
+            litesvm,
+            &[&matched.sponsor],
+            &matched.sponsor.pubkey(),
+            &[ix],
+        );
+
+        assert_error(res.unwrap_err(), FundraiserError::MatchLocked);
+    }
+
+    #[test]
+    fn throw_if_fundraise_claimable() {
+        let (litesvm, _default_payer) = &mut setup();
+        let matched = matched(litesvm, AMOUNT_TO_RAISE);
+
+        forward_time(litesvm, SECONDS_PER_DAY as i64 + 1);
+
+        let ix = reclaim(&matched);
+        let res = build_and_send_transaction(
+            litesvm,
+            &[&matched.sponsor],
+            &matched.sponsor.pubkey(),
+            &[ix],
+        );
+
+        assert_error(res.unwrap_err(), FundraiserError::MatchLocked);
+    }
+
+    #[test]
+    fn throw_if_already_claimed() {
+        let (litesvm, _default_payer) = &mut setup();
+        let matched = matched(litesvm, AMOUNT_TO_RAISE);
+
+        let ix = client::with_match(
+            client::claim(
+                &matched.maker.pubkey(),
+                &matched.mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+                &FEE_COLLECTOR,
+            ),
+            &matched.sponsor.pubkey(),
+        );
+        build_and_send_transaction(litesvm, &[&matched.maker], &matched.maker.pubkey(), &[ix])
+            .unwrap();
+
+        let ix = reclaim(&matched);
+        let res = build_and_send_transaction(
+            litesvm,
+            &[&matched.sponsor],
+            &matched.sponsor.pubkey(),
+            &[ix],
+        );
+
+        assert_error(res.unwrap_err(), FundraiserError::AlreadyClaimed);
+    }
+
+    #[test]
+    fn throw_if_no_match() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let sponsor = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        let ix = client::initialize(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            AMOUNT_TO_RAISE,
+            SECONDS_PER_DAY,
+        );
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        forward_time(litesvm, SECONDS_PER_DAY as i64 + 1);
+
+        let ix = client::reclaim_match(
+            &sponsor.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+        let res = build_and_send_transaction(litesvm, &[&sponsor], &sponsor.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::NoMatch);
+    }
+}
@@ -1,3 +1,5 @@
+use core::mem::size_of;
+
 use pinocchio::{
     ProgramResult,
     account_info::AccountInfo,
@@ -5,14 +7,18 @@ use pinocchio::{
     program_error::ProgramError,
     sysvars::{Sysvar, clock::Clock},
 };
-use pinocchio_token_2022::instructions::{CloseAccount, Transfer};
+use pinocchio_token_2022::instructions::CloseAccount;
 
 use crate::{
-    AccountCheck, AccountLoad, AssociatedTokenAccount, Contributor, Fundraise, FundraiserError,
-    Handler, MintInterface, Prefix, ProgramAccount, SignerAccount,
+    AccountCheck, AccountLoad, AssociatedTokenAccount, CampaignType, Contributor, Fundraise,
+    FundraiseStatus, FundraiserError, FundraiserInstruction, Handler, Liability, MemoProgram,
+    MintInterface, MultisigAccount, NATIVE_MINT, Prefix, ProgramAccount, ProgramIdCheck,
+    ReceiptAccounts, RefundPolicy, SignerAccount, Stats, TokenAccountInterface, TransferHook,
+    WritableAccount, check_account, vault_transfer,
 };
 
 pub struct RefundAccounts<'a> {
+    /// Owns the position. May be an SPL token multisig, signed for by its signers.
     pub authority: &'a AccountInfo,
     pub maker: &'a AccountInfo,
     pub mint_to_raise: &'a AccountInfo,
@@ -22,12 +28,27 @@ pub struct RefundAccounts<'a> {
     pub vault: &'a AccountInfo,
     pub system_program: &'a AccountInfo,
     pub token_program: &'a AccountInfo,
+    pub associated_token_program: &'a AccountInfo,
+    /// Signers of a multisig authority, the first of which pays for anything the refund
+    /// creates. Empty for any other authority.
+    pub multisig_signers: &'a [AccountInfo],
+    /// Receives the contributor account's rent when the refund closes it. It must be whoever
+    /// paid that rent, as recorded on the contributor, and defaults to the authority when left
+    /// out, so it only needs passing when a sponsor or custodian funded the position.
+    pub rent_destination: Option<&'a AccountInfo>,
+    pub transfer_hook_accounts: &'a [AccountInfo],
+    /// Passed when the fundraise issues receipts, which are burned from the authority.
+    pub receipt: Option<ReceiptAccounts<'a>>,
+    pub memo_program: Option<&'a AccountInfo>,
+    pub stats: Option<&'a AccountInfo>,
 }
 
 impl<'a> TryFrom<&'a [AccountInfo]> for RefundAccounts<'a> {
     type Error = ProgramError;
 
     fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let (stats, accounts) = Stats::split_trailing(accounts);
+        let (memo_program, accounts) = MemoProgram::split_trailing(accounts);
         let [
             authority,
             maker,
@@ -38,22 +59,99 @@ impl<'a> TryFrom<&'a [AccountInfo]> for RefundAccounts<'a> {
             vault,
             system_program,
             token_program,
+            associated_token_program,
+            rest @ ..,
         ] = accounts
         else {
-            return Err(ProgramError::NotEnoughAccountKeys);
+            return Err(FundraiserError::MissingAccounts.into());
         };
 
-        SignerAccount::check(authority)?;
-        MintInterface::check(mint_to_raise)?;
-        ProgramAccount::check(fundraise)?;
-        ProgramAccount::check(contributor)?;
-        AssociatedTokenAccount::check(
-            authority_token_account,
-            authority,
-            mint_to_raise,
-            token_program,
+        check_account!(0, "writable", WritableAccount::check(authority))?;
+        check_account!(1, "writable", WritableAccount::check(maker))?;
+        check_account!(3, "writable", WritableAccount::check(fundraise))?;
+        check_account!(4, "writable", WritableAccount::check(contributor))?;
+        check_account!(
+            5,
+            "writable",
+            WritableAccount::check(authority_token_account)
+        )?;
+        check_account!(6, "writable", WritableAccount::check(vault))?;
+        check_account!(2, "mint", MintInterface::check(mint_to_raise))?;
+        check_account!(
+            7,
+            "program id",
+            ProgramIdCheck::check(system_program, &pinocchio_system::ID)
+        )?;
+        check_account!(
+            9,
+            "program id",
+            ProgramIdCheck::check(
+                associated_token_program,
+                &pinocchio_associated_token_account::ID,
+            )
         )?;
-        AssociatedTokenAccount::check(vault, fundraise, mint_to_raise, token_program)?;
+        check_account!(3, "owner", ProgramAccount::check(fundraise))?;
+
+        let fundraise_data = fundraise.try_borrow_data()?;
+        let fundraise_state = Fundraise::load(&fundraise_data)?;
+
+        check_account!(
+            8,
+            "token program",
+            fundraise_state.check_token_program(token_program.key())
+        )?;
+        check_account!(6, "vault", fundraise_state.check_vault(vault.key()))?;
+        let receipt_mint = fundraise_state.receipt_mint;
+        drop(fundraise_data);
+
+        check_account!(4, "owner", ProgramAccount::check(contributor))?;
+        check_account!(
+            5,
+            "address",
+            AssociatedTokenAccount::check_address(
+                authority_token_account,
+                authority,
+                mint_to_raise,
+                token_program,
+            )
+        )?;
+        check_account!(
+            6,
+            "mint and owner",
+            TokenAccountInterface::check_mint_and_owner(
+                vault,
+                mint_to_raise.key(),
+                fundraise.key()
+            )
+        )?;
+
+        let (receipt, rest) = ReceiptAccounts::split_trailing(&receipt_mint, rest)?;
+        let (transfer_hook_accounts, rest) = TransferHook::split_trailing(mint_to_raise, rest)?;
+        let (multisig_signers, rest) =
+            MultisigAccount::split_signers(authority, token_program, rest)?;
+
+        if let Some(receipt) = &receipt {
+            receipt.check_holder(authority.key())?;
+        }
+
+        match MultisigAccount::is_multisig(authority, token_program) {
+            true => {
+                check_account!(
+                    0,
+                    "multisig",
+                    MultisigAccount::check_signers(authority, multisig_signers)
+                )?;
+                WritableAccount::check(&multisig_signers[0])?;
+            }
+            false => check_account!(0, "signer", SignerAccount::check(authority))?,
+        }
+
+        // defaults to the authority, who paid the rent unless a sponsor did
+        let rent_destination = rest.first();
+
+        if let Some(rent_destination) = rent_destination {
+            WritableAccount::check(rent_destination)?;
+        }
 
         Ok(Self {
             authority,
@@ -65,57 +163,169 @@ impl<'a> TryFrom<&'a [AccountInfo]> for RefundAccounts<'a> {
             vault,
             system_program,
             token_program,
+            associated_token_program,
+            multisig_signers,
+            rent_destination,
+            transfer_hook_accounts,
+            receipt,
+            memo_program,
+            stats,
         })
     }
 }
 
+pub struct RefundInstructionData {
+    /// Tokens to pull back, with 0 refunding the whole position.
+    pub amount: u64,
+    /// Whether to close the authority's wrapped SOL account afterwards, paying out lamports.
+    pub unwrap: bool,
+}
+
+impl RefundInstructionData {
+    pub const LEN: usize = size_of::<u64>() + size_of::<u8>();
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut bytes = [0u8; Self::LEN];
+
+        bytes[..8].copy_from_slice(&self.amount.to_le_bytes());
+        bytes[8] = u8::from(self.unwrap);
+
+        bytes
+    }
+}
+
+impl RefundAccounts<'_> {
+    /// Pays for the authority's token account if it has to be recreated, and the stats update.
+    #[inline(always)]
+    fn payer(&self) -> &AccountInfo {
+        self.multisig_signers.first().unwrap_or(self.authority)
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for RefundInstructionData {
+    type Error = ProgramError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        // an empty payload is the original full refund, which deployed clients keep sending,
+        // and the unwrap flag is optional after an amount
+        let (amount, unwrap) = match data.split_at_checked(size_of::<u64>()) {
+            _ if data.is_empty() => (0, &[][..]),
+            Some((amount, unwrap)) if unwrap.len() <= 1 => {
+                (u64::from_le_bytes(amount.try_into().unwrap()), unwrap)
+            }
+            _ => return Err(ProgramError::InvalidInstructionData),
+        };
+        let unwrap = match unwrap {
+            [] | [0] => false,
+            [1] => true,
+            _ => return Err(ProgramError::InvalidInstructionData),
+        };
+
+        Ok(Self { amount, unwrap })
+    }
+}
+
+/// Returns `amount` of the contributor's position, or all of it when `amount` is 0. Under the
+/// flexible refund policy this is allowed while the fundraise is running below target, and
+/// under the locked policy only once it has ended below target. A keep-it-all fundraise is
+/// refundable only before its deadline, target met or not. A partial refund leaves the
+/// contributor account open with the rest of the position, while one that empties it closes
+/// the account, returning its rent to whoever paid it. The refund that empties the vault closes
+/// it too, unless the campaign is still running and could take contributions into it. Refunds of
+/// wrapped SOL can be unwrapped into the authority's lamports, and any receipts for the refunded
+/// amount are burned first.
 pub struct Refund<'a> {
     pub accounts: RefundAccounts<'a>,
+    pub data: RefundInstructionData,
 }
 
-impl<'a> TryFrom<&'a [AccountInfo]> for Refund<'a> {
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for Refund<'a> {
     type Error = ProgramError;
 
-    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
         let accounts = RefundAccounts::try_from(accounts)?;
+        let data = RefundInstructionData::try_from(data)?;
+
+        if data.unwrap && accounts.mint_to_raise.key() != &NATIVE_MINT {
+            return Err(FundraiserError::NotNativeMint.into());
+        }
+
+        // closing the token account would need the multisig's signers to sign the CPI too
+        if data.unwrap && !accounts.multisig_signers.is_empty() {
+            return Err(FundraiserError::MultisigUnwrapUnsupported.into());
+        }
+
+        // the contributor may have closed their token account since contributing
+        AssociatedTokenAccount::init_if_needed(
+            accounts.authority_token_account,
+            accounts.mint_to_raise,
+            accounts.payer(),
+            accounts.authority,
+            accounts.system_program,
+            accounts.token_program,
+        )?;
+        AssociatedTokenAccount::check(
+            accounts.authority_token_account,
+            accounts.authority,
+            accounts.mint_to_raise,
+            accounts.token_program,
+        )?;
 
-        Ok(Self { accounts })
+        Ok(Self { accounts, data })
     }
 }
 
-impl<'a> Handler<'a> for Refund<'a> {
-    const DISCRIMINATOR: &'a u8 = &2;
+impl Handler for Refund<'_> {
+    const DISCRIMINATOR: u8 = FundraiserInstruction::Refund as u8;
 
     fn process(&mut self) -> ProgramResult {
+        // the token program would reject a transfer to or from a frozen account, opaquely
+        if TokenAccountInterface::is_frozen(self.accounts.vault) {
+            return Err(FundraiserError::VaultFrozen.into());
+        }
+
+        if TokenAccountInterface::is_frozen(self.accounts.authority_token_account) {
+            return Err(FundraiserError::DestinationFrozen.into());
+        }
+
         let mut fundraise_data = self.accounts.fundraise.try_borrow_mut_data()?;
         let fundraise = Fundraise::load_mut(fundraise_data.as_mut())?;
 
         let fundraise_maker = fundraise.maker;
+
+        if fundraise_maker != *self.accounts.maker.key() {
+            return Err(FundraiserError::InvalidAddress.into());
+        }
+
         let fundraise_bump = [fundraise.bump];
         let fundraise_seeds = &[Fundraise::PREFIX, &fundraise_maker, &fundraise_bump];
 
         ProgramAccount::validate(fundraise_seeds, *self.accounts.fundraise.key())?;
         fundraise.check_mint_to_raise(self.accounts.mint_to_raise.key())?;
 
-        let contributor_data = self.accounts.contributor.try_borrow_data()?;
-        let contributor = Contributor::load(&contributor_data)?;
+        if fundraise.is_claimed() {
+            return Err(FundraiserError::AlreadyClaimed.into());
+        }
+
+        let mut contributor_data = self.accounts.contributor.try_borrow_mut_data()?;
+        let contributor = Contributor::load_mut(contributor_data.as_mut())?;
+
+        // only the wallet the position belongs to can refund it, whoever funded it
+        if contributor.authority != *self.accounts.authority.key() {
+            return Err(FundraiserError::InvalidAddress.into());
+        }
 
         let contributor_seeds = &[
             Contributor::PREFIX,
-            &contributor.fundraise,
-            &contributor.authority,
+            self.accounts.fundraise.key(),
+            self.accounts.authority.key(),
             &[contributor.bump],
         ];
 
         ProgramAccount::validate(contributor_seeds, *self.accounts.contributor.key())?;
 
-        let now = Clock::get()?.unix_timestamp;
-
-        let duration = fundraise.get_duration();
-        let time_started = fundraise.get_time_started();
-
-        if now > time_started + duration as i64 {
-            return Err(FundraiserError::FundraiserEnded.into());
+        if contributor.mint != *self.accounts.mint_to_raise.key() {
+            return Err(FundraiserError::InvalidMintToRaise.into());
         }
 
         let vault_amount = match *self.accounts.vault.owner() {
@@ -135,48 +345,145 @@ impl<'a> Handler<'a> for Refund<'a> {
                 };
                 vault.amount()
             }
-            _ => return Err(ProgramError::IncorrectProgramId),
+            _ => return Err(FundraiserError::InvalidTokenProgram.into()),
         };
 
-        let amount_to_raise = fundraise.get_amount_to_raise();
-
-        if vault_amount >= amount_to_raise {
-            return Err(FundraiserError::TargetMet.into());
+        let status = fundraise.status(Clock::get()?.unix_timestamp);
+
+        // a keep-it-all fundraise never fails, so its refunds close at the deadline
+        match (fundraise.campaign_type(), fundraise.refund_policy(), status) {
+            (_, _, FundraiseStatus::Refundable) if fundraise.is_cancelled() => {}
+            (CampaignType::KeepItAll, _, FundraiseStatus::Active | FundraiseStatus::TargetMet) => {}
+            (CampaignType::KeepItAll, ..) => return Err(FundraiserError::FundraiserEnded.into()),
+            (CampaignType::AllOrNothing, RefundPolicy::Flexible, FundraiseStatus::Active) => {}
+            (
+                CampaignType::AllOrNothing,
+                RefundPolicy::Flexible,
+                FundraiseStatus::TargetMet | FundraiseStatus::Claimable,
+            ) => {
+                return Err(FundraiserError::TargetMet.into());
+            }
+            (CampaignType::AllOrNothing, RefundPolicy::Flexible, _) => {
+                return Err(FundraiserError::FundraiserEnded.into());
+            }
+            (
+                CampaignType::AllOrNothing,
+                RefundPolicy::Locked,
+                FundraiseStatus::Active | FundraiseStatus::TargetMet,
+            ) => {
+                return Err(FundraiserError::FundraiserNotEnded.into());
+            }
+            (CampaignType::AllOrNothing, RefundPolicy::Locked, FundraiseStatus::Claimable) => {
+                return Err(FundraiserError::TargetMet.into());
+            }
+            (CampaignType::AllOrNothing, RefundPolicy::Locked, _) => {}
         }
 
-        let current_amount = fundraise.get_current_amount();
         let contributor_amount = contributor.get_amount();
-        fundraise.set_current_amount(current_amount - contributor_amount);
+        let refund_amount = match self.data.amount {
+            0 => contributor_amount,
+            amount if amount > contributor_amount => {
+                return Err(FundraiserError::InvalidAmount.into());
+            }
+            amount => amount,
+        };
+        let remaining_amount = contributor_amount - refund_amount;
+        let rent_destination = self
+            .accounts
+            .rent_destination
+            .unwrap_or(self.accounts.authority);
+
+        if remaining_amount == 0 && contributor.rent_payer != *rent_destination.key() {
+            return Err(FundraiserError::InvalidRentDestination.into());
+        }
+
+        // the hook is only owed for what is left of the position
+        let pending_hook_amount = contributor.get_pending_hook_amount();
+
+        if pending_hook_amount > 0 && remaining_amount == 0 {
+            fundraise.set_pending_hooks(fundraise.get_pending_hooks() - 1);
+        }
+
+        contributor.set_pending_hook_amount(pending_hook_amount.min(remaining_amount));
+        contributor.set_amount(remaining_amount);
+        contributor.set_tier(fundraise.tier(remaining_amount));
+
+        // only what the refund leaves counts once the campaign has succeeded, see Snapshot
+        if contributor.is_snapshotted()
+            || matches!(
+                status,
+                FundraiseStatus::TargetMet | FundraiseStatus::Claimable
+            )
+        {
+            contributor.snapshot(fundraise)?;
+        }
 
         let fundraise_seeds = [
             Seed::from(Fundraise::PREFIX),
             Seed::from(fundraise_maker.as_ref()),
             Seed::from(&fundraise_bump),
         ];
-        let fundraise_signer = Signer::from(&fundraise_seeds);
 
+        // nothing stays borrowed across the CPIs, which a transfer hook may read accounts during
+        drop(contributor_data);
         drop(fundraise_data);
-        Transfer {
-            amount: contributor_amount,
-            authority: self.accounts.fundraise,
-            from: self.accounts.vault,
-            to: self.accounts.authority_token_account,
-            token_program: self.accounts.token_program.key(),
+
+        // the receipts go before the funds they stand for
+        if let Some(receipt) = &self.accounts.receipt {
+            receipt.burn(
+                self.accounts.authority,
+                self.accounts.multisig_signers,
+                refund_amount,
+            )?;
+        }
+
+        MemoProgram::memo_if_required(
+            self.accounts.authority_token_account,
+            self.accounts.memo_program,
+            b"fundraiser refund",
+        )?;
+        vault_transfer(
+            Liability::Raised,
+            self.accounts.vault,
+            self.accounts.mint_to_raise,
+            self.accounts.authority_token_account,
+            refund_amount,
+            self.accounts.fundraise,
+            self.accounts.transfer_hook_accounts,
+        )?;
+
+        if let Some(stats) = self.accounts.stats {
+            Stats::update(stats, self.accounts.payer(), |stats| {
+                stats.record_refund(refund_amount)
+            })?;
+        }
+
+        if self.data.unwrap {
+            CloseAccount {
+                account: self.accounts.authority_token_account,
+                destination: self.accounts.authority,
+                authority: self.accounts.authority,
+                token_program: self.accounts.token_program.key(),
+            }
+            .invoke()?;
         }
-        .invoke_signed(&[fundraise_signer.clone()])?;
 
-        if vault_amount - contributor_amount == 0 {
+        // a live campaign keeps its vault for the contributions still to come, see CloseFundraise
+        let live = matches!(status, FundraiseStatus::Active | FundraiseStatus::TargetMet);
+
+        if vault_amount - refund_amount == 0 && !live {
             CloseAccount {
                 account: self.accounts.vault,
                 destination: self.accounts.maker,
                 authority: self.accounts.fundraise,
                 token_program: self.accounts.token_program.key(),
             }
-            .invoke_signed(&[fundraise_signer])?;
+            .invoke_signed(&[Signer::from(&fundraise_seeds)])?;
         }
 
-        drop(contributor_data);
-        ProgramAccount::close(self.accounts.contributor, self.accounts.authority)?;
+        if remaining_amount == 0 {
+            ProgramAccount::close(self.accounts.contributor, rent_destination)?;
+        }
 
         Ok(())
     }
@@ -184,24 +491,32 @@ impl<'a> Handler<'a> for Refund<'a> {
 
 #[cfg(test)]
 mod tests {
+    use litesvm::{LiteSVM, types::FailedTransactionMetadata};
     use solana_instruction::{AccountMeta, Instruction};
+    use solana_keypair::Keypair;
+    use solana_pubkey::Pubkey;
     use solana_signer::Signer;
-    use spl_associated_token_account::{
-        get_associated_token_address_with_program_id,
-        solana_program::{clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL},
+    use spl_associated_token_account::solana_program::{
+        clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL,
+    };
+    use spl_token_2022::{
+        extension::StateWithExtensions,
+        instruction::close_account,
+        state::{Account, Mint},
     };
 
     use crate::{
-        AccountLoad, Contributor, Fundraise, FundraiserError,
+        AccountLoad, Contributor, Fundraise, FundraiserError, RefundPolicy, Space, client, cpi,
         tests::{
             constants::{
-                ASSOCIATED_TOKEN_PROGRAM_ID, MINT_DECIMALS, PROGRAM_ID, SYSTEM_PROGRAM_ID,
-                TOKEN_PROGRAM_ID,
+                ASSOCIATED_TOKEN_PROGRAM_ID, MEMO_PROGRAM_ID, MINT_DECIMALS, PROGRAM_ID,
+                SYSTEM_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID,
             },
-            pda::{get_contributor_pda, get_fundraise_pda},
+            pda::{get_config_pda, get_contributor_pda, get_fundraise_pda, get_vault_pda},
             utils::{
-                assert_error, build_and_send_transaction, forward_time, init_ata, init_mint,
-                init_wallet, setup,
+                assert_error, build_and_send_transaction, fetch_account, forward_time,
+                freeze_token_account, init_ata, init_memo_required_ata, init_mint,
+                init_token_account, init_wallet, setup,
             },
         },
     };
@@ -217,16 +532,16 @@ mod tests {
         let amount_to_raise: u64 = 5_000_000;
         let duration: u64 = SECONDS_PER_DAY; // 1 day
         let fundraise_pda = get_fundraise_pda(&maker.pubkey());
-        let vault = get_associated_token_address_with_program_id(
-            &fundraise_pda,
-            &mint_to_raise,
-            &TOKEN_PROGRAM_ID,
-        );
+        let vault = get_vault_pda(&fundraise_pda);
 
         let data = [
             vec![0u8],
             amount_to_raise.to_le_bytes().to_vec(),
             duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
         ]
         .concat();
         let ix = Instruction {
@@ -239,6 +554,7 @@ mod tests {
                 AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
                 AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
                 AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
             ],
             data,
         };
@@ -260,6 +576,7 @@ mod tests {
                 AccountMeta::new(vault, false),
                 AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
                 AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
             ],
             data,
         };
@@ -269,12 +586,12 @@ mod tests {
         forward_time(litesvm, 3600); // jump forward 1 hour
 
         let fundraise_acc = litesvm.get_account(&fundraise_pda).unwrap();
-        let fundraise = Fundraise::load(&fundraise_acc.data.as_ref()).unwrap();
+        let fundraise = Fundraise::load(fundraise_acc.data.as_ref()).unwrap();
 
         let pre_fundraise_current_amount = fundraise.get_current_amount();
 
         let contributor_acc = litesvm.get_account(&contributor_pda).unwrap();
-        let contributor = Contributor::load(&contributor_acc.data.as_ref()).unwrap();
+        let contributor = Contributor::load(contributor_acc.data.as_ref()).unwrap();
 
         let contributor_amount = contributor.get_amount();
 
@@ -291,6 +608,7 @@ mod tests {
                 AccountMeta::new(vault, false),
                 AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
                 AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
             ],
             data,
         };
@@ -298,7 +616,7 @@ mod tests {
         let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
 
         let fundraise_acc = litesvm.get_account(&fundraise_pda).unwrap();
-        let fundraise = Fundraise::load(&fundraise_acc.data.as_ref()).unwrap();
+        let fundraise = Fundraise::load(fundraise_acc.data.as_ref()).unwrap();
 
         let post_fundraise_current_amount = fundraise.get_current_amount();
 
@@ -311,13 +629,12 @@ mod tests {
 
         assert!(contributor_acc.is_none());
 
-        let vault = litesvm.get_account(&vault);
-
-        assert!(vault.is_none());
+        // the campaign is still running, so the emptied vault stays open
+        assert_eq!(fetch_account::<Account>(litesvm, &vault).amount, 0);
     }
 
     #[test]
-    fn throw_if_fundraiser_ended() {
+    fn partial_refund_then_full_refund() {
         let (litesvm, _default_payer) = &mut setup();
         let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
         let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
@@ -327,16 +644,16 @@ mod tests {
         let amount_to_raise: u64 = 5_000_000;
         let duration: u64 = SECONDS_PER_DAY; // 1 day
         let fundraise_pda = get_fundraise_pda(&maker.pubkey());
-        let vault = get_associated_token_address_with_program_id(
-            &fundraise_pda,
-            &mint_to_raise,
-            &TOKEN_PROGRAM_ID,
-        );
+        let vault = get_vault_pda(&fundraise_pda);
 
         let data = [
             vec![0u8],
             amount_to_raise.to_le_bytes().to_vec(),
             duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
         ]
         .concat();
         let ix = Instruction {
@@ -349,11 +666,12 @@ mod tests {
                 AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
                 AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
                 AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
             ],
             data,
         };
 
-        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
 
         let contribute_amount: u64 = 500_000;
         let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
@@ -370,20 +688,132 @@ mod tests {
                 AccountMeta::new(vault, false),
                 AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
                 AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
             ],
             data,
         };
 
-        let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
 
-        forward_time(litesvm, duration as i64 + 1);
+        let refund_ix = |data: Vec<u8>| Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new(maker.pubkey(), false),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            ],
+            data,
+        };
 
-        let data = vec![2u8];
+        let refund_amount: u64 = 200_000;
+        let ix = refund_ix([vec![2u8], refund_amount.to_le_bytes().to_vec()].concat());
+
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+
+        let contributor_acc = litesvm.get_account(&contributor_pda).unwrap();
+        let contributor = Contributor::load(contributor_acc.data.as_ref()).unwrap();
+
+        assert_eq!(contributor.get_amount(), contribute_amount - refund_amount);
+
+        let fundraise_acc = litesvm.get_account(&fundraise_pda).unwrap();
+        let fundraise = Fundraise::load(fundraise_acc.data.as_ref()).unwrap();
+
+        assert_eq!(
+            fundraise.get_current_amount(),
+            contribute_amount - refund_amount
+        );
+        assert_eq!(
+            fetch_account::<Account>(litesvm, &authority_ata).amount,
+            1_000_000_000 - contribute_amount + refund_amount
+        );
+
+        // the remaining position is refunded in full by the original, data-less encoding
+        build_and_send_transaction(
+            litesvm,
+            &[&authority],
+            &authority.pubkey(),
+            &[refund_ix(vec![2u8])],
+        )
+        .unwrap();
+
+        assert!(litesvm.get_account(&contributor_pda).is_none());
+        assert_eq!(fetch_account::<Account>(litesvm, &vault).amount, 0);
+        assert_eq!(
+            fetch_account::<Account>(litesvm, &authority_ata).amount,
+            1_000_000_000
+        );
+
+        litesvm.expire_blockhash();
+
+        let res = build_and_send_transaction(
+            litesvm,
+            &[&authority],
+            &authority.pubkey(),
+            &[refund_ix(vec![2u8])],
+        );
+
+        assert_error(res.unwrap_err(), FundraiserError::AccountNotOwnedByProgram);
+    }
+
+    /// Runs a fundraise under `refund_policy` in which one contributor puts in `contribute_amount`,
+    /// then attempts their refund `elapsed` seconds later, returning the refund's failure if any.
+    fn refund_after(
+        refund_policy: u8,
+        contribute_amount: u64,
+        elapsed: i64,
+    ) -> Option<FailedTransactionMetadata> {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [
+            vec![0u8],
+            5_000_000u64.to_le_bytes().to_vec(),
+            SECONDS_PER_DAY.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            10_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            vec![0u8, 0u8],
+            0i64.to_le_bytes().to_vec(),
+            vec![refund_policy],
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
         let ix = Instruction {
             program_id: PROGRAM_ID,
             accounts: vec![
                 AccountMeta::new(authority.pubkey(), true),
-                AccountMeta::new(maker.pubkey(), false),
                 AccountMeta::new_readonly(mint_to_raise, false),
                 AccountMeta::new(fundraise_pda, false),
                 AccountMeta::new(contributor_pda, false),
@@ -391,36 +821,100 @@ mod tests {
                 AccountMeta::new(vault, false),
                 AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
                 AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
             ],
             data,
         };
 
-        let res = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
 
-        assert_error(res.unwrap_err(), FundraiserError::FundraiserEnded);
+        forward_time(litesvm, elapsed);
+
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new(maker.pubkey(), false),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            ],
+            data: vec![2u8],
+        };
+
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).err()
     }
 
     #[test]
-    fn throw_if_target_met() {
+    fn refund_policies() {
+        let running = 3600; // 1 hour in
+        let ended = SECONDS_PER_DAY as i64 + 1;
+        let failed = 500_000;
+        let successful = 5_000_000;
+
+        for (policy, contribute_amount, elapsed, expected) in [
+            (RefundPolicy::Flexible, failed, running, None),
+            (
+                RefundPolicy::Flexible,
+                failed,
+                ended,
+                Some(FundraiserError::FundraiserEnded),
+            ),
+            (
+                RefundPolicy::Flexible,
+                successful,
+                ended,
+                Some(FundraiserError::TargetMet),
+            ),
+            (
+                RefundPolicy::Locked,
+                failed,
+                running,
+                Some(FundraiserError::FundraiserNotEnded),
+            ),
+            (RefundPolicy::Locked, failed, ended, None),
+            (
+                RefundPolicy::Locked,
+                successful,
+                ended,
+                Some(FundraiserError::TargetMet),
+            ),
+        ] {
+            let failure = refund_after(policy as u8, contribute_amount, elapsed);
+
+            match expected {
+                None => assert!(failure.is_none(), "{policy:?} after {elapsed}s"),
+                Some(error) => assert_error(failure.unwrap(), error),
+            }
+        }
+    }
+
+    #[test]
+    fn refund_with_token_2022() {
         let (litesvm, _default_payer) = &mut setup();
         let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
         let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
-        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 10_000_000_000);
+        let mint_to_raise = init_mint(litesvm, TOKEN_2022_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
         let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
 
         let amount_to_raise: u64 = 5_000_000;
         let duration: u64 = SECONDS_PER_DAY; // 1 day
         let fundraise_pda = get_fundraise_pda(&maker.pubkey());
-        let vault = get_associated_token_address_with_program_id(
-            &fundraise_pda,
-            &mint_to_raise,
-            &TOKEN_PROGRAM_ID,
-        );
+        let vault = get_vault_pda(&fundraise_pda);
 
         let data = [
             vec![0u8],
             amount_to_raise.to_le_bytes().to_vec(),
             duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
         ]
         .concat();
         let ix = Instruction {
@@ -431,13 +925,14 @@ mod tests {
                 AccountMeta::new(fundraise_pda, false),
                 AccountMeta::new(vault, false),
                 AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
-                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false),
                 AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
             ],
             data,
         };
 
-        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
 
         let contribute_amount: u64 = 500_000;
         let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
@@ -453,37 +948,25 @@ mod tests {
                 AccountMeta::new(authority_ata, false),
                 AccountMeta::new(vault, false),
                 AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
-                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
             ],
             data,
         };
 
-        let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
 
-        // init 9 more authorities to contribute and meet the fundraiser target
-        for _ in 0..9 {
-            let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
-            let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
-            let temp_contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+        forward_time(litesvm, 3600); // jump forward 1 hour
 
-            let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
-            let ix = Instruction {
-                program_id: PROGRAM_ID,
-                accounts: vec![
-                    AccountMeta::new(authority.pubkey(), true),
-                    AccountMeta::new_readonly(mint_to_raise, false),
-                    AccountMeta::new(fundraise_pda, false),
-                    AccountMeta::new(temp_contributor_pda, false),
-                    AccountMeta::new(authority_ata, false),
-                    AccountMeta::new(vault, false),
-                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
-                    AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
-                ],
-                data,
-            };
+        let fundraise_acc = litesvm.get_account(&fundraise_pda).unwrap();
+        let fundraise = Fundraise::load(fundraise_acc.data.as_ref()).unwrap();
 
-            let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
-        }
+        let pre_fundraise_current_amount = fundraise.get_current_amount();
+
+        let contributor_acc = litesvm.get_account(&contributor_pda).unwrap();
+        let contributor = Contributor::load(contributor_acc.data.as_ref()).unwrap();
+
+        let contributor_amount = contributor.get_amount();
 
         let data = vec![2u8];
         let ix = Instruction {
@@ -497,13 +980,1361 @@ mod tests {
                 AccountMeta::new(authority_ata, false),
                 AccountMeta::new(vault, false),
                 AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
-                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
             ],
             data,
         };
 
-        let res = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
 
-        assert_error(res.unwrap_err(), FundraiserError::TargetMet);
+        let fundraise_acc = litesvm.get_account(&fundraise_pda).unwrap();
+        let fundraise = Fundraise::load(fundraise_acc.data.as_ref()).unwrap();
+
+        let post_fundraise_current_amount = fundraise.get_current_amount();
+
+        assert_eq!(
+            pre_fundraise_current_amount,
+            post_fundraise_current_amount + contributor_amount
+        );
+
+        let contributor_acc = litesvm.get_account(&contributor_pda);
+
+        assert!(contributor_acc.is_none());
+
+        assert!(litesvm.get_account(&vault).is_some());
+
+        let authority_ata_bal = fetch_account::<Account>(litesvm, &authority_ata).amount;
+
+        assert_eq!(authority_ata_bal, 1_000_000_000);
+    }
+
+    #[test]
+    fn refund_to_memo_required_account() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_2022_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let authority_ata =
+            init_memo_required_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        // memos are only required on the way in, so contributing needs none
+        let contribute_amount: u64 = 500_000;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+
+        let mut ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new(maker.pubkey(), false),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            ],
+            data: vec![2u8],
+        };
+
+        let res = build_and_send_transaction(
+            litesvm,
+            &[&authority],
+            &authority.pubkey(),
+            core::slice::from_ref(&ix),
+        );
+
+        assert_error(res.unwrap_err(), FundraiserError::MissingMemoProgram);
+
+        ix.accounts
+            .push(AccountMeta::new_readonly(MEMO_PROGRAM_ID, false));
+
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+
+        let authority_ata_acc = litesvm.get_account(&authority_ata).unwrap();
+        let authority_ata_bal = StateWithExtensions::<Account>::unpack(&authority_ata_acc.data)
+            .unwrap()
+            .base
+            .amount;
+
+        assert_eq!(authority_ata_bal, 1_000_000_000);
+        assert!(litesvm.get_account(&contributor_pda).is_none());
+    }
+
+    #[test]
+    fn throw_if_fundraiser_ended() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let contribute_amount: u64 = 500_000;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        forward_time(litesvm, duration as i64 + 1);
+
+        let data = vec![2u8];
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new(maker.pubkey(), false),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            ],
+            data,
+        };
+
+        let res = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::FundraiserEnded);
+    }
+
+    #[test]
+    fn throw_if_maker_mismatch() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let contribute_amount: u64 = 500_000;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        let impostor = init_wallet(litesvm, LAMPORTS_PER_SOL);
+
+        let data = vec![2u8];
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new(impostor.pubkey(), false),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            ],
+            data,
+        };
+
+        let res = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::InvalidAddress);
+    }
+
+    #[test]
+    fn throw_if_target_met() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 10_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let contribute_amount: u64 = 500_000;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        // init 9 more authorities to contribute and meet the fundraiser target
+        for _ in 0..9 {
+            let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+            let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+            let temp_contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+            let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+            let ix = Instruction {
+                program_id: PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new(authority.pubkey(), true),
+                    AccountMeta::new_readonly(mint_to_raise, false),
+                    AccountMeta::new(fundraise_pda, false),
+                    AccountMeta::new(temp_contributor_pda, false),
+                    AccountMeta::new(authority_ata, false),
+                    AccountMeta::new(vault, false),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(get_config_pda(), false),
+                ],
+                data,
+            };
+
+            let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+        }
+
+        let data = vec![2u8];
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new(maker.pubkey(), false),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            ],
+            data,
+        };
+
+        let res = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::TargetMet);
+    }
+
+    #[test]
+    fn throw_if_invalid_program_ids() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let contribute_amount: u64 = 500_000;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        // system program, then associated token program
+        for index in [7, 9] {
+            litesvm.expire_blockhash();
+
+            let data = vec![2u8];
+            let mut ix = Instruction {
+                program_id: PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new(authority.pubkey(), true),
+                    AccountMeta::new(maker.pubkey(), false),
+                    AccountMeta::new_readonly(mint_to_raise, false),
+                    AccountMeta::new(fundraise_pda, false),
+                    AccountMeta::new(contributor_pda, false),
+                    AccountMeta::new(authority_ata, false),
+                    AccountMeta::new(vault, false),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                ],
+                data,
+            };
+
+            ix.accounts[index].pubkey = Pubkey::new_unique();
+
+            let res =
+                build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+            assert_error(res.unwrap_err(), FundraiserError::ProgramIdMismatch);
+        }
+    }
+
+    #[test]
+    fn throw_if_token_program_mismatch() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let contribute_amount: u64 = 500_000;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        let data = vec![2u8];
+        let mut ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new(maker.pubkey(), false),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            ],
+            data,
+        };
+
+        ix.accounts[8].pubkey = TOKEN_2022_PROGRAM_ID;
+
+        let res = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::InvalidTokenProgram);
+    }
+
+    #[test]
+    fn throw_if_vault_mismatch() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let contribute_amount: u64 = 500_000;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        let data = vec![2u8];
+        let mut ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new(maker.pubkey(), false),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            ],
+            data,
+        };
+
+        // a token account of the mint held by the fundraise, but not the recorded vault
+        let decoy_vault = Pubkey::new_unique();
+        init_token_account(litesvm, decoy_vault, mint_to_raise, fundraise_pda, 0);
+
+        ix.accounts[6].pubkey = decoy_vault;
+
+        let res = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+        let failed = res.unwrap_err();
+
+        assert!(
+            failed
+                .meta
+                .logs
+                .contains(&"Program log: account #6 (vault) failed vault check".to_string())
+        );
+        assert_error(failed, FundraiserError::InvalidAddress);
+    }
+
+    #[test]
+    fn throw_if_accounts_not_writable() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let contribute_amount: u64 = 500_000;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        for index in [1, 3, 4, 5, 6] {
+            litesvm.expire_blockhash();
+
+            let data = vec![2u8];
+            let mut ix = Instruction {
+                program_id: PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new(authority.pubkey(), true),
+                    AccountMeta::new(maker.pubkey(), false),
+                    AccountMeta::new_readonly(mint_to_raise, false),
+                    AccountMeta::new(fundraise_pda, false),
+                    AccountMeta::new(contributor_pda, false),
+                    AccountMeta::new(authority_ata, false),
+                    AccountMeta::new(vault, false),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                ],
+                data,
+            };
+
+            ix.accounts[index].is_writable = false;
+
+            let res =
+                build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+            assert_error(res.unwrap_err(), FundraiserError::AccountNotWritable);
+        }
+    }
+
+    #[test]
+    fn refund_to_closed_token_account() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let contribute_amount: u64 = 500_000;
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        // fund the authority with exactly one contribution so its account is empty afterwards
+        let authority_ata = init_ata(
+            litesvm,
+            mint_to_raise,
+            authority.pubkey(),
+            contribute_amount,
+        );
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+
+        let ix = close_account(
+            &TOKEN_PROGRAM_ID,
+            &authority_ata,
+            &authority.pubkey(),
+            &authority.pubkey(),
+            &[],
+        )
+        .unwrap();
+
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+
+        assert!(
+            litesvm
+                .get_account(&authority_ata)
+                .is_none_or(|acc| acc.data.is_empty())
+        );
+
+        let data = vec![2u8];
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new(maker.pubkey(), false),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            ],
+            data,
+        };
+
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+
+        let authority_bal = fetch_account::<Account>(litesvm, &authority_ata).amount;
+
+        assert_eq!(authority_bal, contribute_amount);
+    }
+
+    #[test]
+    fn refund_rent_to_sponsor() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let sponsor = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000);
+
+        let ix = client::initialize(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            5_000_000,
+            SECONDS_PER_DAY,
+        );
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let ix = client::with_rent_payer(
+            client::contribute(
+                &authority.pubkey(),
+                &maker.pubkey(),
+                &mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+                500_000,
+            ),
+            &sponsor.pubkey(),
+        );
+        build_and_send_transaction(litesvm, &[&sponsor, &authority], &sponsor.pubkey(), &[ix])
+            .unwrap();
+
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+        let contributor_acc = litesvm.get_account(&contributor_pda).unwrap();
+
+        assert_eq!(
+            Contributor::load(contributor_acc.data.as_ref())
+                .unwrap()
+                .rent_payer,
+            sponsor.pubkey().to_bytes()
+        );
+
+        let refund_ix = client::refund(
+            &authority.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        // the rent cannot go back to the authority, who never paid it
+        let res = build_and_send_transaction(
+            litesvm,
+            &[&authority],
+            &authority.pubkey(),
+            core::slice::from_ref(&refund_ix),
+        );
+
+        assert_error(res.unwrap_err(), FundraiserError::InvalidRentDestination);
+
+        let ix = client::with_rent_destination(refund_ix.clone(), &authority.pubkey());
+        let res = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::InvalidRentDestination);
+
+        let pre_authority_bal = litesvm.get_balance(&authority.pubkey()).unwrap();
+        let pre_sponsor_bal = litesvm.get_balance(&sponsor.pubkey()).unwrap();
+
+        let ix = client::with_rent_destination(refund_ix, &sponsor.pubkey());
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+
+        assert!(litesvm.get_account(&contributor_pda).is_none());
+        assert_eq!(
+            litesvm.get_balance(&sponsor.pubkey()).unwrap(),
+            pre_sponsor_bal + contributor_acc.lamports
+        );
+        assert_eq!(
+            litesvm.get_balance(&authority.pubkey()).unwrap(),
+            pre_authority_bal - 5_000
+        );
+    }
+
+    #[test]
+    fn partial_refund_lowers_tier() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        init_ata(litesvm, mint_to_raise, authority.pubkey(), 500_000);
+
+        let ix = client::with_tier_thresholds(
+            client::initialize(
+                &maker.pubkey(),
+                &mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+                5_000_000,
+                SECONDS_PER_DAY,
+            ),
+            [300_000, 0, 0, 0],
+        );
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let ix = client::contribute(
+            &authority.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            500_000,
+        );
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+
+        let contributor_pda =
+            get_contributor_pda(&get_fundraise_pda(&maker.pubkey()), &authority.pubkey());
+        let contributor_acc = litesvm.get_account(&contributor_pda).unwrap();
+
+        assert_eq!(
+            Contributor::load(contributor_acc.data.as_ref())
+                .unwrap()
+                .get_tier(),
+            1
+        );
+
+        let mut ix = client::refund(
+            &authority.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+        ix.data = cpi::refund_ix_data(300_000).to_vec();
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+
+        let contributor_acc = litesvm.get_account(&contributor_pda).unwrap();
+        let contributor = Contributor::load(contributor_acc.data.as_ref()).unwrap();
+
+        assert_eq!(contributor.get_amount(), 200_000);
+        assert_eq!(contributor.get_tier(), 0);
+    }
+
+    #[test]
+    fn refund_burns_receipt() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let receipt_mint = Keypair::new();
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        init_ata(litesvm, mint_to_raise, authority.pubkey(), 500_000);
+
+        let ix = client::with_receipt_mint(
+            client::initialize(
+                &maker.pubkey(),
+                &mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+                5_000_000,
+                SECONDS_PER_DAY,
+            ),
+            &receipt_mint.pubkey(),
+        );
+        build_and_send_transaction(litesvm, &[&maker, &receipt_mint], &maker.pubkey(), &[ix])
+            .unwrap();
+        let receipt_ata = init_ata(litesvm, receipt_mint.pubkey(), authority.pubkey(), 0);
+
+        let ix = client::with_receipt(
+            client::contribute(
+                &authority.pubkey(),
+                &maker.pubkey(),
+                &mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+                500_000,
+            ),
+            &receipt_mint.pubkey(),
+            &authority.pubkey(),
+        );
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+
+        let mut ix = client::with_receipt(
+            client::refund(
+                &authority.pubkey(),
+                &maker.pubkey(),
+                &mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+            ),
+            &receipt_mint.pubkey(),
+            &authority.pubkey(),
+        );
+        ix.data = cpi::refund_ix_data(300_000).to_vec();
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+
+        assert_eq!(
+            fetch_account::<Account>(litesvm, &receipt_ata).amount,
+            200_000
+        );
+
+        let receipt_mint_acc = litesvm.get_account(&receipt_mint.pubkey()).unwrap();
+
+        assert_eq!(
+            StateWithExtensions::<Mint>::unpack(&receipt_mint_acc.data)
+                .unwrap()
+                .base
+                .supply,
+            200_000
+        );
+    }
+
+    #[test]
+    fn throw_if_token_accounts_frozen() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 500_000);
+
+        let ix = client::initialize(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            5_000_000,
+            SECONDS_PER_DAY,
+        );
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let ix = client::contribute(
+            &authority.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            500_000,
+        );
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+
+        let refund_ix = client::refund(
+            &authority.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        freeze_token_account(litesvm, authority_ata);
+
+        let res = build_and_send_transaction(
+            litesvm,
+            &[&authority],
+            &authority.pubkey(),
+            core::slice::from_ref(&refund_ix),
+        );
+
+        assert_error(res.unwrap_err(), FundraiserError::DestinationFrozen);
+
+        init_ata(litesvm, mint_to_raise, authority.pubkey(), 0);
+        freeze_token_account(litesvm, get_vault_pda(&get_fundraise_pda(&maker.pubkey())));
+
+        // a fresh blockhash so the repeat is not rejected as a duplicate
+        litesvm.expire_blockhash();
+
+        let res =
+            build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[refund_ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::VaultFrozen);
+    }
+
+    /// A running fundraise in which `authority` is the only backer, holding 500_000, followed by
+    /// the instructions that refund and re-contribute the position.
+    fn refund_and_contribute_ixs(
+        litesvm: &mut LiteSVM,
+        maker: &Keypair,
+        authority: &Keypair,
+    ) -> [Instruction; 2] {
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        init_ata(litesvm, mint_to_raise, authority.pubkey(), 500_000);
+
+        let ix = client::initialize(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            5_000_000,
+            SECONDS_PER_DAY,
+        );
+        build_and_send_transaction(litesvm, &[maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let ix = client::contribute(
+            &authority.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            500_000,
+        );
+        build_and_send_transaction(litesvm, &[authority], &authority.pubkey(), &[ix]).unwrap();
+
+        [
+            client::refund(
+                &authority.pubkey(),
+                &maker.pubkey(),
+                &mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+            ),
+            client::contribute(
+                &authority.pubkey(),
+                &maker.pubkey(),
+                &mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+                200_000,
+            ),
+        ]
+    }
+
+    fn contributor_amount(litesvm: &LiteSVM, maker: &Keypair, authority: &Keypair) -> u64 {
+        let contributor_pda =
+            get_contributor_pda(&get_fundraise_pda(&maker.pubkey()), &authority.pubkey());
+        let contributor_acc = litesvm.get_account(&contributor_pda).unwrap();
+
+        assert_eq!(contributor_acc.data.len(), Contributor::LEN);
+
+        Contributor::load(contributor_acc.data.as_ref())
+            .unwrap()
+            .get_amount()
+    }
+
+    #[test]
+    fn contribute_again_after_full_refund() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let [refund_ix, contribute_ix] = refund_and_contribute_ixs(litesvm, &maker, &authority);
+
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[refund_ix])
+            .unwrap();
+
+        let contributor_pda =
+            get_contributor_pda(&get_fundraise_pda(&maker.pubkey()), &authority.pubkey());
+
+        assert!(litesvm.get_account(&contributor_pda).is_none());
+
+        build_and_send_transaction(
+            litesvm,
+            &[&authority],
+            &authority.pubkey(),
+            &[contribute_ix],
+        )
+        .unwrap();
+
+        assert_eq!(contributor_amount(litesvm, &maker, &authority), 200_000);
+    }
+
+    #[test]
+    fn refund_and_contribute_in_one_transaction() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let ixs = refund_and_contribute_ixs(litesvm, &maker, &authority);
+
+        // the refund closes the contributor account the contribute then recreates
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &ixs).unwrap();
+
+        assert_eq!(contributor_amount(litesvm, &maker, &authority), 200_000);
+    }
+
+    #[test]
+    fn throw_if_contributor_mint_mismatch() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let [refund_ix, _] = refund_and_contribute_ixs(litesvm, &maker, &authority);
+
+        let contributor_pda =
+            get_contributor_pda(&get_fundraise_pda(&maker.pubkey()), &authority.pubkey());
+        let mut contributor_acc = litesvm.get_account(&contributor_pda).unwrap();
+        Contributor::load_mut(contributor_acc.data.as_mut())
+            .unwrap()
+            .mint = Pubkey::new_unique().to_bytes();
+        litesvm
+            .set_account(contributor_pda, contributor_acc)
+            .unwrap();
+
+        let res =
+            build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[refund_ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::InvalidMintToRaise);
+    }
+
+    #[test]
+    fn vault_survives_last_refund_of_live_campaign() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let [refund_ix, contribute_ix] = refund_and_contribute_ixs(litesvm, &maker, &authority);
+        let vault = get_vault_pda(&get_fundraise_pda(&maker.pubkey()));
+
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[refund_ix])
+            .unwrap();
+
+        assert_eq!(fetch_account::<Account>(litesvm, &vault).amount, 0);
+
+        build_and_send_transaction(
+            litesvm,
+            &[&authority],
+            &authority.pubkey(),
+            &[contribute_ix],
+        )
+        .unwrap();
+
+        assert_eq!(fetch_account::<Account>(litesvm, &vault).amount, 200_000);
+    }
+
+    #[test]
+    fn refund_after_vault_topped_up_past_target() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let [refund_ix, _] = refund_and_contribute_ixs(litesvm, &maker, &authority);
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+        let mint_to_raise = fetch_account::<Account>(litesvm, &vault).mint;
+
+        // an outsider sends the target straight to the vault, which is never recorded as raised
+        init_token_account(litesvm, vault, mint_to_raise, fundraise_pda, 5_500_000);
+
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[refund_ix])
+            .unwrap();
+
+        let authority_ata = client::associated_token_address(
+            &authority.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        assert_eq!(
+            fetch_account::<Account>(litesvm, &authority_ata).amount,
+            500_000
+        );
+        assert_eq!(fetch_account::<Account>(litesvm, &vault).amount, 5_000_000);
+    }
+
+    #[test]
+    fn refund_rent_to_explicit_authority() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let [refund_ix, _] = refund_and_contribute_ixs(litesvm, &maker, &authority);
+        let contributor_pda =
+            get_contributor_pda(&get_fundraise_pda(&maker.pubkey()), &authority.pubkey());
+        let rent = litesvm.get_account(&contributor_pda).unwrap().lamports;
+        let pre_authority_bal = litesvm.get_balance(&authority.pubkey()).unwrap();
+
+        // an unrelated account never paid the rent, so it cannot take it
+        let ix = client::with_rent_destination(refund_ix.clone(), &Pubkey::new_unique());
+        let res = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::InvalidRentDestination);
+
+        // naming the authority is the same as leaving the destination out
+        let ix = client::with_rent_destination(refund_ix, &authority.pubkey());
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+
+        assert!(litesvm.get_account(&contributor_pda).is_none());
+        assert_eq!(
+            litesvm.get_balance(&authority.pubkey()).unwrap(),
+            pre_authority_bal + rent - 5_000
+        );
     }
 }
@@ -0,0 +1,824 @@
+use core::mem::size_of;
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    cpi::invoke_signed_with_bounds,
+    instruction::{AccountMeta, Instruction, Seed, Signer},
+    program_error::ProgramError,
+    pubkey::{Pubkey, find_program_address},
+};
+
+use crate::{
+    AccountCheck, AccountLoad, Contributor, Fundraise, FundraiserError, FundraiserInstruction,
+    HOOK_AUTHORITY_SEED, HOOK_MAX_ACCOUNTS, Handler, Prefix, ProgramAccount, WritableAccount,
+    check_account,
+};
+
+/// Accounts forwarded ahead of the registered template: hook authority, fundraise, contributor.
+const HOOK_FIXED_ACCOUNTS: usize = 3;
+const HOOK_DATA_LEN: usize = size_of::<Pubkey>() + size_of::<u64>() + size_of::<u64>();
+
+pub struct RunHookAccounts<'a> {
+    pub fundraise: &'a AccountInfo,
+    pub contributor: &'a AccountInfo,
+    pub hook_authority: &'a AccountInfo,
+    pub hook_program: &'a AccountInfo,
+    pub hook_accounts: &'a [AccountInfo],
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for RunHookAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [
+            fundraise,
+            contributor,
+            hook_authority,
+            hook_program,
+            hook_accounts @ ..,
+        ] = accounts
+        else {
+            return Err(FundraiserError::MissingAccounts.into());
+        };
+
+        check_account!(0, "owner", ProgramAccount::check(fundraise))?;
+        check_account!(1, "owner", ProgramAccount::check(contributor))?;
+        check_account!(0, "writable", WritableAccount::check(fundraise))?;
+        check_account!(1, "writable", WritableAccount::check(contributor))?;
+
+        if hook_accounts.len() > HOOK_MAX_ACCOUNTS {
+            return Err(FundraiserError::InvalidHookAccounts.into());
+        }
+
+        Ok(Self {
+            fundraise,
+            contributor,
+            hook_authority,
+            hook_program,
+            hook_accounts,
+        })
+    }
+}
+
+/// Permissionlessly delivers a contributor's queued contribution details to the fundraise's
+/// registered hook program. Running the hook in its own instruction keeps a failing or
+/// misbehaving hook from ever reverting the contribution itself.
+pub struct RunHook<'a> {
+    pub accounts: RunHookAccounts<'a>,
+    pub bump: u8,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for RunHook<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let accounts = RunHookAccounts::try_from(accounts)?;
+
+        let (pda, bump) =
+            find_program_address(&[HOOK_AUTHORITY_SEED, accounts.fundraise.key()], &crate::ID);
+
+        if pda != *accounts.hook_authority.key() {
+            return Err(FundraiserError::InvalidAddress.into());
+        }
+
+        Ok(Self { accounts, bump })
+    }
+}
+
+impl Handler for RunHook<'_> {
+    const DISCRIMINATOR: u8 = FundraiserInstruction::RunHook as u8;
+
+    fn process(&mut self) -> ProgramResult {
+        let mut fundraise_data = self.accounts.fundraise.try_borrow_mut_data()?;
+        let fundraise = Fundraise::load_mut(fundraise_data.as_mut())?;
+
+        let fundraise_seeds = &[Fundraise::PREFIX, &fundraise.maker, &[fundraise.bump]];
+
+        ProgramAccount::validate(fundraise_seeds, *self.accounts.fundraise.key())?;
+
+        if !fundraise.has_hook() || fundraise.hook_program.ne(self.accounts.hook_program.key()) {
+            return Err(FundraiserError::InvalidHookProgram.into());
+        }
+
+        if self.accounts.hook_accounts.len() != fundraise.hook_accounts_len as usize
+            || fundraise
+                .hook_template()
+                .zip(self.accounts.hook_accounts)
+                .any(|((key, is_writable), account)| {
+                    key.ne(account.key()) || (is_writable && !account.is_writable())
+                })
+        {
+            return Err(FundraiserError::InvalidHookAccounts.into());
+        }
+
+        let mut contributor_data = self.accounts.contributor.try_borrow_mut_data()?;
+        let contributor = Contributor::load_mut(contributor_data.as_mut())?;
+
+        let contributor_seeds = &[
+            Contributor::PREFIX,
+            &contributor.fundraise,
+            &contributor.authority,
+            &[contributor.bump],
+        ];
+
+        ProgramAccount::validate(contributor_seeds, *self.accounts.contributor.key())?;
+
+        if contributor.fundraise.ne(self.accounts.fundraise.key()) {
+            return Err(FundraiserError::InvalidAddress.into());
+        }
+
+        let pending_hook_amount = contributor.get_pending_hook_amount();
+
+        if pending_hook_amount == 0 {
+            return Err(FundraiserError::NoPendingHook.into());
+        }
+
+        let mut data = [0u8; HOOK_DATA_LEN];
+        data[..32].copy_from_slice(&contributor.authority);
+        data[32..40].copy_from_slice(&pending_hook_amount.to_le_bytes());
+        data[40..].copy_from_slice(&contributor.get_amount().to_le_bytes());
+
+        contributor.set_pending_hook_amount(0);
+        fundraise.set_pending_hooks(fundraise.get_pending_hooks() - 1);
+
+        let hook_writable = fundraise.hook_writable;
+
+        drop(contributor_data);
+        drop(fundraise_data);
+
+        let mut metas: [AccountMeta; HOOK_FIXED_ACCOUNTS + HOOK_MAX_ACCOUNTS] =
+            core::array::from_fn(|_| AccountMeta::readonly(self.accounts.hook_authority.key()));
+        let mut infos = [self.accounts.hook_authority; HOOK_FIXED_ACCOUNTS + HOOK_MAX_ACCOUNTS];
+
+        metas[0] = AccountMeta::readonly_signer(self.accounts.hook_authority.key());
+        metas[1] = AccountMeta::readonly(self.accounts.fundraise.key());
+        metas[2] = AccountMeta::readonly(self.accounts.contributor.key());
+        infos[1] = self.accounts.fundraise;
+        infos[2] = self.accounts.contributor;
+
+        for (i, account) in self.accounts.hook_accounts.iter().enumerate() {
+            metas[HOOK_FIXED_ACCOUNTS + i] =
+                AccountMeta::new(account.key(), hook_writable & (1 << i) != 0, false);
+            infos[HOOK_FIXED_ACCOUNTS + i] = account;
+        }
+
+        let len = HOOK_FIXED_ACCOUNTS + self.accounts.hook_accounts.len();
+
+        let hook_authority_bump = [self.bump];
+        let hook_authority_seeds = [
+            Seed::from(HOOK_AUTHORITY_SEED),
+            Seed::from(self.accounts.fundraise.key().as_ref()),
+            Seed::from(&hook_authority_bump),
+        ];
+
+        invoke_signed_with_bounds::<{ HOOK_FIXED_ACCOUNTS + HOOK_MAX_ACCOUNTS }>(
+            &Instruction {
+                program_id: self.accounts.hook_program.key(),
+                data: &data,
+                accounts: &metas[..len],
+            },
+            &infos[..len],
+            &[Signer::from(&hook_authority_seeds)],
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use litesvm::LiteSVM;
+    use solana_instruction::{AccountMeta, Instruction, error::InstructionError};
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use spl_associated_token_account::solana_program::{
+        clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL,
+    };
+
+    use crate::{
+        AccountLoad, Contributor, Fundraise, FundraiserError, FundraiserInstruction,
+        tests::{
+            constants::{
+                ASSOCIATED_TOKEN_PROGRAM_ID, MINT_DECIMALS, PROGRAM_ID, SYSTEM_PROGRAM_ID,
+                TEST_HOOK_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID,
+            },
+            pda::{
+                get_config_pda, get_contributor_pda, get_fundraise_pda, get_hook_authority_pda,
+                get_vault_pda,
+            },
+            utils::{
+                assert_error, assert_instruction_error, build_and_send_transaction, init_ata,
+                init_leaderboard, init_mint, init_wallet, setup,
+            },
+        },
+    };
+
+    #[test]
+    fn run_hook() {
+        let (litesvm, _default_payer) = &mut setup();
+        let leaderboard = init_leaderboard(litesvm);
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let cranker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+                AccountMeta::new_readonly(TEST_HOOK_PROGRAM_ID, false),
+                AccountMeta::new(leaderboard, false),
+            ],
+            data,
+        };
+
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let fundraise_acc = litesvm.get_account(&fundraise_pda).unwrap();
+        let fundraise = Fundraise::load(fundraise_acc.data.as_ref()).unwrap();
+
+        assert_eq!(fundraise.hook_program, TEST_HOOK_PROGRAM_ID.to_bytes());
+        assert_eq!(fundraise.hook_accounts_len, 1);
+        assert_eq!(fundraise.hook_accounts[0], leaderboard.to_bytes());
+
+        let contribute_amount: u64 = 200_000;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        for _ in 0..2 {
+            litesvm.expire_blockhash();
+
+            let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+            let ix = Instruction {
+                program_id: PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new(authority.pubkey(), true),
+                    AccountMeta::new_readonly(mint_to_raise, false),
+                    AccountMeta::new(fundraise_pda, false),
+                    AccountMeta::new(contributor_pda, false),
+                    AccountMeta::new(authority_ata, false),
+                    AccountMeta::new(vault, false),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(get_config_pda(), false),
+                ],
+                data,
+            };
+
+            build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+        }
+
+        let fundraise_acc = litesvm.get_account(&fundraise_pda).unwrap();
+        let fundraise = Fundraise::load(fundraise_acc.data.as_ref()).unwrap();
+
+        assert_eq!(fundraise.get_pending_hooks(), 1);
+
+        let contributor_acc = litesvm.get_account(&contributor_pda).unwrap();
+        let contributor = Contributor::load(contributor_acc.data.as_ref()).unwrap();
+
+        assert_eq!(contributor.get_pending_hook_amount(), contribute_amount * 2);
+
+        let data = vec![14u8];
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new_readonly(get_hook_authority_pda(&fundraise_pda), false),
+                AccountMeta::new_readonly(TEST_HOOK_PROGRAM_ID, false),
+                AccountMeta::new(leaderboard, false),
+            ],
+            data,
+        };
+
+        build_and_send_transaction(litesvm, &[&cranker], &cranker.pubkey(), &[ix]).unwrap();
+
+        let leaderboard_acc = litesvm.get_account(&leaderboard).unwrap();
+
+        assert_eq!(
+            u64::from_le_bytes(leaderboard_acc.data[0..8].try_into().unwrap()),
+            contribute_amount * 2
+        );
+        assert_eq!(
+            u64::from_le_bytes(leaderboard_acc.data[8..16].try_into().unwrap()),
+            1
+        );
+
+        let fundraise_acc = litesvm.get_account(&fundraise_pda).unwrap();
+        let fundraise = Fundraise::load(fundraise_acc.data.as_ref()).unwrap();
+
+        assert_eq!(fundraise.get_pending_hooks(), 0);
+
+        let contributor_acc = litesvm.get_account(&contributor_pda).unwrap();
+        let contributor = Contributor::load(contributor_acc.data.as_ref()).unwrap();
+
+        assert_eq!(contributor.get_pending_hook_amount(), 0);
+        assert_eq!(contributor.get_amount(), contribute_amount * 2);
+    }
+
+    #[test]
+    fn throw_if_no_pending_hook() {
+        let (litesvm, _default_payer) = &mut setup();
+        let leaderboard = init_leaderboard(litesvm);
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+                AccountMeta::new_readonly(TEST_HOOK_PROGRAM_ID, false),
+                AccountMeta::new(leaderboard, false),
+            ],
+            data,
+        };
+
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let contribute_amount: u64 = 200_000;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+
+        let data = vec![14u8];
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new_readonly(get_hook_authority_pda(&fundraise_pda), false),
+                AccountMeta::new_readonly(TEST_HOOK_PROGRAM_ID, false),
+                AccountMeta::new(leaderboard, false),
+            ],
+            data,
+        };
+
+        build_and_send_transaction(
+            litesvm,
+            &[&authority],
+            &authority.pubkey(),
+            core::slice::from_ref(&ix),
+        )
+        .unwrap();
+
+        litesvm.expire_blockhash();
+
+        let res = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::NoPendingHook);
+    }
+
+    #[test]
+    fn throw_if_hook_accounts_mismatch() {
+        let (litesvm, _default_payer) = &mut setup();
+        let leaderboard = init_leaderboard(litesvm);
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+                AccountMeta::new_readonly(TEST_HOOK_PROGRAM_ID, false),
+                AccountMeta::new(leaderboard, false),
+            ],
+            data,
+        };
+
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let contribute_amount: u64 = 200_000;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+
+        let fake_leaderboard = init_leaderboard(litesvm);
+
+        let data = vec![14u8];
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new_readonly(get_hook_authority_pda(&fundraise_pda), false),
+                AccountMeta::new_readonly(TEST_HOOK_PROGRAM_ID, false),
+                AccountMeta::new(fake_leaderboard, false),
+            ],
+            data,
+        };
+
+        let res = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::InvalidHookAccounts);
+    }
+
+    #[test]
+    fn throw_if_accounts_not_writable() {
+        let (litesvm, _default_payer) = &mut setup();
+        let leaderboard = init_leaderboard(litesvm);
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+                AccountMeta::new_readonly(TEST_HOOK_PROGRAM_ID, false),
+                AccountMeta::new(leaderboard, false),
+            ],
+            data,
+        };
+
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let contribute_amount: u64 = 200_000;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+
+        for index in [0, 1] {
+            litesvm.expire_blockhash();
+
+            let data = vec![14u8];
+            let mut ix = Instruction {
+                program_id: PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new(fundraise_pda, false),
+                    AccountMeta::new(contributor_pda, false),
+                    AccountMeta::new_readonly(get_hook_authority_pda(&fundraise_pda), false),
+                    AccountMeta::new_readonly(TEST_HOOK_PROGRAM_ID, false),
+                    AccountMeta::new(leaderboard, false),
+                ],
+                data,
+            };
+
+            ix.accounts[index].is_writable = false;
+
+            let res =
+                build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+            assert_error(res.unwrap_err(), FundraiserError::AccountNotWritable);
+        }
+    }
+
+    #[test]
+    fn throw_if_hook_program_forbidden() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        for hook_program in [
+            PROGRAM_ID,
+            TOKEN_PROGRAM_ID,
+            TOKEN_2022_PROGRAM_ID,
+            maker.pubkey(),
+        ] {
+            litesvm.expire_blockhash();
+
+            let data = [
+                vec![0u8],
+                amount_to_raise.to_le_bytes().to_vec(),
+                duration.to_le_bytes().to_vec(),
+                0u64.to_le_bytes().to_vec(),
+                0u16.to_le_bytes().to_vec(),
+                1_000u16.to_le_bytes().to_vec(),
+                0u64.to_le_bytes().to_vec(),
+            ]
+            .concat();
+            let ix = Instruction {
+                program_id: PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new(maker.pubkey(), true),
+                    AccountMeta::new_readonly(mint_to_raise, false),
+                    AccountMeta::new(fundraise_pda, false),
+                    AccountMeta::new(vault, false),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(get_config_pda(), false),
+                    AccountMeta::new_readonly(hook_program, false),
+                ],
+                data,
+            };
+
+            let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+            assert_error(res.unwrap_err(), FundraiserError::InvalidHookProgram);
+        }
+    }
+
+    /// Registers the test hook over `leaderboard` and contributes `amount` once, leaving one
+    /// hook pending.
+    fn initialize_with_hook_and_contribute(
+        litesvm: &mut LiteSVM,
+        leaderboard: Pubkey,
+        amount: u64,
+    ) -> (Pubkey, Pubkey) {
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            5_000_000u64.to_le_bytes().to_vec(),
+            SECONDS_PER_DAY.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+                AccountMeta::new_readonly(TEST_HOOK_PROGRAM_ID, false),
+                AccountMeta::new(leaderboard, false),
+            ],
+            data,
+        };
+
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+
+        (fundraise_pda, contributor_pda)
+    }
+
+    fn run_hook_ix(fundraise: Pubkey, contributor: Pubkey, hook_program: Pubkey) -> Instruction {
+        Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(fundraise, false),
+                AccountMeta::new(contributor, false),
+                AccountMeta::new_readonly(get_hook_authority_pda(&fundraise), false),
+                AccountMeta::new_readonly(hook_program, false),
+            ],
+            data: vec![FundraiserInstruction::RunHook as u8],
+        }
+    }
+
+    #[test]
+    fn throw_if_run_hook_targets_other_program() {
+        let (litesvm, _default_payer) = &mut setup();
+        let leaderboard = init_leaderboard(litesvm);
+        let cranker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let (fundraise, contributor) =
+            initialize_with_hook_and_contribute(litesvm, leaderboard, 200_000);
+
+        for hook_program in [PROGRAM_ID, TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID] {
+            litesvm.expire_blockhash();
+
+            let mut ix = run_hook_ix(fundraise, contributor, hook_program);
+            ix.accounts.push(AccountMeta::new(leaderboard, false));
+
+            let res = build_and_send_transaction(litesvm, &[&cranker], &cranker.pubkey(), &[ix]);
+
+            assert_error(res.unwrap_err(), FundraiserError::InvalidHookProgram);
+        }
+
+        let fundraise_acc = litesvm.get_account(&fundraise).unwrap();
+
+        assert_eq!(
+            Fundraise::load(fundraise_acc.data.as_ref())
+                .unwrap()
+                .get_pending_hooks(),
+            1
+        );
+    }
+
+    #[test]
+    fn failing_hook_keeps_the_hook_pending() {
+        let (litesvm, _default_payer) = &mut setup();
+        let leaderboard = init_leaderboard(litesvm);
+        let cranker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let contribute_amount: u64 = 200_000;
+        let (fundraise, contributor) =
+            initialize_with_hook_and_contribute(litesvm, leaderboard, contribute_amount);
+
+        // the toy hook only tallies into a leaderboard it owns, so handing the leaderboard to
+        // another owner makes the CPI fail
+        let mut leaderboard_acc = litesvm.get_account(&leaderboard).unwrap();
+        leaderboard_acc.owner = SYSTEM_PROGRAM_ID;
+        litesvm
+            .set_account(leaderboard, leaderboard_acc.clone())
+            .unwrap();
+
+        let mut ix = run_hook_ix(fundraise, contributor, TEST_HOOK_PROGRAM_ID);
+        ix.accounts.push(AccountMeta::new(leaderboard, false));
+
+        let res = build_and_send_transaction(
+            litesvm,
+            &[&cranker],
+            &cranker.pubkey(),
+            core::slice::from_ref(&ix),
+        );
+
+        assert_instruction_error(res.unwrap_err(), InstructionError::InvalidArgument);
+
+        let contributor_acc = litesvm.get_account(&contributor).unwrap();
+        let contributor_state = Contributor::load(contributor_acc.data.as_ref()).unwrap();
+
+        assert_eq!(
+            contributor_state.get_pending_hook_amount(),
+            contribute_amount
+        );
+        assert_eq!(contributor_state.get_amount(), contribute_amount);
+
+        // once the hook can succeed again, the same crank delivers the queued contribution
+        leaderboard_acc.owner = TEST_HOOK_PROGRAM_ID;
+        litesvm.set_account(leaderboard, leaderboard_acc).unwrap();
+        litesvm.expire_blockhash();
+
+        build_and_send_transaction(litesvm, &[&cranker], &cranker.pubkey(), &[ix]).unwrap();
+
+        let leaderboard_acc = litesvm.get_account(&leaderboard).unwrap();
+
+        assert_eq!(
+            u64::from_le_bytes(leaderboard_acc.data[0..8].try_into().unwrap()),
+            contribute_amount
+        );
+        assert_eq!(
+            u64::from_le_bytes(leaderboard_acc.data[8..16].try_into().unwrap()),
+            1
+        );
+    }
+}
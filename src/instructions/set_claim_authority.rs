@@ -0,0 +1,257 @@
+use core::mem::size_of;
+
+use pinocchio::{
+    ProgramResult, account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey,
+};
+
+use crate::{
+    AccountCheck, AccountLoad, Fundraise, FundraiserError, FundraiserInstruction, Handler, Prefix,
+    ProgramAccount, SignerAccount, WritableAccount, check_account,
+};
+
+pub struct SetClaimAuthorityAccounts<'a> {
+    pub maker: &'a AccountInfo,
+    pub fundraise: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SetClaimAuthorityAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [maker, fundraise] = accounts else {
+            return Err(FundraiserError::MissingAccounts.into());
+        };
+
+        check_account!(0, "signer", SignerAccount::check(maker))?;
+        check_account!(1, "writable", WritableAccount::check(fundraise))?;
+        check_account!(1, "owner", ProgramAccount::check(fundraise))?;
+
+        Ok(Self { maker, fundraise })
+    }
+}
+
+pub struct SetClaimAuthorityInstructionData {
+    /// The new claim authority, or all zeroes to hand the claim back to the maker.
+    pub claim_authority: Pubkey,
+}
+
+impl SetClaimAuthorityInstructionData {
+    pub const LEN: usize = size_of::<Pubkey>();
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        self.claim_authority
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for SetClaimAuthorityInstructionData {
+    type Error = ProgramError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        let claim_authority = data
+            .try_into()
+            .map_err(|_| ProgramError::InvalidInstructionData)?;
+
+        Ok(Self { claim_authority })
+    }
+}
+
+/// Lets the maker hand the claim of a fundraise to another key, such as a cold wallet or a
+/// multisig, which then signs the claim in the maker's place and receives its payout. The
+/// maker keeps every other say over the fundraise, and may move the claim on again until it
+/// is made.
+pub struct SetClaimAuthority<'a> {
+    pub accounts: SetClaimAuthorityAccounts<'a>,
+    pub data: SetClaimAuthorityInstructionData,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for SetClaimAuthority<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = SetClaimAuthorityAccounts::try_from(accounts)?;
+        let data = SetClaimAuthorityInstructionData::try_from(data)?;
+
+        Ok(Self { accounts, data })
+    }
+}
+
+impl Handler for SetClaimAuthority<'_> {
+    const DISCRIMINATOR: u8 = FundraiserInstruction::SetClaimAuthority as u8;
+
+    fn process(&mut self) -> ProgramResult {
+        let mut fundraise_data = self.accounts.fundraise.try_borrow_mut_data()?;
+        let fundraise = Fundraise::load_mut(fundraise_data.as_mut())?;
+
+        if fundraise.maker != *self.accounts.maker.key() {
+            return Err(FundraiserError::InvalidAddress.into());
+        }
+
+        ProgramAccount::validate(
+            &[Fundraise::PREFIX, &fundraise.maker, &[fundraise.bump]],
+            *self.accounts.fundraise.key(),
+        )?;
+
+        if fundraise.is_claimed() {
+            return Err(FundraiserError::AlreadyClaimed.into());
+        }
+
+        fundraise.claim_authority = if self.data.claim_authority == Pubkey::default() {
+            fundraise.maker
+        } else {
+            self.data.claim_authority
+        };
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use spl_associated_token_account::solana_program::{
+        clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL,
+    };
+    use spl_token_2022::state::Account;
+
+    use crate::{
+        Fundraise, FundraiserError, client,
+        tests::{
+            constants::{FEE_COLLECTOR, MINT_DECIMALS, TOKEN_PROGRAM_ID},
+            utils::{
+                assert_error, build_and_send_transaction, fetch_account, init_ata, init_config,
+                init_mint, init_wallet, setup,
+            },
+        },
+    };
+
+    const AMOUNT_TO_RAISE: u64 = 5_000_000;
+
+    fn initialize(litesvm: &mut LiteSVM) -> (Keypair, Pubkey) {
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        let ix = client::initialize(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            AMOUNT_TO_RAISE,
+            SECONDS_PER_DAY,
+        );
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        (maker, mint_to_raise)
+    }
+
+    fn meet_target(litesvm: &mut LiteSVM, maker: &Keypair, mint_to_raise: Pubkey) {
+        let backer = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        init_ata(litesvm, mint_to_raise, backer.pubkey(), AMOUNT_TO_RAISE);
+
+        let ix = client::contribute(
+            &backer.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            AMOUNT_TO_RAISE,
+        );
+        build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]).unwrap();
+
+        init_config(litesvm, maker.pubkey(), 0, FEE_COLLECTOR, false);
+    }
+
+    fn set_claim_authority(litesvm: &mut LiteSVM, maker: &Keypair, claim_authority: &Pubkey) {
+        let ix = client::set_claim_authority(&maker.pubkey(), claim_authority);
+        build_and_send_transaction(litesvm, &[maker], &maker.pubkey(), &[ix]).unwrap();
+        litesvm.expire_blockhash();
+    }
+
+    fn claim_authority(litesvm: &LiteSVM, maker: &Keypair) -> [u8; 32] {
+        let fundraise = litesvm
+            .get_account(&client::fundraise_address(&maker.pubkey()))
+            .unwrap();
+
+        Fundraise::unpack(&fundraise.data).unwrap().claim_authority
+    }
+
+    #[test]
+    fn claim_authority_claims_in_makers_place() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, mint_to_raise) = initialize(litesvm);
+        let treasury = init_wallet(litesvm, LAMPORTS_PER_SOL);
+
+        assert_eq!(claim_authority(litesvm, &maker), maker.pubkey().to_bytes());
+
+        set_claim_authority(litesvm, &maker, &treasury.pubkey());
+        meet_target(litesvm, &maker, mint_to_raise);
+
+        let claim = || {
+            client::claim(
+                &maker.pubkey(),
+                &mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+                &FEE_COLLECTOR,
+            )
+        };
+        let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[claim()]);
+
+        assert_error(res.unwrap_err(), FundraiserError::InvalidAddress);
+
+        let ix = client::with_claimant(claim(), &treasury.pubkey());
+        build_and_send_transaction(litesvm, &[&treasury], &treasury.pubkey(), &[ix]).unwrap();
+
+        let treasury_ata =
+            client::associated_token_address(&treasury.pubkey(), &mint_to_raise, &TOKEN_PROGRAM_ID);
+
+        assert_eq!(
+            fetch_account::<Account>(litesvm, &treasury_ata).amount,
+            AMOUNT_TO_RAISE
+        );
+    }
+
+    #[test]
+    fn zero_hands_claim_back_to_maker() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, _) = initialize(litesvm);
+
+        set_claim_authority(litesvm, &maker, &Pubkey::new_unique());
+        set_claim_authority(litesvm, &maker, &Pubkey::default());
+
+        assert_eq!(claim_authority(litesvm, &maker), maker.pubkey().to_bytes());
+    }
+
+    #[test]
+    fn throw_if_already_claimed() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, mint_to_raise) = initialize(litesvm);
+
+        meet_target(litesvm, &maker, mint_to_raise);
+
+        let ix = client::claim(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            &FEE_COLLECTOR,
+        );
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let ix = client::set_claim_authority(&maker.pubkey(), &Pubkey::new_unique());
+        let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::AlreadyClaimed);
+    }
+
+    #[test]
+    fn throw_if_signer_not_maker() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, _) = initialize(litesvm);
+        let other = init_wallet(litesvm, LAMPORTS_PER_SOL);
+
+        let mut ix = client::set_claim_authority(&other.pubkey(), &other.pubkey());
+        ix.accounts[1].pubkey = client::fundraise_address(&maker.pubkey());
+        let res = build_and_send_transaction(litesvm, &[&other], &other.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::InvalidAddress);
+    }
+}
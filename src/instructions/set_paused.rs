@@ -0,0 +1,271 @@
+use core::mem::size_of;
+
+use pinocchio::{ProgramResult, account_info::AccountInfo, program_error::ProgramError};
+
+use crate::{
+    AccountCheck, AccountLoad, Fundraise, FundraiserError, FundraiserInstruction, Handler, Prefix,
+    ProgramAccount, SignerAccount, WritableAccount, check_account,
+};
+
+pub struct SetPausedAccounts<'a> {
+    pub maker: &'a AccountInfo,
+    pub fundraise: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SetPausedAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [maker, fundraise] = accounts else {
+            return Err(FundraiserError::MissingAccounts.into());
+        };
+
+        check_account!(0, "signer", SignerAccount::check(maker))?;
+        check_account!(1, "writable", WritableAccount::check(fundraise))?;
+        check_account!(1, "owner", ProgramAccount::check(fundraise))?;
+
+        Ok(Self { maker, fundraise })
+    }
+}
+
+pub struct SetPausedInstructionData {
+    pub paused: u8,
+}
+
+impl SetPausedInstructionData {
+    pub const LEN: usize = size_of::<u8>();
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        [self.paused]
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for SetPausedInstructionData {
+    type Error = ProgramError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        let [paused] = data else {
+            return Err(ProgramError::InvalidInstructionData);
+        };
+
+        if *paused > 1 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        Ok(Self { paused: *paused })
+    }
+}
+
+/// Lets the maker hold off contributions to a fundraise, or let them back in. The deadline
+/// keeps running while paused, and refunds and claims are unaffected, so contributors can
+/// always get out.
+pub struct SetPaused<'a> {
+    pub accounts: SetPausedAccounts<'a>,
+    pub data: SetPausedInstructionData,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for SetPaused<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = SetPausedAccounts::try_from(accounts)?;
+        let data = SetPausedInstructionData::try_from(data)?;
+
+        Ok(Self { accounts, data })
+    }
+}
+
+impl Handler for SetPaused<'_> {
+    const DISCRIMINATOR: u8 = FundraiserInstruction::SetPaused as u8;
+
+    fn process(&mut self) -> ProgramResult {
+        let mut fundraise_data = self.accounts.fundraise.try_borrow_mut_data()?;
+        let fundraise = Fundraise::load_mut(fundraise_data.as_mut())?;
+
+        if fundraise.maker != *self.accounts.maker.key() {
+            return Err(FundraiserError::InvalidAddress.into());
+        }
+
+        ProgramAccount::validate(
+            &[Fundraise::PREFIX, &fundraise.maker, &[fundraise.bump]],
+            *self.accounts.fundraise.key(),
+        )?;
+
+        fundraise.paused = self.data.paused;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use spl_associated_token_account::solana_program::{
+        clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL,
+    };
+
+    use crate::{
+        Fundraise, FundraiserError, client,
+        tests::{
+            constants::{MINT_DECIMALS, TOKEN_PROGRAM_ID},
+            utils::{
+                assert_error, build_and_send_transaction, forward_time, init_ata, init_mint,
+                init_wallet, setup,
+            },
+        },
+    };
+
+    fn initialize(litesvm: &mut LiteSVM) -> (Keypair, Pubkey) {
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        let ix = client::initialize(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            5_000_000,
+            SECONDS_PER_DAY,
+        );
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        (maker, mint_to_raise)
+    }
+
+    fn backer(litesvm: &mut LiteSVM, mint_to_raise: Pubkey) -> Keypair {
+        let backer = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        init_ata(litesvm, mint_to_raise, backer.pubkey(), 1_000_000);
+
+        backer
+    }
+
+    fn set_paused(litesvm: &mut LiteSVM, maker: &Keypair, paused: bool) {
+        let ix = client::set_paused(&maker.pubkey(), paused);
+        build_and_send_transaction(litesvm, &[maker], &maker.pubkey(), &[ix]).unwrap();
+        litesvm.expire_blockhash();
+    }
+
+    #[test]
+    fn pause_blocks_contribute() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, mint_to_raise) = initialize(litesvm);
+        let backer = backer(litesvm, mint_to_raise);
+
+        set_paused(litesvm, &maker, true);
+
+        let ix = client::contribute(
+            &backer.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            500_000,
+        );
+        let res = build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::FundraisePaused);
+
+        let fundraise = litesvm
+            .get_account(&client::fundraise_address(&maker.pubkey()))
+            .unwrap();
+
+        assert_eq!(Fundraise::unpack(&fundraise.data).unwrap().paused, 1);
+    }
+
+    #[test]
+    fn refund_while_paused() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, mint_to_raise) = initialize(litesvm);
+        let backer = backer(litesvm, mint_to_raise);
+
+        let ix = client::contribute(
+            &backer.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            500_000,
+        );
+        build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]).unwrap();
+
+        set_paused(litesvm, &maker, true);
+
+        let ix = client::refund(
+            &backer.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+        build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]).unwrap();
+
+        let contributor = client::contributor_address(
+            &client::fundraise_address(&maker.pubkey()),
+            &backer.pubkey(),
+        );
+
+        assert!(litesvm.get_account(&contributor).is_none());
+    }
+
+    #[test]
+    fn resume_restores_contribute() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, mint_to_raise) = initialize(litesvm);
+        let backer = backer(litesvm, mint_to_raise);
+
+        set_paused(litesvm, &maker, true);
+        set_paused(litesvm, &maker, false);
+
+        let ix = client::contribute(
+            &backer.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            500_000,
+        );
+        build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]).unwrap();
+
+        let fundraise = litesvm
+            .get_account(&client::fundraise_address(&maker.pubkey()))
+            .unwrap();
+
+        assert_eq!(
+            Fundraise::unpack(&fundraise.data).unwrap().current_amount,
+            500_000
+        );
+    }
+
+    #[test]
+    fn pause_does_not_stop_the_clock() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, mint_to_raise) = initialize(litesvm);
+        let backer = backer(litesvm, mint_to_raise);
+
+        set_paused(litesvm, &maker, true);
+        forward_time(litesvm, SECONDS_PER_DAY as i64 + 1);
+        set_paused(litesvm, &maker, false);
+
+        let ix = client::contribute(
+            &backer.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            500_000,
+        );
+        let res = build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::FundraiserEnded);
+    }
+
+    #[test]
+    fn throw_if_signer_not_maker() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, _) = initialize(litesvm);
+        let other = init_wallet(litesvm, LAMPORTS_PER_SOL);
+
+        let mut ix = client::set_paused(&other.pubkey(), true);
+        ix.accounts[1].pubkey = client::fundraise_address(&maker.pubkey());
+        let res = build_and_send_transaction(litesvm, &[&other], &other.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::InvalidAddress);
+    }
+}
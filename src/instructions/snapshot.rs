@@ -0,0 +1,289 @@
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{Sysvar, clock::Clock},
+};
+
+use crate::{
+    AccountCheck, AccountLoad, Contributor, Fundraise, FundraiseStatus, FundraiserError,
+    FundraiserInstruction, Handler, Prefix, ProgramAccount, WritableAccount, check_account,
+};
+
+pub struct SnapshotAccounts<'a> {
+    pub fundraise: &'a AccountInfo,
+    /// The fundraise's vault, checked against it. Whether the campaign has succeeded is judged by
+    /// the amount it recorded, not by this balance.
+    pub vault: &'a AccountInfo,
+    pub contributor: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SnapshotAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [fundraise, vault, contributor] = accounts else {
+            return Err(FundraiserError::MissingAccounts.into());
+        };
+
+        check_account!(0, "writable", WritableAccount::check(fundraise))?;
+        check_account!(2, "writable", WritableAccount::check(contributor))?;
+        check_account!(0, "owner", ProgramAccount::check(fundraise))?;
+        check_account!(2, "owner", ProgramAccount::check(contributor))?;
+
+        Ok(Self {
+            fundraise,
+            vault,
+            contributor,
+        })
+    }
+}
+
+/// Freezes a contributor's amount into their `snapshot_amount` and the fundraise's
+/// `snapshot_total`, for downstream programs to weigh governance power by. Anyone may send it
+/// once the target is met or a keep-it-all fundraise ends with funds in, and Refund and
+/// ClaimReward take the same snapshot lazily for any contributor not yet sent one. A snapshot is
+/// taken once and never rises after: refunds (which a successful all-or-nothing campaign does
+/// not allow, though keep-it-all does until its deadline) lower it to what is left, and a
+/// snapshotted position cannot be transferred. Sending it again is a no-op.
+pub struct Snapshot<'a> {
+    pub accounts: SnapshotAccounts<'a>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for Snapshot<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        Ok(Self {
+            accounts: SnapshotAccounts::try_from(accounts)?,
+        })
+    }
+}
+
+impl Handler for Snapshot<'_> {
+    const DISCRIMINATOR: u8 = FundraiserInstruction::Snapshot as u8;
+
+    fn process(&mut self) -> ProgramResult {
+        let mut fundraise_data = self.accounts.fundraise.try_borrow_mut_data()?;
+        let fundraise = Fundraise::load_mut(fundraise_data.as_mut())?;
+
+        ProgramAccount::validate(
+            &[Fundraise::PREFIX, &fundraise.maker, &[fundraise.bump]],
+            *self.accounts.fundraise.key(),
+        )?;
+        fundraise.check_vault(self.accounts.vault.key())?;
+
+        // the claim draws down the recorded amount, so a claimed fundraise is ready regardless
+        let ready = fundraise.is_claimed()
+            || matches!(
+                fundraise.status(Clock::get()?.unix_timestamp),
+                FundraiseStatus::TargetMet | FundraiseStatus::Claimable
+            );
+
+        if !ready {
+            return Err(FundraiserError::SnapshotNotReady.into());
+        }
+
+        let mut contributor_data = self.accounts.contributor.try_borrow_mut_data()?;
+        let contributor = Contributor::load_mut(contributor_data.as_mut())?;
+
+        ProgramAccount::validate(
+            &[
+                Contributor::PREFIX,
+                self.accounts.fundraise.key(),
+                &contributor.authority,
+                &[contributor.bump],
+            ],
+            *self.accounts.contributor.key(),
+        )?;
+
+        contributor.snapshot(fundraise)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use spl_associated_token_account::solana_program::{
+        clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL,
+    };
+
+    use crate::{
+        CampaignType, Contributor, ContributorView, Fundraise, FundraiseView, FundraiserError,
+        InitializeInstructionData, client, cpi,
+        tests::{
+            constants::{MINT_DECIMALS, TOKEN_PROGRAM_ID},
+            utils::{
+                assert_error, build_and_send_transaction, forward_time, init_ata, init_mint,
+                init_wallet, setup,
+            },
+        },
+    };
+
+    const AMOUNT_TO_RAISE: u64 = 5_000_000;
+
+    fn initialize(litesvm: &mut LiteSVM, campaign_type: CampaignType) -> (Keypair, Pubkey) {
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        let mut ix = client::initialize(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            AMOUNT_TO_RAISE,
+            SECONDS_PER_DAY,
+        );
+        ix.data = cpi::initialize_v1_ix_data(&InitializeInstructionData {
+            campaign_type,
+            ..InitializeInstructionData::new(AMOUNT_TO_RAISE, SECONDS_PER_DAY)
+        });
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        (maker, mint_to_raise)
+    }
+
+    fn contribute(
+        litesvm: &mut LiteSVM,
+        maker: &Keypair,
+        mint_to_raise: Pubkey,
+        amount: u64,
+    ) -> Keypair {
+        let contributor = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        init_ata(litesvm, mint_to_raise, contributor.pubkey(), amount);
+
+        let ix = client::contribute(
+            &contributor.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            amount,
+        );
+        build_and_send_transaction(litesvm, &[&contributor], &contributor.pubkey(), &[ix]).unwrap();
+
+        contributor
+    }
+
+    fn snapshot(litesvm: &mut LiteSVM, maker: &Keypair, contributor: &Keypair) {
+        let payer = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let ix = client::snapshot(&maker.pubkey(), &contributor.pubkey());
+        build_and_send_transaction(litesvm, &[&payer], &payer.pubkey(), &[ix]).unwrap();
+    }
+
+    fn fetch(
+        litesvm: &LiteSVM,
+        maker: &Keypair,
+        contributor: &Keypair,
+    ) -> (FundraiseView, ContributorView) {
+        let fundraise = client::fundraise_address(&maker.pubkey());
+        let contributor = client::contributor_address(&fundraise, &contributor.pubkey());
+
+        (
+            Fundraise::unpack(&litesvm.get_account(&fundraise).unwrap().data).unwrap(),
+            Contributor::unpack(&litesvm.get_account(&contributor).unwrap().data).unwrap(),
+        )
+    }
+
+    #[test]
+    fn snapshot_once_target_met() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, mint_to_raise) = initialize(litesvm, CampaignType::AllOrNothing);
+        let first = contribute(litesvm, &maker, mint_to_raise, AMOUNT_TO_RAISE / 5 * 2);
+        let second = contribute(litesvm, &maker, mint_to_raise, AMOUNT_TO_RAISE / 5 * 3);
+
+        snapshot(litesvm, &maker, &first);
+        snapshot(litesvm, &maker, &second);
+        // a repeat leaves the total alone
+        snapshot(litesvm, &maker, &first);
+
+        let (fundraise, contributor) = fetch(litesvm, &maker, &first);
+
+        assert_eq!(contributor.snapshotted, 1);
+        assert_eq!(contributor.snapshot_amount, AMOUNT_TO_RAISE / 5 * 2);
+        assert_eq!(fundraise.snapshot_total, AMOUNT_TO_RAISE);
+    }
+
+    #[test]
+    fn refund_snapshots_lazily() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, mint_to_raise) = initialize(litesvm, CampaignType::KeepItAll);
+        let first = contribute(litesvm, &maker, mint_to_raise, AMOUNT_TO_RAISE / 2);
+        contribute(litesvm, &maker, mint_to_raise, AMOUNT_TO_RAISE / 2);
+
+        let mut ix = client::refund(
+            &first.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+        ix.data = cpi::refund_ix_data(AMOUNT_TO_RAISE / 10).to_vec();
+        build_and_send_transaction(litesvm, &[&first], &first.pubkey(), &[ix]).unwrap();
+
+        let (fundraise, contributor) = fetch(litesvm, &maker, &first);
+
+        // the refund counts against the weight it froze
+        assert_eq!(contributor.snapshotted, 1);
+        assert_eq!(
+            contributor.amount,
+            AMOUNT_TO_RAISE / 2 - AMOUNT_TO_RAISE / 10
+        );
+        assert_eq!(contributor.snapshot_amount, contributor.amount);
+        assert_eq!(fundraise.snapshot_total, contributor.amount);
+    }
+
+    #[test]
+    fn refund_lowers_snapshot() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, mint_to_raise) = initialize(litesvm, CampaignType::KeepItAll);
+        let first = contribute(litesvm, &maker, mint_to_raise, AMOUNT_TO_RAISE / 2);
+        let second = contribute(litesvm, &maker, mint_to_raise, AMOUNT_TO_RAISE / 2);
+
+        snapshot(litesvm, &maker, &first);
+        snapshot(litesvm, &maker, &second);
+
+        let mut ix = client::refund(
+            &first.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+        ix.data = cpi::refund_ix_data(AMOUNT_TO_RAISE / 10).to_vec();
+        build_and_send_transaction(litesvm, &[&first], &first.pubkey(), &[ix]).unwrap();
+
+        let (fundraise, contributor) = fetch(litesvm, &maker, &first);
+
+        assert_eq!(contributor.snapshot_amount, contributor.amount);
+        assert_eq!(
+            fundraise.snapshot_total,
+            AMOUNT_TO_RAISE - AMOUNT_TO_RAISE / 10
+        );
+    }
+
+    #[test]
+    fn throw_if_fundraise_active() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, mint_to_raise) = initialize(litesvm, CampaignType::AllOrNothing);
+        let contributor = contribute(litesvm, &maker, mint_to_raise, AMOUNT_TO_RAISE / 2);
+
+        let ix = client::snapshot(&maker.pubkey(), &contributor.pubkey());
+        let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::SnapshotNotReady);
+    }
+
+    #[test]
+    fn throw_if_fundraise_failed() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, mint_to_raise) = initialize(litesvm, CampaignType::AllOrNothing);
+        let contributor = contribute(litesvm, &maker, mint_to_raise, AMOUNT_TO_RAISE / 2);
+
+        forward_time(litesvm, SECONDS_PER_DAY as i64 + 1);
+
+        let ix = client::snapshot(&maker.pubkey(), &contributor.pubkey());
+        let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::SnapshotNotReady);
+    }
+}
@@ -0,0 +1,361 @@
+use core::mem::size_of;
+
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    instruction::{Seed, Signer},
+    program_error::ProgramError,
+};
+use pinocchio_token_2022::instructions::CloseAccount;
+
+use crate::{
+    AccountCheck, AccountLoad, Fundraise, FundraiserError, FundraiserInstruction, Handler,
+    Liability, MintInterface, Prefix, ProgramAccount, ProgramIdCheck, SignerAccount,
+    TokenAccountInterface, WritableAccount, check_account, vault_transfer,
+};
+
+pub struct SweepForeignAccounts<'a> {
+    /// Signs the sweep and receives the rent of the token account if it is closed.
+    pub maker: &'a AccountInfo,
+    pub fundraise: &'a AccountInfo,
+    /// A token account held by the fundraise, of a mint other than its mint to raise.
+    pub token_account: &'a AccountInfo,
+    pub mint: &'a AccountInfo,
+    /// Any token account of `mint`, chosen by the maker.
+    pub destination: &'a AccountInfo,
+    pub token_program: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SweepForeignAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [
+            maker,
+            fundraise,
+            token_account,
+            mint,
+            destination,
+            token_program,
+        ] = accounts
+        else {
+            return Err(FundraiserError::MissingAccounts.into());
+        };
+
+        check_account!(0, "signer", SignerAccount::check(maker))?;
+        check_account!(0, "writable", WritableAccount::check(maker))?;
+        check_account!(2, "writable", WritableAccount::check(token_account))?;
+        check_account!(4, "writable", WritableAccount::check(destination))?;
+        check_account!(3, "mint", MintInterface::check(mint))?;
+        check_account!(
+            5,
+            "program id",
+            ProgramIdCheck::check(token_program, mint.owner())
+        )?;
+        check_account!(1, "owner", ProgramAccount::check(fundraise))?;
+        check_account!(
+            2,
+            "token account",
+            TokenAccountInterface::check_mint_and_owner(token_account, mint.key(), fundraise.key())
+        )?;
+
+        Ok(Self {
+            maker,
+            fundraise,
+            token_account,
+            mint,
+            destination,
+            token_program,
+        })
+    }
+}
+
+pub struct SweepForeignInstructionData {
+    /// Whether to close the token account once emptied, returning its rent to the maker.
+    pub close: bool,
+}
+
+impl SweepForeignInstructionData {
+    pub const LEN: usize = size_of::<u8>();
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        [self.close as u8]
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for SweepForeignInstructionData {
+    type Error = ProgramError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        let close = match data {
+            [0] => false,
+            [1] => true,
+            _ => return Err(ProgramError::InvalidInstructionData),
+        };
+
+        Ok(Self { close })
+    }
+}
+
+/// Lets the maker recover tokens sent by mistake to a token account the fundraise holds, such
+/// as its associated token account of the wrong mint, which no other instruction can move. The
+/// whole balance goes to a destination of the maker's choosing, optionally closing the account
+/// after. The vault and any reward vault are refused, since their mints are the ones the
+/// fundraise accounts for.
+pub struct SweepForeign<'a> {
+    pub accounts: SweepForeignAccounts<'a>,
+    pub data: SweepForeignInstructionData,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for SweepForeign<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = SweepForeignAccounts::try_from(accounts)?;
+        let data = SweepForeignInstructionData::try_from(data)?;
+
+        Ok(Self { accounts, data })
+    }
+}
+
+impl Handler for SweepForeign<'_> {
+    const DISCRIMINATOR: u8 = FundraiserInstruction::SweepForeign as u8;
+
+    fn process(&mut self) -> ProgramResult {
+        let fundraise_data = self.accounts.fundraise.try_borrow_data()?;
+        let fundraise = Fundraise::load(&fundraise_data)?;
+
+        if fundraise.maker != *self.accounts.maker.key() {
+            return Err(FundraiserError::InvalidAddress.into());
+        }
+
+        ProgramAccount::validate(
+            &[Fundraise::PREFIX, &fundraise.maker, &[fundraise.bump]],
+            *self.accounts.fundraise.key(),
+        )?;
+
+        if fundraise.mint_to_raise == *self.accounts.mint.key() {
+            return Err(FundraiserError::InvalidMintToRaise.into());
+        }
+
+        if fundraise.has_rewards() && fundraise.reward_mint == *self.accounts.mint.key() {
+            return Err(FundraiserError::RewardMintNotForeign.into());
+        }
+
+        let fundraise_bump = [fundraise.bump];
+        let fundraise_maker = fundraise.maker;
+        let fundraise_seeds = [
+            Seed::from(Fundraise::PREFIX),
+            Seed::from(fundraise_maker.as_ref()),
+            Seed::from(&fundraise_bump),
+        ];
+
+        drop(fundraise_data);
+
+        let amount = TokenAccountInterface::amount(self.accounts.token_account)?;
+
+        if amount > 0 {
+            vault_transfer(
+                Liability::Foreign,
+                self.accounts.token_account,
+                self.accounts.mint,
+                self.accounts.destination,
+                amount,
+                self.accounts.fundraise,
+                &[],
+            )?;
+        }
+
+        if self.data.close {
+            CloseAccount {
+                account: self.accounts.token_account,
+                destination: self.accounts.maker,
+                authority: self.accounts.fundraise,
+                token_program: self.accounts.token_program.key(),
+            }
+            .invoke_signed(&[Signer::from(&fundraise_seeds)])?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use litesvm::LiteSVM;
+    use solana_keypair::Keypair;
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use spl_associated_token_account::solana_program::{
+        clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL,
+    };
+    use spl_token_2022::state::Account;
+
+    use crate::{
+        FundraiserError, client,
+        tests::{
+            constants::{MINT_DECIMALS, TOKEN_PROGRAM_ID},
+            utils::{
+                assert_error, build_and_send_transaction, fetch_account, init_ata, init_mint,
+                init_wallet, setup,
+            },
+        },
+    };
+
+    const AMOUNT_TO_RAISE: u64 = 5_000_000;
+    const STRAY: u64 = 1_000_000;
+
+    fn initialize(litesvm: &mut LiteSVM) -> (Keypair, Pubkey) {
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        let ix = client::initialize(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            AMOUNT_TO_RAISE,
+            SECONDS_PER_DAY,
+        );
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        (maker, mint_to_raise)
+    }
+
+    /// Sends `STRAY` of a fresh mint to the fundraise's associated token account of it, returning
+    /// the mint, that account and the maker's own account of the mint.
+    fn strand(litesvm: &mut LiteSVM, maker: &Keypair) -> (Pubkey, Pubkey, Pubkey) {
+        let fundraise = client::fundraise_address(&maker.pubkey());
+        let mint = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, STRAY);
+
+        (
+            mint,
+            init_ata(litesvm, mint, fundraise, STRAY),
+            init_ata(litesvm, mint, maker.pubkey(), 0),
+        )
+    }
+
+    #[test]
+    fn sweep_foreign() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, _) = initialize(litesvm);
+        let (mint, stray, destination) = strand(litesvm, &maker);
+
+        let ix = client::sweep_foreign(
+            &maker.pubkey(),
+            &mint,
+            &TOKEN_PROGRAM_ID,
+            &destination,
+            false,
+        );
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        assert_eq!(
+            fetch_account::<Account>(litesvm, &destination).amount,
+            STRAY
+        );
+        assert_eq!(fetch_account::<Account>(litesvm, &stray).amount, 0);
+    }
+
+    #[test]
+    fn sweep_foreign_and_close() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, _) = initialize(litesvm);
+        let (mint, stray, destination) = strand(litesvm, &maker);
+        let rent = litesvm.get_account(&stray).unwrap().lamports;
+        let maker_lamports = litesvm.get_account(&maker.pubkey()).unwrap().lamports;
+        let payer = init_wallet(litesvm, LAMPORTS_PER_SOL);
+
+        let ix = client::sweep_foreign(
+            &maker.pubkey(),
+            &mint,
+            &TOKEN_PROGRAM_ID,
+            &destination,
+            true,
+        );
+        build_and_send_transaction(litesvm, &[&payer, &maker], &payer.pubkey(), &[ix]).unwrap();
+
+        assert_eq!(
+            fetch_account::<Account>(litesvm, &destination).amount,
+            STRAY
+        );
+        assert!(litesvm.get_account(&stray).is_none());
+        assert_eq!(
+            litesvm.get_account(&maker.pubkey()).unwrap().lamports,
+            maker_lamports + rent
+        );
+    }
+
+    #[test]
+    fn throw_if_vault() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, mint_to_raise) = initialize(litesvm);
+        let destination = init_ata(litesvm, mint_to_raise, maker.pubkey(), 0);
+
+        let ix = client::sweep_foreign(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            &destination,
+            true,
+        );
+        let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::InvalidMintToRaise);
+    }
+
+    #[test]
+    fn throw_if_reward_vault() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let reward_mint = init_mint(litesvm, TOKEN_PROGRAM_ID, 9, STRAY);
+        let destination = init_ata(litesvm, reward_mint, maker.pubkey(), STRAY);
+
+        let ix = client::with_rewards(
+            client::initialize(
+                &maker.pubkey(),
+                &mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+                AMOUNT_TO_RAISE,
+                SECONDS_PER_DAY,
+            ),
+            &reward_mint,
+            STRAY,
+        );
+        let deposit =
+            client::deposit_rewards(&maker.pubkey(), &reward_mint, &TOKEN_PROGRAM_ID, STRAY);
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix, deposit]).unwrap();
+
+        let ix = client::sweep_foreign(
+            &maker.pubkey(),
+            &reward_mint,
+            &TOKEN_PROGRAM_ID,
+            &destination,
+            false,
+        );
+        let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::RewardMintNotForeign);
+    }
+
+    #[test]
+    fn throw_if_signer_not_maker() {
+        let (litesvm, _default_payer) = &mut setup();
+        let (maker, _) = initialize(litesvm);
+        let (mint, _, _) = strand(litesvm, &maker);
+        let other = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let destination = init_ata(litesvm, mint, other.pubkey(), 0);
+
+        let mut ix = client::sweep_foreign(
+            &maker.pubkey(),
+            &mint,
+            &TOKEN_PROGRAM_ID,
+            &destination,
+            false,
+        );
+        ix.accounts[0].pubkey = other.pubkey();
+        let res = build_and_send_transaction(litesvm, &[&other], &other.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::InvalidAddress);
+    }
+}
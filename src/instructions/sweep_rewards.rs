@@ -0,0 +1,423 @@
+use pinocchio::{
+    ProgramResult,
+    account_info::AccountInfo,
+    program_error::ProgramError,
+    sysvars::{Sysvar, clock::Clock},
+};
+
+use crate::{
+    AccountCheck, AccountLoad, AssociatedTokenAccount, Fundraise, FundraiseStatus, FundraiserError,
+    FundraiserInstruction, Handler, Liability, MintInterface, Prefix, ProgramAccount,
+    ProgramIdCheck, SignerAccount, TokenAccountInterface, WritableAccount, check_account,
+    vault_transfer,
+};
+
+pub struct SweepRewardsAccounts<'a> {
+    pub maker: &'a AccountInfo,
+    pub fundraise: &'a AccountInfo,
+    /// The fundraise's vault, checked against it. Whether the campaign failed is judged by the
+    /// amount it recorded, not by this balance.
+    pub vault: &'a AccountInfo,
+    pub reward_mint: &'a AccountInfo,
+    pub reward_vault: &'a AccountInfo,
+    /// The maker's associated token account of the reward mint, created if it does not exist
+    /// yet.
+    pub maker_token_account: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+    pub token_program: &'a AccountInfo,
+    pub associated_token_program: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SweepRewardsAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [
+            maker,
+            fundraise,
+            vault,
+            reward_mint,
+            reward_vault,
+            maker_token_account,
+            system_program,
+            token_program,
+            associated_token_program,
+        ] = accounts
+        else {
+            return Err(FundraiserError::MissingAccounts.into());
+        };
+
+        check_account!(0, "signer", SignerAccount::check(maker))?;
+        check_account!(0, "writable", WritableAccount::check(maker))?;
+        check_account!(4, "writable", WritableAccount::check(reward_vault))?;
+        check_account!(5, "writable", WritableAccount::check(maker_token_account))?;
+        check_account!(3, "mint", MintInterface::check(reward_mint))?;
+        check_account!(
+            6,
+            "program id",
+            ProgramIdCheck::check(system_program, &pinocchio_system::ID)
+        )?;
+        check_account!(
+            7,
+            "program id",
+            ProgramIdCheck::check(token_program, reward_mint.owner())
+        )?;
+        check_account!(
+            8,
+            "program id",
+            ProgramIdCheck::check(
+                associated_token_program,
+                &pinocchio_associated_token_account::ID,
+            )
+        )?;
+        check_account!(1, "owner", ProgramAccount::check(fundraise))?;
+        check_account!(
+            4,
+            "address",
+            AssociatedTokenAccount::check_address(
+                reward_vault,
+                fundraise,
+                reward_mint,
+                token_program
+            )
+        )?;
+        check_account!(
+            5,
+            "address",
+            AssociatedTokenAccount::check_address(
+                maker_token_account,
+                maker,
+                reward_mint,
+                token_program,
+            )
+        )?;
+
+        Ok(Self {
+            maker,
+            fundraise,
+            vault,
+            reward_mint,
+            reward_vault,
+            maker_token_account,
+            system_program,
+            token_program,
+            associated_token_program,
+        })
+    }
+}
+
+/// Returns whatever the reward vault holds to the maker once no contributor can claim from it:
+/// the rounding dust after every share of a claimed fundraise is paid, or the whole deposit of
+/// a campaign that failed or was cancelled.
+pub struct SweepRewards<'a> {
+    pub accounts: SweepRewardsAccounts<'a>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for SweepRewards<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        Ok(Self {
+            accounts: SweepRewardsAccounts::try_from(accounts)?,
+        })
+    }
+}
+
+impl Handler for SweepRewards<'_> {
+    const DISCRIMINATOR: u8 = FundraiserInstruction::SweepRewards as u8;
+
+    fn process(&mut self) -> ProgramResult {
+        let fundraise_data = self.accounts.fundraise.try_borrow_data()?;
+        let fundraise = Fundraise::load(&fundraise_data)?;
+
+        if fundraise.maker != *self.accounts.maker.key() {
+            return Err(FundraiserError::InvalidAddress.into());
+        }
+
+        ProgramAccount::validate(
+            &[Fundraise::PREFIX, &fundraise.maker, &[fundraise.bump]],
+            *self.accounts.fundraise.key(),
+        )?;
+
+        if !fundraise.has_rewards() {
+            return Err(FundraiserError::NoRewards.into());
+        }
+
+        if fundraise.reward_mint != *self.accounts.reward_mint.key() {
+            return Err(FundraiserError::InvalidAddress.into());
+        }
+
+        fundraise.check_vault(self.accounts.vault.key())?;
+
+        let status = fundraise.status(Clock::get()?.unix_timestamp);
+
+        match (fundraise.is_claimed(), status) {
+            (true, _) if !fundraise.rewards_settled() => {
+                return Err(FundraiserError::RewardsOutstanding.into());
+            }
+            // a fundraise left expired or refundable will never be claimed
+            (true, _) | (false, FundraiseStatus::Expired | FundraiseStatus::Refundable) => {}
+            _ => return Err(FundraiserError::RewardsLocked.into()),
+        }
+
+        let outstanding_rewards = fundraise.outstanding_rewards();
+
+        drop(fundraise_data);
+
+        let remaining = TokenAccountInterface::amount(self.accounts.reward_vault)?;
+
+        if remaining == 0 {
+            return Ok(());
+        }
+
+        AssociatedTokenAccount::init_if_needed(
+            self.accounts.maker_token_account,
+            self.accounts.reward_mint,
+            self.accounts.maker,
+            self.accounts.maker,
+            self.accounts.system_program,
+            self.accounts.token_program,
+        )?;
+
+        // what is left of the declared rewards, then anything sent to the reward vault past them
+        let rewards = remaining.min(outstanding_rewards);
+
+        for (liability, amount) in [
+            (Liability::Rewards, rewards),
+            (Liability::Surplus, remaining - rewards),
+        ] {
+            if amount > 0 {
+                vault_transfer(
+                    liability,
+                    self.accounts.reward_vault,
+                    self.accounts.reward_mint,
+                    self.accounts.maker_token_account,
+                    amount,
+                    self.accounts.fundraise,
+                    &[],
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use litesvm::LiteSVM;
+    use solana_instruction::Instruction;
+    use solana_keypair::Keypair;
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use spl_associated_token_account::solana_program::{
+        clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL,
+    };
+    use spl_token_2022::state::Account;
+
+    use crate::{
+        AccountLoad, Fundraise, FundraiserError, client,
+        tests::{
+            constants::{FEE_COLLECTOR, MINT_DECIMALS, TOKEN_PROGRAM_ID},
+            utils::{
+                assert_error, build_and_send_transaction, fetch_account, forward_time, init_ata,
+                init_mint, init_token_account, init_wallet, setup,
+            },
+        },
+    };
+
+    const AMOUNT_TO_RAISE: u64 = 5_000_000;
+    const REWARDS: u64 = 1_000_000_007;
+
+    struct Campaign {
+        maker: Keypair,
+        mint_to_raise: Pubkey,
+        reward_mint: Pubkey,
+    }
+
+    fn initialize(litesvm: &mut LiteSVM) -> Campaign {
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let reward_mint = init_mint(litesvm, TOKEN_PROGRAM_ID, 9, REWARDS);
+        init_ata(litesvm, reward_mint, maker.pubkey(), REWARDS);
+
+        let ix = client::with_rewards(
+            client::initialize(
+                &maker.pubkey(),
+                &mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+                AMOUNT_TO_RAISE,
+                SECONDS_PER_DAY,
+            ),
+            &reward_mint,
+            REWARDS,
+        );
+        let deposit =
+            client::deposit_rewards(&maker.pubkey(), &reward_mint, &TOKEN_PROGRAM_ID, REWARDS);
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix, deposit]).unwrap();
+
+        Campaign {
+            maker,
+            mint_to_raise,
+            reward_mint,
+        }
+    }
+
+    fn contribute(litesvm: &mut LiteSVM, campaign: &Campaign, amount: u64) -> Keypair {
+        let backer = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        init_ata(litesvm, campaign.mint_to_raise, backer.pubkey(), amount);
+
+        let ix = client::contribute(
+            &backer.pubkey(),
+            &campaign.maker.pubkey(),
+            &campaign.mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            amount,
+        );
+        build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]).unwrap();
+
+        backer
+    }
+
+    fn claim(litesvm: &mut LiteSVM, campaign: &Campaign) {
+        let maker = &campaign.maker;
+        let ix = client::claim(
+            &maker.pubkey(),
+            &campaign.mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            &FEE_COLLECTOR,
+        );
+        build_and_send_transaction(litesvm, &[maker], &maker.pubkey(), &[ix]).unwrap();
+    }
+
+    fn claim_reward(litesvm: &mut LiteSVM, campaign: &Campaign, backer: &Keypair) {
+        let ix = client::claim_reward(
+            &backer.pubkey(),
+            &campaign.maker.pubkey(),
+            &campaign.reward_mint,
+            &TOKEN_PROGRAM_ID,
+        );
+        build_and_send_transaction(litesvm, &[backer], &backer.pubkey(), &[ix]).unwrap();
+    }
+
+    fn sweep_rewards_ix(campaign: &Campaign) -> Instruction {
+        client::sweep_rewards(
+            &campaign.maker.pubkey(),
+            &campaign.reward_mint,
+            &TOKEN_PROGRAM_ID,
+        )
+    }
+
+    fn reward_balance(litesvm: &LiteSVM, campaign: &Campaign, owner: &Pubkey) -> u64 {
+        let address =
+            client::associated_token_address(owner, &campaign.reward_mint, &TOKEN_PROGRAM_ID);
+
+        fetch_account::<Account>(litesvm, &address).amount
+    }
+
+    #[test]
+    fn sweep_dust_after_all_claims() {
+        let (litesvm, _default_payer) = &mut setup();
+        let campaign = initialize(litesvm);
+        let backers =
+            [1_000_000, 1_500_000, 2_500_000].map(|amount| contribute(litesvm, &campaign, amount));
+
+        claim(litesvm, &campaign);
+
+        for backer in &backers {
+            claim_reward(litesvm, &campaign, backer);
+        }
+
+        let maker = &campaign.maker;
+        let ix = sweep_rewards_ix(&campaign);
+        build_and_send_transaction(litesvm, &[maker], &maker.pubkey(), &[ix]).unwrap();
+
+        // the three shares round down by a combined 1
+        let fundraise = client::fundraise_address(&maker.pubkey());
+
+        assert_eq!(reward_balance(litesvm, &campaign, &maker.pubkey()), 1);
+        assert_eq!(reward_balance(litesvm, &campaign, &fundraise), 0);
+    }
+
+    #[test]
+    fn sweep_rewards_sent_past_the_declared_ones() {
+        let (litesvm, _default_payer) = &mut setup();
+        let campaign = initialize(litesvm);
+        let maker = &campaign.maker;
+        let fundraise = client::fundraise_address(&maker.pubkey());
+        let reward_vault =
+            client::associated_token_address(&fundraise, &campaign.reward_mint, &TOKEN_PROGRAM_ID);
+
+        // sent straight to the reward vault on top of the deposit
+        init_token_account(
+            litesvm,
+            reward_vault,
+            campaign.reward_mint,
+            fundraise,
+            REWARDS + 500,
+        );
+        contribute(litesvm, &campaign, AMOUNT_TO_RAISE / 2);
+        forward_time(litesvm, SECONDS_PER_DAY as i64 + 1);
+
+        let ix = sweep_rewards_ix(&campaign);
+        build_and_send_transaction(litesvm, &[maker], &maker.pubkey(), &[ix]).unwrap();
+
+        assert_eq!(
+            reward_balance(litesvm, &campaign, &maker.pubkey()),
+            REWARDS + 500
+        );
+        assert_eq!(reward_balance(litesvm, &campaign, &fundraise), 0);
+
+        // only the declared rewards are recorded as paid
+        let fundraise = litesvm.get_account(&fundraise).unwrap();
+
+        assert_eq!(
+            Fundraise::load(&fundraise.data).unwrap().get_reward_paid(),
+            REWARDS
+        );
+    }
+
+    #[test]
+    fn sweep_deposit_of_failed_campaign() {
+        let (litesvm, _default_payer) = &mut setup();
+        let campaign = initialize(litesvm);
+        contribute(litesvm, &campaign, AMOUNT_TO_RAISE / 2);
+
+        forward_time(litesvm, SECONDS_PER_DAY as i64 + 1);
+
+        let maker = &campaign.maker;
+        let ix = sweep_rewards_ix(&campaign);
+        build_and_send_transaction(litesvm, &[maker], &maker.pubkey(), &[ix]).unwrap();
+
+        assert_eq!(reward_balance(litesvm, &campaign, &maker.pubkey()), REWARDS);
+    }
+
+    #[test]
+    fn throw_if_rewards_outstanding() {
+        let (litesvm, _default_payer) = &mut setup();
+        let campaign = initialize(litesvm);
+        let backers = [2_000_000, 3_000_000].map(|amount| contribute(litesvm, &campaign, amount));
+
+        claim(litesvm, &campaign);
+        claim_reward(litesvm, &campaign, &backers[0]);
+
+        let maker = &campaign.maker;
+        let ix = sweep_rewards_ix(&campaign);
+        let res = build_and_send_transaction(litesvm, &[maker], &maker.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::RewardsOutstanding);
+    }
+
+    #[test]
+    fn throw_if_campaign_running() {
+        let (litesvm, _default_payer) = &mut setup();
+        let campaign = initialize(litesvm);
+        contribute(litesvm, &campaign, AMOUNT_TO_RAISE / 2);
+
+        let maker = &campaign.maker;
+        let ix = sweep_rewards_ix(&campaign);
+        let res = build_and_send_transaction(litesvm, &[maker], &maker.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::RewardsLocked);
+    }
+}
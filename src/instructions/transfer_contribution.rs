@@ -0,0 +1,453 @@
+use pinocchio::{
+    ProgramResult, account_info::AccountInfo, instruction::Seed, program_error::ProgramError,
+    pubkey::find_program_address,
+};
+
+use crate::{
+    AccountCheck, AccountLoad, Contributor, ContributorParams, Fundraise, FundraiserError,
+    FundraiserInstruction, Handler, Prefix, ProgramAccount, ProgramIdCheck, SignerAccount,
+    WritableAccount, check_account, check_contribution_cap,
+};
+
+pub struct TransferContributionAccounts<'a> {
+    pub authority: &'a AccountInfo,
+    pub new_authority: &'a AccountInfo,
+    pub fundraise: &'a AccountInfo,
+    pub contributor: &'a AccountInfo,
+    pub new_contributor: &'a AccountInfo,
+    pub system_program: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for TransferContributionAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [
+            authority,
+            new_authority,
+            fundraise,
+            contributor,
+            new_contributor,
+            system_program,
+        ] = accounts
+        else {
+            return Err(FundraiserError::MissingAccounts.into());
+        };
+
+        check_account!(0, "signer", SignerAccount::check(authority))?;
+        check_account!(1, "signer", SignerAccount::check(new_authority))?;
+        check_account!(0, "writable", WritableAccount::check(authority))?;
+        check_account!(1, "writable", WritableAccount::check(new_authority))?;
+        check_account!(2, "writable", WritableAccount::check(fundraise))?;
+        check_account!(3, "writable", WritableAccount::check(contributor))?;
+        check_account!(4, "writable", WritableAccount::check(new_contributor))?;
+        check_account!(
+            5,
+            "program id",
+            ProgramIdCheck::check(system_program, &pinocchio_system::ID)
+        )?;
+        check_account!(2, "owner", ProgramAccount::check(fundraise))?;
+        check_account!(3, "owner", ProgramAccount::check(contributor))?;
+
+        if authority.key() == new_authority.key() {
+            return Err(FundraiserError::InvalidAddress.into());
+        }
+
+        Ok(Self {
+            authority,
+            new_authority,
+            fundraise,
+            contributor,
+            new_contributor,
+            system_program,
+        })
+    }
+}
+
+/// Moves `authority`'s contribution to `new_authority`, who signs to accept it and pays for
+/// their contributor account. If `new_authority` already has a position in the fundraise the
+/// two are merged, failing with MaximumContributionsReached when the merged amount would exceed
+/// the contribution cap. The old contributor account is closed to `authority`. Positions whose
+/// reward has been claimed can no longer move, nor can any position in a fundraise that issues
+/// receipts.
+pub struct TransferContribution<'a> {
+    pub accounts: TransferContributionAccounts<'a>,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for TransferContribution<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let accounts = TransferContributionAccounts::try_from(accounts)?;
+
+        Ok(Self { accounts })
+    }
+}
+
+impl Handler for TransferContribution<'_> {
+    const DISCRIMINATOR: u8 = FundraiserInstruction::TransferContribution as u8;
+
+    fn process(&mut self) -> ProgramResult {
+        let mut fundraise_data = self.accounts.fundraise.try_borrow_mut_data()?;
+        let fundraise = Fundraise::load_mut(fundraise_data.as_mut())?;
+
+        ProgramAccount::validate(
+            &[Fundraise::PREFIX, &fundraise.maker, &[fundraise.bump]],
+            *self.accounts.fundraise.key(),
+        )?;
+
+        // the receipts stay with the old authority, who could no longer burn them on refund
+        if fundraise.has_receipt() {
+            return Err(FundraiserError::ReceiptNotTransferable.into());
+        }
+
+        let contributor_data = self.accounts.contributor.try_borrow_data()?;
+        let contributor = Contributor::load(&contributor_data)?;
+
+        if contributor.authority != *self.accounts.authority.key() {
+            return Err(FundraiserError::InvalidAddress.into());
+        }
+
+        ProgramAccount::validate(
+            &[
+                Contributor::PREFIX,
+                self.accounts.fundraise.key(),
+                self.accounts.authority.key(),
+                &[contributor.bump],
+            ],
+            *self.accounts.contributor.key(),
+        )?;
+
+        // a claimed reward would be paid again to the new authority
+        if contributor.is_reward_claimed() {
+            return Err(FundraiserError::RewardAlreadyClaimed.into());
+        }
+
+        // the snapshot froze the weight of this authority's position
+        if contributor.is_snapshotted() {
+            return Err(FundraiserError::SnapshotTaken.into());
+        }
+
+        let amount = contributor.get_amount();
+        let pending_hook_amount = contributor.get_pending_hook_amount();
+
+        drop(contributor_data);
+
+        let (pda, bump) = find_program_address(
+            &[
+                Contributor::PREFIX,
+                self.accounts.fundraise.key(),
+                self.accounts.new_authority.key(),
+            ],
+            &crate::ID,
+        );
+
+        if pda != *self.accounts.new_contributor.key() {
+            return Err(FundraiserError::InvalidAddress.into());
+        }
+
+        let bump_binding = [bump];
+        let new_contributor_seeds = [
+            Seed::from(Contributor::PREFIX),
+            Seed::from(self.accounts.fundraise.key().as_ref()),
+            Seed::from(self.accounts.new_authority.key().as_ref()),
+            Seed::from(&bump_binding),
+        ];
+        let params = ContributorParams::new(
+            *self.accounts.fundraise.key(),
+            *self.accounts.new_authority.key(),
+            bump,
+            *self.accounts.new_authority.key(),
+            fundraise.mint_to_raise,
+        );
+
+        ProgramAccount::init_if_needed::<Contributor>(
+            &new_contributor_seeds,
+            self.accounts.new_contributor,
+            self.accounts.new_authority,
+            params,
+        )?;
+
+        let mut new_contributor_data = self.accounts.new_contributor.try_borrow_mut_data()?;
+        let new_contributor = Contributor::load_mut(new_contributor_data.as_mut())?;
+
+        if new_contributor.is_reward_claimed() {
+            return Err(FundraiserError::RewardAlreadyClaimed.into());
+        }
+
+        let new_amount = new_contributor.get_amount();
+
+        check_contribution_cap(fundraise.max_contribution(), new_amount, amount)?;
+        new_contributor.set_amount(new_amount + amount);
+        new_contributor.set_tier(fundraise.tier(new_contributor.get_amount()));
+
+        let new_pending_hook_amount = new_contributor.get_pending_hook_amount();
+
+        // two pending hooks become one, reported once for the new authority
+        if pending_hook_amount > 0 && new_pending_hook_amount > 0 {
+            fundraise.set_pending_hooks(fundraise.get_pending_hooks() - 1);
+        }
+
+        new_contributor
+            .set_pending_hook_amount(new_pending_hook_amount.saturating_add(pending_hook_amount));
+
+        drop(new_contributor_data);
+        drop(fundraise_data);
+
+        ProgramAccount::close(self.accounts.contributor, self.accounts.authority)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use litesvm::LiteSVM;
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_keypair::Keypair;
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use spl_associated_token_account::solana_program::{
+        clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL,
+    };
+
+    use crate::{
+        AccountLoad, Contributor, Fundraise, FundraiserError, client,
+        tests::{
+            constants::{
+                ASSOCIATED_TOKEN_PROGRAM_ID, MINT_DECIMALS, PROGRAM_ID, SYSTEM_PROGRAM_ID,
+                TOKEN_PROGRAM_ID,
+            },
+            pda::{get_config_pda, get_contributor_pda, get_fundraise_pda, get_vault_pda},
+            utils::{
+                assert_error, build_and_send_transaction, init_ata, init_mint, init_wallet, setup,
+            },
+        },
+    };
+
+    fn initialize(litesvm: &mut LiteSVM, maker: &Keypair, mint_to_raise: Pubkey) -> Pubkey {
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+
+        let data = [
+            vec![0u8],
+            5_000_000u64.to_le_bytes().to_vec(),
+            SECONDS_PER_DAY.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(), // 10%, so 500_000
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(get_vault_pda(&fundraise_pda), false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        build_and_send_transaction(litesvm, &[maker], &maker.pubkey(), &[ix]).unwrap();
+
+        fundraise_pda
+    }
+
+    fn contribute(
+        litesvm: &mut LiteSVM,
+        authority: &Keypair,
+        mint_to_raise: Pubkey,
+        fundraise_pda: Pubkey,
+        amount: u64,
+    ) {
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), amount);
+
+        let data = [vec![1u8], amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(
+                    get_contributor_pda(&fundraise_pda, &authority.pubkey()),
+                    false,
+                ),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(get_vault_pda(&fundraise_pda), false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        build_and_send_transaction(litesvm, &[authority], &authority.pubkey(), &[ix]).unwrap();
+    }
+
+    fn transfer_contribution_ix(
+        authority: &Pubkey,
+        new_authority: &Pubkey,
+        fundraise_pda: Pubkey,
+    ) -> Instruction {
+        Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(*authority, true),
+                AccountMeta::new(*new_authority, true),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(get_contributor_pda(&fundraise_pda, authority), false),
+                AccountMeta::new(get_contributor_pda(&fundraise_pda, new_authority), false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            ],
+            data: vec![24u8],
+        }
+    }
+
+    #[test]
+    fn transfer_contribution() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let new_authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        let fundraise_pda = initialize(litesvm, &maker, mint_to_raise);
+        let contribute_amount: u64 = 500_000;
+
+        contribute(
+            litesvm,
+            &authority,
+            mint_to_raise,
+            fundraise_pda,
+            contribute_amount,
+        );
+
+        let ix =
+            transfer_contribution_ix(&authority.pubkey(), &new_authority.pubkey(), fundraise_pda);
+
+        build_and_send_transaction(
+            litesvm,
+            &[&authority, &new_authority],
+            &new_authority.pubkey(),
+            &[ix],
+        )
+        .unwrap();
+
+        let old_contributor =
+            litesvm.get_account(&get_contributor_pda(&fundraise_pda, &authority.pubkey()));
+
+        assert!(old_contributor.is_none());
+
+        let contributor_acc = litesvm
+            .get_account(&get_contributor_pda(
+                &fundraise_pda,
+                &new_authority.pubkey(),
+            ))
+            .unwrap();
+        let contributor = Contributor::load(contributor_acc.data.as_ref()).unwrap();
+
+        assert_eq!(contributor.authority, new_authority.pubkey().to_bytes());
+        assert_eq!(contributor.get_amount(), contribute_amount);
+
+        // moving a position leaves the fundraise total alone
+        let fundraise_acc = litesvm.get_account(&fundraise_pda).unwrap();
+        let fundraise = Fundraise::load(fundraise_acc.data.as_ref()).unwrap();
+
+        assert_eq!(fundraise.get_current_amount(), contribute_amount);
+    }
+
+    #[test]
+    fn transfer_contribution_merges_positions() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let new_authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        let fundraise_pda = initialize(litesvm, &maker, mint_to_raise);
+
+        contribute(litesvm, &authority, mint_to_raise, fundraise_pda, 200_000);
+        contribute(
+            litesvm,
+            &new_authority,
+            mint_to_raise,
+            fundraise_pda,
+            300_000,
+        );
+
+        let ix =
+            transfer_contribution_ix(&authority.pubkey(), &new_authority.pubkey(), fundraise_pda);
+
+        build_and_send_transaction(
+            litesvm,
+            &[&authority, &new_authority],
+            &new_authority.pubkey(),
+            &[ix],
+        )
+        .unwrap();
+
+        let contributor_acc = litesvm
+            .get_account(&get_contributor_pda(
+                &fundraise_pda,
+                &new_authority.pubkey(),
+            ))
+            .unwrap();
+        let contributor = Contributor::load(contributor_acc.data.as_ref()).unwrap();
+
+        assert_eq!(contributor.get_amount(), 500_000);
+    }
+
+    #[test]
+    fn throw_if_fundraise_has_receipts() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let new_authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let receipt_mint = Keypair::new();
+        init_ata(litesvm, mint_to_raise, authority.pubkey(), 500_000);
+
+        let ix = client::with_receipt_mint(
+            client::initialize(
+                &maker.pubkey(),
+                &mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+                5_000_000,
+                SECONDS_PER_DAY,
+            ),
+            &receipt_mint.pubkey(),
+        );
+        build_and_send_transaction(litesvm, &[&maker, &receipt_mint], &maker.pubkey(), &[ix])
+            .unwrap();
+        init_ata(litesvm, receipt_mint.pubkey(), authority.pubkey(), 0);
+
+        let ix = client::with_receipt(
+            client::contribute(
+                &authority.pubkey(),
+                &maker.pubkey(),
+                &mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+                500_000,
+            ),
+            &receipt_mint.pubkey(),
+            &authority.pubkey(),
+        );
+        build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]).unwrap();
+
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let ix =
+            transfer_contribution_ix(&authority.pubkey(), &new_authority.pubkey(), fundraise_pda);
+        let res = build_and_send_transaction(
+            litesvm,
+            &[&authority, &new_authority],
+            &new_authority.pubkey(),
+            &[ix],
+        );
+
+        assert_error(res.unwrap_err(), FundraiserError::ReceiptNotTransferable);
+    }
+}
@@ -0,0 +1,365 @@
+use core::mem::size_of;
+use pinocchio::{
+    ProgramResult, account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey,
+};
+
+use crate::{
+    AccountCheck, AccountLoad, Config, FundraiserError, FundraiserInstruction, Handler, MAX_BPS,
+    Prefix, ProgramAccount, SignerAccount, WritableAccount, check_account,
+};
+
+pub struct UpdateConfigAccounts<'a> {
+    pub admin: &'a AccountInfo,
+    pub config: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for UpdateConfigAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [admin, config] = accounts else {
+            return Err(FundraiserError::MissingAccounts.into());
+        };
+
+        check_account!(0, "signer", SignerAccount::check(admin))?;
+        check_account!(1, "writable", WritableAccount::check(config))?;
+        check_account!(1, "owner", ProgramAccount::check(config))?;
+
+        Ok(Self { admin, config })
+    }
+}
+
+pub struct UpdateConfigInstructionData {
+    pub admin: Pubkey,
+    pub fee_bps: u16,
+    pub fee_collector: Pubkey,
+    pub paused: u8,
+    /// Bond staked by makers of fundraises initialized from now on.
+    pub bond_lamports: u64,
+    pub bond_slash_bps: u16,
+}
+
+impl UpdateConfigInstructionData {
+    pub const LEN: usize = size_of::<Pubkey>()
+        + size_of::<u16>()
+        + size_of::<Pubkey>()
+        + 1
+        + size_of::<u64>()
+        + size_of::<u16>();
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut bytes = [0u8; Self::LEN];
+
+        bytes[0..32].copy_from_slice(&self.admin);
+        bytes[32..34].copy_from_slice(&self.fee_bps.to_le_bytes());
+        bytes[34..66].copy_from_slice(&self.fee_collector);
+        bytes[66] = self.paused;
+        bytes[67..75].copy_from_slice(&self.bond_lamports.to_le_bytes());
+        bytes[75..77].copy_from_slice(&self.bond_slash_bps.to_le_bytes());
+
+        bytes
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for UpdateConfigInstructionData {
+    type Error = ProgramError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        if data.len() != Self::LEN {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        let offset = size_of::<Pubkey>();
+        let admin: Pubkey = data[0..offset].try_into().unwrap();
+        let fee_bps =
+            u16::from_le_bytes(data[offset..offset + size_of::<u16>()].try_into().unwrap());
+
+        let offset = offset + size_of::<u16>();
+        let fee_collector: Pubkey = data[offset..offset + size_of::<Pubkey>()]
+            .try_into()
+            .unwrap();
+
+        let offset = offset + size_of::<Pubkey>();
+        let paused = data[offset];
+
+        let offset = offset + 1;
+        let bond_lamports =
+            u64::from_le_bytes(data[offset..offset + size_of::<u64>()].try_into().unwrap());
+
+        let offset = offset + size_of::<u64>();
+        let bond_slash_bps =
+            u16::from_le_bytes(data[offset..offset + size_of::<u16>()].try_into().unwrap());
+
+        if paused > 1 {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        if fee_bps > MAX_BPS || bond_slash_bps > MAX_BPS {
+            return Err(FundraiserError::InvalidAmount.into());
+        }
+
+        Ok(Self {
+            admin,
+            fee_bps,
+            fee_collector,
+            paused,
+            bond_lamports,
+            bond_slash_bps,
+        })
+    }
+}
+
+pub struct UpdateConfig<'a> {
+    pub accounts: UpdateConfigAccounts<'a>,
+    pub data: UpdateConfigInstructionData,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for UpdateConfig<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = UpdateConfigAccounts::try_from(accounts)?;
+        let data = UpdateConfigInstructionData::try_from(data)?;
+
+        Ok(Self { accounts, data })
+    }
+}
+
+impl Handler for UpdateConfig<'_> {
+    const DISCRIMINATOR: u8 = FundraiserInstruction::UpdateConfig as u8;
+
+    fn process(&mut self) -> ProgramResult {
+        let mut data = self.accounts.config.try_borrow_mut_data()?;
+        let config = Config::load_mut(data.as_mut())?;
+
+        ProgramAccount::validate(
+            &[Config::PREFIX, &[config.bump]],
+            *self.accounts.config.key(),
+        )?;
+
+        if config.admin != *self.accounts.admin.key() {
+            return Err(FundraiserError::Unauthorized.into());
+        }
+
+        config.admin = self.data.admin;
+        config.set_fee_bps(self.data.fee_bps);
+        config.fee_collector = self.data.fee_collector;
+        config.paused = self.data.paused;
+        config.set_bond(self.data.bond_lamports, self.data.bond_slash_bps);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use spl_associated_token_account::solana_program::{
+        clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL,
+    };
+    use spl_token_2022::state::Account;
+
+    use crate::{
+        AccountLoad, Config, FundraiserError,
+        tests::{
+            constants::{
+                ASSOCIATED_TOKEN_PROGRAM_ID, FEE_COLLECTOR, MINT_DECIMALS, PROGRAM_ID,
+                SYSTEM_PROGRAM_ID, TOKEN_PROGRAM_ID,
+            },
+            pda::{get_config_pda, get_contributor_pda, get_fundraise_pda, get_vault_pda},
+            utils::{
+                assert_error, build_and_send_transaction, fetch_account, init_ata, init_config,
+                init_mint, init_wallet, setup,
+            },
+        },
+    };
+
+    #[test]
+    fn update_config() {
+        let (litesvm, _default_payer) = &mut setup();
+        let admin = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let config_pda = init_config(litesvm, admin.pubkey(), 0, FEE_COLLECTOR, false);
+
+        let new_admin = Pubkey::new_unique();
+        let new_fee_collector = Pubkey::new_unique();
+        let fee_bps: u16 = 100; // 1%
+
+        let data = [
+            vec![17u8],
+            new_admin.to_bytes().to_vec(),
+            fee_bps.to_le_bytes().to_vec(),
+            new_fee_collector.to_bytes().to_vec(),
+            vec![1u8],
+            50_000_000u64.to_le_bytes().to_vec(),
+            2_500u16.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new_readonly(admin.pubkey(), true),
+                AccountMeta::new(config_pda, false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&admin], &admin.pubkey(), &[ix]);
+
+        let config_acc = litesvm.get_account(&config_pda).unwrap();
+        let config = Config::load(config_acc.data.as_ref()).unwrap();
+
+        assert_eq!(config.admin, new_admin.to_bytes());
+        assert_eq!(config.get_fee_bps(), fee_bps);
+        assert_eq!(config.fee_collector, new_fee_collector.to_bytes());
+        assert!(config.is_paused());
+        assert_eq!(config.get_bond_lamports(), 50_000_000);
+        assert_eq!(config.get_bond_slash_bps(), 2_500);
+    }
+
+    #[test]
+    fn throw_if_not_admin() {
+        let (litesvm, _default_payer) = &mut setup();
+        let admin = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let config_pda = init_config(litesvm, admin.pubkey(), 0, FEE_COLLECTOR, false);
+
+        let impostor = init_wallet(litesvm, LAMPORTS_PER_SOL);
+
+        let data = [
+            vec![17u8],
+            impostor.pubkey().to_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            impostor.pubkey().to_bytes().to_vec(),
+            vec![0u8],
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new_readonly(impostor.pubkey(), true),
+                AccountMeta::new(config_pda, false),
+            ],
+            data,
+        };
+
+        let res = build_and_send_transaction(litesvm, &[&impostor], &impostor.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::Unauthorized);
+    }
+
+    #[test]
+    fn unpause() {
+        let (litesvm, _default_payer) = &mut setup();
+        let admin = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let config_pda = init_config(litesvm, admin.pubkey(), 0, FEE_COLLECTOR, true);
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+        let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+        let amount_to_raise: u64 = 5_000_000;
+        let duration: u64 = SECONDS_PER_DAY; // 1 day
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            amount_to_raise.to_le_bytes().to_vec(),
+            duration.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        let contribute_amount: u64 = 500_000;
+        let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let res = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::ProtocolPaused);
+
+        let data = [
+            vec![17u8],
+            admin.pubkey().to_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            FEE_COLLECTOR.to_bytes().to_vec(),
+            vec![0u8],
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new_readonly(admin.pubkey(), true),
+                AccountMeta::new(config_pda, false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&admin], &admin.pubkey(), &[ix]);
+
+        litesvm.expire_blockhash();
+
+        let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(contributor_pda, false),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        let authority_ata_bal = fetch_account::<Account>(litesvm, &authority_ata).amount;
+
+        assert_eq!(authority_ata_bal, 1_000_000_000 - contribute_amount);
+    }
+}
@@ -0,0 +1,251 @@
+use pinocchio::{ProgramResult, account_info::AccountInfo, program_error::ProgramError};
+
+use crate::{
+    AccountCheck, AccountLoad, Fundraise, FundraiserError, FundraiserInstruction, Handler,
+    MAX_NAME_LEN, MAX_URI_LEN, Prefix, ProgramAccount, SignerAccount, WritableAccount,
+    check_account,
+};
+
+pub struct UpdateMetadataAccounts<'a> {
+    pub maker: &'a AccountInfo,
+    pub fundraise: &'a AccountInfo,
+}
+
+impl<'a> TryFrom<&'a [AccountInfo]> for UpdateMetadataAccounts<'a> {
+    type Error = ProgramError;
+
+    fn try_from(accounts: &'a [AccountInfo]) -> Result<Self, Self::Error> {
+        let [maker, fundraise] = accounts else {
+            return Err(FundraiserError::MissingAccounts.into());
+        };
+
+        check_account!(0, "signer", SignerAccount::check(maker))?;
+        check_account!(1, "writable", WritableAccount::check(fundraise))?;
+        check_account!(1, "owner", ProgramAccount::check(fundraise))?;
+
+        Ok(Self { maker, fundraise })
+    }
+}
+
+/// A fundraise's name and the URI of its off-chain metadata, each as a one-byte length followed
+/// by that many bytes of UTF-8. Initialize takes the same encoding after its tier thresholds.
+pub struct UpdateMetadataInstructionData<'a> {
+    pub name: &'a [u8],
+    pub uri: &'a [u8],
+}
+
+impl UpdateMetadataInstructionData<'_> {
+    pub const MAX_LEN: usize = 2 + MAX_NAME_LEN + MAX_URI_LEN;
+
+    /// Encodes the data into a buffer sized for the longest name and URI, returning it with
+    /// the length actually used.
+    pub fn to_bytes(&self) -> ([u8; UpdateMetadataInstructionData::MAX_LEN], usize) {
+        let mut bytes = [0u8; UpdateMetadataInstructionData::MAX_LEN];
+        let mut len = 0;
+
+        for field in [self.name, self.uri] {
+            bytes[len] = field.len() as u8;
+            bytes[len + 1..len + 1 + field.len()].copy_from_slice(field);
+            len += 1 + field.len();
+        }
+
+        (bytes, len)
+    }
+}
+
+impl<'a> TryFrom<&'a [u8]> for UpdateMetadataInstructionData<'a> {
+    type Error = ProgramError;
+
+    fn try_from(data: &'a [u8]) -> Result<Self, Self::Error> {
+        let (name, rest) = length_prefixed(data, MAX_NAME_LEN)?;
+        let (uri, rest) = length_prefixed(rest, MAX_URI_LEN)?;
+
+        if !rest.is_empty() {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+
+        Ok(Self { name, uri })
+    }
+}
+
+/// Splits a one-byte length and a UTF-8 string of at most `max_len` bytes off `data`.
+fn length_prefixed(data: &[u8], max_len: usize) -> Result<(&[u8], &[u8]), ProgramError> {
+    let (&len, rest) = data
+        .split_first()
+        .ok_or(ProgramError::InvalidInstructionData)?;
+    let len = len as usize;
+
+    if len > max_len {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let (field, rest) = rest
+        .split_at_checked(len)
+        .ok_or(ProgramError::InvalidInstructionData)?;
+
+    // trailing zeros would be lost to the padding the account stores the field with
+    if core::str::from_utf8(field).is_err() || field.last() == Some(&0) {
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    Ok((field, rest))
+}
+
+/// Lets the maker rename a fundraise or point it at new off-chain metadata, at any point in its
+/// life.
+pub struct UpdateMetadata<'a> {
+    pub accounts: UpdateMetadataAccounts<'a>,
+    pub data: UpdateMetadataInstructionData<'a>,
+}
+
+impl<'a> TryFrom<(&'a [u8], &'a [AccountInfo])> for UpdateMetadata<'a> {
+    type Error = ProgramError;
+
+    fn try_from((data, accounts): (&'a [u8], &'a [AccountInfo])) -> Result<Self, Self::Error> {
+        let accounts = UpdateMetadataAccounts::try_from(accounts)?;
+        let data = UpdateMetadataInstructionData::try_from(data)?;
+
+        Ok(Self { accounts, data })
+    }
+}
+
+impl Handler for UpdateMetadata<'_> {
+    const DISCRIMINATOR: u8 = FundraiserInstruction::UpdateMetadata as u8;
+
+    fn process(&mut self) -> ProgramResult {
+        let mut fundraise_data = self.accounts.fundraise.try_borrow_mut_data()?;
+        let fundraise = Fundraise::load_mut(fundraise_data.as_mut())?;
+
+        if fundraise.maker != *self.accounts.maker.key() {
+            return Err(FundraiserError::InvalidAddress.into());
+        }
+
+        ProgramAccount::validate(
+            &[Fundraise::PREFIX, &fundraise.maker, &[fundraise.bump]],
+            *self.accounts.fundraise.key(),
+        )?;
+
+        fundraise.set_metadata(self.data.name, self.data.uri);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use litesvm::LiteSVM;
+    use pinocchio::program_error::ProgramError;
+    use solana_instruction::error::InstructionError;
+    use solana_keypair::Keypair;
+    use solana_signer::Signer;
+    use spl_associated_token_account::solana_program::{
+        clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL,
+    };
+
+    use crate::{
+        Fundraise, FundraiseView, FundraiserError, MAX_NAME_LEN, UpdateMetadataInstructionData,
+        client,
+        tests::{
+            constants::{MINT_DECIMALS, TOKEN_PROGRAM_ID},
+            utils::{
+                assert_error, assert_instruction_error, build_and_send_transaction, init_mint,
+                init_wallet, setup,
+            },
+        },
+    };
+
+    fn initialize(litesvm: &mut LiteSVM) -> Keypair {
+        let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+        let ix = client::with_metadata(
+            client::initialize(
+                &maker.pubkey(),
+                &mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+                5_000_000,
+                SECONDS_PER_DAY,
+            ),
+            "Seed round",
+            "https://example.com/seed.json",
+        );
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        maker
+    }
+
+    fn fundraise(litesvm: &LiteSVM, maker: &Keypair) -> FundraiseView {
+        let account = litesvm
+            .get_account(&client::fundraise_address(&maker.pubkey()))
+            .unwrap();
+
+        Fundraise::unpack(&account.data).unwrap()
+    }
+
+    #[test]
+    fn update_metadata() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = initialize(litesvm);
+
+        let fundraise_before = fundraise(litesvm, &maker);
+
+        assert_eq!(fundraise_before.name, "Seed round");
+        assert_eq!(fundraise_before.uri, "https://example.com/seed.json");
+
+        let ix = client::update_metadata(&maker.pubkey(), "Series A", "");
+        build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+        let fundraise = fundraise(litesvm, &maker);
+
+        // a shorter name leaves no trace of the longer one it replaced
+        assert_eq!(fundraise.name, "Series A");
+        assert_eq!(fundraise.uri, "");
+        assert_eq!(fundraise.amount_to_raise, fundraise_before.amount_to_raise);
+    }
+
+    #[test]
+    fn throw_if_name_too_long() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = initialize(litesvm);
+
+        let ix = client::update_metadata(&maker.pubkey(), &"n".repeat(MAX_NAME_LEN + 1), "");
+        let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+        assert_instruction_error(res.unwrap_err(), InstructionError::InvalidInstructionData);
+    }
+
+    #[test]
+    fn throw_if_signer_not_maker() {
+        let (litesvm, _default_payer) = &mut setup();
+        let maker = initialize(litesvm);
+        let other = init_wallet(litesvm, LAMPORTS_PER_SOL);
+
+        let mut ix = client::update_metadata(&other.pubkey(), "Impostor", "");
+        ix.accounts[1].pubkey = client::fundraise_address(&maker.pubkey());
+        let res = build_and_send_transaction(litesvm, &[&other], &other.pubkey(), &[ix]);
+
+        assert_error(res.unwrap_err(), FundraiserError::InvalidAddress);
+    }
+
+    #[test]
+    fn parse_rejects_malformed_metadata() {
+        for data in [
+            &[][..],
+            &[1, b'a'],
+            &[1, b'a', 2, b'b'],
+            &[1, b'a', 0, 0],
+            &[2, 0xff, 0xfe, 0],
+            &[1, 0, 0],
+        ] {
+            assert_eq!(
+                UpdateMetadataInstructionData::try_from(data).err(),
+                Some(ProgramError::InvalidInstructionData),
+                "{data:?}"
+            );
+        }
+
+        let data = UpdateMetadataInstructionData::try_from(&[1, b'a', 0][..]).unwrap();
+
+        assert_eq!((data.name, data.uri), (&b"a"[..], &b""[..]));
+    }
+}
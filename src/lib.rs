@@ -1,3 +1,4 @@
+#[cfg(feature = "bpf-entrypoint")]
 use pinocchio::{
     ProgramResult, account_info::AccountInfo, entrypoint, program_error::ProgramError,
     pubkey::Pubkey,
@@ -19,25 +20,205 @@ pub use helpers::*;
 pub mod constants;
 pub use constants::*;
 
+pub mod events;
+pub use events::*;
+
+pub mod return_data;
+pub use return_data::*;
+
+pub mod math;
+pub use math::*;
+
+pub mod decode;
+pub use decode::*;
+
+pub mod cpi;
+
+pub mod filters;
+
+#[cfg(any(test, feature = "client"))]
+pub mod client;
+
+#[cfg(any(test, feature = "client"))]
+pub mod idl;
+
+/// LiteSVM helpers shared by the program's tests, exported under `test-utils` for integrators'
+/// own suites. [`tests::utils::setup`] loads the program from `target/deploy`.
+#[cfg(any(test, feature = "test-utils"))]
 pub mod tests;
 
 declare_id!("961YdRKb41e47DoC8JM973Xp52dVQ1NQ3P4bUm82eT8D");
+
+// The lazy entrypoint saves nothing here: the runtime lays the instruction data out after every
+// account, so the discriminator, and with it the parser to use, is only readable once all of
+// them have been walked, which is the work the eager entrypoint already does.
+#[cfg(feature = "bpf-entrypoint")]
 entrypoint!(process_instruction);
 
+#[cfg(feature = "bpf-entrypoint")]
 fn process_instruction(
     _program_id: &Pubkey,
     accounts: &[AccountInfo],
     instruction_data: &[u8],
 ) -> ProgramResult {
-    match instruction_data.split_first() {
-        Some((Initialize::DISCRIMINATOR, data)) => {
-            Initialize::try_from((data, accounts))?.process()
-        }
-        Some((Contribute::DISCRIMINATOR, data)) => {
-            Contribute::try_from((data, accounts))?.process()
+    let Some((instruction, encoding, data)) = FundraiserInstruction::parse(instruction_data) else {
+        #[cfg(feature = "logging")]
+        log_unknown_discriminator(instruction_data.first().copied());
+
+        return Err(ProgramError::InvalidInstructionData);
+    };
+
+    #[cfg(feature = "logging")]
+    {
+        log_instruction(instruction);
+
+        if encoding == Encoding::Legacy {
+            pinocchio::log::sol_log("deprecations: legacy one-byte instruction discriminator");
         }
-        Some((Claim::DISCRIMINATOR, _)) => Claim::try_from(accounts)?.process(),
-        Some((Refund::DISCRIMINATOR, _)) => Refund::try_from(accounts)?.process(),
+    }
+    let result = match encoding {
+        Encoding::Versioned => dispatch_versioned(instruction, Versioned(data), accounts),
+        _ => dispatch(instruction, data, accounts),
+    };
+
+    #[cfg(feature = "logging")]
+    if let Err(ProgramError::Custom(code)) = result
+        && let Ok(error) = FundraiserError::try_from(code)
+    {
+        use pinocchio::program_error::ToStr;
+
+        pinocchio::log::sol_log(error.to_str::<FundraiserError>());
+    }
+
+    result
+}
+
+/// Dispatches an instruction sent under its versioned code, which only Initialize and
+/// Contribute have.
+#[cfg(feature = "bpf-entrypoint")]
+fn dispatch_versioned(
+    instruction: FundraiserInstruction,
+    data: Versioned,
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    match instruction {
+        FundraiserInstruction::Initialize => Initialize::try_from((data, accounts))?.process(),
+        FundraiserInstruction::Contribute => Contribute::try_from((data, accounts))?.process(),
         _ => Err(ProgramError::InvalidInstructionData),
     }
 }
+
+#[cfg(feature = "bpf-entrypoint")]
+fn dispatch(
+    instruction: FundraiserInstruction,
+    data: &[u8],
+    accounts: &[AccountInfo],
+) -> ProgramResult {
+    match instruction {
+        FundraiserInstruction::Initialize => Initialize::try_from((data, accounts))?.process(),
+        FundraiserInstruction::Contribute => Contribute::try_from((data, accounts))?.process(),
+        FundraiserInstruction::Refund => Refund::try_from((data, accounts))?.process(),
+        FundraiserInstruction::Claim => Claim::try_from((data, accounts))?.process(),
+        FundraiserInstruction::RunHook => RunHook::try_from(accounts)?.process(),
+        FundraiserInstruction::Complete => Complete::try_from(accounts)?.process(),
+        FundraiserInstruction::InitializeConfig => {
+            InitializeConfig::try_from((data, accounts))?.process()
+        }
+        FundraiserInstruction::UpdateConfig => UpdateConfig::try_from((data, accounts))?.process(),
+        FundraiserInstruction::InitializeAllowlistPage => {
+            InitializeAllowlistPage::try_from((data, accounts))?.process()
+        }
+        FundraiserInstruction::ClaimAllowlistSlot => {
+            ClaimAllowlistSlot::try_from((data, accounts))?.process()
+        }
+        FundraiserInstruction::Finalize => Finalize::try_from(accounts)?.process(),
+        FundraiserInstruction::Donate => Donate::try_from((data, accounts))?.process(),
+        FundraiserInstruction::CreateContributor => {
+            CreateContributor::try_from(accounts)?.process()
+        }
+        FundraiserInstruction::ContributeFor => {
+            ContributeFor::try_from((data, accounts))?.process()
+        }
+        FundraiserInstruction::TransferContribution => {
+            TransferContribution::try_from(accounts)?.process()
+        }
+        FundraiserInstruction::ForceRefund => ForceRefund::try_from(accounts)?.process(),
+        FundraiserInstruction::Extend => Extend::try_from((data, accounts))?.process(),
+        FundraiserInstruction::ContributeDelegated => {
+            ContributeDelegated::try_from((data, accounts))?.process()
+        }
+        FundraiserInstruction::CrankRefund => CrankRefund::try_from(accounts)?.process(),
+        FundraiserInstruction::CloseFundraise => CloseFundraise::try_from(accounts)?.process(),
+        FundraiserInstruction::UpdateMetadata => {
+            UpdateMetadata::try_from((data, accounts))?.process()
+        }
+        FundraiserInstruction::SetPaused => SetPaused::try_from((data, accounts))?.process(),
+        FundraiserInstruction::Cancel => Cancel::try_from(accounts)?.process(),
+        FundraiserInstruction::DepositRewards => {
+            DepositRewards::try_from((data, accounts))?.process()
+        }
+        FundraiserInstruction::ClaimReward => ClaimReward::try_from(accounts)?.process(),
+        FundraiserInstruction::SweepRewards => SweepRewards::try_from(accounts)?.process(),
+        FundraiserInstruction::DepositMatch => DepositMatch::try_from((data, accounts))?.process(),
+        FundraiserInstruction::ReclaimMatch => ReclaimMatch::try_from(accounts)?.process(),
+        FundraiserInstruction::PostUpdate => PostUpdate::try_from((data, accounts))?.process(),
+        FundraiserInstruction::Snapshot => Snapshot::try_from(accounts)?.process(),
+        FundraiserInstruction::SetClaimAuthority => {
+            SetClaimAuthority::try_from((data, accounts))?.process()
+        }
+        FundraiserInstruction::SweepForeign => SweepForeign::try_from((data, accounts))?.process(),
+    }
+}
+
+/// Logs `Instruction: <Name>`, assembled on the stack since the program does not allocate.
+#[cfg(all(feature = "bpf-entrypoint", feature = "logging"))]
+fn log_instruction(instruction: FundraiserInstruction) {
+    const PREFIX: &[u8] = b"Instruction: ";
+
+    let name = instruction.name().as_bytes();
+    let len = PREFIX.len() + name.len();
+    let mut line = [0u8; 64];
+
+    line[..PREFIX.len()].copy_from_slice(PREFIX);
+    line[PREFIX.len()..len].copy_from_slice(name);
+
+    if let Ok(line) = core::str::from_utf8(&line[..len]) {
+        pinocchio::log::sol_log(line);
+    }
+}
+
+/// Logs `Unknown instruction discriminator: <byte>` for data no instruction is addressed by, or
+/// that the data is empty.
+#[cfg(all(feature = "bpf-entrypoint", feature = "logging"))]
+fn log_unknown_discriminator(discriminator: Option<u8>) {
+    const PREFIX: &[u8] = b"Unknown instruction discriminator: ";
+
+    let Some(discriminator) = discriminator else {
+        pinocchio::log::sol_log("Missing instruction discriminator");
+        return;
+    };
+
+    let digits = [
+        discriminator / 100,
+        discriminator / 10 % 10,
+        discriminator % 10,
+    ];
+    let skip = match discriminator {
+        0..=9 => 2,
+        10..=99 => 1,
+        _ => 0,
+    };
+    let mut line = [0u8; PREFIX.len() + 3];
+    let mut len = PREFIX.len();
+
+    line[..len].copy_from_slice(PREFIX);
+
+    for digit in &digits[skip..] {
+        line[len] = b'0' + digit;
+        len += 1;
+    }
+
+    if let Ok(line) = core::str::from_utf8(&line[..len]) {
+        pinocchio::log::sol_log(line);
+    }
+}
@@ -0,0 +1,214 @@
+use pinocchio::{ProgramResult, program_error::ProgramError};
+
+use crate::{FundraiserError, MAX_BPS};
+
+/// Converts `amount` base units of a mint with `from_decimals` into base units of a mint with
+/// `to_decimals`. Scaling down rounds toward zero; scaling up fails rather than overflowing.
+pub fn normalize(amount: u64, from_decimals: u8, to_decimals: u8) -> Result<u64, ProgramError> {
+    if from_decimals == to_decimals {
+        return Ok(amount);
+    }
+
+    let factor = 10u64
+        .checked_pow(u32::from(from_decimals.abs_diff(to_decimals)))
+        .ok_or(FundraiserError::MathOverflow)?;
+
+    if to_decimals > from_decimals {
+        amount
+            .checked_mul(factor)
+            .ok_or(FundraiserError::MathOverflow.into())
+    } else {
+        Ok(amount / factor)
+    }
+}
+
+/// Largest total a single contributor may give towards `amount_to_raise`, with a cap of 0 bps
+/// meaning unlimited.
+pub fn max_contribution(amount_to_raise: u64, max_contribution_bps: u16) -> u64 {
+    match max_contribution_bps {
+        0 => u64::MAX,
+        bps => (u128::from(amount_to_raise) * u128::from(bps) / u128::from(MAX_BPS)) as u64,
+    }
+}
+
+/// Checks a contributor who has already given `contributor_amount` may add `amount` without
+/// exceeding `max_contribution`. A total that does not fit in a `u64` is always over the cap.
+pub fn check_contribution_cap(
+    max_contribution: u64,
+    contributor_amount: u64,
+    amount: u64,
+) -> ProgramResult {
+    if amount > max_contribution {
+        return Err(FundraiserError::ContributionTooBig.into());
+    }
+
+    match contributor_amount.checked_add(amount) {
+        Some(total) if total <= max_contribution => Ok(()),
+        _ => Err(FundraiserError::MaximumContributionsReached.into()),
+    }
+}
+
+/// Largest part of `amount` a contributor who has already given `contributor_amount` may add
+/// without exceeding `max_contribution` or the `remaining` target, failing if that falls short
+/// of `min_contribution`, as it does once either is used up.
+pub fn clamp_contribution(
+    amount: u64,
+    min_contribution: u64,
+    max_contribution: u64,
+    contributor_amount: u64,
+    remaining: u64,
+) -> Result<u64, ProgramError> {
+    let amount = amount
+        .min(max_contribution.saturating_sub(contributor_amount))
+        .min(remaining);
+
+    if amount == 0 || amount < min_contribution {
+        return Err(FundraiserError::ContributionTooSmall.into());
+    }
+
+    Ok(amount)
+}
+
+/// Share of `total_rewards` owed for `amount` out of `basis`, rounded down so the shares of
+/// amounts summing to `basis` never exceed the total.
+pub fn reward_share(amount: u64, total_rewards: u64, basis: u64) -> u64 {
+    match basis {
+        0 => 0,
+        basis => (u128::from(amount) * u128::from(total_rewards) / u128::from(basis)) as u64,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pinocchio::program_error::ProgramError;
+
+    use crate::{
+        FundraiserError, check_contribution_cap, clamp_contribution, max_contribution, normalize,
+        reward_share,
+    };
+
+    #[test]
+    fn normalize_equal_decimals() {
+        for decimals in [0, 6, 9] {
+            assert_eq!(normalize(1_234_567, decimals, decimals), Ok(1_234_567));
+        }
+    }
+
+    #[test]
+    fn normalize_six_and_nine_decimals() {
+        assert_eq!(normalize(1_500_000, 6, 9), Ok(1_500_000_000));
+        assert_eq!(normalize(1_500_000_000, 9, 6), Ok(1_500_000));
+        assert_eq!(normalize(1_500_000_999, 9, 6), Ok(1_500_000));
+    }
+
+    #[test]
+    fn normalize_zero_and_six_decimals() {
+        assert_eq!(normalize(42, 0, 6), Ok(42_000_000));
+        assert_eq!(normalize(42_999_999, 6, 0), Ok(42));
+        assert_eq!(normalize(999_999, 6, 0), Ok(0));
+    }
+
+    #[test]
+    fn throw_if_normalize_overflows() {
+        let overflow = Err(ProgramError::from(FundraiserError::MathOverflow));
+
+        assert_eq!(normalize(u64::MAX, 6, 9), overflow);
+        assert_eq!(normalize(1, 0, 20), overflow);
+        assert_eq!(
+            normalize(u64::MAX / 1_000, 6, 9),
+            Ok(u64::MAX / 1_000 * 1_000)
+        );
+    }
+
+    #[test]
+    fn max_contribution_rounds_down() {
+        assert_eq!(max_contribution(5_000_000, 0), u64::MAX);
+        assert_eq!(max_contribution(5_000_000, 1_000), 500_000);
+        assert_eq!(max_contribution(9_999, 1), 0);
+        assert_eq!(max_contribution(u64::MAX, 10_000), u64::MAX);
+    }
+
+    #[test]
+    fn throw_if_contribution_exceeds_cap() {
+        assert_eq!(check_contribution_cap(500_000, 400_000, 100_000), Ok(()));
+        assert_eq!(
+            check_contribution_cap(500_000, 0, 500_001),
+            Err(FundraiserError::ContributionTooBig.into())
+        );
+        assert_eq!(
+            check_contribution_cap(500_000, 400_001, 100_000),
+            Err(FundraiserError::MaximumContributionsReached.into())
+        );
+        assert_eq!(
+            check_contribution_cap(u64::MAX, u64::MAX, 1),
+            Err(FundraiserError::MaximumContributionsReached.into())
+        );
+    }
+
+    #[test]
+    fn clamp_contribution_to_cap_and_target() {
+        // within both, the amount is left as is
+        assert_eq!(
+            clamp_contribution(100_000, 1, 500_000, 0, 1_000_000),
+            Ok(100_000)
+        );
+        // the bps cap counts what the contributor has already given
+        assert_eq!(
+            clamp_contribution(300_000, 1, 500_000, 400_000, 1_000_000),
+            Ok(100_000)
+        );
+        // the target remainder binds when it is the smaller
+        assert_eq!(
+            clamp_contribution(300_000, 1, 500_000, 0, 250_000),
+            Ok(250_000)
+        );
+        assert_eq!(
+            clamp_contribution(u64::MAX, 1, u64::MAX, u64::MAX - 1, u64::MAX),
+            Ok(1)
+        );
+    }
+
+    #[test]
+    fn throw_if_clamped_contribution_too_small() {
+        let too_small = Err(ProgramError::from(FundraiserError::ContributionTooSmall));
+
+        // a clamp below the minimum is not taken
+        assert_eq!(
+            clamp_contribution(300_000, 50_000, 500_000, 460_000, 1_000_000),
+            too_small
+        );
+        assert_eq!(
+            clamp_contribution(300_000, 50_000, 500_000, 0, 40_000),
+            too_small
+        );
+        // nor is nothing at all, whatever the minimum
+        assert_eq!(
+            clamp_contribution(300_000, 0, 500_000, 500_000, 1_000_000),
+            too_small
+        );
+        assert_eq!(clamp_contribution(300_000, 0, 500_000, 0, 0), too_small);
+        assert_eq!(clamp_contribution(0, 0, 500_000, 0, 1_000_000), too_small);
+    }
+
+    #[test]
+    fn reward_share_rounds_down() {
+        assert_eq!(reward_share(500_000, 1_000_000, 5_000_000), 100_000);
+        assert_eq!(reward_share(1, 1_000_000, 3), 333_333);
+        assert_eq!(reward_share(1_000, 1, 5_000_000), 0);
+        assert_eq!(reward_share(u64::MAX, u64::MAX, u64::MAX), u64::MAX);
+        assert_eq!(reward_share(1_000, 1_000, 0), 0);
+    }
+
+    #[test]
+    fn reward_shares_never_exceed_total() {
+        let amounts = [1_234_567, 2_345_678, 1_419_755];
+        let basis = amounts.iter().sum();
+        let paid: u64 = amounts
+            .iter()
+            .map(|&amount| reward_share(amount, 7_777_777, basis))
+            .sum();
+
+        assert!(paid <= 7_777_777);
+        assert!(7_777_777 - paid < amounts.len() as u64);
+    }
+}
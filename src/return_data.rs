@@ -0,0 +1,100 @@
+//! Return data set by the fundraiser, so programs invoking it through CPI can read the outcome
+//! with `get_return_data` instead of re-reading accounts. Every field is a little-endian `u64`.
+
+use core::mem::size_of;
+use pinocchio::cpi::set_return_data;
+
+/// Set by Contribute once the contribution has been recorded.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContributeReturnData {
+    /// Everything the contributor has put in, including this contribution.
+    pub contributor_total: u64,
+    /// Organic contributions recorded by the fundraise, including this contribution.
+    pub fundraise_total: u64,
+    /// Amount still needed to reach the target, counting donations and any maker completion.
+    pub remaining: u64,
+    /// Amount this contribution took, less than the one sent if it was clamped.
+    pub amount: u64,
+}
+
+impl ContributeReturnData {
+    pub const LEN: usize = 4 * size_of::<u64>();
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        let mut bytes = [0u8; Self::LEN];
+
+        bytes[0..8].copy_from_slice(&self.contributor_total.to_le_bytes());
+        bytes[8..16].copy_from_slice(&self.fundraise_total.to_le_bytes());
+        bytes[16..24].copy_from_slice(&self.remaining.to_le_bytes());
+        bytes[24..32].copy_from_slice(&self.amount.to_le_bytes());
+
+        bytes
+    }
+
+    pub fn from_bytes(bytes: &[u8; Self::LEN]) -> Self {
+        Self {
+            contributor_total: u64::from_le_bytes(bytes[0..8].try_into().unwrap()),
+            fundraise_total: u64::from_le_bytes(bytes[8..16].try_into().unwrap()),
+            remaining: u64::from_le_bytes(bytes[16..24].try_into().unwrap()),
+            amount: u64::from_le_bytes(bytes[24..32].try_into().unwrap()),
+        }
+    }
+
+    pub fn set(&self) {
+        set_return_data(&self.to_bytes());
+    }
+}
+
+/// Set by Claim once the raised funds have been paid out.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClaimReturnData {
+    /// Tokens sent to the claim destination, net of the protocol fee and including any match and
+    /// anything sent straight to the vault.
+    pub claimed_amount: u64,
+}
+
+impl ClaimReturnData {
+    pub const LEN: usize = size_of::<u64>();
+
+    pub fn to_bytes(&self) -> [u8; Self::LEN] {
+        self.claimed_amount.to_le_bytes()
+    }
+
+    pub fn from_bytes(bytes: &[u8; Self::LEN]) -> Self {
+        Self {
+            claimed_amount: u64::from_le_bytes(*bytes),
+        }
+    }
+
+    pub fn set(&self) {
+        set_return_data(&self.to_bytes());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ClaimReturnData, ContributeReturnData};
+
+    #[test]
+    fn contribute_round_trip() {
+        let data = ContributeReturnData {
+            contributor_total: 1,
+            fundraise_total: u64::MAX,
+            remaining: 0x0102_0304_0506_0708,
+            amount: 2,
+        };
+        let bytes = data.to_bytes();
+
+        assert_eq!(bytes[16..24], [8, 7, 6, 5, 4, 3, 2, 1]);
+        assert_eq!(ContributeReturnData::from_bytes(&bytes), data);
+    }
+
+    #[test]
+    fn claim_round_trip() {
+        let data = ClaimReturnData {
+            claimed_amount: 5_000_000,
+        };
+
+        assert_eq!(ClaimReturnData::from_bytes(&data.to_bytes()), data);
+    }
+}
@@ -0,0 +1,270 @@
+use pinocchio::{program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{AccountLoad, FundraiserError, Prefix, SetInner, Space};
+use core::mem::size_of;
+
+#[cfg(any(test, feature = "client"))]
+use crate::idl::field;
+
+/// Number of allowlist slots held by a single page.
+pub const ALLOWLIST_PAGE_BITS: u16 = 4096;
+
+/// A 4096-slot bitmap of the allowlist of a fundraise. A set bit means the slot has been claimed
+/// by the wallet recorded in the matching [`AllowlistReceipt`].
+#[repr(C)]
+pub struct AllowlistPage {
+    pub fundraise: Pubkey,
+    page_index: [u8; 2],
+    pub bump: u8,
+    bits: [u8; ALLOWLIST_PAGE_BITS as usize / 8],
+}
+
+impl Prefix for AllowlistPage {
+    const PREFIX: &'static [u8] = b"allowpage";
+}
+
+impl Space for AllowlistPage {
+    const LEN: usize = size_of::<Self>();
+}
+
+impl AccountLoad for AllowlistPage {}
+
+#[cfg(any(test, feature = "client"))]
+impl AllowlistPage {
+    pub const IDL_FIELDS: &'static [crate::idl::IdlField] = &[
+        field!(AllowlistPage, fundraise, "pubkey"),
+        field!(AllowlistPage, page_index, "u16"),
+        field!(AllowlistPage, bump, "u8"),
+        field!(AllowlistPage, bits, "[u8; 512]"),
+    ];
+}
+
+impl AllowlistPage {
+    #[inline(always)]
+    pub fn get_page_index(&self) -> u16 {
+        u16::from_le_bytes(self.page_index)
+    }
+
+    #[inline(always)]
+    pub fn set_page_index(&mut self, page_index: u16) {
+        self.page_index = page_index.to_le_bytes();
+    }
+
+    #[inline(always)]
+    fn position(bit_index: u16) -> Result<(usize, u8), ProgramError> {
+        if bit_index >= ALLOWLIST_PAGE_BITS {
+            return Err(FundraiserError::InvalidAllowlistSlot.into());
+        }
+
+        Ok((bit_index as usize / 8, 1 << (bit_index % 8)))
+    }
+
+    #[inline(always)]
+    pub fn is_set(&self, bit_index: u16) -> Result<bool, ProgramError> {
+        let (byte, mask) = Self::position(bit_index)?;
+
+        Ok(self.bits[byte] & mask != 0)
+    }
+
+    /// Sets `bit_index`, failing if the slot was already taken.
+    #[inline(always)]
+    pub fn set(&mut self, bit_index: u16) -> Result<(), ProgramError> {
+        let (byte, mask) = Self::position(bit_index)?;
+
+        if self.bits[byte] & mask != 0 {
+            return Err(FundraiserError::AllowlistSlotTaken.into());
+        }
+
+        self.bits[byte] |= mask;
+
+        Ok(())
+    }
+}
+
+pub struct AllowlistPageParams {
+    pub fundraise: Pubkey,
+    pub page_index: u16,
+    pub bump: u8,
+}
+
+impl AllowlistPageParams {
+    pub fn new(fundraise: Pubkey, page_index: u16, bump: u8) -> Self {
+        Self {
+            fundraise,
+            page_index,
+            bump,
+        }
+    }
+}
+
+impl SetInner for AllowlistPage {
+    type Params = AllowlistPageParams;
+
+    fn set_inner(&mut self, params: Self::Params) {
+        self.fundraise = params.fundraise;
+        self.set_page_index(params.page_index);
+        self.bump = params.bump;
+        self.bits = [0; ALLOWLIST_PAGE_BITS as usize / 8];
+    }
+}
+
+/// Records the allowlist slot claimed by a wallet, so contribute can find its bit.
+#[repr(C)]
+pub struct AllowlistReceipt {
+    pub fundraise: Pubkey,
+    pub wallet: Pubkey,
+    page_index: [u8; 2],
+    bit_index: [u8; 2],
+    pub bump: u8,
+}
+
+impl Prefix for AllowlistReceipt {
+    const PREFIX: &'static [u8] = b"allowreceipt";
+}
+
+impl Space for AllowlistReceipt {
+    const LEN: usize = size_of::<Self>();
+}
+
+impl AccountLoad for AllowlistReceipt {}
+
+#[cfg(any(test, feature = "client"))]
+impl AllowlistReceipt {
+    pub const IDL_FIELDS: &'static [crate::idl::IdlField] = &[
+        field!(AllowlistReceipt, fundraise, "pubkey"),
+        field!(AllowlistReceipt, wallet, "pubkey"),
+        field!(AllowlistReceipt, page_index, "u16"),
+        field!(AllowlistReceipt, bit_index, "u16"),
+        field!(AllowlistReceipt, bump, "u8"),
+    ];
+}
+
+impl AllowlistReceipt {
+    #[inline(always)]
+    pub fn get_page_index(&self) -> u16 {
+        u16::from_le_bytes(self.page_index)
+    }
+
+    #[inline(always)]
+    pub fn get_bit_index(&self) -> u16 {
+        u16::from_le_bytes(self.bit_index)
+    }
+}
+
+pub struct AllowlistReceiptParams {
+    pub fundraise: Pubkey,
+    pub wallet: Pubkey,
+    pub page_index: u16,
+    pub bit_index: u16,
+    pub bump: u8,
+}
+
+impl AllowlistReceiptParams {
+    pub fn new(
+        fundraise: Pubkey,
+        wallet: Pubkey,
+        page_index: u16,
+        bit_index: u16,
+        bump: u8,
+    ) -> Self {
+        Self {
+            fundraise,
+            wallet,
+            page_index,
+            bit_index,
+            bump,
+        }
+    }
+}
+
+impl SetInner for AllowlistReceipt {
+    type Params = AllowlistReceiptParams;
+
+    fn set_inner(&mut self, params: Self::Params) {
+        self.fundraise = params.fundraise;
+        self.wallet = params.wallet;
+        self.page_index = params.page_index.to_le_bytes();
+        self.bit_index = params.bit_index.to_le_bytes();
+        self.bump = params.bump;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use litesvm::LiteSVM;
+
+    use crate::{
+        ALLOWLIST_PAGE_BITS, AccountLoad, AllowlistPage, AllowlistPageParams, AllowlistReceipt,
+        FundraiserError, SetInner, Space,
+    };
+
+    fn page() -> Vec<u8> {
+        let mut data = vec![0u8; AllowlistPage::LEN];
+        AllowlistPage::load_mut(&mut data)
+            .unwrap()
+            .set_inner(AllowlistPageParams::new([1u8; 32], 3, 254));
+
+        data
+    }
+
+    #[test]
+    fn set_bits_independently() {
+        let mut data = page();
+        let page = AllowlistPage::load_mut(&mut data).unwrap();
+
+        for bit in [0, 7, 8, 1_000, ALLOWLIST_PAGE_BITS - 1] {
+            assert!(!page.is_set(bit).unwrap());
+            page.set(bit).unwrap();
+            assert!(page.is_set(bit).unwrap());
+        }
+
+        let set = (0..ALLOWLIST_PAGE_BITS)
+            .filter(|bit| page.is_set(*bit).unwrap())
+            .count();
+
+        assert_eq!(set, 5);
+        assert_eq!(page.get_page_index(), 3);
+    }
+
+    #[test]
+    fn throw_if_bit_already_set() {
+        let mut data = page();
+        let page = AllowlistPage::load_mut(&mut data).unwrap();
+
+        page.set(42).unwrap();
+
+        assert_eq!(
+            page.set(42),
+            Err(FundraiserError::AllowlistSlotTaken.into())
+        );
+    }
+
+    #[test]
+    fn throw_if_bit_out_of_range() {
+        let mut data = page();
+        let page = AllowlistPage::load_mut(&mut data).unwrap();
+
+        assert_eq!(
+            page.is_set(ALLOWLIST_PAGE_BITS),
+            Err(FundraiserError::InvalidAllowlistSlot.into())
+        );
+        assert_eq!(
+            page.set(u16::MAX),
+            Err(FundraiserError::InvalidAllowlistSlot.into())
+        );
+    }
+
+    #[test]
+    fn page_rent_is_cheaper_than_per_address_pdas() {
+        let litesvm = LiteSVM::new();
+        let wallets = u64::from(ALLOWLIST_PAGE_BITS);
+
+        // the maker funds one page per 4096 wallets rather than one PDA per wallet, while each
+        // receipt is paid for by the contributor claiming the slot
+        let page_rent = litesvm.minimum_balance_for_rent_exemption(AllowlistPage::LEN);
+        let per_address_rent =
+            wallets * litesvm.minimum_balance_for_rent_exemption(AllowlistReceipt::LEN);
+
+        assert!(page_rent * 100 < per_address_rent);
+    }
+}
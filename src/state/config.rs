@@ -0,0 +1,183 @@
+use pinocchio::pubkey::Pubkey;
+
+use crate::{AccountLoad, FEE_EXEMPT_THRESHOLD, MAX_BPS, Prefix, SetInner, Space};
+use core::mem::size_of;
+
+#[cfg(any(test, feature = "client"))]
+use crate::idl::field;
+
+#[repr(C)]
+pub struct Config {
+    pub admin: Pubkey,
+    fee_bps: [u8; 2],
+    pub fee_collector: Pubkey,
+    pub paused: u8,
+    pub bump: u8,
+    bond_lamports: [u8; 8],
+    bond_slash_bps: [u8; 2],
+}
+
+impl Prefix for Config {
+    const PREFIX: &'static [u8] = b"config";
+}
+
+impl Space for Config {
+    const LEN: usize = size_of::<Self>();
+}
+
+impl AccountLoad for Config {}
+
+#[cfg(any(test, feature = "client"))]
+impl Config {
+    pub const IDL_FIELDS: &'static [crate::idl::IdlField] = &[
+        field!(Config, admin, "pubkey"),
+        field!(Config, fee_bps, "u16"),
+        field!(Config, fee_collector, "pubkey"),
+        field!(Config, paused, "bool"),
+        field!(Config, bump, "u8"),
+        field!(Config, bond_lamports, "u64"),
+        field!(Config, bond_slash_bps, "u16"),
+    ];
+}
+
+impl Config {
+    #[inline(always)]
+    pub fn get_fee_bps(&self) -> u16 {
+        u16::from_le_bytes(self.fee_bps)
+    }
+
+    /// Protocol fee charged on a claim of `amount`, rounded down. Amounts below
+    /// `FEE_EXEMPT_THRESHOLD` are charged nothing.
+    #[inline(always)]
+    pub fn fee_on(&self, amount: u64) -> u64 {
+        if amount < FEE_EXEMPT_THRESHOLD {
+            return 0;
+        }
+
+        (u128::from(amount) * u128::from(self.get_fee_bps()) / u128::from(MAX_BPS)) as u64
+    }
+
+    #[inline(always)]
+    pub fn is_paused(&self) -> bool {
+        self.paused == 1
+    }
+
+    /// Lamports a maker stakes on each new fundraise, beyond its rent.
+    #[inline(always)]
+    pub fn get_bond_lamports(&self) -> u64 {
+        u64::from_le_bytes(self.bond_lamports)
+    }
+
+    #[inline(always)]
+    pub fn get_bond_slash_bps(&self) -> u16 {
+        u16::from_le_bytes(self.bond_slash_bps)
+    }
+
+    /// Share of a `bond` forfeited to the fee collector by a maker cancelling with
+    /// contributions, rounded down.
+    #[inline(always)]
+    pub fn bond_slash_on(&self, bond: u64) -> u64 {
+        (u128::from(bond) * u128::from(self.get_bond_slash_bps()) / u128::from(MAX_BPS)) as u64
+    }
+
+    #[inline(always)]
+    pub fn set_fee_bps(&mut self, fee_bps: u16) {
+        self.fee_bps = fee_bps.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn set_bond(&mut self, lamports: u64, slash_bps: u16) {
+        self.bond_lamports = lamports.to_le_bytes();
+        self.bond_slash_bps = slash_bps.to_le_bytes();
+    }
+}
+
+pub struct ConfigParams {
+    pub admin: Pubkey,
+    pub fee_bps: u16,
+    pub fee_collector: Pubkey,
+    pub paused: u8,
+    pub bump: u8,
+}
+
+impl ConfigParams {
+    pub fn new(admin: Pubkey, fee_bps: u16, fee_collector: Pubkey, paused: u8, bump: u8) -> Self {
+        Self {
+            admin,
+            fee_bps,
+            fee_collector,
+            paused,
+            bump,
+        }
+    }
+}
+
+impl SetInner for Config {
+    type Params = ConfigParams;
+
+    fn set_inner(&mut self, params: Self::Params) {
+        self.admin = params.admin;
+        self.set_fee_bps(params.fee_bps);
+        self.fee_collector = params.fee_collector;
+        self.paused = params.paused;
+        self.bump = params.bump;
+        self.set_bond(0, 0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::CONFIG_ADDRESS;
+
+    #[test]
+    fn fee_rounds_down_above_exempt_threshold() {
+        let mut data = [0u8; Config::LEN];
+        let config = Config::load_mut(&mut data).unwrap();
+
+        config.set_fee_bps(250); // 2.5%
+
+        for (amount, fee) in [
+            // exempt, although 2.5% of it would be well above zero
+            (FEE_EXEMPT_THRESHOLD - 1, 0),
+            (FEE_EXEMPT_THRESHOLD, 25_000),
+            (FEE_EXEMPT_THRESHOLD + 39, 25_000),
+            (FEE_EXEMPT_THRESHOLD + 40, 25_001),
+            (5_499_999, 137_499),
+            (u64::MAX, 461_168_601_842_738_790),
+        ] {
+            assert_eq!(config.fee_on(amount), fee);
+        }
+
+        config.set_fee_bps(0);
+
+        assert_eq!(config.fee_on(u64::MAX), 0);
+    }
+
+    #[test]
+    fn bond_slash_rounds_down() {
+        let mut data = [0u8; Config::LEN];
+        let config = Config::load_mut(&mut data).unwrap();
+
+        config.set_bond(100_000_000, 2_500); // 25%
+
+        assert_eq!(config.bond_slash_on(config.get_bond_lamports()), 25_000_000);
+        assert_eq!(config.bond_slash_on(99), 24);
+
+        config.set_bond(100_000_000, MAX_BPS);
+
+        assert_eq!(config.bond_slash_on(u64::MAX), u64::MAX);
+    }
+
+    #[test]
+    fn config_address_matches_derivation() {
+        assert_eq!(
+            solana_pubkey::Pubkey::find_program_address(
+                &[Config::PREFIX],
+                &crate::tests::constants::PROGRAM_ID
+            )
+            .0,
+            solana_pubkey::Pubkey::new_from_array(CONFIG_ADDRESS)
+        );
+    }
+}
@@ -1,7 +1,10 @@
-use pinocchio::pubkey::Pubkey;
+use pinocchio::{ProgramResult, pubkey::Pubkey};
 
-use crate::{AccountLoad, Prefix, SetInner, Space};
-use core::mem::size_of;
+use crate::{AccountLoad, Fundraise, FundraiserError, Prefix, SetInner, Space};
+use core::mem::{offset_of, size_of};
+
+#[cfg(any(test, feature = "client"))]
+use crate::idl::field;
 
 #[repr(C)]
 pub struct Contributor {
@@ -9,6 +12,22 @@ pub struct Contributor {
     pub authority: Pubkey,
     amount: [u8; 8],
     pub bump: u8,
+    pending_hook_amount: [u8; 8],
+    contribution_count: [u8; 4],
+    last_contribution_ts: [u8; 8],
+    /// Whoever paid the account's rent, and so gets it back when the account closes.
+    pub rent_payer: Pubkey,
+    /// Reward tier reached by `amount`, kept in step with it.
+    tier: u8,
+    /// Set once the contributor has claimed their reward.
+    pub reward_claimed: u8,
+    /// `amount` as frozen by the contributor's first snapshot, and lowered to it by any refund
+    /// since.
+    snapshot_amount: [u8; 8],
+    /// Set once the contributor has been snapshotted.
+    pub snapshotted: u8,
+    /// Mint the fundraise raises, copied from it so indexers can group positions by mint.
+    pub mint: Pubkey,
 }
 
 impl Prefix for Contributor {
@@ -21,30 +40,157 @@ impl Space for Contributor {
 
 impl AccountLoad for Contributor {}
 
+// The published filter offsets must track the layout, so moving a field fails the build.
+const _: () = {
+    use crate::filters::contributor;
+
+    assert!(contributor::DATA_SIZE == Contributor::LEN);
+    assert!(contributor::FUNDRAISE == offset_of!(Contributor, fundraise));
+    assert!(contributor::AUTHORITY == offset_of!(Contributor, authority));
+    assert!(contributor::RENT_PAYER == offset_of!(Contributor, rent_payer));
+    assert!(contributor::MINT == offset_of!(Contributor, mint));
+};
+
+#[cfg(any(test, feature = "client"))]
+impl Contributor {
+    pub const IDL_FIELDS: &'static [crate::idl::IdlField] = &[
+        field!(Contributor, fundraise, "pubkey"),
+        field!(Contributor, authority, "pubkey"),
+        field!(Contributor, amount, "u64"),
+        field!(Contributor, bump, "u8"),
+        field!(Contributor, pending_hook_amount, "u64"),
+        field!(Contributor, contribution_count, "u32"),
+        field!(Contributor, last_contribution_ts, "i64"),
+        field!(Contributor, rent_payer, "pubkey"),
+        field!(Contributor, tier, "u8"),
+        field!(Contributor, reward_claimed, "bool"),
+        field!(Contributor, snapshot_amount, "u64"),
+        field!(Contributor, snapshotted, "bool"),
+        field!(Contributor, mint, "pubkey"),
+    ];
+}
+
 impl Contributor {
     #[inline(always)]
     pub fn get_amount(&self) -> u64 {
         u64::from_le_bytes(self.amount)
     }
 
+    #[inline(always)]
+    pub fn get_pending_hook_amount(&self) -> u64 {
+        u64::from_le_bytes(self.pending_hook_amount)
+    }
+
+    #[inline(always)]
+    pub fn get_contribution_count(&self) -> u32 {
+        u32::from_le_bytes(self.contribution_count)
+    }
+
+    #[inline(always)]
+    pub fn get_last_contribution_ts(&self) -> i64 {
+        i64::from_le_bytes(self.last_contribution_ts)
+    }
+
+    #[inline(always)]
+    pub fn get_tier(&self) -> u8 {
+        self.tier
+    }
+
+    /// Whether the contributor has claimed their reward.
+    #[inline(always)]
+    pub fn is_reward_claimed(&self) -> bool {
+        self.reward_claimed == 1
+    }
+
+    #[inline(always)]
+    pub fn get_snapshot_amount(&self) -> u64 {
+        u64::from_le_bytes(self.snapshot_amount)
+    }
+
+    /// Whether the contributor has been snapshotted.
+    #[inline(always)]
+    pub fn is_snapshotted(&self) -> bool {
+        self.snapshotted == 1
+    }
+
     #[inline(always)]
     pub fn set_amount(&mut self, amount: u64) {
         self.amount = amount.to_le_bytes();
     }
+
+    #[inline(always)]
+    pub fn set_pending_hook_amount(&mut self, amount: u64) {
+        self.pending_hook_amount = amount.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn set_contribution_count(&mut self, count: u32) {
+        self.contribution_count = count.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn set_last_contribution_ts(&mut self, ts: i64) {
+        self.last_contribution_ts = ts.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn set_tier(&mut self, tier: u8) {
+        self.tier = tier;
+    }
+
+    /// Records a contribution made at `now`.
+    #[inline(always)]
+    pub fn record_contribution(&mut self, now: i64) {
+        self.set_contribution_count(self.get_contribution_count().saturating_add(1));
+        self.set_last_contribution_ts(now);
+    }
+
+    /// Freezes `amount` as the contributor's snapshot the first time, and after that only ever
+    /// lowers the snapshot to `amount`, keeping the fundraise's `snapshot_total` in step. A
+    /// snapshot never rises, so tokens refunded after it carry no weight, nor do they regain any
+    /// by being contributed back.
+    pub fn snapshot(&mut self, fundraise: &mut Fundraise) -> ProgramResult {
+        let amount = self.get_amount();
+        let frozen = self.get_snapshot_amount();
+        let snapshot_amount = if self.is_snapshotted() {
+            frozen.min(amount)
+        } else {
+            amount
+        };
+        let snapshot_total = (fundraise.get_snapshot_total() - frozen)
+            .checked_add(snapshot_amount)
+            .ok_or(FundraiserError::MathOverflow)?;
+
+        fundraise.set_snapshot_total(snapshot_total);
+        self.snapshot_amount = snapshot_amount.to_le_bytes();
+        self.snapshotted = 1;
+
+        Ok(())
+    }
 }
 
 pub struct ContributorParams {
     pub fundraise: Pubkey,
     pub authority: Pubkey,
     pub bump: u8,
+    pub rent_payer: Pubkey,
+    pub mint: Pubkey,
 }
 
 impl ContributorParams {
-    pub fn new(fundraise: Pubkey, authority: Pubkey, bump: u8) -> Self {
+    pub fn new(
+        fundraise: Pubkey,
+        authority: Pubkey,
+        bump: u8,
+        rent_payer: Pubkey,
+        mint: Pubkey,
+    ) -> Self {
         Self {
             fundraise,
             authority,
             bump,
+            rent_payer,
+            mint,
         }
     }
 }
@@ -57,5 +203,39 @@ impl SetInner for Contributor {
         self.authority = params.authority;
         self.set_amount(0);
         self.bump = params.bump;
+        self.set_pending_hook_amount(0);
+        self.set_contribution_count(0);
+        self.set_last_contribution_ts(0);
+        self.rent_payer = params.rent_payer;
+        self.tier = 0;
+        self.reward_claimed = 0;
+        self.snapshot_amount = [0; 8];
+        self.snapshotted = 0;
+        self.mint = params.mint;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        AccountLoad, Contributor, ContributorParams, SetInner, Space, filters::contributor,
+    };
+
+    #[test]
+    fn filters_match_layout() {
+        let mut data = [0u8; Contributor::LEN];
+        Contributor::load_mut(&mut data)
+            .unwrap()
+            .set_inner(ContributorParams::new(
+                [1; 32], [2; 32], 254, [3; 32], [4; 32],
+            ));
+
+        let key = |offset: usize| &data[offset..offset + 32];
+
+        assert_eq!(key(contributor::FUNDRAISE), [1; 32]);
+        assert_eq!(key(contributor::AUTHORITY), [2; 32]);
+        assert_eq!(key(contributor::RENT_PAYER), [3; 32]);
+        assert_eq!(key(contributor::MINT), [4; 32]);
+        assert_eq!(data.len(), contributor::DATA_SIZE);
     }
 }
@@ -1,7 +1,13 @@
-use pinocchio::{ProgramResult, pubkey::Pubkey};
+use pinocchio::{ProgramResult, program_error::ProgramError, pubkey::Pubkey};
 
-use crate::{AccountLoad, Prefix, SetInner, Space};
-use core::mem::size_of;
+use crate::{
+    AccountLoad, FundraiserError, HOOK_MAX_ACCOUNTS, MAX_BPS, MAX_NAME_LEN, MAX_TIERS, MAX_URI_LEN,
+    Prefix, SetInner, Space,
+};
+use core::mem::{offset_of, size_of};
+
+#[cfg(any(test, feature = "client"))]
+use crate::idl::field;
 
 #[repr(C)]
 pub struct Fundraise {
@@ -12,6 +18,134 @@ pub struct Fundraise {
     time_started: [u8; 8],
     duration: [u8; 8],
     pub bump: u8,
+    pub hook_program: Pubkey,
+    pub hook_accounts: [Pubkey; HOOK_MAX_ACCOUNTS],
+    pub hook_accounts_len: u8,
+    pub hook_writable: u8,
+    pending_hooks: [u8; 8],
+    completion_window: [u8; 8],
+    completion_threshold_bps: [u8; 2],
+    maker_completion: [u8; 8],
+    pub mint_decimals: u8,
+    max_contribution_bps: [u8; 2],
+    min_contribution: [u8; 8],
+    pub allowlist: u8,
+    pub token_program: Pubkey,
+    pub vault: Pubkey,
+    donated_amount: [u8; 8],
+    pub claimed: u8,
+    pub extensions_used: u8,
+    pub refund_policy: u8,
+    pub campaign_type: u8,
+    pub merkle_root: Pubkey,
+    pub registered: u8,
+    /// Zero-padded UTF-8, set at Initialize and by UpdateMetadata.
+    pub name: [u8; MAX_NAME_LEN],
+    pub uri: [u8; MAX_URI_LEN],
+    /// Set by the maker to hold off contributions without stopping the clock.
+    pub paused: u8,
+    /// Ascending contribution totals at which each reward tier starts, with unused tiers 0.
+    tier_thresholds: [[u8; 8]; MAX_TIERS],
+    /// Lamports the maker staked at Initialize on top of rent, returned once the campaign
+    /// settles.
+    bond: [u8; 8],
+    /// Set by the maker's Cancel, after which the fundraise only refunds.
+    pub cancelled: u8,
+    /// Mint contributors are paid rewards in, or all zeroes for a fundraise without rewards.
+    pub reward_mint: Pubkey,
+    /// Rewards shared between contributors pro rata once the maker claims.
+    reward_amount: [u8; 8],
+    /// Contributions the shares are counted against, recorded by the maker's claim.
+    reward_basis: [u8; 8],
+    /// Contributions whose share has been claimed.
+    reward_claimed: [u8; 8],
+    /// Non-transferable Token-2022 mint of the receipts issued 1:1 with contributions, or all
+    /// zeroes for a fundraise without receipts.
+    pub receipt_mint: Pubkey,
+    /// Sponsor of the matching pool, or all zeroes for a fundraise nobody has matched.
+    pub match_sponsor: Pubkey,
+    /// Tokens held in the match vault, paid out at the maker's claim up to what contributors
+    /// put in, with the rest back to the sponsor.
+    match_pool: [u8; 8],
+    /// Mint a contributor must hold at least one token of to contribute, or all zeroes for an
+    /// ungated fundraise.
+    pub gate_mint: Pubkey,
+    /// Sum of the contributors' snapshot amounts, for weighing governance power once the
+    /// campaign succeeds.
+    snapshot_total: [u8; 8],
+    /// Set when the maker accepted a mint with a freeze authority, which could freeze the vault,
+    /// so UIs can warn backers.
+    pub accept_freezable: u8,
+    /// Signs the claim and receives its payout, the maker unless they handed it to another key.
+    pub claim_authority: Pubkey,
+    /// Last timestamp, inclusive, at which the fundraise is still running, fixed at Initialize
+    /// and moved only by Extend and Cancel.
+    end_time: [u8; 8],
+    /// Rewards paid out of the reward vault, to contributors or swept back to the maker.
+    reward_paid: [u8; 8],
+}
+
+/// What a signed transfer out of one of the fundraise's token accounts is paid from. Each is
+/// accounted for apart from the others, so no payout can draw on tokens recorded for another.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Liability {
+    /// Contributions, donations and the maker's completion deposit, held in the vault.
+    Raised,
+    /// Whatever the vault or reward vault holds beyond what is recorded against it, which was
+    /// sent there directly.
+    Surplus,
+    /// The rewards declared at Initialize, held in the reward vault.
+    Rewards,
+    /// The sponsor's matching pool, held in the match vault.
+    Match,
+    /// Tokens of a mint the fundraise records nothing of.
+    Foreign,
+}
+
+/// When contributors may pull their contributions back out, fixed at Initialize.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RefundPolicy {
+    /// Refunds are open while the fundraise is running and short of its target.
+    Flexible = 0,
+    /// Contributions are locked in until the fundraise has ended short of its target.
+    Locked = 1,
+}
+
+impl TryFrom<u8> for RefundPolicy {
+    type Error = ProgramError;
+
+    fn try_from(policy: u8) -> Result<Self, Self::Error> {
+        match policy {
+            0 => Ok(Self::Flexible),
+            1 => Ok(Self::Locked),
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
+}
+
+/// What the maker can claim once the fundraise ends, fixed at Initialize.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CampaignType {
+    /// The maker claims only if the target is reached, and contributions are refundable
+    /// otherwise.
+    AllOrNothing = 0,
+    /// The maker claims whatever the vault holds once the fundraise has ended, target or not.
+    /// Contributions are only refundable before the deadline.
+    KeepItAll = 1,
+}
+
+impl TryFrom<u8> for CampaignType {
+    type Error = ProgramError;
+
+    fn try_from(campaign_type: u8) -> Result<Self, Self::Error> {
+        match campaign_type {
+            0 => Ok(Self::AllOrNothing),
+            1 => Ok(Self::KeepItAll),
+            _ => Err(ProgramError::InvalidInstructionData),
+        }
+    }
 }
 
 impl Prefix for Fundraise {
@@ -24,6 +158,94 @@ impl Space for Fundraise {
 
 impl AccountLoad for Fundraise {}
 
+// The published filter offsets must track the layout, so moving a field fails the build.
+const _: () = {
+    use crate::filters::fundraise;
+
+    assert!(fundraise::DATA_SIZE == Fundraise::LEN);
+    assert!(fundraise::MAKER == offset_of!(Fundraise, maker));
+    assert!(fundraise::MINT_TO_RAISE == offset_of!(Fundraise, mint_to_raise));
+    assert!(fundraise::AMOUNT_TO_RAISE == offset_of!(Fundraise, amount_to_raise));
+    assert!(fundraise::CURRENT_AMOUNT == offset_of!(Fundraise, current_amount));
+    assert!(fundraise::TIME_STARTED == offset_of!(Fundraise, time_started));
+    assert!(fundraise::DURATION == offset_of!(Fundraise, duration));
+    assert!(fundraise::HOOK_PROGRAM == offset_of!(Fundraise, hook_program));
+    assert!(fundraise::ALLOWLIST == offset_of!(Fundraise, allowlist));
+    assert!(fundraise::TOKEN_PROGRAM == offset_of!(Fundraise, token_program));
+    assert!(fundraise::VAULT == offset_of!(Fundraise, vault));
+    assert!(fundraise::CLAIMED == offset_of!(Fundraise, claimed));
+    assert!(fundraise::REFUND_POLICY == offset_of!(Fundraise, refund_policy));
+    assert!(fundraise::CAMPAIGN_TYPE == offset_of!(Fundraise, campaign_type));
+    assert!(fundraise::MERKLE_ROOT == offset_of!(Fundraise, merkle_root));
+    assert!(fundraise::REGISTERED == offset_of!(Fundraise, registered));
+    assert!(fundraise::NAME == offset_of!(Fundraise, name));
+    assert!(fundraise::URI == offset_of!(Fundraise, uri));
+    assert!(fundraise::PAUSED == offset_of!(Fundraise, paused));
+    assert!(fundraise::TIER_THRESHOLDS == offset_of!(Fundraise, tier_thresholds));
+    assert!(fundraise::BOND == offset_of!(Fundraise, bond));
+    assert!(fundraise::CANCELLED == offset_of!(Fundraise, cancelled));
+    assert!(fundraise::REWARD_MINT == offset_of!(Fundraise, reward_mint));
+    assert!(fundraise::RECEIPT_MINT == offset_of!(Fundraise, receipt_mint));
+    assert!(fundraise::MATCH_SPONSOR == offset_of!(Fundraise, match_sponsor));
+    assert!(fundraise::GATE_MINT == offset_of!(Fundraise, gate_mint));
+    assert!(fundraise::CLAIM_AUTHORITY == offset_of!(Fundraise, claim_authority));
+    assert!(fundraise::END_TIME == offset_of!(Fundraise, end_time));
+    assert!(fundraise::REWARD_PAID == offset_of!(Fundraise, reward_paid));
+};
+
+#[cfg(any(test, feature = "client"))]
+impl Fundraise {
+    pub const IDL_FIELDS: &'static [crate::idl::IdlField] = &[
+        field!(Fundraise, maker, "pubkey"),
+        field!(Fundraise, mint_to_raise, "pubkey"),
+        field!(Fundraise, amount_to_raise, "u64"),
+        field!(Fundraise, current_amount, "u64"),
+        field!(Fundraise, time_started, "i64"),
+        field!(Fundraise, duration, "u64"),
+        field!(Fundraise, bump, "u8"),
+        field!(Fundraise, hook_program, "pubkey"),
+        field!(Fundraise, hook_accounts, "[pubkey; 4]"),
+        field!(Fundraise, hook_accounts_len, "u8"),
+        field!(Fundraise, hook_writable, "u8"),
+        field!(Fundraise, pending_hooks, "u64"),
+        field!(Fundraise, completion_window, "u64"),
+        field!(Fundraise, completion_threshold_bps, "u16"),
+        field!(Fundraise, maker_completion, "u64"),
+        field!(Fundraise, mint_decimals, "u8"),
+        field!(Fundraise, max_contribution_bps, "u16"),
+        field!(Fundraise, min_contribution, "u64"),
+        field!(Fundraise, allowlist, "bool"),
+        field!(Fundraise, token_program, "pubkey"),
+        field!(Fundraise, vault, "pubkey"),
+        field!(Fundraise, donated_amount, "u64"),
+        field!(Fundraise, claimed, "bool"),
+        field!(Fundraise, extensions_used, "u8"),
+        field!(Fundraise, refund_policy, "u8"),
+        field!(Fundraise, campaign_type, "u8"),
+        field!(Fundraise, merkle_root, "[u8; 32]"),
+        field!(Fundraise, registered, "bool"),
+        field!(Fundraise, name, "[u8; 32]"),
+        field!(Fundraise, uri, "[u8; 128]"),
+        field!(Fundraise, paused, "bool"),
+        field!(Fundraise, tier_thresholds, "[u64; 4]"),
+        field!(Fundraise, bond, "u64"),
+        field!(Fundraise, cancelled, "bool"),
+        field!(Fundraise, reward_mint, "pubkey"),
+        field!(Fundraise, reward_amount, "u64"),
+        field!(Fundraise, reward_basis, "u64"),
+        field!(Fundraise, reward_claimed, "u64"),
+        field!(Fundraise, receipt_mint, "pubkey"),
+        field!(Fundraise, match_sponsor, "pubkey"),
+        field!(Fundraise, match_pool, "u64"),
+        field!(Fundraise, gate_mint, "pubkey"),
+        field!(Fundraise, snapshot_total, "u64"),
+        field!(Fundraise, accept_freezable, "bool"),
+        field!(Fundraise, claim_authority, "pubkey"),
+        field!(Fundraise, end_time, "i64"),
+        field!(Fundraise, reward_paid, "u64"),
+    ];
+}
+
 impl Fundraise {
     #[inline(always)]
     pub fn get_amount_to_raise(&self) -> u64 {
@@ -45,6 +267,271 @@ impl Fundraise {
         u64::from_le_bytes(self.duration)
     }
 
+    /// Last timestamp, inclusive, at which the fundraise is still running.
+    #[inline(always)]
+    pub fn get_end_time(&self) -> i64 {
+        i64::from_le_bytes(self.end_time)
+    }
+
+    #[inline(always)]
+    pub fn get_pending_hooks(&self) -> u64 {
+        u64::from_le_bytes(self.pending_hooks)
+    }
+
+    #[inline(always)]
+    pub fn get_completion_window(&self) -> u64 {
+        u64::from_le_bytes(self.completion_window)
+    }
+
+    #[inline(always)]
+    pub fn get_completion_threshold_bps(&self) -> u16 {
+        u16::from_le_bytes(self.completion_threshold_bps)
+    }
+
+    #[inline(always)]
+    pub fn get_maker_completion(&self) -> u64 {
+        u64::from_le_bytes(self.maker_completion)
+    }
+
+    #[inline(always)]
+    pub fn get_donated_amount(&self) -> u64 {
+        u64::from_le_bytes(self.donated_amount)
+    }
+
+    #[inline(always)]
+    pub fn get_max_contribution_bps(&self) -> u16 {
+        u16::from_le_bytes(self.max_contribution_bps)
+    }
+
+    #[inline(always)]
+    pub fn get_min_contribution(&self) -> u64 {
+        u64::from_le_bytes(self.min_contribution)
+    }
+
+    /// Largest total a single contributor may give, with a stored cap of 0 meaning unlimited.
+    #[inline(always)]
+    pub fn max_contribution(&self) -> u64 {
+        crate::max_contribution(self.get_amount_to_raise(), self.get_max_contribution_bps())
+    }
+
+    #[inline(always)]
+    pub fn has_hook(&self) -> bool {
+        self.hook_program != Pubkey::default()
+    }
+
+    /// Whether contributions require a claimed allowlist slot.
+    #[inline(always)]
+    pub fn has_allowlist(&self) -> bool {
+        self.allowlist == 1
+    }
+
+    /// Whether contributions require a Merkle proof against `merkle_root`.
+    #[inline(always)]
+    pub fn has_merkle_root(&self) -> bool {
+        self.merkle_root != [0; 32]
+    }
+
+    #[inline(always)]
+    pub fn refund_policy(&self) -> RefundPolicy {
+        match self.refund_policy {
+            1 => RefundPolicy::Locked,
+            _ => RefundPolicy::Flexible,
+        }
+    }
+
+    #[inline(always)]
+    pub fn campaign_type(&self) -> CampaignType {
+        match self.campaign_type {
+            1 => CampaignType::KeepItAll,
+            _ => CampaignType::AllOrNothing,
+        }
+    }
+
+    /// Whether the fundraise is counted in the registry of its mint.
+    #[inline(always)]
+    pub fn is_registered(&self) -> bool {
+        self.registered == 1
+    }
+
+    /// Whether the maker has claimed the raised funds.
+    #[inline(always)]
+    pub fn is_claimed(&self) -> bool {
+        self.claimed == 1
+    }
+
+    #[inline(always)]
+    pub fn get_tier_thresholds(&self) -> [u64; MAX_TIERS] {
+        self.tier_thresholds.map(u64::from_le_bytes)
+    }
+
+    #[inline(always)]
+    pub fn set_tier_thresholds(&mut self, thresholds: [u64; MAX_TIERS]) {
+        self.tier_thresholds = thresholds.map(u64::to_le_bytes);
+    }
+
+    /// The reward tier a contributor with `amount` in total has reached, from 0 for none up to
+    /// the number of tiers defined.
+    #[inline(always)]
+    pub fn tier(&self, amount: u64) -> u8 {
+        self.get_tier_thresholds()
+            .iter()
+            .filter(|&&threshold| threshold != 0 && amount >= threshold)
+            .count() as u8
+    }
+
+    /// Whether the maker has paused contributions.
+    #[inline(always)]
+    pub fn is_paused(&self) -> bool {
+        self.paused == 1
+    }
+
+    #[inline(always)]
+    pub fn get_bond(&self) -> u64 {
+        u64::from_le_bytes(self.bond)
+    }
+
+    #[inline(always)]
+    pub fn set_bond(&mut self, bond: u64) {
+        self.bond = bond.to_le_bytes();
+    }
+
+    /// Whether the maker has cancelled the fundraise.
+    #[inline(always)]
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled == 1
+    }
+
+    /// Whether contributors are paid rewards.
+    #[inline(always)]
+    pub fn has_rewards(&self) -> bool {
+        self.reward_mint != Pubkey::default()
+    }
+
+    #[inline(always)]
+    pub fn get_reward_amount(&self) -> u64 {
+        u64::from_le_bytes(self.reward_amount)
+    }
+
+    #[inline(always)]
+    pub fn get_reward_basis(&self) -> u64 {
+        u64::from_le_bytes(self.reward_basis)
+    }
+
+    #[inline(always)]
+    pub fn get_reward_claimed(&self) -> u64 {
+        u64::from_le_bytes(self.reward_claimed)
+    }
+
+    #[inline(always)]
+    pub fn set_rewards(&mut self, mint: Pubkey, amount: u64) {
+        self.reward_mint = mint;
+        self.reward_amount = amount.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn set_reward_basis(&mut self, basis: u64) {
+        self.reward_basis = basis.to_le_bytes();
+    }
+
+    /// The reward owed on a contribution of `amount`. Shares are counted against the target,
+    /// or against everything contributed if that overshot it, so they never add up to more
+    /// than the rewards.
+    #[inline(always)]
+    pub fn reward_for(&self, amount: u64) -> u64 {
+        crate::reward_share(
+            amount,
+            self.get_reward_amount(),
+            self.get_amount_to_raise().max(self.get_reward_basis()),
+        )
+    }
+
+    /// Records the reward on a contribution of `amount` as claimed.
+    #[inline(always)]
+    pub fn record_reward_claimed(&mut self, amount: u64) -> ProgramResult {
+        let reward_claimed = self
+            .get_reward_claimed()
+            .checked_add(amount)
+            .ok_or(FundraiserError::MathOverflow)?;
+
+        self.reward_claimed = reward_claimed.to_le_bytes();
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn get_reward_paid(&self) -> u64 {
+        u64::from_le_bytes(self.reward_paid)
+    }
+
+    #[inline(always)]
+    pub fn set_reward_paid(&mut self, amount: u64) {
+        self.reward_paid = amount.to_le_bytes();
+    }
+
+    /// Rewards the reward vault still owes out of those declared at Initialize.
+    #[inline(always)]
+    pub fn outstanding_rewards(&self) -> u64 {
+        self.get_reward_amount()
+            .saturating_sub(self.get_reward_paid())
+    }
+
+    /// Whether every contribution's reward has been claimed.
+    #[inline(always)]
+    pub fn rewards_settled(&self) -> bool {
+        self.get_reward_claimed() >= self.get_reward_basis()
+    }
+
+    /// Whether contributors are issued receipts.
+    #[inline(always)]
+    pub fn has_receipt(&self) -> bool {
+        self.receipt_mint != Pubkey::default()
+    }
+
+    /// Whether a sponsor has matched the fundraise.
+    #[inline(always)]
+    pub fn has_match(&self) -> bool {
+        self.match_sponsor != Pubkey::default()
+    }
+
+    /// Whether contributing requires holding a token of the gate mint.
+    #[inline(always)]
+    pub fn has_gate(&self) -> bool {
+        self.gate_mint != Pubkey::default()
+    }
+
+    /// Whether the maker accepted a mint whose freeze authority could freeze the vault.
+    #[inline(always)]
+    pub fn accepts_freezable(&self) -> bool {
+        self.accept_freezable == 1
+    }
+
+    #[inline(always)]
+    pub fn get_match_pool(&self) -> u64 {
+        u64::from_le_bytes(self.match_pool)
+    }
+
+    #[inline(always)]
+    pub fn set_match_pool(&mut self, amount: u64) {
+        self.match_pool = amount.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn get_snapshot_total(&self) -> u64 {
+        u64::from_le_bytes(self.snapshot_total)
+    }
+
+    #[inline(always)]
+    pub fn set_snapshot_total(&mut self, amount: u64) {
+        self.snapshot_total = amount.to_le_bytes();
+    }
+
+    /// The match paid to the maker at their claim: the contributions, one for one, capped by
+    /// the pool.
+    #[inline(always)]
+    pub fn match_payout(&self) -> u64 {
+        self.get_current_amount().min(self.get_match_pool())
+    }
+
     #[inline(always)]
     pub fn set_amount_to_raise(&mut self, amount: u64) {
         self.amount_to_raise = amount.to_le_bytes();
@@ -65,6 +552,62 @@ impl Fundraise {
         self.duration = duration.to_le_bytes();
     }
 
+    #[inline(always)]
+    pub fn set_end_time(&mut self, end_time: i64) {
+        self.end_time = end_time.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn set_pending_hooks(&mut self, pending_hooks: u64) {
+        self.pending_hooks = pending_hooks.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn set_completion(&mut self, window: u64, threshold_bps: u16) {
+        self.completion_window = window.to_le_bytes();
+        self.completion_threshold_bps = threshold_bps.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn set_maker_completion(&mut self, amount: u64) {
+        self.maker_completion = amount.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn set_donated_amount(&mut self, amount: u64) {
+        self.donated_amount = amount.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn set_max_contribution_bps(&mut self, bps: u16) {
+        self.max_contribution_bps = bps.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn set_min_contribution(&mut self, amount: u64) {
+        self.min_contribution = amount.to_le_bytes();
+    }
+
+    #[inline(always)]
+    pub fn set_hook(&mut self, program: Pubkey, accounts: &[(Pubkey, bool)]) {
+        self.hook_program = program;
+        self.hook_accounts_len = accounts.len() as u8;
+        self.hook_writable = 0;
+
+        for (i, (key, is_writable)) in accounts.iter().enumerate() {
+            self.hook_accounts[i] = *key;
+            self.hook_writable |= u8::from(*is_writable) << i;
+        }
+    }
+
+    #[inline(always)]
+    pub fn hook_template(&self) -> impl Iterator<Item = (&Pubkey, bool)> {
+        self.hook_accounts[..self.hook_accounts_len as usize]
+            .iter()
+            .enumerate()
+            .map(|(i, key)| (key, self.hook_writable & (1 << i) != 0))
+    }
+
     #[inline(always)]
     pub fn set_inner(
         &mut self,
@@ -81,19 +624,211 @@ impl Fundraise {
         self.set_current_amount(0);
         self.set_time_started(time_started);
         self.set_duration(duration);
+        self.set_end_time(0);
         self.bump = bump;
+        self.set_hook(Pubkey::default(), &[]);
+        self.set_pending_hooks(0);
+        self.set_completion(0, 0);
+        self.set_maker_completion(0);
+        self.mint_decimals = 0;
+        self.set_max_contribution_bps(0);
+        self.set_min_contribution(0);
+        self.allowlist = 0;
+        self.token_program = Pubkey::default();
+        self.vault = Pubkey::default();
+        self.set_donated_amount(0);
+        self.claimed = 0;
+        self.extensions_used = 0;
+        self.refund_policy = RefundPolicy::Flexible as u8;
+        self.campaign_type = CampaignType::AllOrNothing as u8;
+        self.merkle_root = [0; 32];
+        self.registered = 0;
+        self.name = [0; MAX_NAME_LEN];
+        self.uri = [0; MAX_URI_LEN];
+        self.paused = 0;
+        self.tier_thresholds = [[0; 8]; MAX_TIERS];
+        self.set_bond(0);
+        self.cancelled = 0;
+        self.set_rewards(Pubkey::default(), 0);
+        self.set_reward_basis(0);
+        self.reward_claimed = [0; 8];
+        self.receipt_mint = Pubkey::default();
+        self.match_sponsor = Pubkey::default();
+        self.set_match_pool(0);
+        self.gate_mint = Pubkey::default();
+        self.set_snapshot_total(0);
+        self.accept_freezable = 0;
+        self.claim_authority = maker;
+        self.set_reward_paid(0);
+    }
+
+    /// Returns the shortfall the maker may deposit to complete the fundraise at `now`, which
+    /// requires the fundraise to be inside its final `completion_window` seconds and to have
+    /// raised at least `completion_threshold_bps` of its target organically. Donations count
+    /// towards the shortfall but not the threshold.
+    #[inline(always)]
+    pub fn completion_shortfall(&self, now: i64) -> Result<u64, ProgramError> {
+        let window = self.get_completion_window();
+        let end = self.get_end_time();
+
+        if now > end {
+            return Err(FundraiserError::FundraiserEnded.into());
+        }
+
+        let amount_to_raise = self.get_amount_to_raise();
+        let current_amount = self.get_current_amount();
+        let raised = current_amount.saturating_add(self.get_donated_amount());
+
+        if raised >= amount_to_raise || self.get_maker_completion() > 0 {
+            return Err(FundraiserError::TargetMet.into());
+        }
+
+        if window == 0
+            || now < end.saturating_sub_unsigned(window)
+            || u128::from(current_amount) * u128::from(MAX_BPS)
+                < u128::from(amount_to_raise) * u128::from(self.get_completion_threshold_bps())
+        {
+            return Err(FundraiserError::MakerCompletionNotEligible.into());
+        }
+
+        Ok(amount_to_raise - raised)
+    }
+
+    /// Tokens the vault owes out: organic contributions, donations and the maker's completion
+    /// deposit. Anything the vault holds beyond this was never recorded and is never signed out.
+    #[inline(always)]
+    pub fn outstanding_liability(&self) -> u64 {
+        self.get_current_amount()
+            .saturating_add(self.get_donated_amount())
+            .saturating_add(self.get_maker_completion())
+    }
+
+    /// Records `amount` leaving the vault, drawing down contributions, then donations, then the
+    /// maker's completion deposit, and fails if it exceeds the outstanding liability.
+    #[inline(always)]
+    pub fn debit_liability(&mut self, amount: u64) -> ProgramResult {
+        if amount > self.outstanding_liability() {
+            return Err(FundraiserError::TransferExceedsLiability.into());
+        }
+
+        let current_amount = self.get_current_amount();
+        let from_contributions = amount.min(current_amount);
+        let remaining = amount - from_contributions;
+
+        let donated_amount = self.get_donated_amount();
+        let from_donations = remaining.min(donated_amount);
+
+        self.set_current_amount(current_amount - from_contributions);
+        self.set_donated_amount(donated_amount - from_donations);
+        self.set_maker_completion(self.get_maker_completion() - (remaining - from_donations));
+
+        Ok(())
+    }
+
+    /// Records `amount` leaving `from`, a token account of `mint` holding `balance`, against
+    /// `liability`. Fails with `TransferExceedsLiability` if `liability` covers less than
+    /// `amount`, or is not held in `mint`.
+    pub fn debit(
+        &mut self,
+        liability: Liability,
+        from: &Pubkey,
+        mint: &Pubkey,
+        balance: u64,
+        amount: u64,
+    ) -> ProgramResult {
+        let is_vault = from == &self.vault && mint == &self.mint_to_raise;
+        let is_reward_vault = self.has_rewards() && mint == &self.reward_mint;
+
+        let available = match liability {
+            Liability::Raised if is_vault => self.outstanding_liability(),
+            Liability::Surplus if is_vault => balance.saturating_sub(self.outstanding_liability()),
+            Liability::Surplus if is_reward_vault => {
+                balance.saturating_sub(self.outstanding_rewards())
+            }
+            Liability::Rewards if is_reward_vault => self.outstanding_rewards(),
+            Liability::Match if mint == &self.mint_to_raise && !is_vault => self.get_match_pool(),
+            Liability::Foreign if mint != &self.mint_to_raise && !is_reward_vault => balance,
+            _ => 0,
+        };
+
+        if amount > available {
+            return Err(FundraiserError::TransferExceedsLiability.into());
+        }
+
+        match liability {
+            Liability::Raised => self.debit_liability(amount)?,
+            Liability::Rewards => self.set_reward_paid(self.get_reward_paid() + amount),
+            Liability::Match => self.set_match_pool(self.get_match_pool() - amount),
+            Liability::Surplus | Liability::Foreign => {}
+        }
+
+        Ok(())
+    }
+
+    /// The fundraise's name, without its zero padding.
+    #[inline(always)]
+    pub fn name(&self) -> &[u8] {
+        trim_padding(&self.name)
+    }
+
+    /// The URI of the fundraise's off-chain metadata, without its zero padding.
+    #[inline(always)]
+    pub fn uri(&self) -> &[u8] {
+        trim_padding(&self.uri)
+    }
+
+    /// Replaces the name and URI, which the instruction parsers have already bounded.
+    #[inline(always)]
+    pub fn set_metadata(&mut self, name: &[u8], uri: &[u8]) {
+        self.name = [0; MAX_NAME_LEN];
+        self.name[..name.len()].copy_from_slice(name);
+        self.uri = [0; MAX_URI_LEN];
+        self.uri[..uri.len()].copy_from_slice(uri);
     }
 
     #[inline(always)]
     pub fn check_mint_to_raise(&self, mint: &Pubkey) -> ProgramResult {
         if &self.mint_to_raise != mint {
-            return Err(crate::FundraiserError::InvalidMintToRaise.into());
+            return Err(FundraiserError::InvalidMintToRaise.into());
+        }
+
+        Ok(())
+    }
+
+    /// Checks the token program passed in is the one recorded for the mint at initialization.
+    #[inline(always)]
+    pub fn check_token_program(&self, token_program: &Pubkey) -> ProgramResult {
+        if &self.token_program != token_program {
+            return Err(FundraiserError::InvalidTokenProgram.into());
+        }
+
+        Ok(())
+    }
+
+    /// Checks `vault` is the token account recorded as holding the fundraise's tokens, the
+    /// `[VAULT_SEED, fundraise]` program address. Fundraises created before the vault was
+    /// recorded have a shorter layout that [`Fundraise::load`] rejects, so they must be migrated
+    /// to the current layout before this build accepts them.
+    #[inline(always)]
+    pub fn check_vault(&self, vault: &Pubkey) -> ProgramResult {
+        if &self.vault != vault {
+            return Err(FundraiserError::InvalidAddress.into());
         }
 
         Ok(())
     }
 }
 
+#[inline(always)]
+fn trim_padding(bytes: &[u8]) -> &[u8] {
+    let len = bytes
+        .iter()
+        .rposition(|&byte| byte != 0)
+        .map_or(0, |i| i + 1);
+
+    &bytes[..len]
+}
+
 pub struct FundraiseParams {
     pub maker: Pubkey,
     pub mint_to_raise: Pubkey,
@@ -126,13 +861,78 @@ impl FundraiseParams {
 impl SetInner for Fundraise {
     type Params = FundraiseParams;
 
+    #[inline(always)]
     fn set_inner(&mut self, params: Self::Params) {
-        self.maker = params.maker;
-        self.mint_to_raise = params.mint_to_raise;
-        self.set_amount_to_raise(params.amount_to_raise);
-        self.set_current_amount(0);
-        self.set_time_started(params.time_started);
-        self.set_duration(params.duration);
-        self.bump = params.bump;
+        Fundraise::set_inner(
+            self,
+            params.maker,
+            params.mint_to_raise,
+            params.amount_to_raise,
+            params.time_started,
+            params.duration,
+            params.bump,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AccountLoad, Fundraise, Space, filters::fundraise};
+
+    #[test]
+    fn filters_match_layout() {
+        let mut data = [0u8; Fundraise::LEN];
+        let fundraise = Fundraise::load_mut(&mut data).unwrap();
+        fundraise.maker = [1; 32];
+        fundraise.mint_to_raise = [2; 32];
+        fundraise.set_amount_to_raise(5_000_000);
+
+        let at = |offset: usize, len: usize| &data[offset..offset + len];
+
+        assert_eq!(at(fundraise::MAKER, 32), [1; 32]);
+        assert_eq!(at(fundraise::MINT_TO_RAISE, 32), [2; 32]);
+        assert_eq!(
+            at(fundraise::AMOUNT_TO_RAISE, 8),
+            5_000_000u64.to_le_bytes()
+        );
+        assert_eq!(data.len(), fundraise::DATA_SIZE);
+    }
+
+    #[test]
+    fn reward_shares_count_against_overshoot() {
+        let mut data = [0u8; Fundraise::LEN];
+        let fundraise = Fundraise::load_mut(&mut data).unwrap();
+        fundraise.set_amount_to_raise(1_000);
+        fundraise.set_rewards([1; 32], 500);
+
+        // short of the target, a share counts against the target
+        assert_eq!(fundraise.reward_for(100), 50);
+
+        // past it, against everything raised, so the shares never exceed the deposit
+        fundraise.set_reward_basis(4_000);
+
+        assert_eq!(fundraise.reward_for(1_000), 125);
+        assert_eq!(fundraise.reward_for(4_000), 500);
+    }
+
+    #[test]
+    fn match_is_capped_by_pool() {
+        let mut data = [0u8; Fundraise::LEN];
+        let fundraise = Fundraise::load_mut(&mut data).unwrap();
+        fundraise.set_match_pool(3_000);
+
+        // one for one while contributions are below the pool
+        fundraise.set_current_amount(1_200);
+
+        assert_eq!(fundraise.match_payout(), 1_200);
+
+        // and never more than the pool holds
+        fundraise.set_current_amount(4_000);
+
+        assert_eq!(fundraise.match_payout(), 3_000);
+
+        fundraise.set_match_pool(0);
+
+        assert_eq!(fundraise.match_payout(), 0);
     }
 }
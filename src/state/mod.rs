@@ -1,12 +1,30 @@
-use std::mem::transmute;
+use core::mem::align_of;
 
 use pinocchio::program_error::ProgramError;
 
+use crate::FundraiserError;
+
+pub mod allowlist;
+pub mod config;
 pub mod contributor;
 pub mod fundraise;
+pub mod referral;
+pub mod registry;
+pub mod stats;
+pub mod status;
+pub mod trigger;
+pub mod updates;
 
+pub use allowlist::*;
+pub use config::*;
 pub use contributor::*;
 pub use fundraise::*;
+pub use referral::*;
+pub use registry::*;
+pub use stats::*;
+pub use status::*;
+pub use trigger::*;
+pub use updates::*;
 
 pub trait Prefix {
     const PREFIX: &'static [u8];
@@ -16,20 +34,99 @@ pub trait Space {
     const LEN: usize;
 }
 
+/// Zero-copy view of account data as `Self`. Account data carries no alignment guarantee, so
+/// implementors must be byte-aligned, holding only byte arrays and `Pubkey`s, which loading
+/// asserts at compile time.
 pub trait AccountLoad: Sized + Space {
+    /// Fails the build of any `load` or `load_mut` of an implementor that is not byte-aligned.
+    const ALIGNED: () = assert!(
+        align_of::<Self>() == 1,
+        "account types must be byte-aligned"
+    );
+
     #[inline(always)]
     fn load(bytes: &[u8]) -> Result<&Self, ProgramError> {
+        let () = Self::ALIGNED;
+
         if bytes.len() != Self::LEN {
-            return Err(ProgramError::InvalidAccountData);
+            return Err(FundraiserError::InvalidAccountDataLength.into());
         }
-        Ok(unsafe { &*transmute::<*const u8, *const Self>(bytes.as_ptr()) })
+        // SAFETY: `bytes` holds `Self::LEN` bytes, and `Self` is byte-aligned.
+        Ok(unsafe { &*bytes.as_ptr().cast::<Self>() })
     }
 
     #[inline(always)]
     fn load_mut(bytes: &mut [u8]) -> Result<&mut Self, ProgramError> {
+        let () = Self::ALIGNED;
+
         if bytes.len() != Self::LEN {
-            return Err(ProgramError::InvalidAccountData);
+            return Err(FundraiserError::InvalidAccountDataLength.into());
+        }
+        // SAFETY: `bytes` holds `Self::LEN` bytes, and `Self` is byte-aligned.
+        Ok(unsafe { &mut *bytes.as_mut_ptr().cast::<Self>() })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        AccountLoad, Contributor, ContributorParams, Fundraise, FundraiserError, SetInner, Space,
+    };
+
+    fn contributor(data: &mut [u8]) {
+        let contributor = Contributor::load_mut(data).unwrap();
+
+        contributor.set_inner(ContributorParams::new(
+            [1; 32], [2; 32], 254, [3; 32], [4; 32],
+        ));
+        contributor.set_amount(500_000);
+    }
+
+    #[test]
+    fn throw_if_data_short() {
+        let data = [0u8; Contributor::LEN];
+
+        assert_eq!(
+            Contributor::load(&data[..Contributor::LEN - 1]).err(),
+            Some(FundraiserError::InvalidAccountDataLength.into())
+        );
+        assert_eq!(
+            Fundraise::load(&[]).err(),
+            Some(FundraiserError::InvalidAccountDataLength.into())
+        );
+    }
+
+    #[test]
+    fn throw_if_data_long() {
+        let mut data = [0u8; Contributor::LEN + 1];
+
+        assert_eq!(
+            Contributor::load(&data).err(),
+            Some(FundraiserError::InvalidAccountDataLength.into())
+        );
+        assert_eq!(
+            Contributor::load_mut(&mut data).err(),
+            Some(FundraiserError::InvalidAccountDataLength.into())
+        );
+    }
+
+    #[test]
+    fn load_misaligned_data() {
+        let mut aligned = [0u8; Contributor::LEN];
+        contributor(&mut aligned);
+
+        // every offset of a buffer, whatever its own alignment, loads the same account
+        let mut buffer = [0u8; Contributor::LEN + 8];
+
+        for offset in 0..8 {
+            let data = &mut buffer[offset..offset + Contributor::LEN];
+            contributor(data);
+
+            let loaded = Contributor::load(data).unwrap();
+
+            assert_eq!(loaded.get_amount(), 500_000);
+            assert_eq!(loaded.authority, [2; 32]);
+            assert_eq!(data, &aligned[..]);
         }
-        Ok(unsafe { &mut *transmute::<*mut u8, *mut Self>(bytes.as_mut_ptr()) })
     }
 }
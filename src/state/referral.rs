@@ -0,0 +1,115 @@
+use pinocchio::{ProgramResult, pubkey::Pubkey};
+
+use crate::{AccountLoad, FundraiserError, Prefix, SetInner, Space};
+use core::mem::size_of;
+
+#[cfg(any(test, feature = "client"))]
+use crate::idl::field;
+
+/// Running total of the contributions a referrer brought to a fundraise, created by the first
+/// contribution naming the referrer. Refunds are not deducted.
+#[repr(C)]
+pub struct Referral {
+    pub fundraise: Pubkey,
+    pub referrer: Pubkey,
+    referred_amount: [u8; 8],
+    referred_count: [u8; 4],
+    pub bump: u8,
+}
+
+impl Prefix for Referral {
+    const PREFIX: &'static [u8] = b"referral";
+}
+
+impl Space for Referral {
+    const LEN: usize = size_of::<Self>();
+}
+
+impl AccountLoad for Referral {}
+
+#[cfg(any(test, feature = "client"))]
+impl Referral {
+    pub const IDL_FIELDS: &'static [crate::idl::IdlField] = &[
+        field!(Referral, fundraise, "pubkey"),
+        field!(Referral, referrer, "pubkey"),
+        field!(Referral, referred_amount, "u64"),
+        field!(Referral, referred_count, "u32"),
+        field!(Referral, bump, "u8"),
+    ];
+}
+
+impl Referral {
+    #[inline(always)]
+    pub fn get_referred_amount(&self) -> u64 {
+        u64::from_le_bytes(self.referred_amount)
+    }
+
+    #[inline(always)]
+    pub fn get_referred_count(&self) -> u32 {
+        u32::from_le_bytes(self.referred_count)
+    }
+
+    /// Credits the referrer with a contribution of `amount`.
+    #[inline(always)]
+    pub fn record_referred(&mut self, amount: u64) -> ProgramResult {
+        let referred_amount = self
+            .get_referred_amount()
+            .checked_add(amount)
+            .ok_or(FundraiserError::MathOverflow)?;
+
+        self.referred_amount = referred_amount.to_le_bytes();
+        self.referred_count = self.get_referred_count().saturating_add(1).to_le_bytes();
+
+        Ok(())
+    }
+}
+
+pub struct ReferralParams {
+    pub fundraise: Pubkey,
+    pub referrer: Pubkey,
+    pub bump: u8,
+}
+
+impl ReferralParams {
+    pub fn new(fundraise: Pubkey, referrer: Pubkey, bump: u8) -> Self {
+        Self {
+            fundraise,
+            referrer,
+            bump,
+        }
+    }
+}
+
+impl SetInner for Referral {
+    type Params = ReferralParams;
+
+    fn set_inner(&mut self, params: Self::Params) {
+        self.fundraise = params.fundraise;
+        self.referrer = params.referrer;
+        self.referred_amount = [0; 8];
+        self.referred_count = [0; 4];
+        self.bump = params.bump;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AccountLoad, FundraiserError, Referral, ReferralParams, SetInner, Space};
+
+    #[test]
+    fn totals_accumulate() {
+        let mut data = [0u8; Referral::LEN];
+        let referral = Referral::load_mut(&mut data).unwrap();
+
+        referral.set_inner(ReferralParams::new([1; 32], [2; 32], 255));
+        referral.record_referred(300_000).unwrap();
+        referral.record_referred(200_000).unwrap();
+
+        assert_eq!(referral.get_referred_amount(), 500_000);
+        assert_eq!(referral.get_referred_count(), 2);
+        assert_eq!(
+            referral.record_referred(u64::MAX),
+            Err(FundraiserError::MathOverflow.into())
+        );
+    }
+}
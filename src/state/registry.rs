@@ -0,0 +1,117 @@
+use pinocchio::{ProgramResult, pubkey::Pubkey};
+
+use crate::{AccountLoad, FundraiserError, Prefix, SetInner, Space};
+use core::mem::size_of;
+
+#[cfg(any(test, feature = "client"))]
+use crate::idl::field;
+
+/// Per-mint directory of fundraisers, so campaigns raising a mint can be found from its address
+/// alone. Fundraises opt in at Initialize and leave the count once claimed.
+#[repr(C)]
+pub struct Registry {
+    pub mint: Pubkey,
+    active_fundraisers: [u8; 8],
+    pub last_fundraise: Pubkey,
+    pub bump: u8,
+}
+
+impl Prefix for Registry {
+    const PREFIX: &'static [u8] = b"registry";
+}
+
+impl Space for Registry {
+    const LEN: usize = size_of::<Self>();
+}
+
+impl AccountLoad for Registry {}
+
+#[cfg(any(test, feature = "client"))]
+impl Registry {
+    pub const IDL_FIELDS: &'static [crate::idl::IdlField] = &[
+        field!(Registry, mint, "pubkey"),
+        field!(Registry, active_fundraisers, "u64"),
+        field!(Registry, last_fundraise, "pubkey"),
+        field!(Registry, bump, "u8"),
+    ];
+}
+
+impl Registry {
+    #[inline(always)]
+    pub fn get_active_fundraisers(&self) -> u64 {
+        u64::from_le_bytes(self.active_fundraisers)
+    }
+
+    #[inline(always)]
+    pub fn record_created(&mut self, fundraise: Pubkey) -> ProgramResult {
+        let active_fundraisers = self
+            .get_active_fundraisers()
+            .checked_add(1)
+            .ok_or(FundraiserError::MathOverflow)?;
+
+        self.active_fundraisers = active_fundraisers.to_le_bytes();
+        self.last_fundraise = fundraise;
+
+        Ok(())
+    }
+
+    #[inline(always)]
+    pub fn record_closed(&mut self) -> ProgramResult {
+        let active_fundraisers = self
+            .get_active_fundraisers()
+            .checked_sub(1)
+            .ok_or(FundraiserError::MathOverflow)?;
+
+        self.active_fundraisers = active_fundraisers.to_le_bytes();
+
+        Ok(())
+    }
+}
+
+pub struct RegistryParams {
+    pub mint: Pubkey,
+    pub bump: u8,
+}
+
+impl RegistryParams {
+    pub fn new(mint: Pubkey, bump: u8) -> Self {
+        Self { mint, bump }
+    }
+}
+
+impl SetInner for Registry {
+    type Params = RegistryParams;
+
+    fn set_inner(&mut self, params: Self::Params) {
+        self.mint = params.mint;
+        self.active_fundraisers = [0; 8];
+        self.last_fundraise = Pubkey::default();
+        self.bump = params.bump;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AccountLoad, FundraiserError, Registry, RegistryParams, SetInner, Space};
+
+    #[test]
+    fn count_follows_created_and_closed() {
+        let mut data = [0u8; Registry::LEN];
+        let registry = Registry::load_mut(&mut data).unwrap();
+
+        registry.set_inner(RegistryParams::new([1; 32], 255));
+        registry.record_created([2; 32]).unwrap();
+        registry.record_created([3; 32]).unwrap();
+        registry.record_closed().unwrap();
+
+        assert_eq!(registry.get_active_fundraisers(), 1);
+        assert_eq!(registry.last_fundraise, [3; 32]);
+
+        registry.record_closed().unwrap();
+
+        assert_eq!(
+            registry.record_closed(),
+            Err(FundraiserError::MathOverflow.into())
+        );
+    }
+}
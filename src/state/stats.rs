@@ -0,0 +1,187 @@
+use pinocchio::{ProgramResult, account_info::AccountInfo, instruction::Seed};
+
+use crate::{
+    AccountCheck, AccountLoad, FundraiserError, Prefix, ProgramAccount, STATS_ADDRESS, STATS_BUMP,
+    SetInner, Space, WritableAccount,
+};
+use core::mem::size_of;
+
+#[cfg(any(test, feature = "client"))]
+use crate::idl::field;
+
+/// Protocol-wide totals, kept in a singleton created by the first instruction passing it.
+#[repr(C)]
+pub struct Stats {
+    fundraisers_created: [u8; 8],
+    total_raised: [u8; 8],
+    total_refunded: [u8; 8],
+    fundraisers_claimed: [u8; 8],
+    total_claimed: [u8; 8],
+    pub bump: u8,
+}
+
+impl Prefix for Stats {
+    const PREFIX: &'static [u8] = b"stats";
+}
+
+impl Space for Stats {
+    const LEN: usize = size_of::<Self>();
+}
+
+impl AccountLoad for Stats {}
+
+#[cfg(any(test, feature = "client"))]
+impl Stats {
+    pub const IDL_FIELDS: &'static [crate::idl::IdlField] = &[
+        field!(Stats, fundraisers_created, "u64"),
+        field!(Stats, total_raised, "u64"),
+        field!(Stats, total_refunded, "u64"),
+        field!(Stats, fundraisers_claimed, "u64"),
+        field!(Stats, total_claimed, "u64"),
+        field!(Stats, bump, "u8"),
+    ];
+}
+
+#[inline(always)]
+fn checked_add(counter: &mut [u8; 8], amount: u64) -> ProgramResult {
+    let total = u64::from_le_bytes(*counter)
+        .checked_add(amount)
+        .ok_or(FundraiserError::MathOverflow)?;
+
+    *counter = total.to_le_bytes();
+
+    Ok(())
+}
+
+impl Stats {
+    #[inline(always)]
+    pub fn get_fundraisers_created(&self) -> u64 {
+        u64::from_le_bytes(self.fundraisers_created)
+    }
+
+    #[inline(always)]
+    pub fn get_total_raised(&self) -> u64 {
+        u64::from_le_bytes(self.total_raised)
+    }
+
+    #[inline(always)]
+    pub fn get_total_refunded(&self) -> u64 {
+        u64::from_le_bytes(self.total_refunded)
+    }
+
+    #[inline(always)]
+    pub fn get_fundraisers_claimed(&self) -> u64 {
+        u64::from_le_bytes(self.fundraisers_claimed)
+    }
+
+    #[inline(always)]
+    pub fn get_total_claimed(&self) -> u64 {
+        u64::from_le_bytes(self.total_claimed)
+    }
+
+    #[inline(always)]
+    pub fn record_fundraiser_created(&mut self) -> ProgramResult {
+        checked_add(&mut self.fundraisers_created, 1)
+    }
+
+    #[inline(always)]
+    pub fn record_contribution(&mut self, amount: u64) -> ProgramResult {
+        checked_add(&mut self.total_raised, amount)
+    }
+
+    #[inline(always)]
+    pub fn record_refund(&mut self, amount: u64) -> ProgramResult {
+        checked_add(&mut self.total_refunded, amount)
+    }
+
+    #[inline(always)]
+    pub fn record_claim(&mut self, amount: u64) -> ProgramResult {
+        checked_add(&mut self.fundraisers_claimed, 1)?;
+        checked_add(&mut self.total_claimed, amount)
+    }
+
+    /// Splits the stats account off the end of `accounts`, where it is optionally passed to the
+    /// instructions that update it.
+    #[inline(always)]
+    pub fn split_trailing(accounts: &[AccountInfo]) -> (Option<&AccountInfo>, &[AccountInfo]) {
+        match accounts.split_last() {
+            Some((stats, accounts)) if stats.key() == &STATS_ADDRESS => (Some(stats), accounts),
+            _ => (None, accounts),
+        }
+    }
+
+    /// Applies `update` to the stats account, first creating it at the expense of `payer` if
+    /// this is its first use. Its address was checked by [`Stats::split_trailing`].
+    pub fn update(
+        stats: &AccountInfo,
+        payer: &AccountInfo,
+        update: impl FnOnce(&mut Stats) -> ProgramResult,
+    ) -> ProgramResult {
+        WritableAccount::check(stats)?;
+
+        let bump = [STATS_BUMP];
+        let seeds = [Seed::from(Self::PREFIX), Seed::from(&bump)];
+
+        ProgramAccount::init_if_needed::<Self>(&seeds, stats, payer, StatsParams::new(STATS_BUMP))?;
+
+        let mut data = stats.try_borrow_mut_data()?;
+
+        update(Self::load_mut(data.as_mut())?)
+    }
+}
+
+pub struct StatsParams {
+    pub bump: u8,
+}
+
+impl StatsParams {
+    pub fn new(bump: u8) -> Self {
+        Self { bump }
+    }
+}
+
+impl SetInner for Stats {
+    type Params = StatsParams;
+
+    fn set_inner(&mut self, params: Self::Params) {
+        self.fundraisers_created = [0; 8];
+        self.total_raised = [0; 8];
+        self.total_refunded = [0; 8];
+        self.fundraisers_claimed = [0; 8];
+        self.total_claimed = [0; 8];
+        self.bump = params.bump;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_pubkey::Pubkey;
+
+    use crate::{
+        AccountLoad, FundraiserError, Prefix, STATS_ADDRESS, STATS_BUMP, SetInner, Space, Stats,
+        StatsParams, tests::constants::PROGRAM_ID,
+    };
+
+    #[test]
+    fn stats_address_matches_derivation() {
+        assert_eq!(
+            Pubkey::find_program_address(&[Stats::PREFIX], &PROGRAM_ID),
+            (Pubkey::new_from_array(STATS_ADDRESS), STATS_BUMP)
+        );
+    }
+
+    #[test]
+    fn throw_if_counter_overflows() {
+        let mut data = [0u8; Stats::LEN];
+        let stats = Stats::load_mut(&mut data).unwrap();
+
+        stats.set_inner(StatsParams::new(STATS_BUMP));
+        stats.record_contribution(u64::MAX).unwrap();
+
+        assert_eq!(
+            stats.record_contribution(1),
+            Err(FundraiserError::MathOverflow.into())
+        );
+        assert_eq!(stats.get_total_raised(), u64::MAX);
+    }
+}
@@ -0,0 +1,237 @@
+use crate::{CampaignType, Fundraise, MAX_BPS};
+
+/// Lifecycle of a fundraise, derived from the clock and the amount the program has recorded as
+/// raised so that every instruction and off-chain reader agrees on what a campaign currently
+/// allows. Tokens sent straight to the vault are never counted, so they cannot complete a
+/// campaign and lock its contributors out of their refunds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FundraiseStatus {
+    /// Running and below target. Contributions are accepted, as are refunds under the flexible
+    /// refund policy.
+    Active,
+    /// Still running, but the target has already been raised. The maker may claim early.
+    TargetMet,
+    /// Ended below target with nothing raised left, so nothing is left to settle.
+    Expired,
+    /// Ended with the target raised, or with anything at all raised by a keep-it-all fundraise.
+    /// Only the maker's claim remains.
+    Claimable,
+    /// Ended below target with contributions still sitting in the vault, which the locked
+    /// refund policy lets contributors take back. A cancelled fundraise with contributions left
+    /// is refundable whatever its target, type or policy.
+    Refundable,
+}
+
+impl Fundraise {
+    /// Amount still needed to reach the target, counting donations and any maker completion
+    /// deposit.
+    #[inline(always)]
+    pub fn remaining_amount(&self) -> u64 {
+        self.get_amount_to_raise()
+            .saturating_sub(self.outstanding_liability())
+    }
+
+    /// Share of the target raised so far, including donations and any maker completion deposit,
+    /// capped at `MAX_BPS`.
+    #[inline(always)]
+    pub fn progress_bps(&self) -> u16 {
+        let amount_to_raise = self.get_amount_to_raise();
+
+        if amount_to_raise == 0 {
+            return MAX_BPS;
+        }
+
+        let raised = u128::from(amount_to_raise - self.remaining_amount());
+
+        (raised * u128::from(MAX_BPS) / u128::from(amount_to_raise)) as u16
+    }
+
+    /// Where the fundraise stands at `now`, counting what it has raised by its
+    /// [`Fundraise::outstanding_liability`] rather than by the vault balance.
+    #[inline(always)]
+    pub fn status(&self, now: i64) -> FundraiseStatus {
+        let raised = self.outstanding_liability();
+
+        if self.is_cancelled() {
+            return match raised {
+                0 => FundraiseStatus::Expired,
+                _ => FundraiseStatus::Refundable,
+            };
+        }
+
+        let target_met = raised >= self.get_amount_to_raise();
+
+        match (now > self.get_end_time(), target_met) {
+            (false, false) => FundraiseStatus::Active,
+            (false, true) => FundraiseStatus::TargetMet,
+            (true, true) => FundraiseStatus::Claimable,
+            (true, false) if raised > 0 => match self.campaign_type() {
+                CampaignType::AllOrNothing => FundraiseStatus::Refundable,
+                CampaignType::KeepItAll => FundraiseStatus::Claimable,
+            },
+            (true, false) => FundraiseStatus::Expired,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AccountLoad, CampaignType, Fundraise, FundraiseStatus, MAX_BPS, Space};
+
+    const TIME_STARTED: i64 = 1_000;
+    const DURATION: u64 = 100;
+    const AMOUNT_TO_RAISE: u64 = 5_000;
+
+    fn fundraise() -> Vec<u8> {
+        let mut data = vec![0u8; Fundraise::LEN];
+        let fundraise = Fundraise::load_mut(&mut data).unwrap();
+
+        fundraise.set_inner(
+            [1u8; 32],
+            [2u8; 32],
+            AMOUNT_TO_RAISE,
+            TIME_STARTED,
+            DURATION,
+            255,
+        );
+        fundraise.set_end_time(TIME_STARTED + DURATION as i64);
+
+        data
+    }
+
+    fn status(fundraise: &mut Fundraise, now: i64, raised: u64) -> FundraiseStatus {
+        fundraise.set_current_amount(raised);
+
+        fundraise.status(now)
+    }
+
+    #[test]
+    fn deadline_is_stored_end_time() {
+        let mut data = fundraise();
+        let fundraise = Fundraise::load_mut(&mut data).unwrap();
+
+        // the duration is only kept for display
+        fundraise.set_duration(u64::MAX);
+
+        assert_eq!(fundraise.get_end_time(), TIME_STARTED + DURATION as i64);
+        assert_eq!(
+            status(fundraise, TIME_STARTED + DURATION as i64 + 1, 0),
+            FundraiseStatus::Expired
+        );
+
+        fundraise.set_end_time(i64::MAX);
+
+        assert_eq!(status(fundraise, i64::MAX, 0), FundraiseStatus::Active);
+    }
+
+    #[test]
+    fn status_at_clock_boundaries() {
+        let mut data = fundraise();
+        let fundraise = Fundraise::load_mut(&mut data).unwrap();
+        let end = fundraise.get_end_time();
+
+        assert_eq!(status(fundraise, TIME_STARTED, 0), FundraiseStatus::Active);
+        assert_eq!(
+            status(fundraise, end, AMOUNT_TO_RAISE - 1),
+            FundraiseStatus::Active
+        );
+        assert_eq!(
+            status(fundraise, end, AMOUNT_TO_RAISE),
+            FundraiseStatus::TargetMet
+        );
+        assert_eq!(
+            status(fundraise, end + 1, AMOUNT_TO_RAISE),
+            FundraiseStatus::Claimable
+        );
+        assert_eq!(
+            status(fundraise, end + 1, AMOUNT_TO_RAISE - 1),
+            FundraiseStatus::Refundable
+        );
+        assert_eq!(status(fundraise, end + 1, 0), FundraiseStatus::Expired);
+    }
+
+    #[test]
+    fn keep_it_all_is_claimable_below_target() {
+        let mut data = fundraise();
+        let fundraise = Fundraise::load_mut(&mut data).unwrap();
+        let end = fundraise.get_end_time();
+
+        fundraise.campaign_type = CampaignType::KeepItAll as u8;
+
+        assert_eq!(
+            status(fundraise, end, AMOUNT_TO_RAISE - 1),
+            FundraiseStatus::Active
+        );
+        assert_eq!(
+            status(fundraise, end + 1, AMOUNT_TO_RAISE - 1),
+            FundraiseStatus::Claimable
+        );
+        assert_eq!(status(fundraise, end + 1, 0), FundraiseStatus::Expired);
+    }
+
+    #[test]
+    fn cancelled_only_refunds() {
+        let mut data = fundraise();
+        let fundraise = Fundraise::load_mut(&mut data).unwrap();
+
+        fundraise.cancelled = 1;
+
+        for campaign_type in [CampaignType::AllOrNothing, CampaignType::KeepItAll] {
+            fundraise.campaign_type = campaign_type as u8;
+
+            assert_eq!(
+                status(fundraise, TIME_STARTED, AMOUNT_TO_RAISE),
+                FundraiseStatus::Refundable
+            );
+            assert_eq!(status(fundraise, TIME_STARTED, 0), FundraiseStatus::Expired);
+        }
+    }
+
+    #[test]
+    fn status_above_target() {
+        let mut data = fundraise();
+        let fundraise = Fundraise::load_mut(&mut data).unwrap();
+        let end = fundraise.get_end_time();
+
+        assert_eq!(status(fundraise, end, u64::MAX), FundraiseStatus::TargetMet);
+        assert_eq!(
+            status(fundraise, i64::MAX, u64::MAX),
+            FundraiseStatus::Claimable
+        );
+    }
+
+    #[test]
+    fn remaining_amount_and_progress() {
+        let mut data = fundraise();
+        let fundraise = Fundraise::load_mut(&mut data).unwrap();
+
+        assert_eq!(fundraise.remaining_amount(), AMOUNT_TO_RAISE);
+        assert_eq!(fundraise.progress_bps(), 0);
+
+        fundraise.set_current_amount(AMOUNT_TO_RAISE / 4);
+
+        assert_eq!(fundraise.remaining_amount(), AMOUNT_TO_RAISE * 3 / 4);
+        assert_eq!(fundraise.progress_bps(), MAX_BPS / 4);
+
+        fundraise.set_donated_amount(AMOUNT_TO_RAISE / 4);
+
+        assert_eq!(fundraise.remaining_amount(), AMOUNT_TO_RAISE / 2);
+        assert_eq!(fundraise.progress_bps(), MAX_BPS / 2);
+
+        fundraise.set_maker_completion(AMOUNT_TO_RAISE / 2);
+
+        assert_eq!(fundraise.remaining_amount(), 0);
+        assert_eq!(fundraise.progress_bps(), MAX_BPS);
+    }
+
+    #[test]
+    fn progress_is_capped_when_over_raised() {
+        let mut data = fundraise();
+        let fundraise = Fundraise::load_mut(&mut data).unwrap();
+
+        fundraise.set_current_amount(u64::MAX);
+
+        assert_eq!(fundraise.remaining_amount(), 0);
+        assert_eq!(fundraise.progress_bps(), MAX_BPS);
+    }
+}
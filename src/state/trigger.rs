@@ -0,0 +1,48 @@
+use crate::{AccountLoad, Prefix, Space};
+use core::mem::size_of;
+
+#[cfg(any(test, feature = "client"))]
+use crate::idl::field;
+
+/// Keeper-facing copy of a fundraise's expiry. Keepers watch `end_time` and call Finalize once
+/// it has passed, which sets `fired` so the trigger is not fired again.
+#[repr(C)]
+pub struct Trigger {
+    end_time: [u8; 8],
+    pub fired: u8,
+}
+
+impl Prefix for Trigger {
+    const PREFIX: &'static [u8] = b"trigger";
+}
+
+impl Space for Trigger {
+    const LEN: usize = size_of::<Self>();
+}
+
+impl AccountLoad for Trigger {}
+
+#[cfg(any(test, feature = "client"))]
+impl Trigger {
+    pub const IDL_FIELDS: &'static [crate::idl::IdlField] = &[
+        field!(Trigger, end_time, "i64"),
+        field!(Trigger, fired, "bool"),
+    ];
+}
+
+impl Trigger {
+    #[inline(always)]
+    pub fn get_end_time(&self) -> i64 {
+        i64::from_le_bytes(self.end_time)
+    }
+
+    #[inline(always)]
+    pub fn is_fired(&self) -> bool {
+        self.fired == 1
+    }
+
+    #[inline(always)]
+    pub fn set_end_time(&mut self, end_time: i64) {
+        self.end_time = end_time.to_le_bytes();
+    }
+}
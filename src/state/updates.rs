@@ -0,0 +1,127 @@
+use pinocchio::pubkey::Pubkey;
+
+use crate::{AccountLoad, Prefix, SetInner, Space};
+use core::mem::size_of;
+
+#[cfg(any(test, feature = "client"))]
+use crate::idl::field;
+
+/// Log of the updates the maker of a fundraise has posted, created by the first PostUpdate and
+/// grown by one [`UpdateEntry`] per post. The entries follow this header in posting order.
+#[repr(C)]
+pub struct Updates {
+    pub fundraise: Pubkey,
+    count: [u8; 2],
+    pub bump: u8,
+}
+
+impl Prefix for Updates {
+    const PREFIX: &'static [u8] = b"updates";
+}
+
+impl Space for Updates {
+    const LEN: usize = size_of::<Self>();
+}
+
+impl AccountLoad for Updates {}
+
+#[cfg(any(test, feature = "client"))]
+impl Updates {
+    pub const IDL_FIELDS: &'static [crate::idl::IdlField] = &[
+        field!(Updates, fundraise, "pubkey"),
+        field!(Updates, count, "u16"),
+        field!(Updates, bump, "u8"),
+    ];
+}
+
+impl Updates {
+    #[inline(always)]
+    pub fn get_count(&self) -> u16 {
+        u16::from_le_bytes(self.count)
+    }
+
+    #[inline(always)]
+    pub fn set_count(&mut self, count: u16) {
+        self.count = count.to_le_bytes();
+    }
+
+    /// Size of an updates account holding `count` entries.
+    #[inline(always)]
+    pub const fn space(count: usize) -> usize {
+        Self::LEN + count * UpdateEntry::LEN
+    }
+}
+
+pub struct UpdatesParams {
+    pub fundraise: Pubkey,
+    pub bump: u8,
+}
+
+impl UpdatesParams {
+    pub fn new(fundraise: Pubkey, bump: u8) -> Self {
+        Self { fundraise, bump }
+    }
+}
+
+impl SetInner for Updates {
+    type Params = UpdatesParams;
+
+    fn set_inner(&mut self, params: Self::Params) {
+        self.fundraise = params.fundraise;
+        self.count = [0; 2];
+        self.bump = params.bump;
+    }
+}
+
+/// A posted update: the hash of its off-chain content and when it was posted.
+#[repr(C)]
+pub struct UpdateEntry {
+    pub content_hash: [u8; 32],
+    timestamp: [u8; 8],
+}
+
+impl Space for UpdateEntry {
+    const LEN: usize = size_of::<Self>();
+}
+
+impl AccountLoad for UpdateEntry {}
+
+impl UpdateEntry {
+    #[inline(always)]
+    pub fn get_timestamp(&self) -> i64 {
+        i64::from_le_bytes(self.timestamp)
+    }
+
+    #[inline(always)]
+    pub fn set(&mut self, content_hash: [u8; 32], timestamp: i64) {
+        self.content_hash = content_hash;
+        self.timestamp = timestamp.to_le_bytes();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AccountLoad, SetInner, Space, UpdateEntry, Updates, UpdatesParams};
+
+    #[test]
+    fn entries_follow_the_header() {
+        let mut data = [0u8; Updates::space(2)];
+        let (header, entries) = data.split_at_mut(Updates::LEN);
+
+        Updates::load_mut(header)
+            .unwrap()
+            .set_inner(UpdatesParams::new([1; 32], 254));
+
+        for (i, entry) in entries.chunks_exact_mut(UpdateEntry::LEN).enumerate() {
+            UpdateEntry::load_mut(entry)
+                .unwrap()
+                .set([i as u8; 32], 1_000 + i as i64);
+        }
+
+        let entry = UpdateEntry::load(&data[Updates::space(1)..]).unwrap();
+
+        assert_eq!(Updates::load(&data[..Updates::LEN]).unwrap().bump, 254);
+        assert_eq!(entry.content_hash, [1; 32]);
+        assert_eq!(entry.get_timestamp(), 1_001);
+    }
+}
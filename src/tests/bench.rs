@@ -0,0 +1,1072 @@
+//! Compute units consumed by each instruction, checked against committed budgets.
+//!
+//! Every budget lives in this file, so raising one is an explicit, reviewed change. Run with
+//! `cargo test compute_units -- --nocapture` to print the table.
+
+use litesvm::LiteSVM;
+use solana_account::Account;
+use solana_instruction::{AccountMeta, Instruction};
+use solana_keypair::Keypair;
+use solana_pubkey::Pubkey;
+use solana_signer::Signer;
+use spl_associated_token_account::solana_program::{
+    clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL,
+};
+
+use crate::{
+    CRANK_REFUND_MAX_ENTRIES, CampaignType, ContributeInstructionData, FundraiserInstruction,
+    InitializeInstructionData, MAX_NAME_LEN, MAX_TIERS, MAX_URI_LEN, RefundPolicy, client, cpi,
+    tests::{
+        constants::{
+            ASSOCIATED_TOKEN_PROGRAM_ID, FEE_COLLECTOR, MINT_DECIMALS, SYSTEM_PROGRAM_ID,
+            TEST_HOOK_PROGRAM_ID, TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID,
+        },
+        pda::{
+            get_allowlist_page_pda, get_allowlist_receipt_pda, get_config_pda,
+            get_hook_authority_pda, get_program_data_pda, get_trigger_pda,
+        },
+        utils::{
+            approve_delegate, build_and_send_transaction, forward_time, init_leaderboard,
+            init_mint, init_program_data, init_token_account, init_wallet, setup,
+        },
+    },
+};
+
+pub const MAX_CU_INITIALIZE: u64 = 40_000;
+pub const MAX_CU_CONTRIBUTE: u64 = 25_000;
+pub const MAX_CU_CONTRIBUTE_TOKEN_2022: u64 = 30_000;
+pub const MAX_CU_CONTRIBUTE_EXISTING: u64 = 15_000;
+pub const MAX_CU_REFUND: u64 = 25_000;
+pub const MAX_CU_CLAIM: u64 = 40_000;
+pub const MAX_CU_RUN_HOOK: u64 = 15_000;
+pub const MAX_CU_COMPLETE: u64 = 15_000;
+pub const MAX_CU_INITIALIZE_CONFIG: u64 = 10_000;
+pub const MAX_CU_UPDATE_CONFIG: u64 = 5_000;
+pub const MAX_CU_INITIALIZE_ALLOWLIST_PAGE: u64 = 10_000;
+pub const MAX_CU_CLAIM_ALLOWLIST_SLOT: u64 = 15_000;
+pub const MAX_CU_FINALIZE: u64 = 5_000;
+pub const MAX_CU_DONATE: u64 = 15_000;
+pub const MAX_CU_CREATE_CONTRIBUTOR: u64 = 15_000;
+pub const MAX_CU_CONTRIBUTE_FOR: u64 = 25_000;
+pub const MAX_CU_TRANSFER_CONTRIBUTION: u64 = 20_000;
+pub const MAX_CU_FORCE_REFUND: u64 = 25_000;
+pub const MAX_CU_EXTEND: u64 = 5_000;
+pub const MAX_CU_CONTRIBUTE_DELEGATED: u64 = 25_000;
+pub const MAX_CU_CRANK_REFUND: u64 = 80_000;
+pub const MAX_CU_CLOSE_FUNDRAISE: u64 = 10_000;
+pub const MAX_CU_UPDATE_METADATA: u64 = 5_000;
+pub const MAX_CU_SET_PAUSED: u64 = 5_000;
+pub const MAX_CU_CANCEL: u64 = 10_000;
+pub const MAX_CU_DEPOSIT_REWARDS: u64 = 40_000;
+pub const MAX_CU_CLAIM_REWARD: u64 = 40_000;
+pub const MAX_CU_SWEEP_REWARDS: u64 = 30_000;
+pub const MAX_CU_DEPOSIT_MATCH: u64 = 40_000;
+pub const MAX_CU_RECLAIM_MATCH: u64 = 30_000;
+pub const MAX_CU_POST_UPDATE: u64 = 15_000;
+pub const MAX_CU_SNAPSHOT: u64 = 5_000;
+pub const MAX_CU_SET_CLAIM_AUTHORITY: u64 = 5_000;
+pub const MAX_CU_SWEEP_FOREIGN: u64 = 20_000;
+
+const AMOUNT_TO_RAISE: u64 = 5_000_000;
+const CONTRIBUTION: u64 = 500_000;
+const REWARDS: u64 = 1_000_000_000;
+const MATCH_POOL: u64 = 3_000_000;
+
+struct Campaign {
+    litesvm: LiteSVM,
+    maker: Keypair,
+    mint_to_raise: Pubkey,
+    token_program: Pubkey,
+}
+
+impl Campaign {
+    fn fundraise(&self) -> Pubkey {
+        client::fundraise_address(&self.maker.pubkey())
+    }
+
+    fn funded_wallet(&mut self, amount: u64) -> Keypair {
+        let wallet = init_wallet(&mut self.litesvm, LAMPORTS_PER_SOL);
+        let ata = client::associated_token_address(
+            &wallet.pubkey(),
+            &self.mint_to_raise,
+            &self.token_program,
+        );
+
+        init_token_account(
+            &mut self.litesvm,
+            ata,
+            self.mint_to_raise,
+            wallet.pubkey(),
+            amount,
+        );
+
+        wallet
+    }
+
+    fn contribute_ix(&self, authority: &Keypair, amount: u64) -> Instruction {
+        client::contribute(
+            &authority.pubkey(),
+            &self.maker.pubkey(),
+            &self.mint_to_raise,
+            &self.token_program,
+            amount,
+        )
+    }
+
+    fn send(&mut self, signer: &Keypair, ix: Instruction) -> u64 {
+        build_and_send_transaction(&mut self.litesvm, &[signer], &signer.pubkey(), &[ix])
+            .unwrap()
+            .compute_units_consumed
+    }
+
+    fn contribute(&mut self, amount: u64) -> (Keypair, u64) {
+        let authority = self.funded_wallet(amount);
+        let ix = self.contribute_ix(&authority, amount);
+        let consumed = self.send(&authority, ix);
+
+        (authority, consumed)
+    }
+}
+
+/// Sets up a maker and mint, then initializes a fundraise with `data` and any `extra` accounts
+/// appended to the Initialize account list.
+fn campaign_with(
+    token_program: Pubkey,
+    data: InitializeInstructionData,
+    extra: Vec<AccountMeta>,
+) -> (Campaign, u64) {
+    let (mut litesvm, _default_payer) = setup();
+    let maker = init_wallet(&mut litesvm, LAMPORTS_PER_SOL);
+    let mint_to_raise = init_mint(&mut litesvm, token_program, MINT_DECIMALS, 1_000_000_000);
+
+    let mut ix = client::initialize(
+        &maker.pubkey(),
+        &mint_to_raise,
+        &token_program,
+        data.amount_to_raise,
+        data.duration,
+    );
+
+    ix.data = [
+        vec![FundraiserInstruction::Initialize.code()],
+        data.to_bytes().to_vec(),
+    ]
+    .concat();
+    ix.accounts.extend(extra);
+
+    let mut campaign = Campaign {
+        litesvm,
+        maker,
+        mint_to_raise,
+        token_program,
+    };
+    let maker = campaign.maker.insecure_clone();
+    let consumed = campaign.send(&maker, ix);
+
+    (campaign, consumed)
+}
+
+fn initialize_data() -> InitializeInstructionData<'static> {
+    InitializeInstructionData {
+        amount_to_raise: AMOUNT_TO_RAISE,
+        duration: SECONDS_PER_DAY,
+        completion_window: 0,
+        completion_threshold_bps: 0,
+        max_contribution_bps: 0,
+        min_contribution: 0,
+        allowlist: false,
+        keeper_trigger: false,
+        start_time: 0,
+        refund_policy: RefundPolicy::Flexible,
+        campaign_type: CampaignType::AllOrNothing,
+        merkle_root: [0; 32],
+        register: false,
+        tier_thresholds: [0; MAX_TIERS],
+        reward_mint: [0; 32],
+        reward_amount: 0,
+        receipt: false,
+        gate_mint: [0; 32],
+        accept_freezable: false,
+        claim_authority: [0; 32],
+        name: &[],
+        uri: &[],
+    }
+}
+
+fn campaign(token_program: Pubkey) -> (Campaign, u64) {
+    campaign_with(token_program, initialize_data(), vec![])
+}
+
+fn measure_initialize() -> u64 {
+    campaign(TOKEN_PROGRAM_ID).1
+}
+
+fn measure_contribute(token_program: Pubkey) -> u64 {
+    let (mut campaign, _) = campaign(token_program);
+
+    campaign.contribute(CONTRIBUTION).1
+}
+
+/// Creates the contributor up front, then contributes with `require_existing`, which skips the
+/// bump search and the CreateAccount CPI paid by a first-time Contribute.
+fn measure_contributor_split() -> (u64, u64) {
+    let (mut campaign, _) = campaign(TOKEN_PROGRAM_ID);
+    let authority = campaign.funded_wallet(CONTRIBUTION);
+    let fundraise = campaign.fundraise();
+    let contributor = client::contributor_address(&fundraise, &authority.pubkey());
+
+    let ix = Instruction {
+        program_id: client::program_id(),
+        accounts: vec![
+            AccountMeta::new(authority.pubkey(), true),
+            AccountMeta::new_readonly(fundraise, false),
+            AccountMeta::new(contributor, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: cpi::create_contributor_ix_data().to_vec(),
+    };
+    let create_consumed = campaign.send(&authority, ix);
+
+    let mut ix = campaign.contribute_ix(&authority, CONTRIBUTION);
+
+    ix.data = [
+        vec![FundraiserInstruction::Contribute.code()],
+        ContributeInstructionData {
+            amount: CONTRIBUTION,
+            reject_duplicates: false,
+            require_existing: true,
+            clamp: false,
+            referrer: None,
+            proof: &[],
+        }
+        .to_bytes()
+        .to_vec(),
+    ]
+    .concat();
+
+    (create_consumed, campaign.send(&authority, ix))
+}
+
+fn measure_contribute_for() -> u64 {
+    let (mut campaign, _) = campaign(TOKEN_PROGRAM_ID);
+    let payer = campaign.funded_wallet(CONTRIBUTION);
+    let beneficiary = init_wallet(&mut campaign.litesvm, LAMPORTS_PER_SOL);
+    let fundraise = campaign.fundraise();
+
+    let mut ix = campaign.contribute_ix(&payer, CONTRIBUTION);
+
+    ix.accounts[3] = AccountMeta::new(
+        client::contributor_address(&fundraise, &beneficiary.pubkey()),
+        false,
+    );
+    ix.accounts
+        .insert(1, AccountMeta::new_readonly(beneficiary.pubkey(), false));
+    ix.data = cpi::contribute_for_ix_data(CONTRIBUTION).to_vec();
+
+    campaign.send(&payer, ix)
+}
+
+fn measure_contribute_delegated() -> u64 {
+    let (mut campaign, _) = campaign(TOKEN_PROGRAM_ID);
+    let owner = campaign.funded_wallet(CONTRIBUTION);
+    let delegate = init_wallet(&mut campaign.litesvm, LAMPORTS_PER_SOL);
+    let owner_ata = client::associated_token_address(
+        &owner.pubkey(),
+        &campaign.mint_to_raise,
+        &campaign.token_program,
+    );
+
+    approve_delegate(
+        &mut campaign.litesvm,
+        owner_ata,
+        delegate.pubkey(),
+        CONTRIBUTION,
+    );
+
+    let mut ix = campaign.contribute_ix(&owner, CONTRIBUTION);
+
+    ix.accounts[0] = AccountMeta::new(delegate.pubkey(), true);
+    ix.accounts
+        .insert(1, AccountMeta::new_readonly(owner.pubkey(), false));
+    ix.data = cpi::contribute_delegated_ix_data(CONTRIBUTION).to_vec();
+
+    campaign.send(&delegate, ix)
+}
+
+fn measure_transfer_contribution() -> u64 {
+    let (mut campaign, _) = campaign(TOKEN_PROGRAM_ID);
+    let (authority, _) = campaign.contribute(CONTRIBUTION);
+    let new_authority = init_wallet(&mut campaign.litesvm, LAMPORTS_PER_SOL);
+    let fundraise = campaign.fundraise();
+
+    let ix = Instruction {
+        program_id: client::program_id(),
+        accounts: vec![
+            AccountMeta::new(authority.pubkey(), true),
+            AccountMeta::new(new_authority.pubkey(), true),
+            AccountMeta::new(fundraise, false),
+            AccountMeta::new(
+                client::contributor_address(&fundraise, &authority.pubkey()),
+                false,
+            ),
+            AccountMeta::new(
+                client::contributor_address(&fundraise, &new_authority.pubkey()),
+                false,
+            ),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: cpi::transfer_contribution_ix_data().to_vec(),
+    };
+
+    build_and_send_transaction(
+        &mut campaign.litesvm,
+        &[&authority, &new_authority],
+        &new_authority.pubkey(),
+        &[ix],
+    )
+    .unwrap()
+    .compute_units_consumed
+}
+
+fn measure_refund() -> u64 {
+    let (mut campaign, _) = campaign(TOKEN_PROGRAM_ID);
+    let (authority, _) = campaign.contribute(CONTRIBUTION);
+
+    let ix = client::refund(
+        &authority.pubkey(),
+        &campaign.maker.pubkey(),
+        &campaign.mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+    );
+
+    campaign.send(&authority, ix)
+}
+
+/// A crank of a full batch of positions, the most a single call refunds.
+fn measure_crank_refund() -> u64 {
+    let (mut campaign, _) = campaign(TOKEN_PROGRAM_ID);
+    let positions: Vec<(Pubkey, Pubkey)> = (0..CRANK_REFUND_MAX_ENTRIES)
+        .map(|_| {
+            let (authority, _) = campaign.contribute(CONTRIBUTION);
+
+            (authority.pubkey(), authority.pubkey())
+        })
+        .collect();
+    let cranker = init_wallet(&mut campaign.litesvm, LAMPORTS_PER_SOL);
+
+    forward_time(&mut campaign.litesvm, SECONDS_PER_DAY as i64 + 1);
+
+    let ix = client::crank_refund(
+        &campaign.maker.pubkey(),
+        &campaign.mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+        &positions,
+    );
+
+    campaign.send(&cranker, ix)
+}
+
+fn measure_close_fundraise() -> u64 {
+    let (mut campaign, _) = campaign(TOKEN_PROGRAM_ID);
+    let maker = campaign.maker.insecure_clone();
+
+    forward_time(&mut campaign.litesvm, SECONDS_PER_DAY as i64 + 1);
+
+    let ix = client::close_fundraise(&maker.pubkey(), &TOKEN_PROGRAM_ID);
+
+    campaign.send(&maker, ix)
+}
+
+fn measure_update_metadata() -> u64 {
+    let (mut campaign, _) = campaign(TOKEN_PROGRAM_ID);
+    let maker = campaign.maker.insecure_clone();
+
+    let ix = client::update_metadata(
+        &maker.pubkey(),
+        &"n".repeat(MAX_NAME_LEN),
+        &"u".repeat(MAX_URI_LEN),
+    );
+
+    campaign.send(&maker, ix)
+}
+
+fn measure_set_paused() -> u64 {
+    let (mut campaign, _) = campaign(TOKEN_PROGRAM_ID);
+    let maker = campaign.maker.insecure_clone();
+
+    let ix = client::set_paused(&maker.pubkey(), true);
+
+    campaign.send(&maker, ix)
+}
+
+fn measure_cancel() -> u64 {
+    let (mut campaign, _) = campaign(TOKEN_PROGRAM_ID);
+    campaign.contribute(CONTRIBUTION);
+    let maker = campaign.maker.insecure_clone();
+
+    let ix = client::cancel(&maker.pubkey(), &FEE_COLLECTOR);
+
+    campaign.send(&maker, ix)
+}
+
+/// Initializes a campaign sharing [`REWARDS`] of a fresh reward mint, which the maker holds and
+/// deposits in full.
+fn rewarded_campaign() -> (Campaign, Pubkey) {
+    let reward_mint = Pubkey::new_unique();
+    let data = InitializeInstructionData {
+        reward_mint: reward_mint.to_bytes(),
+        reward_amount: REWARDS,
+        ..initialize_data()
+    };
+    let (mut campaign, _) = campaign_with(TOKEN_PROGRAM_ID, data, vec![]);
+
+    // Initialize only records the reward mint, so it can be created at that address afterwards
+    let mint = init_mint(&mut campaign.litesvm, TOKEN_PROGRAM_ID, 9, REWARDS);
+    let mint = campaign.litesvm.get_account(&mint).unwrap();
+    campaign.litesvm.set_account(reward_mint, mint).unwrap();
+
+    let maker = campaign.maker.insecure_clone();
+    init_token_account(
+        &mut campaign.litesvm,
+        client::associated_token_address(&maker.pubkey(), &reward_mint, &TOKEN_PROGRAM_ID),
+        reward_mint,
+        maker.pubkey(),
+        REWARDS,
+    );
+
+    (campaign, reward_mint)
+}
+
+fn deposit_rewards(campaign: &mut Campaign, reward_mint: &Pubkey) -> u64 {
+    let maker = campaign.maker.insecure_clone();
+    let ix = client::deposit_rewards(&maker.pubkey(), reward_mint, &TOKEN_PROGRAM_ID, REWARDS);
+
+    campaign.send(&maker, ix)
+}
+
+fn measure_deposit_rewards() -> u64 {
+    let (mut campaign, reward_mint) = rewarded_campaign();
+
+    deposit_rewards(&mut campaign, &reward_mint)
+}
+
+fn measure_claim_reward() -> u64 {
+    let (mut campaign, reward_mint) = rewarded_campaign();
+    deposit_rewards(&mut campaign, &reward_mint);
+    let (authority, _) = campaign.contribute(AMOUNT_TO_RAISE);
+    let maker = campaign.maker.insecure_clone();
+
+    let ix = client::claim(
+        &maker.pubkey(),
+        &campaign.mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+        &FEE_COLLECTOR,
+    );
+    campaign.send(&maker, ix);
+
+    let ix = client::claim_reward(
+        &authority.pubkey(),
+        &maker.pubkey(),
+        &reward_mint,
+        &TOKEN_PROGRAM_ID,
+    );
+
+    campaign.send(&authority, ix)
+}
+
+/// Sweeps the whole deposit back from a campaign that fell short of its target.
+fn measure_sweep_rewards() -> u64 {
+    let (mut campaign, reward_mint) = rewarded_campaign();
+    deposit_rewards(&mut campaign, &reward_mint);
+    campaign.contribute(CONTRIBUTION);
+    forward_time(&mut campaign.litesvm, SECONDS_PER_DAY as i64 + 1);
+    let maker = campaign.maker.insecure_clone();
+
+    let ix = client::sweep_rewards(&maker.pubkey(), &reward_mint, &TOKEN_PROGRAM_ID);
+
+    campaign.send(&maker, ix)
+}
+
+/// Makes a fresh sponsor and matches the campaign with the whole pool.
+fn deposit_match(campaign: &mut Campaign) -> (Keypair, u64) {
+    let sponsor = campaign.funded_wallet(MATCH_POOL);
+    let ix = client::deposit_match(
+        &sponsor.pubkey(),
+        &campaign.maker.pubkey(),
+        &campaign.mint_to_raise,
+        &campaign.token_program,
+        MATCH_POOL,
+    );
+    let consumed = campaign.send(&sponsor, ix);
+
+    (sponsor, consumed)
+}
+
+fn measure_deposit_match() -> u64 {
+    let (mut campaign, _) = campaign(TOKEN_PROGRAM_ID);
+
+    deposit_match(&mut campaign).1
+}
+
+/// Reclaims the pool of a campaign that fell short of its target.
+fn measure_reclaim_match() -> u64 {
+    let (mut campaign, _) = campaign(TOKEN_PROGRAM_ID);
+    let (sponsor, _) = deposit_match(&mut campaign);
+    campaign.contribute(CONTRIBUTION);
+    forward_time(&mut campaign.litesvm, SECONDS_PER_DAY as i64 + 1);
+
+    let ix = client::reclaim_match(
+        &sponsor.pubkey(),
+        &campaign.maker.pubkey(),
+        &campaign.mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+    );
+
+    campaign.send(&sponsor, ix)
+}
+
+/// Posts the second update, which grows the updates account the first created.
+fn measure_post_update() -> u64 {
+    let (mut campaign, _) = campaign(TOKEN_PROGRAM_ID);
+    let maker = campaign.maker.insecure_clone();
+    campaign.send(&maker, client::post_update(&maker.pubkey(), [1; 32]));
+
+    let ix = client::post_update(&maker.pubkey(), [2; 32]);
+
+    campaign.send(&maker, ix)
+}
+
+fn measure_snapshot() -> u64 {
+    let (mut campaign, _) = campaign(TOKEN_PROGRAM_ID);
+    let (authority, _) = campaign.contribute(AMOUNT_TO_RAISE);
+
+    let ix = client::snapshot(&campaign.maker.pubkey(), &authority.pubkey());
+
+    campaign.send(&authority, ix)
+}
+
+fn measure_set_claim_authority() -> u64 {
+    let (mut campaign, _) = campaign(TOKEN_PROGRAM_ID);
+    let maker = campaign.maker.insecure_clone();
+
+    let ix = client::set_claim_authority(&maker.pubkey(), &Pubkey::new_unique());
+
+    campaign.send(&maker, ix)
+}
+
+/// Empties and closes the fundraise's associated token account of a mint it does not raise.
+fn measure_sweep_foreign() -> u64 {
+    let (mut campaign, _) = campaign(TOKEN_PROGRAM_ID);
+    let maker = campaign.maker.insecure_clone();
+    let mint = init_mint(&mut campaign.litesvm, TOKEN_PROGRAM_ID, 9, CONTRIBUTION);
+    let fundraise = campaign.fundraise();
+    init_token_account(
+        &mut campaign.litesvm,
+        client::associated_token_address(&fundraise, &mint, &TOKEN_PROGRAM_ID),
+        mint,
+        fundraise,
+        CONTRIBUTION,
+    );
+    let destination = client::associated_token_address(&maker.pubkey(), &mint, &TOKEN_PROGRAM_ID);
+    init_token_account(&mut campaign.litesvm, destination, mint, maker.pubkey(), 0);
+
+    let ix = client::sweep_foreign(
+        &maker.pubkey(),
+        &mint,
+        &TOKEN_PROGRAM_ID,
+        &destination,
+        true,
+    );
+
+    campaign.send(&maker, ix)
+}
+
+fn measure_force_refund() -> u64 {
+    let (mut campaign, _) = campaign(TOKEN_PROGRAM_ID);
+    let (authority, _) = campaign.contribute(CONTRIBUTION);
+    let fundraise = campaign.fundraise();
+
+    let ix = Instruction {
+        program_id: client::program_id(),
+        accounts: vec![
+            AccountMeta::new(campaign.maker.pubkey(), true),
+            AccountMeta::new(authority.pubkey(), false),
+            AccountMeta::new_readonly(campaign.mint_to_raise, false),
+            AccountMeta::new(fundraise, false),
+            AccountMeta::new(
+                client::contributor_address(&fundraise, &authority.pubkey()),
+                false,
+            ),
+            AccountMeta::new(
+                client::associated_token_address(
+                    &authority.pubkey(),
+                    &campaign.mint_to_raise,
+                    &TOKEN_PROGRAM_ID,
+                ),
+                false,
+            ),
+            AccountMeta::new(client::vault_address(&fundraise), false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+        ],
+        data: cpi::force_refund_ix_data().to_vec(),
+    };
+    let maker = campaign.maker.insecure_clone();
+
+    campaign.send(&maker, ix)
+}
+
+fn measure_claim() -> u64 {
+    let (mut campaign, _) = campaign(TOKEN_PROGRAM_ID);
+    campaign.contribute(AMOUNT_TO_RAISE);
+
+    let ix = client::claim(
+        &campaign.maker.pubkey(),
+        &campaign.mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+        &FEE_COLLECTOR,
+    );
+    let maker = campaign.maker.insecure_clone();
+
+    campaign.send(&maker, ix)
+}
+
+fn measure_run_hook() -> u64 {
+    let (mut litesvm, _default_payer) = setup();
+    let leaderboard = init_leaderboard(&mut litesvm);
+    let maker = init_wallet(&mut litesvm, LAMPORTS_PER_SOL);
+    let mint_to_raise = init_mint(&mut litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+    let mut ix = client::initialize(
+        &maker.pubkey(),
+        &mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+        AMOUNT_TO_RAISE,
+        SECONDS_PER_DAY,
+    );
+
+    ix.accounts.extend([
+        AccountMeta::new_readonly(TEST_HOOK_PROGRAM_ID, false),
+        AccountMeta::new(leaderboard, false),
+    ]);
+
+    build_and_send_transaction(&mut litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+    let mut campaign = Campaign {
+        litesvm,
+        maker,
+        mint_to_raise,
+        token_program: TOKEN_PROGRAM_ID,
+    };
+    let (authority, _) = campaign.contribute(CONTRIBUTION);
+    let fundraise = campaign.fundraise();
+    let cranker = init_wallet(&mut campaign.litesvm, LAMPORTS_PER_SOL);
+
+    let ix = Instruction {
+        program_id: client::program_id(),
+        accounts: vec![
+            AccountMeta::new(fundraise, false),
+            AccountMeta::new(
+                client::contributor_address(&fundraise, &authority.pubkey()),
+                false,
+            ),
+            AccountMeta::new_readonly(get_hook_authority_pda(&fundraise), false),
+            AccountMeta::new_readonly(TEST_HOOK_PROGRAM_ID, false),
+            AccountMeta::new(leaderboard, false),
+        ],
+        data: cpi::run_hook_ix_data().to_vec(),
+    };
+
+    campaign.send(&cranker, ix)
+}
+
+fn measure_complete() -> u64 {
+    let (mut campaign, _) = campaign_with(
+        TOKEN_PROGRAM_ID,
+        InitializeInstructionData {
+            completion_window: SECONDS_PER_DAY,
+            ..initialize_data()
+        },
+        vec![],
+    );
+    campaign.contribute(CONTRIBUTION);
+
+    let maker = campaign.maker.insecure_clone();
+    let maker_ata = client::associated_token_address(
+        &maker.pubkey(),
+        &campaign.mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+    );
+
+    init_token_account(
+        &mut campaign.litesvm,
+        maker_ata,
+        campaign.mint_to_raise,
+        maker.pubkey(),
+        AMOUNT_TO_RAISE,
+    );
+
+    let fundraise = campaign.fundraise();
+    let ix = Instruction {
+        program_id: client::program_id(),
+        accounts: vec![
+            AccountMeta::new_readonly(maker.pubkey(), true),
+            AccountMeta::new_readonly(campaign.mint_to_raise, false),
+            AccountMeta::new(fundraise, false),
+            AccountMeta::new(maker_ata, false),
+            AccountMeta::new(client::vault_address(&fundraise), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data: cpi::complete_ix_data().to_vec(),
+    };
+
+    campaign.send(&maker, ix)
+}
+
+fn measure_donate() -> u64 {
+    let (mut campaign, _) = campaign_with(TOKEN_PROGRAM_ID, initialize_data(), vec![]);
+    let donor = campaign.funded_wallet(CONTRIBUTION);
+
+    let fundraise = campaign.fundraise();
+    let ix = Instruction {
+        program_id: client::program_id(),
+        accounts: vec![
+            AccountMeta::new_readonly(donor.pubkey(), true),
+            AccountMeta::new_readonly(campaign.mint_to_raise, false),
+            AccountMeta::new(fundraise, false),
+            AccountMeta::new(
+                client::associated_token_address(
+                    &donor.pubkey(),
+                    &campaign.mint_to_raise,
+                    &TOKEN_PROGRAM_ID,
+                ),
+                false,
+            ),
+            AccountMeta::new(client::vault_address(&fundraise), false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data: cpi::donate_ix_data(CONTRIBUTION).to_vec(),
+    };
+
+    campaign.send(&donor, ix)
+}
+
+fn measure_initialize_config() -> u64 {
+    let (mut litesvm, _default_payer) = setup();
+    let admin = init_wallet(&mut litesvm, LAMPORTS_PER_SOL);
+    init_program_data(&mut litesvm, admin.pubkey());
+
+    // drop the config preloaded by setup
+    litesvm
+        .set_account(get_config_pda(), Account::default())
+        .unwrap();
+
+    let ix = Instruction {
+        program_id: client::program_id(),
+        accounts: vec![
+            AccountMeta::new(admin.pubkey(), true),
+            AccountMeta::new(get_config_pda(), false),
+            AccountMeta::new_readonly(get_program_data_pda(), false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: cpi::initialize_config_ix_data(250, FEE_COLLECTOR.to_bytes()).to_vec(),
+    };
+
+    build_and_send_transaction(&mut litesvm, &[&admin], &admin.pubkey(), &[ix])
+        .unwrap()
+        .compute_units_consumed
+}
+
+fn measure_update_config() -> u64 {
+    let (mut litesvm, default_payer) = setup();
+
+    let ix = Instruction {
+        program_id: client::program_id(),
+        accounts: vec![
+            AccountMeta::new_readonly(default_payer.pubkey(), true),
+            AccountMeta::new(get_config_pda(), false),
+        ],
+        data: cpi::update_config_ix_data(
+            default_payer.pubkey().to_bytes(),
+            100,
+            FEE_COLLECTOR.to_bytes(),
+            false,
+            0,
+            0,
+        )
+        .to_vec(),
+    };
+
+    build_and_send_transaction(
+        &mut litesvm,
+        &[&default_payer],
+        &default_payer.pubkey(),
+        &[ix],
+    )
+    .unwrap()
+    .compute_units_consumed
+}
+
+fn allowlist_page_ix(campaign: &Campaign) -> Instruction {
+    let fundraise = campaign.fundraise();
+
+    Instruction {
+        program_id: client::program_id(),
+        accounts: vec![
+            AccountMeta::new(campaign.maker.pubkey(), true),
+            AccountMeta::new_readonly(fundraise, false),
+            AccountMeta::new(get_allowlist_page_pda(&fundraise, 0), false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: cpi::initialize_allowlist_page_ix_data(0).to_vec(),
+    }
+}
+
+fn allowlist_campaign() -> Campaign {
+    campaign_with(
+        TOKEN_PROGRAM_ID,
+        InitializeInstructionData {
+            allowlist: true,
+            ..initialize_data()
+        },
+        vec![],
+    )
+    .0
+}
+
+fn measure_initialize_allowlist_page() -> u64 {
+    let mut campaign = allowlist_campaign();
+    let ix = allowlist_page_ix(&campaign);
+    let maker = campaign.maker.insecure_clone();
+
+    campaign.send(&maker, ix)
+}
+
+fn measure_claim_allowlist_slot() -> u64 {
+    let mut campaign = allowlist_campaign();
+    let ix = allowlist_page_ix(&campaign);
+    let maker = campaign.maker.insecure_clone();
+    campaign.send(&maker, ix);
+
+    let wallet = init_wallet(&mut campaign.litesvm, LAMPORTS_PER_SOL);
+    let fundraise = campaign.fundraise();
+
+    let ix = Instruction {
+        program_id: client::program_id(),
+        accounts: vec![
+            AccountMeta::new(wallet.pubkey(), true),
+            AccountMeta::new_readonly(maker.pubkey(), true),
+            AccountMeta::new_readonly(fundraise, false),
+            AccountMeta::new(get_allowlist_page_pda(&fundraise, 0), false),
+            AccountMeta::new(
+                get_allowlist_receipt_pda(&fundraise, &wallet.pubkey()),
+                false,
+            ),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+        ],
+        data: cpi::claim_allowlist_slot_ix_data(0, 7).to_vec(),
+    };
+
+    build_and_send_transaction(
+        &mut campaign.litesvm,
+        &[&wallet, &maker],
+        &wallet.pubkey(),
+        &[ix],
+    )
+    .unwrap()
+    .compute_units_consumed
+}
+
+fn measure_finalize() -> u64 {
+    let maker = Keypair::new();
+    let trigger = get_trigger_pda(&client::fundraise_address(&maker.pubkey()));
+
+    let (mut litesvm, _default_payer) = setup();
+    litesvm.airdrop(&maker.pubkey(), LAMPORTS_PER_SOL).unwrap();
+
+    let mint_to_raise = init_mint(&mut litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+    let mut ix = client::initialize(
+        &maker.pubkey(),
+        &mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+        AMOUNT_TO_RAISE,
+        SECONDS_PER_DAY,
+    );
+
+    ix.data = [
+        vec![FundraiserInstruction::Initialize.code()],
+        InitializeInstructionData {
+            keeper_trigger: true,
+            ..initialize_data()
+        }
+        .to_bytes()
+        .to_vec(),
+    ]
+    .concat();
+    ix.accounts.push(AccountMeta::new(trigger, false));
+
+    build_and_send_transaction(&mut litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+    forward_time(&mut litesvm, SECONDS_PER_DAY as i64 + 1);
+
+    let keeper = init_wallet(&mut litesvm, LAMPORTS_PER_SOL);
+    let ix = Instruction {
+        program_id: client::program_id(),
+        accounts: vec![
+            AccountMeta::new_readonly(client::fundraise_address(&maker.pubkey()), false),
+            AccountMeta::new(trigger, false),
+        ],
+        data: cpi::finalize_ix_data().to_vec(),
+    };
+
+    build_and_send_transaction(&mut litesvm, &[&keeper], &keeper.pubkey(), &[ix])
+        .unwrap()
+        .compute_units_consumed
+}
+
+fn measure_extend() -> u64 {
+    let (mut campaign, _) = campaign(TOKEN_PROGRAM_ID);
+
+    let maker = campaign.maker.insecure_clone();
+    let ix = Instruction {
+        program_id: client::program_id(),
+        accounts: vec![
+            AccountMeta::new_readonly(maker.pubkey(), true),
+            AccountMeta::new(campaign.fundraise(), false),
+        ],
+        data: cpi::extend_ix_data(SECONDS_PER_DAY).to_vec(),
+    };
+
+    campaign.send(&maker, ix)
+}
+
+#[test]
+fn compute_units_within_budget() {
+    let (create_contributor, contribute_existing) = measure_contributor_split();
+    let measurements = [
+        ("Initialize", measure_initialize(), MAX_CU_INITIALIZE),
+        (
+            "Contribute",
+            measure_contribute(TOKEN_PROGRAM_ID),
+            MAX_CU_CONTRIBUTE,
+        ),
+        (
+            "Contribute (Token-2022)",
+            measure_contribute(TOKEN_2022_PROGRAM_ID),
+            MAX_CU_CONTRIBUTE_TOKEN_2022,
+        ),
+        (
+            "Contribute (existing)",
+            contribute_existing,
+            MAX_CU_CONTRIBUTE_EXISTING,
+        ),
+        ("Refund", measure_refund(), MAX_CU_REFUND),
+        ("Claim", measure_claim(), MAX_CU_CLAIM),
+        ("RunHook", measure_run_hook(), MAX_CU_RUN_HOOK),
+        ("Complete", measure_complete(), MAX_CU_COMPLETE),
+        (
+            "InitializeConfig",
+            measure_initialize_config(),
+            MAX_CU_INITIALIZE_CONFIG,
+        ),
+        (
+            "UpdateConfig",
+            measure_update_config(),
+            MAX_CU_UPDATE_CONFIG,
+        ),
+        (
+            "InitializeAllowlistPage",
+            measure_initialize_allowlist_page(),
+            MAX_CU_INITIALIZE_ALLOWLIST_PAGE,
+        ),
+        (
+            "ClaimAllowlistSlot",
+            measure_claim_allowlist_slot(),
+            MAX_CU_CLAIM_ALLOWLIST_SLOT,
+        ),
+        ("Finalize", measure_finalize(), MAX_CU_FINALIZE),
+        ("Donate", measure_donate(), MAX_CU_DONATE),
+        (
+            "CreateContributor",
+            create_contributor,
+            MAX_CU_CREATE_CONTRIBUTOR,
+        ),
+        (
+            "ContributeFor",
+            measure_contribute_for(),
+            MAX_CU_CONTRIBUTE_FOR,
+        ),
+        (
+            "TransferContribution",
+            measure_transfer_contribution(),
+            MAX_CU_TRANSFER_CONTRIBUTION,
+        ),
+        ("ForceRefund", measure_force_refund(), MAX_CU_FORCE_REFUND),
+        ("Extend", measure_extend(), MAX_CU_EXTEND),
+        (
+            "ContributeDelegated",
+            measure_contribute_delegated(),
+            MAX_CU_CONTRIBUTE_DELEGATED,
+        ),
+        ("CrankRefund", measure_crank_refund(), MAX_CU_CRANK_REFUND),
+        (
+            "CloseFundraise",
+            measure_close_fundraise(),
+            MAX_CU_CLOSE_FUNDRAISE,
+        ),
+        (
+            "UpdateMetadata",
+            measure_update_metadata(),
+            MAX_CU_UPDATE_METADATA,
+        ),
+        ("SetPaused", measure_set_paused(), MAX_CU_SET_PAUSED),
+        ("Cancel", measure_cancel(), MAX_CU_CANCEL),
+        (
+            "DepositRewards",
+            measure_deposit_rewards(),
+            MAX_CU_DEPOSIT_REWARDS,
+        ),
+        ("ClaimReward", measure_claim_reward(), MAX_CU_CLAIM_REWARD),
+        (
+            "SweepRewards",
+            measure_sweep_rewards(),
+            MAX_CU_SWEEP_REWARDS,
+        ),
+        (
+            "DepositMatch",
+            measure_deposit_match(),
+            MAX_CU_DEPOSIT_MATCH,
+        ),
+        (
+            "ReclaimMatch",
+            measure_reclaim_match(),
+            MAX_CU_RECLAIM_MATCH,
+        ),
+        ("PostUpdate", measure_post_update(), MAX_CU_POST_UPDATE),
+        ("Snapshot", measure_snapshot(), MAX_CU_SNAPSHOT),
+        (
+            "SetClaimAuthority",
+            measure_set_claim_authority(),
+            MAX_CU_SET_CLAIM_AUTHORITY,
+        ),
+        (
+            "SweepForeign",
+            measure_sweep_foreign(),
+            MAX_CU_SWEEP_FOREIGN,
+        ),
+    ];
+
+    println!("{:<24} {:>10} {:>10}", "instruction", "consumed", "budget");
+
+    for (name, consumed, budget) in measurements {
+        println!("{name:<24} {consumed:>10} {budget:>10}");
+    }
+
+    let over: Vec<_> = measurements
+        .iter()
+        .filter(|(_, consumed, budget)| consumed > budget)
+        .map(|(name, consumed, budget)| format!("{name}: {consumed} > {budget}"))
+        .collect();
+
+    assert!(over.is_empty(), "over budget: {over:?}");
+}
@@ -0,0 +1,185 @@
+use litesvm::LiteSVM;
+use solana_keypair::Keypair;
+use solana_pubkey::Pubkey;
+use solana_signer::Signer;
+use spl_associated_token_account::solana_program::{
+    clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL,
+};
+
+use crate::{
+    Fundraise, FundraiserError, Space, client,
+    tests::{
+        constants::{FEE_COLLECTOR, MINT_DECIMALS, TOKEN_PROGRAM_ID},
+        utils::{
+            assert_error, build_and_send_transaction, forward_time, init_ata, init_mint,
+            init_wallet, set_config_bond, setup,
+        },
+    },
+};
+
+const BOND: u64 = LAMPORTS_PER_SOL / 10;
+const AMOUNT_TO_RAISE: u64 = 5_000_000;
+
+fn lamports(litesvm: &LiteSVM, address: &Pubkey) -> u64 {
+    litesvm
+        .get_account(address)
+        .map_or(0, |account| account.lamports)
+}
+
+/// Asserts the fundraise holds exactly its rent plus `bond`, so it never dips below rent
+/// exemption while open.
+fn assert_fundraise_lamports(litesvm: &LiteSVM, maker: &Pubkey, bond: u64) {
+    let fundraise = client::fundraise_address(maker);
+    let rent = litesvm.minimum_balance_for_rent_exemption(Fundraise::LEN);
+
+    assert_eq!(lamports(litesvm, &fundraise), rent + bond);
+    assert_eq!(
+        Fundraise::unpack(&litesvm.get_account(&fundraise).unwrap().data)
+            .unwrap()
+            .bond,
+        bond
+    );
+}
+
+fn initialize(litesvm: &mut LiteSVM) -> (Keypair, Pubkey) {
+    set_config_bond(litesvm, BOND, 2_500);
+
+    let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+    let ix = client::initialize(
+        &maker.pubkey(),
+        &mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+        AMOUNT_TO_RAISE,
+        SECONDS_PER_DAY,
+    );
+    build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+    (maker, mint_to_raise)
+}
+
+fn contribute(
+    litesvm: &mut LiteSVM,
+    maker: &Keypair,
+    mint_to_raise: Pubkey,
+    amount: u64,
+) -> Keypair {
+    let backer = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    init_ata(litesvm, mint_to_raise, backer.pubkey(), amount);
+
+    let ix = client::contribute(
+        &backer.pubkey(),
+        &maker.pubkey(),
+        &mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+        amount,
+    );
+    build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]).unwrap();
+
+    backer
+}
+
+#[test]
+fn bond_across_cancel_and_close() {
+    let (litesvm, _default_payer) = &mut setup();
+    let (maker, mint_to_raise) = initialize(litesvm);
+
+    assert_fundraise_lamports(litesvm, &maker.pubkey(), BOND);
+
+    let backer = contribute(litesvm, &maker, mint_to_raise, 500_000);
+
+    assert_fundraise_lamports(litesvm, &maker.pubkey(), BOND);
+
+    let collector_before = lamports(litesvm, &FEE_COLLECTOR);
+
+    let ix = client::cancel(&maker.pubkey(), &FEE_COLLECTOR);
+    build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+    // the slash and the rest of the bond both leave, and nothing of the rent
+    assert_fundraise_lamports(litesvm, &maker.pubkey(), 0);
+    assert_eq!(
+        lamports(litesvm, &FEE_COLLECTOR),
+        collector_before + BOND / 4
+    );
+
+    let ix = client::refund(
+        &backer.pubkey(),
+        &maker.pubkey(),
+        &mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+    );
+    build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]).unwrap();
+
+    assert_fundraise_lamports(litesvm, &maker.pubkey(), 0);
+
+    forward_time(litesvm, SECONDS_PER_DAY as i64 + 1);
+
+    let fundraise = client::fundraise_address(&maker.pubkey());
+    let rent = litesvm.minimum_balance_for_rent_exemption(Fundraise::LEN);
+    let maker_before = lamports(litesvm, &maker.pubkey());
+
+    let ix = client::close_fundraise(&maker.pubkey(), &TOKEN_PROGRAM_ID);
+    build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]).unwrap();
+
+    assert!(litesvm.get_account(&fundraise).is_none());
+    assert_eq!(lamports(litesvm, &maker.pubkey()), maker_before + rent);
+}
+
+#[test]
+fn bond_across_claim_by_claim_authority() {
+    let (litesvm, _default_payer) = &mut setup();
+    let (maker, mint_to_raise) = initialize(litesvm);
+    let claim_authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+
+    let ix = client::set_claim_authority(&maker.pubkey(), &claim_authority.pubkey());
+    build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+    contribute(litesvm, &maker, mint_to_raise, AMOUNT_TO_RAISE);
+
+    assert_fundraise_lamports(litesvm, &maker.pubkey(), BOND);
+
+    let maker_before = lamports(litesvm, &maker.pubkey());
+
+    let ix = client::with_claimant(
+        client::claim(
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            &FEE_COLLECTOR,
+        ),
+        &claim_authority.pubkey(),
+    );
+    build_and_send_transaction(
+        litesvm,
+        &[&claim_authority],
+        &claim_authority.pubkey(),
+        &[ix],
+    )
+    .unwrap();
+
+    // the bond goes back to the maker who posted it, not to whoever was handed the claim
+    assert_fundraise_lamports(litesvm, &maker.pubkey(), 0);
+    assert_eq!(lamports(litesvm, &maker.pubkey()), maker_before + BOND);
+}
+
+#[test]
+fn throw_if_claim_bond_paid_to_other_than_maker() {
+    let (litesvm, _default_payer) = &mut setup();
+    let (maker, mint_to_raise) = initialize(litesvm);
+    let impostor = init_wallet(litesvm, LAMPORTS_PER_SOL);
+
+    contribute(litesvm, &maker, mint_to_raise, AMOUNT_TO_RAISE);
+
+    let mut ix = client::claim(
+        &maker.pubkey(),
+        &mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+        &FEE_COLLECTOR,
+    );
+    ix.accounts[11].pubkey = impostor.pubkey();
+    let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+    assert_error(res.unwrap_err(), FundraiserError::InvalidAddress);
+    assert_fundraise_lamports(litesvm, &maker.pubkey(), BOND);
+}
@@ -3,8 +3,22 @@ use solana_pubkey::Pubkey;
 pub static SYSTEM_PROGRAM_ID: Pubkey = Pubkey::new_from_array(pinocchio_system::ID);
 pub static TOKEN_PROGRAM_ID: Pubkey = Pubkey::new_from_array(pinocchio_token::ID);
 pub static TOKEN_2022_PROGRAM_ID: Pubkey = Pubkey::new_from_array(pinocchio_token_2022::ID);
+pub static INSTRUCTIONS_SYSVAR_ID: Pubkey =
+    Pubkey::new_from_array(pinocchio::sysvars::instructions::INSTRUCTIONS_ID);
+pub static MEMO_PROGRAM_ID: Pubkey = Pubkey::new_from_array(crate::MEMO_PROGRAM_ID);
 pub static ASSOCIATED_TOKEN_PROGRAM_ID: Pubkey =
     Pubkey::new_from_array(pinocchio_associated_token_account::ID);
 
 pub static PROGRAM_ID: Pubkey = Pubkey::new_from_array(crate::ID);
+pub static TEST_HOOK_PROGRAM_ID: Pubkey =
+    Pubkey::new_from_array(*b"fundraiser_test_hook_program_id_");
+pub static TEST_TRANSFER_HOOK_PROGRAM_ID: Pubkey =
+    Pubkey::new_from_array(*b"fundraiser_test_transfer_hook___");
+/// `Execute` instruction of the transfer hook interface, also keying its validation data.
+pub const EXECUTE_DISCRIMINATOR: [u8; 8] = [105, 37, 101, 197, 75, 251, 102, 26];
+pub static PDA_MAKER_PROGRAM_ID: Pubkey =
+    Pubkey::new_from_array(*b"fundraiser_test_pda_maker_progrm");
+pub static MOCK_MULTISIG_PROGRAM_ID: Pubkey =
+    Pubkey::new_from_array(*b"fundraiser_test_multisig_program");
+pub static FEE_COLLECTOR: Pubkey = Pubkey::new_from_array(*b"fundraiser_test_fee_collector___");
 pub const MINT_DECIMALS: u8 = 6;
@@ -0,0 +1,579 @@
+//! Custom errors raised by Contribute.
+
+use solana_clock::Clock;
+use solana_instruction::{AccountMeta, Instruction};
+use solana_pubkey::Pubkey;
+use solana_signer::Signer;
+use spl_associated_token_account::solana_program::{
+    clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL,
+};
+
+use crate::{
+    FundraiserError,
+    tests::{
+        constants::{
+            ASSOCIATED_TOKEN_PROGRAM_ID, MINT_DECIMALS, PROGRAM_ID, SYSTEM_PROGRAM_ID,
+            TOKEN_PROGRAM_ID,
+        },
+        pda::{get_config_pda, get_contributor_pda, get_fundraise_pda, get_vault_pda},
+        utils::{
+            assert_custom_error, build_and_send_transaction, forward_time, init_ata, init_mint,
+            init_wallet, setup,
+        },
+    },
+};
+
+#[test]
+fn invalid_contributor_pda() {
+    let (litesvm, _default_payer) = &mut setup();
+    let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+    let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+    let amount_to_raise: u64 = 5_000_000;
+    let duration: u64 = SECONDS_PER_DAY; // 1 day
+    let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+    let vault = get_vault_pda(&fundraise_pda);
+
+    let data = [
+        vec![0u8],
+        amount_to_raise.to_le_bytes().to_vec(),
+        duration.to_le_bytes().to_vec(),
+        0u64.to_le_bytes().to_vec(),
+        0u16.to_le_bytes().to_vec(),
+        1_000u16.to_le_bytes().to_vec(),
+        0u64.to_le_bytes().to_vec(),
+    ]
+    .concat();
+    let ix = Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(maker.pubkey(), true),
+            AccountMeta::new_readonly(mint_to_raise, false),
+            AccountMeta::new(fundraise_pda, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(get_config_pda(), false),
+        ],
+        data,
+    };
+
+    let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+    let contribute_amount: u64 = 500_000;
+    let contributor_pda = Pubkey::new_unique();
+
+    let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+    let ix = Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(authority.pubkey(), true),
+            AccountMeta::new_readonly(mint_to_raise, false),
+            AccountMeta::new(fundraise_pda, false),
+            AccountMeta::new(contributor_pda, false),
+            AccountMeta::new(authority_ata, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(get_config_pda(), false),
+        ],
+        data,
+    };
+
+    let res = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+    assert_custom_error(res.unwrap_err(), FundraiserError::InvalidAddress);
+}
+
+#[test]
+fn contribution_above_cap() {
+    let (litesvm, _default_payer) = &mut setup();
+    let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+    let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+    let amount_to_raise: u64 = 5_000_000;
+    let duration: u64 = SECONDS_PER_DAY; // 1 day
+    let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+    let vault = get_vault_pda(&fundraise_pda);
+
+    let data = [
+        vec![0u8],
+        amount_to_raise.to_le_bytes().to_vec(),
+        duration.to_le_bytes().to_vec(),
+        0u64.to_le_bytes().to_vec(),
+        0u16.to_le_bytes().to_vec(),
+        1_000u16.to_le_bytes().to_vec(),
+        0u64.to_le_bytes().to_vec(),
+    ]
+    .concat();
+    let ix = Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(maker.pubkey(), true),
+            AccountMeta::new_readonly(mint_to_raise, false),
+            AccountMeta::new(fundraise_pda, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(get_config_pda(), false),
+        ],
+        data,
+    };
+
+    let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+    let contribute_amount: u64 = 500_001;
+    let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+    let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+    let ix = Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(authority.pubkey(), true),
+            AccountMeta::new_readonly(mint_to_raise, false),
+            AccountMeta::new(fundraise_pda, false),
+            AccountMeta::new(contributor_pda, false),
+            AccountMeta::new(authority_ata, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(get_config_pda(), false),
+        ],
+        data,
+    };
+
+    let res = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+    assert_custom_error(res.unwrap_err(), FundraiserError::ContributionTooBig);
+}
+
+#[test]
+fn contribution_below_minimum() {
+    let (litesvm, _default_payer) = &mut setup();
+    let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+    let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+    let amount_to_raise: u64 = 5_000_000;
+    let duration: u64 = SECONDS_PER_DAY; // 1 day
+    let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+    let vault = get_vault_pda(&fundraise_pda);
+
+    let data = [
+        vec![0u8],
+        amount_to_raise.to_le_bytes().to_vec(),
+        duration.to_le_bytes().to_vec(),
+        0u64.to_le_bytes().to_vec(),
+        0u16.to_le_bytes().to_vec(),
+        1_000u16.to_le_bytes().to_vec(),
+        0u64.to_le_bytes().to_vec(),
+    ]
+    .concat();
+    let ix = Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(maker.pubkey(), true),
+            AccountMeta::new_readonly(mint_to_raise, false),
+            AccountMeta::new(fundraise_pda, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(get_config_pda(), false),
+        ],
+        data,
+    };
+
+    let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+    let contribute_amount: u64 = 0;
+    let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+    let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+    let ix = Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(authority.pubkey(), true),
+            AccountMeta::new_readonly(mint_to_raise, false),
+            AccountMeta::new(fundraise_pda, false),
+            AccountMeta::new(contributor_pda, false),
+            AccountMeta::new(authority_ata, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(get_config_pda(), false),
+        ],
+        data,
+    };
+
+    let res = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+    assert_custom_error(res.unwrap_err(), FundraiserError::ContributionTooSmall);
+}
+
+#[test]
+fn accumulated_contributions_above_cap() {
+    let (litesvm, _default_payer) = &mut setup();
+    let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+    let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+    let amount_to_raise: u64 = 5_000_000;
+    let duration: u64 = SECONDS_PER_DAY; // 1 day
+    let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+    let vault = get_vault_pda(&fundraise_pda);
+
+    let data = [
+        vec![0u8],
+        amount_to_raise.to_le_bytes().to_vec(),
+        duration.to_le_bytes().to_vec(),
+        0u64.to_le_bytes().to_vec(),
+        0u16.to_le_bytes().to_vec(),
+        1_000u16.to_le_bytes().to_vec(),
+        0u64.to_le_bytes().to_vec(),
+    ]
+    .concat();
+    let ix = Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(maker.pubkey(), true),
+            AccountMeta::new_readonly(mint_to_raise, false),
+            AccountMeta::new(fundraise_pda, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(get_config_pda(), false),
+        ],
+        data,
+    };
+
+    let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+    let contribute_amount: u64 = 300_000;
+    let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+    let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+    let ix = Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(authority.pubkey(), true),
+            AccountMeta::new_readonly(mint_to_raise, false),
+            AccountMeta::new(fundraise_pda, false),
+            AccountMeta::new(contributor_pda, false),
+            AccountMeta::new(authority_ata, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(get_config_pda(), false),
+        ],
+        data,
+    };
+
+    let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+    let second_amount: u64 = 250_000;
+
+    let data = [vec![1u8], second_amount.to_le_bytes().to_vec()].concat();
+    let ix = Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(authority.pubkey(), true),
+            AccountMeta::new_readonly(mint_to_raise, false),
+            AccountMeta::new(fundraise_pda, false),
+            AccountMeta::new(contributor_pda, false),
+            AccountMeta::new(authority_ata, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(get_config_pda(), false),
+        ],
+        data,
+    };
+
+    let res = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+    assert_custom_error(
+        res.unwrap_err(),
+        FundraiserError::MaximumContributionsReached,
+    );
+}
+
+#[test]
+fn contribution_after_expiry() {
+    let (litesvm, _default_payer) = &mut setup();
+    let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+    let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+    let amount_to_raise: u64 = 5_000_000;
+    let duration: u64 = SECONDS_PER_DAY; // 1 day
+    let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+    let vault = get_vault_pda(&fundraise_pda);
+
+    let data = [
+        vec![0u8],
+        amount_to_raise.to_le_bytes().to_vec(),
+        duration.to_le_bytes().to_vec(),
+        0u64.to_le_bytes().to_vec(),
+        0u16.to_le_bytes().to_vec(),
+        1_000u16.to_le_bytes().to_vec(),
+        0u64.to_le_bytes().to_vec(),
+    ]
+    .concat();
+    let ix = Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(maker.pubkey(), true),
+            AccountMeta::new_readonly(mint_to_raise, false),
+            AccountMeta::new(fundraise_pda, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(get_config_pda(), false),
+        ],
+        data,
+    };
+
+    let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+    forward_time(litesvm, duration as i64 + 1);
+
+    let contribute_amount: u64 = 500_000;
+    let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+    let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+    let ix = Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(authority.pubkey(), true),
+            AccountMeta::new_readonly(mint_to_raise, false),
+            AccountMeta::new(fundraise_pda, false),
+            AccountMeta::new(contributor_pda, false),
+            AccountMeta::new(authority_ata, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(get_config_pda(), false),
+        ],
+        data,
+    };
+
+    let res = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+    assert_custom_error(res.unwrap_err(), FundraiserError::FundraiserEnded);
+}
+
+#[test]
+fn wrong_mint_to_raise() {
+    let (litesvm, _default_payer) = &mut setup();
+    let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+    let amount_to_raise: u64 = 5_000_000;
+    let duration: u64 = SECONDS_PER_DAY; // 1 day
+    let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+    let vault = get_vault_pda(&fundraise_pda);
+
+    let data = [
+        vec![0u8],
+        amount_to_raise.to_le_bytes().to_vec(),
+        duration.to_le_bytes().to_vec(),
+        0u64.to_le_bytes().to_vec(),
+        0u16.to_le_bytes().to_vec(),
+        1_000u16.to_le_bytes().to_vec(),
+        0u64.to_le_bytes().to_vec(),
+    ]
+    .concat();
+    let ix = Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(maker.pubkey(), true),
+            AccountMeta::new_readonly(mint_to_raise, false),
+            AccountMeta::new(fundraise_pda, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(get_config_pda(), false),
+        ],
+        data,
+    };
+
+    let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+    // an unrelated mint with a matching contributor token account, against the recorded vault
+    let wrong_mint = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+    let wrong_authority_ata = init_ata(litesvm, wrong_mint, authority.pubkey(), 1_000_000_000);
+
+    let contribute_amount: u64 = 500_000;
+    let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+    let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+    let ix = Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(authority.pubkey(), true),
+            AccountMeta::new_readonly(wrong_mint, false),
+            AccountMeta::new(fundraise_pda, false),
+            AccountMeta::new(contributor_pda, false),
+            AccountMeta::new(wrong_authority_ata, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(get_config_pda(), false),
+        ],
+        data,
+    };
+
+    let res = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+    assert_custom_error(res.unwrap_err(), FundraiserError::InvalidMintToRaise);
+}
+
+#[test]
+fn contribute_requiring_missing_contributor() {
+    let (litesvm, _default_payer) = &mut setup();
+    let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+    let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+    let amount_to_raise: u64 = 5_000_000;
+    let duration: u64 = SECONDS_PER_DAY; // 1 day
+    let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+    let vault = get_vault_pda(&fundraise_pda);
+
+    let data = [
+        vec![0u8],
+        amount_to_raise.to_le_bytes().to_vec(),
+        duration.to_le_bytes().to_vec(),
+        0u64.to_le_bytes().to_vec(),
+        0u16.to_le_bytes().to_vec(),
+        1_000u16.to_le_bytes().to_vec(),
+        0u64.to_le_bytes().to_vec(),
+    ]
+    .concat();
+    let ix = Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(maker.pubkey(), true),
+            AccountMeta::new_readonly(mint_to_raise, false),
+            AccountMeta::new(fundraise_pda, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(get_config_pda(), false),
+        ],
+        data,
+    };
+
+    let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+    // require_existing set, without a CreateContributor beforehand
+    let data = [vec![1u8], 500_000u64.to_le_bytes().to_vec(), vec![0u8, 1u8]].concat();
+    let ix = Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(authority.pubkey(), true),
+            AccountMeta::new_readonly(mint_to_raise, false),
+            AccountMeta::new(fundraise_pda, false),
+            AccountMeta::new(
+                get_contributor_pda(&fundraise_pda, &authority.pubkey()),
+                false,
+            ),
+            AccountMeta::new(authority_ata, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(get_config_pda(), false),
+        ],
+        data,
+    };
+
+    let res = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+    assert_custom_error(res.unwrap_err(), FundraiserError::ContributorNotInitialized);
+}
+
+#[test]
+fn contribution_before_start() {
+    let (litesvm, _default_payer) = &mut setup();
+    let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+    let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+    let amount_to_raise: u64 = 5_000_000;
+    let duration: u64 = SECONDS_PER_DAY; // 1 day
+    let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+    let vault = get_vault_pda(&fundraise_pda);
+    let start_time = litesvm.get_sysvar::<Clock>().unix_timestamp + 3600;
+
+    let data = [
+        vec![0u8],
+        amount_to_raise.to_le_bytes().to_vec(),
+        duration.to_le_bytes().to_vec(),
+        0u64.to_le_bytes().to_vec(),
+        0u16.to_le_bytes().to_vec(),
+        1_000u16.to_le_bytes().to_vec(),
+        0u64.to_le_bytes().to_vec(),
+        vec![0u8, 0u8],
+        start_time.to_le_bytes().to_vec(),
+    ]
+    .concat();
+    let ix = Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(maker.pubkey(), true),
+            AccountMeta::new_readonly(mint_to_raise, false),
+            AccountMeta::new(fundraise_pda, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(get_config_pda(), false),
+        ],
+        data,
+    };
+
+    let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+    forward_time(litesvm, 3599);
+
+    let contribute_amount: u64 = 500_000;
+    let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+    let data = [vec![1u8], contribute_amount.to_le_bytes().to_vec()].concat();
+    let ix = Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(authority.pubkey(), true),
+            AccountMeta::new_readonly(mint_to_raise, false),
+            AccountMeta::new(fundraise_pda, false),
+            AccountMeta::new(contributor_pda, false),
+            AccountMeta::new(authority_ata, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(get_config_pda(), false),
+        ],
+        data,
+    };
+
+    let res = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+    assert_custom_error(res.unwrap_err(), FundraiserError::FundraiserNotStarted);
+}
@@ -0,0 +1,142 @@
+//! Custom errors raised by Donate.
+
+use solana_instruction::{AccountMeta, Instruction};
+use solana_signer::Signer;
+use spl_associated_token_account::solana_program::{
+    clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL,
+};
+
+use crate::{
+    FundraiserError,
+    tests::{
+        constants::{
+            ASSOCIATED_TOKEN_PROGRAM_ID, MINT_DECIMALS, PROGRAM_ID, SYSTEM_PROGRAM_ID,
+            TOKEN_PROGRAM_ID,
+        },
+        pda::{get_config_pda, get_fundraise_pda, get_vault_pda},
+        utils::{
+            assert_custom_error, build_and_send_transaction, forward_time, init_ata, init_mint,
+            init_wallet, setup,
+        },
+    },
+};
+
+#[test]
+fn donate_after_fundraiser_ended() {
+    let (litesvm, _default_payer) = &mut setup();
+    let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let donor = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+    let donor_ata = init_ata(litesvm, mint_to_raise, donor.pubkey(), 1_000_000_000);
+
+    let amount_to_raise: u64 = 5_000_000;
+    let duration: u64 = SECONDS_PER_DAY; // 1 day
+    let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+    let vault = get_vault_pda(&fundraise_pda);
+
+    let data = [
+        vec![0u8],
+        amount_to_raise.to_le_bytes().to_vec(),
+        duration.to_le_bytes().to_vec(),
+        0u64.to_le_bytes().to_vec(),
+        0u16.to_le_bytes().to_vec(),
+        1_000u16.to_le_bytes().to_vec(),
+        0u64.to_le_bytes().to_vec(),
+    ]
+    .concat();
+    let ix = Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(maker.pubkey(), true),
+            AccountMeta::new_readonly(mint_to_raise, false),
+            AccountMeta::new(fundraise_pda, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(get_config_pda(), false),
+        ],
+        data,
+    };
+
+    let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+    forward_time(litesvm, duration as i64 + 1);
+
+    let data = [vec![21u8], 1_000_000u64.to_le_bytes().to_vec()].concat();
+    let ix = Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(donor.pubkey(), true),
+            AccountMeta::new_readonly(mint_to_raise, false),
+            AccountMeta::new(fundraise_pda, false),
+            AccountMeta::new(donor_ata, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data,
+    };
+
+    let res = build_and_send_transaction(litesvm, &[&donor], &donor.pubkey(), &[ix]);
+
+    assert_custom_error(res.unwrap_err(), FundraiserError::FundraiserEnded);
+}
+
+#[test]
+fn donate_nothing() {
+    let (litesvm, _default_payer) = &mut setup();
+    let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let donor = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+    let donor_ata = init_ata(litesvm, mint_to_raise, donor.pubkey(), 1_000_000_000);
+
+    let amount_to_raise: u64 = 5_000_000;
+    let duration: u64 = SECONDS_PER_DAY; // 1 day
+    let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+    let vault = get_vault_pda(&fundraise_pda);
+
+    let data = [
+        vec![0u8],
+        amount_to_raise.to_le_bytes().to_vec(),
+        duration.to_le_bytes().to_vec(),
+        0u64.to_le_bytes().to_vec(),
+        0u16.to_le_bytes().to_vec(),
+        1_000u16.to_le_bytes().to_vec(),
+        0u64.to_le_bytes().to_vec(),
+    ]
+    .concat();
+    let ix = Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(maker.pubkey(), true),
+            AccountMeta::new_readonly(mint_to_raise, false),
+            AccountMeta::new(fundraise_pda, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(get_config_pda(), false),
+        ],
+        data,
+    };
+
+    let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+    let data = [vec![21u8], 0u64.to_le_bytes().to_vec()].concat();
+    let ix = Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(donor.pubkey(), true),
+            AccountMeta::new_readonly(mint_to_raise, false),
+            AccountMeta::new(fundraise_pda, false),
+            AccountMeta::new(donor_ata, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+        ],
+        data,
+    };
+
+    let res = build_and_send_transaction(litesvm, &[&donor], &donor.pubkey(), &[ix]);
+
+    assert_custom_error(res.unwrap_err(), FundraiserError::InvalidAmount);
+}
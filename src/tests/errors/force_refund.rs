@@ -0,0 +1,137 @@
+//! Custom errors raised by ForceRefund.
+
+use solana_instruction::{AccountMeta, Instruction};
+use solana_signer::Signer;
+use spl_associated_token_account::{
+    get_associated_token_address_with_program_id,
+    solana_program::{clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL},
+};
+
+use crate::{
+    FundraiserError,
+    tests::{
+        constants::{
+            ASSOCIATED_TOKEN_PROGRAM_ID, FEE_COLLECTOR, MINT_DECIMALS, PROGRAM_ID,
+            SYSTEM_PROGRAM_ID, TOKEN_PROGRAM_ID,
+        },
+        pda::{get_config_pda, get_contributor_pda, get_fundraise_pda, get_vault_pda},
+        utils::{
+            assert_custom_error, build_and_send_transaction, init_ata, init_mint, init_wallet,
+            setup,
+        },
+    },
+};
+
+#[test]
+fn force_refund_after_claim() {
+    let (litesvm, _default_payer) = &mut setup();
+    let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+    let authority_ata = init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+    let amount_to_raise: u64 = 5_000_000;
+    let duration: u64 = SECONDS_PER_DAY; // 1 day
+    let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+    let vault = get_vault_pda(&fundraise_pda);
+
+    let data = [
+        vec![0u8],
+        amount_to_raise.to_le_bytes().to_vec(),
+        duration.to_le_bytes().to_vec(),
+        0u64.to_le_bytes().to_vec(),
+        0u16.to_le_bytes().to_vec(),
+        10_000u16.to_le_bytes().to_vec(),
+        0u64.to_le_bytes().to_vec(),
+    ]
+    .concat();
+    let ix = Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(maker.pubkey(), true),
+            AccountMeta::new_readonly(mint_to_raise, false),
+            AccountMeta::new(fundraise_pda, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(get_config_pda(), false),
+        ],
+        data,
+    };
+
+    let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+    let contributor_pda = get_contributor_pda(&fundraise_pda, &authority.pubkey());
+
+    let data = [vec![1u8], amount_to_raise.to_le_bytes().to_vec()].concat();
+    let ix = Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(authority.pubkey(), true),
+            AccountMeta::new_readonly(mint_to_raise, false),
+            AccountMeta::new(fundraise_pda, false),
+            AccountMeta::new(contributor_pda, false),
+            AccountMeta::new(authority_ata, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(get_config_pda(), false),
+        ],
+        data,
+    };
+
+    let _ = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+    let maker_ata = init_ata(litesvm, mint_to_raise, maker.pubkey(), 0);
+
+    let fee_collector_ata = get_associated_token_address_with_program_id(
+        &FEE_COLLECTOR,
+        &mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+    );
+
+    let data = vec![3u8];
+    let ix = Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(maker.pubkey(), true),
+            AccountMeta::new_readonly(mint_to_raise, false),
+            AccountMeta::new(fundraise_pda, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new(maker_ata, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(get_config_pda(), false),
+            AccountMeta::new_readonly(FEE_COLLECTOR, false),
+            AccountMeta::new(fee_collector_ata, false),
+            AccountMeta::new(maker.pubkey(), false),
+        ],
+        data,
+    };
+
+    let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+    let data = vec![25u8];
+    let ix = Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(maker.pubkey(), true),
+            AccountMeta::new(authority.pubkey(), false),
+            AccountMeta::new_readonly(mint_to_raise, false),
+            AccountMeta::new(fundraise_pda, false),
+            AccountMeta::new(contributor_pda, false),
+            AccountMeta::new(authority_ata, false),
+            AccountMeta::new(vault, false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+        ],
+        data,
+    };
+
+    let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+    assert_custom_error(res.unwrap_err(), FundraiserError::AlreadyClaimed);
+}
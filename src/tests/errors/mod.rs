@@ -0,0 +1,9 @@
+//! Negative paths, one module per instruction, each asserting the exact custom error code.
+
+mod claim;
+mod contribute;
+mod donate;
+mod extend;
+mod force_refund;
+mod refund;
+mod transfer_contribution;
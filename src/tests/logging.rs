@@ -0,0 +1,72 @@
+//! Entrypoint logs, present when the program is built with the default `logging` feature.
+
+use solana_signer::Signer;
+use spl_associated_token_account::solana_program::{
+    clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL,
+};
+
+use crate::{
+    client,
+    tests::{
+        constants::{MINT_DECIMALS, TOKEN_PROGRAM_ID},
+        utils::{build_and_send_transaction, init_ata, init_mint, init_wallet, setup},
+    },
+};
+
+fn assert_logged(logs: &[String], line: &str) {
+    let line = format!("Program log: {line}");
+
+    assert!(logs.contains(&line), "{line:?} not in {logs:#?}");
+}
+
+#[test]
+fn logs_instruction_name_on_success() {
+    let (litesvm, _default_payer) = &mut setup();
+    let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+    let ix = client::initialize(
+        &maker.pubkey(),
+        &mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+        5_000_000,
+        SECONDS_PER_DAY,
+    );
+
+    let res = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+    assert_logged(&res.logs, "Instruction: Initialize");
+}
+
+#[test]
+fn logs_instruction_name_and_error_on_failure() {
+    let (litesvm, _default_payer) = &mut setup();
+    let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let authority = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+    init_ata(litesvm, mint_to_raise, authority.pubkey(), 1_000_000_000);
+
+    let ix = client::initialize(
+        &maker.pubkey(),
+        &mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+        5_000_000,
+        SECONDS_PER_DAY,
+    );
+
+    let _ = build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]);
+
+    let ix = client::contribute(
+        &authority.pubkey(),
+        &maker.pubkey(),
+        &mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+        0,
+    );
+
+    let res = build_and_send_transaction(litesvm, &[&authority], &authority.pubkey(), &[ix]);
+    let logs = res.unwrap_err().meta.logs;
+
+    assert_logged(&logs, "Instruction: Contribute");
+    assert_logged(&logs, "The contribution is too small");
+}
@@ -1,8 +1,35 @@
-#[cfg(test)]
 pub mod constants;
 
 #[cfg(test)]
+mod bench;
+
+#[cfg(test)]
+mod bond;
+
+#[cfg(test)]
+mod errors;
+
+#[cfg(test)]
+mod logging;
+
 pub mod pda;
 
 #[cfg(test)]
+mod multisig;
+
+#[cfg(test)]
+mod pda_maker;
+
+#[cfg(test)]
+mod properties;
+
 pub mod utils;
+
+#[cfg(test)]
+pub mod replay;
+
+#[cfg(test)]
+mod transfer_hook;
+
+#[cfg(test)]
+mod wrapped_sol;
@@ -0,0 +1,169 @@
+//! Contributions from a token account owned by a 2-of-3 SPL token multisig, signed for by its
+//! signers instead of by the multisig itself.
+
+use solana_instruction::{AccountMeta, Instruction};
+use solana_keypair::Keypair;
+use solana_pubkey::Pubkey;
+use solana_signer::Signer;
+use spl_associated_token_account::solana_program::{
+    clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL,
+};
+use spl_token_2022::state::Account;
+
+use crate::{
+    AccountLoad, Contributor, FundraiserError, client,
+    tests::{
+        constants::{MINT_DECIMALS, TOKEN_PROGRAM_ID},
+        utils::{
+            assert_error, build_and_send_transaction, fetch_account, init_ata, init_mint,
+            init_multisig, init_wallet, setup,
+        },
+    },
+};
+
+struct Treasury {
+    maker: Pubkey,
+    mint_to_raise: Pubkey,
+    multisig: Pubkey,
+    multisig_ata: Pubkey,
+    signers: [Keypair; 3],
+}
+
+fn treasury(litesvm: &mut litesvm::LiteSVM) -> Treasury {
+    let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+    let signers = [
+        init_wallet(litesvm, LAMPORTS_PER_SOL),
+        init_wallet(litesvm, LAMPORTS_PER_SOL),
+        init_wallet(litesvm, LAMPORTS_PER_SOL),
+    ];
+    let multisig = init_multisig(
+        litesvm,
+        TOKEN_PROGRAM_ID,
+        2,
+        &signers.each_ref().map(|signer| signer.pubkey()),
+    );
+    let multisig_ata = init_ata(litesvm, mint_to_raise, multisig, 1_000_000);
+
+    let ix = client::initialize(
+        &maker.pubkey(),
+        &mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+        5_000_000,
+        SECONDS_PER_DAY,
+    );
+    build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+    Treasury {
+        maker: maker.pubkey(),
+        mint_to_raise,
+        multisig,
+        multisig_ata,
+        signers,
+    }
+}
+
+impl Treasury {
+    /// Contribution of `amount` signed by `signers`, with the first of them paying the rent.
+    fn contribute(&self, signers: &[&Keypair], amount: u64) -> Instruction {
+        let mut ix = client::with_multisig_signers(
+            client::contribute(
+                &self.multisig,
+                &self.maker,
+                &self.mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+                amount,
+            ),
+            &signers
+                .iter()
+                .map(|signer| signer.pubkey())
+                .collect::<Vec<_>>(),
+        );
+        ix.accounts
+            .push(AccountMeta::new(signers[0].pubkey(), true));
+        ix
+    }
+}
+
+#[test]
+fn contribute_and_refund_from_multisig() {
+    let (litesvm, _default_payer) = &mut setup();
+    let treasury = treasury(litesvm);
+    let [first, _, third] = &treasury.signers;
+
+    let ix = treasury.contribute(&[first, third], 500_000);
+    build_and_send_transaction(litesvm, &[first, third], &first.pubkey(), &[ix]).unwrap();
+
+    // the position belongs to the multisig, with its rent owed back to the signer who paid it
+    let fundraise = client::fundraise_address(&treasury.maker);
+    let contributor = client::contributor_address(&fundraise, &treasury.multisig);
+    let contributor_acc = litesvm.get_account(&contributor).unwrap();
+    let state = Contributor::load(&contributor_acc.data).unwrap();
+
+    assert_eq!(state.get_amount(), 500_000);
+    assert_eq!(state.rent_payer, first.pubkey().to_bytes());
+    assert_eq!(
+        fetch_account::<Account>(litesvm, &treasury.multisig_ata).amount,
+        500_000
+    );
+
+    let ix = client::with_rent_destination(
+        client::with_multisig_signers(
+            client::refund(
+                &treasury.multisig,
+                &treasury.maker,
+                &treasury.mint_to_raise,
+                &TOKEN_PROGRAM_ID,
+            ),
+            &[first.pubkey(), third.pubkey()],
+        ),
+        &first.pubkey(),
+    );
+    build_and_send_transaction(litesvm, &[first, third], &first.pubkey(), &[ix]).unwrap();
+
+    assert!(litesvm.get_account(&contributor).is_none());
+    assert_eq!(
+        fetch_account::<Account>(litesvm, &treasury.multisig_ata).amount,
+        1_000_000
+    );
+}
+
+#[test]
+fn throw_if_multisig_signers_below_threshold() {
+    let (litesvm, _default_payer) = &mut setup();
+    let treasury = treasury(litesvm);
+    let [first, _, _] = &treasury.signers;
+
+    let ix = treasury.contribute(&[first], 500_000);
+    let res = build_and_send_transaction(litesvm, &[first], &first.pubkey(), &[ix]);
+
+    assert_error(res.unwrap_err(), FundraiserError::NotEnoughMultisigSigners);
+
+    // a signer outside the multisig does not count towards it
+    let outsider = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let ix = treasury.contribute(&[first, &outsider], 500_000);
+    let res = build_and_send_transaction(litesvm, &[first, &outsider], &first.pubkey(), &[ix]);
+
+    assert_error(res.unwrap_err(), FundraiserError::NotEnoughMultisigSigners);
+}
+
+#[test]
+fn throw_if_multisig_contributes_without_rent_payer() {
+    let (litesvm, _default_payer) = &mut setup();
+    let treasury = treasury(litesvm);
+    let [first, ..] = &treasury.signers;
+
+    let ix = client::with_multisig_signers(
+        client::contribute(
+            &treasury.multisig,
+            &treasury.maker,
+            &treasury.mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            500_000,
+        ),
+        &[],
+    );
+    let res = build_and_send_transaction(litesvm, &[first], &first.pubkey(), &[ix]);
+
+    assert_error(res.unwrap_err(), FundraiserError::MissingRentPayer);
+}
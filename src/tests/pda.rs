@@ -1,6 +1,9 @@
 use solana_pubkey::Pubkey;
 
-use crate::{Contributor, Fundraise, Prefix, tests::constants::PROGRAM_ID};
+use crate::{
+    AllowlistPage, AllowlistReceipt, BPF_LOADER_UPGRADEABLE_ID, Config, Contributor, Fundraise,
+    HOOK_AUTHORITY_SEED, Prefix, Registry, Trigger, VAULT_SEED, tests::constants::PROGRAM_ID,
+};
 
 pub fn get_fundraise_pda(maker: &Pubkey) -> Pubkey {
     Pubkey::find_program_address(&[Fundraise::PREFIX, maker.as_ref()], &PROGRAM_ID).0
@@ -13,3 +16,55 @@ pub fn get_contributor_pda(fundraise: &Pubkey, authority: &Pubkey) -> Pubkey {
     )
     .0
 }
+
+pub fn get_allowlist_page_pda(fundraise: &Pubkey, page_index: u16) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            AllowlistPage::PREFIX,
+            fundraise.as_ref(),
+            &page_index.to_le_bytes(),
+        ],
+        &PROGRAM_ID,
+    )
+    .0
+}
+
+pub fn get_allowlist_receipt_pda(fundraise: &Pubkey, wallet: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            AllowlistReceipt::PREFIX,
+            fundraise.as_ref(),
+            wallet.as_ref(),
+        ],
+        &PROGRAM_ID,
+    )
+    .0
+}
+
+pub fn get_trigger_pda(fundraise: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[Trigger::PREFIX, fundraise.as_ref()], &PROGRAM_ID).0
+}
+
+pub fn get_hook_authority_pda(fundraise: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[HOOK_AUTHORITY_SEED, fundraise.as_ref()], &PROGRAM_ID).0
+}
+
+pub fn get_vault_pda(fundraise: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[VAULT_SEED, fundraise.as_ref()], &PROGRAM_ID).0
+}
+
+pub fn get_config_pda() -> Pubkey {
+    Pubkey::find_program_address(&[Config::PREFIX], &PROGRAM_ID).0
+}
+
+pub fn get_program_data_pda() -> Pubkey {
+    Pubkey::find_program_address(
+        &[PROGRAM_ID.as_ref()],
+        &Pubkey::new_from_array(BPF_LOADER_UPGRADEABLE_ID),
+    )
+    .0
+}
+
+pub fn get_registry_pda(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[Registry::PREFIX, mint.as_ref()], &PROGRAM_ID).0
+}
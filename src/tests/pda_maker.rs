@@ -0,0 +1,153 @@
+//! Fundraises whose maker is a PDA of another program, which signs Initialize and Claim through
+//! CPI while a keypair in the outer transaction pays for what they create.
+
+use litesvm::LiteSVM;
+use solana_instruction::{AccountMeta, Instruction};
+use solana_keypair::Keypair;
+use solana_pubkey::Pubkey;
+use solana_signer::Signer;
+use spl_associated_token_account::solana_program::{
+    clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL,
+};
+use spl_token_2022::state::Account;
+
+use crate::{
+    Fundraise, client,
+    tests::{
+        constants::{FEE_COLLECTOR, MINT_DECIMALS, PDA_MAKER_PROGRAM_ID, TOKEN_PROGRAM_ID},
+        utils::{
+            build_and_send_transaction, fetch_account, init_ata, init_config, init_mint,
+            init_pda_maker, init_wallet, setup,
+        },
+    },
+};
+
+const AMOUNT_TO_RAISE: u64 = 5_000_000;
+
+/// `ix` sent through the PDA maker program, which signs for `maker` wherever it appears.
+fn through_pda_maker(ix: Instruction, maker: &Pubkey) -> Instruction {
+    let accounts = [AccountMeta::new_readonly(client::program_id(), false)]
+        .into_iter()
+        .chain(ix.accounts.into_iter().map(|meta| AccountMeta {
+            is_signer: meta.is_signer && meta.pubkey != *maker,
+            ..meta
+        }))
+        .collect();
+
+    Instruction {
+        program_id: PDA_MAKER_PROGRAM_ID,
+        accounts,
+        data: ix.data,
+    }
+}
+
+fn initialize(litesvm: &mut LiteSVM, maker: &Pubkey, payer: &Keypair) -> Pubkey {
+    let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+
+    let ix = client::with_rent_payer(
+        client::initialize(
+            maker,
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            AMOUNT_TO_RAISE,
+            SECONDS_PER_DAY,
+        ),
+        &payer.pubkey(),
+    );
+    build_and_send_transaction(
+        litesvm,
+        &[payer],
+        &payer.pubkey(),
+        &[through_pda_maker(ix, maker)],
+    )
+    .unwrap();
+
+    mint_to_raise
+}
+
+fn meet_target(litesvm: &mut LiteSVM, maker: &Pubkey, mint_to_raise: Pubkey) {
+    let backer = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    init_ata(litesvm, mint_to_raise, backer.pubkey(), AMOUNT_TO_RAISE);
+
+    let ix = client::contribute(
+        &backer.pubkey(),
+        maker,
+        &mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+        AMOUNT_TO_RAISE,
+    );
+    build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]).unwrap();
+}
+
+#[test]
+fn initialize_with_pda_maker() {
+    let (litesvm, _default_payer) = &mut setup();
+    let maker = init_pda_maker(litesvm);
+    let payer = init_wallet(litesvm, LAMPORTS_PER_SOL);
+
+    initialize(litesvm, &maker, &payer);
+
+    let fundraise = client::fundraise_address(&maker);
+    let fundraise = Fundraise::unpack(&litesvm.get_account(&fundraise).unwrap().data).unwrap();
+
+    assert_eq!(fundraise.maker, maker.to_bytes());
+    assert_eq!(fundraise.claim_authority, maker.to_bytes());
+    // the maker never held lamports
+    assert!(
+        litesvm
+            .get_account(&maker)
+            .is_none_or(|maker| maker.lamports == 0)
+    );
+}
+
+#[test]
+fn claim_with_pda_maker() {
+    let (litesvm, _default_payer) = &mut setup();
+    let maker = init_pda_maker(litesvm);
+    let payer = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let mint_to_raise = initialize(litesvm, &maker, &payer);
+
+    meet_target(litesvm, &maker, mint_to_raise);
+    init_config(litesvm, payer.pubkey(), 100, FEE_COLLECTOR, false);
+
+    // the payer creates both the maker's and the fee collector's token accounts
+    let ix = client::with_rent_payer(
+        client::claim(&maker, &mint_to_raise, &TOKEN_PROGRAM_ID, &FEE_COLLECTOR),
+        &payer.pubkey(),
+    );
+    build_and_send_transaction(
+        litesvm,
+        &[&payer],
+        &payer.pubkey(),
+        &[through_pda_maker(ix, &maker)],
+    )
+    .unwrap();
+
+    let maker_ata = client::associated_token_address(&maker, &mint_to_raise, &TOKEN_PROGRAM_ID);
+
+    assert_eq!(
+        fetch_account::<Account>(litesvm, &maker_ata).amount,
+        AMOUNT_TO_RAISE - AMOUNT_TO_RAISE / 100
+    );
+}
+
+#[test]
+fn throw_if_pda_maker_pays_for_claim() {
+    let (litesvm, _default_payer) = &mut setup();
+    let maker = init_pda_maker(litesvm);
+    let payer = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let mint_to_raise = initialize(litesvm, &maker, &payer);
+
+    meet_target(litesvm, &maker, mint_to_raise);
+
+    // without a rent payer the maker has to fund its own token account, which it cannot
+    let ix = client::claim(&maker, &mint_to_raise, &TOKEN_PROGRAM_ID, &FEE_COLLECTOR);
+    let res = build_and_send_transaction(
+        litesvm,
+        &[&payer],
+        &payer.pubkey(),
+        &[through_pda_maker(ix, &maker)],
+    );
+
+    assert!(res.is_err());
+}
@@ -0,0 +1,739 @@
+//! Property tests for the instruction data parsers and the contribution cap. Each property runs
+//! over `CASES` inputs drawn from a fixed seed, biased towards boundary values, so a failure
+//! reproduces on every run.
+
+use pinocchio::program_error::ProgramError;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+use crate::{
+    CampaignType, ContributeInstructionData, FundraiserError, InitializeInstructionData, MAX_BPS,
+    MAX_NAME_LEN, MAX_TIERS, MAX_URI_LEN, RefundPolicy, UpdateMetadataInstructionData, Versioned,
+    check_contribution_cap, max_contribution,
+};
+
+const CASES: usize = 10_000;
+const SEED: u64 = 0x6675_6e64_7261_6973;
+
+/// Fields of Initialize before the optional trailing flags.
+const INITIALIZE_REQUIRED_LEN: usize = InitializeInstructionData::LEN - 183;
+
+fn rng() -> StdRng {
+    StdRng::seed_from_u64(SEED)
+}
+
+fn any_u64(rng: &mut StdRng) -> u64 {
+    match rng.gen_range(0..4) {
+        0 => [0, 1, u64::MAX - 1, u64::MAX][rng.gen_range(0..4)],
+        1 => rng.gen_range(0..=u64::from(u32::MAX)),
+        _ => rng.r#gen(),
+    }
+}
+
+fn any_bps(rng: &mut StdRng) -> u16 {
+    match rng.gen_range(0..4) {
+        0 => [0, 1, MAX_BPS - 1, MAX_BPS][rng.gen_range(0..4)],
+        _ => rng.gen_range(0..=MAX_BPS),
+    }
+}
+
+fn any_bytes(rng: &mut StdRng) -> Vec<u8> {
+    let len = rng.gen_range(0..=InitializeInstructionData::LEN + 8);
+
+    (0..len).map(|_| rng.r#gen()).collect()
+}
+
+fn any_initialize_data(rng: &mut StdRng) -> InitializeInstructionData<'static> {
+    let duration = any_u64(rng);
+    // a keep-it-all fundraise cannot lock its contributions
+    let (refund_policy, campaign_type) = [
+        (RefundPolicy::Flexible, CampaignType::AllOrNothing),
+        (RefundPolicy::Locked, CampaignType::AllOrNothing),
+        (RefundPolicy::Flexible, CampaignType::KeepItAll),
+    ][rng.gen_range(0..3)];
+    // rewards come as a mint and an amount together, or not at all
+    let (reward_mint, reward_amount) = match rng.r#gen() {
+        true => (
+            [rng.gen_range(1..=u8::MAX); 32],
+            rng.gen_range(1..=u64::MAX),
+        ),
+        false => ([0; 32], 0),
+    };
+
+    InitializeInstructionData {
+        amount_to_raise: any_u64(rng),
+        duration,
+        completion_window: rng.gen_range(0..=duration),
+        completion_threshold_bps: any_bps(rng),
+        max_contribution_bps: any_bps(rng),
+        min_contribution: any_u64(rng),
+        allowlist: rng.r#gen(),
+        keeper_trigger: rng.r#gen(),
+        start_time: rng.r#gen(),
+        refund_policy,
+        campaign_type,
+        merkle_root: rng.r#gen(),
+        register: rng.r#gen(),
+        tier_thresholds: any_tier_thresholds(rng),
+        reward_mint,
+        reward_amount,
+        receipt: rng.r#gen(),
+        gate_mint: rng.r#gen(),
+        accept_freezable: rng.r#gen(),
+        claim_authority: rng.r#gen(),
+        name: &[],
+        uri: &[],
+    }
+}
+
+/// Between none and `MAX_TIERS` strictly ascending thresholds, padded with zeros.
+fn any_tier_thresholds(rng: &mut StdRng) -> [u64; MAX_TIERS] {
+    let mut thresholds = [0; MAX_TIERS];
+    let mut floor = 0;
+
+    for threshold in thresholds.iter_mut().take(rng.gen_range(0..=MAX_TIERS)) {
+        floor = rng.gen_range(floor + 1..=floor + u64::from(u32::MAX));
+        *threshold = floor;
+    }
+
+    thresholds
+}
+
+#[test]
+fn initialize_data_round_trips() {
+    let rng = &mut rng();
+
+    for _ in 0..CASES {
+        let data = any_initialize_data(rng);
+        let bytes = data.to_bytes();
+        let parsed = InitializeInstructionData::try_from(&bytes[..]).unwrap();
+
+        assert_eq!(parsed.to_bytes(), bytes);
+        assert!(parsed.name.is_empty() && parsed.uri.is_empty());
+
+        // a name and URI follow the claim authority
+        let name = "n".repeat(rng.gen_range(0..=MAX_NAME_LEN));
+        let uri = "u".repeat(rng.gen_range(0..=MAX_URI_LEN));
+        let (metadata, len) = UpdateMetadataInstructionData {
+            name: name.as_bytes(),
+            uri: uri.as_bytes(),
+        }
+        .to_bytes();
+        let with_metadata = [&bytes[..], &metadata[..len]].concat();
+        let parsed = InitializeInstructionData::try_from(&with_metadata[..]).unwrap();
+
+        assert_eq!(parsed.to_bytes(), bytes);
+        assert_eq!((parsed.name, parsed.uri), (name.as_bytes(), uri.as_bytes()));
+
+        // dropping the claim authority leaves the claim to the maker
+        let parsed =
+            InitializeInstructionData::try_from(&bytes[..INITIALIZE_REQUIRED_LEN + 151]).unwrap();
+
+        assert_eq!(parsed.accept_freezable, data.accept_freezable);
+        assert_eq!(parsed.claim_authority, [0; 32]);
+
+        // dropping the freezable flag rejects a mint with a freeze authority
+        let parsed =
+            InitializeInstructionData::try_from(&bytes[..INITIALIZE_REQUIRED_LEN + 150]).unwrap();
+
+        assert_eq!(parsed.gate_mint, data.gate_mint);
+        assert!(!parsed.accept_freezable);
+
+        // dropping the gate mint admits anyone
+        let parsed =
+            InitializeInstructionData::try_from(&bytes[..INITIALIZE_REQUIRED_LEN + 118]).unwrap();
+
+        assert_eq!(parsed.receipt, data.receipt);
+        assert_eq!(parsed.gate_mint, [0; 32]);
+
+        // dropping the receipt flag issues no receipts
+        let parsed =
+            InitializeInstructionData::try_from(&bytes[..INITIALIZE_REQUIRED_LEN + 117]).unwrap();
+
+        assert_eq!(parsed.reward_amount, data.reward_amount);
+        assert!(!parsed.receipt);
+
+        // dropping the reward mint and amount leaves the fundraise without rewards
+        let parsed =
+            InitializeInstructionData::try_from(&bytes[..INITIALIZE_REQUIRED_LEN + 77]).unwrap();
+
+        assert_eq!(parsed.tier_thresholds, data.tier_thresholds);
+        assert_eq!((parsed.reward_mint, parsed.reward_amount), ([0; 32], 0));
+
+        // dropping the tier thresholds leaves the fundraise without tiers
+        let parsed =
+            InitializeInstructionData::try_from(&bytes[..INITIALIZE_REQUIRED_LEN + 45]).unwrap();
+
+        assert_eq!(parsed.register, data.register);
+        assert_eq!(parsed.tier_thresholds, [0; MAX_TIERS]);
+
+        // dropping the registry flag leaves the fundraise unregistered
+        let parsed =
+            InitializeInstructionData::try_from(&bytes[..INITIALIZE_REQUIRED_LEN + 44]).unwrap();
+
+        assert_eq!(parsed.merkle_root, data.merkle_root);
+        assert!(!parsed.register);
+
+        // dropping the Merkle root opens the fundraise to everyone
+        let parsed =
+            InitializeInstructionData::try_from(&bytes[..INITIALIZE_REQUIRED_LEN + 12]).unwrap();
+
+        assert_eq!(parsed.campaign_type, data.campaign_type);
+        assert_eq!(parsed.merkle_root, [0; 32]);
+
+        // dropping the campaign type parses as all-or-nothing
+        let parsed =
+            InitializeInstructionData::try_from(&bytes[..INITIALIZE_REQUIRED_LEN + 11]).unwrap();
+
+        assert_eq!(parsed.refund_policy, data.refund_policy);
+        assert_eq!(parsed.campaign_type, CampaignType::AllOrNothing);
+
+        // dropping the refund policy parses as flexible
+        let parsed =
+            InitializeInstructionData::try_from(&bytes[..INITIALIZE_REQUIRED_LEN + 10]).unwrap();
+
+        assert_eq!(parsed.start_time, data.start_time);
+        assert_eq!(parsed.refund_policy, RefundPolicy::Flexible);
+
+        // dropping the start time parses as starting now
+        let parsed =
+            InitializeInstructionData::try_from(&bytes[..INITIALIZE_REQUIRED_LEN + 2]).unwrap();
+
+        assert_eq!(parsed.keeper_trigger, data.keeper_trigger);
+        assert_eq!(parsed.start_time, 0);
+
+        // dropping the trailing flags parses as both unset
+        let parsed =
+            InitializeInstructionData::try_from(&bytes[..INITIALIZE_REQUIRED_LEN]).unwrap();
+
+        assert_eq!(parsed.amount_to_raise, data.amount_to_raise);
+        assert_eq!(parsed.duration, data.duration);
+        assert!(!parsed.allowlist && !parsed.keeper_trigger);
+    }
+}
+
+#[test]
+fn contribute_data_round_trips() {
+    let rng = &mut rng();
+
+    for _ in 0..CASES {
+        let data = ContributeInstructionData {
+            amount: any_u64(rng),
+            reject_duplicates: rng.r#gen(),
+            require_existing: rng.r#gen(),
+            clamp: rng.r#gen(),
+            referrer: None,
+            proof: &[],
+        };
+        let bytes = data.to_bytes();
+        let parsed = ContributeInstructionData::try_from(&bytes[..]).unwrap();
+
+        assert_eq!(parsed.to_bytes(), bytes);
+
+        // a referrer leads any proof
+        let referrer: [u8; 32] = rng.r#gen();
+        let proof: Vec<u8> = (0..rng.gen_range(0..4) * 32).map(|_| rng.r#gen()).collect();
+        let referred = [&bytes[..], &[1], &referrer, &proof].concat();
+        let parsed = ContributeInstructionData::try_from(&referred[..]).unwrap();
+
+        assert_eq!(parsed.referrer, Some(referrer));
+        assert_eq!(parsed.proof, &proof[..]);
+
+        // each trailing flag parses as unset once dropped
+        let parsed = ContributeInstructionData::try_from(&bytes[..10]).unwrap();
+
+        assert_eq!(parsed.require_existing, data.require_existing);
+        assert!(!parsed.clamp);
+
+        let parsed = ContributeInstructionData::try_from(&bytes[..9]).unwrap();
+
+        assert_eq!(parsed.reject_duplicates, data.reject_duplicates);
+        assert!(!parsed.require_existing);
+
+        let parsed = ContributeInstructionData::try_from(&bytes[..size_of::<u64>()]).unwrap();
+
+        assert_eq!(parsed.amount, data.amount);
+        assert!(!parsed.reject_duplicates && !parsed.require_existing);
+    }
+}
+
+#[test]
+fn initialize_data_rejects_other_lengths() {
+    let rng = &mut rng();
+
+    for _ in 0..CASES {
+        let bytes = any_bytes(rng);
+        let res = InitializeInstructionData::try_from(&bytes[..]);
+
+        // a name and URI may follow the full payload
+        let metadata = bytes
+            .get(InitializeInstructionData::LEN..)
+            .unwrap_or_default();
+
+        if !(INITIALIZE_REQUIRED_LEN..=InitializeInstructionData::LEN).contains(&bytes.len())
+            && UpdateMetadataInstructionData::try_from(metadata).is_err()
+        {
+            assert_eq!(
+                res.err(),
+                Some(ProgramError::InvalidInstructionData),
+                "{} bytes",
+                bytes.len()
+            );
+        }
+    }
+}
+
+#[test]
+fn initialize_data_rejects_partial_fields() {
+    // where each optional field ends, past the required ones
+    const FIELD_ENDS: [usize; 14] = [0, 1, 2, 10, 11, 12, 44, 45, 77, 117, 118, 150, 151, 183];
+
+    let rng = &mut rng();
+
+    for _ in 0..CASES / 100 {
+        let bytes = any_initialize_data(rng).to_bytes();
+
+        for len in INITIALIZE_REQUIRED_LEN..=InitializeInstructionData::LEN {
+            let res = InitializeInstructionData::try_from(&bytes[..len]);
+
+            match FIELD_ENDS.contains(&(len - INITIALIZE_REQUIRED_LEN)) {
+                true => assert!(res.is_ok(), "{len} bytes"),
+                false => assert_eq!(
+                    res.err(),
+                    Some(ProgramError::InvalidInstructionData),
+                    "{len} bytes"
+                ),
+            }
+        }
+    }
+}
+
+#[test]
+fn initialize_data_rejects_unordered_tiers() {
+    let rng = &mut rng();
+
+    for _ in 0..CASES {
+        let mut data = any_initialize_data(rng);
+        data.completion_window = 0;
+        data.completion_threshold_bps = 0;
+        data.max_contribution_bps = 0;
+
+        let defined = data.tier_thresholds.iter().filter(|&&t| t != 0).count();
+
+        // a gap before a defined tier, or a tier no higher than the one before it
+        match rng.gen_range(0..2) {
+            0 if defined < MAX_TIERS - 1 => {
+                data.tier_thresholds[MAX_TIERS - 1] = rng.gen_range(1..=u64::MAX);
+            }
+            _ if defined >= 2 => {
+                let i = rng.gen_range(1..defined);
+                data.tier_thresholds[i] = rng.gen_range(1..=data.tier_thresholds[i - 1]);
+            }
+            _ => continue,
+        }
+
+        let bytes = data.to_bytes();
+        let res = InitializeInstructionData::try_from(&bytes[..]);
+
+        assert_eq!(
+            res.err(),
+            Some(FundraiserError::InvalidAmount.into()),
+            "{:?}",
+            data.tier_thresholds
+        );
+    }
+}
+
+#[test]
+fn contribute_data_rejects_other_lengths() {
+    let rng = &mut rng();
+
+    for _ in 0..CASES {
+        let bytes = any_bytes(rng);
+        let res = ContributeInstructionData::try_from(&bytes[..]);
+
+        match bytes.len() {
+            8 => assert!(res.is_ok()),
+            9 => assert_eq!(res.is_ok(), bytes[8] <= 1),
+            10 => assert_eq!(res.is_ok(), bytes[8] <= 1 && bytes[9] <= 1),
+            // a referrer leads whatever past the flags is one byte over whole proof nodes
+            len @ 11.. if (len - 11) % 32 == 1 => {
+                assert_eq!(
+                    res.is_ok(),
+                    bytes[8..11].iter().all(|&flag| flag <= 1) && bytes[11] == 1 && len >= 44
+                );
+
+                if let Ok(data) = res {
+                    assert_eq!(data.referrer.map(Vec::from), Some(bytes[12..44].to_vec()));
+                    assert_eq!(data.proof, &bytes[44..]);
+                }
+            }
+            // and anything else past them is taken as the Merkle proof
+            11.. => {
+                assert_eq!(res.is_ok(), bytes[8..11].iter().all(|&flag| flag <= 1));
+
+                if let Ok(data) = res {
+                    assert!(data.referrer.is_none());
+                    assert_eq!(data.proof, &bytes[11..]);
+                }
+            }
+            len => assert_eq!(
+                res.err(),
+                Some(ProgramError::InvalidInstructionData),
+                "{len} bytes"
+            ),
+        }
+    }
+}
+
+fn any_contribute_data<'a>(rng: &mut StdRng, proof: &'a [u8]) -> ContributeInstructionData<'a> {
+    ContributeInstructionData {
+        amount: any_u64(rng),
+        reject_duplicates: rng.r#gen(),
+        require_existing: rng.r#gen(),
+        clamp: rng.r#gen(),
+        referrer: rng.r#gen::<bool>().then(|| rng.r#gen()),
+        proof,
+    }
+}
+
+fn any_proof(rng: &mut StdRng) -> Vec<u8> {
+    (0..rng.gen_range(0..4) * 32).map(|_| rng.r#gen()).collect()
+}
+
+#[test]
+fn initialize_v1_data_round_trips() {
+    let rng = &mut rng();
+
+    for _ in 0..CASES {
+        let name = "n".repeat(rng.gen_range(0..=MAX_NAME_LEN));
+        let uri = "u".repeat(rng.gen_range(0..=MAX_URI_LEN));
+        let data = InitializeInstructionData {
+            name: name.as_bytes(),
+            uri: uri.as_bytes(),
+            ..any_initialize_data(rng)
+        };
+        let bytes = data.to_v1_bytes();
+        let parsed = InitializeInstructionData::try_from(Versioned(&bytes)).unwrap();
+
+        assert_eq!(parsed.to_bytes(), data.to_bytes());
+        assert_eq!((parsed.name, parsed.uri), (name.as_bytes(), uri.as_bytes()));
+        assert_eq!(parsed.to_v1_bytes(), bytes);
+    }
+
+    // only the fields set are sent
+    let bytes = InitializeInstructionData::new(5_000_000, 86_400).to_v1_bytes();
+
+    assert_eq!(bytes.len(), 1 + 2 + INITIALIZE_REQUIRED_LEN);
+    assert_eq!(bytes[..3], [Versioned::V1, 0, 0]);
+}
+
+#[test]
+fn initialize_v1_data_sets_each_field_alone() {
+    let data = InitializeInstructionData {
+        gate_mint: [7; 32],
+        ..InitializeInstructionData::new(5_000_000, 86_400)
+    };
+    let bytes = data.to_v1_bytes();
+    let flags = u16::from_le_bytes([bytes[1], bytes[2]]);
+
+    // unlike version 0, a later field is sent without any of the ones before it
+    assert_eq!(flags, InitializeInstructionData::GATE_MINT);
+    assert_eq!(bytes.len(), 1 + 2 + INITIALIZE_REQUIRED_LEN + 32);
+    assert_eq!(
+        InitializeInstructionData::try_from(Versioned(&bytes))
+            .unwrap()
+            .gate_mint,
+        [7; 32]
+    );
+}
+
+#[test]
+fn initialize_v1_data_rejects_truncated_payloads() {
+    let rng = &mut rng();
+
+    for _ in 0..CASES / 100 {
+        let name = "n".repeat(rng.gen_range(0..=MAX_NAME_LEN));
+        let data = InitializeInstructionData {
+            name: name.as_bytes(),
+            ..any_initialize_data(rng)
+        };
+        let bytes = data.to_v1_bytes();
+
+        // every field flagged is read in full, so no shorter payload parses
+        for len in 0..bytes.len() {
+            assert_eq!(
+                InitializeInstructionData::try_from(Versioned(&bytes[..len])).err(),
+                Some(ProgramError::InvalidInstructionData),
+                "{len} of {} bytes",
+                bytes.len()
+            );
+        }
+    }
+}
+
+#[test]
+fn initialize_v1_data_rejects_over_long_payloads() {
+    let rng = &mut rng();
+
+    for _ in 0..CASES {
+        let name = "n".repeat(rng.gen_range(0..=MAX_NAME_LEN));
+        let data = InitializeInstructionData {
+            name: name.as_bytes(),
+            ..any_initialize_data(rng)
+        };
+        let extra: Vec<u8> = (0..rng.gen_range(1..=64)).map(|_| rng.r#gen()).collect();
+        let bytes = [data.to_v1_bytes(), extra].concat();
+
+        assert_eq!(
+            InitializeInstructionData::try_from(Versioned(&bytes)).err(),
+            Some(ProgramError::InvalidInstructionData),
+            "{} bytes",
+            bytes.len()
+        );
+    }
+}
+
+#[test]
+fn initialize_v1_data_rejects_malformed_payloads() {
+    let rng = &mut rng();
+
+    for _ in 0..CASES {
+        let data = any_initialize_data(rng);
+        let bytes = data.to_v1_bytes();
+
+        // a version this build does not know
+        let mut unknown_version = bytes.clone();
+        unknown_version[0] = rng.gen_range(2..=u8::MAX);
+
+        assert_eq!(
+            InitializeInstructionData::try_from(Versioned(&unknown_version)).err(),
+            Some(ProgramError::InvalidInstructionData)
+        );
+
+        // a field this build does not know
+        let mut unknown_flag = bytes.clone();
+        let flags = u16::from_le_bytes([bytes[1], bytes[2]]) | 1 << rng.gen_range(14..16);
+        unknown_flag[1..3].copy_from_slice(&flags.to_le_bytes());
+
+        assert_eq!(
+            InitializeInstructionData::try_from(Versioned(&unknown_flag)).err(),
+            Some(ProgramError::InvalidInstructionData)
+        );
+
+        // version 0 under the versioned code reads as the unversioned layout
+        let v0 = [&[Versioned::V0][..], &data.to_bytes()].concat();
+
+        assert_eq!(
+            InitializeInstructionData::try_from(Versioned(&v0))
+                .unwrap()
+                .to_bytes(),
+            data.to_bytes()
+        );
+    }
+
+    // the fields are checked against each other as in version 0
+    let data = InitializeInstructionData {
+        tier_thresholds: [2, 1, 0, 0],
+        ..InitializeInstructionData::new(5_000_000, 86_400)
+    };
+
+    assert_eq!(
+        InitializeInstructionData::try_from(Versioned(&data.to_v1_bytes())).err(),
+        Some(FundraiserError::InvalidAmount.into())
+    );
+
+    let data = InitializeInstructionData {
+        refund_policy: RefundPolicy::Locked,
+        campaign_type: CampaignType::KeepItAll,
+        ..InitializeInstructionData::new(5_000_000, 86_400)
+    };
+
+    assert_eq!(
+        InitializeInstructionData::try_from(Versioned(&data.to_v1_bytes())).err(),
+        Some(ProgramError::InvalidInstructionData)
+    );
+}
+
+#[test]
+fn contribute_v1_data_round_trips() {
+    let rng = &mut rng();
+
+    for _ in 0..CASES {
+        let proof = any_proof(rng);
+        let data = any_contribute_data(rng, &proof);
+        let bytes = data.to_v1_bytes();
+        let parsed = ContributeInstructionData::try_from(Versioned(&bytes)).unwrap();
+
+        assert_eq!(parsed.to_bytes(), data.to_bytes());
+        assert_eq!(parsed.referrer, data.referrer);
+        assert_eq!(parsed.proof, &proof[..]);
+        assert_eq!(parsed.to_v1_bytes(), bytes);
+    }
+}
+
+#[test]
+fn contribute_v1_data_rejects_truncated_payloads() {
+    let rng = &mut rng();
+
+    for _ in 0..CASES / 100 {
+        let proof = any_proof(rng);
+        let data = any_contribute_data(rng, &proof);
+        let bytes = data.to_v1_bytes();
+        let proof_start = bytes.len() - proof.len();
+
+        for len in 0..bytes.len() {
+            let res = ContributeInstructionData::try_from(Versioned(&bytes[..len]));
+
+            // the proof takes the rest of the payload, so cutting off whole nodes leaves a
+            // shorter one, which the Merkle root then rejects
+            match len > proof_start && (len - proof_start).is_multiple_of(32) {
+                true => assert_eq!(res.unwrap().proof, &proof[..len - proof_start]),
+                false => assert_eq!(
+                    res.err(),
+                    Some(ProgramError::InvalidInstructionData),
+                    "{len} of {} bytes",
+                    bytes.len()
+                ),
+            }
+        }
+    }
+}
+
+#[test]
+fn contribute_v1_data_rejects_over_long_payloads() {
+    let rng = &mut rng();
+
+    for _ in 0..CASES {
+        let proof = any_proof(rng);
+        let data = any_contribute_data(rng, &proof);
+        let extra: Vec<u8> = (0..rng.gen_range(1..=64)).map(|_| rng.r#gen()).collect();
+        let bytes = [data.to_v1_bytes(), extra.clone()].concat();
+        let res = ContributeInstructionData::try_from(Versioned(&bytes));
+
+        // a flagged proof takes whatever whole nodes follow it, and nothing else may
+        match !proof.is_empty() && extra.len().is_multiple_of(32) {
+            true => assert_eq!(res.unwrap().proof.len(), proof.len() + extra.len()),
+            false => assert_eq!(
+                res.err(),
+                Some(ProgramError::InvalidInstructionData),
+                "{} bytes",
+                bytes.len()
+            ),
+        }
+    }
+}
+
+#[test]
+fn contribute_v1_data_rejects_malformed_payloads() {
+    let rng = &mut rng();
+
+    for _ in 0..CASES {
+        let proof = any_proof(rng);
+        let data = any_contribute_data(rng, &proof);
+        let bytes = data.to_v1_bytes();
+
+        let mut unknown_version = bytes.clone();
+        unknown_version[0] = rng.gen_range(2..=u8::MAX);
+
+        assert_eq!(
+            ContributeInstructionData::try_from(Versioned(&unknown_version)).err(),
+            Some(ProgramError::InvalidInstructionData)
+        );
+
+        let mut unknown_flag = bytes.clone();
+        let flags = u16::from_le_bytes([bytes[1], bytes[2]]) | 1 << rng.gen_range(5..16);
+        unknown_flag[1..3].copy_from_slice(&flags.to_le_bytes());
+
+        assert_eq!(
+            ContributeInstructionData::try_from(Versioned(&unknown_flag)).err(),
+            Some(ProgramError::InvalidInstructionData)
+        );
+    }
+
+    // a proof flagged but left out
+    let bytes = [
+        &[Versioned::V1][..],
+        &ContributeInstructionData::PROOF.to_le_bytes(),
+        &500_000u64.to_le_bytes(),
+    ]
+    .concat();
+
+    assert_eq!(
+        ContributeInstructionData::try_from(Versioned(&bytes)).err(),
+        Some(ProgramError::InvalidInstructionData)
+    );
+
+    // version 0 under the versioned code reads as the unversioned layout
+    let bytes = [&[Versioned::V0][..], &500_000u64.to_le_bytes()].concat();
+
+    assert_eq!(
+        ContributeInstructionData::try_from(Versioned(&bytes))
+            .unwrap()
+            .amount,
+        500_000
+    );
+}
+
+/// Reference cap check, computed entirely in `u128`.
+fn within_cap(
+    amount_to_raise: u64,
+    max_contribution_bps: u16,
+    contributor_amount: u64,
+    amount: u64,
+) -> bool {
+    let cap = match max_contribution_bps {
+        0 => u128::from(u64::MAX),
+        bps => u128::from(amount_to_raise) * u128::from(bps) / u128::from(MAX_BPS),
+    };
+
+    u128::from(contributor_amount) + u128::from(amount) <= cap
+}
+
+#[test]
+fn contribution_cap_matches_reference() {
+    let rng = &mut rng();
+
+    for _ in 0..CASES {
+        let amount_to_raise = any_u64(rng);
+        let max_contribution_bps = any_bps(rng);
+        let cap = max_contribution(amount_to_raise, max_contribution_bps);
+
+        // draw around the cap half the time so both outcomes are well covered
+        let (contributor_amount, amount) = if rng.r#gen() {
+            let contributor_amount = rng.gen_range(0..=cap);
+            let remaining = cap - contributor_amount;
+
+            (
+                contributor_amount,
+                remaining
+                    .saturating_add(rng.gen_range(0..=2))
+                    .saturating_sub(1),
+            )
+        } else {
+            (any_u64(rng), any_u64(rng))
+        };
+
+        let res = check_contribution_cap(cap, contributor_amount, amount);
+
+        assert_eq!(
+            res.is_ok(),
+            within_cap(
+                amount_to_raise,
+                max_contribution_bps,
+                contributor_amount,
+                amount
+            ),
+            "raise {amount_to_raise} at {max_contribution_bps} bps, \
+             {contributor_amount} given, {amount} more"
+        );
+
+        if amount > cap {
+            assert_eq!(res, Err(FundraiserError::ContributionTooBig.into()));
+        } else if res.is_err() {
+            assert_eq!(
+                res,
+                Err(FundraiserError::MaximumContributionsReached.into())
+            );
+        }
+    }
+}
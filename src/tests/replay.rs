@@ -0,0 +1,647 @@
+//! Differential replay of recorded transactions against the current program build.
+//!
+//! A fixture is a JSON file holding a named list of transactions. Each transaction carries its
+//! instructions, the clock it ran at, a snapshot of every non-executable account it referenced
+//! before execution, and the outcome and account snapshots recorded after it. Replaying loads
+//! the pre-state into a fresh LiteSVM with signature and blockhash checks disabled, re-executes
+//! the instructions against `target/deploy/pinocchio_fundraiser.so`, and diffs the outcome and
+//! post-state against the recording.
+//!
+//! Fixtures are captured with [`Recorder`], which wraps `build_and_send_transaction` in a
+//! scenario, so the same format can hold snapshots of historical mainnet transactions. The
+//! built-in scenarios are recorded by `replay_fixtures` itself whenever their fixture is missing.
+
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use base64::{Engine, prelude::BASE64_STANDARD};
+use litesvm::{LiteSVM, types::TransactionResult};
+use serde::{Deserialize, Serialize};
+use solana_account::Account;
+use solana_clock::Clock;
+use solana_instruction::{AccountMeta, Instruction};
+use solana_keypair::Keypair;
+use solana_message::Message;
+use solana_pubkey::Pubkey;
+use solana_transaction::Transaction;
+
+use crate::tests::utils::{build_and_send_transaction, init_program};
+
+pub const REPLAY_FIXTURES_DIR: &str = "src/tests/fixtures/replay";
+
+const SYSVAR_OWNER_ID: Pubkey =
+    Pubkey::from_str_const("Sysvar1111111111111111111111111111111111111");
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccountSnapshot {
+    pub pubkey: String,
+    pub lamports: u64,
+    pub owner: String,
+    pub executable: bool,
+    pub data: String,
+}
+
+impl AccountSnapshot {
+    fn new(pubkey: &Pubkey, account: &Account) -> Self {
+        Self {
+            pubkey: pubkey.to_string(),
+            lamports: account.lamports,
+            owner: account.owner.to_string(),
+            executable: account.executable,
+            data: BASE64_STANDARD.encode(&account.data),
+        }
+    }
+
+    fn to_account(&self) -> (Pubkey, Account) {
+        (
+            parse_pubkey(&self.pubkey),
+            Account {
+                lamports: self.lamports,
+                data: BASE64_STANDARD.decode(&self.data).unwrap(),
+                owner: parse_pubkey(&self.owner),
+                executable: self.executable,
+                rent_epoch: 0,
+            },
+        )
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedAccountMeta {
+    pub pubkey: String,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedInstruction {
+    pub program_id: String,
+    pub accounts: Vec<RecordedAccountMeta>,
+    pub data: String,
+}
+
+impl RecordedInstruction {
+    fn new(ix: &Instruction) -> Self {
+        Self {
+            program_id: ix.program_id.to_string(),
+            accounts: ix
+                .accounts
+                .iter()
+                .map(|meta| RecordedAccountMeta {
+                    pubkey: meta.pubkey.to_string(),
+                    is_signer: meta.is_signer,
+                    is_writable: meta.is_writable,
+                })
+                .collect(),
+            data: BASE64_STANDARD.encode(&ix.data),
+        }
+    }
+
+    fn to_instruction(&self) -> Instruction {
+        Instruction {
+            program_id: parse_pubkey(&self.program_id),
+            accounts: self
+                .accounts
+                .iter()
+                .map(|meta| AccountMeta {
+                    pubkey: parse_pubkey(&meta.pubkey),
+                    is_signer: meta.is_signer,
+                    is_writable: meta.is_writable,
+                })
+                .collect(),
+            data: BASE64_STANDARD.decode(&self.data).unwrap(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordedTransaction {
+    pub payer: String,
+    pub unix_timestamp: i64,
+    pub instructions: Vec<RecordedInstruction>,
+    pub pre_state: Vec<AccountSnapshot>,
+    /// `None` on success, otherwise the `Debug` rendering of the `TransactionError`.
+    pub error: Option<String>,
+    pub post_state: Vec<AccountSnapshot>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReplayFixture {
+    pub name: String,
+    pub transactions: Vec<RecordedTransaction>,
+}
+
+impl ReplayFixture {
+    pub fn load(path: &Path) -> Self {
+        serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap()
+    }
+
+    /// Every `*.json` fixture in `dir`, sorted by file name.
+    pub fn load_dir(dir: &str) -> Vec<Self> {
+        let Ok(entries) = fs::read_dir(dir) else {
+            return vec![];
+        };
+
+        let mut paths: Vec<_> = entries
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .collect();
+        paths.sort();
+
+        paths.iter().map(|path| Self::load(path)).collect()
+    }
+
+    /// Where [`Self::save`] writes this fixture in `dir`.
+    pub fn path(&self, dir: &str) -> PathBuf {
+        Path::new(dir).join(format!("{}.json", self.name))
+    }
+
+    pub fn save(&self, dir: &str) {
+        fs::create_dir_all(dir).unwrap();
+        fs::write(
+            self.path(dir),
+            serde_json::to_string_pretty(self).unwrap() + "\n",
+        )
+        .unwrap();
+    }
+}
+
+/// Captures every transaction a scenario sends into a [`ReplayFixture`].
+pub struct Recorder {
+    pub fixture: ReplayFixture,
+}
+
+impl Recorder {
+    pub fn new(name: &str) -> Self {
+        Self {
+            fixture: ReplayFixture {
+                name: name.to_string(),
+                transactions: vec![],
+            },
+        }
+    }
+
+    #[allow(clippy::result_large_err)]
+    pub fn send(
+        &mut self,
+        litesvm: &mut LiteSVM,
+        signers: &[&Keypair],
+        payer: &Pubkey,
+        ixs: &[Instruction],
+    ) -> TransactionResult {
+        let keys = referenced_keys(payer, ixs);
+        let pre_state = snapshot(litesvm, &keys);
+        let unix_timestamp = litesvm.get_sysvar::<Clock>().unix_timestamp;
+
+        let res = build_and_send_transaction(litesvm, signers, payer, ixs);
+
+        self.fixture.transactions.push(RecordedTransaction {
+            payer: payer.to_string(),
+            unix_timestamp,
+            instructions: ixs.iter().map(RecordedInstruction::new).collect(),
+            pre_state,
+            error: res.as_ref().err().map(|failed| format!("{:?}", failed.err)),
+            post_state: snapshot(litesvm, &keys),
+        });
+
+        res
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct AccountDiff {
+    pub pubkey: String,
+    pub recorded: Option<AccountSnapshot>,
+    pub replayed: Option<AccountSnapshot>,
+}
+
+#[derive(Debug)]
+pub struct TransactionReport {
+    pub index: usize,
+    pub recorded_error: Option<String>,
+    pub replayed_error: Option<String>,
+    pub diffs: Vec<AccountDiff>,
+}
+
+impl TransactionReport {
+    pub fn is_clean(&self) -> bool {
+        self.recorded_error == self.replayed_error && self.diffs.is_empty()
+    }
+}
+
+#[derive(Debug)]
+pub struct ReplayReport {
+    pub name: String,
+    pub transactions: Vec<TransactionReport>,
+}
+
+impl ReplayReport {
+    pub fn is_clean(&self) -> bool {
+        self.transactions.iter().all(TransactionReport::is_clean)
+    }
+}
+
+/// Re-executes each recorded transaction in isolation on top of its own pre-state.
+pub fn replay(fixture: &ReplayFixture) -> ReplayReport {
+    let transactions = fixture
+        .transactions
+        .iter()
+        .enumerate()
+        .map(|(index, recorded)| {
+            let mut litesvm = LiteSVM::new()
+                .with_sigverify(false)
+                .with_blockhash_check(false);
+            init_program(&mut litesvm);
+
+            for snapshot in &recorded.pre_state {
+                let (pubkey, account) = snapshot.to_account();
+                litesvm.set_account(pubkey, account).unwrap();
+            }
+
+            let mut clock = litesvm.get_sysvar::<Clock>();
+            clock.unix_timestamp = recorded.unix_timestamp;
+            litesvm.set_sysvar(&clock);
+
+            let payer = parse_pubkey(&recorded.payer);
+            let ixs: Vec<Instruction> = recorded
+                .instructions
+                .iter()
+                .map(RecordedInstruction::to_instruction)
+                .collect();
+
+            let mut tx = Transaction::new_unsigned(Message::new(&ixs, Some(&payer)));
+            tx.message.recent_blockhash = litesvm.latest_blockhash();
+
+            let replayed_error = litesvm
+                .send_transaction(tx)
+                .err()
+                .map(|failed| format!("{:?}", failed.err));
+
+            let post_state = snapshot(&litesvm, &referenced_keys(&payer, &ixs));
+
+            TransactionReport {
+                index,
+                recorded_error: recorded.error.clone(),
+                replayed_error,
+                diffs: diff(&recorded.post_state, &post_state),
+            }
+        })
+        .collect();
+
+    ReplayReport {
+        name: fixture.name.clone(),
+        transactions,
+    }
+}
+
+fn parse_pubkey(s: &str) -> Pubkey {
+    Pubkey::from_str(s).unwrap()
+}
+
+fn referenced_keys(payer: &Pubkey, ixs: &[Instruction]) -> Vec<Pubkey> {
+    let mut keys = vec![*payer];
+
+    for ix in ixs {
+        keys.push(ix.program_id);
+        keys.extend(ix.accounts.iter().map(|meta| meta.pubkey));
+    }
+
+    keys.sort();
+    keys.dedup();
+    keys
+}
+
+/// Executable accounts are left out, as the programs under test come from the current build,
+/// and so are sysvars, which the runtime provides for every transaction.
+fn snapshot(litesvm: &LiteSVM, keys: &[Pubkey]) -> Vec<AccountSnapshot> {
+    keys.iter()
+        .filter_map(|key| {
+            litesvm
+                .get_account(key)
+                .filter(|account| !account.executable && account.owner != SYSVAR_OWNER_ID)
+                .map(|account| AccountSnapshot::new(key, &account))
+        })
+        .collect()
+}
+
+fn diff(recorded: &[AccountSnapshot], replayed: &[AccountSnapshot]) -> Vec<AccountDiff> {
+    let mut accounts: BTreeMap<&str, (Option<&AccountSnapshot>, Option<&AccountSnapshot>)> =
+        BTreeMap::new();
+
+    for snapshot in recorded {
+        accounts.entry(&snapshot.pubkey).or_default().0 = Some(snapshot);
+    }
+
+    for snapshot in replayed {
+        accounts.entry(&snapshot.pubkey).or_default().1 = Some(snapshot);
+    }
+
+    accounts
+        .into_iter()
+        .filter(|(_, (recorded, replayed))| recorded != replayed)
+        .map(|(pubkey, (recorded, replayed))| AccountDiff {
+            pubkey: pubkey.to_string(),
+            recorded: recorded.cloned(),
+            replayed: replayed.cloned(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_instruction::{AccountMeta, Instruction};
+    use solana_keypair::Keypair;
+    use solana_pubkey::Pubkey;
+    use solana_signer::Signer;
+    use spl_associated_token_account::{
+        get_associated_token_address_with_program_id,
+        solana_program::{clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL},
+    };
+
+    use crate::tests::{
+        constants::{
+            ASSOCIATED_TOKEN_PROGRAM_ID, FEE_COLLECTOR, MINT_DECIMALS, PROGRAM_ID,
+            SYSTEM_PROGRAM_ID, TOKEN_PROGRAM_ID,
+        },
+        pda::{get_config_pda, get_contributor_pda, get_fundraise_pda, get_vault_pda},
+        replay::{REPLAY_FIXTURES_DIR, Recorder, ReplayFixture, replay},
+        utils::{forward_time, init_ata, init_mint, init_wallet, setup},
+    };
+
+    const AMOUNT_TO_RAISE: u64 = 5_000_000;
+    const CONTRIBUTE_AMOUNT: u64 = 500_000;
+
+    struct Scenario {
+        maker: Keypair,
+        mint_to_raise: Pubkey,
+        fundraise_pda: Pubkey,
+        vault: Pubkey,
+    }
+
+    impl Scenario {
+        fn contribute_ix(&self, authority: &Pubkey, authority_ata: &Pubkey) -> Instruction {
+            Instruction {
+                program_id: PROGRAM_ID,
+                accounts: vec![
+                    AccountMeta::new(*authority, true),
+                    AccountMeta::new_readonly(self.mint_to_raise, false),
+                    AccountMeta::new(self.fundraise_pda, false),
+                    AccountMeta::new(get_contributor_pda(&self.fundraise_pda, authority), false),
+                    AccountMeta::new(*authority_ata, false),
+                    AccountMeta::new(self.vault, false),
+                    AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                    AccountMeta::new_readonly(get_config_pda(), false),
+                ],
+                data: [vec![1u8], CONTRIBUTE_AMOUNT.to_le_bytes().to_vec()].concat(),
+            }
+        }
+    }
+
+    /// Creates a fundraise through the recorder and returns the accounts later steps need.
+    fn initialize(recorder: &mut Recorder) -> (litesvm::LiteSVM, Scenario) {
+        let (mut litesvm, _default_payer) = setup();
+        let maker = init_wallet(&mut litesvm, LAMPORTS_PER_SOL);
+        let mint_to_raise = init_mint(
+            &mut litesvm,
+            TOKEN_PROGRAM_ID,
+            MINT_DECIMALS,
+            10_000_000_000,
+        );
+
+        let fundraise_pda = get_fundraise_pda(&maker.pubkey());
+        let vault = get_vault_pda(&fundraise_pda);
+
+        let data = [
+            vec![0u8],
+            AMOUNT_TO_RAISE.to_le_bytes().to_vec(),
+            SECONDS_PER_DAY.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+            0u16.to_le_bytes().to_vec(),
+            1_000u16.to_le_bytes().to_vec(),
+            0u64.to_le_bytes().to_vec(),
+        ]
+        .concat();
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(maker.pubkey(), true),
+                AccountMeta::new_readonly(mint_to_raise, false),
+                AccountMeta::new(fundraise_pda, false),
+                AccountMeta::new(vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+            ],
+            data,
+        };
+
+        recorder
+            .send(&mut litesvm, &[&maker], &maker.pubkey(), &[ix])
+            .unwrap();
+
+        (
+            litesvm,
+            Scenario {
+                maker,
+                mint_to_raise,
+                fundraise_pda,
+                vault,
+            },
+        )
+    }
+
+    fn record_contribute_and_refund() -> ReplayFixture {
+        let mut recorder = Recorder::new("contribute_and_refund");
+        let (mut litesvm, scenario) = initialize(&mut recorder);
+
+        let authority = init_wallet(&mut litesvm, LAMPORTS_PER_SOL);
+        let authority_ata = init_ata(
+            &mut litesvm,
+            scenario.mint_to_raise,
+            authority.pubkey(),
+            1_000_000_000,
+        );
+
+        let ix = scenario.contribute_ix(&authority.pubkey(), &authority_ata);
+        recorder
+            .send(&mut litesvm, &[&authority], &authority.pubkey(), &[ix])
+            .unwrap();
+
+        forward_time(&mut litesvm, 3600); // jump forward 1 hour
+
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(authority.pubkey(), true),
+                AccountMeta::new(scenario.maker.pubkey(), false),
+                AccountMeta::new_readonly(scenario.mint_to_raise, false),
+                AccountMeta::new(scenario.fundraise_pda, false),
+                AccountMeta::new(
+                    get_contributor_pda(&scenario.fundraise_pda, &authority.pubkey()),
+                    false,
+                ),
+                AccountMeta::new(authority_ata, false),
+                AccountMeta::new(scenario.vault, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+            ],
+            data: vec![2u8],
+        };
+        recorder
+            .send(&mut litesvm, &[&authority], &authority.pubkey(), &[ix])
+            .unwrap();
+
+        recorder.fixture
+    }
+
+    fn record_contribute_and_claim() -> ReplayFixture {
+        let mut recorder = Recorder::new("contribute_and_claim");
+        let (mut litesvm, scenario) = initialize(&mut recorder);
+
+        for _ in 0..AMOUNT_TO_RAISE / CONTRIBUTE_AMOUNT {
+            let authority = init_wallet(&mut litesvm, LAMPORTS_PER_SOL);
+            let authority_ata = init_ata(
+                &mut litesvm,
+                scenario.mint_to_raise,
+                authority.pubkey(),
+                1_000_000_000,
+            );
+
+            let ix = scenario.contribute_ix(&authority.pubkey(), &authority_ata);
+            recorder
+                .send(&mut litesvm, &[&authority], &authority.pubkey(), &[ix])
+                .unwrap();
+        }
+
+        // a contribution past the target is recorded as a failure
+        let authority = init_wallet(&mut litesvm, LAMPORTS_PER_SOL);
+        let authority_ata = init_ata(
+            &mut litesvm,
+            scenario.mint_to_raise,
+            authority.pubkey(),
+            1_000_000_000,
+        );
+
+        let ix = scenario.contribute_ix(&authority.pubkey(), &authority_ata);
+        let _ = recorder.send(&mut litesvm, &[&authority], &authority.pubkey(), &[ix]);
+
+        let maker_ata = get_associated_token_address_with_program_id(
+            &scenario.maker.pubkey(),
+            &scenario.mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+        let fee_collector_ata = get_associated_token_address_with_program_id(
+            &FEE_COLLECTOR,
+            &scenario.mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+        );
+
+        let ix = Instruction {
+            program_id: PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(scenario.maker.pubkey(), true),
+                AccountMeta::new_readonly(scenario.mint_to_raise, false),
+                AccountMeta::new(scenario.fundraise_pda, false),
+                AccountMeta::new(scenario.vault, false),
+                AccountMeta::new(maker_ata, false),
+                AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+                AccountMeta::new_readonly(TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+                AccountMeta::new_readonly(get_config_pda(), false),
+                AccountMeta::new_readonly(FEE_COLLECTOR, false),
+                AccountMeta::new(fee_collector_ata, false),
+                AccountMeta::new(scenario.maker.pubkey(), false),
+            ],
+            data: vec![3u8],
+        };
+        recorder
+            .send(
+                &mut litesvm,
+                &[&scenario.maker],
+                &scenario.maker.pubkey(),
+                &[ix],
+            )
+            .unwrap();
+
+        recorder.fixture
+    }
+
+    fn scenarios() -> Vec<ReplayFixture> {
+        vec![
+            record_contribute_and_refund(),
+            record_contribute_and_claim(),
+        ]
+    }
+
+    /// Records any scenario whose fixture is missing from [`REPLAY_FIXTURES_DIR`], then replays
+    /// every fixture there, so a fresh checkout writes the fixtures on its first run and later
+    /// runs diff against them. Regenerate every fixture with
+    /// `UPDATE_REPLAY_FIXTURES=1 cargo test replay_fixtures` after a deliberate behavior change,
+    /// once the diffs reported here have been reviewed.
+    #[test]
+    fn replay_fixtures() {
+        let update = std::env::var_os("UPDATE_REPLAY_FIXTURES").is_some();
+
+        let scenarios = scenarios();
+
+        for fixture in &scenarios {
+            if update || !fixture.path(REPLAY_FIXTURES_DIR).exists() {
+                fixture.save(REPLAY_FIXTURES_DIR);
+            }
+        }
+
+        let fixtures = ReplayFixture::load_dir(REPLAY_FIXTURES_DIR);
+
+        assert!(fixtures.len() >= scenarios.len());
+
+        for fixture in &fixtures {
+            let report = replay(fixture);
+
+            assert!(report.is_clean(), "{report:#?}");
+        }
+    }
+
+    #[test]
+    fn replay_round_trips_recorded_scenarios() {
+        for fixture in scenarios() {
+            let json = serde_json::to_string(&fixture).unwrap();
+            let fixture: ReplayFixture = serde_json::from_str(&json).unwrap();
+            let report = replay(&fixture);
+
+            assert_eq!(report.transactions.len(), fixture.transactions.len());
+            assert!(report.is_clean(), "{report:#?}");
+        }
+    }
+
+    #[test]
+    fn replay_reports_divergence() {
+        let mut fixture = record_contribute_and_refund();
+
+        let tampered = &mut fixture.transactions[1];
+        tampered.post_state[0].lamports += 1;
+        tampered.error = Some("InsufficientFundsForFee".to_string());
+        let tampered_pubkey = tampered.post_state[0].pubkey.clone();
+
+        let report = replay(&fixture);
+
+        assert!(report.transactions[0].is_clean());
+        assert!(report.transactions[2].is_clean());
+
+        let diverged = &report.transactions[1];
+
+        assert_eq!(
+            diverged.recorded_error.as_deref(),
+            Some("InsufficientFundsForFee")
+        );
+        assert_eq!(diverged.replayed_error, None);
+        assert_eq!(diverged.diffs.len(), 1);
+        assert_eq!(diverged.diffs[0].pubkey, tampered_pubkey);
+    }
+}
@@ -0,0 +1,281 @@
+//! A fundraise of a Token-2022 mint with a transfer hook, whose accounts are passed through to
+//! every transfer of contributions, refunds and the claim.
+
+use solana_instruction::{AccountMeta, Instruction};
+use solana_signer::Signer;
+use spl_associated_token_account::solana_program::{
+    clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL,
+};
+use spl_token_2022::{extension::StateWithExtensions, state::Account as TokenAccount};
+
+use crate::{
+    AccountLoad, Fundraise, FundraiserError, client, cpi,
+    tests::{
+        constants::{
+            ASSOCIATED_TOKEN_PROGRAM_ID, FEE_COLLECTOR, MINT_DECIMALS, PROGRAM_ID,
+            SYSTEM_PROGRAM_ID, TEST_TRANSFER_HOOK_PROGRAM_ID, TOKEN_2022_PROGRAM_ID,
+        },
+        utils::{
+            assert_error, build_and_send_transaction, init_transfer_hook_ata,
+            init_transfer_hook_mint, init_wallet, setup,
+        },
+    },
+};
+
+/// Raises a hooked mint through a contribution, a partial refund, a second contribution and the
+/// claim, with the hook also reading the fundraise on every transfer if `read_fundraise` is set.
+fn raise(read_fundraise: bool) {
+    let (litesvm, _default_payer) = &mut setup();
+    let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let backer = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let fundraise = client::fundraise_address(&maker.pubkey());
+    let read_accounts = match read_fundraise {
+        true => vec![fundraise],
+        false => vec![],
+    };
+    let (mint_to_raise, counter) =
+        init_transfer_hook_mint(litesvm, MINT_DECIMALS, 1_000_000_000, &read_accounts);
+    init_transfer_hook_ata(litesvm, mint_to_raise, backer.pubkey(), 6_000_000);
+
+    let with_hook = |ix| {
+        let extra_accounts = core::iter::once(AccountMeta::new(counter, false))
+            .chain(
+                read_accounts
+                    .iter()
+                    .map(|account| AccountMeta::new_readonly(*account, false)),
+            )
+            .collect::<Vec<_>>();
+
+        client::with_transfer_hook(
+            ix,
+            &mint_to_raise,
+            &TEST_TRANSFER_HOOK_PROGRAM_ID,
+            &extra_accounts,
+        )
+    };
+
+    let ix = client::initialize(
+        &maker.pubkey(),
+        &mint_to_raise,
+        &TOKEN_2022_PROGRAM_ID,
+        5_000_000,
+        SECONDS_PER_DAY,
+    );
+    build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+    let contribute = |amount| {
+        with_hook(client::contribute(
+            &backer.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_2022_PROGRAM_ID,
+            amount,
+        ))
+    };
+
+    build_and_send_transaction(
+        litesvm,
+        &[&backer],
+        &backer.pubkey(),
+        &[contribute(5_000_000)],
+    )
+    .unwrap();
+
+    let mut ix = client::refund(
+        &backer.pubkey(),
+        &maker.pubkey(),
+        &mint_to_raise,
+        &TOKEN_2022_PROGRAM_ID,
+    );
+    ix.data = cpi::refund_ix_data(1_000_000).to_vec();
+    let ix = with_hook(ix);
+    build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]).unwrap();
+
+    litesvm.expire_blockhash();
+    build_and_send_transaction(
+        litesvm,
+        &[&backer],
+        &backer.pubkey(),
+        &[contribute(1_000_000)],
+    )
+    .unwrap();
+
+    let account = litesvm.get_account(&fundraise).unwrap();
+
+    assert_eq!(
+        Fundraise::load(&account.data).unwrap().get_current_amount(),
+        5_000_000
+    );
+
+    let ix = with_hook(client::claim(
+        &maker.pubkey(),
+        &mint_to_raise,
+        &TOKEN_2022_PROGRAM_ID,
+        &FEE_COLLECTOR,
+    ));
+    build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+    let maker_ata =
+        client::associated_token_address(&maker.pubkey(), &mint_to_raise, &TOKEN_2022_PROGRAM_ID);
+    let maker_ata_acc = litesvm.get_account(&maker_ata).unwrap();
+
+    assert_eq!(
+        StateWithExtensions::<TokenAccount>::unpack(&maker_ata_acc.data)
+            .unwrap()
+            .base
+            .amount,
+        5_000_000
+    );
+
+    // the hook saw both contributions, the refund and the claim
+    let counter = litesvm.get_account(&counter).unwrap();
+
+    assert_eq!(
+        u64::from_le_bytes(counter.data[0..8].try_into().unwrap()),
+        12_000_000
+    );
+    assert_eq!(
+        u64::from_le_bytes(counter.data[8..16].try_into().unwrap()),
+        4
+    );
+}
+
+#[test]
+fn raise_transfer_hook_mint() {
+    raise(false);
+}
+
+#[test]
+fn hook_reads_fundraise_during_transfers() {
+    // fails with an account borrow error if a handler holds the fundraise across a transfer
+    raise(true);
+}
+
+#[test]
+fn throw_if_transfer_hook_accounts_missing() {
+    let (litesvm, _default_payer) = &mut setup();
+    let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let backer = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let (mint_to_raise, _counter) =
+        init_transfer_hook_mint(litesvm, MINT_DECIMALS, 1_000_000_000, &[]);
+    init_transfer_hook_ata(litesvm, mint_to_raise, backer.pubkey(), 1_000_000);
+
+    let ix = client::initialize(
+        &maker.pubkey(),
+        &mint_to_raise,
+        &TOKEN_2022_PROGRAM_ID,
+        5_000_000,
+        SECONDS_PER_DAY,
+    );
+    build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+    let ix = client::contribute(
+        &backer.pubkey(),
+        &maker.pubkey(),
+        &mint_to_raise,
+        &TOKEN_2022_PROGRAM_ID,
+        500_000,
+    );
+    let res = build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]);
+
+    assert_error(
+        res.unwrap_err(),
+        FundraiserError::MissingTransferHookAccounts,
+    );
+}
+
+#[test]
+fn force_refund_transfer_hook_mint() {
+    let (litesvm, _default_payer) = &mut setup();
+    let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let backer = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let fundraise = client::fundraise_address(&maker.pubkey());
+    let (mint_to_raise, counter) =
+        init_transfer_hook_mint(litesvm, MINT_DECIMALS, 1_000_000_000, &[]);
+    let backer_ata = init_transfer_hook_ata(litesvm, mint_to_raise, backer.pubkey(), 1_000_000);
+
+    let with_hook = |ix| {
+        client::with_transfer_hook(
+            ix,
+            &mint_to_raise,
+            &TEST_TRANSFER_HOOK_PROGRAM_ID,
+            &[AccountMeta::new(counter, false)],
+        )
+    };
+
+    let ix = client::initialize(
+        &maker.pubkey(),
+        &mint_to_raise,
+        &TOKEN_2022_PROGRAM_ID,
+        5_000_000,
+        SECONDS_PER_DAY,
+    );
+    let contribute = with_hook(client::contribute(
+        &backer.pubkey(),
+        &maker.pubkey(),
+        &mint_to_raise,
+        &TOKEN_2022_PROGRAM_ID,
+        1_000_000,
+    ));
+    build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+    build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[contribute]).unwrap();
+
+    let force_refund = Instruction {
+        program_id: PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(maker.pubkey(), true),
+            AccountMeta::new(backer.pubkey(), false),
+            AccountMeta::new_readonly(mint_to_raise, false),
+            AccountMeta::new(fundraise, false),
+            AccountMeta::new(
+                client::contributor_address(&fundraise, &backer.pubkey()),
+                false,
+            ),
+            AccountMeta::new(backer_ata, false),
+            AccountMeta::new(client::vault_address(&fundraise), false),
+            AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false),
+            AccountMeta::new_readonly(TOKEN_2022_PROGRAM_ID, false),
+            AccountMeta::new_readonly(ASSOCIATED_TOKEN_PROGRAM_ID, false),
+        ],
+        data: cpi::force_refund_ix_data().to_vec(),
+    };
+
+    // the refund is a checked transfer, which needs the hook's accounts
+    let res = build_and_send_transaction(
+        litesvm,
+        &[&maker],
+        &maker.pubkey(),
+        core::slice::from_ref(&force_refund),
+    );
+
+    assert_error(
+        res.unwrap_err(),
+        FundraiserError::MissingTransferHookAccounts,
+    );
+
+    build_and_send_transaction(
+        litesvm,
+        &[&maker],
+        &maker.pubkey(),
+        &[with_hook(force_refund)],
+    )
+    .unwrap();
+
+    let backer_ata_acc = litesvm.get_account(&backer_ata).unwrap();
+
+    assert_eq!(
+        StateWithExtensions::<TokenAccount>::unpack(&backer_ata_acc.data)
+            .unwrap()
+            .base
+            .amount,
+        1_000_000
+    );
+
+    // the hook saw the contribution and the forced refund
+    let counter = litesvm.get_account(&counter).unwrap();
+
+    assert_eq!(
+        u64::from_le_bytes(counter.data[8..16].try_into().unwrap()),
+        2
+    );
+}
@@ -1,44 +1,82 @@
+use core::mem::transmute;
+
+use base64::{Engine, prelude::BASE64_STANDARD};
 use litesvm::{
     LiteSVM,
     types::{FailedTransactionMetadata, TransactionResult},
 };
 use solana_account::Account;
 use solana_clock::Clock;
-use solana_instruction::Instruction;
+use solana_instruction::{Instruction, error::InstructionError};
 use solana_keypair::Keypair;
 use solana_message::Message;
 use solana_pubkey::Pubkey;
 use solana_signer::Signer;
 use solana_transaction::Transaction;
+use solana_transaction_error::TransactionError;
 use spl_associated_token_account::{
-    get_associated_token_address,
+    get_associated_token_address_with_program_id,
     solana_program::{
         native_token::LAMPORTS_PER_SOL,
         program_pack::{IsInitialized, Pack},
     },
 };
-use spl_token_2022::state::{Account as TokenAccount, AccountState, Mint};
+use spl_token_2022::{
+    extension::{
+        BaseStateWithExtensionsMut, ExtensionType, StateWithExtensionsMut,
+        memo_transfer::MemoTransfer,
+        transfer_hook::{TransferHook, TransferHookAccount},
+    },
+    state::{Account as TokenAccount, AccountState, Mint, Multisig},
+};
 
-use crate::{FundraiserError, tests::constants::PROGRAM_ID};
+use crate::{
+    AccountLoad, BPF_LOADER_UPGRADEABLE_ID, Config, ConfigParams, FundraiserError, MerkleProof,
+    NATIVE_MINT, Prefix, SetInner, Space,
+    tests::{
+        constants::{
+            EXECUTE_DISCRIMINATOR, FEE_COLLECTOR, PDA_MAKER_PROGRAM_ID, PROGRAM_ID,
+            TEST_HOOK_PROGRAM_ID, TEST_TRANSFER_HOOK_PROGRAM_ID, TOKEN_2022_PROGRAM_ID,
+            TOKEN_PROGRAM_ID,
+        },
+        pda::{get_config_pda, get_program_data_pda},
+    },
+};
+
+const RAW_ACCOUNT_HEADER_LEN: usize = 88;
+const RAW_ACCOUNT_OWNER_OFFSET: usize = 40;
+const RAW_ACCOUNT_DATA_LEN_OFFSET: usize = 80;
 
 pub fn setup() -> (LiteSVM, Keypair) {
     let mut litesvm = LiteSVM::new();
 
-    litesvm
-        .add_program_from_file(
-            PROGRAM_ID.to_bytes(),
-            "target/deploy/pinocchio_fundraiser.so",
-        )
-        .unwrap();
+    init_program(&mut litesvm);
 
     let default_payer = Keypair::new();
     litesvm
         .airdrop(&default_payer.pubkey(), LAMPORTS_PER_SOL * 100)
         .unwrap();
 
+    init_config(
+        &mut litesvm,
+        default_payer.pubkey(),
+        0,
+        FEE_COLLECTOR,
+        false,
+    );
+
     (litesvm, default_payer)
 }
 
+pub fn init_program(litesvm: &mut LiteSVM) {
+    litesvm
+        .add_program_from_file(
+            PROGRAM_ID.to_bytes(),
+            "target/deploy/pinocchio_fundraiser.so",
+        )
+        .unwrap();
+}
+
 fn pack_data<T: Pack>(state: T) -> Vec<u8> {
     let mut data = vec![0; T::LEN];
     T::pack(state, &mut data).unwrap();
@@ -47,9 +85,10 @@ fn pack_data<T: Pack>(state: T) -> Vec<u8> {
 
 pub fn fetch_account<T: Pack + IsInitialized>(litesvm: &LiteSVM, pubkey: &Pubkey) -> T {
     let account = litesvm.get_account(pubkey).unwrap();
-    T::unpack(&mut account.data.as_ref()).unwrap()
+    T::unpack(account.data.as_ref()).unwrap()
 }
 
+#[allow(clippy::result_large_err)]
 pub fn build_and_send_transaction(
     litesvm: &mut LiteSVM,
     signers: &[&Keypair],
@@ -64,17 +103,164 @@ pub fn build_and_send_transaction(
     litesvm.send_transaction(tx)
 }
 
+/// Simulates `ixs` without committing them, returning the program and data of the return data
+/// left by the last instruction to set it.
+pub fn simulate_return_data(
+    litesvm: &LiteSVM,
+    signers: &[&Keypair],
+    payer: &Pubkey,
+    ixs: &[Instruction],
+) -> (Pubkey, Vec<u8>) {
+    let tx = Transaction::new(
+        signers,
+        Message::new(ixs, Some(payer)),
+        litesvm.latest_blockhash(),
+    );
+    let return_data = litesvm.simulate_transaction(tx).unwrap().meta.return_data;
+
+    (return_data.program_id, return_data.data)
+}
+
 pub fn assert_error(tx_meta: FailedTransactionMetadata, error: FundraiserError) {
-    let hex_string = format!("custom program error: 0x{:x}", error as u32);
+    let hex_string = format!("custom program error: 0x{:x}", error.code());
     assert!(tx_meta.meta.pretty_logs().contains(&hex_string));
 }
 
+pub fn assert_instruction_error(tx_meta: FailedTransactionMetadata, error: InstructionError) {
+    match tx_meta.err {
+        TransactionError::InstructionError(_, err) => assert_eq!(err, error),
+        err => panic!("unexpected transaction error: {err:?}"),
+    }
+}
+
+/// Asserts the transaction failed with `error` as the custom code of its failing instruction.
+pub fn assert_custom_error(tx_meta: FailedTransactionMetadata, error: FundraiserError) {
+    assert_instruction_error(tx_meta, InstructionError::Custom(error.code()));
+}
+
+pub fn emitted_events(logs: &[String]) -> Vec<Vec<u8>> {
+    logs.iter()
+        .filter_map(|log| log.strip_prefix("Program data: "))
+        .map(|data| BASE64_STANDARD.decode(data).unwrap())
+        .collect()
+}
+
 pub fn forward_time(litesvm: &mut LiteSVM, seconds: i64) {
     let mut clock = litesvm.get_sysvar::<Clock>();
     clock.unix_timestamp += seconds;
     litesvm.set_sysvar(&clock);
 }
 
+pub fn init_config(
+    litesvm: &mut LiteSVM,
+    admin: Pubkey,
+    fee_bps: u16,
+    fee_collector: Pubkey,
+    paused: bool,
+) -> Pubkey {
+    let (config, bump) = Pubkey::find_program_address(&[Config::PREFIX], &PROGRAM_ID);
+
+    let mut data = vec![0u8; Config::LEN];
+    Config::load_mut(&mut data)
+        .unwrap()
+        .set_inner(ConfigParams::new(
+            admin.to_bytes(),
+            fee_bps,
+            fee_collector.to_bytes(),
+            u8::from(paused),
+            bump,
+        ));
+    let lamports = litesvm.minimum_balance_for_rent_exemption(Config::LEN);
+
+    litesvm
+        .set_account(
+            config,
+            Account {
+                lamports,
+                data,
+                owner: PROGRAM_ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+
+    config
+}
+
+/// Sets the maker bond of the config created by [`setup`].
+pub fn set_config_bond(litesvm: &mut LiteSVM, lamports: u64, slash_bps: u16) {
+    let config = get_config_pda();
+    let mut account = litesvm.get_account(&config).unwrap();
+
+    Config::load_mut(&mut account.data)
+        .unwrap()
+        .set_bond(lamports, slash_bps);
+    litesvm.set_account(config, account).unwrap();
+}
+
+pub fn init_program_data(litesvm: &mut LiteSVM, upgrade_authority: Pubkey) -> Pubkey {
+    let program_data = get_program_data_pda();
+
+    let data = [
+        3u32.to_le_bytes().as_ref(),
+        0u64.to_le_bytes().as_ref(),
+        &[1],
+        upgrade_authority.as_ref(),
+    ]
+    .concat();
+    let lamports = litesvm.minimum_balance_for_rent_exemption(data.len());
+
+    litesvm
+        .set_account(
+            program_data,
+            Account {
+                lamports,
+                data,
+                owner: Pubkey::new_from_array(BPF_LOADER_UPGRADEABLE_ID),
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+
+    program_data
+}
+
+/// Loads the PDA maker program, returning the PDA it signs for as a maker.
+pub fn init_pda_maker(litesvm: &mut LiteSVM) -> Pubkey {
+    litesvm
+        .add_program_from_file(PDA_MAKER_PROGRAM_ID, "target/deploy/pda_maker.so")
+        .unwrap();
+
+    Pubkey::find_program_address(&[b"maker"], &PDA_MAKER_PROGRAM_ID).0
+}
+
+/// Loads the test hook program and creates the leaderboard account it tallies into.
+pub fn init_leaderboard(litesvm: &mut LiteSVM) -> Pubkey {
+    litesvm
+        .add_program_from_file(TEST_HOOK_PROGRAM_ID, "target/deploy/test_hook.so")
+        .unwrap();
+
+    let leaderboard = Pubkey::new_unique();
+    let lamports = litesvm.minimum_balance_for_rent_exemption(16);
+
+    litesvm
+        .set_account(
+            leaderboard,
+            Account {
+                lamports,
+                data: vec![0; 16],
+                owner: TEST_HOOK_PROGRAM_ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+
+    leaderboard
+}
+
 pub fn init_wallet(litesvm: &mut LiteSVM, lamports: u64) -> Keypair {
     let wallet = Keypair::new();
     litesvm.airdrop(&wallet.pubkey(), lamports).unwrap();
@@ -111,11 +297,38 @@ pub fn init_mint(litesvm: &mut LiteSVM, owner: Pubkey, decimals: u8, supply: u64
     mint
 }
 
+/// Gives `mint`, a mint of either token program, `freeze_authority`.
+pub fn set_freeze_authority(litesvm: &mut LiteSVM, mint: Pubkey, freeze_authority: Pubkey) {
+    let mut account = litesvm.get_account(&mint).unwrap();
+    let mut state = Mint::unpack_unchecked(&account.data[..Mint::LEN]).unwrap();
+
+    state.freeze_authority = Some(freeze_authority).into();
+    Mint::pack(state, &mut account.data[..Mint::LEN]).unwrap();
+
+    litesvm.set_account(mint, account).unwrap();
+}
+
+/// Creates `owner`'s associated token account for `mint`, derived under whichever token program
+/// owns the mint.
 pub fn init_ata(litesvm: &mut LiteSVM, mint: Pubkey, owner: Pubkey, amount: u64) -> Pubkey {
     let token_program = litesvm.get_account(&mint).unwrap().owner;
-    let ata = get_associated_token_address(&owner, &mint);
+    let ata = get_associated_token_address_with_program_id(&owner, &mint, &token_program);
 
-    let ata_state = TokenAccount {
+    init_token_account(litesvm, ata, mint, owner, amount);
+
+    ata
+}
+
+pub fn init_token_account(
+    litesvm: &mut LiteSVM,
+    address: Pubkey,
+    mint: Pubkey,
+    owner: Pubkey,
+    amount: u64,
+) {
+    let token_program = litesvm.get_account(&mint).unwrap().owner;
+
+    let token_account_state = TokenAccount {
         mint,
         owner,
         amount,
@@ -126,15 +339,71 @@ pub fn init_ata(litesvm: &mut LiteSVM, mint: Pubkey, owner: Pubkey, amount: u64)
         close_authority: None.into(),
     };
 
-    let ata_data = pack_data(ata_state);
+    let token_account_data = pack_data(token_account_state);
     let lamports = litesvm.minimum_balance_for_rent_exemption(TokenAccount::LEN);
 
     litesvm
         .set_account(
-            ata,
+            address,
+            Account {
+                lamports,
+                data: token_account_data,
+                owner: token_program,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+}
+
+/// Approves `delegate` to spend `amount` from the plain token account at `address`, as the
+/// token program's `Approve` would.
+pub fn approve_delegate(litesvm: &mut LiteSVM, address: Pubkey, delegate: Pubkey, amount: u64) {
+    let mut account = litesvm.get_account(&address).unwrap();
+    let mut token_account = TokenAccount::unpack(&account.data).unwrap();
+
+    token_account.delegate = Some(delegate).into();
+    token_account.delegated_amount = amount;
+    account.data = pack_data(token_account);
+
+    litesvm.set_account(address, account).unwrap();
+}
+
+/// Freezes the token account at `address`, as its mint's freeze authority would.
+pub fn freeze_token_account(litesvm: &mut LiteSVM, address: Pubkey) {
+    let mut account = litesvm.get_account(&address).unwrap();
+    let mut token_account = TokenAccount::unpack(&account.data[..TokenAccount::LEN]).unwrap();
+
+    token_account.state = AccountState::Frozen;
+    TokenAccount::pack(token_account, &mut account.data[..TokenAccount::LEN]).unwrap();
+
+    litesvm.set_account(address, account).unwrap();
+}
+
+/// Creates an `m`-of-`signers.len()` SPL token multisig under `token_program`.
+pub fn init_multisig(
+    litesvm: &mut LiteSVM,
+    token_program: Pubkey,
+    m: u8,
+    signers: &[Pubkey],
+) -> Pubkey {
+    let multisig = Pubkey::new_unique();
+    let mut multisig_state = Multisig {
+        m,
+        n: signers.len() as u8,
+        is_initialized: true,
+        ..Default::default()
+    };
+    multisig_state.signers[..signers.len()].copy_from_slice(signers);
+
+    let lamports = litesvm.minimum_balance_for_rent_exemption(Multisig::LEN);
+
+    litesvm
+        .set_account(
+            multisig,
             Account {
                 lamports,
-                data: ata_data,
+                data: pack_data(multisig_state),
                 owner: token_program,
                 executable: false,
                 rent_epoch: 0,
@@ -142,5 +411,340 @@ pub fn init_ata(litesvm: &mut LiteSVM, mint: Pubkey, owner: Pubkey, amount: u64)
         )
         .unwrap();
 
+    multisig
+}
+
+/// Creates the wrapped SOL mint, which LiteSVM does not load with the token program.
+pub fn init_native_mint(litesvm: &mut LiteSVM) -> Pubkey {
+    let mint = Pubkey::new_from_array(NATIVE_MINT);
+
+    let mint_state = Mint {
+        mint_authority: None.into(),
+        supply: 0,
+        decimals: 9,
+        is_initialized: true,
+        freeze_authority: None.into(),
+    };
+
+    let lamports = litesvm.minimum_balance_for_rent_exemption(Mint::LEN);
+
+    litesvm
+        .set_account(
+            mint,
+            Account {
+                lamports,
+                data: pack_data(mint_state),
+                owner: TOKEN_PROGRAM_ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+
+    mint
+}
+
+/// Creates `owner`'s wrapped SOL associated token account holding `amount` lamports on top of
+/// its rent-exempt reserve.
+pub fn init_wrapped_sol_ata(litesvm: &mut LiteSVM, owner: Pubkey, amount: u64) -> Pubkey {
+    let mint = Pubkey::new_from_array(NATIVE_MINT);
+    let ata = get_associated_token_address_with_program_id(&owner, &mint, &TOKEN_PROGRAM_ID);
+    let rent = litesvm.minimum_balance_for_rent_exemption(TokenAccount::LEN);
+
+    let token_account_state = TokenAccount {
+        mint,
+        owner,
+        amount,
+        delegate: None.into(),
+        state: AccountState::Initialized,
+        is_native: Some(rent).into(),
+        delegated_amount: 0,
+        close_authority: None.into(),
+    };
+
+    litesvm
+        .set_account(
+            ata,
+            Account {
+                lamports: rent + amount,
+                data: pack_data(token_account_state),
+                owner: TOKEN_PROGRAM_ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+
     ata
 }
+
+/// Creates `owner`'s Token-2022 associated token account for `mint` with the `MemoTransfer`
+/// extension set to require memos on incoming transfers.
+pub fn init_memo_required_ata(
+    litesvm: &mut LiteSVM,
+    mint: Pubkey,
+    owner: Pubkey,
+    amount: u64,
+) -> Pubkey {
+    let ata = get_associated_token_address_with_program_id(&owner, &mint, &TOKEN_2022_PROGRAM_ID);
+    let len =
+        ExtensionType::try_calculate_account_len::<TokenAccount>(&[ExtensionType::MemoTransfer])
+            .unwrap();
+    let mut data = vec![0; len];
+    let mut state =
+        StateWithExtensionsMut::<TokenAccount>::unpack_uninitialized(&mut data).unwrap();
+
+    state.base = TokenAccount {
+        mint,
+        owner,
+        amount,
+        delegate: None.into(),
+        state: AccountState::Initialized,
+        is_native: None.into(),
+        delegated_amount: 0,
+        close_authority: None.into(),
+    };
+    state.pack_base();
+    state.init_account_type().unwrap();
+    state
+        .init_extension::<MemoTransfer>(true)
+        .unwrap()
+        .require_incoming_transfer_memos = true.into();
+
+    let lamports = litesvm.minimum_balance_for_rent_exemption(len);
+
+    litesvm
+        .set_account(
+            ata,
+            Account {
+                lamports,
+                data,
+                owner: TOKEN_2022_PROGRAM_ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+
+    ata
+}
+
+/// Loads the test transfer hook program and creates a Token-2022 mint registered with it, along
+/// with the hook's validation account and the counter it tallies transfers into. The hook also
+/// reads `read_accounts` on every transfer. Returns the mint and the counter.
+pub fn init_transfer_hook_mint(
+    litesvm: &mut LiteSVM,
+    decimals: u8,
+    supply: u64,
+    read_accounts: &[Pubkey],
+) -> (Pubkey, Pubkey) {
+    litesvm
+        .add_program_from_file(
+            TEST_TRANSFER_HOOK_PROGRAM_ID,
+            "target/deploy/test_transfer_hook.so",
+        )
+        .unwrap();
+
+    let mint = Keypair::new().pubkey();
+    let len =
+        ExtensionType::try_calculate_account_len::<Mint>(&[ExtensionType::TransferHook]).unwrap();
+    let mut data = vec![0; len];
+    let mut state = StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut data).unwrap();
+
+    state.base = Mint {
+        mint_authority: None.into(),
+        supply,
+        decimals,
+        is_initialized: true,
+        freeze_authority: None.into(),
+    };
+    state.pack_base();
+    state.init_account_type().unwrap();
+    state
+        .init_extension::<TransferHook>(true)
+        .unwrap()
+        .program_id = Some(TEST_TRANSFER_HOOK_PROGRAM_ID).try_into().unwrap();
+
+    let lamports = litesvm.minimum_balance_for_rent_exemption(len);
+
+    litesvm
+        .set_account(
+            mint,
+            Account {
+                lamports,
+                data,
+                owner: TOKEN_2022_PROGRAM_ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+
+    let counter = Pubkey::new_unique();
+    let (validation, _) = Pubkey::find_program_address(
+        &[b"extra-account-metas", mint.as_ref()],
+        &TEST_TRANSFER_HOOK_PROGRAM_ID,
+    );
+
+    // an `ExtraAccountMetaList` TLV entry for `Execute`, listing the counter as a fixed,
+    // writable account and the accounts to read as fixed, read-only ones
+    let metas = 1 + read_accounts.len() as u32;
+    let mut validation_data = EXECUTE_DISCRIMINATOR.to_vec();
+    validation_data.extend_from_slice(&(4 + 35 * metas).to_le_bytes());
+    validation_data.extend_from_slice(&metas.to_le_bytes());
+
+    for (account, is_writable) in
+        core::iter::once((&counter, 1)).chain(read_accounts.iter().map(|account| (account, 0)))
+    {
+        validation_data.push(0);
+        validation_data.extend_from_slice(account.as_ref());
+        validation_data.extend_from_slice(&[0, is_writable]);
+    }
+
+    for (address, data) in [(validation, validation_data), (counter, vec![0; 16])] {
+        let lamports = litesvm.minimum_balance_for_rent_exemption(data.len());
+
+        litesvm
+            .set_account(
+                address,
+                Account {
+                    lamports,
+                    data,
+                    owner: TEST_TRANSFER_HOOK_PROGRAM_ID,
+                    executable: false,
+                    rent_epoch: 0,
+                },
+            )
+            .unwrap();
+    }
+
+    (mint, counter)
+}
+
+/// Creates `owner`'s associated token account for a mint with a transfer hook, carrying the
+/// `TransferHookAccount` extension Token-2022 flags during transfers.
+pub fn init_transfer_hook_ata(
+    litesvm: &mut LiteSVM,
+    mint: Pubkey,
+    owner: Pubkey,
+    amount: u64,
+) -> Pubkey {
+    let ata = get_associated_token_address_with_program_id(&owner, &mint, &TOKEN_2022_PROGRAM_ID);
+    let len = ExtensionType::try_calculate_account_len::<TokenAccount>(&[
+        ExtensionType::TransferHookAccount,
+    ])
+    .unwrap();
+    let mut data = vec![0; len];
+    let mut state =
+        StateWithExtensionsMut::<TokenAccount>::unpack_uninitialized(&mut data).unwrap();
+
+    state.base = TokenAccount {
+        mint,
+        owner,
+        amount,
+        delegate: None.into(),
+        state: AccountState::Initialized,
+        is_native: None.into(),
+        delegated_amount: 0,
+        close_authority: None.into(),
+    };
+    state.pack_base();
+    state.init_account_type().unwrap();
+    state.init_extension::<TransferHookAccount>(true).unwrap();
+
+    let lamports = litesvm.minimum_balance_for_rent_exemption(len);
+
+    litesvm
+        .set_account(
+            ata,
+            Account {
+                lamports,
+                data,
+                owner: TOKEN_2022_PROGRAM_ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        )
+        .unwrap();
+
+    ata
+}
+
+/// Backing buffer laid out like a runtime-serialized account, for exercising account checks
+/// without going through LiteSVM.
+pub struct RawAccount {
+    buffer: Vec<u64>,
+}
+
+impl RawAccount {
+    pub fn new(owner: &[u8; 32], data: &[u8]) -> Self {
+        let mut buffer = vec![0u64; (RAW_ACCOUNT_HEADER_LEN + data.len()).div_ceil(8)];
+        let bytes = unsafe {
+            core::slice::from_raw_parts_mut(buffer.as_mut_ptr() as *mut u8, buffer.len() * 8)
+        };
+
+        bytes[0] = u8::MAX;
+        bytes[RAW_ACCOUNT_OWNER_OFFSET..RAW_ACCOUNT_OWNER_OFFSET + 32].copy_from_slice(owner);
+        bytes[RAW_ACCOUNT_DATA_LEN_OFFSET..RAW_ACCOUNT_HEADER_LEN]
+            .copy_from_slice(&(data.len() as u64).to_le_bytes());
+        bytes[RAW_ACCOUNT_HEADER_LEN..RAW_ACCOUNT_HEADER_LEN + data.len()].copy_from_slice(data);
+
+        Self { buffer }
+    }
+
+    pub fn info(&mut self) -> pinocchio::account_info::AccountInfo {
+        unsafe {
+            transmute::<*mut u8, pinocchio::account_info::AccountInfo>(
+                self.buffer.as_mut_ptr() as *mut u8
+            )
+        }
+    }
+}
+
+/// Every level of the Merkle tree over `wallets`, from the leaves up to the root. A node
+/// without a sibling is carried up unchanged.
+fn merkle_levels(wallets: &[Pubkey]) -> Vec<Vec<[u8; 32]>> {
+    let mut levels = vec![
+        wallets
+            .iter()
+            .map(|wallet| MerkleProof::leaf(&wallet.to_bytes()))
+            .collect::<Vec<_>>(),
+    ];
+
+    while levels.last().unwrap().len() > 1 {
+        let level = levels
+            .last()
+            .unwrap()
+            .chunks(2)
+            .map(|pair| match pair {
+                [a, b] => MerkleProof::node(a, b),
+                [a] => *a,
+                _ => unreachable!(),
+            })
+            .collect();
+
+        levels.push(level);
+    }
+
+    levels
+}
+
+pub fn merkle_root(wallets: &[Pubkey]) -> [u8; 32] {
+    merkle_levels(wallets).last().unwrap()[0]
+}
+
+/// The concatenated siblings proving `wallet` is one of `wallets`, as Contribute expects them.
+pub fn merkle_proof(wallets: &[Pubkey], wallet: &Pubkey) -> Vec<u8> {
+    let mut index = wallets.iter().position(|w| w == wallet).unwrap();
+    let mut proof = vec![];
+
+    for level in merkle_levels(wallets).split_last().unwrap().1 {
+        if let Some(sibling) = level.get(index ^ 1) {
+            proof.extend_from_slice(sibling);
+        }
+
+        index /= 2;
+    }
+
+    proof
+}
@@ -0,0 +1,151 @@
+//! A fundraise of wrapped SOL, from contributions funded by plain lamport transfers to a
+//! claim paid out as lamports.
+
+use solana_signer::Signer;
+use spl_associated_token_account::solana_program::{
+    clock::SECONDS_PER_DAY, native_token::LAMPORTS_PER_SOL,
+};
+
+use crate::{
+    AccountLoad, Fundraise, FundraiserError, client,
+    tests::{
+        constants::{FEE_COLLECTOR, MINT_DECIMALS, TOKEN_PROGRAM_ID},
+        utils::{
+            assert_error, build_and_send_transaction, init_ata, init_mint, init_native_mint,
+            init_wallet, init_wrapped_sol_ata, setup,
+        },
+    },
+};
+
+#[test]
+fn raise_wrapped_sol() {
+    let (litesvm, _default_payer) = &mut setup();
+    let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let mint_to_raise = init_native_mint(litesvm);
+
+    let ix = client::initialize(
+        &maker.pubkey(),
+        &mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+        3 * LAMPORTS_PER_SOL,
+        SECONDS_PER_DAY,
+    );
+    build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+    let early = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let late = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    init_wrapped_sol_ata(litesvm, late.pubkey(), 3 * LAMPORTS_PER_SOL);
+
+    // lamports sent straight to the account are synced into its balance when contributing
+    let early_ata = init_wrapped_sol_ata(litesvm, early.pubkey(), 0);
+    litesvm.airdrop(&early_ata, LAMPORTS_PER_SOL / 2).unwrap();
+
+    for (backer, amount) in [
+        (&late, 2 * LAMPORTS_PER_SOL),
+        (&early, LAMPORTS_PER_SOL / 2),
+    ] {
+        let ix = client::contribute(
+            &backer.pubkey(),
+            &maker.pubkey(),
+            &mint_to_raise,
+            &TOKEN_PROGRAM_ID,
+            amount,
+        );
+        build_and_send_transaction(litesvm, &[backer], &backer.pubkey(), &[ix]).unwrap();
+    }
+
+    // the early backer takes their contribution back as lamports
+    let balance = litesvm.get_balance(&early.pubkey()).unwrap();
+    let ata_lamports = litesvm.get_balance(&early_ata).unwrap();
+
+    let mut ix = client::refund(
+        &early.pubkey(),
+        &maker.pubkey(),
+        &mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+    );
+    *ix.data.last_mut().unwrap() = 1;
+    build_and_send_transaction(litesvm, &[&early], &early.pubkey(), &[ix]).unwrap();
+
+    assert!(litesvm.get_account(&early_ata).is_none());
+    assert_eq!(
+        litesvm.get_balance(&early.pubkey()).unwrap(),
+        balance + ata_lamports + LAMPORTS_PER_SOL / 2 - 5_000
+    );
+
+    let ix = client::contribute(
+        &late.pubkey(),
+        &maker.pubkey(),
+        &mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+        LAMPORTS_PER_SOL,
+    );
+    build_and_send_transaction(litesvm, &[&late], &late.pubkey(), &[ix]).unwrap();
+
+    let fundraise = client::fundraise_address(&maker.pubkey());
+    let account = litesvm.get_account(&fundraise).unwrap();
+
+    assert_eq!(
+        Fundraise::load(&account.data).unwrap().get_current_amount(),
+        3 * LAMPORTS_PER_SOL
+    );
+
+    // the maker's wrapped SOL account is created and closed within the claim
+    let balance = litesvm.get_balance(&maker.pubkey()).unwrap();
+
+    let mut ix = client::claim(
+        &maker.pubkey(),
+        &mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+        &FEE_COLLECTOR,
+    );
+    *ix.data.last_mut().unwrap() = 1;
+    build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+    let maker_ata =
+        client::associated_token_address(&maker.pubkey(), &mint_to_raise, &TOKEN_PROGRAM_ID);
+
+    assert!(litesvm.get_account(&maker_ata).is_none());
+    assert_eq!(
+        litesvm.get_balance(&maker.pubkey()).unwrap(),
+        balance + 3 * LAMPORTS_PER_SOL - 5_000
+    );
+}
+
+#[test]
+fn throw_if_unwrapping_other_mint() {
+    let (litesvm, _default_payer) = &mut setup();
+    let maker = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let backer = init_wallet(litesvm, LAMPORTS_PER_SOL);
+    let mint_to_raise = init_mint(litesvm, TOKEN_PROGRAM_ID, MINT_DECIMALS, 1_000_000_000);
+    init_ata(litesvm, mint_to_raise, backer.pubkey(), 1_000_000);
+
+    let ix = client::initialize(
+        &maker.pubkey(),
+        &mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+        5_000_000,
+        SECONDS_PER_DAY,
+    );
+    build_and_send_transaction(litesvm, &[&maker], &maker.pubkey(), &[ix]).unwrap();
+
+    let ix = client::contribute(
+        &backer.pubkey(),
+        &maker.pubkey(),
+        &mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+        500_000,
+    );
+    build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]).unwrap();
+
+    let mut ix = client::refund(
+        &backer.pubkey(),
+        &maker.pubkey(),
+        &mint_to_raise,
+        &TOKEN_PROGRAM_ID,
+    );
+    *ix.data.last_mut().unwrap() = 1;
+    let res = build_and_send_transaction(litesvm, &[&backer], &backer.pubkey(), &[ix]);
+
+    assert_error(res.unwrap_err(), FundraiserError::NotNativeMint);
+}